@@ -282,6 +282,7 @@ export default defineConfig({
           { text: "Overview", link: "/graph/" },
           { text: "Concepts", link: "/graph/concepts" },
           { text: "Repo catalog", link: "/graph/repo-catalog" },
+          { text: "Workspaces", link: "/graph/workspaces" },
           {
             text: "Coordinated changes",
             link: "/graph/coordinated-changes",
@@ -447,6 +448,10 @@ export default defineConfig({
                   { text: "merge", link: "/reference/cli/daft-merge" },
                   { text: "push", link: "/reference/cli/daft-push" },
                   { text: "prune", link: "/reference/cli/daft-prune" },
+                  {
+                    text: "adopt-worktree",
+                    link: "/reference/cli/daft-adopt-worktree",
+                  },
                   { text: "update", link: "/reference/cli/daft-update" },
                   { text: "carry", link: "/reference/cli/daft-carry" },
                   { text: "exec", link: "/reference/cli/daft-exec" },
@@ -480,13 +485,29 @@ export default defineConfig({
                     text: "repo unlink",
                     link: "/reference/cli/daft-repo-unlink",
                   },
+                  {
+                    text: "workspace",
+                    link: "/reference/cli/daft-workspace",
+                  },
                 ],
               },
               {
                 text: "Utilities",
                 items: [
                   { text: "list", link: "/reference/cli/daft-list" },
+                  { text: "which", link: "/reference/cli/daft-which" },
                   { text: "doctor", link: "/reference/cli/daft-doctor" },
+                  { text: "auth", link: "/reference/cli/daft-auth" },
+                  { text: "remote", link: "/reference/cli/daft-remote" },
+                  { text: "clone-all", link: "/reference/cli/daft-clone-all" },
+                  {
+                    text: "consolidate",
+                    link: "/reference/cli/daft-consolidate",
+                  },
+                  { text: "stats", link: "/reference/cli/daft-stats" },
+                  { text: "backup", link: "/reference/cli/daft-backup" },
+                  { text: "template", link: "/reference/cli/daft-template" },
+                  { text: "pin", link: "/reference/cli/daft-pin" },
                   {
                     text: "skill install",
                     link: "/reference/cli/daft-skill-install",
@@ -503,10 +524,15 @@ export default defineConfig({
                     text: "release-notes",
                     link: "/reference/cli/daft-release-notes",
                   },
+                  { text: "whats-new", link: "/reference/cli/daft-whats-new" },
                   {
                     text: "shell-init",
                     link: "/reference/cli/daft-shell-init",
                   },
+                  {
+                    text: "env",
+                    link: "/reference/cli/daft-env",
+                  },
                   {
                     text: "completions",
                     link: "/reference/cli/daft-completions",
@@ -518,6 +544,10 @@ export default defineConfig({
                 text: "Configuration",
                 items: [
                   { text: "config", link: "/reference/cli/daft-config" },
+                  {
+                    text: "config setup",
+                    link: "/reference/cli/daft-config-setup",
+                  },
                   { text: "hooks", link: "/reference/cli/git-daft-hooks" },
                   { text: "layout", link: "/reference/cli/daft-layout" },
                   {
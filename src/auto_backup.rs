@@ -0,0 +1,160 @@
+//! Scheduled background backups.
+//!
+//! When a repository sets both `daft.backup.to` and `daft.backup.interval`,
+//! every daft invocation inside it checks the store's backup history and,
+//! once the interval has passed since the last successful backup, spawns a
+//! detached `daft __backup` — the same fire-and-forget pattern as the update
+//! check and trust pruning.
+//!
+//! So that a project nobody opens is still backed up, `daft backup schedule
+//! install` adds a user-level systemd timer or launchd agent ([`timer`]),
+//! generated by the same machinery as `daft hooks schedule install`. It runs
+//! `daft __backup` hourly, which backs up only when the interval is due.
+//!
+//! A failed run is recorded like a successful one and shows in
+//! `daft backup status`; it is retried after an hour (or after the interval,
+//! when that is shorter) rather than on every invocation.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::core::backup::{self, Destination};
+use crate::git::GitCommand;
+use crate::hooks::schedule::{ScheduledTask, unit_prefix};
+use crate::settings::{DaftSettings, keys};
+
+/// Environment variable to disable scheduled backups.
+pub const NO_AUTO_BACKUP_ENV: &str = "DAFT_NO_AUTO_BACKUP";
+
+/// How long a failed run waits before the next attempt.
+const RETRY_AFTER: Duration = Duration::from_secs(60 * 60);
+
+/// How often the installed timer asks `daft __backup` whether a backup is
+/// due. The interval itself is enforced by the same check as in-process.
+const TIMER_SCHEDULE: &str = "@hourly";
+
+/// Unit-name prefix of the backup timer for `worktree`. Distinct from the
+/// `daft.yml` task prefix, so `daft hooks schedule install` never removes the
+/// timer as stale.
+pub fn timer_prefix(worktree: &Path) -> String {
+    format!(
+        "daft-backup-{}",
+        unit_prefix(worktree).trim_start_matches("daft-")
+    )
+}
+
+/// The timer that runs `daft __backup` in `worktree`.
+pub fn timer(worktree: &Path, daft: PathBuf, path_env: String) -> Result<ScheduledTask> {
+    Ok(ScheduledTask {
+        task: "check".to_string(),
+        schedule: TIMER_SCHEDULE.parse()?,
+        worktree: worktree.to_path_buf(),
+        daft,
+        args: vec!["__backup".to_string()],
+        prefix: timer_prefix(worktree),
+        origin: "Generated by `daft backup schedule install`; set daft.backup.interval instead.",
+        path_env,
+    })
+}
+
+/// Spawn a background backup if this repository's schedule says one is due.
+/// Never panics — wrapped in `catch_unwind`.
+pub fn maybe_backup() {
+    let _ = std::panic::catch_unwind(maybe_backup_inner);
+}
+
+/// Entry point for the `daft __backup` background process.
+pub fn run_scheduled_backup() -> Result<()> {
+    let cwd = env::current_dir()?;
+    let settings = DaftSettings::load()?;
+    let (Some(spec), Some(interval)) = (settings.backup_to, settings.backup_interval) else {
+        return Ok(());
+    };
+    if !is_due(&cwd, interval) {
+        return Ok(());
+    }
+    let project = crate::core::repo::get_project_root()?
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .context("Could not name the project")?;
+    let destination =
+        Destination::resolve(&spec, crate::settings::keys::BACKUP_TO, &cwd, &project)?;
+    backup::run(&cwd, &destination)?;
+    Ok(())
+}
+
+fn maybe_backup_inner() {
+    // Background processes never schedule further background work.
+    if crate::cli::argv().iter().any(|a| a.starts_with("__")) {
+        return;
+    }
+    if env::var(NO_AUTO_BACKUP_ENV).is_ok() || crate::trust_prune::is_ci_environment() {
+        return;
+    }
+    let Ok(cwd) = env::current_dir() else {
+        return;
+    };
+    // Outside a repository config_get fails, which ends the check here.
+    let git = GitCommand::new(true);
+    let configured = |key| git.config_get(key).ok().flatten().filter(|v| !v.is_empty());
+    let Some(interval) = configured(keys::BACKUP_INTERVAL)
+        .and_then(|v| crate::settings::parse_push_timeout(&v))
+        .flatten()
+    else {
+        return;
+    };
+    if configured(keys::BACKUP_TO).is_none() || !is_due(&cwd, interval) {
+        return;
+    }
+    let _ = spawn_background(&cwd);
+}
+
+/// Whether the last successful backup is older than `interval`, and no
+/// failed attempt is still waiting out its retry delay.
+fn is_due(repo_dir: &Path, interval: Duration) -> bool {
+    let Ok((latest, latest_success)) = backup::history(repo_dir) else {
+        return false;
+    };
+    let now = Utc::now();
+    let age = |at: chrono::DateTime<Utc>| (now - at).to_std().unwrap_or_default();
+    if latest_success.is_some_and(|run| age(run.finished_at) < interval) {
+        return false;
+    }
+    !latest
+        .is_some_and(|run| run.error.is_some() && age(run.finished_at) < interval.min(RETRY_AFTER))
+}
+
+fn spawn_background(cwd: &Path) -> Result<()> {
+    let exe = env::current_exe().context("Could not determine current executable")?;
+    Command::new(exe)
+        .arg("__backup")
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("Failed to spawn background backup")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer_runs_the_backup_check_outside_the_hook_prefix() {
+        let worktree = Path::new("/tmp/project/main");
+        let timer = timer(worktree, PathBuf::from("/usr/bin/daft"), String::new()).unwrap();
+        assert!(
+            timer
+                .systemd_service()
+                .contains("ExecStart=/usr/bin/daft __backup")
+        );
+        assert!(timer.unit_name().starts_with("daft-backup-"));
+        assert!(!timer.unit_name().starts_with(&unit_prefix(worktree)));
+    }
+}
@@ -0,0 +1,296 @@
+//! Cross-project links — pairing a worktree with the matching-branch
+//! worktree of another repo.
+//!
+//! Declared in `daft.yml` under a top-level `links:` key:
+//!
+//! ```yaml
+//! links:
+//!   - url: git@github.com:org/ui-kit.git
+//!     name: ui-kit          # optional friendly label
+//!     path: vendor/ui-kit   # symlink in this worktree → the linked worktree
+//!     env: UI_KIT_PATH      # set to the linked worktree's path for hooks/tasks
+//!     create: true          # provision the branch over there (default)
+//! ```
+//!
+//! Where a relation (see [`super::relations`]) only says two repos belong
+//! together, a link says *this worktree uses that repo's worktree for the
+//! same branch*. `daft start feature/x` / `daft go feature/x` provision
+//! `feature/x` in every linked repo before creating the worktree here
+//! (checking the branch out over there when it exists, else branching from
+//! that repo's default branch), so post-create hooks can already build
+//! against it. A linked repo without a worktree for the branch — `create:
+//! false`, or a provisioning failure — falls back to its default-branch
+//! worktree. Links are keyed by remote URL like relations, and are not
+//! followed transitively.
+//!
+//! Links act outside the worktree — symlinks into it, worktrees in other
+//! repos — so they only take effect in a trusted repository, and a `path`
+//! that isn't relative and inside the worktree is refused.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::relations::{row_for_url, url_label};
+use super::worktrees::worktree_children;
+use crate::store::CatalogRepoRow;
+
+/// One `links:` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LinkEntry {
+    /// Remote URL of the linked repo — resolved against the catalog.
+    pub url: String,
+
+    /// Optional friendly label, used in output when present.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Path, relative to the worktree root, where a symlink to the linked
+    /// worktree is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Environment variable set to the linked worktree's path in hooks and
+    /// tasks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+
+    /// Whether a missing matching-branch worktree in the linked repo is
+    /// created on demand (default: true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create: Option<bool>,
+}
+
+impl LinkEntry {
+    /// Display label: the manifest's `name` if given, else the last URL
+    /// path component.
+    pub fn label(&self) -> &str {
+        match &self.name {
+            Some(name) => name,
+            None => url_label(&self.url),
+        }
+    }
+
+    pub fn creates(&self) -> bool {
+        self.create.unwrap_or(true)
+    }
+}
+
+/// A `links:` entry resolved against the local catalog.
+#[derive(Debug, Clone)]
+pub struct ResolvedLink {
+    pub entry: LinkEntry,
+    /// The local clone, when one exists.
+    pub repo: Option<CatalogRepoRow>,
+}
+
+/// Resolve entries against live catalog rows by normalized URL.
+pub fn resolve_links(entries: &[LinkEntry], live_rows: &[CatalogRepoRow]) -> Vec<ResolvedLink> {
+    entries
+        .iter()
+        .map(|entry| ResolvedLink {
+            entry: entry.clone(),
+            repo: row_for_url(&entry.url, live_rows).cloned(),
+        })
+        .collect()
+}
+
+/// `links:` from the merged `daft.yml` of the worktree at `root`.
+pub fn links_at(root: &Path) -> Vec<LinkEntry> {
+    crate::hooks::yaml_config_loader::load_merged_config(root)
+        .ok()
+        .flatten()
+        .and_then(|config| config.links)
+        .unwrap_or_default()
+}
+
+/// Whether the repository containing `root` is trusted (`allow`), which
+/// links need before they create anything.
+pub fn links_trusted(root: &Path) -> bool {
+    crate::core::repo::git_common_dir_at(root).is_some_and(|git_dir| {
+        crate::hooks::TrustDatabase::load()
+            .map(|db| db.get_trust_level(&git_dir) == crate::hooks::TrustLevel::Allow)
+            .unwrap_or(false)
+    })
+}
+
+/// Whether a link `path` names a location inside the worktree: non-empty,
+/// relative, and without `..` components.
+pub fn path_is_contained(rel: &str) -> bool {
+    let path = Path::new(rel);
+    !rel.is_empty()
+        && !path.is_absolute()
+        && path.components().all(|c| {
+            matches!(
+                c,
+                std::path::Component::Normal(_) | std::path::Component::CurDir
+            )
+        })
+}
+
+/// Resolve `entries` against the live catalog. A catalog that can't be
+/// opened leaves every link unresolved rather than failing the caller.
+pub fn resolve_against_catalog(entries: &[LinkEntry]) -> Vec<ResolvedLink> {
+    let rows = crate::catalog::Catalog::open_ro()
+        .ok()
+        .flatten()
+        .and_then(|catalog| catalog.list(false).ok())
+        .unwrap_or_default();
+    resolve_links(entries, &rows)
+}
+
+/// The linked repo's worktree for exactly `branch`.
+pub fn matching_worktree(row: &CatalogRepoRow, branch: &str) -> Option<PathBuf> {
+    worktree_children(row, None)?
+        .into_iter()
+        .find(|c| c.branch.as_deref() == Some(branch))
+        .map(|c| PathBuf::from(c.path))
+}
+
+/// The worktree a link points at for `branch`: the matching-branch
+/// worktree, else the linked repo's default-branch worktree.
+pub fn linked_worktree(row: &CatalogRepoRow, branch: &str) -> Option<PathBuf> {
+    matching_worktree(row, branch).or_else(|| {
+        let default = crate::catalog::effective_default_branch(row)?;
+        matching_worktree(row, &default)
+    })
+}
+
+/// Environment for hooks and tasks running on `branch`: each link's `env`
+/// name mapped to its linked worktree. Links that don't resolve are left
+/// out, so a hook can test `[ -n "$NAME" ]`.
+pub fn hook_env(entries: &[LinkEntry], branch: &str) -> BTreeMap<String, String> {
+    if !entries.iter().any(|e| e.env.is_some()) {
+        return BTreeMap::new();
+    }
+    resolve_against_catalog(entries)
+        .into_iter()
+        .filter_map(|link| {
+            let name = link.entry.env?;
+            let path = linked_worktree(link.repo.as_ref()?, branch)?;
+            Some((name, path.display().to_string()))
+        })
+        .collect()
+}
+
+/// Create the `path:` symlinks in a freshly created worktree. Returns one
+/// warning per link that couldn't be made; never fails the creation.
+pub fn link_paths_on_create(worktree_path: &Path, branch: &str) -> Vec<String> {
+    let entries = links_at(worktree_path);
+    if !entries.iter().any(|e| e.path.is_some()) {
+        return Vec::new();
+    }
+    if !links_trusted(worktree_path) {
+        return vec![format!(
+            "link paths skipped: repository not trusted (run `{}` to allow them)",
+            crate::daft_cmd("hooks trust")
+        )];
+    }
+    let mut warnings = Vec::new();
+    for link in resolve_against_catalog(&entries) {
+        let Some(rel_path) = &link.entry.path else {
+            continue;
+        };
+        let label = link.entry.label();
+        let Some(target) = link
+            .repo
+            .as_ref()
+            .and_then(|row| linked_worktree(row, branch))
+        else {
+            warnings.push(format!(
+                "link '{label}': no worktree to point '{rel_path}' at"
+            ));
+            continue;
+        };
+        if let Err(e) = create_link_symlink(worktree_path, rel_path, &target) {
+            warnings.push(format!("link '{label}': {e:#}"));
+        }
+    }
+    warnings
+}
+
+fn create_link_symlink(worktree_path: &Path, rel_path: &str, target: &Path) -> anyhow::Result<()> {
+    if !path_is_contained(rel_path) {
+        anyhow::bail!("'{rel_path}' must be a relative path inside the worktree");
+    }
+    let link_path = worktree_path.join(rel_path);
+    let parent = link_path.parent().unwrap_or(worktree_path);
+    let rel_target = crate::core::shared::relative_symlink_target(parent, target)?;
+    if link_path.is_symlink() && std::fs::read_link(&link_path)? == rel_target {
+        return Ok(());
+    }
+    if link_path.exists() || link_path.is_symlink() {
+        anyhow::bail!("'{rel_path}' already exists; not replacing it with a link");
+    }
+    std::fs::create_dir_all(parent)?;
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&rel_target, &link_path)?;
+    #[cfg(not(unix))]
+    anyhow::bail!("link symlinks are not supported on this platform ({rel_path})");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn yaml_shape_parses_with_defaults() {
+        let yaml = r#"
+- url: git@github.com:org/ui-kit.git
+  path: vendor/ui-kit
+  env: UI_KIT_PATH
+- url: https://github.com/org/schema
+  name: db-schema
+  create: false
+"#;
+        let entries: Vec<LinkEntry> = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(entries[0].label(), "ui-kit");
+        assert!(entries[0].creates());
+        assert_eq!(entries[1].label(), "db-schema");
+        assert!(!entries[1].creates());
+        assert!(serde_yaml::from_str::<Vec<LinkEntry>>("- url: x\n  env_var: X\n").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_is_relative_idempotent_and_never_clobbers() {
+        let tmp = TempDir::new().unwrap();
+        let worktree = tmp.path().join("app/feat");
+        let target = tmp.path().join("lib/feat");
+        std::fs::create_dir_all(&worktree).unwrap();
+        std::fs::create_dir_all(&target).unwrap();
+
+        create_link_symlink(&worktree, "vendor/lib", &target).unwrap();
+        let link = worktree.join("vendor/lib");
+        assert!(std::fs::read_link(&link).unwrap().is_relative());
+        assert_eq!(link.canonicalize().unwrap(), target.canonicalize().unwrap());
+        create_link_symlink(&worktree, "vendor/lib", &target).unwrap();
+
+        std::fs::write(worktree.join("taken"), "").unwrap();
+        let err = create_link_symlink(&worktree, "taken", &target).unwrap_err();
+        assert!(err.to_string().contains("already exists"), "got: {err}");
+
+        for escaping in ["../outside", "vendor/../../outside", "/tmp/outside", ""] {
+            let err = create_link_symlink(&worktree, escaping, &target).unwrap_err();
+            assert!(
+                err.to_string().contains("inside the worktree"),
+                "got: {err}"
+            );
+        }
+        assert!(!tmp.path().join("app/outside").exists());
+    }
+
+    #[test]
+    fn contained_paths() {
+        assert!(path_is_contained("vendor/lib"));
+        assert!(path_is_contained("./lib"));
+        assert!(!path_is_contained("../lib"));
+        assert!(!path_is_contained("a/../../lib"));
+        assert!(!path_is_contained("/abs"));
+        assert!(!path_is_contained(""));
+    }
+}
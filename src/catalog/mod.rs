@@ -24,6 +24,7 @@
 //! trust/layout into the store is its own future PR.
 
 pub mod fleet;
+pub mod links;
 pub mod normalize;
 pub mod registration;
 pub mod relations;
@@ -11,6 +11,8 @@
 //! path-shaped so path-based remotes match across clones of the same
 //! fixture.
 
+use crate::core::remote_url::{RemoteUrl, scp_like_parts};
+
 /// Normalize a remote URL into its match-key form.
 ///
 /// Rules: scheme and user stripped, host lowercased (path case preserved),
@@ -18,57 +20,34 @@
 /// trailing slashes stripped. Non-URL inputs (local paths) only get the
 /// trailing-slash and `.git` treatment.
 pub fn normalize_url(raw: &str) -> String {
-    let s = raw.trim().trim_end_matches('/');
-
-    if let Some(rest) = s.strip_prefix("ssh://") {
-        return host_path(rest, Some("22"));
-    }
-    if let Some(rest) = s.strip_prefix("git+ssh://") {
-        return host_path(rest, Some("22"));
-    }
-    if let Some(rest) = s.strip_prefix("git://") {
-        return host_path(rest, Some("9418"));
-    }
-    if let Some(rest) = s.strip_prefix("http://") {
-        return host_path(rest, Some("80"));
-    }
-    if let Some(rest) = s.strip_prefix("https://") {
-        return host_path(rest, Some("443"));
-    }
-    if let Some(rest) = s.strip_prefix("file://") {
-        return strip_git_suffix(rest).to_string();
-    }
-    if let Some((user_host, path)) = scp_like_parts(s) {
-        let host = user_host.rsplit('@').next().unwrap_or(user_host);
-        let path = strip_git_suffix(path.trim_start_matches('/'));
-        return format!("{}/{}", host.to_lowercase(), path);
-    }
-    // Local path (absolute, relative, or ~) — keep as-is minus suffixes.
-    strip_git_suffix(s).to_string()
-}
-
-/// `[user@]host[:port]/path` → `host[:port]/path` with the scheme's default
-/// port dropped and the host lowercased.
-fn host_path(rest: &str, default_port: Option<&str>) -> String {
-    let (host_part, path) = match rest.split_once('/') {
-        Some((h, p)) => (h, p),
-        None => (rest, ""),
+    let Some(url) = RemoteUrl::parse(raw) else {
+        return String::new();
+    };
+    let Some(host) = url.host.as_deref() else {
+        // Local path (absolute, relative, or ~) — keep as-is minus suffixes.
+        return strip_git_suffix(&url.path).to_string();
+    };
+    let default_port = match url.scheme.as_deref() {
+        Some("ssh") | Some("git+ssh") => Some(22),
+        Some("git") => Some(9418),
+        Some("http") => Some(80),
+        Some("https") => Some(443),
+        _ => None,
     };
-    let host_port = host_part.rsplit('@').next().unwrap_or(host_part);
-    let (host, port) = match host_port.rsplit_once(':') {
-        Some((h, p)) if !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()) => (h, Some(p)),
-        _ => (host_port, None),
+    let mut out = if host.contains(':') {
+        format!("[{host}]")
+    } else {
+        host.to_string()
     };
-    let mut out = host.to_lowercase();
-    if let Some(p) = port
-        && default_port != Some(p)
+    if let Some(port) = url.port
+        && default_port != Some(port)
     {
         out.push(':');
-        out.push_str(p);
+        out.push_str(&port.to_string());
     }
-    if !path.is_empty() {
+    if !url.path.is_empty() {
         out.push('/');
-        out.push_str(strip_git_suffix(path));
+        out.push_str(strip_git_suffix(&url.path));
     }
     out
 }
@@ -86,21 +65,6 @@ pub(crate) fn looks_like_remote_source(input: &str) -> bool {
         || std::path::Path::new(input).exists()
 }
 
-/// Split an scp-like remote (`[user@]host:path`) into its two halves.
-/// Returns `None` for anything that can't be scp shorthand: URLs with a
-/// scheme, strings without a colon, or colons that appear after the first
-/// path separator (`/dir/weird:name` is a local path, not a host).
-fn scp_like_parts(s: &str) -> Option<(&str, &str)> {
-    if s.contains("://") {
-        return None;
-    }
-    let (before, after) = s.split_once(':')?;
-    if before.is_empty() || before.contains('/') {
-        return None;
-    }
-    Some((before, after))
-}
-
 fn strip_git_suffix(s: &str) -> &str {
     let s = s.trim_end_matches('/');
     s.strip_suffix(".git").unwrap_or(s)
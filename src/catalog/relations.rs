@@ -41,19 +41,35 @@ impl RelationEntry {
     /// Display label: the manifest's `name` if given, else the last URL
     /// path component, else the raw URL.
     pub fn label(&self) -> &str {
-        if let Some(name) = &self.name {
-            return name;
+        match &self.name {
+            Some(name) => name,
+            None => url_label(&self.url),
         }
-        self.url
-            .trim_end_matches('/')
-            .trim_end_matches(".git")
-            .rsplit(['/', ':'])
-            .next()
-            .filter(|s| !s.is_empty())
-            .unwrap_or(&self.url)
     }
 }
 
+/// The last path component of a remote URL (`org/api.git` → `api`), else
+/// the raw URL.
+pub(crate) fn url_label(url: &str) -> &str {
+    url.trim_end_matches('/')
+        .trim_end_matches(".git")
+        .rsplit(['/', ':'])
+        .next()
+        .filter(|s| !s.is_empty())
+        .unwrap_or(url)
+}
+
+/// The live catalog row whose normalized remote matches `url`.
+pub(crate) fn row_for_url<'a>(
+    url: &str,
+    live_rows: &'a [CatalogRepoRow],
+) -> Option<&'a CatalogRepoRow> {
+    let key = normalize_url(url);
+    live_rows
+        .iter()
+        .find(|row| row.remote_url_normalized.as_deref() == Some(key.as_str()))
+}
+
 /// A manifest edge resolved against the local catalog.
 #[derive(Debug, Clone)]
 pub struct ResolvedRelation {
@@ -71,16 +87,9 @@ pub fn resolve_relations(
 ) -> Vec<ResolvedRelation> {
     entries
         .iter()
-        .map(|entry| {
-            let key = normalize_url(&entry.url);
-            let repo = live_rows
-                .iter()
-                .find(|row| row.remote_url_normalized.as_deref() == Some(key.as_str()))
-                .cloned();
-            ResolvedRelation {
-                entry: entry.clone(),
-                repo,
-            }
+        .map(|entry| ResolvedRelation {
+            entry: entry.clone(),
+            repo: row_for_url(&entry.url, live_rows).cloned(),
         })
         .collect()
 }
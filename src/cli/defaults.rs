@@ -0,0 +1,270 @@
+//! Per-command default flags from `daft.defaults.<command>`.
+//!
+//! `git config daft.defaults.prune "--force --stat lines"` makes every
+//! `daft prune` behave as if those flags were typed first. The defaults are
+//! spliced in front of the command-line arguments, and any default the
+//! command line already settles is dropped so the command line always wins:
+//!
+//! * the same option given again (`--stat summary` beats `--stat lines`);
+//! * its negation (`--no-carry` beats `--carry`, and the reverse);
+//! * an option the command declares as conflicting with it.
+//!
+//! A default that names no option of the command is skipped with a warning
+//! instead of failing every invocation of it.
+//!
+//! Matching is done against the command's clap definition, so short and long
+//! spellings and aliases of one option are recognised as the same option.
+
+use clap::{Arg, Command};
+
+use crate::output::deferred_warn;
+
+/// Config key prefix; the command name follows it.
+pub const KEY_PREFIX: &str = "daft.defaults.";
+
+/// The `daft.defaults.*` key for a command, from the name
+/// [`crate::get_clap_args`] gives it (`git-worktree-prune`, `daft-go`).
+///
+/// Keys use the `daft <verb>` names, so `git-worktree-fetch` (`daft update`)
+/// reads `daft.defaults.update`.
+pub fn config_key(expected_cmd: &str) -> String {
+    let name = expected_cmd
+        .strip_prefix("git-worktree-")
+        .or_else(|| expected_cmd.strip_prefix("daft-"))
+        .unwrap_or(expected_cmd);
+    let verb = match name {
+        "fetch" => "update",
+        "flow-adopt" => "adopt",
+        "flow-eject" => "eject",
+        other => other,
+    };
+    format!("{KEY_PREFIX}{verb}")
+}
+
+/// Split a `daft.defaults.*` value into arguments with shell quoting rules.
+///
+/// Returns `None` (after warning) when the quoting is unbalanced.
+pub fn split(key: &str, value: &str) -> Option<Vec<String>> {
+    let words = shlex::split(value);
+    if words.is_none() {
+        deferred_warn::warn(format!(
+            "warning: ignoring {key}: unbalanced quotes in '{value}'"
+        ));
+    }
+    words
+}
+
+/// Splice `defaults` into `args` (whose first element is the program name),
+/// dropping the defaults the command line overrides.
+pub fn apply(cmd: &Command, key: &str, defaults: &[String], args: &[String]) -> Vec<String> {
+    let Some((program, cli)) = args.split_first() else {
+        return args.to_vec();
+    };
+
+    let given: Vec<&Arg> = options(cmd, cli)
+        .into_iter()
+        .flat_map(|(ids, _)| ids)
+        .filter_map(|id| find(cmd, &id))
+        .collect();
+
+    let mut merged = vec![program.clone()];
+    for (ids, tokens) in options(cmd, defaults) {
+        if ids.is_empty() {
+            deferred_warn::warn(format!(
+                "warning: ignoring '{}' in {key}: not an option of this command",
+                tokens.join(" ")
+            ));
+            continue;
+        }
+        let overridden = ids
+            .iter()
+            .filter_map(|id| find(cmd, id))
+            .any(|arg| given.iter().any(|g| overrides(cmd, g, arg)));
+        if !overridden {
+            merged.extend(tokens);
+        }
+    }
+    merged.extend(cli.iter().cloned());
+    merged
+}
+
+/// Whether `given` on the command line settles the default `default`.
+fn overrides(cmd: &Command, given: &Arg, default: &Arg) -> bool {
+    if given.get_id() == default.get_id() {
+        return true;
+    }
+    if let (Some(a), Some(b)) = (given.get_long(), default.get_long())
+        && (a.strip_prefix("no-") == Some(b) || b.strip_prefix("no-") == Some(a))
+    {
+        return true;
+    }
+    let conflicts = |x: &Arg, y: &Arg| {
+        cmd.get_arg_conflicts_with(x)
+            .iter()
+            .any(|c| c.get_id() == y.get_id())
+    };
+    conflicts(given, default) || conflicts(default, given)
+}
+
+fn find<'a>(cmd: &'a Command, id: &str) -> Option<&'a Arg> {
+    cmd.get_arguments().find(|a| a.get_id() == id)
+}
+
+/// Group `args` into options with their values: each group is the ids of
+/// the options it sets and the tokens that spell it. Positional arguments
+/// form groups with no ids; everything after `--` is one such group.
+fn options(cmd: &Command, args: &[String]) -> Vec<(Vec<String>, Vec<String>)> {
+    let mut groups = Vec::new();
+    let mut iter = args.iter().peekable();
+    while let Some(token) = iter.next() {
+        if token == "--" {
+            let rest: Vec<String> = std::iter::once(token).chain(iter).cloned().collect();
+            groups.push((Vec::new(), rest));
+            break;
+        }
+        let mut tokens = vec![token.clone()];
+        let mut ids = Vec::new();
+        let mut wants_value = false;
+        if let Some(long) = token.strip_prefix("--") {
+            let (name, inline) = match long.split_once('=') {
+                Some((name, _)) => (name, true),
+                None => (long, false),
+            };
+            if let Some(arg) = cmd.get_arguments().find(|a| {
+                a.get_long() == Some(name)
+                    || a.get_all_aliases()
+                        .is_some_and(|aliases| aliases.contains(&name))
+            }) {
+                ids.push(arg.get_id().to_string());
+                wants_value = takes_value(arg) && !inline;
+            }
+        } else if let Some(shorts) = token.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // A cluster of short flags (`-qv`); a value-taking short ends the
+            // cluster, with the rest of the token (if any) as its value.
+            for (i, c) in shorts.char_indices() {
+                let Some(arg) = cmd.get_arguments().find(|a| {
+                    a.get_short() == Some(c)
+                        || a.get_all_short_aliases()
+                            .is_some_and(|aliases| aliases.contains(&c))
+                }) else {
+                    ids.clear();
+                    break;
+                };
+                ids.push(arg.get_id().to_string());
+                if takes_value(arg) {
+                    wants_value = i + c.len_utf8() == shorts.len();
+                    break;
+                }
+            }
+        }
+        // An unknown option takes its value along, so both are skipped together.
+        let unknown_option = ids.is_empty() && token.starts_with('-') && !token.contains('=');
+        if (wants_value || unknown_option)
+            && let Some(value) = iter.next_if(|v| !v.starts_with('-'))
+        {
+            tokens.push(value.clone());
+        }
+        groups.push((ids, tokens));
+    }
+    groups
+}
+
+fn takes_value(arg: &Arg) -> bool {
+    arg.get_action().takes_values()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{CommandFactory, Parser};
+
+    #[derive(Parser, Debug)]
+    #[command(name = "git-worktree-demo")]
+    struct Demo {
+        branch: Option<String>,
+        #[arg(short = 'c', long)]
+        carry: bool,
+        #[arg(long)]
+        no_carry: bool,
+        #[arg(short, long)]
+        quiet: bool,
+        #[arg(short, long)]
+        verbose: bool,
+        #[arg(long, visible_alias = "statistics")]
+        stat: Option<String>,
+        #[arg(long, conflicts_with = "all")]
+        repo: Option<String>,
+        #[arg(long)]
+        all: bool,
+    }
+
+    fn apply_to(defaults: &str, cli: &[&str]) -> Vec<String> {
+        let cmd = Demo::command();
+        let defaults = shlex::split(defaults).unwrap();
+        let args: Vec<String> = std::iter::once("demo")
+            .chain(cli.iter().copied())
+            .map(String::from)
+            .collect();
+        apply(&cmd, "daft.defaults.demo", &defaults, &args)
+    }
+
+    #[test]
+    fn config_key_uses_the_daft_verb() {
+        assert_eq!(config_key("git-worktree-prune"), "daft.defaults.prune");
+        assert_eq!(config_key("daft-go"), "daft.defaults.go");
+        assert_eq!(config_key("git-worktree-fetch"), "daft.defaults.update");
+        assert_eq!(config_key("git-worktree-flow-adopt"), "daft.defaults.adopt");
+        assert_eq!(
+            config_key("git-worktree-branch-delete"),
+            "daft.defaults.branch-delete"
+        );
+    }
+
+    #[test]
+    fn defaults_go_before_the_command_line() {
+        assert_eq!(
+            apply_to("--carry --stat lines", &["feat"]),
+            ["demo", "--carry", "--stat", "lines", "feat"]
+        );
+    }
+
+    #[test]
+    fn the_command_line_overrides_the_same_option() {
+        assert_eq!(
+            apply_to("--stat lines -q", &["--statistics=summary", "feat"]),
+            ["demo", "-q", "--statistics=summary", "feat"]
+        );
+        assert_eq!(apply_to("-qv", &["-v"]), ["demo", "-v"]);
+    }
+
+    #[test]
+    fn a_negation_overrides_in_either_direction() {
+        assert_eq!(apply_to("--carry", &["--no-carry"]), ["demo", "--no-carry"]);
+        assert_eq!(apply_to("--no-carry", &["-c"]), ["demo", "-c"]);
+    }
+
+    #[test]
+    fn a_conflicting_option_overrides() {
+        assert_eq!(
+            apply_to("--all", &["--repo", "api"]),
+            ["demo", "--repo", "api"]
+        );
+        assert_eq!(apply_to("--repo api", &["--all"]), ["demo", "--all"]);
+    }
+
+    #[test]
+    fn unknown_defaults_are_skipped() {
+        assert_eq!(
+            apply_to("--merged --older-than 30d --carry", &["feat"]),
+            ["demo", "--carry", "feat"]
+        );
+    }
+
+    #[test]
+    fn arguments_after_double_dash_are_not_options() {
+        assert_eq!(
+            apply_to("--carry", &["--", "--no-carry"]),
+            ["demo", "--carry", "--", "--no-carry"]
+        );
+    }
+}
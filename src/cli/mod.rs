@@ -15,6 +15,7 @@ use std::sync::OnceLock;
 use anyhow::{Context, Result};
 
 pub mod argv;
+pub mod defaults;
 
 static ARGV: OnceLock<Vec<String>> = OnceLock::new();
 
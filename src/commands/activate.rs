@@ -9,6 +9,13 @@ use crate::commands::shortcuts::{effective_install_dir, enable_style};
 use crate::output::{CliOutput, Output, OutputConfig};
 use crate::shortcuts::ShortcutStyle;
 
+/// First line of the block `activate` appends to a shell config file.
+pub(crate) const RC_BLOCK_START: &str = "# daft shell integration - enables cd into new worktrees";
+
+/// Last line of that block. Files activated by older versions lack it; their
+/// block is the start line plus the shell-init line.
+pub(crate) const RC_BLOCK_END: &str = "# end daft shell integration";
+
 #[derive(Debug, Clone, PartialEq)]
 enum Shell {
     Bash,
@@ -135,8 +142,9 @@ pub fn run() -> Result<()> {
     // Show what will be added
     output.info(&format!("Will append to {}:", config_file.display()));
     output.info("");
-    output.info("  # daft shell integration - enables cd into new worktrees");
+    output.info(&format!("  {RC_BLOCK_START}"));
     output.info(&format!("  {init_line}"));
+    output.info(&format!("  {RC_BLOCK_END}"));
     output.info("");
 
     if args.dry_run {
@@ -189,11 +197,9 @@ pub fn run() -> Result<()> {
     }
 
     writeln!(file)?;
-    writeln!(
-        file,
-        "# daft shell integration - enables cd into new worktrees"
-    )?;
+    writeln!(file, "{RC_BLOCK_START}")?;
     writeln!(file, "{init_line}")?;
+    writeln!(file, "{RC_BLOCK_END}")?;
 
     // Install git-style shortcuts silently
     let shortcuts_installed = if let Ok(install_dir) = effective_install_dir() {
@@ -224,3 +230,86 @@ pub fn run() -> Result<()> {
 
     Ok(())
 }
+
+/// The user's shell (`bash`, `zsh` or `fish`), from `$SHELL`.
+pub(crate) fn detected_shell() -> Option<&'static str> {
+    Shell::from_env().map(|shell| shell.name())
+}
+
+/// Whether the detected shell's config file already runs `daft shell-init`.
+pub(crate) fn is_activated() -> bool {
+    Shell::from_env()
+        .and_then(|shell| fs::read_to_string(shell.config_file()).ok())
+        .is_some_and(|content| content.contains("daft shell-init"))
+}
+
+/// Config files `activate` may have written to, for every supported shell.
+pub(crate) fn config_files() -> Vec<PathBuf> {
+    [Shell::Bash, Shell::Zsh, Shell::Fish]
+        .iter()
+        .map(Shell::config_file)
+        .collect()
+}
+
+/// `content` with every block `activate` appended removed, along with the
+/// blank line written before it. `None` when there is no block.
+pub(crate) fn remove_init_block(content: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut kept: Vec<&str> = Vec::with_capacity(lines.len());
+    let mut removed = false;
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i] != RC_BLOCK_START {
+            kept.push(lines[i]);
+            i += 1;
+            continue;
+        }
+        removed = true;
+        if kept.last().is_some_and(|line| line.is_empty()) {
+            kept.pop();
+        }
+        i += 1;
+        if lines
+            .get(i)
+            .is_some_and(|line| line.contains("daft shell-init"))
+        {
+            i += 1;
+        }
+        if lines.get(i) == Some(&RC_BLOCK_END) {
+            i += 1;
+        }
+    }
+    if !removed {
+        return None;
+    }
+    let mut result = kept.join("\n");
+    if !result.is_empty() && content.ends_with('\n') {
+        result.push('\n');
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_init_block_handles_current_and_legacy_blocks() {
+        let current = format!(
+            "export A=1\n\n{RC_BLOCK_START}\neval \"$(daft shell-init zsh)\"\n{RC_BLOCK_END}\nalias x=y\n"
+        );
+        assert_eq!(
+            remove_init_block(&current).as_deref(),
+            Some("export A=1\nalias x=y\n")
+        );
+
+        let legacy = format!("export A=1\n\n{RC_BLOCK_START}\neval \"$(daft shell-init bash)\"\n");
+        assert_eq!(remove_init_block(&legacy).as_deref(), Some("export A=1\n"));
+
+        assert_eq!(
+            remove_init_block("eval \"$(daft shell-init bash)\"\n"),
+            None,
+            "an unmarked line is the user's own and is left alone"
+        );
+    }
+}
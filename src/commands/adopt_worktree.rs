@@ -0,0 +1,124 @@
+use crate::{
+    core::{CommandBridge, worktree::adopt_worktree},
+    get_git_common_dir, get_project_root,
+    git::GitCommand,
+    hooks::HookExecutor,
+    is_git_repository,
+    logging::init_logging,
+    output::{CliOutput, Output, OutputConfig},
+    settings::DaftSettings,
+};
+use anyhow::Result;
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(name = "daft-adopt-worktree")]
+#[command(version = crate::VERSION)]
+#[command(about = "Register a worktree created outside daft")]
+#[command(long_about = r#"
+Registers a worktree created with plain `git worktree add` as if daft had
+created it: records the worktree's branch identity, copies untracked visitor
+daft files from the current worktree, links the files declared under
+`shared:`, and runs the worktree-post-create hook (which is where environment
+setup such as direnv or mise usually lives).
+
+The worktree must already be registered with git and have a branch checked
+out. To turn a plain directory into a worktree instead, use
+`daft go <branch> --on-conflict adopt`.
+
+With --move, the worktree is first moved to the path the repository's layout
+computes for its branch, and the shell follows when the current directory was
+inside it. Without --move, a worktree outside the layout is left in place and
+reported.
+"#)]
+pub struct Args {
+    #[arg(help = "Path to the worktree to adopt")]
+    path: PathBuf,
+
+    #[arg(
+        short = 'm',
+        long = "move",
+        help = "Move the worktree to its canonical layout path first"
+    )]
+    move_to_layout: bool,
+
+    #[arg(short, long, help = "Be verbose; show detailed progress")]
+    verbose: bool,
+}
+
+pub fn run() -> Result<()> {
+    // Skip argv[0] so clap sees "adopt-worktree" as the program name (same
+    // dispatcher shape as run/install/doctor).
+    let args = Args::parse_from(crate::cli::argv().iter().skip(1));
+
+    init_logging(args.verbose);
+
+    if !is_git_repository()? {
+        anyhow::bail!("Not inside a Git repository");
+    }
+
+    let settings = DaftSettings::load()?;
+    let config = OutputConfig::with_autocd(false, args.verbose, settings.autocd);
+    let mut output = CliOutput::new(config);
+
+    let git = GitCommand::new(false).with_gitoxide(settings.use_gitoxide);
+    let project_root = get_project_root()?;
+    let git_dir = get_git_common_dir()?;
+    let (layout, _) = crate::commands::checkout::resolve_checkout_layout(&git, &mut output);
+
+    let params = adopt_worktree::AdoptWorktreeParams {
+        path: args.path,
+        move_to_layout: args.move_to_layout,
+        remote_name: settings.remote.clone(),
+        layout: Some(layout),
+        multi_remote_enabled: settings.multi_remote_enabled,
+        multi_remote_default: settings.multi_remote_default.clone(),
+    };
+
+    let hooks_config = crate::core::settings::load_hooks_config_with(&git)?;
+    let hook_output_config = hooks_config.output.with_cli_verbose(args.verbose);
+    let executor = HookExecutor::new(hooks_config)?;
+
+    let result = {
+        let mut bridge =
+            CommandBridge::with_output_config(&mut output, executor, hook_output_config);
+        adopt_worktree::execute(&params, &git, &project_root, &git_dir, &mut bridge)?
+    };
+
+    render_result(&result, &mut output);
+    if let Some(ref cd_target) = result.cd_target {
+        output.cd_path(cd_target);
+    }
+    crate::catalog::touch_current_repo();
+
+    Ok(())
+}
+
+fn render_result(result: &adopt_worktree::AdoptWorktreeResult, output: &mut dyn Output) {
+    if let Some(ref from) = result.moved_from {
+        output.step(&format!(
+            "Moved worktree from '{}' to '{}'",
+            from.display(),
+            result.worktree_path.display()
+        ));
+    }
+    for filename in &result.files_propagated {
+        output.step(&format!("Copied {filename} from the current worktree"));
+    }
+    output.success(&format!(
+        "Adopted worktree '{}' for branch '{}'",
+        result.worktree_path.display(),
+        result.branch
+    ));
+    if result.off_layout() {
+        output.info(&format!(
+            "The layout places this branch at '{}'; run `{}` to move it there.",
+            result.canonical_path.display(),
+            crate::daft_cmd(&format!(
+                "adopt-worktree --move {}",
+                result.worktree_path.display()
+            ))
+        ));
+    }
+}
@@ -0,0 +1,153 @@
+//! Command: `daft auth` — log in to the forge daft's PR features talk to.
+
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+
+use crate::forge::ForgeConfig;
+use crate::forge::auth::{AuthTarget, Platform, normalize_host};
+use crate::git::GitCommand;
+use crate::output::{CliOutput, Output};
+use crate::styles::{bold, dim, green, yellow};
+
+#[derive(Parser)]
+#[command(name = "daft-auth")]
+#[command(version = crate::VERSION)]
+#[command(about = "Log in to GitHub or GitLab for daft's forge features")]
+#[command(long_about = r#"
+Manage the forge login that pull-request checkout (`daft go pr:123`), the
+`pr` column in `daft list`, and the merge check in `daft prune` use.
+
+daft talks to forges through the GitHub CLI (gh) and the GitLab CLI (glab)
+and stores no tokens itself. `daft auth login` runs that CLI's login for
+the right host, which keeps the token in your OS keychain; from then on
+every forge feature uses it, with no environment variables to export.
+
+Without a HOST, the host comes from daft.forge.hostname, then from the
+repository's remotes, then defaults to github.com. The platform is detected
+from the host; --platform (or daft.forge.platform) overrides it for
+self-hosted instances whose name doesn't say.
+
+A token in the environment still wins over the stored login, as it does
+for gh and glab themselves: GH_TOKEN or GITHUB_TOKEN for github.com,
+GH_ENTERPRISE_TOKEN for other GitHub hosts, GITLAB_TOKEN for GitLab.
+`daft auth status` says which one is in use.
+"#)]
+pub struct Args {
+    #[command(subcommand)]
+    command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+enum AuthCommand {
+    /// Log in to a forge host and store the token in the OS keychain
+    Login(LoginArgs),
+    /// Show whether a forge host is logged in and where its token comes from
+    Status(TargetArgs),
+    /// Remove the stored login for a forge host
+    Logout(TargetArgs),
+}
+
+#[derive(Parser)]
+struct LoginArgs {
+    #[command(flatten)]
+    target: TargetArgs,
+
+    /// Read the token from stdin instead of logging in interactively
+    #[arg(long)]
+    with_token: bool,
+}
+
+#[derive(Parser)]
+struct TargetArgs {
+    /// Forge host, e.g. github.com or gitlab.example.com
+    host: Option<String>,
+
+    /// Forge platform, when the host name doesn't say
+    #[arg(long, value_enum, value_name = "PLATFORM")]
+    platform: Option<Platform>,
+}
+
+pub fn run() -> Result<()> {
+    // Skip argv[0] so clap sees "auth" as the program name (same shape as
+    // `daft shared`).
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    match args.command {
+        AuthCommand::Login(login) => run_login(&login, &mut output),
+        AuthCommand::Status(target) => run_status(&target, &mut output),
+        AuthCommand::Logout(target) => run_logout(&target, &mut output),
+    }
+}
+
+fn resolve(args: &TargetArgs) -> Result<AuthTarget> {
+    let git = GitCommand::new(true);
+    let config = ForgeConfig::load(&git);
+    let host = args.host.as_deref().map(normalize_host).transpose()?;
+    AuthTarget::resolve(&git, &config, host.as_deref(), args.platform)
+}
+
+fn run_login(args: &LoginArgs, output: &mut dyn Output) -> Result<()> {
+    let target = resolve(&args.target)?;
+    if let Some(var) = target.token_env() {
+        output.warning(&format!(
+            "{var} is set; {} will keep using it instead of the stored login until it is unset",
+            target.tool
+        ));
+    }
+    output.info(&dim(&format!(
+        "Logging in to {} with {}",
+        target.host, target.tool
+    )));
+    let status = target.login(args.with_token)?;
+    if !status.success() {
+        bail!("{} auth login failed for {}", target.tool, target.host);
+    }
+    output.success(&format!("Logged in to {}", bold(&target.host)));
+    Ok(())
+}
+
+fn run_status(args: &TargetArgs, output: &mut dyn Output) -> Result<()> {
+    let target = resolve(args)?;
+    let (logged_in, report) = target.status()?;
+    let source = match target.token_env() {
+        Some(var) => format!("token from {var}"),
+        None => format!("stored by {}", target.tool),
+    };
+    let verdict = if logged_in {
+        green(&format!("logged in ({source})"))
+    } else {
+        yellow("not logged in")
+    };
+    output.info(&format!(
+        "{} {}: {verdict}",
+        bold(&target.host),
+        dim(&format!("({})", target.platform.label()))
+    ));
+    for line in report.lines() {
+        output.info(&dim(&format!("  {line}")));
+    }
+    if !logged_in {
+        bail!(
+            "not logged in to {}; run `{}`",
+            target.host,
+            crate::daft_cmd(&format!("auth login {}", target.host))
+        );
+    }
+    Ok(())
+}
+
+fn run_logout(args: &TargetArgs, output: &mut dyn Output) -> Result<()> {
+    let target = resolve(args)?;
+    let status = target.logout()?;
+    if !status.success() {
+        bail!("{} auth logout failed for {}", target.tool, target.host);
+    }
+    if let Some(var) = target.token_env() {
+        output.warning(&format!(
+            "{var} is still set; forge features keep using it until it is unset"
+        ));
+    }
+    Ok(())
+}
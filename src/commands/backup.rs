@@ -0,0 +1,340 @@
+//! Command: `daft backup` — copy a project's local-only work to a backup
+//! remote or bundle file, and bring it back.
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use clap::{Parser, Subcommand};
+
+use std::path::Path;
+
+use crate::auto_backup;
+use crate::core::backup::{self, Destination};
+use crate::hooks::schedule::Backend;
+use crate::output::format::shorthand_from_seconds;
+use crate::output::{CliOutput, Output};
+use crate::settings::{DaftSettings, keys};
+use crate::store::models::BackupRunRow;
+use crate::styles::{bold, dim, green, yellow};
+
+#[derive(Parser)]
+#[command(name = "daft-backup")]
+#[command(version = crate::VERSION)]
+#[command(about = "Back up local branches, stashes, and worktree changes")]
+#[command(long_about = r#"
+Copy the work that exists only on this machine to a backup remote or a
+bundle file, so a disk failure doesn't take unpushed commits with it.
+
+A backup holds every local branch, every stash entry, and a snapshot of the
+uncommitted changes (tracked files only) in each worktree, detached ones
+included. It is written under refs/daft-backup/ and never touches the
+destination's own branches; each run replaces the previous backup. A
+worktree whose changes can't be read (its index is locked, say) fails the
+run, so the previous backup is left as it was.
+
+DEST is the name of a remote of this repository, or a bundle path. A
+directory means <dir>/<project>.bundle. Without --to, daft.backup.to is
+used.
+
+With daft.backup.interval set (e.g. 1d), daft backs the project up in the
+background whenever you use daft in it and the interval has passed since
+the last successful backup. Set DAFT_NO_AUTO_BACKUP=1 to turn that off for
+a shell. `daft backup schedule install` also adds a user-level systemd
+timer (Linux) or launchd agent (macOS) that checks hourly, so the project
+is backed up even when daft isn't used in it; `daft backup schedule
+uninstall` removes it.
+
+`daft backup restore` fetches a backup back. Missing branches are created,
+branches the backup is ahead of are fast-forwarded, and diverged branches
+keep their local tip. Stash entries and worktree snapshots come back as
+stash entries. `daft backup status` shows the last run.
+"#)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<BackupCommand>,
+
+    /// Backup remote or bundle path (default: daft.backup.to)
+    #[arg(long, value_name = "DEST")]
+    to: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum BackupCommand {
+    /// Restore branches and stashes from a backup
+    Restore {
+        /// Backup remote or bundle path (default: daft.backup.to)
+        #[arg(long, value_name = "DEST")]
+        from: Option<String>,
+    },
+    /// Show the last backup and the schedule
+    Status,
+    /// Show, install, or remove the backup timer for this worktree
+    Schedule {
+        #[command(subcommand)]
+        command: Option<ScheduleCommand>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScheduleCommand {
+    /// Generate and enable a timer that backs up when the interval is due
+    Install {
+        /// Print the generated units without writing or enabling them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Disable and remove the backup timer
+    Uninstall,
+}
+
+pub fn run() -> Result<()> {
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+    let settings = DaftSettings::load()?;
+
+    match args.command {
+        None => run_backup(args.to, &settings, &mut output),
+        Some(BackupCommand::Restore { from }) => run_restore(from, &settings, &mut output),
+        Some(BackupCommand::Status) => run_status(&settings, &mut output),
+        Some(BackupCommand::Schedule { command }) => {
+            let worktree = crate::get_current_worktree_path()?;
+            match command {
+                None => run_schedule_status(&worktree, &mut output),
+                Some(ScheduleCommand::Install { dry_run }) => {
+                    run_schedule_install(&worktree, dry_run, &settings, &mut output)
+                }
+                Some(ScheduleCommand::Uninstall) => run_schedule_uninstall(&worktree, &mut output),
+            }
+        }
+    }
+}
+
+/// The destination given on the command line (as `flag`), else
+/// `daft.backup.to`.
+fn destination(spec: Option<String>, flag: &str, settings: &DaftSettings) -> Result<Destination> {
+    let (spec, key) = match (spec, &settings.backup_to) {
+        (Some(spec), _) => (spec, flag),
+        (None, Some(spec)) => (spec.clone(), keys::BACKUP_TO),
+        (None, None) => bail!(
+            "no backup destination; pass --to or set one with `git config daft.backup.to <remote|path>`"
+        ),
+    };
+    let cwd = std::env::current_dir()?;
+    let project = crate::core::repo::get_project_root()?
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .context("Could not name the project")?;
+    Destination::resolve(&spec, key, &cwd, &project)
+}
+
+fn run_backup(
+    spec: Option<String>,
+    settings: &DaftSettings,
+    output: &mut dyn Output,
+) -> Result<()> {
+    let destination = destination(spec, "--to", settings)?;
+    output.start_spinner(&format!("Backing up to {}...", destination.describe()));
+    let result = backup::run(&std::env::current_dir()?, &destination);
+    output.finish_spinner();
+    let snapshot = result?;
+    output.success(&format!(
+        "Backed up {} {}, {} {}, {} {} to {}",
+        snapshot.branches,
+        plural(snapshot.branches, "branch", "branches"),
+        snapshot.stashes,
+        plural(snapshot.stashes, "stash entry", "stash entries"),
+        snapshot.snapshots,
+        plural(
+            snapshot.snapshots,
+            "worktree snapshot",
+            "worktree snapshots"
+        ),
+        bold(&destination.describe())
+    ));
+    Ok(())
+}
+
+fn run_restore(
+    spec: Option<String>,
+    settings: &DaftSettings,
+    output: &mut dyn Output,
+) -> Result<()> {
+    let source = destination(spec, "--from", settings)?;
+    output.start_spinner(&format!("Restoring from {}...", source.describe()));
+    let result = backup::restore(&std::env::current_dir()?, &source);
+    output.finish_spinner();
+    let report = result?;
+
+    for branch in &report.created {
+        output.info(&format!("  {} {branch}", green("created")));
+    }
+    for branch in &report.fast_forwarded {
+        output.info(&format!("  {} {branch}", green("fast-forwarded")));
+    }
+    for branch in &report.kept {
+        output.info(&format!(
+            "  {} {branch} {}",
+            yellow("kept"),
+            dim("(local tip differs from the backup)")
+        ));
+    }
+    if report.created.is_empty() && report.fast_forwarded.is_empty() && report.stashes == 0 {
+        output.success("Nothing to restore; everything in the backup is already here");
+    } else {
+        let branches = report.created.len() + report.fast_forwarded.len();
+        output.success(&format!(
+            "Restored {branches} {} and {} {} from {}",
+            plural(branches, "branch", "branches"),
+            report.stashes,
+            plural(report.stashes, "stash entry", "stash entries"),
+            bold(&source.describe())
+        ));
+    }
+    Ok(())
+}
+
+fn run_status(settings: &DaftSettings, output: &mut dyn Output) -> Result<()> {
+    let (latest, latest_success) = backup::history(&std::env::current_dir()?)?;
+
+    match &settings.backup_to {
+        Some(to) => output.info(&format!("Destination: {}", bold(to))),
+        None => output.info(&format!("Destination: {}", dim("not set (daft.backup.to)"))),
+    }
+    match settings.backup_interval {
+        Some(interval) => output.info(&format!(
+            "Schedule:    every {}",
+            shorthand_from_seconds(interval.as_secs() as i64)
+        )),
+        None => output.info(&format!(
+            "Schedule:    {}",
+            dim("off (daft.backup.interval)")
+        )),
+    }
+
+    match &latest_success {
+        Some(run) => output.info(&format!(
+            "Last backup: {} to {} ({})",
+            green(&ago(run, settings)),
+            run.destination,
+            counts(run)
+        )),
+        None => output.info(&format!("Last backup: {}", dim("never"))),
+    }
+    if let Ok(worktree) = crate::get_current_worktree_path() {
+        run_schedule_status(&worktree, output)?;
+    }
+    if let Some(run) = latest.as_ref().filter(|run| run.error.is_some()) {
+        output.warning(&format!(
+            "Last attempt failed {}: {}",
+            ago(run, settings),
+            run.error.as_deref().unwrap_or_default()
+        ));
+    }
+    Ok(())
+}
+
+fn run_schedule_status(worktree: &Path, output: &mut dyn Output) -> Result<()> {
+    let backend = Backend::detect()?;
+    if backend
+        .installed(&auto_backup::timer_prefix(worktree))
+        .is_empty()
+    {
+        output.info(&format!(
+            "Timer:       {} {}",
+            yellow("not installed"),
+            dim(&format!(
+                "(backups run when daft is used; `{}` adds one)",
+                crate::daft_cmd("backup schedule install")
+            ))
+        ));
+    } else {
+        output.info(&format!(
+            "Timer:       {} {}",
+            green("installed"),
+            dim(&format!("(checks hourly, in {})", backend.dir().display()))
+        ));
+    }
+    Ok(())
+}
+
+fn run_schedule_install(
+    worktree: &Path,
+    dry_run: bool,
+    settings: &DaftSettings,
+    output: &mut dyn Output,
+) -> Result<()> {
+    if settings.backup_to.is_none() || settings.backup_interval.is_none() {
+        bail!(
+            "the timer backs up on daft.backup.interval to daft.backup.to; set both first (`git config daft.backup.interval 1d`)"
+        );
+    }
+    let backend = Backend::detect()?;
+    let daft = std::env::current_exe()
+        .context("Could not determine current executable")?
+        .canonicalize()
+        .context("Could not canonicalize executable path")?;
+    let timer = auto_backup::timer(worktree, daft, std::env::var("PATH").unwrap_or_default())?;
+
+    if dry_run {
+        for (path, contents) in backend.files(&timer)? {
+            output.info(&bold(&path.display().to_string()));
+            output.info(&contents);
+        }
+        return Ok(());
+    }
+
+    backend.write(std::slice::from_ref(&timer))?;
+    let manual = backend.activate(&[timer.unit_name()]);
+    output.success(&format!(
+        "Scheduled backups of {} {}",
+        bold(&worktree.display().to_string()),
+        dim("(checked hourly)")
+    ));
+    output.info(&dim(&format!(
+        "Units written to {}",
+        backend.dir().display()
+    )));
+    if let Some(command) = manual {
+        output.warning(&format!(
+            "Could not enable the timer; enable it with: {command}"
+        ));
+    }
+    Ok(())
+}
+
+fn run_schedule_uninstall(worktree: &Path, output: &mut dyn Output) -> Result<()> {
+    let backend = Backend::detect()?;
+    let installed = backend.installed(&auto_backup::timer_prefix(worktree));
+    if installed.is_empty() {
+        output.info(&dim("No backup timer installed for this worktree."));
+        return Ok(());
+    }
+    for name in &installed {
+        backend.remove(name)?;
+        output.success(&format!("Removed {}", bold(name)));
+    }
+    Ok(())
+}
+
+fn ago(run: &BackupRunRow, settings: &DaftSettings) -> String {
+    crate::output::time::ago(
+        run.finished_at.timestamp(),
+        Utc::now().timestamp(),
+        settings.ui_time_format,
+    )
+}
+
+fn counts(run: &BackupRunRow) -> String {
+    let count = |n: i64, one: &str, many: &str| format!("{n} {}", plural(n as usize, one, many));
+    [
+        count(run.branches, "branch", "branches"),
+        count(run.stashes, "stash entry", "stash entries"),
+        count(run.snapshots, "worktree snapshot", "worktree snapshots"),
+    ]
+    .join(", ")
+}
+
+fn plural<'a>(n: usize, one: &'a str, many: &'a str) -> &'a str {
+    if n == 1 { one } else { many }
+}
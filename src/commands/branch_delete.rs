@@ -87,10 +87,14 @@ pub struct Args {
 }
 
 pub fn run() -> Result<()> {
-    eprintln!(
-        "warning: 'git worktree-branch-delete' is deprecated, use 'git worktree-branch -d/-D' instead."
+    crate::deprecations::record(
+        crate::deprecations::DeprecationKind::Command,
+        "git-worktree-branch-delete",
+        "'git worktree-branch-delete' is deprecated, use 'git worktree-branch -d/-D' instead.",
     );
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-branch-delete"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-branch-delete",
+    ));
     init_logging(args.verbose);
 
     if !is_git_repository()? {
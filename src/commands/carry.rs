@@ -28,16 +28,32 @@ Targets may be specified by worktree directory name or by branch name. If
 both a worktree and a branch have the same name, the worktree takes
 precedence.
 
+Use --to to name a single destination explicitly; it always moves.
+
+A target's own uncommitted changes are left in place, but if they touch any
+of the files being carried, the carry is refused before anything is stashed.
+Staged changes arrive staged; when the target's index cannot take them, they
+arrive unstaged and a warning says so. If a move fails to apply, the changes
+are put back in the source worktree.
+
 After transferring changes, the working directory is changed to the last
 target worktree (or the only target, if just one was specified).
 "#)]
 pub struct Args {
     #[arg(
-        required = true,
+        required_unless_present = "to",
         help = "Target worktree(s) by directory name or branch name"
     )]
     targets: Vec<String>,
 
+    #[arg(
+        long = "to",
+        value_name = "BRANCH",
+        conflicts_with_all = ["targets", "copy"],
+        help = "Move changes into the worktree of this branch (or directory name)"
+    )]
+    to: Option<String>,
+
     #[arg(
         short = 'c',
         long = "copy",
@@ -50,7 +66,9 @@ pub struct Args {
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-carry"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-carry",
+    ));
 
     init_logging(args.verbose);
 
@@ -70,7 +88,7 @@ pub fn run() -> Result<()> {
     let project_root = get_project_root()?;
 
     let params = carry::CarryParams {
-        targets: args.targets,
+        targets: args.to.map_or(args.targets, |to| vec![to]),
         copy: args.copy,
     };
 
@@ -110,6 +128,12 @@ fn render_carry_result(result: &carry::CarryResult, output: &mut dyn Output) {
         return;
     }
 
+    for name in &result.unstaged_targets {
+        output.warning(&format!(
+            "Staged changes could not be restored to the index in '{name}'; they arrived unstaged"
+        ));
+    }
+
     if result.failures.is_empty() {
         if result.copy_mode {
             if result.successes.len() == 1 {
@@ -137,7 +161,12 @@ fn render_carry_result(result: &carry::CarryResult, output: &mut dyn Output) {
         for failure in &result.failures {
             output.error(&format!("  {}: {}", failure.name, failure.error));
         }
-        if result.stash_preserved {
+        if result.restored_to_source {
+            output.warning(&format!(
+                "Changes restored to the source worktree. Still in {}",
+                result.cd_target.display()
+            ));
+        } else if result.stash_preserved {
             output.warning(&format!(
                 "Stash preserved for recovery. Now in {}",
                 result.cd_target.display()
@@ -7,7 +7,7 @@ use crate::{
             BuiltinLayout, Layout,
             resolver::{LayoutResolutionContext, LayoutSource, resolve_layout},
         },
-        worktree::{checkout, checkout_branch, previous},
+        worktree::{checkout, checkout_branch, path_conflict::OnConflict, previous},
     },
     get_current_worktree_path, get_git_common_dir, get_project_root,
     git::GitCommand,
@@ -136,6 +136,14 @@ pub struct Args {
     #[arg(short = '@', long, value_name = "PATH")]
     at: Option<PathBuf>,
 
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        help = "When the worktree path is taken by a non-worktree: adopt it, use a suffixed path, or fail"
+    )]
+    on_conflict: Option<OnConflict>,
+
     #[arg(long, help = "Skip all remote operations (no fetch, no push)")]
     local: bool,
 
@@ -271,6 +279,14 @@ pub struct GoArgs {
     #[arg(short = '@', long, value_name = "PATH")]
     at: Option<PathBuf>,
 
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        help = "When the worktree path is taken by a non-worktree: adopt it, use a suffixed path, or fail"
+    )]
+    on_conflict: Option<OnConflict>,
+
     #[arg(long, help = "Skip all remote operations (no fetch, no push)")]
     local: bool,
 
@@ -326,6 +342,13 @@ target repo's hooks run only if it is trusted, and the shell lands in the new
 worktree there. Carry (`-c`) cannot cross repositories; `-x` runs in the
 target worktree.
 
+The new branch name must be a valid git ref name; a bad name is rejected
+before anything is fetched or created. The derived worktree path is shown
+before it is created; when the layout's path template places it somewhere
+that does not end in the branch name (or its `/`-to-`-` form, alone or after
+`<repo>.`), confirmation is asked for first. Non-interactive runs and an
+explicit --at skip the question.
+
 With --with-related, the same branch is also created in every repo the
 primary repo's daft.yml `relations:` manifest points at — the entry point
 for a coordinated cross-repo change (pair with `daft exec --related`). The
@@ -411,6 +434,14 @@ pub struct StartArgs {
     #[arg(short = '@', long, value_name = "PATH")]
     at: Option<PathBuf>,
 
+    #[arg(
+        long,
+        value_enum,
+        value_name = "MODE",
+        help = "When the worktree path is taken by a non-worktree: adopt it, use a suffixed path, or fail"
+    )]
+    on_conflict: Option<OnConflict>,
+
     #[arg(long, help = "Skip all remote operations (no fetch, no push)")]
     local: bool,
 
@@ -449,6 +480,7 @@ impl StartArgs {
             quiet: self.quiet,
             verbose: self.verbose,
             at: self.at.clone(),
+            on_conflict: self.on_conflict,
             local: self.local,
             no_verify: self.no_verify,
             skip_hooks: self.skip_hooks.clone(),
@@ -458,13 +490,15 @@ impl StartArgs {
 
 /// Entry point for `git-worktree-checkout`.
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-checkout"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-checkout",
+    ));
     run_with_args(args, GoRouting::local_only())
 }
 
 /// Entry point for `daft go`.
 pub fn run_go() -> Result<()> {
-    let mut raw = crate::get_clap_args("daft-go");
+    let mut raw = crate::get_clap_args_with_defaults::<GoArgs>("daft-go");
     raw[0] = "daft go".to_string();
     let go_args = GoArgs::parse_from(raw);
 
@@ -487,6 +521,7 @@ pub fn run_go() -> Result<()> {
         quiet: go_args.quiet,
         verbose: go_args.verbose,
         at: go_args.at,
+        on_conflict: go_args.on_conflict,
         local: go_args.local,
         no_verify: go_args.no_verify,
         skip_hooks: go_args.skip_hooks,
@@ -609,7 +644,7 @@ fn decode_go_grammar(
 
 /// Entry point for `daft start`.
 pub fn run_start() -> Result<()> {
-    let mut raw = crate::get_clap_args("daft-start");
+    let mut raw = crate::get_clap_args_with_defaults::<StartArgs>("daft-start");
     raw[0] = "daft start".to_string();
     let start_args = StartArgs::parse_from(raw);
 
@@ -1223,6 +1258,10 @@ fn run_in_repo(
     let config = OutputConfig::with_autocd(args.quiet, args.verbose, autocd);
     let mut output = CliOutput::new(config);
 
+    // `links:` — the matching branch exists in every linked repo before the
+    // worktree here is created, so its post-create hooks can use it.
+    provision_links(&args, catalog_fallback, &settings, &git, &mut output);
+
     let result = if args.create_branch {
         run_create_branch(&args, &settings, &git, &mut output)
     } else {
@@ -1279,7 +1318,7 @@ fn run_in_repo(
                         );
                     }
                     render_branch_not_found_error(branch, remote, fetch_failed, &settings);
-                    std::process::exit(1);
+                    crate::deprecations::exit(1);
                 }
             }
             Err(checkout::CheckoutError::Other(e)) => Err(e),
@@ -1341,7 +1380,7 @@ fn run_go_previous(output: &mut dyn Output) -> Result<()> {
 /// Loads the layout from the config chain: repo store > daft.yml > global config > detection > default.
 /// Also checks if the resolved layout requires a bare repo and warns if the current repo
 /// is not bare.
-fn resolve_checkout_layout(
+pub(crate) fn resolve_checkout_layout(
     git: &GitCommand,
     output: &mut dyn Output,
 ) -> (crate::core::layout::Layout, LayoutSource) {
@@ -1628,6 +1667,40 @@ fn run_checkout(
     git: &GitCommand,
     output: &mut dyn Output,
 ) -> Result<bool, checkout::CheckoutError> {
+    let result = run_checkout_core(args, settings, git, output)?;
+
+    // Run exec commands (after hooks, before cd_path)
+    let exec_result = crate::exec::run_exec_commands(&args.exec, output);
+
+    output.cd_path(&result.cd_target);
+    maybe_show_shell_hint(output)?;
+
+    // Propagate exec error after cd_path is written
+    exec_result?;
+
+    Ok(result.already_existed)
+}
+
+/// Open `branch` in a worktree, creating one when it has none, for `daft
+/// which --create`: the checkout machinery without the terminal tail.
+/// Returns the worktree's path.
+pub(crate) fn provision_worktree(branch: &str, output: &mut dyn Output) -> Result<PathBuf> {
+    let args = Args::parse_from(["git-worktree-checkout", branch]);
+    let git = GitCommand::new(output.is_quiet());
+    let settings = DaftSettings::load_with(&git)?;
+    let git = git.with_gitoxide(settings.use_gitoxide);
+    let result = run_checkout_core(&args, &settings, &git, output)?;
+    Ok(result.worktree_path)
+}
+
+/// The checkout machinery without the terminal tail (exec commands, cd
+/// redirect, shell hint) — reusable per-repo by `links:` provisioning.
+fn run_checkout_core(
+    args: &Args,
+    settings: &DaftSettings,
+    git: &GitCommand,
+    output: &mut dyn Output,
+) -> Result<checkout::CheckoutResult, checkout::CheckoutError> {
     let wt_config = WorktreeConfig {
         remote_name: settings.remote.clone(),
         quiet: output.is_quiet(),
@@ -1679,6 +1752,7 @@ fn run_checkout(
         },
         layout: Some(layout),
         at_path: args.at.clone(),
+        on_conflict: args.on_conflict,
         // The morph (branch missing → run_create_branch) must leave no rail
         // behind: hold the plan until the branch is known to exist, so the
         // fetch runs under the planning face and a not-found dissolves the
@@ -1734,16 +1808,7 @@ fn run_checkout(
         render_checkout_result(&result, output);
     }
 
-    // Run exec commands (after hooks, before cd_path)
-    let exec_result = crate::exec::run_exec_commands(&args.exec, output);
-
-    output.cd_path(&result.cd_target);
-    maybe_show_shell_hint(output)?;
-
-    // Propagate exec error after cd_path is written
-    exec_result?;
-
-    Ok(result.already_existed)
+    Ok(result)
 }
 
 fn run_create_branch(
@@ -1836,6 +1901,7 @@ fn run_create_branch_core(
         },
         layout: Some(layout),
         at_path: args.at.clone(),
+        on_conflict: args.on_conflict,
     };
 
     let hooks_config = crate::core::settings::load_hooks_config_with(git)?;
@@ -2059,26 +2125,172 @@ fn create_branch_in_related_repo(
         repo_args.no_carry = true;
         repo_args.exec = Vec::new();
 
-        let git_dir = get_git_common_dir()?;
-        let trusted = TrustDatabase::load()
-            .map(|db| db.get_trust_level(&git_dir) == crate::hooks::TrustLevel::Allow)
-            .unwrap_or(false);
-        if !trusted && !repo_args.skip_hooks.iter().any(|s| s == "all") {
-            repo_args.skip_hooks.push("all".to_string());
-            output.notice(&format!(
-                "hooks skipped in '{}' (repo not trusted; run `{}` there)",
-                row.name,
-                crate::daft_cmd("hooks trust")
+        skip_hooks_unless_trusted(&row.name, &mut repo_args, output)?;
+
+        run_create_branch_core(&repo_args, &settings, &git, output).map(|_| ())
+    })();
+
+    change_directory(&restore).ok();
+    result
+}
+
+/// Provision `args.branch_name` in every repo the current worktree's
+/// `links:` point at, when this invocation is about to create a worktree
+/// for it here and the repo is trusted. Best-effort: failures are warnings,
+/// and a link that can't be provisioned falls back to the linked repo's
+/// default-branch worktree.
+fn provision_links(
+    args: &Args,
+    catalog_fallback: bool,
+    settings: &DaftSettings,
+    git: &GitCommand,
+    output: &mut dyn Output,
+) {
+    let Ok(worktree) = get_current_worktree_path() else {
+        return;
+    };
+    let links = crate::catalog::links::links_at(&worktree);
+    if links.is_empty() || !creates_worktree_here(args, catalog_fallback, settings, git) {
+        return;
+    }
+    // Provisioning creates worktrees in other repositories on this repo's
+    // say-so, so it needs the same standing consent hooks do.
+    if !crate::catalog::links::links_trusted(&worktree) {
+        output.notice(&format!(
+            "links not provisioned: repository not trusted (run `{}` to allow them)",
+            crate::daft_cmd("hooks trust")
+        ));
+        return;
+    }
+    let branch = &args.branch_name;
+    for link in crate::catalog::links::resolve_against_catalog(&links) {
+        let label = link.entry.label();
+        let Some(row) = &link.repo else {
+            output.warning(&format!(
+                "link '{label}' skipped: not cloned locally (tip: `{}`)",
+                crate::daft_cmd(&format!("clone {}", link.entry.url))
             ));
+            continue;
+        };
+        if !link.entry.creates() || crate::catalog::links::matching_worktree(row, branch).is_some()
+        {
+            continue;
         }
+        output.result(&format!(
+            "Provisioning '{branch}' in linked repo '{}'…",
+            row.name
+        ));
+        if let Err(e) = open_branch_in_linked_repo(row, args, output) {
+            output.warning(&format!("link '{label}': {e:#}"));
+        }
+    }
+}
 
-        run_create_branch_core(&repo_args, &settings, &git, output).map(|_| ())
+/// Whether `args` will create a new worktree in the current repo — the only
+/// time links are provisioned. Navigating to an existing worktree, opening a
+/// PR/MR, or a `daft go <repo>` catalog hop never provisions.
+fn creates_worktree_here(
+    args: &Args,
+    catalog_fallback: bool,
+    settings: &DaftSettings,
+    git: &GitCommand,
+) -> bool {
+    let branch = &args.branch_name;
+    if crate::forge::ForgeTarget::parse(branch).is_some() {
+        return false;
+    }
+    let has_worktree = git.worktree_list_porcelain().is_ok_and(|porcelain| {
+        crate::core::worktree::porcelain::parse_worktree_list_porcelain(&porcelain)
+            .iter()
+            .any(|e| e.branch.as_deref() == Some(branch.as_str()))
+    });
+    if has_worktree {
+        return false;
+    }
+    if args.create_branch || args.start {
+        return true;
+    }
+    let remote = args.remote.as_deref().unwrap_or(&settings.remote);
+    let branch_known = [
+        format!("refs/heads/{branch}"),
+        format!("refs/remotes/{remote}/{branch}"),
+    ]
+    .iter()
+    .any(|r| git.show_ref_exists(r).unwrap_or(false));
+    branch_known
+        || (settings.go_auto_start && !(catalog_fallback && lookup_live_repo(branch).is_some()))
+}
+
+/// Open `args.branch_name` in a linked repo: check the branch out when the
+/// repo has it (locally or on its remote), else create it from that repo's
+/// own default branch. Never carry, never run `-x`, and run hooks only when
+/// the repo is explicitly trusted.
+fn open_branch_in_linked_repo(
+    row: &crate::store::CatalogRepoRow,
+    args: &Args,
+    output: &mut dyn Output,
+) -> Result<()> {
+    let repo_root = std::path::Path::new(&row.path);
+    let Some(worktree) = crate::core::repo::find_representative_worktree(repo_root) else {
+        anyhow::bail!("'{}' has no worktrees to open the branch from", row.name);
+    };
+    let restore = get_current_directory()?;
+    change_directory(&worktree)?;
+
+    let result = (|| {
+        let git = GitCommand::new(args.quiet);
+        let settings = DaftSettings::load_with(&git)?;
+        let git = git.with_gitoxide(settings.use_gitoxide);
+
+        let mut repo_args = args.clone();
+        repo_args.create_branch = false;
+        repo_args.start = true;
+        repo_args.base_branch_name = None;
+        repo_args.remote = None;
+        repo_args.at = None;
+        repo_args.carry = false;
+        repo_args.no_carry = true;
+        repo_args.exec = Vec::new();
+        skip_hooks_unless_trusted(&row.name, &mut repo_args, output)?;
+
+        match run_checkout_core(&repo_args, &settings, &git, output) {
+            Ok(_) => Ok(()),
+            Err(checkout::CheckoutError::BranchNotFound { .. }) => {
+                change_directory(&worktree)?;
+                repo_args.create_branch = true;
+                repo_args.base_branch_name = crate::catalog::effective_default_branch(row);
+                run_create_branch_core(&repo_args, &settings, &git, output).map(|_| ())
+            }
+            Err(checkout::CheckoutError::Other(e)) => Err(e),
+        }
     })();
 
     change_directory(&restore).ok();
     result
 }
 
+/// Fan-outs into other repos must not block on interactive trust prompts:
+/// unless the repo at the cwd is explicitly trusted (`Allow`), skip its
+/// hooks and say so.
+fn skip_hooks_unless_trusted(
+    repo_name: &str,
+    repo_args: &mut Args,
+    output: &mut dyn Output,
+) -> Result<()> {
+    let git_dir = get_git_common_dir()?;
+    let trusted = TrustDatabase::load()
+        .map(|db| db.get_trust_level(&git_dir) == crate::hooks::TrustLevel::Allow)
+        .unwrap_or(false);
+    if !trusted && !repo_args.skip_hooks.iter().any(|s| s == "all") {
+        repo_args.skip_hooks.push("all".to_string());
+        output.notice(&format!(
+            "hooks skipped in '{repo_name}' (repo not trusted; run `{}` there)",
+            crate::daft_cmd("hooks trust")
+        ));
+    }
+    Ok(())
+}
+
 fn render_branch_not_found_error(
     branch: &str,
     remote: &str,
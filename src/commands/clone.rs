@@ -21,8 +21,8 @@ use crate::{
     executor::cli_presenter::CliPresenter,
     git::GitCommand,
     hints::{
-        LayoutPromptResult, layout_prompt_applicable, maybe_prompt_layout_choice,
-        maybe_show_shell_hint,
+        LayoutPromptResult, NextStep, layout_prompt_applicable, maybe_prompt_layout_choice,
+        maybe_show_shell_hint, show_next_steps,
     },
     hooks::{
         HookContext, HookExecutor, HookType, TrustDatabase, TrustLevel, get_remote_url_for_git_dir,
@@ -60,6 +60,11 @@ master, or other configured default), then performs a bare clone and creates
 the initial worktree. This structure allows multiple worktrees to be created
 as siblings, each containing a different branch.
 
+The directory is named after the repository. Set daft.clone.nameTemplate to
+name it after the owner or host as well, e.g. '{org}-{repo}' or
+'{host}/{org}/{repo}', so same-named repositories from different owners don't
+collide.
+
 If the repository contains a .daft/hooks/ directory and the repository is
 trusted, lifecycle hooks are executed. See git-daft(1) for hook management.
 "#)]
@@ -168,7 +173,9 @@ pub struct Args {
 }
 
 pub fn run() -> Result<()> {
-    let mut args = Args::parse_from(crate::get_clap_args("git-worktree-clone"));
+    let mut args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-clone",
+    ));
 
     init_logging(args.verbose >= 2);
 
@@ -315,6 +322,8 @@ fn run_clone(args: &Args, settings: &DaftSettings, output: &mut dyn Output) -> R
         multi_remote_default: settings.multi_remote_default.clone(),
         checkout_upstream: settings.checkout_upstream,
         use_gitoxide: settings.use_gitoxide,
+        name_template: settings.clone_name_template.clone(),
+        nested_projects: settings.nested_projects,
     };
 
     // Plan-execute rail timeline (#651): the rail opens the moment the
@@ -324,7 +333,7 @@ fn run_clone(args: &Args, settings: &DaftSettings, output: &mut dyn Output) -> R
     // resolve span (bare clone, layout, branch resolution) has the facts;
     // until then the planning face carries the liveness, and the bare phase
     // later appears on the rail as a pre-completed row.
-    let repo_name = crate::extract_repo_name(&args.repository_url)?;
+    let repo_name = crate::core::repo::extract_clone_repo_name(&args.repository_url)?;
     let mut timeline = Timeline::new(
         TimelineMode::auto(output.is_quiet()),
         output.is_verbose(),
@@ -388,7 +397,7 @@ fn run_clone(args: &Args, settings: &DaftSettings, output: &mut dyn Output) -> R
             LayoutPromptResult::Cancelled => {
                 // Clean up: we already cloned, so delete it
                 change_directory(&original_dir).ok();
-                remove_directory(&bare_result.parent_dir).ok();
+                remove_directory(&bare_result.created_dir).ok();
                 return Ok(());
             }
         }
@@ -411,7 +420,7 @@ fn run_clone(args: &Args, settings: &DaftSettings, output: &mut dyn Output) -> R
         // clone leaves no orphan directory behind.
         timeline.abandon_planning();
         change_directory(&original_dir).ok();
-        remove_directory(&bare_result.parent_dir).ok();
+        remove_directory(&bare_result.created_dir).ok();
         return Err(e);
     }
 
@@ -818,6 +827,7 @@ fn run_clone(args: &Args, settings: &DaftSettings, output: &mut dyn Output) -> R
             output.cd_path(cd_target);
         }
         maybe_show_shell_hint(output)?;
+        show_next_steps(NextStep::Cloned, settings.ui_hints, output);
 
         exec_result?;
     } else if result.branch_not_found {
@@ -837,6 +847,7 @@ fn run_clone(args: &Args, settings: &DaftSettings, output: &mut dyn Output) -> R
         // the bare .git) so they can immediately operate on the new repo.
         output.cd_path(&result.parent_dir);
         maybe_show_shell_hint(output)?;
+        show_next_steps(NextStep::Cloned, settings.ui_hints, output);
     }
 
     Ok(())
@@ -1539,6 +1550,7 @@ fn create_satellite_worktrees_tui(
             // `stream_post_setup_info`). Nothing is finalized at seed time.
             seeded_fields: FieldSet::EMPTY,
             forge_prs: None,
+            time_format: settings.ui_time_format,
         },
         None,
     );
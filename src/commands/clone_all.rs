@@ -0,0 +1,408 @@
+//! Command: `daft clone-all` — clone every missing repo from a manifest or a
+//! forge org.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+use anyhow::{Context, Result, bail};
+use clap::Parser;
+
+use crate::core::clone_manifest::{self, ManifestEntry, PlanAction, PlannedClone};
+use crate::executor::cli_presenter::CliPresenter;
+use crate::executor::presenter::JobPresenter;
+use crate::forge::ForgeConfig;
+use crate::forge::auth::{AuthTarget, Platform, normalize_host};
+use crate::git::GitCommand;
+use crate::output::term_guard::EchoCtlGuard;
+use crate::output::{CliOutput, Output};
+use crate::settings::{DaftSettings, HookOutputConfig};
+use crate::styles::{bold, dim};
+
+/// Output lines kept per clone, printed after the dashboard when it fails.
+const FAILURE_TAIL_LINES: usize = 20;
+
+#[derive(Parser)]
+#[command(name = "daft-clone-all")]
+#[command(version = crate::VERSION)]
+#[command(about = "Clone every repository from a manifest or forge org that isn't here yet")]
+#[command(long_about = r#"
+Clone a set of repositories into the current directory in one command —
+the way to set up a new machine.
+
+The set comes from a manifest, a YAML file listing remote URLs (a plain
+list, or a `repos:` list whose entries may also set `layout` and `branch`):
+
+    repos:
+      - git@github.com:acme/api.git
+      - url: git@github.com:acme/web.git
+        layout: sibling
+        branch: develop
+
+or, with --org, from every repository an organization (GitHub) or group
+(GitLab) owns, listed through gh or glab with your existing login. Archived
+repositories are left out unless --include-archived is given. Org listings
+clone over SSH unless --https is given or the CLI's git_protocol is https.
+
+Each repository lands where `daft clone` would put it — named by
+daft.clone.nameTemplate and laid out by the usual layout resolution — and is
+skipped when that directory already exists, so running it again only fetches
+what is new. Clones run in parallel (--jobs) with a live progress row each;
+--dry-run prints the plan without cloning.
+"#)]
+pub struct Args {
+    #[arg(
+        value_name = "MANIFEST",
+        required_unless_present = "org",
+        conflicts_with = "org",
+        help = "YAML manifest of repository URLs"
+    )]
+    manifest: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "OWNER",
+        help = "Clone the repositories of this org, group, or user"
+    )]
+    org: Option<String>,
+
+    #[arg(
+        long,
+        value_enum,
+        value_name = "PLATFORM",
+        requires = "org",
+        help = "Forge platform, when the host name doesn't say"
+    )]
+    platform: Option<Platform>,
+
+    #[arg(
+        long,
+        value_name = "HOST",
+        requires = "org",
+        help = "Forge host (default: daft.forge.hostname, then github.com)"
+    )]
+    host: Option<String>,
+
+    #[arg(
+        long,
+        requires = "org",
+        help = "Clone org repositories over HTTPS instead of SSH"
+    )]
+    https: bool,
+
+    #[arg(long, requires = "org", help = "Include archived org repositories")]
+    include_archived: bool,
+
+    #[arg(
+        long,
+        value_name = "LAYOUT",
+        help = "Layout for every clone that doesn't set one in the manifest"
+    )]
+    layout: Option<String>,
+
+    #[arg(
+        short = 'j',
+        long,
+        value_name = "N",
+        default_value_t = 4,
+        value_parser = clap::value_parser!(u16).range(1..),
+        help = "Number of clones to run at once"
+    )]
+    jobs: u16,
+
+    #[arg(long, help = "Show what would be cloned and skipped, then exit")]
+    dry_run: bool,
+}
+
+/// How one clone ended.
+struct CloneOutcome {
+    dir: PathBuf,
+    success: bool,
+    tail: VecDeque<String>,
+}
+
+pub fn run() -> Result<()> {
+    // Skip argv[0] so clap sees "clone-all" as the program name (same shape
+    // as `daft shared`).
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+    run_clone_all(&args, &mut output)
+}
+
+fn run_clone_all(args: &Args, output: &mut dyn Output) -> Result<()> {
+    let root = std::env::current_dir()?;
+    let entries = match (&args.manifest, &args.org) {
+        (Some(path), _) => clone_manifest::load(path)?,
+        (None, Some(owner)) => org_entries(args, owner, &root, output)?,
+        (None, None) => unreachable!("clap requires MANIFEST or --org"),
+    };
+    if entries.is_empty() {
+        output.info("Nothing to clone: the list of repositories is empty");
+        return Ok(());
+    }
+
+    let template = DaftSettings::load_global()?.clone_name_template;
+    let planned = clone_manifest::plan(entries, &root, &template);
+    report_skips(&planned, output);
+
+    let to_clone: Vec<&PlannedClone> = planned
+        .iter()
+        .filter(|p| p.action == PlanAction::Clone)
+        .collect();
+    if to_clone.is_empty() {
+        output.success("All repositories are already present");
+        return Ok(());
+    }
+    if args.dry_run {
+        for p in &to_clone {
+            output.info(&format!(
+                "{} {}  {}",
+                dim("would clone"),
+                bold(&p.dir.display().to_string()),
+                dim(&crate::core::repo::display_url(&p.entry.url))
+            ));
+        }
+        return Ok(());
+    }
+
+    let outcomes = clone_in_parallel(&to_clone, args, &root)?;
+    let failed: Vec<&CloneOutcome> = outcomes.iter().filter(|o| !o.success).collect();
+    for outcome in &failed {
+        output.info("");
+        output.error(&format!("Clone into {} failed:", outcome.dir.display()));
+        for line in &outcome.tail {
+            output.info(&format!("  {line}"));
+        }
+    }
+
+    let skipped = planned.len() - to_clone.len();
+    let cloned = outcomes.len() - failed.len();
+    let summary = format!(
+        "{cloned} cloned, {skipped} skipped, {} failed",
+        failed.len()
+    );
+    if !failed.is_empty() {
+        bail!("{summary}");
+    }
+    output.success(&summary);
+    Ok(())
+}
+
+/// Manifest entries for every repository `owner` has on the forge.
+fn org_entries(
+    args: &Args,
+    owner: &str,
+    root: &Path,
+    output: &mut dyn Output,
+) -> Result<Vec<ManifestEntry>> {
+    let git = GitCommand::new(true);
+    let config = ForgeConfig::load(&git);
+    let host = args.host.as_deref().map(normalize_host).transpose()?;
+    let target = AuthTarget::resolve(&git, &config, host.as_deref(), args.platform)?;
+    let https = args.https
+        || crate::forge::cli::config_value(&target.tool, "git_protocol")
+            .is_some_and(|p| p == "https");
+
+    output.start_spinner(&format!(
+        "Listing repositories of {owner} on {}...",
+        target.host
+    ));
+    let repos = crate::forge::org::list(&target, owner, root);
+    output.finish_spinner();
+    let repos = repos?;
+
+    let archived = repos.iter().filter(|r| r.archived).count();
+    if archived > 0 && !args.include_archived {
+        output.info(&dim(&format!(
+            "Leaving out {archived} archived repositories (--include-archived to clone them)"
+        )));
+    }
+    Ok(repos
+        .iter()
+        .filter(|r| args.include_archived || !r.archived)
+        .map(|r| ManifestEntry::from_url(r.clone_url(https)))
+        .collect())
+}
+
+fn report_skips(planned: &[PlannedClone], output: &mut dyn Output) {
+    for p in planned {
+        let dir = p.dir.display().to_string();
+        let url = crate::core::repo::display_url(&p.entry.url);
+        match &p.action {
+            PlanAction::Clone => {}
+            PlanAction::Present => {
+                output.info(&format!("{} {dir} {}", dim("present"), dim("(skipped)")));
+            }
+            PlanAction::Duplicate => output.warning(&format!(
+                "Skipping {url}: an earlier entry already clones into {dir}"
+            )),
+            PlanAction::Invalid(reason) => {
+                output.warning(&format!("Skipping {url}: {reason}"));
+            }
+        }
+    }
+}
+
+/// Run the clones `jobs` at a time, one dashboard row per destination.
+/// Outcomes come back in plan order.
+fn clone_in_parallel(
+    planned: &[&PlannedClone],
+    args: &Args,
+    root: &Path,
+) -> Result<Vec<CloneOutcome>> {
+    // Each clone is a `daft clone` child, so the layout, name template, and
+    // hooks behave exactly as they do for a single clone. Canonicalized so a
+    // multicall symlink (`git-worktree-clone`) still resolves to daft.
+    let exe = std::env::current_exe()
+        .and_then(|p| p.canonicalize())
+        .context("could not locate the daft executable")?;
+
+    let _echoctl_guard = EchoCtlGuard::new();
+    let presenter_concrete = CliPresenter::auto(&HookOutputConfig {
+        compact_finalization: true,
+        ..HookOutputConfig::default()
+    });
+    let widest = planned
+        .iter()
+        .map(|p| p.dir.display().to_string().len())
+        .max()
+        .unwrap_or(crate::output::hook_progress::DEFAULT_NAME_COLUMN_WIDTH);
+    presenter_concrete.set_name_column_width(widest);
+    let presenter: Arc<dyn JobPresenter> = presenter_concrete;
+
+    let queue = Mutex::new(planned.iter().enumerate().collect::<VecDeque<_>>());
+    let results = Mutex::new(Vec::with_capacity(planned.len()));
+    let workers = usize::from(args.jobs).min(planned.len());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Some((index, p)) = queue.lock().ok().and_then(|mut q| q.pop_front()) {
+                    let outcome = clone_one(&exe, p, args.layout.as_deref(), root, &presenter);
+                    if let Ok(mut results) = results.lock() {
+                        results.push((index, outcome));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap_or_default();
+    results.sort_by_key(|(index, _)| *index);
+    Ok(results.into_iter().map(|(_, outcome)| outcome).collect())
+}
+
+fn clone_one(
+    exe: &Path,
+    planned: &PlannedClone,
+    default_layout: Option<&str>,
+    root: &Path,
+    presenter: &Arc<dyn JobPresenter>,
+) -> CloneOutcome {
+    let name = planned.dir.display().to_string();
+    let entry = &planned.entry;
+    let mut cmd = Command::new(exe);
+    cmd.arg("clone").arg("--no-cd");
+    if let Some(layout) = entry.layout.as_deref().or(default_layout) {
+        cmd.args(["--layout", layout]);
+    }
+    if let Some(branch) = &entry.branch {
+        cmd.args(["--branch", branch]);
+    }
+    // `--` keeps a manifest URL starting with '-' from being read as an option.
+    cmd.arg("--")
+        .arg(&entry.url)
+        .current_dir(root)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // The children report through the dashboard: no cd-file handoff, no
+        // per-clone hints or update notices, no prompts.
+        .env_remove(crate::CD_FILE_ENV)
+        .env(crate::hints::NO_HINTS_ENV, "1")
+        .env(crate::update_check::NO_UPDATE_CHECK_ENV, "1");
+
+    presenter.on_job_start(
+        &name,
+        None,
+        Some(&format!(
+            "daft clone {}",
+            crate::core::repo::display_url(&entry.url)
+        )),
+    );
+    let started = Instant::now();
+    let tail = Arc::new(Mutex::new(VecDeque::new()));
+    let status = cmd.spawn().and_then(|mut child| {
+        let readers: Vec<_> = [
+            child
+                .stdout
+                .take()
+                .map(|r| Box::new(r) as Box<dyn std::io::Read + Send>),
+            child
+                .stderr
+                .take()
+                .map(|r| Box::new(r) as Box<dyn std::io::Read + Send>),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|reader| stream_lines(reader, &name, presenter, &tail))
+        .collect();
+        let status = child.wait();
+        for reader in readers {
+            let _ = reader.join();
+        }
+        status
+    });
+    let elapsed = started.elapsed();
+    let mut tail = std::mem::take(&mut *tail.lock().unwrap_or_else(|e| e.into_inner()));
+
+    let success = match status {
+        Ok(status) if status.success() => {
+            presenter.on_job_success(&name, elapsed);
+            true
+        }
+        Ok(status) => {
+            presenter.on_job_failure_with_exit(&name, elapsed, status.code());
+            false
+        }
+        Err(e) => {
+            tail.push_back(format!("could not run daft clone: {e}"));
+            presenter.on_job_failure(&name, elapsed);
+            false
+        }
+    };
+    CloneOutcome {
+        dir: planned.dir.clone(),
+        success,
+        tail,
+    }
+}
+
+/// Forward `reader`'s lines to the dashboard row `name`, keeping the last
+/// [`FAILURE_TAIL_LINES`] for the failure report.
+fn stream_lines(
+    reader: Box<dyn std::io::Read + Send>,
+    name: &str,
+    presenter: &Arc<dyn JobPresenter>,
+    tail: &Arc<Mutex<VecDeque<String>>>,
+) -> thread::JoinHandle<()> {
+    let presenter = Arc::clone(presenter);
+    let tail = Arc::clone(tail);
+    let name = name.to_string();
+    thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            let line = line.trim_end_matches('\r').to_string();
+            presenter.on_job_output(&name, &line);
+            if let Ok(mut tail) = tail.lock() {
+                if tail.len() == FAILURE_TAIL_LINES {
+                    tail.pop_front();
+                }
+                tail.push_back(line);
+            }
+        }
+    })
+}
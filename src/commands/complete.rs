@@ -192,6 +192,12 @@ fn complete(
             &CONFIG_RENAME,
         )?)),
 
+        // daft-which: any branch (--create can check out a branch without a worktree)
+        ("daft-which", _) => Ok(format_entries_as_strings(&complete_rich_branches(
+            word,
+            &CONFIG_WHICH,
+        )?)),
+
         // git-worktree-push: single branch positional (worktree + local).
         // Position-agnostic like every other single-positional command: the
         // shells pass the raw word index, which counts flags, so keying on 1
@@ -236,6 +242,12 @@ fn complete(
         // no catalog open on the Tab path.
         ("relation-label", _) => complete_relation_labels(word),
 
+        // workspace-name: workspace files under ~/.config/daft/workspaces
+        ("workspace-name", _) => Ok(crate::core::workspace::list_names()?
+            .into_iter()
+            .filter(|name| name.starts_with(word))
+            .collect()),
+
         // shared-worktrees: complete worktree directory names
         ("shared-worktrees", _) => complete_worktree_names(word),
 
@@ -2204,6 +2216,13 @@ const CONFIG_RENAME: RichCompletionConfig = RichCompletionConfig {
     exclude_current: false,
 };
 
+const CONFIG_WHICH: RichCompletionConfig = RichCompletionConfig {
+    include_worktrees: true,
+    include_local: true,
+    include_remote: true,
+    exclude_current: false,
+};
+
 const CONFIG_CARRY: RichCompletionConfig = RichCompletionConfig {
     include_worktrees: true,
     include_local: false,
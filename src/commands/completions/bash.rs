@@ -428,11 +428,11 @@ _daft() {
             fi
         done
         case "$_fmt_path" in
-            list|worktree-list|"hooks trust list"|"hooks jobs"|"layout list"|"shared status")
+            list|worktree-list|"hooks trust list"|"hooks jobs"|"hooks audit"|"layout list"|"shared status")
                 COMPREPLY=( $(compgen -W "json ndjson tsv csv yaml toon markdown" -- "$cur") )
                 return 0
                 ;;
-            release-notes|"multi-remote status"|"hooks run")
+            release-notes|env|"multi-remote status"|"hooks run")
                 COMPREPLY=( $(compgen -W "json yaml toon markdown" -- "$cur") )
                 return 0
                 ;;
@@ -443,7 +443,7 @@ _daft() {
     if [[ $cword -ge 2 && "${words[1]}" == "hooks" ]]; then
         # hooks subcommand completion (position 2)
         if [[ $cword -eq 2 ]]; then
-            COMPREPLY=( $(compgen -W "trust prompt deny status migrate install validate dump run jobs" -- "$cur") )
+            COMPREPLY=( $(compgen -W "trust prompt deny status audit migrate install validate dump run test jobs schedule" -- "$cur") )
             COMPREPLY+=( $(compgen -d -- "$cur") )
             return 0
         fi
@@ -466,9 +466,9 @@ _daft() {
                     fi
                     return 0
                 fi
-                [[ "$prev" == "--tag" ]] && return 0
+                [[ "$prev" == "--tag" || "$prev" == "--branch" ]] && return 0
                 if [[ "$cur" == -* ]]; then
-                    COMPREPLY=( $(compgen -W "--job --tag --dry-run -v --verbose -h --help" -- "$cur") )
+                    COMPREPLY=( $(compgen -W "--job --tag --all-worktrees --branch --dry-run -v --verbose -h --help" -- "$cur") )
                     return 0
                 fi
                 local hooks
@@ -516,6 +516,24 @@ _daft() {
                 ;;
             migrate)
                 if [[ "$cur" == -* ]]; then
+                    COMPREPLY=( $(compgen -W "--dry-run --repo-wide -h --help" -- "$cur") )
+                fi
+                return 0
+                ;;
+            install)
+                if [[ "$prev" == "--preset" ]]; then
+                    COMPREPLY=( $(compgen -W "node rust python" -- "$cur") )
+                    return 0
+                fi
+                if [[ "$cur" == -* ]]; then
+                    COMPREPLY=( $(compgen -W "--preset -h --help" -- "$cur") )
+                fi
+                return 0
+                ;;
+            schedule)
+                if [[ $cword -eq 3 ]]; then
+                    COMPREPLY=( $(compgen -W "install uninstall" -- "$cur") )
+                elif [[ "${words[3]}" == "install" && "$cur" == -* ]]; then
                     COMPREPLY=( $(compgen -W "--dry-run -h --help" -- "$cur") )
                 fi
                 return 0
@@ -768,9 +786,155 @@ _daft() {
         return 0
     fi
 
+    # auth: complete subcommands and flags
+    if [[ $cword -ge 2 && "${words[1]}" == "auth" ]]; then
+        if [[ $cword -eq 2 ]]; then
+            COMPREPLY=( $(compgen -W "login status logout" -- "$cur") )
+            return 0
+        fi
+        if [[ "$prev" == "--platform" ]]; then
+            COMPREPLY=( $(compgen -W "github gitlab" -- "$cur") )
+            return 0
+        fi
+        if [[ "$cur" == -* ]]; then
+            if [[ "${words[2]}" == "login" ]]; then
+                COMPREPLY=( $(compgen -W "--platform --with-token -h --help" -- "$cur") )
+            else
+                COMPREPLY=( $(compgen -W "--platform -h --help" -- "$cur") )
+            fi
+        fi
+        return 0
+    fi
+
+    # remote: complete subcommands, remote names, and flags
+    if [[ $cword -ge 2 && "${words[1]}" == "remote" ]]; then
+        if [[ $cword -eq 2 ]]; then
+            COMPREPLY=( $(compgen -W "show" -- "$cur") )
+            return 0
+        fi
+        if [[ "$prev" == "--format" ]]; then
+            COMPREPLY=( $(compgen -W "json ndjson tsv csv yaml toon markdown" -- "$cur") )
+            return 0
+        fi
+        if [[ "$cur" == -* ]]; then
+            COMPREPLY=( $(compgen -W "--parsed --format --template --no-headers -h --help" -- "$cur") )
+            return 0
+        fi
+        COMPREPLY=( $(compgen -W "$(git remote 2>/dev/null)" -- "$cur") )
+        return 0
+    fi
+
+    # clone-all: complete flags, platforms, and the manifest path
+    if [[ $cword -ge 2 && "${words[1]}" == "clone-all" ]]; then
+        case "$prev" in
+            --platform)
+                COMPREPLY=( $(compgen -W "github gitlab" -- "$cur") )
+                return 0
+                ;;
+            --org|--host|--layout|-j|--jobs)
+                return 0
+                ;;
+        esac
+        if [[ "$cur" == -* ]]; then
+            COMPREPLY=( $(compgen -W "--org --platform --host --https --include-archived --layout -j --jobs --dry-run -h --help" -- "$cur") )
+            return 0
+        fi
+        COMPREPLY=( $(compgen -f -- "$cur") )
+        return 0
+    fi
+
+    # stats: complete subcommands
+    if [[ $cword -eq 2 && "${words[1]}" == "stats" ]]; then
+        COMPREPLY=( $(compgen -W "storage" -- "$cur") )
+        return 0
+    fi
+
+    # backup: complete subcommands and flags
+    if [[ $cword -ge 2 && "${words[1]}" == "backup" ]]; then
+        case "$prev" in
+            --to|--from)
+                COMPREPLY=( $(compgen -W "$(git remote 2>/dev/null)" -f -- "$cur") )
+                return 0
+                ;;
+        esac
+        if [[ $cword -eq 2 && "$cur" != -* ]]; then
+            COMPREPLY=( $(compgen -W "restore status" -- "$cur") )
+            return 0
+        fi
+        if [[ "${words[2]}" == "restore" ]]; then
+            COMPREPLY=( $(compgen -W "--from -h --help" -- "$cur") )
+        elif [[ "${words[2]}" != "status" ]]; then
+            COMPREPLY=( $(compgen -W "--to -h --help" -- "$cur") )
+        fi
+        return 0
+    fi
+
+    # pin: the branch, then the ref to hold it at
+    if [[ $cword -ge 2 && "${words[1]}" == "pin" ]]; then
+        if [[ "$cur" == -* ]]; then
+            COMPREPLY=( $(compgen -W "--remove -h --help" -- "$cur") )
+        elif [[ $cword -eq 2 || "$prev" == "--remove" ]]; then
+            COMPREPLY=( $(compgen -W "$(git for-each-ref --format='%(refname:short)' refs/heads 2>/dev/null)" -- "$cur") )
+        elif [[ $cword -eq 3 ]]; then
+            COMPREPLY=( $(compgen -W "$(git for-each-ref --format='%(refname:short)' refs/tags refs/heads refs/remotes 2>/dev/null)" -- "$cur") )
+        fi
+        return 0
+    fi
+
+    # template: complete subcommands and the source branch
+    if [[ $cword -ge 2 && "${words[1]}" == "template" ]]; then
+        if [[ "$prev" == "--from" ]]; then
+            COMPREPLY=( $(compgen -W "$(git for-each-ref --format='%(refname:short)' refs/heads 2>/dev/null)" -- "$cur") )
+            return 0
+        fi
+        if [[ $cword -eq 2 ]]; then
+            COMPREPLY=( $(compgen -W "snapshot status clear" -- "$cur") )
+        elif [[ "${words[2]}" == "snapshot" ]]; then
+            COMPREPLY=( $(compgen -W "--from -h --help" -- "$cur") )
+        fi
+        return 0
+    fi
+
+    # workspace: complete subcommands and workspace names
+    if [[ $cword -ge 2 && "${words[1]}" == "workspace" ]]; then
+        if [[ $cword -eq 2 ]]; then
+            COMPREPLY=( $(compgen -W "list open status" -- "$cur") )
+            return 0
+        fi
+        case "${words[2]}" in
+            open)
+                if [[ "$prev" == "--with" ]]; then
+                    COMPREPLY=( $(compgen -W "tmux editor" -- "$cur") )
+                    return 0
+                fi
+                if [[ "$cur" == -* ]]; then
+                    COMPREPLY=( $(compgen -W "--with --print -h --help" -- "$cur") )
+                    return 0
+                fi
+                ;;
+            status)
+                if [[ "$prev" == "--format" ]]; then
+                    COMPREPLY=( $(compgen -W "json ndjson tsv csv yaml toon markdown" -- "$cur") )
+                    return 0
+                fi
+                if [[ "$cur" == -* ]]; then
+                    COMPREPLY=( $(compgen -W "--format --template --no-headers -h --help" -- "$cur") )
+                    return 0
+                fi
+                ;;
+            *)
+                return 0
+                ;;
+        esac
+        local names
+        names=$(daft __complete workspace-name "$cur" 2>/dev/null)
+        COMPREPLY=( $(compgen -W "$names" -- "$cur") )
+        return 0
+    fi
+
     # config: complete subcommands
     if [[ $cword -eq 2 && "${words[1]}" == "config" ]]; then
-        COMPREPLY=( $(compgen -W "remote-sync" -- "$cur") )
+        COMPREPLY=( $(compgen -W "remote-sync setup" -- "$cur") )
         return 0
     fi
 
@@ -922,6 +1086,18 @@ _daft() {
                 _daft_rename
                 return 0
                 ;;
+            which)
+                COMP_WORDS=("daft-which" "${COMP_WORDS[@]:2}")
+                COMP_CWORD=$((COMP_CWORD - 1))
+                _daft_which
+                return 0
+                ;;
+            adopt-worktree)
+                COMP_WORDS=("daft-adopt-worktree" "${COMP_WORDS[@]:2}")
+                COMP_CWORD=$((COMP_CWORD - 1))
+                _daft_adopt_worktree
+                return 0
+                ;;
             sync)
                 COMP_WORDS=("git-worktree-sync" "${COMP_WORDS[@]:2}")
                 COMP_CWORD=$((COMP_CWORD - 1))
@@ -972,7 +1148,7 @@ _daft() {
         if [[ "$cur" == -* ]]; then
             COMPREPLY=( $(compgen -W "--version -V --help -h -C" -- "$cur") )
         else
-            COMPREPLY=( $(compgen -W "activate hooks shell-init multi-remote release-notes doctor layout shared config file repo skill clone init install go start carry exec run update list prune rename sync push remove merge worktree-merge adopt eject" -- "$cur") )
+            COMPREPLY=( $(compgen -W "activate hooks shell-init env multi-remote release-notes whats-new doctor auth remote clone-all consolidate stats backup template pin layout shared config file repo skill workspace clone init install go start carry exec run update list which prune rename sync push remove merge worktree-merge adopt adopt-worktree eject" -- "$cur") )
         fi
         return 0
     fi
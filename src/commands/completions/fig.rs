@@ -457,6 +457,19 @@ fn build_fig_hooks_subcommand() -> FigSubcommand {
                     description: "Run only jobs with this tag".into(),
                     args: None,
                 },
+                FigOption {
+                    name: FigName::Single("--all-worktrees".into()),
+                    description: "Run the hook in every worktree".into(),
+                    args: None,
+                },
+                FigOption {
+                    name: FigName::Single("--branch".into()),
+                    description: "Run the hook in worktrees whose branch matches a glob".into(),
+                    args: Some(FigOptionArg {
+                        suggestions: None,
+                        template: None,
+                    }),
+                },
                 FigOption {
                     name: FigName::Single("--dry-run".into()),
                     description: "Preview what would run".into(),
@@ -516,6 +529,83 @@ fn build_fig_hooks_subcommand() -> FigSubcommand {
         }),
     };
 
+    let hooks_audit = FigSubcommand {
+        name: "audit".to_string(),
+        description: Some("Review trust changes and hook decisions".to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: None,
+        options: Some({
+            let mut opts = vec![
+                FigOption {
+                    name: FigName::Single("--repo".into()),
+                    description: "Show only this repository's entries".into(),
+                    args: Some(FigOptionArg {
+                        suggestions: None,
+                        template: Some("folders".to_string()),
+                    }),
+                },
+                FigOption {
+                    name: FigName::Multiple(vec!["--limit".into(), "-n".into()]),
+                    description: "Show only the newest N entries".into(),
+                    args: Some(FigOptionArg {
+                        suggestions: None,
+                        template: None,
+                    }),
+                },
+            ];
+            opts.extend(build_emit_options("hooks audit"));
+            opts
+        }),
+    };
+
+    let hooks_schedule = FigSubcommand {
+        name: "schedule".to_string(),
+        description: Some("Install scheduled tasks as timers".to_string()),
+        load_spec: None,
+        subcommands: Some(vec![
+            FigSubcommand {
+                name: "install".to_string(),
+                description: Some("Generate and enable timers".to_string()),
+                load_spec: None,
+                subcommands: None,
+                args: None,
+                options: Some(vec![FigOption {
+                    name: FigName::Single("--dry-run".into()),
+                    description: "Print the generated units without writing them".into(),
+                    args: None,
+                }]),
+            },
+            fig_subcommand("uninstall", "Disable and remove timers"),
+        ]),
+        args: None,
+        options: None,
+    };
+
+    let hooks_install = FigSubcommand {
+        name: "install".to_string(),
+        description: Some("Scaffold hooks config".to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: None,
+        options: Some(vec![FigOption {
+            name: FigName::Single("--preset".into()),
+            description: "Write a curated starter config for a toolchain".into(),
+            args: Some(FigOptionArg {
+                suggestions: Some(
+                    ["node", "rust", "python"]
+                        .into_iter()
+                        .map(|name| FigSuggestion {
+                            name: name.to_string(),
+                            description: format!("{name} preset"),
+                        })
+                        .collect(),
+                ),
+                template: None,
+            }),
+        }]),
+    };
+
     FigSubcommand {
         name: "hooks".to_string(),
         description: Some("Manage lifecycle hooks".to_string()),
@@ -525,12 +615,15 @@ fn build_fig_hooks_subcommand() -> FigSubcommand {
             fig_subcommand("prompt", "Prompt before hooks"),
             fig_subcommand("deny", "Deny hooks"),
             fig_subcommand("status", "Show hooks status"),
+            hooks_audit,
             fig_subcommand("migrate", "Migrate hook files"),
-            fig_subcommand("install", "Scaffold hooks config"),
+            hooks_install,
             fig_subcommand("validate", "Validate hooks config"),
             fig_subcommand("dump", "Show merged config"),
             hooks_run,
+            fig_subcommand("test", "Run hook tests in a sandbox"),
             hooks_jobs,
+            hooks_schedule,
         ]),
         args: None,
         options: None,
@@ -852,6 +945,377 @@ fn build_fig_skill_subcommand() -> FigSubcommand {
     }
 }
 
+/// Build the auth subcommand with nested subcommands
+fn build_fig_auth_subcommand() -> FigSubcommand {
+    let platform = || FigOption {
+        name: FigName::Single("--platform".into()),
+        description: "Forge platform, when the host name doesn't say".into(),
+        args: Some(FigOptionArg {
+            suggestions: Some(
+                [("github", "GitHub"), ("gitlab", "GitLab")]
+                    .into_iter()
+                    .map(|(name, label)| FigSuggestion {
+                        name: name.to_string(),
+                        description: label.to_string(),
+                    })
+                    .collect(),
+            ),
+            template: None,
+        }),
+    };
+    let with_target = |name: &str, description: &str, extra: Vec<FigOption>| {
+        let mut options = vec![platform()];
+        options.extend(extra);
+        FigSubcommand {
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            load_spec: None,
+            subcommands: None,
+            args: Some(FigArgs::Single(FigArg {
+                name: "host".to_string(),
+                description: Some("Forge host, e.g. github.com".to_string()),
+                generators: None,
+            })),
+            options: Some(options),
+        }
+    };
+
+    FigSubcommand {
+        name: "auth".to_string(),
+        description: Some("Log in to GitHub or GitLab for daft's forge features".to_string()),
+        load_spec: None,
+        subcommands: Some(vec![
+            with_target(
+                "login",
+                "Log in to a forge host and store the token in the OS keychain",
+                vec![FigOption {
+                    name: FigName::Single("--with-token".into()),
+                    description: "Read the token from stdin".into(),
+                    args: None,
+                }],
+            ),
+            with_target(
+                "status",
+                "Show whether a forge host is logged in and where its token comes from",
+                vec![],
+            ),
+            with_target("logout", "Remove the stored login for a forge host", vec![]),
+        ]),
+        args: None,
+        options: None,
+    }
+}
+
+/// Build the remote subcommand with nested subcommands
+fn build_fig_remote_subcommand() -> FigSubcommand {
+    let mut options = vec![FigOption {
+        name: FigName::Single("--parsed".into()),
+        description: "Break each URL into its parts".into(),
+        args: None,
+    }];
+    options.extend(build_emit_options("remote show"));
+
+    let show = FigSubcommand {
+        name: "show".to_string(),
+        description: Some("Show remote URLs, with insteadOf rewrites applied".to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: Some(FigArgs::Single(FigArg {
+            name: "name".to_string(),
+            description: Some("Remote to show".to_string()),
+            generators: Some(FigGenerator {
+                script: vec!["git".into(), "remote".into()],
+                split_on: "\n".to_string(),
+            }),
+        })),
+        options: Some(options),
+    };
+
+    FigSubcommand {
+        name: "remote".to_string(),
+        description: Some("Inspect the repository's remotes as daft parses them".to_string()),
+        load_spec: None,
+        subcommands: Some(vec![show]),
+        args: None,
+        options: None,
+    }
+}
+
+/// Build the clone-all subcommand
+fn build_fig_clone_all_subcommand() -> FigSubcommand {
+    let flag = |name: &str, description: &str| FigOption {
+        name: FigName::Single(name.into()),
+        description: description.into(),
+        args: None,
+    };
+    let valued = |name: FigName, description: &str| FigOption {
+        name,
+        description: description.into(),
+        args: Some(FigOptionArg {
+            suggestions: None,
+            template: None,
+        }),
+    };
+    FigSubcommand {
+        name: "clone-all".to_string(),
+        description: Some(
+            "Clone every repository from a manifest or forge org that isn't here yet".to_string(),
+        ),
+        load_spec: None,
+        subcommands: None,
+        args: Some(FigArgs::Single(FigArg {
+            name: "manifest".to_string(),
+            description: Some("YAML manifest of repository URLs".to_string()),
+            generators: None,
+        })),
+        options: Some(vec![
+            valued(
+                FigName::Single("--org".into()),
+                "Clone the repositories of this org, group, or user",
+            ),
+            FigOption {
+                name: FigName::Single("--platform".into()),
+                description: "Forge platform, when the host name doesn't say".into(),
+                args: Some(FigOptionArg {
+                    suggestions: Some(
+                        [("github", "GitHub"), ("gitlab", "GitLab")]
+                            .into_iter()
+                            .map(|(name, label)| FigSuggestion {
+                                name: name.to_string(),
+                                description: label.to_string(),
+                            })
+                            .collect(),
+                    ),
+                    template: None,
+                }),
+            },
+            valued(FigName::Single("--host".into()), "Forge host"),
+            flag(
+                "--https",
+                "Clone org repositories over HTTPS instead of SSH",
+            ),
+            flag("--include-archived", "Include archived org repositories"),
+            valued(
+                FigName::Single("--layout".into()),
+                "Layout for every clone that doesn't set one in the manifest",
+            ),
+            valued(
+                FigName::Multiple(vec!["-j".into(), "--jobs".into()]),
+                "Number of clones to run at once",
+            ),
+            flag(
+                "--dry-run",
+                "Show what would be cloned and skipped, then exit",
+            ),
+        ]),
+    }
+}
+
+/// Build the backup subcommand with nested subcommands
+fn build_fig_backup_subcommand() -> FigSubcommand {
+    let dest = |name: &str, description: &str| FigOption {
+        name: FigName::Single(name.into()),
+        description: description.into(),
+        args: Some(FigOptionArg {
+            suggestions: None,
+            template: Some("filepaths".to_string()),
+        }),
+    };
+    let leaf = |name: &str, description: &str, options: Option<Vec<FigOption>>| FigSubcommand {
+        name: name.to_string(),
+        description: Some(description.to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: None,
+        options,
+    };
+    FigSubcommand {
+        name: "backup".to_string(),
+        description: Some("Back up local branches, stashes, and worktree changes".to_string()),
+        load_spec: None,
+        subcommands: Some(vec![
+            leaf(
+                "restore",
+                "Restore branches and stashes from a backup",
+                Some(vec![dest("--from", "Backup remote or bundle path")]),
+            ),
+            leaf("status", "Show the last backup and the schedule", None),
+        ]),
+        args: None,
+        options: Some(vec![dest("--to", "Backup remote or bundle path")]),
+    }
+}
+
+/// Build the pin subcommand: a branch, then the ref to hold it at
+fn build_fig_pin_subcommand() -> FigSubcommand {
+    let refs = |name: &str, description: &str, namespaces: &[&str]| {
+        let mut script: Vec<String> = vec![
+            "git".into(),
+            "for-each-ref".into(),
+            "--format=%(refname:short)".into(),
+        ];
+        script.extend(namespaces.iter().map(|n| n.to_string()));
+        FigArg {
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            generators: Some(FigGenerator {
+                script,
+                split_on: "\n".to_string(),
+            }),
+        }
+    };
+    FigSubcommand {
+        name: "pin".to_string(),
+        description: Some("Hold a worktree at a fixed ref".to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: Some(FigArgs::Multiple(vec![
+            refs("branch", "Branch whose worktree to pin", &["refs/heads"]),
+            refs(
+                "ref",
+                "Ref to hold the worktree at",
+                &["refs/tags", "refs/heads", "refs/remotes"],
+            ),
+        ])),
+        options: Some(vec![FigOption {
+            name: FigName::Single("--remove".into()),
+            description: "Release the pin and check the branch out again".into(),
+            args: None,
+        }]),
+    }
+}
+
+/// Build the template subcommand with nested subcommands
+fn build_fig_template_subcommand() -> FigSubcommand {
+    let leaf = |name: &str, description: &str, options: Option<Vec<FigOption>>| FigSubcommand {
+        name: name.to_string(),
+        description: Some(description.to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: None,
+        options,
+    };
+    FigSubcommand {
+        name: "template".to_string(),
+        description: Some(
+            "Pre-seed new worktrees with build directories from a snapshot".to_string(),
+        ),
+        load_spec: None,
+        subcommands: Some(vec![
+            leaf(
+                "snapshot",
+                "Capture the configured directories from a worktree",
+                Some(vec![FigOption {
+                    name: FigName::Single("--from".into()),
+                    description: "Branch whose worktree to capture".into(),
+                    args: Some(FigOptionArg {
+                        suggestions: None,
+                        template: None,
+                    }),
+                }]),
+            ),
+            leaf("status", "Show the current template", None),
+            leaf("clear", "Delete the template", None),
+        ]),
+        args: None,
+        options: None,
+    }
+}
+
+/// Build the stats subcommand with nested subcommands
+fn build_fig_stats_subcommand() -> FigSubcommand {
+    FigSubcommand {
+        name: "stats".to_string(),
+        description: Some("Show numbers about the current project".to_string()),
+        load_spec: None,
+        subcommands: Some(vec![FigSubcommand {
+            name: "storage".to_string(),
+            description: Some(
+                "Show the disk saved by sharing one object store, and check the sharing"
+                    .to_string(),
+            ),
+            load_spec: None,
+            subcommands: None,
+            args: None,
+            options: None,
+        }]),
+        args: None,
+        options: None,
+    }
+}
+
+/// Build the workspace subcommand with nested subcommands
+fn build_fig_workspace_subcommand() -> FigSubcommand {
+    let name_arg = || {
+        Some(FigArgs::Single(FigArg {
+            name: "name".to_string(),
+            description: Some("Workspace name".to_string()),
+            generators: Some(FigGenerator {
+                script: vec![
+                    "daft".into(),
+                    "__complete".into(),
+                    "workspace-name".into(),
+                    String::new(),
+                ],
+                split_on: "\n".to_string(),
+            }),
+        }))
+    };
+
+    let open = FigSubcommand {
+        name: "open".to_string(),
+        description: Some("Open every member's worktree in tmux or an editor".to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: name_arg(),
+        options: Some(vec![
+            FigOption {
+                name: FigName::Single("--with".into()),
+                description: "Override the workspace's open setting".into(),
+                args: Some(FigOptionArg {
+                    suggestions: Some(
+                        ["tmux", "editor"]
+                            .into_iter()
+                            .map(|name| FigSuggestion {
+                                name: name.to_string(),
+                                description: format!("Open with {name}"),
+                            })
+                            .collect(),
+                    ),
+                    template: None,
+                }),
+            },
+            FigOption {
+                name: FigName::Single("--print".into()),
+                description: "Print repo, branch, and worktree path per member".into(),
+                args: None,
+            },
+        ]),
+    };
+
+    let status = FigSubcommand {
+        name: "status".to_string(),
+        description: Some("Show the state of every member's worktree".to_string()),
+        load_spec: None,
+        subcommands: None,
+        args: name_arg(),
+        options: Some(build_emit_options("workspace status")),
+    };
+
+    FigSubcommand {
+        name: "workspace".to_string(),
+        description: Some("Open and inspect groups of daft projects".to_string()),
+        load_spec: None,
+        subcommands: Some(vec![
+            fig_subcommand("list", "List the defined workspaces"),
+            open,
+            status,
+        ]),
+        args: None,
+        options: None,
+    }
+}
+
 /// Build the multi-remote subcommand with nested subcommands
 fn build_fig_multi_remote_subcommand() -> FigSubcommand {
     FigSubcommand {
@@ -1251,8 +1715,10 @@ fn build_fig_run_subcommand() -> FigSubcommand {
 pub(super) fn generate_fig_daft_spec() -> Result<String> {
     let simple_subcommands = [
         ("shell-init", "Generate shell initialization scripts"),
+        ("env", "Print daft's context for the current worktree"),
         ("activate", "Activate daft in this shell"),
         ("release-notes", "Generate release notes"),
+        ("whats-new", "Show the pending update's release notes"),
     ];
 
     let mut subcommands: Vec<FigSubcommand> = vec![
@@ -1261,6 +1727,14 @@ pub(super) fn generate_fig_daft_spec() -> Result<String> {
         build_fig_layout_subcommand(),
         build_fig_repo_subcommand(),
         build_fig_skill_subcommand(),
+        build_fig_auth_subcommand(),
+        build_fig_remote_subcommand(),
+        build_fig_clone_all_subcommand(),
+        build_fig_backup_subcommand(),
+        build_fig_template_subcommand(),
+        build_fig_pin_subcommand(),
+        build_fig_stats_subcommand(),
+        build_fig_workspace_subcommand(),
         build_fig_merge_subcommand("merge"),
         build_fig_merge_subcommand("worktree-merge"),
         build_fig_run_subcommand(),
@@ -479,9 +479,11 @@ complete -c daft -n '__fish_use_subcommand' -s V -l version -d 'Print version in
 complete -c daft -n '__fish_use_subcommand' -s h -l help -d 'Print help'
 complete -c daft -n '__fish_use_subcommand' -a 'hooks' -d 'Manage lifecycle hooks'
 complete -c daft -n '__fish_use_subcommand' -a 'shell-init' -d 'Generate shell wrappers'
+complete -c daft -n '__fish_use_subcommand' -a 'env' -d 'Print worktree context'
 complete -c daft -n '__fish_use_subcommand' -a 'activate' -d 'Activate daft in this shell'
 complete -c daft -n '__fish_use_subcommand' -a 'multi-remote' -d 'Multi-remote management'
 complete -c daft -n '__fish_use_subcommand' -a 'release-notes' -d 'Generate release notes'
+complete -c daft -n '__fish_use_subcommand' -a 'whats-new' -d "Show the pending update's release notes"
 complete -c daft -n '__fish_use_subcommand' -a 'doctor' -d 'Check installation'
 complete -c daft -n '__fish_use_subcommand' -a 'layout' -d 'Manage worktree layouts'
 complete -c daft -n '__fish_use_subcommand' -a 'clone' -d 'Clone repo into worktree layout'
@@ -497,9 +499,11 @@ complete -c daft -n '__fish_use_subcommand' -a 'prune' -d 'Remove stale worktree
 complete -c daft -n '__fish_use_subcommand' -a 'rename' -d 'Rename branch and move worktree'
 complete -c daft -n '__fish_use_subcommand' -a 'remove' -d 'Delete branch and worktree'
 complete -c daft -n '__fish_use_subcommand' -a 'adopt' -d 'Convert repo to worktree layout'
+complete -c daft -n '__fish_use_subcommand' -a 'adopt-worktree' -d 'Register a worktree created outside daft'
 complete -c daft -n '__fish_use_subcommand' -a 'sync' -d 'Synchronize worktrees with remote'
 complete -c daft -n '__fish_use_subcommand' -a 'push' -d 'Push a branch, hooks in its worktree'
 complete -c daft -n '__fish_use_subcommand' -a 'list' -d 'List worktrees with status'
+complete -c daft -n '__fish_use_subcommand' -a 'which' -d 'Print the worktree path for a branch'
 complete -c daft -n '__fish_use_subcommand' -a 'merge' -d 'Merge branches across worktrees'
 complete -c daft -n '__fish_use_subcommand' -a 'worktree-merge' -d 'Merge branches across worktrees'
 complete -c daft -n '__fish_use_subcommand' -a 'eject' -d 'Convert back to traditional layout'
@@ -507,6 +511,15 @@ complete -c daft -n '__fish_use_subcommand' -a 'config' -d 'Configure daft setti
 complete -c daft -n '__fish_use_subcommand' -a 'shared' -d 'Manage shared files across worktrees'
 complete -c daft -n '__fish_use_subcommand' -a 'repo' -d 'Repository-level operations'
 complete -c daft -n '__fish_use_subcommand' -a 'skill' -d 'Manage the daft agent skill'
+complete -c daft -n '__fish_use_subcommand' -a 'auth' -d "Log in to GitHub or GitLab for daft's forge features"
+complete -c daft -n '__fish_use_subcommand' -a 'remote' -d "Inspect the repository's remotes as daft parses them"
+complete -c daft -n '__fish_use_subcommand' -a 'clone-all' -d "Clone every repository from a manifest or forge org that isn't here yet"
+complete -c daft -n '__fish_use_subcommand' -a 'consolidate' -d 'Turn separate clones of a repository into worktrees of one'
+complete -c daft -n '__fish_use_subcommand' -a 'stats' -d 'Show numbers about the current project'
+complete -c daft -n '__fish_use_subcommand' -a 'backup' -d 'Back up local branches, stashes, and worktree changes'
+complete -c daft -n '__fish_use_subcommand' -a 'template' -d 'Pre-seed new worktrees with build directories from a snapshot'
+complete -c daft -n '__fish_use_subcommand' -a 'pin' -d 'Hold a worktree at a fixed ref'
+complete -c daft -n '__fish_use_subcommand' -a 'workspace' -d 'Open and inspect groups of daft projects'
 complete -c daft -n '__fish_use_subcommand' -a 'file' -d 'Manage YAML config files'
 complete -c daft -n '__fish_seen_subcommand_from go; and test (__daft_verb_position) -eq 1' -f -a "(daft __complete daft-go (commandline -ct) --position 1 --fetch-on-miss 2>/dev/null | awk -F'\t' '{c=$1; sub(/[*?]+$/,\"\",c); s=substr($1,length(c)+1); if (NF>=5) printf \"%s\t%s %s · %s · %s\n\",c,s,$3,$4,$5; else if (NF>=4) printf \"%s\t%s %s · %s\n\",c,s,$3,$4; else printf \"%s\t%s\n\",c,$3}')"
 complete -c daft -n '__fish_seen_subcommand_from go; and test (__daft_verb_position) -eq 2' -f -a "(env DAFT_COMPLETE_GO_FIRST=(__daft_verb_first) daft __complete daft-go (commandline -ct) --position 2 2>/dev/null | cut -f1)"
@@ -529,7 +542,9 @@ complete -c daft -n '__fish_seen_subcommand_from update' -f -a "(daft __complete
 complete -c daft -n '__fish_seen_subcommand_from remove' -f -a "(daft __complete daft-remove (commandline -ct) --position 1 2>/dev/null | awk -F'\t' '{c=$1; sub(/[*?]+$/,\"\",c); s=substr($1,length(c)+1); if (NF>=5) printf \"%s\t%s %s · %s · %s\n\",c,s,$3,$4,$5; else if (NF>=4) printf \"%s\t%s %s · %s\n\",c,s,$3,$4; else printf \"%s\t%s\n\",c,$3}')"
 complete -c daft -n '__fish_seen_subcommand_from remove' -a "(__fish_complete_directories (commandline -ct))"
 complete -c daft -n '__fish_seen_subcommand_from rename' -f -a "(daft __complete daft-rename (commandline -ct) --position 1 2>/dev/null | awk -F'\t' '{c=$1; sub(/[*?]+$/,\"\",c); s=substr($1,length(c)+1); if (NF>=5) printf \"%s\t%s %s · %s · %s\n\",c,s,$3,$4,$5; else if (NF>=4) printf \"%s\t%s %s · %s\n\",c,s,$3,$4; else printf \"%s\t%s\n\",c,$3}')"
+complete -c daft -n '__fish_seen_subcommand_from which' -f -a "(daft __complete daft-which (commandline -ct) --position 1 2>/dev/null | awk -F'\t' '{c=$1; sub(/[*?]+$/,\"\",c); s=substr($1,length(c)+1); if (NF>=5) printf \"%s\t%s %s · %s · %s\n\",c,s,$3,$4,$5; else if (NF>=4) printf \"%s\t%s %s · %s\n\",c,s,$3,$4; else printf \"%s\t%s\n\",c,$3}')"
 complete -c daft -n '__fish_seen_subcommand_from rename' -a "(__fish_complete_directories (commandline -ct))"
+complete -c daft -n '__fish_seen_subcommand_from adopt-worktree' -a "(__fish_complete_directories (commandline -ct))"
 complete -c daft -n '__fish_seen_subcommand_from layout; and not __fish_seen_subcommand_from default list show transform' -f -a 'default list show transform'
 complete -c daft -n '__fish_seen_subcommand_from layout; and __fish_seen_subcommand_from show' -F
 complete -c daft -n '__fish_seen_subcommand_from layout; and __fish_seen_subcommand_from transform' -f -a "(daft __complete layout-transform '' 2>/dev/null)"
@@ -591,6 +606,54 @@ complete -c daft -n '__fish_seen_subcommand_from repo; and __fish_seen_subcomman
 complete -c daft -n '__fish_seen_subcommand_from repo; and __fish_seen_subcommand_from link' -l kind -r -d 'Free-form relationship kind'
 # repo unlink: relation-label completion
 complete -c daft -n '__fish_seen_subcommand_from repo; and __fish_seen_subcommand_from unlink' -f -a "(daft __complete relation-label (commandline -ct) 2>/dev/null)"
+# auth: subcommands and flags
+complete -c daft -n '__fish_seen_subcommand_from auth; and not __fish_seen_subcommand_from login status logout' -f -a 'login' -d 'Log in to a forge host and store the token in the OS keychain'
+complete -c daft -n '__fish_seen_subcommand_from auth; and not __fish_seen_subcommand_from login status logout' -f -a 'status' -d 'Show whether a forge host is logged in and where its token comes from'
+complete -c daft -n '__fish_seen_subcommand_from auth; and not __fish_seen_subcommand_from login status logout' -f -a 'logout' -d 'Remove the stored login for a forge host'
+complete -c daft -n '__fish_seen_subcommand_from auth; and __fish_seen_subcommand_from login status logout' -l platform -x -a 'github gitlab' -d 'Forge platform'
+complete -c daft -n '__fish_seen_subcommand_from auth; and __fish_seen_subcommand_from login' -l with-token -d 'Read the token from stdin'
+# remote: subcommands, remote names, flags
+complete -c daft -n '__fish_seen_subcommand_from remote; and not __fish_seen_subcommand_from show' -f -a 'show' -d 'Show remote URLs, with insteadOf rewrites applied'
+complete -c daft -n '__fish_seen_subcommand_from remote; and __fish_seen_subcommand_from show' -f -a "(git remote 2>/dev/null)"
+complete -c daft -n '__fish_seen_subcommand_from remote; and __fish_seen_subcommand_from show' -l parsed -d 'Break each URL into its parts'
+complete -c daft -n '__fish_seen_subcommand_from remote; and __fish_seen_subcommand_from show' -l format -x -a 'json ndjson tsv csv yaml toon markdown' -d 'Output format'
+# clone-all: manifest path and flags
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -F
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -l org -x -d 'Clone the repositories of this org, group, or user'
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -l platform -x -a 'github gitlab' -d 'Forge platform'
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -l host -x -d 'Forge host'
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -l https -d 'Clone org repositories over HTTPS'
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -l include-archived -d 'Include archived org repositories'
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -l layout -x -d 'Layout for every clone'
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -s j -l jobs -x -d 'Number of clones to run at once'
+complete -c daft -n '__fish_seen_subcommand_from clone-all' -l dry-run -d 'Show what would be cloned, then exit'
+# consolidate: a directory to scan
+complete -c daft -n '__fish_seen_subcommand_from consolidate' -a "(__fish_complete_directories (commandline -ct))"
+complete -c daft -n '__fish_seen_subcommand_from consolidate' -l dry-run -d 'Show what would be consolidated without changing anything'
+# stats: subcommands
+complete -c daft -n '__fish_seen_subcommand_from stats; and not __fish_seen_subcommand_from storage' -f -a 'storage' -d 'Show the disk saved by sharing one object store, and check the sharing'
+# backup: subcommands and destinations
+complete -c daft -n '__fish_seen_subcommand_from backup; and not __fish_seen_subcommand_from restore status' -f -a 'restore' -d 'Restore branches and stashes from a backup'
+complete -c daft -n '__fish_seen_subcommand_from backup; and not __fish_seen_subcommand_from restore status' -f -a 'status' -d 'Show the last backup and the schedule'
+complete -c daft -n '__fish_seen_subcommand_from backup; and not __fish_seen_subcommand_from restore status' -l to -r -a "(git remote 2>/dev/null)" -d 'Backup remote or bundle path'
+complete -c daft -n '__fish_seen_subcommand_from backup; and __fish_seen_subcommand_from restore' -l from -r -a "(git remote 2>/dev/null)" -d 'Backup remote or bundle path'
+# pin: the branch, then the ref to hold it at
+complete -c daft -n '__fish_seen_subcommand_from pin; and test (count (commandline -opc)) -eq 2' -f -a "(git for-each-ref --format='%(refname:short)' refs/heads 2>/dev/null)"
+complete -c daft -n '__fish_seen_subcommand_from pin; and test (count (commandline -opc)) -eq 3' -f -a "(git for-each-ref --format='%(refname:short)' refs/tags refs/heads refs/remotes 2>/dev/null)"
+complete -c daft -n '__fish_seen_subcommand_from pin' -l remove -d 'Release the pin and check the branch out again'
+# template: subcommands and the source branch
+complete -c daft -n '__fish_seen_subcommand_from template; and not __fish_seen_subcommand_from snapshot status clear' -f -a 'snapshot' -d 'Capture the configured directories from a worktree'
+complete -c daft -n '__fish_seen_subcommand_from template; and not __fish_seen_subcommand_from snapshot status clear' -f -a 'status' -d 'Show the current template'
+complete -c daft -n '__fish_seen_subcommand_from template; and not __fish_seen_subcommand_from snapshot status clear' -f -a 'clear' -d 'Delete the template'
+complete -c daft -n '__fish_seen_subcommand_from template; and __fish_seen_subcommand_from snapshot' -l from -x -a "(git for-each-ref --format='%(refname:short)' refs/heads 2>/dev/null)" -d 'Branch whose worktree to capture'
+# workspace: subcommands, workspace names, flags
+complete -c daft -n '__fish_seen_subcommand_from workspace; and not __fish_seen_subcommand_from list open status' -f -a 'list' -d 'List the defined workspaces'
+complete -c daft -n '__fish_seen_subcommand_from workspace; and not __fish_seen_subcommand_from list open status' -f -a 'open' -d "Open every member's worktree in tmux or an editor"
+complete -c daft -n '__fish_seen_subcommand_from workspace; and not __fish_seen_subcommand_from list open status' -f -a 'status' -d "Show the state of every member's worktree"
+complete -c daft -n '__fish_seen_subcommand_from workspace; and __fish_seen_subcommand_from open status' -f -a "(daft __complete workspace-name (commandline -ct) 2>/dev/null)"
+complete -c daft -n '__fish_seen_subcommand_from workspace; and __fish_seen_subcommand_from open' -l with -x -a 'tmux editor' -d "Override the workspace's open setting"
+complete -c daft -n '__fish_seen_subcommand_from workspace; and __fish_seen_subcommand_from open' -l print -d 'Print repo, branch, and worktree path per member'
+complete -c daft -n '__fish_seen_subcommand_from workspace; and __fish_seen_subcommand_from status' -l format -x -a 'json ndjson tsv csv yaml toon markdown' -d 'Output format'
 # skill: subcommands
 complete -c daft -n '__fish_seen_subcommand_from skill; and not __fish_seen_subcommand_from install uninstall show' -f -a 'install' -d 'Install or update the agent skill for Claude Code'
 complete -c daft -n '__fish_seen_subcommand_from skill; and not __fish_seen_subcommand_from install uninstall show' -f -a 'uninstall' -d 'Remove the installed agent skill'
@@ -607,21 +670,24 @@ complete -c daft -n '__fish_seen_subcommand_from skill; and __fish_seen_subcomma
 complete -c daft -n '__fish_seen_subcommand_from skill; and __fish_seen_subcommand_from uninstall' -s v -l verbose -d 'Show detailed progress'
 # skill show: flags
 complete -c daft -n '__fish_seen_subcommand_from skill; and __fish_seen_subcommand_from show' -l no-pager -d 'Print rendered output directly instead of through a pager'
-complete -c daft -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from remote-sync' -f -a 'remote-sync'
+complete -c daft -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from remote-sync setup' -f -a 'remote-sync' -d 'Configure remote sync behavior'
+complete -c daft -n '__fish_seen_subcommand_from config; and not __fish_seen_subcommand_from remote-sync setup' -f -a 'setup' -d 'Run the first-run configuration wizard'
 # file: subcommands
 complete -c daft -n '__fish_seen_subcommand_from file; and not __fish_seen_subcommand_from merge' -f -a 'merge' -d 'Merge a source daft.yml into a target daft.yml'
 # file merge: file completion + flags
 complete -c daft -n '__fish_seen_subcommand_from file; and __fish_seen_subcommand_from merge' -F
 complete -c daft -n '__fish_seen_subcommand_from file; and __fish_seen_subcommand_from merge' -l keep-source -d 'Keep the source file after merging'
 complete -c daft -n '__fish_seen_subcommand_from file; and __fish_seen_subcommand_from merge' -s y -l yes -d 'Skip confirmation prompt when target is untracked'
-complete -c daft -n '__fish_seen_subcommand_from hooks; and not __fish_seen_subcommand_from trust prompt deny status migrate install validate dump run jobs' -f -a 'trust prompt deny status migrate install validate dump run jobs'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and not __fish_seen_subcommand_from trust prompt deny status audit migrate install validate dump run test jobs schedule' -f -a 'trust prompt deny status audit migrate install validate dump run test jobs schedule'
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from run' -f -a "(daft __complete hooks-run '' 2>/dev/null)"
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from run' -l job -d 'Run only the named job' -r -f -a "(set -l hook (commandline -opc | string match -rv '^-' | tail -n1); DAFT_COMPLETE_HOOK=\$hook daft __complete hooks-run-job '' 2>/dev/null)"
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from run' -l tag -d 'Run only jobs with this tag'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from run' -l all-worktrees -d 'Run the hook in every worktree'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from run' -l branch -r -d 'Run the hook in worktrees whose branch matches a glob'
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from run' -l dry-run -d 'Preview what would run'
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from run' -s v -l verbose -d 'Show verbose output'
 # hooks: also allow path completion alongside subcommands
-complete -c daft -n '__fish_seen_subcommand_from hooks; and not __fish_seen_subcommand_from trust prompt deny status migrate install validate dump run jobs' -F
+complete -c daft -n '__fish_seen_subcommand_from hooks; and not __fish_seen_subcommand_from trust prompt deny status audit migrate install validate dump run test jobs schedule' -F
 # hooks status: path + flags
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from status' -F
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from status' -s s -l short -d 'Show compact one-line summary'
@@ -634,6 +700,15 @@ complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcomma
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from prompt deny' -s f -l force -d 'Do not ask for confirmation'
 # hooks migrate: flags
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from migrate' -l dry-run -d 'Preview renames without making changes'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from migrate' -l repo-wide -d 'Migrate hook files in every worktree and deprecated config keys'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from install' -l preset -x -a 'node rust python' -d 'Write a curated starter config for a toolchain'
+# hooks audit: flags
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from audit' -l repo -r -F -d 'Show only this repository'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from audit' -s n -l limit -x -d 'Show only the newest N entries'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from audit' -l format -x -a 'json ndjson tsv csv yaml toon markdown' -d 'Output format'
+# hooks schedule: sub-subcommands and flags
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from schedule; and not __fish_seen_subcommand_from install uninstall' -f -a 'install uninstall'
+complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from schedule; and __fish_seen_subcommand_from install' -l dry-run -d 'Print the generated units without writing them'
 # hooks jobs: sub-subcommands and flags
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from jobs; and not __fish_seen_subcommand_from logs cancel retry prune' -f -a 'logs cancel retry prune'
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from jobs' -l all -d 'Show jobs from all worktrees'
@@ -666,6 +741,9 @@ complete -c daft -n '__fish_seen_subcommand_from shared; and __fish_seen_subcomm
 # --format value completions (emit-enabled subcommands)
 complete -c daft -n '__fish_seen_subcommand_from list' -l format -x -a 'json ndjson tsv csv yaml toon markdown'
 complete -c daft -n '__fish_seen_subcommand_from release-notes' -l format -x -a 'json yaml toon markdown'
+complete -c daft -n '__fish_seen_subcommand_from env' -l format -x -a 'json yaml toon markdown'
+complete -c daft -n '__fish_seen_subcommand_from env' -l shell -x -a 'bash zsh fish'
+complete -c daft -n '__fish_seen_subcommand_from env' -l dotenv -d 'Print KEY=value lines'
 complete -c daft -n '__fish_seen_subcommand_from hooks; and __fish_seen_subcommand_from trust; and __fish_seen_subcommand_from list' -l format -x -a 'json ndjson tsv csv yaml toon markdown'
 complete -c daft -n '__fish_seen_subcommand_from layout; and __fish_seen_subcommand_from list' -l format -x -a 'json ndjson tsv csv yaml toon markdown'
 complete -c daft -n '__fish_seen_subcommand_from shared; and __fish_seen_subcommand_from status' -l format -x -a 'json ndjson tsv csv yaml toon markdown'
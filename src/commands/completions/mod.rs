@@ -6,10 +6,11 @@
 mod bash;
 mod fig;
 mod fish;
+mod uninstall;
 mod zsh;
 
 use anyhow::{Context, Result};
-use clap::{Command, CommandFactory, Parser, ValueEnum};
+use clap::{Command, CommandFactory, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 /// Completion targets supported by daft
@@ -31,6 +32,7 @@ pub(super) const VERB_ALIAS_GROUPS: &[(&[&str], &str)] = &[
     (&["update"], "git-worktree-fetch"),
     (&["remove"], "daft-remove"),
     (&["rename"], "daft-rename"),
+    (&["which"], "daft-which"),
     (&["sync"], "git-worktree-sync"),
     (&["list"], "git-worktree-list"),
     (&["prune"], "git-worktree-prune"),
@@ -40,6 +42,7 @@ pub(super) const VERB_ALIAS_GROUPS: &[(&[&str], &str)] = &[
     (&["exec"], "git-worktree-exec"),
     (&["run"], "daft-run"),
     (&["push"], "git-worktree-push"),
+    (&["adopt-worktree"], "daft-adopt-worktree"),
 ];
 
 /// Available daft commands that need completion scripts
@@ -58,12 +61,14 @@ pub(super) const COMMANDS: &[&str] = &[
     "daft-start",
     "daft-remove",
     "daft-rename",
+    "daft-which",
     "git-worktree-sync",
     "git-worktree-push",
     "daft-shared",
     "daft-install",
     "daft-file",
     "daft-run",
+    "daft-adopt-worktree",
 ];
 
 /// Get the clap Command for a given command name by using CommandFactory
@@ -83,12 +88,14 @@ pub(super) fn get_command_for_name(command_name: &str) -> Option<Command> {
         "daft-start" => Some(crate::commands::checkout::StartArgs::command()),
         "daft-remove" => Some(crate::commands::worktree_branch::RemoveArgs::command()),
         "daft-rename" => Some(crate::commands::worktree_branch::RenameArgs::command()),
+        "daft-which" => Some(crate::commands::which::Args::command()),
         "git-worktree-sync" => Some(crate::commands::sync::Args::command()),
         "git-worktree-push" => Some(crate::commands::push::Args::command()),
         "daft-shared" => Some(crate::commands::shared::Args::command()),
         "daft-install" => Some(crate::commands::install::Args::command()),
         "daft-file" => Some(crate::commands::file::merge::Args::command()),
         "daft-run" => Some(crate::commands::run::Args::command()),
+        "daft-adopt-worktree" => Some(crate::commands::adopt_worktree::Args::command()),
         _ => None,
     }
 }
@@ -103,6 +110,7 @@ pub(super) fn uses_rich_completions(command_name: &str) -> bool {
             | "git-worktree-checkout"
             | "daft-remove"
             | "daft-rename"
+            | "daft-which"
             | "git-worktree-carry"
             | "git-worktree-fetch"
             | "git-worktree-branch"
@@ -145,7 +153,10 @@ pub(super) fn command_has_repo_positional(command_name: &str) -> bool {
 /// might type `./` or `../`) and outside any repo (where the dynamic source
 /// can't return branches at all).
 pub(super) fn allows_path_completion(command_name: &str) -> bool {
-    matches!(command_name, "daft-remove" | "daft-rename")
+    matches!(
+        command_name,
+        "daft-remove" | "daft-rename" | "daft-adopt-worktree"
+    )
 }
 
 /// Extract flag strings from a clap Command for shell completions
@@ -287,11 +298,13 @@ pub(super) fn emit_formats_for(command_path: &str) -> Option<Vec<&'static str>>
 
     let shape = match command_path {
         "git-worktree-list" | "list" => Shape::Tabular,
-        "release-notes" => Shape::Document,
+        "release-notes" | "env" => Shape::Document,
         "hooks trust list" => Shape::Tabular,
         "hooks jobs" => Shape::Tabular,
+        "hooks audit" => Shape::Tabular,
         "layout list" => Shape::Tabular,
         "shared status" => Shape::Matrix,
+        "workspace status" | "remote show" => Shape::Tabular,
         "multi-remote status" => Shape::Sectioned,
         "hooks run" => Shape::Sectioned,
         _ => return None,
@@ -307,12 +320,17 @@ pub(super) fn emit_formats_for(command_path: &str) -> Option<Vec<&'static str>>
 #[derive(Parser)]
 #[command(name = "daft-completions")]
 #[command(about = "Generate shell completion scripts for daft commands")]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Args {
+    #[command(subcommand)]
+    subcommand: Option<CompletionsCommand>,
+
     #[arg(
         value_enum,
+        required = true,
         help = "Target to generate completions for (bash, zsh, fish, fig)"
     )]
-    target: CompletionTarget,
+    target: Option<CompletionTarget>,
 
     #[arg(
         short,
@@ -325,6 +343,32 @@ pub struct Args {
     install: bool,
 }
 
+#[derive(Subcommand)]
+enum CompletionsCommand {
+    /// Remove everything daft installed outside its own binary
+    #[command(long_about = r#"
+Remove what daft installed into your home directory and shell setup:
+
+  - completion files written by `daft completions <shell> --install`
+  - Fig/Amazon Q specs under ~/.amazon-q/autocomplete/build/ and
+    ~/.fig/autocomplete/build/
+  - shortcut symlinks (gwtco, gcw, ...) next to the daft binary
+  - man pages in ~/.local/share/man/man1/
+  - the shell-init block `daft activate` added to ~/.bashrc, ~/.zshrc, and
+    ~/.config/fish/config.fish (each edited file is first copied to
+    <name>.daft-<timestamp>.bak)
+
+Only files daft generates are removed, and only shell-init lines inside
+daft's markers are edited; a `daft shell-init` line you added by hand is
+reported, not touched. Files installed by a package manager are left to it.
+"#)]
+    Uninstall {
+        /// Show what would be removed without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
 /// Silently install Fig/Amazon Q specs if an autocomplete directory exists.
 ///
 /// Called from `shell-init` so specs stay in sync whenever a shell starts.
@@ -418,12 +462,17 @@ pub fn run() -> Result<()> {
 
     let args = Args::parse_from(&args_vec);
 
+    if let Some(CompletionsCommand::Uninstall { dry_run }) = args.subcommand {
+        return uninstall::run(dry_run);
+    }
+    let target = args.target.context("A completion target is required")?;
+
     if args.install {
-        install_completions(&args.target)?;
+        install_completions(&target)?;
     } else if let Some(command) = args.command {
-        generate_completion_for_command(&command, &args.target)?;
+        generate_completion_for_command(&command, &target)?;
     } else {
-        generate_all_output(&args.target)?;
+        generate_all_output(&target)?;
     }
 
     Ok(())
@@ -505,6 +554,14 @@ fn generate_daft_subcommand_completions(target: &CompletionTarget) -> String {
     }
 }
 
+/// Install completions for `shell` (`bash`, `zsh` or `fish`), as
+/// `daft completions <shell> --install` does.
+pub(crate) fn install_for_shell(shell: &str) -> Result<()> {
+    let target = CompletionTarget::from_str(shell, true)
+        .map_err(|e| anyhow::anyhow!("Unsupported shell '{shell}': {e}"))?;
+    install_completions(&target)
+}
+
 /// Install completions to standard locations
 fn install_completions(target: &CompletionTarget) -> Result<()> {
     match target {
@@ -1338,10 +1395,14 @@ before adding flags (zsh flag-leak regression)"
             "git-worktree-list",
             "list",
             "release-notes",
+            "env",
             "hooks trust list",
             "hooks jobs",
+            "hooks audit",
             "layout list",
             "shared status",
+            "workspace status",
+            "remote show",
             "multi-remote status",
             "hooks run",
         ] {
@@ -1419,12 +1480,12 @@ before adding flags (zsh flag-leak regression)"
         let script = bash::DAFT_BASH_COMPLETIONS;
         assert!(
             script.contains(
-                "list|worktree-list|\"hooks trust list\"|\"hooks jobs\"|\"layout list\"|\"shared status\""
+                "list|worktree-list|\"hooks trust list\"|\"hooks jobs\"|\"hooks audit\"|\"layout list\"|\"shared status\""
             ),
             "bash umbrella must dispatch tabular/matrix paths to all-7-format list"
         );
         assert!(
-            script.contains("release-notes|\"multi-remote status\"|\"hooks run\""),
+            script.contains("release-notes|env|\"multi-remote status\"|\"hooks run\""),
             "bash umbrella must dispatch document/sectioned paths to 4-format list"
         );
     }
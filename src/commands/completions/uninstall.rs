@@ -0,0 +1,191 @@
+//! `daft completions uninstall` — remove what daft installed outside its own
+//! binary: completion files, Fig specs, shortcut symlinks, man pages, and the
+//! shell-init block `daft activate` appended to shell config files.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use super::{COMMANDS, CompletionTarget, get_completion_dir, get_completion_filename};
+use crate::commands::activate::{config_files, remove_init_block};
+use crate::commands::shortcuts::{effective_install_dir, remove_installed_shortcuts};
+use crate::output::{CliOutput, Output, OutputConfig};
+
+pub(super) fn run(dry_run: bool) -> Result<()> {
+    // Verbose, so each removal (reported as a step) is listed.
+    let mut output = CliOutput::new(OutputConfig::new(false, true));
+    let output: &mut dyn Output = &mut output;
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    let mut count = 0;
+
+    for target in [
+        CompletionTarget::Bash,
+        CompletionTarget::Zsh,
+        CompletionTarget::Fish,
+    ] {
+        let dir = get_completion_dir(&target)?;
+        for command in COMMANDS {
+            count += remove_file(
+                &dir.join(get_completion_filename(command, &target)),
+                dry_run,
+                output,
+            )?;
+        }
+    }
+
+    for parent in [".amazon-q", ".fig"] {
+        let dir = home.join(parent).join("autocomplete/build");
+        for name in fig_spec_filenames() {
+            count += remove_file(&dir.join(name), dry_run, output)?;
+        }
+    }
+
+    let man_dir = home.join(".local/share/man/man1");
+    if let Ok(entries) = std::fs::read_dir(&man_dir) {
+        let mut pages: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(is_daft_man_page)
+            })
+            .collect();
+        pages.sort();
+        for page in pages {
+            count += remove_file(&page, dry_run, output)?;
+        }
+    }
+
+    if let Ok(install_dir) = effective_install_dir() {
+        match remove_installed_shortcuts(&install_dir, dry_run, output) {
+            Ok(removed) => count += removed.len(),
+            Err(e) => output.warning(&format!("Could not remove shortcut symlinks: {e:#}")),
+        }
+    }
+
+    for config in config_files() {
+        let Ok(content) = std::fs::read_to_string(&config) else {
+            continue;
+        };
+        match remove_init_block(&content) {
+            Some(cleaned) => {
+                count += 1;
+                if dry_run {
+                    output.info(&format!(
+                        "  Would remove the daft block from: {}",
+                        config.display()
+                    ));
+                    continue;
+                }
+                let backup = backup_path(&config);
+                std::fs::copy(&config, &backup)
+                    .with_context(|| format!("Failed to create backup at {}", backup.display()))?;
+                crate::state_file::write_atomic(&config, cleaned.as_bytes())?;
+                output.step(&format!(
+                    "  Removed the daft block from: {} (backup: {})",
+                    config.display(),
+                    backup.display()
+                ));
+            }
+            None if content.contains("daft shell-init") => output.warning(&format!(
+                "{} runs `daft shell-init` outside daft's markers; remove that line by hand",
+                config.display()
+            )),
+            None => {}
+        }
+    }
+
+    output.info("");
+    if count == 0 {
+        output.info("Nothing to remove.");
+    } else if dry_run {
+        output.info(&format!("[dry-run] Would remove {count} items."));
+    } else {
+        output.result(&format!("Done! Removed {count} items."));
+    }
+    Ok(())
+}
+
+/// Remove `path` if it exists. Returns 1 when it did (or would).
+fn remove_file(path: &Path, dry_run: bool, output: &mut dyn Output) -> Result<usize> {
+    if !path.is_file() {
+        return Ok(0);
+    }
+    if dry_run {
+        output.info(&format!("  Would remove: {}", path.display()));
+    } else {
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove {}", path.display()))?;
+        output.step(&format!("  Removed: {}", path.display()));
+    }
+    Ok(1)
+}
+
+/// A fresh backup name next to `config`: `<name>.daft-<unix-ts>.bak`, with a
+/// `-<n>` suffix when that is taken, so an earlier backup (or a user's own
+/// `.bak`) is never overwritten.
+fn backup_path(config: &Path) -> PathBuf {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let name = config
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("shellrc");
+    let mut backup = config.with_file_name(format!("{name}.daft-{ts}.bak"));
+    let mut n = 1;
+    while backup.exists() {
+        backup = config.with_file_name(format!("{name}.daft-{ts}-{n}.bak"));
+        n += 1;
+    }
+    backup
+}
+
+/// Every spec file `install_fig_completions` writes.
+fn fig_spec_filenames() -> Vec<String> {
+    let mut names: Vec<String> = COMMANDS.iter().map(|c| format!("{c}.js")).collect();
+    for shortcut in crate::shortcuts::SHORTCUTS {
+        let name = format!("{}.js", shortcut.alias);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names.push("daft.js".to_string());
+    names.push("git-daft.js".to_string());
+    names
+}
+
+/// Whether `name` is one of the pages `xtask gen-man` generates.
+fn is_daft_man_page(name: &str) -> bool {
+    name.strip_suffix(".1").is_some_and(|stem| {
+        stem == "daft"
+            || stem.starts_with("daft-")
+            || stem.starts_with("git-worktree-")
+            || stem.starts_with("git-daft")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_path_never_reuses_an_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join(".zshrc");
+        std::fs::write(&config, "rc").unwrap();
+        std::fs::write(dir.path().join(".zshrc.bak"), "mine").unwrap();
+
+        let first = backup_path(&config);
+        let name = first.file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with(".zshrc.daft-") && name.ends_with(".bak"));
+        std::fs::write(&first, "rc").unwrap();
+
+        let second = backup_path(&config);
+        assert_ne!(first, second);
+        assert!(!second.exists());
+    }
+}
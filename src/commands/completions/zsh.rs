@@ -611,11 +611,11 @@ _daft() {
             fi
         done
         case "$_fmt_path" in
-            list|worktree-list|"hooks trust list"|"hooks jobs"|"layout list"|"shared status")
+            list|worktree-list|"hooks trust list"|"hooks jobs"|"hooks audit"|"layout list"|"shared status")
                 compadd json ndjson tsv csv yaml toon markdown
                 return
                 ;;
-            release-notes|"multi-remote status"|"hooks run")
+            release-notes|env|"multi-remote status"|"hooks run")
                 compadd json yaml toon markdown
                 return
                 ;;
@@ -626,7 +626,7 @@ _daft() {
     if (( CURRENT >= 3 )) && [[ "$words[2]" == "hooks" ]]; then
         # hooks subcommand completion (position 3)
         if (( CURRENT == 3 )); then
-            compadd trust prompt deny status migrate install validate dump run jobs
+            compadd trust prompt deny status audit migrate install validate dump run test jobs schedule
             _files -/
             return
         fi
@@ -659,9 +659,9 @@ _daft() {
                     fi
                     return
                 fi
-                [[ "$prev" == "--tag" ]] && return
+                [[ "$prev" == "--tag" || "$prev" == "--branch" ]] && return
                 if [[ "$curword" == -* ]]; then
-                    compadd -- --job --tag --dry-run -v --verbose -h --help
+                    compadd -- --job --tag --all-worktrees --branch --dry-run -v --verbose -h --help
                     return
                 fi
                 local -a hooks
@@ -708,6 +708,24 @@ _daft() {
                 ;;
             migrate)
                 if [[ "$curword" == -* ]]; then
+                    compadd -- --dry-run --repo-wide -h --help
+                fi
+                return
+                ;;
+            install)
+                if [[ "$words[$((CURRENT-1))]" == "--preset" ]]; then
+                    compadd node rust python
+                    return
+                fi
+                if [[ "$curword" == -* ]]; then
+                    compadd -- --preset -h --help
+                fi
+                return
+                ;;
+            schedule)
+                if (( CURRENT == 4 )); then
+                    compadd install uninstall
+                elif [[ "$words[4]" == "install" && "$curword" == -* ]]; then
                     compadd -- --dry-run -h --help
                 fi
                 return
@@ -991,9 +1009,157 @@ _daft() {
         return
     fi
 
+    # auth: complete subcommands and flags
+    if (( CURRENT >= 3 )) && [[ "$words[2]" == "auth" ]]; then
+        if (( CURRENT == 3 )); then
+            compadd login status logout
+            return
+        fi
+        if [[ "${words[$((CURRENT-1))]}" == "--platform" ]]; then
+            compadd github gitlab
+            return
+        fi
+        if [[ "$curword" == -* ]]; then
+            if [[ "$words[3]" == "login" ]]; then
+                compadd -- --platform --with-token -h --help
+            else
+                compadd -- --platform -h --help
+            fi
+        fi
+        return
+    fi
+
+    # remote: complete subcommands, remote names, and flags
+    if (( CURRENT >= 3 )) && [[ "$words[2]" == "remote" ]]; then
+        if (( CURRENT == 3 )); then
+            compadd show
+            return
+        fi
+        if [[ "${words[$((CURRENT-1))]}" == "--format" ]]; then
+            compadd json ndjson tsv csv yaml toon markdown
+            return
+        fi
+        if [[ "$curword" == -* ]]; then
+            compadd -- --parsed --format --template --no-headers -h --help
+            return
+        fi
+        compadd -- ${(f)"$(git remote 2>/dev/null)"}
+        return
+    fi
+
+    # clone-all: complete flags, platforms, and the manifest path
+    if (( CURRENT >= 3 )) && [[ "$words[2]" == "clone-all" ]]; then
+        case "${words[$((CURRENT-1))]}" in
+            --platform)
+                compadd github gitlab
+                return
+                ;;
+            --org|--host|--layout|-j|--jobs)
+                return
+                ;;
+        esac
+        if [[ "$curword" == -* ]]; then
+            compadd -- --org --platform --host --https --include-archived --layout -j --jobs --dry-run -h --help
+            return
+        fi
+        _files
+        return
+    fi
+
+    # stats: complete subcommands
+    if (( CURRENT == 3 )) && [[ "$words[2]" == "stats" ]]; then
+        compadd storage
+        return
+    fi
+
+    # backup: complete subcommands and flags
+    if (( CURRENT >= 3 )) && [[ "$words[2]" == "backup" ]]; then
+        case "${words[$((CURRENT-1))]}" in
+            --to|--from)
+                compadd -- $(git remote 2>/dev/null)
+                _files
+                return
+                ;;
+        esac
+        if (( CURRENT == 3 )) && [[ "$curword" != -* ]]; then
+            compadd restore status
+            return
+        fi
+        if [[ "$words[3]" == "restore" ]]; then
+            compadd -- --from -h --help
+        elif [[ "$words[3]" != "status" ]]; then
+            compadd -- --to -h --help
+        fi
+        return
+    fi
+
+    # pin: the branch, then the ref to hold it at
+    if (( CURRENT >= 3 )) && [[ "$words[2]" == "pin" ]]; then
+        if [[ "$curword" == -* ]]; then
+            compadd -- --remove -h --help
+        elif (( CURRENT == 3 )) || [[ "${words[$((CURRENT-1))]}" == "--remove" ]]; then
+            compadd -- $(git for-each-ref --format='%(refname:short)' refs/heads 2>/dev/null)
+        elif (( CURRENT == 4 )); then
+            compadd -- $(git for-each-ref --format='%(refname:short)' refs/tags refs/heads refs/remotes 2>/dev/null)
+        fi
+        return
+    fi
+
+    # template: complete subcommands and the source branch
+    if (( CURRENT >= 3 )) && [[ "$words[2]" == "template" ]]; then
+        if [[ "${words[$((CURRENT-1))]}" == "--from" ]]; then
+            compadd -- $(git for-each-ref --format='%(refname:short)' refs/heads 2>/dev/null)
+            return
+        fi
+        if (( CURRENT == 3 )); then
+            compadd snapshot status clear
+        elif [[ "$words[3]" == "snapshot" ]]; then
+            compadd -- --from -h --help
+        fi
+        return
+    fi
+
+    # workspace: complete subcommands and workspace names
+    if (( CURRENT >= 3 )) && [[ "$words[2]" == "workspace" ]]; then
+        if (( CURRENT == 3 )); then
+            compadd list open status
+            return
+        fi
+        local prev_word="${words[$((CURRENT-1))]}"
+        case "$words[3]" in
+            open)
+                if [[ "$prev_word" == "--with" ]]; then
+                    compadd tmux editor
+                    return
+                fi
+                if [[ "$curword" == -* ]]; then
+                    compadd -- --with --print -h --help
+                    return
+                fi
+                ;;
+            status)
+                if [[ "$prev_word" == "--format" ]]; then
+                    compadd json ndjson tsv csv yaml toon markdown
+                    return
+                fi
+                if [[ "$curword" == -* ]]; then
+                    compadd -- --format --template --no-headers -h --help
+                    return
+                fi
+                ;;
+            *)
+                return
+                ;;
+        esac
+        local -a names
+        names=(${(f)"$(daft __complete workspace-name "$curword" 2>/dev/null)"})
+        compadd -a names
+        return
+    fi
+
     # config: complete subcommands
     if (( CURRENT == 3 )) && [[ "$words[2]" == "config" ]]; then
-        compadd remote-sync
+        compadd remote-sync setup
         return
     fi
 
@@ -1157,6 +1323,18 @@ _daft() {
                 __daft_rename_impl
                 return
                 ;;
+            which)
+                words=("daft-which" "${(@)words[3,-1]}")
+                CURRENT=$((CURRENT - 1))
+                __daft_which_impl
+                return
+                ;;
+            adopt-worktree)
+                words=("daft-adopt-worktree" "${(@)words[3,-1]}")
+                CURRENT=$((CURRENT - 1))
+                __daft_adopt_worktree_impl
+                return
+                ;;
             sync)
                 words=("git-worktree-sync" "${(@)words[3,-1]}")
                 CURRENT=$((CURRENT - 1))
@@ -1207,9 +1385,9 @@ _daft() {
         if [[ "$curword" == -* ]]; then
             compadd -- --version -V --help -h -C
         else
-            compadd activate hooks shell-init multi-remote release-notes doctor layout shared \
-                    config file repo skill clone init install go start carry exec run update list prune rename sync push remove \
-                    merge worktree-merge adopt eject
+            compadd activate hooks shell-init env multi-remote release-notes whats-new doctor auth remote clone-all consolidate stats backup template pin layout shared \
+                    config file repo skill workspace clone init install go start carry exec run update list which prune rename sync push remove \
+                    merge worktree-merge adopt adopt-worktree eject
         fi
         return
     fi
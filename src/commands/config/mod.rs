@@ -1,4 +1,5 @@
 pub mod remote_sync;
+pub mod setup;
 
 use anyhow::Result;
 
@@ -15,13 +16,14 @@ pub fn run() -> Result<()> {
 
     match sub_args[0].as_str() {
         "remote-sync" => remote_sync::run(&sub_args[1..]),
+        "setup" => setup::run(&sub_args[1..]),
         "--help" | "-h" => {
             show_usage();
             Ok(())
         }
         other => {
             anyhow::bail!(
-                "Unknown config subcommand: '{}'\n\nUsage: daft config remote-sync",
+                "Unknown config subcommand: '{}'\n\nUsage: daft config <remote-sync|setup>",
                 other
             );
         }
@@ -33,6 +35,7 @@ fn show_usage() {
     eprintln!();
     eprintln!("Available subcommands:");
     eprintln!("  remote-sync    Configure remote sync behavior");
+    eprintln!("  setup          Run the first-run configuration wizard");
     eprintln!();
     eprintln!("Run 'daft config <subcommand> --help' for details.");
 }
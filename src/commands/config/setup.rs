@@ -0,0 +1,422 @@
+//! `daft config setup` — the first-run configuration wizard.
+//!
+//! The first interactive invocation on a machine with no daft config or
+//! state runs the wizard before the command itself; `daft config setup`
+//! runs it again on demand. It asks for the handful of preferences that
+//! shape everyday use and writes each through the owning subsystem:
+//!
+//! | Question            | Written to                                       |
+//! | ------------------- | ------------------------------------------------ |
+//! | Worktree layout     | `defaults.layout` in the global `config.toml`    |
+//! | cd into worktrees   | `daft.autocd` (global git config)                |
+//! | Shell completions   | `daft completions <shell> --install`             |
+//! | Update checks       | `daft.updateCheck`, `daft.updateCheck.interval`  |
+//! | Existing projects   | the repo catalog, as `daft repo add` would       |
+//!
+//! Skipping the automatic wizard (Esc at any question) still creates the
+//! global `config.toml`, so it is offered once and never again.
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Confirm, Input, Select};
+use std::collections::BTreeSet;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use crate::core::global_config::GlobalConfig;
+use crate::core::layout::{BuiltinLayout, DEFAULT_LAYOUT};
+use crate::core::settings::keys;
+use crate::git::GitCommand;
+use crate::output::{CliOutput, Output, OutputConfig};
+
+#[derive(Parser)]
+#[command(name = "daft config setup")]
+#[command(about = "Run the first-run configuration wizard")]
+#[command(long_about = r#"
+Walks through daft's main preferences: the default worktree layout, whether
+commands cd into new worktrees, shell completions for the detected shell, how
+often to check for new versions, and registering repositories already on this
+machine in the repo catalog.
+
+The wizard runs on its own the first time daft is used interactively on a
+machine without daft config or state; this command runs it again. Answers are
+written to the global config (`config.toml` and `git config --global`), so
+they apply to every repository. Press Esc at any question to stop without
+changing anything further.
+"#)]
+pub struct Args {}
+
+/// How often daft looks for a new version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UpdateCadence {
+    Daily,
+    Weekly,
+    Never,
+}
+
+impl UpdateCadence {
+    const ALL: [Self; 3] = [Self::Daily, Self::Weekly, Self::Never];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Daily => "Daily",
+            Self::Weekly => "Weekly",
+            Self::Never => "Never",
+        }
+    }
+}
+
+/// Everything the wizard asked, ready to apply.
+#[derive(Debug, Clone)]
+struct Choices {
+    layout: String,
+    autocd: bool,
+    /// Shell to install completions for, when accepted.
+    completions: Option<&'static str>,
+    update_cadence: UpdateCadence,
+    /// Directory to scan for repositories to register, when accepted.
+    register_under: Option<PathBuf>,
+}
+
+/// Run `daft config setup`.
+pub fn run(args: &[String]) -> Result<()> {
+    let mut cli_args = vec!["daft config setup".to_string()];
+    cli_args.extend_from_slice(args);
+    let _ = Args::parse_from(cli_args);
+
+    if !is_interactive() {
+        anyhow::bail!("The setup wizard requires an interactive terminal.");
+    }
+    let mut output = CliOutput::new(OutputConfig::new(false, false));
+    run_wizard(&mut output)
+}
+
+/// Run the wizard ahead of the first interactive command on a fresh machine.
+///
+/// Called from `main` only when startup background tasks are not skipped
+/// (so never for `shell-init`, completions, internal `__*` commands or the
+/// YAML test runner). Failures are reported and swallowed: the command the
+/// user actually typed still runs.
+pub fn maybe_run_first_run(argv: &[String]) {
+    let explicit =
+        argv.get(1).is_some_and(|a| a == "config") && argv.get(2).is_some_and(|a| a == "setup");
+    let help = argv
+        .iter()
+        .skip(1)
+        .any(|a| matches!(a.as_str(), "--help" | "-h" | "--version" | "-V"));
+    if explicit || help || !is_interactive() || crate::update_check::is_ci_environment() {
+        return;
+    }
+    if !is_first_run(&first_run_markers()) {
+        return;
+    }
+    let mut output = CliOutput::new(OutputConfig::new(false, false));
+    if let Err(e) = run_wizard(&mut output) {
+        output.warning(&format!("Setup did not finish: {e:#}"));
+    }
+    // Even a failed or skipped wizard is offered only once.
+    if let Err(e) = GlobalConfig::ensure_file() {
+        output.warning(&format!("Could not create the daft config file: {e:#}"));
+    }
+    output.info("");
+}
+
+fn is_interactive() -> bool {
+    std::io::stdin().is_terminal()
+        && std::io::stderr().is_terminal()
+        && std::env::var("DAFT_TESTING").is_err()
+}
+
+/// Files whose presence means daft has run here before: the global config,
+/// the trust registry and the repo catalog.
+fn first_run_markers() -> Vec<PathBuf> {
+    let mut markers = Vec::new();
+    if let Ok(path) = GlobalConfig::default_path() {
+        markers.push(path);
+    }
+    if let Ok(dir) = crate::daft_config_dir() {
+        markers.extend(["repos.json", "trust.json"].map(|name| dir.join(name)));
+    }
+    markers.extend(crate::store::paths::catalog_db_probe());
+    markers
+}
+
+/// A first run has no marker on disk. Unresolvable markers count as "not a
+/// first run": the wizard must never fire on a machine it cannot inspect.
+fn is_first_run(markers: &[PathBuf]) -> bool {
+    !markers.is_empty() && markers.iter().all(|path| !path.exists())
+}
+
+fn run_wizard(output: &mut dyn Output) -> Result<()> {
+    output.info("Welcome to daft! A few questions to set up your defaults.");
+    output.info(&format!(
+        "Press Esc to skip; run `{}` to come back to this later.",
+        crate::daft_cmd("config setup")
+    ));
+    output.info("");
+
+    let Some(choices) = ask()? else {
+        output.info("Setup skipped.");
+        return Ok(());
+    };
+    apply(&choices, output)
+}
+
+/// Ask every question. `None` when the user skipped out.
+fn ask() -> Result<Option<Choices>> {
+    let theme = ColorfulTheme::default();
+    let global_config = GlobalConfig::load().unwrap_or_default();
+
+    let layouts = BuiltinLayout::all();
+    let current_layout = global_config
+        .defaults
+        .layout
+        .clone()
+        .unwrap_or_else(|| DEFAULT_LAYOUT.name().to_string());
+    let display: Vec<String> = layouts
+        .iter()
+        .map(|b| format!("{:<20}{}", b.name(), b.to_layout().template))
+        .collect();
+    let Some(layout_idx) = Select::with_theme(&theme)
+        .with_prompt("Where should new worktrees go?")
+        .items(&display)
+        .default(
+            layouts
+                .iter()
+                .position(|b| b.name() == current_layout)
+                .unwrap_or(0),
+        )
+        .interact_opt()?
+    else {
+        return Ok(None);
+    };
+
+    let Some(autocd) = Confirm::with_theme(&theme)
+        .with_prompt("cd into new worktrees automatically?")
+        .default(true)
+        .interact_opt()?
+    else {
+        return Ok(None);
+    };
+
+    let completions = match crate::commands::activate::detected_shell() {
+        Some(shell) => {
+            let Some(install) = Confirm::with_theme(&theme)
+                .with_prompt(format!("Install {shell} completions?"))
+                .default(true)
+                .interact_opt()?
+            else {
+                return Ok(None);
+            };
+            install.then_some(shell)
+        }
+        None => None,
+    };
+
+    let cadences: Vec<&str> = UpdateCadence::ALL.iter().map(|c| c.label()).collect();
+    let Some(cadence_idx) = Select::with_theme(&theme)
+        .with_prompt("Check for new daft versions")
+        .items(&cadences)
+        .default(0)
+        .interact_opt()?
+    else {
+        return Ok(None);
+    };
+
+    let Some(register) = Confirm::with_theme(&theme)
+        .with_prompt("Register the git repositories already on this machine?")
+        .default(false)
+        .interact_opt()?
+    else {
+        return Ok(None);
+    };
+    let register_under = if register {
+        let default_dir = crate::utils::get_current_directory()
+            .ok()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let dir: String = Input::with_theme(&theme)
+            .with_prompt("Directory to look in (two levels deep)")
+            .default(default_dir.display().to_string())
+            .interact_text()?;
+        Some(crate::core::settings::expand_path("setup directory", &dir)?)
+    } else {
+        None
+    };
+
+    Ok(Some(Choices {
+        layout: layouts[layout_idx].name().to_string(),
+        autocd,
+        completions,
+        update_cadence: UpdateCadence::ALL[cadence_idx],
+        register_under,
+    }))
+}
+
+fn apply(choices: &Choices, output: &mut dyn Output) -> Result<()> {
+    let git = GitCommand::new(false);
+
+    GlobalConfig::set_default_layout(&choices.layout)?;
+    output.result(&format!("Default layout: {}", choices.layout));
+
+    git.config_set_global(keys::AUTOCD, &choices.autocd.to_string())?;
+    output.result(&format!(
+        "cd into new worktrees: {}",
+        if choices.autocd { "on" } else { "off" }
+    ));
+    if choices.autocd && !crate::commands::activate::is_activated() {
+        output.info(&format!(
+            "  cd needs shell integration; enable it with `{}`",
+            crate::daft_cmd("activate")
+        ));
+    }
+
+    if let Some(shell) = choices.completions {
+        // A failed install should not lose the answers still to apply.
+        if let Err(e) = crate::commands::completions::install_for_shell(shell) {
+            output.warning(&format!("Could not install {shell} completions: {e:#}"));
+        }
+    }
+
+    match choices.update_cadence {
+        UpdateCadence::Never => git.config_set_global(keys::UPDATE_CHECK, "false")?,
+        cadence => {
+            git.config_set_global(keys::UPDATE_CHECK, "true")?;
+            let interval = if cadence == UpdateCadence::Weekly {
+                "7d"
+            } else {
+                "1d"
+            };
+            git.config_set_global(keys::UPDATE_CHECK_INTERVAL, interval)?;
+        }
+    }
+    output.result(&format!(
+        "Update checks: {}",
+        choices.update_cadence.label().to_lowercase()
+    ));
+
+    if let Some(dir) = &choices.register_under {
+        register_repos_under(dir, output)?;
+    }
+    Ok(())
+}
+
+/// Register every repository found under `dir` in the repo catalog.
+fn register_repos_under(dir: &Path, output: &mut dyn Output) -> Result<()> {
+    let catalog = crate::catalog::Catalog::open_rw().context("could not open the repo catalog")?;
+    let use_gitoxide = crate::DaftSettings::load_global()
+        .map(|s| s.use_gitoxide)
+        .unwrap_or(false);
+    let mut seen = BTreeSet::new();
+    for candidate in find_repos(dir, 2) {
+        let facts =
+            crate::core::worktree::remove_repo::resolve_repo(Some(&candidate), use_gitoxide)
+                .and_then(|target| {
+                    crate::catalog::gather_facts(
+                        &target.bare_git_dir,
+                        &target.project_root,
+                        None,
+                        None,
+                    )
+                });
+        let facts = match facts {
+            Ok(facts) => facts,
+            Err(e) => {
+                output.debug(&format!("skipping {}: {e:#}", candidate.display()));
+                continue;
+            }
+        };
+        // Worktrees of one repo share its identity; register it once.
+        if !seen.insert(facts.uuid.clone()) {
+            continue;
+        }
+        match catalog.register(&facts) {
+            Ok(outcome) => output.list_item(&format!("{} → {}", outcome.assigned_name, facts.path)),
+            Err(e) => output.warning(&format!("Could not register {}: {e:#}", facts.path)),
+        }
+    }
+    output.result(&format!(
+        "Registered {} repositor{} under {}",
+        seen.len(),
+        if seen.len() == 1 { "y" } else { "ies" },
+        dir.display()
+    ));
+    Ok(())
+}
+
+/// Directories under `dir`, at most `depth` levels down, that are a git
+/// repository or worktree root (they hold a `.git` entry). The search does
+/// not descend into a repository it found, or into hidden directories.
+fn find_repos(dir: &Path, depth: usize) -> Vec<PathBuf> {
+    if dir.join(".git").exists() {
+        return vec![dir.to_path_buf()];
+    }
+    if depth == 0 {
+        return Vec::new();
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut children: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+        .map(|entry| entry.path())
+        .collect();
+    children.sort();
+    children
+        .iter()
+        .flat_map(|child| find_repos(child, depth - 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_run_only_when_no_marker_exists() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = tmp.path().join("config.toml");
+        let catalog = tmp.path().join("catalog.db");
+        assert!(is_first_run(&[config.clone(), catalog.clone()]));
+
+        std::fs::write(&catalog, "").unwrap();
+        assert!(!is_first_run(&[config, catalog]));
+        assert!(
+            !is_first_run(&[]),
+            "unresolvable markers are not a first run"
+        );
+    }
+
+    #[test]
+    fn find_repos_stops_at_repo_roots_and_skips_hidden_dirs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        for dir in [
+            "api/.git",
+            "api/vendor/lib/.git",
+            "work/web/.git",
+            "work/deep/er/.git",
+            ".cache/tool/.git",
+        ] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        std::fs::create_dir_all(root.join("work/web.feature")).unwrap();
+        std::fs::write(root.join("work/web.feature/.git"), "gitdir: x\n").unwrap();
+
+        let found: Vec<PathBuf> = find_repos(root, 2)
+            .into_iter()
+            .map(|p| p.strip_prefix(root).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            found,
+            [
+                PathBuf::from("api"),
+                PathBuf::from("work/web"),
+                PathBuf::from("work/web.feature"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Command: `daft consolidate` — fold sibling clones of a repository into
+//! worktrees of one.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+
+use crate::core::consolidate::{self, CloneGroup, Outcome};
+use crate::core::size_walk;
+use crate::output::format::format_human_size;
+use crate::output::{CliOutput, Output};
+use crate::styles::{bold, dim, green, yellow};
+
+#[derive(Parser)]
+#[command(name = "daft-consolidate")]
+#[command(version = crate::VERSION)]
+#[command(about = "Turn separate clones of a repository into worktrees of one")]
+#[command(long_about = r#"
+Find the directories directly under DIR (default: the current directory)
+that are separate clones of the same repository, and turn all but one of
+them into worktrees that share a single object store.
+
+Clones are matched by the URL of their origin remote (or first remote), or
+by their root commit when they have no remote. The clone with the largest
+.git directory is kept as the primary; every other clone's branches, tags,
+remotes, and Git LFS objects are copied into it, and its .git directory is
+replaced by a link to the primary. Each checkout keeps its branch, its
+staged and unstaged changes, and its untracked files, and stays where it
+is.
+
+A converted clone's old .git directory is kept beside it, as
+.<name>.git-consolidate, until you confirm it can go; it still holds the
+branch reflogs. Answer the prompt at the end, pass --yes, or delete the
+directories yourself once the worktrees look right.
+
+A clone is left alone, with the reason shown, when converting it would
+lose work: stash entries, a rebase or merge in progress, worktrees or
+submodules of its own, refs other than branches, tags, and remote-tracking
+refs (such as notes), a tag that points elsewhere in the primary, hooks,
+info/exclude or info/attributes patterns, a sparse checkout, repository
+settings the primary doesn't share (remotes and branch upstreams are copied),
+or a branch that has diverged from the primary's (or is checked out in
+both).
+
+Use --dry-run to see the plan first. `daft adopt-worktree --move <path>`
+moves a converted worktree to where the layout would place it.
+"#)]
+pub struct Args {
+    /// Directory whose subdirectories are scanned for clones
+    #[arg(value_name = "DIR", default_value = ".")]
+    dir: PathBuf,
+
+    /// Show what would be consolidated without changing anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Delete converted clones' old .git directories without asking
+    #[arg(short = 'y', long)]
+    yes: bool,
+}
+
+pub fn run() -> Result<()> {
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    let dir = args
+        .dir
+        .canonicalize()
+        .with_context(|| format!("Cannot access {}", args.dir.display()))?;
+    let jobs = size_walk::resolve_jobs(None);
+    let groups = consolidate::discover(&dir, |dirs| {
+        size_walk::walk_all(dirs, None, jobs)
+            .into_iter()
+            .map(|size| size.unwrap_or(0))
+            .collect()
+    })?;
+    if groups.is_empty() {
+        output.info(&format!(
+            "No repository has more than one clone in {}",
+            dir.display()
+        ));
+        return Ok(());
+    }
+
+    let mut reclaimable = 0;
+    let mut parked = Vec::new();
+    for group in &groups {
+        reclaimable +=
+            consolidate_group(group, &dir, args.dry_run, jobs, &mut parked, &mut output)?;
+    }
+    if args.dry_run || parked.is_empty() {
+        return Ok(());
+    }
+
+    output.info("");
+    if args.yes || confirm_discard(parked.len()) {
+        for dir in &parked {
+            consolidate::discard_parked(dir)?;
+        }
+        output.success(&format!("Reclaimed {}", format_human_size(reclaimable)));
+    } else {
+        output.info("Kept the old .git directories of the converted clones:");
+        for path in &parked {
+            output.info(&format!("  {}", relative(path, &dir)));
+        }
+        output.info(&format!(
+            "Delete them once the worktrees look right to reclaim {}",
+            format_human_size(reclaimable)
+        ));
+    }
+    Ok(())
+}
+
+fn confirm_discard(count: usize) -> bool {
+    use crate::prompt::{PromptConfig, PromptOption, PromptResult, single_key_select};
+    eprint!(
+        "Delete the old .git {} now? [y/N] ",
+        if count == 1 {
+            "directory"
+        } else {
+            "directories"
+        }
+    );
+    let result = single_key_select(&PromptConfig {
+        options: vec![
+            PromptOption {
+                key: 'y',
+                label: "yes",
+                is_default: false,
+            },
+            PromptOption {
+                key: 'n',
+                label: "no",
+                is_default: true,
+            },
+        ],
+        cancel_message: None,
+    });
+    eprintln!();
+    matches!(result, PromptResult::Selected('y'))
+}
+
+/// Absorb one group's clones into its primary, adding the old `.git`
+/// directories it parked to `parked`. Returns the bytes deleting them frees.
+fn consolidate_group(
+    group: &CloneGroup,
+    dir: &Path,
+    dry_run: bool,
+    jobs: usize,
+    parked: &mut Vec<PathBuf>,
+    output: &mut dyn Output,
+) -> Result<u64> {
+    output.info(&format!(
+        "{} {} into {}",
+        bold(&group.identity),
+        dim(&format!("({} clones)", group.others.len() + 1)),
+        bold(&relative(&group.primary, dir))
+    ));
+
+    let primary_before = if dry_run {
+        0
+    } else {
+        git_dir_size(&group.primary, jobs)
+    };
+    let mut absorbed = Vec::new();
+    for clone in &group.others {
+        let name = relative(clone, dir);
+        let outcome = if dry_run {
+            let reasons = consolidate::blockers(&group.primary, clone)?;
+            if reasons.is_empty() {
+                output.info(&format!("  {} {name}", green("would convert")));
+                continue;
+            }
+            Outcome::Skipped(reasons)
+        } else {
+            let git_size = git_dir_size(clone, jobs);
+            match consolidate::absorb(&group.primary, clone) {
+                Ok(outcome) => {
+                    if let Outcome::Absorbed { parked: old } = &outcome {
+                        absorbed.push(git_size);
+                        parked.push(old.clone());
+                    }
+                    outcome
+                }
+                Err(e) => Outcome::Skipped(vec![format!("{e:#}")]),
+            }
+        };
+        match outcome {
+            Outcome::Absorbed { .. } => output.info(&format!("  {} {name}", green("converted"))),
+            Outcome::Skipped(reasons) => output.info(&format!(
+                "  {} {name} {}",
+                yellow("skipped"),
+                dim(&format!("({})", reasons.join("; ")))
+            )),
+        }
+    }
+    if absorbed.is_empty() {
+        return Ok(0);
+    }
+
+    // The clones' stores go once the parked directories are deleted; the
+    // primary grew by whatever they held that it didn't.
+    let primary_after = git_dir_size(&group.primary, jobs);
+    let freed: u64 = absorbed.iter().sum();
+    Ok(freed.saturating_sub(primary_after.saturating_sub(primary_before)))
+}
+
+fn git_dir_size(repo: &Path, jobs: usize) -> u64 {
+    size_walk::walk_all(&[repo.join(".git")], None, jobs)[0].unwrap_or(0)
+}
+
+fn relative(path: &Path, dir: &Path) -> String {
+    path.strip_prefix(dir).unwrap_or(path).display().to_string()
+}
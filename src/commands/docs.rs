@@ -9,9 +9,10 @@ use clap::{Command, CommandFactory};
 use std::path::Path;
 
 use crate::commands::{
-    carry, checkout, clone, config, doctor, exec, fetch, file, flow_adopt, flow_eject, hooks, init,
-    install, layout, list, merge, multi_remote, prune, push, release_notes, repo, run, shared,
-    shell_init, shortcuts, skill, sync, worktree_branch,
+    adopt_worktree, auth, backup, carry, checkout, clone, clone_all, config, consolidate, doctor,
+    env, exec, fetch, file, flow_adopt, flow_eject, hooks, init, install, layout, list, merge,
+    multi_remote, pin, prune, push, release_notes, remote, repo, run, shared, shell_init,
+    shortcuts, skill, stats, sync, template, whats_new, which, workspace, worktree_branch,
 };
 use crate::styles;
 
@@ -24,227 +25,153 @@ enum Mode {
     GitDaft,
 }
 
-/// How a category's commands are rendered.
-enum CategoryLayout {
-    /// One command per line with aligned `about` descriptions.
-    List,
-    /// Commands joined on a single line; `about` strings are omitted.
-    Inline,
-}
-
 /// A category of commands with a title and list of commands.
 struct CommandCategory {
     title: &'static str,
     commands: Vec<CommandEntry>,
-    layout: CategoryLayout,
 }
 
 /// A single command entry with its display name and clap Command.
 struct CommandEntry {
     display_name: &'static str,
     command: Command,
+    /// Shown with its description in the default `daft --help`; the other
+    /// entries of the category are only named, and described by `--all`.
+    common: bool,
+    /// Not settled yet — flags and output may still change.
+    experimental: bool,
+    /// Shortcut aliases (`gwtco`, ...) that run this command.
+    shortcuts: Vec<&'static str>,
+}
+
+fn entry(display_name: &'static str, command: Command) -> CommandEntry {
+    CommandEntry {
+        display_name,
+        command,
+        common: false,
+        experimental: false,
+        shortcuts: Vec::new(),
+    }
+}
+
+impl CommandEntry {
+    fn common(mut self) -> Self {
+        self.common = true;
+        self
+    }
+
+    fn experimental(mut self) -> Self {
+        self.experimental = true;
+        self
+    }
+
+    /// The shortcuts of the Git-style command this verb runs (see
+    /// [`crate::shortcuts::aliases_for`]).
+    fn shortcuts_of(mut self, git_command: &str, creates_branch: bool) -> Self {
+        self.shortcuts = crate::shortcuts::aliases_for(git_command, creates_branch);
+        self
+    }
 }
 
-/// Get category layout for `daft` invocation — daft-verb style, everyday
-/// commands at the top.
+/// Get category layout for `daft` invocation — daft verbs grouped by the
+/// task they serve, everyday commands first within each group.
 fn get_daft_categories() -> Vec<CommandCategory> {
     vec![
         CommandCategory {
-            title: "work on branches (each branch gets its own directory)",
-            layout: CategoryLayout::List,
-            commands: vec![
-                CommandEntry {
-                    display_name: "go",
-                    command: checkout::GoArgs::command(),
-                },
-                CommandEntry {
-                    display_name: "start",
-                    command: checkout::StartArgs::command(),
-                },
-            ],
-        },
-        CommandCategory {
-            title: "maintain your worktrees",
-            layout: CategoryLayout::List,
+            title: "Start: set up a worktree-based repository",
             commands: vec![
-                CommandEntry {
-                    display_name: "list",
-                    command: list::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "rename",
-                    command: worktree_branch::RenameArgs::command(),
-                },
-                CommandEntry {
-                    display_name: "remove",
-                    command: worktree_branch::RemoveArgs::command(),
-                },
-                CommandEntry {
-                    display_name: "update",
-                    command: fetch::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "prune",
-                    command: prune::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "sync",
-                    command: sync::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "push",
-                    command: push::Args::command(),
-                },
+                entry("clone", clone::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-clone", false),
+                entry("init", init::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-init", false),
+                entry("adopt", flow_adopt::Args::command()),
+                entry("eject", flow_eject::Args::command()),
+                entry("clone-all", clone_all::Args::command()),
+                entry("consolidate", consolidate::Args::command()).experimental(),
             ],
         },
         CommandCategory {
-            title: "share changes across worktrees",
-            layout: CategoryLayout::List,
-            commands: vec![
-                CommandEntry {
-                    display_name: "carry",
-                    command: carry::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "merge",
-                    command: merge::Args::command(),
-                },
-            ],
-        },
-        CommandCategory {
-            title: "run commands across worktrees",
-            layout: CategoryLayout::List,
-            commands: vec![CommandEntry {
-                display_name: "exec",
-                command: exec::Args::command(),
-            }],
-        },
-        CommandCategory {
-            title: "run project tasks",
-            layout: CategoryLayout::List,
-            commands: vec![CommandEntry {
-                display_name: "run",
-                command: run::Args::command(),
-            }],
-        },
-        CommandCategory {
-            title: "start a worktree-based repository",
-            layout: CategoryLayout::List,
+            title: "Branch: work on branches, each in its own directory",
             commands: vec![
-                CommandEntry {
-                    display_name: "clone",
-                    command: clone::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "init",
-                    command: init::Args::command(),
-                },
+                entry("go", checkout::GoArgs::command())
+                    .common()
+                    .shortcuts_of("git-worktree-checkout", false),
+                entry("start", checkout::StartArgs::command())
+                    .common()
+                    .shortcuts_of("git-worktree-checkout", true),
+                entry("carry", carry::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-carry", false),
+                entry("merge", merge::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-merge", false),
+                entry("rename", worktree_branch::RenameArgs::command()),
+                entry("remove", worktree_branch::RemoveArgs::command())
+                    .shortcuts_of("git-worktree-branch", false),
+                entry("adopt-worktree", adopt_worktree::Args::command()),
             ],
         },
         CommandCategory {
-            title: "manage repositories",
-            layout: CategoryLayout::List,
+            title: "Maintain: keep worktrees and branches current and tidy",
             commands: vec![
-                CommandEntry {
-                    display_name: "repo add",
-                    command: repo::add::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "repo info",
-                    command: repo::info::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "repo install",
-                    command: repo::install::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "repo link",
-                    command: repo::link::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "repo list",
-                    command: repo::list::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "repo remove",
-                    command: repo::remove::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "repo unlink",
-                    command: repo::unlink::Args::command(),
-                },
+                entry("list", list::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-list", false),
+                entry("update", fetch::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-fetch", false),
+                entry("prune", prune::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-prune", false),
+                entry("sync", sync::Args::command())
+                    .common()
+                    .shortcuts_of("git-worktree-sync", false),
+                entry("exec", exec::Args::command()).common(),
+                entry("push", push::Args::command()).shortcuts_of("git-worktree-push", false),
+                entry("which", which::Args::command()),
+                entry("run", run::Args::command()),
+                entry("pin", pin::Args::command()),
+                entry("backup", backup::Args::command()),
+                entry("stats", stats::Args::command()),
             ],
         },
         CommandCategory {
-            title: "manage the agent skill",
-            layout: CategoryLayout::List,
+            title: "Configure: tune daft and share setup across worktrees",
             commands: vec![
-                CommandEntry {
-                    display_name: "skill install",
-                    command: skill::install::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "skill uninstall",
-                    command: skill::uninstall::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "skill show",
-                    command: skill::show::Args::command(),
-                },
+                entry("hooks", hooks::Args::command()).common(),
+                entry("layout", layout::LayoutArgs::command()).common(),
+                entry("shell-init", shell_init::Args::command()).common(),
+                entry("doctor", doctor::Args::command()).common(),
+                entry("config", config::remote_sync::Args::command()),
+                entry("shared", shared::Args::command()),
+                entry("template", template::Args::command()),
+                entry("activate shortcuts", shortcuts::Args::command()),
+                entry("install", install::Args::command()),
+                entry("env", env::Args::command()),
+                entry("multi-remote", multi_remote::Args::command()),
+                entry("file merge", file::merge::Args::command()),
+                entry("release-notes", release_notes::Args::command()),
+                entry("whats-new", whats_new::Args::command()),
             ],
         },
         CommandCategory {
-            title: "share configuration across worktrees",
-            layout: CategoryLayout::List,
-            commands: vec![CommandEntry {
-                display_name: "shared",
-                command: shared::Args::command(),
-            }],
-        },
-        CommandCategory {
-            title: "manage daft configuration",
-            layout: CategoryLayout::Inline,
+            title: "Integrate: connect repositories, forges, and agents",
             commands: vec![
-                CommandEntry {
-                    display_name: "install",
-                    command: install::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "file merge",
-                    command: file::merge::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "config",
-                    command: config::remote_sync::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "hooks",
-                    command: hooks::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "layout",
-                    command: layout::LayoutArgs::command(),
-                },
-                CommandEntry {
-                    display_name: "multi-remote",
-                    command: multi_remote::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "shell-init",
-                    command: shell_init::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "activate shortcuts",
-                    command: shortcuts::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "doctor",
-                    command: doctor::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "release-notes",
-                    command: release_notes::Args::command(),
-                },
+                entry("repo list", repo::list::Args::command()).common(),
+                entry("repo add", repo::add::Args::command()),
+                entry("repo info", repo::info::Args::command()),
+                entry("repo install", repo::install::Args::command()),
+                entry("repo link", repo::link::Args::command()),
+                entry("repo remove", repo::remove::Args::command()),
+                entry("repo unlink", repo::unlink::Args::command()),
+                entry("workspace", workspace::Args::command()).experimental(),
+                entry("auth", auth::Args::command()),
+                entry("remote", remote::Args::command()),
+                entry("skill install", skill::install::Args::command()).common(),
+                entry("skill uninstall", skill::uninstall::Args::command()),
+                entry("skill show", skill::show::Args::command()),
             ],
         },
     ]
@@ -256,190 +183,91 @@ fn get_git_daft_categories() -> Vec<CommandCategory> {
     vec![
         CommandCategory {
             title: "start a worktree-based repository",
-            layout: CategoryLayout::List,
             commands: vec![
-                CommandEntry {
-                    display_name: "worktree-clone",
-                    command: clone::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-init",
-                    command: init::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-flow-adopt",
-                    command: flow_adopt::Args::command(),
-                },
+                entry("worktree-clone", clone::Args::command()),
+                entry("worktree-init", init::Args::command()),
+                entry("worktree-flow-adopt", flow_adopt::Args::command()),
             ],
         },
         CommandCategory {
             title: "work on branches (each branch gets its own directory)",
-            layout: CategoryLayout::List,
-            commands: vec![CommandEntry {
-                display_name: "worktree-checkout",
-                command: checkout::Args::command(),
-            }],
+            commands: vec![entry("worktree-checkout", checkout::Args::command())],
         },
         CommandCategory {
             title: "share changes across worktrees",
-            layout: CategoryLayout::List,
             commands: vec![
-                CommandEntry {
-                    display_name: "worktree-carry",
-                    command: carry::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-merge",
-                    command: merge::Args::command(),
-                },
+                entry("worktree-carry", carry::Args::command()),
+                entry("worktree-merge", merge::Args::command()),
             ],
         },
         CommandCategory {
             title: "run commands across worktrees",
-            layout: CategoryLayout::List,
-            commands: vec![CommandEntry {
-                display_name: "worktree-exec",
-                command: exec::Args::command(),
-            }],
+            commands: vec![entry("worktree-exec", exec::Args::command())],
         },
         CommandCategory {
             title: "maintain your worktrees",
-            layout: CategoryLayout::List,
             commands: vec![
-                CommandEntry {
-                    display_name: "worktree-list",
-                    command: list::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-branch",
-                    command: worktree_branch::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-prune",
-                    command: prune::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-fetch",
-                    command: fetch::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "sync",
-                    command: sync::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-push",
-                    command: push::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "worktree-flow-eject",
-                    command: flow_eject::Args::command(),
-                },
+                entry("worktree-list", list::Args::command()),
+                entry("daft which", which::Args::command()),
+                entry("worktree-branch", worktree_branch::Args::command()),
+                entry("worktree-prune", prune::Args::command()),
+                entry("adopt-worktree", adopt_worktree::Args::command()),
+                entry("worktree-fetch", fetch::Args::command()),
+                entry("sync", sync::Args::command()),
+                entry("worktree-push", push::Args::command()),
+                entry("daft pin", pin::Args::command()),
+                entry("worktree-flow-eject", flow_eject::Args::command()),
             ],
         },
         CommandCategory {
             title: "manage repositories",
-            layout: CategoryLayout::List,
             commands: vec![
-                CommandEntry {
-                    display_name: "daft repo add",
-                    command: repo::add::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft repo info",
-                    command: repo::info::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft repo install",
-                    command: repo::install::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft repo link",
-                    command: repo::link::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft repo list",
-                    command: repo::list::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft repo remove",
-                    command: repo::remove::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft repo unlink",
-                    command: repo::unlink::Args::command(),
-                },
+                entry("daft repo add", repo::add::Args::command()),
+                entry("daft repo info", repo::info::Args::command()),
+                entry("daft repo install", repo::install::Args::command()),
+                entry("daft repo link", repo::link::Args::command()),
+                entry("daft repo list", repo::list::Args::command()),
+                entry("daft repo remove", repo::remove::Args::command()),
+                entry("daft repo unlink", repo::unlink::Args::command()),
+                entry("daft workspace", workspace::Args::command()),
             ],
         },
         CommandCategory {
             title: "manage the agent skill",
-            layout: CategoryLayout::List,
             commands: vec![
-                CommandEntry {
-                    display_name: "daft skill install",
-                    command: skill::install::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft skill uninstall",
-                    command: skill::uninstall::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft skill show",
-                    command: skill::show::Args::command(),
-                },
+                entry("daft skill install", skill::install::Args::command()),
+                entry("daft skill uninstall", skill::uninstall::Args::command()),
+                entry("daft skill show", skill::show::Args::command()),
             ],
         },
         CommandCategory {
             title: "share configuration across worktrees",
-            layout: CategoryLayout::List,
-            commands: vec![CommandEntry {
-                display_name: "daft shared",
-                command: shared::Args::command(),
-            }],
+            commands: vec![
+                entry("daft shared", shared::Args::command()),
+                entry("daft template", template::Args::command()),
+            ],
         },
         CommandCategory {
             title: "manage daft configuration",
-            layout: CategoryLayout::List,
             commands: vec![
-                CommandEntry {
-                    display_name: "daft install",
-                    command: install::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft file merge",
-                    command: file::merge::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft hooks",
-                    command: hooks::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft layout",
-                    command: layout::LayoutArgs::command(),
-                },
-                CommandEntry {
-                    display_name: "daft multi-remote",
-                    command: multi_remote::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft activate shortcuts",
-                    command: shortcuts::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft shell-init",
-                    command: shell_init::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft config",
-                    command: config::remote_sync::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft doctor",
-                    command: doctor::Args::command(),
-                },
-                CommandEntry {
-                    display_name: "daft release-notes",
-                    command: release_notes::Args::command(),
-                },
+                entry("daft install", install::Args::command()),
+                entry("daft file merge", file::merge::Args::command()),
+                entry("daft hooks", hooks::Args::command()),
+                entry("daft layout", layout::LayoutArgs::command()),
+                entry("daft multi-remote", multi_remote::Args::command()),
+                entry("daft activate shortcuts", shortcuts::Args::command()),
+                entry("daft shell-init", shell_init::Args::command()),
+                entry("daft env", env::Args::command()),
+                entry("daft config", config::remote_sync::Args::command()),
+                entry("daft doctor", doctor::Args::command()),
+                entry("daft auth", auth::Args::command()),
+                entry("daft remote", remote::Args::command()),
+                entry("daft clone-all", clone_all::Args::command()),
+                entry("daft consolidate", consolidate::Args::command()),
+                entry("daft stats", stats::Args::command()),
+                entry("daft backup", backup::Args::command()),
+                entry("daft release-notes", release_notes::Args::command()),
+                entry("daft whats-new", whats_new::Args::command()),
             ],
         },
     ]
@@ -452,17 +280,22 @@ fn get_about(cmd: &Command) -> String {
         .unwrap_or_else(|| "(no description)".to_string())
 }
 
-/// Maximum display-name length across `List`-layout categories (used for
-/// column alignment). `Inline` categories are skipped — their names are
-/// joined on a single line and don't participate in column alignment.
-fn max_list_display_name_len(categories: &[CommandCategory]) -> usize {
-    categories
-        .iter()
-        .filter(|c| matches!(c.layout, CategoryLayout::List))
-        .flat_map(|c| c.commands.iter())
-        .map(|e| e.display_name.len())
-        .max()
-        .unwrap_or(20)
+/// Maximum display-name length across `entries` (used for column alignment).
+fn max_display_name_len<'a>(entries: impl Iterator<Item = &'a CommandEntry>) -> usize {
+    entries.map(|e| e.display_name.len()).max().unwrap_or(20)
+}
+
+/// The description column of a `daft --help` line: the command's `about`,
+/// then its experimental marker and shortcut aliases.
+fn describe(entry: &CommandEntry) -> String {
+    let mut text = get_about(&entry.command);
+    if entry.experimental {
+        text.push_str(" (experimental)");
+    }
+    if !entry.shortcuts.is_empty() {
+        text.push_str(&format!(" [shortcuts: {}]", entry.shortcuts.join(", ")));
+    }
+    text
 }
 
 /// Wrap text in bold+underline (clap's `header`/`usage` style) when color is enabled.
@@ -490,11 +323,9 @@ fn bold(text: &str, use_color: bool) -> String {
 }
 
 pub fn run() -> Result<()> {
+    let argv = crate::cli::argv();
     // Detect how we were invoked
-    let program_path = crate::cli::argv()
-        .first()
-        .cloned()
-        .unwrap_or_else(|| "daft".to_string());
+    let program_path = argv.first().cloned().unwrap_or_else(|| "daft".to_string());
     let program_name = Path::new(&program_path)
         .file_name()
         .and_then(|n| n.to_str())
@@ -505,19 +336,28 @@ pub fn run() -> Result<()> {
     } else {
         Mode::Daft
     };
+    let all = argv.iter().skip(1).any(|a| a == "--all");
 
     match mode {
-        Mode::Daft => render_daft(),
+        Mode::Daft => render_daft(all),
         Mode::GitDaft => render_git_daft(),
     }
 }
 
-/// Render daft-style help (invoked as `daft`): short verbs, everyday
-/// commands first, clap-matching styling.
-fn render_daft() -> Result<()> {
+/// Render daft-style help (invoked as `daft`): verbs grouped by task,
+/// clap-matching styling.
+///
+/// By default each group describes its common commands and names the rest on
+/// one line; `all` describes every command.
+fn render_daft(all: bool) -> Result<()> {
     let use_color = styles::colors_enabled();
     let categories = get_daft_categories();
-    let max_len = max_list_display_name_len(&categories);
+    let max_len = max_display_name_len(
+        categories
+            .iter()
+            .flat_map(|c| c.commands.iter())
+            .filter(|e| all || e.common),
+    );
 
     println!(
         "{} daft <command> [<args>]",
@@ -525,31 +365,38 @@ fn render_daft() -> Result<()> {
     );
 
     println!();
-    println!("These are common daft commands used in various situations:");
+    if all {
+        println!("All daft commands, grouped by task:");
+    } else {
+        println!("These are common daft commands, grouped by task:");
+    }
 
     for category in &categories {
         println!();
         println!("{}", bold_underline(category.title, use_color));
 
-        match category.layout {
-            CategoryLayout::List => {
-                for entry in &category.commands {
-                    let about = get_about(&entry.command);
-                    // Pad from raw display_name length — ANSI escapes are
-                    // zero-width visually but would otherwise skew `{:width$}`.
-                    let pad = " ".repeat(max_len.saturating_sub(entry.display_name.len()));
-                    let name = bold(entry.display_name, use_color);
-                    println!("   {name}{pad}   {about}");
-                }
-            }
-            CategoryLayout::Inline => {
-                let names: Vec<String> = category
-                    .commands
-                    .iter()
-                    .map(|e| bold(e.display_name, use_color))
-                    .collect();
-                println!("   {}", names.join(", "));
-            }
+        let (described, named): (Vec<&CommandEntry>, Vec<&CommandEntry>) =
+            category.commands.iter().partition(|e| all || e.common);
+        for entry in described {
+            // Pad from raw display_name length — ANSI escapes are
+            // zero-width visually but would otherwise skew `{:width$}`.
+            let pad = " ".repeat(max_len.saturating_sub(entry.display_name.len()));
+            let name = bold(entry.display_name, use_color);
+            println!("   {name}{pad}   {}", describe(entry));
+        }
+        if !named.is_empty() {
+            let names: Vec<String> = named
+                .iter()
+                .map(|e| {
+                    let name = bold(e.display_name, use_color);
+                    if e.experimental {
+                        format!("{name} (experimental)")
+                    } else {
+                        name
+                    }
+                })
+                .collect();
+            println!("   also: {}", names.join(", "));
         }
     }
 
@@ -565,6 +412,12 @@ fn render_daft() -> Result<()> {
         "'daft {} --help' to read about a specific command.",
         bold("<command>", use_color)
     );
+    if !all {
+        println!(
+            "'daft --help {}' describes every command.",
+            bold("--all", use_color)
+        );
+    }
     println!("Equivalent 'git worktree-<command>' forms also exist — run 'git daft' to see them.");
     println!("See https://github.com/avihut/daft for documentation.");
 
@@ -582,28 +435,20 @@ fn render_git_daft() -> Result<()> {
     println!("These are common daft commands used in various situations:");
 
     let categories = get_git_daft_categories();
-    let max_len = max_list_display_name_len(&categories);
+    let max_len = max_display_name_len(categories.iter().flat_map(|c| c.commands.iter()));
 
     for category in &categories {
         println!();
         println!("{}", category.title);
 
-        match category.layout {
-            CategoryLayout::List => {
-                for entry in &category.commands {
-                    let about = get_about(&entry.command);
-                    println!(
-                        "   {:width$}   {}",
-                        entry.display_name,
-                        about,
-                        width = max_len
-                    );
-                }
-            }
-            CategoryLayout::Inline => {
-                let names: Vec<&str> = category.commands.iter().map(|e| e.display_name).collect();
-                println!("   {}", names.join(", "));
-            }
+        for entry in &category.commands {
+            let about = get_about(&entry.command);
+            println!(
+                "   {:width$}   {}",
+                entry.display_name,
+                about,
+                width = max_len
+            );
         }
     }
 
@@ -7,8 +7,8 @@ use anyhow::Result;
 use clap::Parser;
 
 use crate::doctor::{
-    CheckCategory, CheckStatus, DoctorSummary, hooks_checks, installation, repository,
-    status_symbol,
+    CheckCategory, CheckSelection, CheckStatus, DoctorSummary, hooks_checks, installation,
+    repository, status_symbol,
 };
 use crate::output::{CliOutput, Output, OutputConfig};
 use crate::styles::{bold, dim, green, red, yellow};
@@ -21,18 +21,29 @@ fn long_about() -> String {
         "and hooks configuration. Reports issues with actionable suggestions.",
         "",
         "When run outside a git repository, only installation checks are performed.",
-        "Inside a daft-managed repository, repository and hooks checks run too.",
+        "Inside a git repository, repository and hooks checks run too; a plain",
+        "repository daft does not manage is reported as such, with the command",
+        "that adopts it.",
         "",
         "The --fix flag auto-repairs: missing command symlinks, missing shortcut",
         "symlinks for partially-installed styles, orphaned worktree entries,",
         "incorrect fetch refspecs, missing remote HEAD, non-executable hooks,",
-        "and deprecated hook names. Issues requiring manual intervention (binary",
-        "not in PATH, git not installed, shell integration) show suggestions only.",
+        "deprecated hook names, and an unreadable trust registry (backed up",
+        "first). Issues requiring manual intervention (binary not in PATH, git",
+        "not installed, shell integration) show suggestions only.",
         "",
         "Use --fix --dry-run to preview planned actions with pre-flight validation.",
         "Each action shows whether it would succeed or fail (e.g., directory not",
         "writable, conflicting files). Actions marked + would succeed; actions",
         "marked x would fail, with the reason shown below.",
+        "",
+        "Use --only and --skip to run a subset of checks. Each takes a",
+        "comma-separated list of categories (installation, forge, catalog,",
+        "repository, hooks) or check names as shown in the report, matched",
+        "case-insensitively (\"Shell integration\" or shell-integration). The exit",
+        "code reflects only the selected checks, and --fix only repairs those, so",
+        "a script can gate on the checks it cares about. A name that matches no",
+        "category and no check that ran is an error.",
     ]
     .join("\n")
 }
@@ -68,6 +79,24 @@ pub struct Args {
         help = "Check every cataloged repository, not just the current one"
     )]
     all_repos: bool,
+
+    /// Run only these categories or checks (comma-separated)
+    #[arg(
+        long,
+        value_name = "CHECKS",
+        value_delimiter = ',',
+        help = "Run only these categories or checks (comma-separated)"
+    )]
+    only: Vec<String>,
+
+    /// Skip these categories or checks (comma-separated)
+    #[arg(
+        long,
+        value_name = "CHECKS",
+        value_delimiter = ',',
+        help = "Skip these categories or checks (comma-separated)"
+    )]
+    skip: Vec<String>,
 }
 
 pub fn run() -> Result<()> {
@@ -76,7 +105,8 @@ pub fn run() -> Result<()> {
     // Doctor manages its own quiet/verbose filtering; Output is just the print sink.
     let mut output = CliOutput::new(OutputConfig::new(false, false));
 
-    let mut categories = collect_categories(args.all_repos);
+    let selection = CheckSelection::new(&args.only, &args.skip);
+    let mut categories = collect_categories(args.all_repos, &selection)?;
 
     // Apply fixes if requested
     if args.fix {
@@ -86,7 +116,12 @@ pub fn run() -> Result<()> {
         }
         apply_fixes(&categories, &mut output);
         // Re-run checks after fixes
-        categories = collect_categories(args.all_repos);
+        categories = collect_categories(args.all_repos, &selection)?;
+    }
+
+    if categories.is_empty() {
+        output.info(&dim("None of the selected checks apply here."));
+        return Ok(());
     }
 
     // Display results
@@ -98,21 +133,30 @@ pub fn run() -> Result<()> {
     print_summary(&summary, &mut output);
 
     if summary.has_failures() {
-        std::process::exit(1);
+        crate::deprecations::exit(1);
     }
 
     Ok(())
 }
 
-/// Assemble the full check-category list: installation + catalog always,
+/// Assemble the check-category list: installation + catalog always,
 /// then repository/hooks for the current repo — or, with `--all-repos`,
-/// for every live catalog entry (per-repo titled categories).
-fn collect_categories(all_repos: bool) -> Vec<CheckCategory> {
-    let mut categories = vec![
-        run_installation_checks(),
-        crate::doctor::forge_checks::run_forge_checks(),
-        crate::doctor::catalog_checks::run_catalog_checks(),
-    ];
+/// for every live catalog entry (per-repo titled categories). Categories
+/// `selection` rules out are not run; the rest are narrowed to the selected
+/// checks.
+fn collect_categories(all_repos: bool, selection: &CheckSelection) -> Result<Vec<CheckCategory>> {
+    let mut categories = Vec::new();
+    if selection.runs_category("Installation") {
+        categories.push(run_installation_checks());
+    }
+    if selection.runs_category("Forge integration") {
+        categories.push(crate::doctor::forge_checks::run_forge_checks());
+    }
+    if selection.runs_category("Catalog") {
+        categories.push(crate::doctor::catalog_checks::run_catalog_checks());
+    }
+    let run_repository = selection.runs_category("Repository");
+    let run_hooks = selection.runs_category("Hooks");
 
     if all_repos {
         let rows = crate::catalog::Catalog::open_ro()
@@ -131,12 +175,16 @@ fn collect_categories(all_repos: bool) -> Vec<CheckCategory> {
                 continue;
             }
             if let Some(ctx) = repository::get_repo_context() {
-                let mut repo_cat = run_repository_checks(&ctx);
-                repo_cat.title = format!("Repository — {}", row.name);
-                categories.push(repo_cat);
-                let mut hooks_cat = run_hooks_checks(&ctx);
-                hooks_cat.title = format!("Hooks — {}", row.name);
-                categories.push(hooks_cat);
+                if run_repository {
+                    let mut repo_cat = run_repository_checks(&ctx);
+                    repo_cat.title = format!("Repository — {}", row.name);
+                    categories.push(repo_cat);
+                }
+                if run_hooks {
+                    let mut hooks_cat = run_hooks_checks(&ctx);
+                    hooks_cat.title = format!("Hooks — {}", row.name);
+                    categories.push(hooks_cat);
+                }
             }
             if let Some(ref dir) = original {
                 let _ = crate::utils::change_directory(dir);
@@ -145,12 +193,18 @@ fn collect_categories(all_repos: bool) -> Vec<CheckCategory> {
         if let Some(ref dir) = original {
             let _ = crate::utils::change_directory(dir);
         }
-    } else if let Some(ref ctx) = repository::get_repo_context() {
-        categories.push(run_repository_checks(ctx));
-        categories.push(run_hooks_checks(ctx));
+    } else if (run_repository || run_hooks)
+        && let Some(ref ctx) = repository::get_repo_context()
+    {
+        if run_repository {
+            categories.push(run_repository_checks(ctx));
+        }
+        if run_hooks {
+            categories.push(run_hooks_checks(ctx));
+        }
     }
 
-    categories
+    selection.apply(categories).map_err(anyhow::Error::msg)
 }
 
 fn run_installation_checks() -> CheckCategory {
@@ -162,6 +216,7 @@ fn run_installation_checks() -> CheckCategory {
         installation::check_man_pages(),
         installation::check_agent_skill(),
         installation::check_shell_integration(),
+        installation::check_state_files(),
     ];
 
     // Shell wrappers check (only if shell integration is configured)
@@ -186,7 +241,9 @@ fn run_repository_checks(ctx: &repository::RepoContext) -> CheckCategory {
         repository::check_daft_config(ctx),
         repository::check_worktree_layout(ctx),
         repository::check_worktree_consistency(ctx),
+        repository::check_nesting(ctx),
         repository::check_worktree_identity_drift(ctx),
+        repository::check_git_exclude_block(ctx),
         repository::check_fetch_refspec(ctx),
         repository::check_remote_head(ctx),
         repository::check_remote_sync_config(ctx),
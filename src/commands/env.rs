@@ -0,0 +1,242 @@
+//! Command: `daft env` — print daft's resolved context for scripts.
+
+use anyhow::Result;
+use clap::Parser;
+use serde::Serialize;
+
+use crate::commands::shell_init::Shell;
+use crate::core::global_config::GlobalConfig;
+use crate::core::layout::detect::detect_layout;
+use crate::core::layout::resolver::{LayoutResolutionContext, LayoutSource, resolve_layout};
+use crate::core::repo::{get_current_branch, get_git_common_dir, get_project_root};
+use crate::hooks::{TrustDatabase, yaml_config_loader};
+use crate::output::{
+    CliOutput, Output,
+    emit::{self, EmitArgs, EmitPayload},
+};
+use crate::settings::DaftSettings;
+use crate::{get_current_worktree_path, is_git_repository};
+
+#[derive(Parser)]
+#[command(name = "daft-env")]
+#[command(version = crate::VERSION)]
+#[command(about = "Print daft's context for the current worktree")]
+#[command(long_about = r#"
+Print the context daft resolves for the current directory — project root,
+git common dir, current worktree, branch, remote, layout, and default
+branch — so scripts can use it instead of reassembling it from
+`git rev-parse` calls.
+
+By default the values are printed as shell exports, using the same
+variable names hooks receive:
+
+    eval "$(daft env)"
+    daft env --shell fish | source
+
+`--dotenv` prints KEY=value lines for tools that read .env files, and
+`--format json` (or yaml, toon, markdown, or a `--template`) prints a
+document with lowercase keys. Values that don't apply — the worktree and
+branch at a container root, a detached HEAD's branch, an unresolved
+layout or default branch — are empty in exports and null in documents.
+"#)]
+pub struct Args {
+    /// Shell syntax for the exports
+    #[arg(long, value_enum, value_name = "SHELL", default_value = "bash")]
+    shell: Shell,
+
+    /// Print KEY=value lines instead of shell exports
+    #[arg(long, conflicts_with_all = ["shell", "format", "template"])]
+    dotenv: bool,
+
+    #[command(flatten)]
+    emit: EmitArgs,
+}
+
+/// The resolved context.
+#[derive(Debug, Clone, Serialize)]
+struct DaftEnv {
+    project_root: String,
+    git_dir: String,
+    worktree_path: Option<String>,
+    branch: Option<String>,
+    remote: String,
+    layout: Option<String>,
+    default_branch: Option<String>,
+}
+
+impl DaftEnv {
+    /// `(variable, value)` pairs for exports and dotenv, named like the hook
+    /// environment.
+    fn vars(&self) -> [(&'static str, &str); 7] {
+        fn or_empty(v: &Option<String>) -> &str {
+            v.as_deref().unwrap_or("")
+        }
+        [
+            ("DAFT_PROJECT_ROOT", self.project_root.as_str()),
+            ("DAFT_GIT_DIR", self.git_dir.as_str()),
+            ("DAFT_WORKTREE_PATH", or_empty(&self.worktree_path)),
+            ("DAFT_BRANCH_NAME", or_empty(&self.branch)),
+            ("DAFT_REMOTE", self.remote.as_str()),
+            ("DAFT_LAYOUT", or_empty(&self.layout)),
+            ("DAFT_DEFAULT_BRANCH", or_empty(&self.default_branch)),
+        ]
+    }
+}
+
+pub fn run() -> Result<()> {
+    // Skip argv[0] so clap sees "env" as the program name (same shape as
+    // `daft shell-init`).
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    if !is_git_repository()? {
+        anyhow::bail!("Not inside a Git repository");
+    }
+    let env = resolve()?;
+
+    if args.emit.is_structured() {
+        let payload = EmitPayload::Document(serde_json::to_value(&env)?);
+        return emit::emit_and_handle("env", payload, &args.emit, &mut std::io::stdout())
+            .map_err(|e| anyhow::anyhow!("{e}"));
+    }
+
+    let text = if args.dotenv {
+        render_dotenv(&env)
+    } else {
+        render_exports(&env, &args.shell)
+    };
+    output.raw(text.trim_end());
+    Ok(())
+}
+
+fn resolve() -> Result<DaftEnv> {
+    let settings = DaftSettings::load()?;
+    let project_root = get_project_root()?;
+    let git_dir = get_git_common_dir()?;
+    let worktree = get_current_worktree_path().ok();
+    let branch = worktree.as_ref().and_then(|_| get_current_branch().ok());
+    let default_branch = crate::core::remote::local_default_branch(&project_root, &settings.remote);
+
+    Ok(DaftEnv {
+        layout: resolved_layout(&git_dir, worktree.as_deref()),
+        project_root: project_root.display().to_string(),
+        git_dir: git_dir.display().to_string(),
+        worktree_path: worktree.map(|p| p.display().to_string()),
+        branch,
+        remote: settings.remote,
+        default_branch,
+    })
+}
+
+/// The layout `daft layout show` reports, by name; `None` when unresolved.
+fn resolved_layout(
+    git_dir: &std::path::Path,
+    worktree: Option<&std::path::Path>,
+) -> Option<String> {
+    let global_config = GlobalConfig::load().unwrap_or_default();
+    let trust_db = TrustDatabase::load().unwrap_or_default();
+    let yaml_layout = worktree
+        .and_then(|wt| yaml_config_loader::load_merged_config(wt).ok().flatten())
+        .and_then(|cfg| cfg.layout);
+    let repo_store_layout = trust_db.get_layout(git_dir).map(String::from);
+    let detection = (repo_store_layout.is_none() && yaml_layout.is_none())
+        .then(|| detect_layout(git_dir, &global_config));
+    let (layout, source) = resolve_layout(&LayoutResolutionContext {
+        cli_layout: None,
+        repo_store_layout: repo_store_layout.as_deref(),
+        yaml_layout: yaml_layout.as_deref(),
+        global_config: &global_config,
+        detection,
+    });
+    (source != LayoutSource::Unresolved).then_some(layout.name)
+}
+
+fn render_exports(env: &DaftEnv, shell: &Shell) -> String {
+    env.vars()
+        .iter()
+        .map(|(name, value)| match shell {
+            Shell::Bash | Shell::Zsh => format!("export {name}={}\n", sh_quote(value)),
+            Shell::Fish => format!("set -gx {name} {}\n", fish_quote(value)),
+        })
+        .collect()
+}
+
+fn render_dotenv(env: &DaftEnv) -> String {
+    env.vars()
+        .iter()
+        .map(|(name, value)| format!("{name}={}\n", dotenv_quote(value)))
+        .collect()
+}
+
+/// POSIX single-quoting: `'` becomes `'\''`.
+pub(crate) fn sh_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// fish single-quoting: only `\` and `'` are special inside.
+pub(crate) fn fish_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+/// Bare when the value is plain, double-quoted with `\`/`"`/`$` escaped
+/// otherwise — the subset every dotenv reader agrees on.
+fn dotenv_quote(value: &str) -> String {
+    let plain = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '.' | '_' | '-' | ':' | '+'));
+    if plain {
+        return value.to_string();
+    }
+    let escaped = value
+        .replace('\\', r"\\")
+        .replace('"', "\\\"")
+        .replace('$', r"\$");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DaftEnv {
+        DaftEnv {
+            project_root: "/code/my app".into(),
+            git_dir: "/code/my app/.git".into(),
+            worktree_path: Some("/code/my app/main".into()),
+            branch: Some("main".into()),
+            remote: "origin".into(),
+            layout: None,
+            default_branch: Some("main".into()),
+        }
+    }
+
+    #[test]
+    fn exports_quote_for_each_shell() {
+        let env = sample();
+        let sh = render_exports(&env, &Shell::Bash);
+        assert!(sh.contains("export DAFT_PROJECT_ROOT='/code/my app'\n"));
+        assert!(sh.contains("export DAFT_LAYOUT=''\n"));
+        let fish = render_exports(&env, &Shell::Fish);
+        assert!(fish.contains("set -gx DAFT_BRANCH_NAME 'main'\n"));
+        assert_eq!(sh_quote("it's"), r"'it'\''s'");
+        assert_eq!(fish_quote(r"it's\"), r"'it\'s\\'");
+    }
+
+    #[test]
+    fn dotenv_quotes_only_when_needed() {
+        let dotenv = render_dotenv(&sample());
+        assert!(dotenv.contains("DAFT_PROJECT_ROOT=\"/code/my app\"\n"));
+        assert!(dotenv.contains("DAFT_REMOTE=origin\n"));
+        assert!(dotenv.contains("DAFT_LAYOUT=\n"));
+        assert_eq!(dotenv_quote("a\"$b"), r#""a\"\$b""#);
+    }
+
+    #[test]
+    fn document_keeps_unresolved_fields_as_null() {
+        let value = serde_json::to_value(sample()).unwrap();
+        assert_eq!(value.as_object().unwrap().len(), 7);
+        assert_eq!(value["worktree_path"], "/code/my app/main");
+        assert!(value["layout"].is_null());
+    }
+}
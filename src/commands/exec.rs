@@ -173,7 +173,9 @@ fn should_use_rail(args: &Args, target_count: usize, interactive: bool) -> bool
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-exec"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-exec",
+    ));
     validate_args(&args)?;
 
     let inside_repo = is_git_repository()?;
@@ -281,10 +283,10 @@ pub fn run() -> Result<()> {
 
             let status = cmd.status()?;
             if !status.success() {
-                std::process::exit(status.code().unwrap_or(1));
+                crate::deprecations::exit(status.code().unwrap_or(1));
             }
         }
-        std::process::exit(0);
+        crate::deprecations::exit(0);
     }
 
     let mode = if args.keep_going {
@@ -347,7 +349,7 @@ pub fn run() -> Result<()> {
         drop(sink);
     }
 
-    std::process::exit(report.aggregate_exit_code());
+    crate::deprecations::exit(report.aggregate_exit_code());
 }
 
 /// Arm the two-stage Ctrl-C escalation on the interrupt dispatcher's slot,
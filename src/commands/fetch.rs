@@ -106,7 +106,9 @@ pub struct Args {
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-fetch"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-fetch",
+    ));
 
     init_logging(args.verbose);
 
@@ -122,7 +122,9 @@ pub struct Args {
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-flow-adopt"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-flow-adopt",
+    ));
 
     init_logging(args.verbose);
 
@@ -0,0 +1,124 @@
+use crate::hooks::trust_audit;
+use crate::output::Output;
+use crate::output::emit::{self, Cell, EmitArgs, EmitPayload, Table};
+use crate::store::models::TrustAuditRow;
+use crate::styles::{bold, cyan, dim, green, red, yellow};
+use anyhow::Result;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+/// Show the hooks trust audit log, optionally for one repository.
+pub(super) fn cmd_audit(
+    repo: Option<&Path>,
+    limit: Option<usize>,
+    emit_args: &EmitArgs,
+    output: &mut dyn Output,
+) -> Result<()> {
+    let config_dir = crate::daft_config_dir()?;
+    let git_dir = repo.map(resolve_git_dir);
+    let entries = trust_audit::entries(&config_dir, git_dir.as_deref(), limit)?;
+
+    if emit_args.is_structured() {
+        return emit::emit_and_handle(
+            "hooks audit",
+            EmitPayload::Tabular(build_audit_table(&entries)),
+            emit_args,
+            &mut std::io::stdout(),
+        )
+        .map_err(|e| anyhow::anyhow!("{e}"));
+    }
+
+    if entries.is_empty() {
+        output.info(&dim(match &git_dir {
+            Some(_) => "No audit entries for this repository.",
+            None => "The hooks trust audit log is empty.",
+        }));
+        return Ok(());
+    }
+
+    let time_format = crate::output::time::configured();
+    let mut text = String::new();
+    let title = match &git_dir {
+        Some(dir) => format!("Hooks trust audit log for {}:", display_repo(dir)),
+        None => "Hooks trust audit log:".to_string(),
+    };
+    text.push_str(&bold(&title));
+    text.push_str("\n\n");
+    for entry in &entries {
+        let time = crate::output::time::stamp(entry.recorded_at.timestamp(), time_format);
+        text.push_str(&format!("  {}  {}", dim(&time), describe(entry)));
+        if git_dir.is_none() {
+            text.push_str(&format!("  {}", cyan(&display_repo(&entry.repo))));
+        }
+        text.push_str(&format!("\n    {}\n", dim(&entry.command)));
+    }
+
+    if text.lines().count() > 20 && std::io::stdout().is_terminal() {
+        crate::output::pager::display_with_pager(&text);
+    } else {
+        output.raw(&text);
+    }
+    Ok(())
+}
+
+/// The git dir the trust registry keys `path` under. A path that is no
+/// longer a repository (the audit outlives the repo) is used as given.
+fn resolve_git_dir(path: &Path) -> PathBuf {
+    crate::core::repo::git_common_dir_at(path)
+        .unwrap_or_else(|| std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()))
+}
+
+fn display_repo(git_dir: impl AsRef<Path>) -> String {
+    let path = git_dir.as_ref().to_string_lossy();
+    path.strip_suffix("/.git").unwrap_or(&path).to_string()
+}
+
+/// One-line summary of an entry, e.g. `trust deny -> allow` or
+/// `worktree-post-create: prompted: declined`.
+fn describe(entry: &TrustAuditRow) -> String {
+    match entry.event.as_str() {
+        "hook" => format!(
+            "{}: {}",
+            entry.hook.as_deref().unwrap_or("hook"),
+            styled_outcome(&entry.outcome)
+        ),
+        event => {
+            let from = entry.previous.as_deref().unwrap_or("unset");
+            format!("{event} {from} -> {}", styled_outcome(&entry.outcome))
+        }
+    }
+}
+
+fn styled_outcome(outcome: &str) -> String {
+    match outcome {
+        "allow" | "allowed" | "prompted: accepted" | "explicit run" => green(outcome),
+        "prompt" | "sandboxed" => yellow(outcome),
+        _ => red(outcome),
+    }
+}
+
+fn build_audit_table(entries: &[TrustAuditRow]) -> Table {
+    let mut table = Table::new([
+        "timestamp",
+        "event",
+        "repo_path",
+        "hook",
+        "outcome",
+        "previous",
+        "command",
+        "timestamp_epoch",
+    ]);
+    for entry in entries {
+        table = table.row([
+            Cell::str(entry.recorded_at.to_rfc3339()),
+            Cell::str(&entry.event),
+            Cell::str(display_repo(&entry.repo)),
+            Cell::str(entry.hook.as_deref().unwrap_or("")),
+            Cell::str(&entry.outcome),
+            Cell::str(entry.previous.as_deref().unwrap_or("")),
+            Cell::str(&entry.command),
+            Cell::int(entry.recorded_at.timestamp()),
+        ]);
+    }
+    table
+}
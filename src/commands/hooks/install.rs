@@ -3,6 +3,82 @@ use crate::hooks::{yaml_config, yaml_config_loader};
 use crate::output::Output;
 use crate::styles::{bold, cyan, dim, green};
 use anyhow::{Context, Result};
+use clap::ValueEnum;
+
+/// A curated starter `daft.yml` for one toolchain, embedded in the binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Preset {
+    Node,
+    Rust,
+    Python,
+}
+
+impl Preset {
+    fn content(self) -> &'static str {
+        match self {
+            Self::Node => include_str!("presets/node.yml"),
+            Self::Rust => include_str!("presets/rust.yml"),
+            Self::Python => include_str!("presets/python.yml"),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Node => "node",
+            Self::Rust => "rust",
+            Self::Python => "python",
+        }
+    }
+}
+
+/// Write the `preset` config as a new daft.yml. An existing config is left
+/// alone and the preset is printed so it can be merged by hand.
+pub(super) fn cmd_install_preset(preset: Preset, output: &mut dyn Output) -> Result<()> {
+    let worktree_root = find_worktree_root()?;
+
+    if let Some((config_path, _)) = yaml_config_loader::find_config_file(&worktree_root) {
+        output.info(&format!(
+            "Config file already exists: {}",
+            bold(&config_path.display().to_string())
+        ));
+        output.info(&format!(
+            "\nThe {} preset is below; merge the parts you want by hand:\n",
+            cyan(preset.name())
+        ));
+        output.raw(preset.content());
+        return Ok(());
+    }
+
+    let config_path = worktree_root.join("daft.yml");
+    std::fs::write(&config_path, preset.content())
+        .with_context(|| format!("Failed to write {}", config_path.display()))?;
+
+    output.success(&format!(
+        "{} {} from the {} preset",
+        green("Created"),
+        config_path.display(),
+        preset.name()
+    ));
+    let config: yaml_config::YamlConfig =
+        serde_yaml::from_str(preset.content()).context("Failed to parse the embedded preset")?;
+    let mut hooks: Vec<&String> = config.hooks.keys().collect();
+    hooks.sort();
+    for name in hooks {
+        output.info(&format!("  {} hook {name}", green("added")));
+    }
+    let mut tasks: Vec<&String> = config.tasks.keys().collect();
+    tasks.sort();
+    for name in tasks {
+        output.info(&format!("  {} task {name}", green("added")));
+    }
+    output.info(&dim(&format!(
+        "\nRun `{}` to let these hooks run, and `{}` before committing.",
+        crate::daft_cmd("hooks trust"),
+        crate::daft_cmd("run check")
+    )));
+
+    Ok(())
+}
 
 /// Scaffold a daft.yml configuration with hook definitions.
 pub(super) fn cmd_install(hooks: &[String], output: &mut dyn Output) -> Result<()> {
@@ -125,3 +201,27 @@ pub(super) fn cmd_install(hooks: &[String], output: &mut dyn Output) -> Result<(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::yaml_config_validate::validate_config;
+
+    #[test]
+    fn presets_parse_and_validate_cleanly() {
+        for preset in Preset::value_variants() {
+            let config: yaml_config::YamlConfig = serde_yaml::from_str(preset.content())
+                .unwrap_or_else(|e| panic!("{} preset: {e}", preset.name()));
+            let result = validate_config(&config).unwrap();
+            assert!(
+                result.errors.is_empty() && result.warnings.is_empty(),
+                "{} preset: {:?} {:?}",
+                preset.name(),
+                result.errors,
+                result.warnings
+            );
+            assert!(config.hooks.contains_key("worktree-post-create"));
+            assert!(config.tasks.contains_key("check"));
+        }
+    }
+}
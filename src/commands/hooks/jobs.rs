@@ -10,6 +10,8 @@ use crate::output::Output;
 use crate::output::emit::{self, Cell, EmitArgs, EmitPayload, Table};
 use crate::output::format::{pad_to_visible_width, shorthand_from_seconds, visible_width};
 use crate::output::outline::{self, Body, Node, Outline, Section};
+use crate::output::time;
+use crate::settings::TimeFormat;
 use crate::styles::{
     BOLD, CURRENT_WORKTREE_SYMBOL, CYAN, RESET, blue, bold, dim, dim_underline, green, orange, red,
     yellow,
@@ -73,12 +75,12 @@ fn worktree_header(marker: &str, name: &str) -> String {
 
 /// Label for an invocation node hanging off the spine. The bullet (`●`) is
 /// the outline renderer's responsibility; this helper produces only the
-/// label text. `time_ago` is the bare relative duration (e.g. `"2h"`); the
-/// helper appends `" ago"` so the rendered text reads `"2h ago"`.
-fn invocation_node_label(time_ago: &str, trigger: &str, short_id: &str) -> String {
+/// label text. `when` is the rendered invocation time (`"2h ago"` by
+/// default, see [`crate::output::time::ago`]).
+fn invocation_node_label(when: &str, trigger: &str, short_id: &str) -> String {
     format!(
         "{} · {trigger} {}",
-        dim(&format!("{time_ago} ago")),
+        dim(when),
         dim(&format!("[{short_id}]")),
     )
 }
@@ -798,6 +800,9 @@ fn build_jobs_payload(
         "exit_code",
         "command",
         "size_bytes",
+        "invocation_created_epoch",
+        "started_epoch",
+        "finished_epoch",
     ]);
 
     for inv in invocations {
@@ -856,6 +861,11 @@ fn build_jobs_payload(
                     .unwrap_or(Cell::Null),
                 Cell::str(&meta.command),
                 size_cell,
+                Cell::int(inv.created_at.timestamp()),
+                Cell::int(meta.started_at.timestamp()),
+                meta.finished_at
+                    .map(|t| Cell::int(t.timestamp()))
+                    .unwrap_or(Cell::Null),
             ]);
         }
     }
@@ -889,11 +899,12 @@ const LIST_JOBS_HEADERS: [&str; 5] = ["Job", "Status", "Started", "Duration", "S
 fn build_invocation_node(
     sec: InvocationSection<'_>,
     now: chrono::DateTime<chrono::Utc>,
+    time_format: TimeFormat,
     max_widths: &[usize; 5],
 ) -> Node {
-    let ago = shorthand_from_seconds(now.signed_duration_since(sec.inv.created_at).num_seconds());
+    let when = time::ago(sec.inv.created_at.timestamp(), now.timestamp(), time_format);
     let short_id = &sec.inv.invocation_id[..4.min(sec.inv.invocation_id.len())];
-    let label = invocation_node_label(&ago, &sec.inv.trigger_command, short_id);
+    let label = invocation_node_label(&when, &sec.inv.trigger_command, short_id);
 
     let body = if sec.rows.is_empty() {
         Body::Placeholder(dim("(no jobs declared)"))
@@ -945,6 +956,7 @@ fn list_jobs(args: &JobsArgs, _path: &Path, output: &mut dyn Output) -> Result<(
         String::new()
     };
     let coordinator_alive = is_coordinator_running(&repo_hash);
+    let time_format = time::configured();
 
     let store = LogStore::for_repo(&repo_hash)?;
     let sqlite_index = load_sqlite_job_meta_index(&repo_hash, &store.base_dir);
@@ -1051,10 +1063,7 @@ fn list_jobs(args: &JobsArgs, _path: &Path, output: &mut dyn Output) -> Result<(
                 };
                 let job_label = format!("{icon} {}", meta.name);
                 let status = format_status_inline(&meta.status, coordinator_alive);
-                let started = {
-                    let local: chrono::DateTime<chrono::Local> = meta.started_at.into();
-                    local.format("%H:%M:%S").to_string()
-                };
+                let started = time::clock(meta.started_at.timestamp(), time_format);
                 let duration = match (&meta.status, meta.finished_at) {
                     (_, Some(finished)) => {
                         format_duration(finished.signed_duration_since(meta.started_at))
@@ -1114,7 +1123,7 @@ fn list_jobs(args: &JobsArgs, _path: &Path, output: &mut dyn Output) -> Result<(
                     header: worktree_header(marker, &worktree),
                     nodes: secs
                         .into_iter()
-                        .map(|sec| build_invocation_node(sec, now, &max_widths))
+                        .map(|sec| build_invocation_node(sec, now, time_format, &max_widths))
                         .collect(),
                 }
             })
@@ -1130,10 +1139,9 @@ fn list_jobs(args: &JobsArgs, _path: &Path, output: &mut dyn Output) -> Result<(
         && let Some(s) = &cache.last_summary
     {
         let now = chrono::Utc::now().timestamp();
-        let age = now - cache.cleaned_at;
-        let ago = shorthand_from_seconds(age);
+        let when = time::ago(cache.cleaned_at, now, time_format);
         output.info(&dim(&format!(
-            "Last log cleanup {ago} ago: removed {} job log(s) ({} freed)",
+            "Last log cleanup {when}: removed {} job log(s) ({} freed)",
             s.removed_jobs,
             format_bytes(s.freed_bytes),
         )));
@@ -1340,6 +1348,7 @@ fn show_logs(
         None
     };
 
+    let time_format = time::configured();
     let mut buf = String::new();
 
     if let Some(invocation_id) = invocation_only {
@@ -1348,11 +1357,19 @@ fn show_logs(
             sqlite_index.as_ref(),
             &invocation_id,
             filter,
+            time_format,
             &mut buf,
         )?;
     } else {
         let resolved = resolve_job_address(&addr, &store, &current_worktree)?;
-        render_single_job_log(&store, sqlite_index.as_ref(), &resolved, filter, &mut buf)?;
+        render_single_job_log(
+            &store,
+            sqlite_index.as_ref(),
+            &resolved,
+            filter,
+            time_format,
+            &mut buf,
+        )?;
     }
 
     crate::output::pager::display_with_pager(&buf);
@@ -1373,6 +1390,7 @@ fn render_single_job_log(
     >,
     resolved: &ResolvedAddress,
     filter: &LogsFilter,
+    time_format: TimeFormat,
     buf: &mut String,
 ) -> Result<()> {
     use std::fmt::Write;
@@ -1420,13 +1438,10 @@ fn render_single_job_log(
         writeln!(buf, "trigger:   {}", im.trigger_command)?;
     }
 
-    let ago = shorthand_from_seconds(now.signed_duration_since(meta.started_at).num_seconds());
-    let local_started: chrono::DateTime<chrono::Local> = meta.started_at.into();
     writeln!(
         buf,
-        "started:   {} ago ({})",
-        ago,
-        local_started.format("%Y-%m-%d %H:%M:%S"),
+        "started:   {}",
+        time::detailed(meta.started_at.timestamp(), now.timestamp(), time_format),
     )?;
 
     let duration_str = match meta.finished_at {
@@ -1463,6 +1478,7 @@ fn render_invocation_logs(
     >,
     invocation_id: &str,
     filter: &LogsFilter,
+    time_format: TimeFormat,
     buf: &mut String,
 ) -> Result<()> {
     use std::fmt::Write;
@@ -1489,13 +1505,14 @@ fn render_invocation_logs(
     if !inv_meta.worktree.is_empty() {
         writeln!(buf, "worktree:  {}", inv_meta.worktree)?;
     }
-    let ago = shorthand_from_seconds(now.signed_duration_since(inv_meta.created_at).num_seconds());
-    let local_started: chrono::DateTime<chrono::Local> = inv_meta.created_at.into();
     writeln!(
         buf,
-        "started:   {} ago ({})",
-        ago,
-        local_started.format("%Y-%m-%d %H:%M:%S"),
+        "started:   {}",
+        time::detailed(
+            inv_meta.created_at.timestamp(),
+            now.timestamp(),
+            time_format
+        ),
     )?;
     writeln!(buf, "jobs:      {}", jobs.len())?;
     writeln!(buf)?;
@@ -2852,7 +2869,7 @@ mod tests {
 
     #[test]
     fn invocation_node_label_omits_bullet_and_dims_time_and_id() {
-        let rendered = invocation_node_label("2h", "worktree-post-create", "c9d4");
+        let rendered = invocation_node_label("2h ago", "worktree-post-create", "c9d4");
         // The bullet is now the outline renderer's responsibility — the
         // label must not contain it.
         assert!(
@@ -1,20 +1,36 @@
+use crate::core::worktree::porcelain::parse_worktree_list_porcelain;
+use crate::git::GitCommand;
 use crate::hooks::{HookType, PROJECT_HOOKS_DIR};
 use crate::output::Output;
+use crate::settings::keys;
 use crate::styles::{bold, dim, green, red, yellow};
 use crate::{get_git_common_dir, is_git_repository};
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Tallies across every directory and config key a migration touches.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct MigrateCounts {
+    renamed: u32,
+    skipped: u32,
+    conflicts: u32,
+}
 
 /// Migrate deprecated hook filenames to their new canonical names.
 ///
-/// Must be run from within a worktree. Only migrates hooks in the
-/// current worktree's `.daft/hooks/` directory.
-pub(super) fn cmd_migrate(dry_run: bool, output: &mut dyn Output) -> Result<()> {
+/// Without `repo_wide`, must be run from within a worktree and only migrates
+/// hooks in the current worktree's `.daft/hooks/` directory. With it, every
+/// worktree of the repository is migrated, along with deprecated config keys
+/// in the repository's local git config.
+pub(super) fn cmd_migrate(dry_run: bool, repo_wide: bool, output: &mut dyn Output) -> Result<()> {
     if !is_git_repository()? {
         anyhow::bail!("Not in a git repository");
     }
 
     let git_dir = get_git_common_dir()?;
+    if repo_wide {
+        return migrate_repo_wide(&git_dir, dry_run, output);
+    }
     let project_root = git_dir.parent().context("Invalid git directory")?;
 
     // Determine the current worktree using git rev-parse --show-toplevel
@@ -48,22 +64,80 @@ pub(super) fn cmd_migrate(dry_run: bool, output: &mut dyn Output) -> Result<()>
         return Ok(());
     }
 
-    // Build the rename mapping: (old_name, new_name) for hooks that have deprecated names
-    let rename_map: Vec<(&str, &str)> = HookType::all()
-        .iter()
-        .filter_map(|ht| ht.deprecated_filename().map(|old| (old, ht.filename())))
-        .collect();
+    let mut counts = MigrateCounts::default();
+    if dry_run {
+        output.info(&bold("Dry run - no files will be changed"));
+        output.info("");
+    }
+    migrate_hooks_dir(&hooks_dir, dry_run, &mut counts, output);
+    print_summary(&counts, dry_run, output);
+
+    Ok(())
+}
 
-    let mut renamed = 0u32;
-    let mut skipped = 0u32;
-    let mut conflicts = 0u32;
+/// Migrate every worktree's hook files, then the repository's config keys.
+fn migrate_repo_wide(git_dir: &Path, dry_run: bool, output: &mut dyn Output) -> Result<()> {
+    let git = GitCommand::new(true);
+    let entries = parse_worktree_list_porcelain(&git.worktree_list_porcelain()?);
 
+    let mut counts = MigrateCounts::default();
     if dry_run {
         output.info(&bold("Dry run - no files will be changed"));
         output.info("");
     }
 
-    for &(old_name, new_name) in &rename_map {
+    for entry in entries.iter().filter(|e| !e.is_bare) {
+        let hooks_dir = entry.path.join(PROJECT_HOOKS_DIR);
+        let has_deprecated = rename_map()
+            .iter()
+            .any(|(old_name, _)| hooks_dir.join(old_name).exists());
+        if !has_deprecated {
+            continue;
+        }
+        output.info(&bold(&entry.path.display().to_string()));
+        migrate_hooks_dir(&hooks_dir, dry_run, &mut counts, output);
+    }
+
+    migrate_config_keys(git_dir, &git, dry_run, &mut counts, output)?;
+    print_summary(&counts, dry_run, output);
+    Ok(())
+}
+
+/// `(old, new)` hook filenames for every renamed hook type.
+fn rename_map() -> Vec<(&'static str, &'static str)> {
+    HookType::all()
+        .iter()
+        .filter_map(|ht| ht.deprecated_filename().map(|old| (old, ht.filename())))
+        .collect()
+}
+
+/// `(old, new)` config keys daft still reads under their deprecated names.
+fn config_key_map() -> Vec<(String, String)> {
+    let mut pairs = vec![(
+        keys::FETCH_ARGS_DEPRECATED.to_string(),
+        keys::UPDATE_ARGS.to_string(),
+    )];
+    for ht in HookType::all() {
+        if let Some(old) = ht.deprecated_config_key() {
+            for setting in ["enabled", "failMode"] {
+                pairs.push((
+                    keys::hooks::hook_key(old, setting),
+                    keys::hooks::hook_key(ht.config_key(), setting),
+                ));
+            }
+        }
+    }
+    pairs
+}
+
+/// Rename deprecated hook files in one `.daft/hooks/` directory.
+fn migrate_hooks_dir(
+    hooks_dir: &Path,
+    dry_run: bool,
+    counts: &mut MigrateCounts,
+    output: &mut dyn Output,
+) {
+    for (old_name, new_name) in rename_map() {
         let old_path = hooks_dir.join(old_name);
         let new_path = hooks_dir.join(new_name);
 
@@ -80,7 +154,7 @@ pub(super) fn cmd_migrate(dry_run: bool, output: &mut dyn Output) -> Result<()>
                 old_name,
                 new_name,
             ));
-            conflicts += 1;
+            counts.conflicts += 1;
             continue;
         }
 
@@ -91,7 +165,7 @@ pub(super) fn cmd_migrate(dry_run: bool, output: &mut dyn Output) -> Result<()>
                 old_name,
                 new_name,
             ));
-            renamed += 1;
+            counts.renamed += 1;
         } else {
             match std::fs::rename(&old_path, &new_path) {
                 Ok(()) => {
@@ -101,38 +175,169 @@ pub(super) fn cmd_migrate(dry_run: bool, output: &mut dyn Output) -> Result<()>
                         old_name,
                         new_name,
                     ));
-                    renamed += 1;
+                    counts.renamed += 1;
                 }
                 Err(e) => {
                     output.error(&format!("{} -> {}: {}", old_name, new_name, e));
-                    skipped += 1;
+                    counts.skipped += 1;
                 }
             }
         }
     }
+}
 
+/// Move deprecated keys in the repository's local config to their new names.
+/// Global config is only reported: it is shared with every other repository.
+fn migrate_config_keys(
+    git_dir: &Path,
+    git: &GitCommand,
+    dry_run: bool,
+    counts: &mut MigrateCounts,
+    output: &mut dyn Output,
+) -> Result<()> {
+    let mut printed_header = false;
+    for (old_key, new_key) in config_key_map() {
+        if let Some(value) = local_config_get(git_dir, &old_key)? {
+            if !printed_header {
+                output.info(&bold("git config (local)"));
+                printed_header = true;
+            }
+            if local_config_get(git_dir, &new_key)?.is_some() {
+                output.warning(&format!(
+                    "{} {}: both '{}' and '{}' are set",
+                    red("conflict"),
+                    bold(&old_key),
+                    old_key,
+                    new_key,
+                ));
+                counts.conflicts += 1;
+            } else if dry_run {
+                output.info(&format!(
+                    "  {} {} -> {}",
+                    yellow("would rename"),
+                    old_key,
+                    new_key,
+                ));
+                counts.renamed += 1;
+            } else {
+                match rename_local_key(git_dir, &old_key, &new_key, &value) {
+                    Ok(()) => {
+                        output.info(&format!(
+                            "  {} {} -> {}",
+                            green("renamed"),
+                            old_key,
+                            new_key,
+                        ));
+                        counts.renamed += 1;
+                    }
+                    Err(e) => {
+                        output.error(&format!("{} -> {}: {}", old_key, new_key, e));
+                        counts.skipped += 1;
+                    }
+                }
+            }
+        }
+
+        if git.config_get_global(&old_key)?.is_some() {
+            output.warning(&format!(
+                "'{old_key}' is set in your global git config; rename it to '{new_key}' there \
+                 (daft does not edit global config)",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn local_config_get(git_dir: &Path, key: &str) -> Result<Option<String>> {
+    let out = crate::utils::git_command_at(git_dir)
+        .args(["config", "--local", "--get", key])
+        .output()
+        .context("Failed to execute git config")?;
+    // Exit code 1 means the key is not set.
+    Ok(out
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&out.stdout).trim().to_string()))
+}
+
+fn rename_local_key(git_dir: &Path, old_key: &str, new_key: &str, value: &str) -> Result<()> {
+    for args in [
+        vec!["config", "--local", new_key, value],
+        vec!["config", "--local", "--unset", old_key],
+    ] {
+        let out = crate::utils::git_command_at(git_dir)
+            .args(&args)
+            .output()
+            .context("Failed to execute git config")?;
+        if !out.status.success() {
+            anyhow::bail!("{}", String::from_utf8_lossy(&out.stderr).trim());
+        }
+    }
+    Ok(())
+}
+
+fn print_summary(counts: &MigrateCounts, dry_run: bool, output: &mut dyn Output) {
     output.info("");
     if dry_run {
         output.result(&format!(
             "{} would be renamed, {} conflicts",
-            bold(&renamed.to_string()),
-            bold(&conflicts.to_string())
+            bold(&counts.renamed.to_string()),
+            bold(&counts.conflicts.to_string())
         ));
-    } else if renamed == 0 && conflicts == 0 {
+    } else if counts.renamed == 0 && counts.conflicts == 0 {
         output.info(&dim("No deprecated hook files found."));
     } else {
         output.result(&format!(
             "{} renamed, {} skipped, {} conflicts",
-            bold(&renamed.to_string()),
-            bold(&skipped.to_string()),
-            bold(&conflicts.to_string())
+            bold(&counts.renamed.to_string()),
+            bold(&counts.skipped.to_string()),
+            bold(&counts.conflicts.to_string())
         ));
-        if renamed > 0 {
+        if counts.renamed > 0 {
             output.info(&dim(
                 "Remember to 'git add' the renamed files if they are tracked.",
             ));
         }
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::TestOutput;
+
+    #[test]
+    fn renames_deprecated_files_and_reports_conflicts() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path();
+        std::fs::write(dir.join("post-create"), "#!/bin/sh").unwrap();
+        std::fs::write(dir.join("pre-remove"), "#!/bin/sh").unwrap();
+        std::fs::write(dir.join("worktree-pre-remove"), "#!/bin/sh").unwrap();
+
+        let mut counts = MigrateCounts::default();
+        migrate_hooks_dir(dir, false, &mut counts, &mut TestOutput::default());
+
+        assert!(dir.join("worktree-post-create").is_file());
+        assert!(!dir.join("post-create").exists());
+        assert!(dir.join("pre-remove").is_file());
+        assert_eq!(
+            counts,
+            MigrateCounts {
+                renamed: 1,
+                skipped: 0,
+                conflicts: 1
+            }
+        );
+    }
+
+    #[test]
+    fn config_key_map_covers_fetch_args_and_renamed_hooks() {
+        let map = config_key_map();
+        assert!(map.contains(&("daft.fetch.args".into(), "daft.update.args".into())));
+        assert!(map.contains(&(
+            "daft.hooks.postCreate.failMode".into(),
+            "daft.hooks.worktreePostCreate.failMode".into()
+        )));
+        assert!(!map.iter().any(|(old, _)| old.contains("postClone")));
+    }
 }
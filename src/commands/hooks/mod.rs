@@ -8,19 +8,25 @@
 //! - `prompt` - Trust a repository but prompt before each hook
 //! - `deny` - Revoke trust from a repository
 //! - `status` - Show trust status and available hooks
+//! - `audit` - Review the trust audit log
 //! - `migrate` - Rename deprecated hook files to their new names
 //! - `install` - Scaffold a daft.yml with hook definitions
 //! - `validate` - Validate YAML hook configuration
 //! - `dump` - Dump merged YAML hook configuration
 //! - `run` - Manually run a hook (bypasses trust checks)
+//! - `test` - Run the hook tests defined in daft.yml in a sandbox
+//! - `schedule` - Install scheduled tasks as systemd timers / launchd agents
 
+mod audit;
 mod dump;
 mod formatting;
 mod install;
 mod jobs;
 mod migrate;
 mod run_cmd;
+mod schedule;
 mod status;
+mod test;
 mod trust;
 mod validate;
 
@@ -200,12 +206,25 @@ fn migrate_long_about() -> String {
         &def("pre-remove", "worktree-pre-remove"),
         &def("post-remove", "worktree-post-remove"),
         "",
-        "This command must be run from within a worktree. It renames deprecated",
-        "hook files in the current worktree's .daft/hooks/ directory.",
+        "By default this command must be run from within a worktree. It renames",
+        "deprecated hook files in the current worktree's .daft/hooks/ directory.",
         "",
         "If both old and new names exist, the old file is skipped (conflict).",
         "Resolve conflicts manually before re-running.",
         "",
+        &format!(
+            "With {}, the hooks directory of every worktree is migrated, and",
+            bold("--repo-wide")
+        ),
+        "deprecated config keys in the repository's local git config are renamed",
+        "(daft.hooks.postCreate.* to daft.hooks.worktreePostCreate.*, and so on,",
+        "plus daft.fetch.args to daft.update.args). Keys set in global config are",
+        "reported but left for you to rename.",
+        "",
+        "Deprecated hook files keep running until daft v2.0.0. To rehearse the",
+        "cutover, set daft.hooks.executeDeprecated to false: deprecated files are",
+        "then reported but not executed.",
+        "",
         &format!(
             "Use {} to preview changes without renaming.",
             bold("--dry-run")
@@ -224,6 +243,12 @@ fn install_long_about() -> String {
         "If a config file already exists, it is not modified. Instead, a YAML",
         "snippet is printed for any missing hooks so you can add them manually.",
         "",
+        "With --preset, writes a working starter config for a toolchain instead",
+        "of placeholders: dependency install on worktree-post-create (plus a",
+        "background warmup build for rust) and a `check` task with the",
+        "toolchain's lint and test commands, run with `daft run check`.",
+        "Presets: node, rust, python.",
+        "",
         "Valid hook names:",
         "  post-clone, worktree-pre-create, worktree-post-create,",
         "  worktree-pre-remove, worktree-post-remove",
@@ -284,6 +309,74 @@ fn jobs_long_about() -> String {
     .join("\n")
 }
 
+fn audit_long_about() -> String {
+    [
+        "Show the hooks trust audit log.",
+        "",
+        "Every trust change and every hook execution decision is recorded with",
+        "a timestamp, the repository, and the daft command that caused it:",
+        "",
+        &def(
+            "trust",
+            "A repository's trust level was set, changed, or removed",
+        ),
+        &def(
+            "pattern",
+            "A trust pattern rule was added, changed, or removed",
+        ),
+        &def(
+            "hook",
+            "A hook was allowed, denied, prompted for (with the answer), or run explicitly",
+        ),
+        "",
+        "The log is append-only and lives next to the trust database.",
+        "",
+        &format!(
+            "Use {} to show only one repository's entries, and {} to show",
+            bold("--repo <path>"),
+            bold("--limit <n>")
+        ),
+        "only the newest entries.",
+    ]
+    .join("\n")
+}
+
+fn schedule_long_about() -> String {
+    [
+        "Install scheduled tasks as operating-system timers.",
+        "",
+        "A task in daft.yml can declare a cron-like schedule:",
+        "",
+        "  tasks:",
+        "    fetch:",
+        "      schedule: \"0 * * * *\"",
+        "      jobs:",
+        "        - run: git fetch --all --prune",
+        "",
+        "daft has no scheduler of its own. This command generates a user-level",
+        "systemd timer (Linux) or launchd agent (macOS) per scheduled task that",
+        "runs the task's daft run in this worktree, so periodic maintenance is",
+        "declared in daft.yml and executed by the OS.",
+        "",
+        &def(
+            "(none)",
+            "List the scheduled tasks and whether they are installed",
+        ),
+        &def(
+            "install",
+            "Write and enable the timers; removes timers for tasks no longer scheduled",
+        ),
+        &def("uninstall", "Disable and remove this worktree's timers"),
+        "",
+        "Scheduled tasks run unattended, so install requires the repository to",
+        &format!(
+            "be trusted ({}). Re-run install after changing a schedule.",
+            bold("allow")
+        ),
+    ]
+    .join("\n")
+}
+
 fn run_long_about() -> String {
     [
         "Manually run a hook by name.",
@@ -306,6 +399,37 @@ fn run_long_about() -> String {
             "Use {} to run only jobs with a specific tag.",
             bold("--tag <tag>")
         ),
+        "",
+        &format!(
+            "Use {} to run the hook in every worktree, or {} to run it in",
+            bold("--all-worktrees"),
+            bold("--branch <glob>")
+        ),
+        "the worktrees whose branch matches (e.g. 'feature/*') -- for example to",
+        "re-run worktree-post-create everywhere after adding a setup job. Each",
+        "worktree uses its own daft.yml; a summary lists the outcome per worktree,",
+        "and the command exits non-zero if the hook failed in any of them.",
+    ]
+    .join("\n")
+}
+
+fn test_long_about() -> String {
+    [
+        "Run the hook tests defined in the tests: section of daft.yml.",
+        "",
+        "Each test fires one hook in a throwaway clone of the current worktree",
+        "and checks the outcome against its expect: section -- the hook's exit",
+        "code and, per job, the exit code, whether it was skipped, and strings",
+        "its output must or must not contain. Without expect:, the hook must",
+        "succeed.",
+        "",
+        "The sandbox holds the worktree's committed history plus its tracked",
+        "and untracked files, so uncommitted edits are tested; ignored files",
+        "(including daft.local.yml) are left out. It has no remote, job logs",
+        "go to a temp directory, and background jobs run inline, so a test run",
+        "changes nothing in the real repository.",
+        "",
+        "Exits with code 1 if any test fails, for use in CI.",
     ]
     .join("\n")
 }
@@ -362,10 +486,37 @@ enum HooksCommand {
         short: bool,
     },
 
+    /// Install scheduled tasks as systemd timers or launchd agents
+    #[command(long_about = schedule_long_about())]
+    Schedule(schedule::ScheduleArgs),
+
+    /// Review the log of trust changes and hook decisions
+    #[command(long_about = audit_long_about())]
+    Audit {
+        /// Show only this repository's entries
+        #[arg(long, value_name = "PATH")]
+        repo: Option<std::path::PathBuf>,
+
+        /// Show only the newest N entries
+        #[arg(short = 'n', long, value_name = "N")]
+        limit: Option<usize>,
+
+        #[command(flatten)]
+        emit: crate::output::emit::EmitArgs,
+    },
+
     /// Run a hook manually
     #[command(long_about = run_long_about())]
     Run(HooksRunArgs),
 
+    /// Run the hook tests defined in daft.yml
+    #[command(long_about = test_long_about())]
+    Test {
+        /// Run only the test with this name
+        #[arg(help = "Run only the test with this name")]
+        name: Option<String>,
+    },
+
     /// Scaffold a daft.yml configuration with hook definitions
     #[command(long_about = install_long_about())]
     Install {
@@ -373,6 +524,15 @@ enum HooksCommand {
         /// If omitted, scaffolds all hooks.
         #[arg(help = "Hook names to add (omit for all hooks)")]
         hooks: Vec<String>,
+
+        /// Write a curated starter config for a toolchain instead of placeholders
+        #[arg(
+            long,
+            value_enum,
+            conflicts_with = "hooks",
+            help = "Write a curated starter config for a toolchain"
+        )]
+        preset: Option<install::Preset>,
     },
 
     /// Validate the YAML hooks configuration
@@ -393,6 +553,13 @@ enum HooksCommand {
         /// Show what would be renamed without making changes
         #[arg(long, help = "Preview renames without making changes")]
         dry_run: bool,
+
+        /// Migrate every worktree and the repository's config keys
+        #[arg(
+            long,
+            help = "Migrate hook files in every worktree and deprecated config keys"
+        )]
+        repo_wide: bool,
     },
 }
 
@@ -419,6 +586,23 @@ pub(super) struct HooksRunArgs {
     #[arg(short, long, help = "Show verbose output including skipped jobs")]
     pub verbose: bool,
 
+    /// Run the hook in every worktree of the repository
+    #[arg(
+        long,
+        requires = "hook_type",
+        help = "Run the hook in every worktree of the repository"
+    )]
+    pub all_worktrees: bool,
+
+    /// Run the hook in the worktrees whose branch matches a glob
+    #[arg(
+        long,
+        value_name = "GLOB",
+        requires = "hook_type",
+        help = "Run the hook in worktrees whose branch matches GLOB"
+    )]
+    pub branch: Option<String>,
+
     #[command(flatten)]
     pub emit: crate::output::emit::EmitArgs,
 }
@@ -517,12 +701,22 @@ pub fn run() -> Result<()> {
         }
         Some(HooksCommand::Deny { path, force }) => trust::cmd_deny(&path, force, &mut output),
         Some(HooksCommand::Status { path, short }) => status::cmd_status(&path, short, &mut output),
-        Some(HooksCommand::Migrate { dry_run }) => migrate::cmd_migrate(dry_run, &mut output),
-        Some(HooksCommand::Install { hooks }) => install::cmd_install(&hooks, &mut output),
+        Some(HooksCommand::Schedule(schedule_args)) => schedule::run(schedule_args, &mut output),
+        Some(HooksCommand::Audit { repo, limit, emit }) => {
+            audit::cmd_audit(repo.as_deref(), limit, &emit, &mut output)
+        }
+        Some(HooksCommand::Migrate { dry_run, repo_wide }) => {
+            migrate::cmd_migrate(dry_run, repo_wide, &mut output)
+        }
+        Some(HooksCommand::Install { hooks, preset }) => match preset {
+            Some(preset) => install::cmd_install_preset(preset, &mut output),
+            None => install::cmd_install(&hooks, &mut output),
+        },
         Some(HooksCommand::Validate) => validate::cmd_validate(&mut output),
         Some(HooksCommand::Dump) => dump::cmd_dump(&mut output),
         Some(HooksCommand::Jobs(jobs_args)) => jobs::run(jobs_args, &args.path, &mut output),
         Some(HooksCommand::Run(run_args)) => run_cmd::cmd_run(&run_args, &mut output),
+        Some(HooksCommand::Test { name }) => test::cmd_test(name.as_deref(), &mut output),
         None => {
             status::cmd_status(&args.path, false, &mut output)?;
             output.info(&dim(&format!(
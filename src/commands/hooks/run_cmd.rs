@@ -1,19 +1,25 @@
 use super::{HooksRunArgs, styled_trust_level};
+use crate::core::worktree::porcelain::parse_worktree_list_porcelain;
 use crate::executor::cli_presenter::CliPresenter;
+use crate::git::GitCommand;
 use crate::hooks::yaml_executor::JobFilter;
 use crate::hooks::{
     HookExecutor, HookType, TrustDatabase, TrustLevel, yaml_config, yaml_config_loader,
 };
 use crate::output::Output;
 use crate::output::emit::{self, Cell, EmitArgs, EmitPayload, Section, Table};
-use crate::styles::{bold, cyan, dim};
+use crate::styles::{bold, cyan, dim, green, red};
 use crate::{get_current_branch, get_current_worktree_path, get_git_common_dir, get_project_root};
 use anyhow::{Context, Result};
+use std::path::PathBuf;
 
 /// Run a hook manually.
 pub(super) fn cmd_run(args: &HooksRunArgs, output: &mut dyn Output) -> Result<()> {
     use crate::hooks::HookContext;
-    use crate::hooks::yaml_config_loader::get_effective_jobs;
+
+    if args.all_worktrees || args.branch.is_some() {
+        return cmd_run_worktrees(args, output);
+    }
 
     // Resolve worktree context
     let worktree_path = get_current_worktree_path()
@@ -37,14 +43,7 @@ pub(super) fn cmd_run(args: &HooksRunArgs, output: &mut dyn Output) -> Result<()
         }
     };
 
-    // Parse hook type
-    let hook_type = HookType::from_yaml_name(&hook_type_str).ok_or_else(|| {
-        anyhow::anyhow!(
-            "Unknown hook type: '{}'\nValid hook types: {}",
-            hook_type_str,
-            yaml_config::KNOWN_HOOK_NAMES.join(", ")
-        )
-    })?;
+    let hook_type = parse_hook_type(&hook_type_str)?;
 
     let git_dir = get_git_common_dir().context("Could not determine git directory")?;
     let project_root = get_project_root().context("Could not determine project root")?;
@@ -66,21 +65,7 @@ pub(super) fn cmd_run(args: &HooksRunArgs, output: &mut dyn Output) -> Result<()
     })?;
 
     // Check trust level and show hint if not trusted
-    let trust_db = TrustDatabase::load().unwrap_or_default();
-    let trust_level = trust_db.get_trust_level(&git_dir);
-    if trust_level != TrustLevel::Allow {
-        output.info(&format!(
-            "{} this repository is not in your trust list ({}).",
-            dim("Note:"),
-            styled_trust_level(trust_level)
-        ));
-        output.info(&format!(
-            "  {} run `{}` to allow hooks to run automatically.",
-            dim("Tip:"),
-            cyan(&crate::daft_cmd("hooks trust"))
-        ));
-        output.info("");
-    }
+    print_trust_note(&git_dir, output);
 
     // Build job filter
     let filter = JobFilter {
@@ -91,116 +76,7 @@ pub(super) fn cmd_run(args: &HooksRunArgs, output: &mut dyn Output) -> Result<()
 
     // Dry-run: preview jobs without executing
     if args.dry_run {
-        let mut jobs = get_effective_jobs(hook_def);
-
-        // Apply exclude_tags from hook definition
-        if let Some(ref exclude_tags) = hook_def.exclude_tags {
-            jobs.retain(|job| {
-                if let Some(ref tags) = job.tags {
-                    !tags.iter().any(|t| exclude_tags.contains(t))
-                } else {
-                    true
-                }
-            });
-        }
-
-        // Apply inclusion filters
-        if let Some(ref name) = filter.only_job_name {
-            jobs.retain(|j| j.name.as_deref() == Some(name.as_str()));
-            if jobs.is_empty() {
-                anyhow::bail!("No job named '{}' found in hook '{}'", name, hook_name);
-            }
-        }
-        if !filter.only_tags.is_empty() {
-            jobs.retain(|job| {
-                job.tags
-                    .as_ref()
-                    .is_some_and(|tags| tags.iter().any(|t| filter.only_tags.contains(t)))
-            });
-            if jobs.is_empty() {
-                anyhow::bail!(
-                    "No jobs matching tags {:?} in hook '{}'",
-                    filter.only_tags,
-                    hook_name
-                );
-            }
-        }
-
-        // Sort by priority
-        jobs.sort_by_key(|j| j.priority.unwrap_or(0));
-
-        if jobs.is_empty() {
-            output.info(&dim("No jobs to run."));
-            return Ok(());
-        }
-
-        let job_count = jobs.len();
-        let job_word = if job_count == 1 { "job" } else { "jobs" };
-        output.info(&format!(
-            "{} {} ({} {})",
-            bold("Hook:"),
-            cyan(hook_name),
-            job_count,
-            job_word
-        ));
-        output.info("");
-
-        for (i, job) in jobs.iter().enumerate() {
-            let name = job.name.as_deref().unwrap_or("(unnamed)");
-            output.info(&format!("  {}. {}", i + 1, bold(name)));
-
-            if let Some(ref desc) = job.description {
-                output.info(&format!("     {}", dim(desc)));
-            }
-
-            if let Some(ref arch) = job.arch {
-                let arch_list: Vec<&str> = arch.as_slice().iter().map(|a| a.as_str()).collect();
-                output.info(&format!("     {}: {}", dim("arch"), arch_list.join(", ")));
-            }
-
-            if let Some(ref run) = job.run {
-                let run_display = match run {
-                    crate::hooks::yaml_config::RunCommand::Simple(s) => s.clone(),
-                    crate::hooks::yaml_config::RunCommand::Platform(map) => {
-                        let entries: Vec<String> = map
-                            .iter()
-                            .map(|(os, cmd)| {
-                                format!("{}: {}", os.as_str(), cmd.to_command_string())
-                            })
-                            .collect();
-                        format!("{{{}}}", entries.join(", "))
-                    }
-                };
-                output.info(&format!("     {}: {}", dim("run"), run_display));
-            } else if let Some(ref script) = job.script {
-                let runner_str = job
-                    .runner
-                    .as_ref()
-                    .map(|r| format!("{r} "))
-                    .unwrap_or_default();
-                output.info(&format!("     {}: {}{}", dim("script"), runner_str, script));
-            } else if job.group.is_some() {
-                output.info(&format!("     {}", dim("(group)")));
-            }
-
-            if let Some(ref needs) = job.needs
-                && !needs.is_empty()
-            {
-                output.info(&format!("     {}: [{}]", dim("needs"), needs.join(", ")));
-            }
-
-            if let Some(ref tags) = job.tags
-                && !tags.is_empty()
-            {
-                output.info(&format!("     {}: [{}]", dim("tags"), tags.join(", ")));
-            }
-
-            if i + 1 < job_count {
-                output.info("");
-            }
-        }
-
-        return Ok(());
+        return preview_jobs(hook_name, hook_def, &filter, output);
     }
 
     // Build HookContext for execution
@@ -232,12 +108,222 @@ pub(super) fn cmd_run(args: &HooksRunArgs, output: &mut dyn Output) -> Result<()
             output.info(&dim(&format!("Skipped: {reason}")));
         }
     } else if !result.success {
-        std::process::exit(result.exit_code.unwrap_or(1));
+        crate::deprecations::exit(result.exit_code.unwrap_or(1));
+    }
+
+    Ok(())
+}
+
+fn parse_hook_type(name: &str) -> Result<HookType> {
+    HookType::from_yaml_name(name).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unknown hook type: '{}'\nValid hook types: {}",
+            name,
+            yaml_config::KNOWN_HOOK_NAMES.join(", ")
+        )
+    })
+}
+
+/// How the hook went in one worktree of a multi-worktree run.
+enum WorktreeOutcome {
+    Ran,
+    Failed(Option<i32>),
+    Skipped(String),
+}
+
+/// Run a hook in every worktree (`--all-worktrees`) or in those whose branch
+/// matches `--branch <glob>`, then summarize the outcome per worktree.
+fn cmd_run_worktrees(args: &HooksRunArgs, output: &mut dyn Output) -> Result<()> {
+    let hook_type = parse_hook_type(args.hook_type.as_deref().unwrap_or_default())?;
+    let git_dir = get_git_common_dir().context("Could not determine git directory")?;
+    let project_root = get_project_root().context("Could not determine project root")?;
+
+    let matcher = args
+        .branch
+        .as_deref()
+        .map(|glob| {
+            globset::Glob::new(glob)
+                .map(|g| g.compile_matcher())
+                .with_context(|| format!("Invalid --branch glob '{glob}'"))
+        })
+        .transpose()?;
+    let git = GitCommand::new(true);
+    let targets: Vec<(String, PathBuf)> =
+        parse_worktree_list_porcelain(&git.worktree_list_porcelain()?)
+            .into_iter()
+            .filter(|e| !e.is_bare)
+            .filter_map(|e| e.branch.map(|branch| (branch, e.path)))
+            .filter(|(branch, _)| matcher.as_ref().is_none_or(|m| m.is_match(branch)))
+            .collect();
+    if targets.is_empty() {
+        match args.branch {
+            Some(ref glob) => anyhow::bail!("No worktree has a branch matching '{glob}'"),
+            None => anyhow::bail!("No worktrees with a branch checked out"),
+        }
+    }
+
+    print_trust_note(&git_dir, output);
+
+    let filter = JobFilter {
+        only_job_name: args.job.clone(),
+        only_tags: args.tag.clone(),
+        ..Default::default()
+    };
+    let mut hooks_config = crate::core::settings::load_hooks_config()?;
+    if args.verbose {
+        hooks_config.output.verbose = true;
+    }
+    let output_config = hooks_config.output.clone();
+    let executor = HookExecutor::new(hooks_config)?
+        .with_bypass_trust(true)
+        .with_job_filter(filter.clone());
+
+    let run = WorktreeRun {
+        hook_type,
+        filter: &filter,
+        executor: &executor,
+        output_config: &output_config,
+        project_root: &project_root,
+        git_dir: &git_dir,
+        dry_run: args.dry_run,
+    };
+    let mut outcomes = Vec::with_capacity(targets.len());
+    for (branch, path) in &targets {
+        output.info(&format!(
+            "{} {}",
+            bold(branch),
+            dim(&path.display().to_string())
+        ));
+        let outcome = run.in_worktree(branch, path, output);
+        outcomes.push((branch.as_str(), outcome));
+        output.info("");
     }
 
+    print_worktree_summary(hook_type.yaml_name(), &outcomes, args.dry_run, output);
+    if outcomes
+        .iter()
+        .any(|(_, o)| matches!(o, WorktreeOutcome::Failed(_)))
+    {
+        crate::deprecations::exit(1);
+    }
     Ok(())
 }
 
+/// What stays the same across the worktrees of a multi-worktree run.
+struct WorktreeRun<'a> {
+    hook_type: HookType,
+    filter: &'a JobFilter,
+    executor: &'a HookExecutor,
+    output_config: &'a crate::settings::HookOutputConfig,
+    project_root: &'a std::path::Path,
+    git_dir: &'a std::path::Path,
+    dry_run: bool,
+}
+
+impl WorktreeRun<'_> {
+    /// Run (or preview) the hook in one worktree, using that worktree's own
+    /// `daft.yml`.
+    fn in_worktree(
+        &self,
+        branch: &str,
+        path: &std::path::Path,
+        output: &mut dyn Output,
+    ) -> WorktreeOutcome {
+        let hook_name = self.hook_type.yaml_name();
+        let config = match yaml_config_loader::load_merged_config(path) {
+            Ok(Some(config)) => config,
+            Ok(None) => return WorktreeOutcome::Skipped("no daft.yml".into()),
+            Err(e) => return WorktreeOutcome::Skipped(format!("could not load daft.yml: {e}")),
+        };
+        let Some(hook_def) = config.hooks.get(hook_name) else {
+            return WorktreeOutcome::Skipped("hook not defined in daft.yml".into());
+        };
+        if let Some(reason) = filter_mismatch(hook_def, self.filter) {
+            return WorktreeOutcome::Skipped(reason);
+        }
+        if self.dry_run {
+            return match preview_jobs(hook_name, hook_def, self.filter, output) {
+                Ok(()) => WorktreeOutcome::Ran,
+                Err(e) => WorktreeOutcome::Skipped(e.to_string()),
+            };
+        }
+
+        let ctx = crate::hooks::HookContext::new(
+            self.hook_type,
+            "hooks-run",
+            self.project_root,
+            self.git_dir,
+            "origin",
+            path,
+            path,
+            branch,
+        );
+        match self
+            .executor
+            .execute(&ctx, output, CliPresenter::auto(self.output_config))
+        {
+            Ok(result) if result.skipped => {
+                WorktreeOutcome::Skipped(result.skip_reason.unwrap_or_default())
+            }
+            Ok(result) if !result.success => WorktreeOutcome::Failed(result.exit_code),
+            Ok(_) => WorktreeOutcome::Ran,
+            Err(e) => {
+                output.error(&format!("{e:#}"));
+                WorktreeOutcome::Failed(None)
+            }
+        }
+    }
+}
+
+/// Why `--job`/`--tag` select nothing in this hook, if they don't. A branch
+/// whose `daft.yml` predates the job is skipped rather than failed.
+fn filter_mismatch(hook_def: &yaml_config::HookDef, filter: &JobFilter) -> Option<String> {
+    let jobs = crate::hooks::yaml_config_loader::get_effective_jobs(hook_def);
+    if let Some(ref name) = filter.only_job_name
+        && !jobs
+            .iter()
+            .any(|j| j.name.as_deref() == Some(name.as_str()))
+    {
+        return Some(format!("no job named '{name}'"));
+    }
+    if !filter.only_tags.is_empty()
+        && !jobs.iter().any(|j| {
+            j.tags
+                .as_ref()
+                .is_some_and(|tags| tags.iter().any(|t| filter.only_tags.contains(t)))
+        })
+    {
+        return Some(format!("no job tagged {}", filter.only_tags.join(", ")));
+    }
+    None
+}
+
+fn print_worktree_summary(
+    hook_name: &str,
+    outcomes: &[(&str, WorktreeOutcome)],
+    dry_run: bool,
+    output: &mut dyn Output,
+) {
+    let width = outcomes.iter().map(|(b, _)| b.len()).max().unwrap_or(0);
+    output.info(&format!(
+        "{} {} in {} worktree{}",
+        bold("Summary:"),
+        cyan(hook_name),
+        outcomes.len(),
+        if outcomes.len() == 1 { "" } else { "s" }
+    ));
+    for (branch, outcome) in outcomes {
+        let status = match outcome {
+            WorktreeOutcome::Ran if dry_run => green("would run"),
+            WorktreeOutcome::Ran => green("ok"),
+            WorktreeOutcome::Failed(Some(code)) => red(&format!("failed (exit {code})")),
+            WorktreeOutcome::Failed(None) => red("failed"),
+            WorktreeOutcome::Skipped(reason) => dim(&format!("skipped: {reason}")),
+        };
+        output.info(&format!("  {branch:<width$}  {status}"));
+    }
+}
+
 /// List available hooks when `hooks run` is invoked with no arguments.
 fn cmd_run_list_hooks(
     config: &yaml_config::YamlConfig,
@@ -303,3 +389,180 @@ fn cmd_run_list_hooks(
 
     Ok(())
 }
+
+/// Print the jobs `hook_def` would run under `filter`, without running them.
+fn preview_jobs(
+    hook_name: &str,
+    hook_def: &yaml_config::HookDef,
+    filter: &JobFilter,
+    output: &mut dyn Output,
+) -> Result<()> {
+    use crate::hooks::yaml_config_loader::get_effective_jobs;
+
+    let mut jobs = get_effective_jobs(hook_def);
+
+    // Apply exclude_tags from hook definition
+    if let Some(ref exclude_tags) = hook_def.exclude_tags {
+        jobs.retain(|job| {
+            if let Some(ref tags) = job.tags {
+                !tags.iter().any(|t| exclude_tags.contains(t))
+            } else {
+                true
+            }
+        });
+    }
+
+    // Apply inclusion filters
+    if let Some(ref name) = filter.only_job_name {
+        jobs.retain(|j| j.name.as_deref() == Some(name.as_str()));
+        if jobs.is_empty() {
+            anyhow::bail!("No job named '{}' found in hook '{}'", name, hook_name);
+        }
+    }
+    if !filter.only_tags.is_empty() {
+        jobs.retain(|job| {
+            job.tags
+                .as_ref()
+                .is_some_and(|tags| tags.iter().any(|t| filter.only_tags.contains(t)))
+        });
+        if jobs.is_empty() {
+            anyhow::bail!(
+                "No jobs matching tags {:?} in hook '{}'",
+                filter.only_tags,
+                hook_name
+            );
+        }
+    }
+
+    // Sort by priority
+    jobs.sort_by_key(|j| j.priority.unwrap_or(0));
+
+    if jobs.is_empty() {
+        output.info(&dim("No jobs to run."));
+        return Ok(());
+    }
+
+    let job_count = jobs.len();
+    let job_word = if job_count == 1 { "job" } else { "jobs" };
+    output.info(&format!(
+        "{} {} ({} {})",
+        bold("Hook:"),
+        cyan(hook_name),
+        job_count,
+        job_word
+    ));
+    output.info("");
+
+    for (i, job) in jobs.iter().enumerate() {
+        let name = job.name.as_deref().unwrap_or("(unnamed)");
+        output.info(&format!("  {}. {}", i + 1, bold(name)));
+
+        if let Some(ref desc) = job.description {
+            output.info(&format!("     {}", dim(desc)));
+        }
+
+        if let Some(ref arch) = job.arch {
+            let arch_list: Vec<&str> = arch.as_slice().iter().map(|a| a.as_str()).collect();
+            output.info(&format!("     {}: {}", dim("arch"), arch_list.join(", ")));
+        }
+
+        if let Some(ref run) = job.run {
+            let run_display = match run {
+                crate::hooks::yaml_config::RunCommand::Simple(s) => s.clone(),
+                crate::hooks::yaml_config::RunCommand::Platform(map) => {
+                    let entries: Vec<String> = map
+                        .iter()
+                        .map(|(os, cmd)| format!("{}: {}", os.as_str(), cmd.to_command_string()))
+                        .collect();
+                    format!("{{{}}}", entries.join(", "))
+                }
+            };
+            output.info(&format!("     {}: {}", dim("run"), run_display));
+        } else if let Some(ref script) = job.script {
+            let runner_str = job
+                .runner
+                .as_ref()
+                .map(|r| format!("{r} "))
+                .unwrap_or_default();
+            output.info(&format!("     {}: {}{}", dim("script"), runner_str, script));
+        } else if job.group.is_some() {
+            output.info(&format!("     {}", dim("(group)")));
+        }
+
+        if let Some(ref needs) = job.needs
+            && !needs.is_empty()
+        {
+            output.info(&format!("     {}: [{}]", dim("needs"), needs.join(", ")));
+        }
+
+        if let Some(ref tags) = job.tags
+            && !tags.is_empty()
+        {
+            output.info(&format!("     {}: [{}]", dim("tags"), tags.join(", ")));
+        }
+
+        if i + 1 < job_count {
+            output.info("");
+        }
+    }
+
+    Ok(())
+}
+
+/// Note that `hooks run` bypasses trust, with a hint when the repository is
+/// not trusted for automatic runs.
+fn print_trust_note(git_dir: &std::path::Path, output: &mut dyn Output) {
+    let trust_db = TrustDatabase::load().unwrap_or_default();
+    let trust_level = trust_db.get_trust_level(git_dir);
+    if trust_level != TrustLevel::Allow {
+        output.info(&format!(
+            "{} this repository is not in your trust list ({}).",
+            dim("Note:"),
+            styled_trust_level(trust_level)
+        ));
+        output.info(&format!(
+            "  {} run `{}` to allow hooks to run automatically.",
+            dim("Tip:"),
+            cyan(&crate::daft_cmd("hooks trust"))
+        ));
+        output.info("");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::yaml_config::{HookDef, JobDef};
+
+    fn hook_with(name: &str, tags: &[&str]) -> HookDef {
+        HookDef {
+            jobs: Some(vec![JobDef {
+                name: Some(name.into()),
+                tags: Some(tags.iter().map(|t| t.to_string()).collect()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn filter_mismatch_names_the_missing_job_or_tag() {
+        let hook = hook_with("install", &["deps"]);
+        assert_eq!(filter_mismatch(&hook, &JobFilter::default()), None);
+
+        let by_name = JobFilter {
+            only_job_name: Some("build".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter_mismatch(&hook, &by_name).as_deref(),
+            Some("no job named 'build'")
+        );
+
+        let by_tag = JobFilter {
+            only_tags: vec!["deps".into()],
+            ..Default::default()
+        };
+        assert_eq!(filter_mismatch(&hook, &by_tag), None);
+    }
+}
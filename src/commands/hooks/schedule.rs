@@ -0,0 +1,193 @@
+//! `daft hooks schedule` — install scheduled tasks as OS timers.
+
+use super::find_worktree_root;
+use crate::hooks::schedule::{Backend, Schedule, ScheduledTask, unit_prefix};
+use crate::hooks::{TrustDatabase, TrustLevel, yaml_config_loader};
+use crate::output::Output;
+use crate::styles::{bold, cyan, dim, green, yellow};
+use anyhow::{Context, Result, bail};
+use clap::{Args, Subcommand};
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub(super) struct ScheduleArgs {
+    #[command(subcommand)]
+    command: Option<ScheduleCommand>,
+}
+
+#[derive(Subcommand)]
+enum ScheduleCommand {
+    /// Generate and enable timers for this worktree's scheduled tasks
+    Install {
+        /// Print the generated units without writing or enabling them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Disable and remove this worktree's timers
+    Uninstall,
+}
+
+pub(super) fn run(args: ScheduleArgs, output: &mut dyn Output) -> Result<()> {
+    let worktree = find_worktree_root()?;
+    match args.command {
+        None => cmd_list(&worktree, output),
+        Some(ScheduleCommand::Install { dry_run }) => cmd_install(&worktree, dry_run, output),
+        Some(ScheduleCommand::Uninstall) => cmd_uninstall(&worktree, output),
+    }
+}
+
+/// The worktree's tasks that declare a `schedule:`, sorted by name.
+fn scheduled_tasks(worktree: &Path) -> Result<Vec<(String, Schedule)>> {
+    let Some(config) =
+        yaml_config_loader::load_merged_config(worktree).context("Failed to load YAML config")?
+    else {
+        return Ok(Vec::new());
+    };
+    let mut tasks = config
+        .tasks
+        .iter()
+        .filter_map(|(name, def)| def.schedule.as_ref().map(|s| (name, s)))
+        .map(|(name, schedule)| {
+            let schedule = schedule
+                .parse()
+                .with_context(|| format!("tasks.{name}: invalid schedule '{schedule}'"))?;
+            Ok((name.clone(), schedule))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    tasks.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(tasks)
+}
+
+fn cmd_list(worktree: &Path, output: &mut dyn Output) -> Result<()> {
+    let tasks = scheduled_tasks(worktree)?;
+    if tasks.is_empty() {
+        output.info(&dim("No scheduled tasks in daft.yml."));
+        return Ok(());
+    }
+    let backend = Backend::detect()?;
+    let installed = backend.installed(&unit_prefix(worktree));
+    let width = tasks.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    output.info(&bold("Scheduled tasks:"));
+    for (name, schedule) in &tasks {
+        let task = ScheduledTask::for_task(worktree, name, schedule, PathBuf::new(), String::new());
+        let state = if installed.contains(&task.unit_name()) {
+            green("installed")
+        } else {
+            yellow("not installed")
+        };
+        output.info(&format!(
+            "  {name:<width$}  {}  {}",
+            cyan(&schedule.to_string()),
+            state
+        ));
+    }
+    if installed.len() < tasks.len() {
+        output.info("");
+        output.info(&dim(&format!(
+            "Run `{}` to install them.",
+            crate::daft_cmd("hooks schedule install")
+        )));
+    }
+    Ok(())
+}
+
+fn cmd_install(worktree: &Path, dry_run: bool, output: &mut dyn Output) -> Result<()> {
+    let tasks = scheduled_tasks(worktree)?;
+    let backend = Backend::detect()?;
+    let prefix = unit_prefix(worktree);
+
+    // Scheduled tasks run unattended, so they need the same standing consent
+    // lifecycle hooks do. This check only keeps installs honest: the timer
+    // runs `daft __scheduled-run`, which re-checks trust every time it fires.
+    let git_dir = crate::get_git_common_dir().context("Could not determine git directory")?;
+    let level = TrustDatabase::load()
+        .unwrap_or_default()
+        .get_trust_level(&git_dir);
+    if !tasks.is_empty() && level != TrustLevel::Allow {
+        bail!(
+            "scheduled tasks run unattended, so the repository must be trusted (it is '{level}'); run `{}` first",
+            crate::daft_cmd("hooks trust")
+        );
+    }
+
+    let daft = std::env::current_exe()
+        .context("Could not determine current executable")?
+        .canonicalize()
+        .context("Could not canonicalize executable path")?;
+    let path_env = std::env::var("PATH").unwrap_or_default();
+    let tasks: Vec<ScheduledTask> = tasks
+        .iter()
+        .map(|(name, schedule)| {
+            ScheduledTask::for_task(worktree, name, schedule, daft.clone(), path_env.clone())
+        })
+        .collect();
+
+    let wanted: Vec<String> = tasks.iter().map(ScheduledTask::unit_name).collect();
+    let stale: Vec<String> = backend
+        .installed(&prefix)
+        .into_iter()
+        .filter(|name| !wanted.contains(name))
+        .collect();
+
+    if dry_run {
+        for task in &tasks {
+            for (path, contents) in backend.files(task)? {
+                output.info(&bold(&path.display().to_string()));
+                output.info(&contents);
+            }
+        }
+        for name in &stale {
+            output.info(&format!("Would remove {}", bold(name)));
+        }
+        if tasks.is_empty() && stale.is_empty() {
+            output.info(&dim("No scheduled tasks in daft.yml."));
+        }
+        return Ok(());
+    }
+
+    for name in &stale {
+        backend.remove(name)?;
+        output.info(&format!("Removed {}", dim(name)));
+    }
+    if tasks.is_empty() {
+        if stale.is_empty() {
+            output.info(&dim("No scheduled tasks in daft.yml."));
+        }
+        return Ok(());
+    }
+
+    backend.write(&tasks)?;
+
+    let manual = backend.activate(&wanted);
+    for task in &tasks {
+        output.success(&format!(
+            "Scheduled {} ({})",
+            bold(&task.task),
+            cyan(&task.schedule.to_string())
+        ));
+    }
+    output.info(&dim(&format!(
+        "Units written to {}",
+        backend.dir().display()
+    )));
+    if let Some(command) = manual {
+        output.warning(&format!(
+            "Could not enable the timers; enable them with: {command}"
+        ));
+    }
+    Ok(())
+}
+
+fn cmd_uninstall(worktree: &Path, output: &mut dyn Output) -> Result<()> {
+    let backend = Backend::detect()?;
+    let installed = backend.installed(&unit_prefix(worktree));
+    if installed.is_empty() {
+        output.info(&dim("No scheduled tasks installed for this worktree."));
+        return Ok(());
+    }
+    for name in &installed {
+        backend.remove(name)?;
+        output.success(&format!("Removed {}", bold(name)));
+    }
+    Ok(())
+}
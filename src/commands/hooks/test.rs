@@ -0,0 +1,602 @@
+//! `daft hooks test`: run the `tests:` section of daft.yml.
+//!
+//! Each test fires one hook in a throwaway clone of the current worktree —
+//! committed history plus the worktree's tracked and untracked files, so
+//! uncommitted edits to the config and its scripts are what gets tested. The
+//! clone has no remote and its job logs go to a temp state directory, so a
+//! test run leaves the real repository, its trust records and the job log
+//! untouched. Background jobs run inline so their results can be asserted.
+
+use super::find_worktree_root;
+use crate::core::repo::git_stdout;
+use crate::executor::presenter::JobPresenter;
+use crate::executor::{JobResult, JobSpec};
+use crate::hooks::yaml_config::{HookDef, HookTest, HookTestExpect, JobDef, YamlConfig};
+use crate::hooks::yaml_executor::{self, HookExecutionContext, JobFilter};
+use crate::hooks::{HookContext, HookEnvironment, HookType, RemovalReason, yaml_config_loader};
+use crate::output::Output;
+use crate::styles::{bold, cyan, dim, green, red};
+use crate::utils::git_command_at;
+use anyhow::{Context, Result, bail};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Run the hook tests defined in daft.yml, or only the one named `name`.
+pub(super) fn cmd_test(name: Option<&str>, output: &mut dyn Output) -> Result<()> {
+    let worktree_root = find_worktree_root()?;
+    let config = yaml_config_loader::load_merged_config(&worktree_root)
+        .context("Failed to load YAML config")?;
+    let Some(config) = config else {
+        output.info(&dim("No daft.yml found."));
+        return Ok(());
+    };
+
+    let tests: Vec<&HookTest> = config
+        .tests
+        .iter()
+        .flatten()
+        .filter(|t| name.is_none_or(|n| t.name == n))
+        .collect();
+    if tests.is_empty() {
+        if let Some(name) = name {
+            bail!("No test named '{name}' in daft.yml");
+        }
+        output.info(&dim("No tests defined in daft.yml."));
+        return Ok(());
+    }
+
+    let source = SandboxSource::resolve(&worktree_root)?;
+    output.info(&format!(
+        "Running {} hook test{}",
+        tests.len(),
+        if tests.len() == 1 { "" } else { "s" }
+    ));
+
+    let mut failed = 0;
+    for test in &tests {
+        let outcome = run_test(test, &source, output);
+        let label = format!("{} {}", test.name, dim(&format!("({})", test.hook)));
+        match outcome {
+            Ok(run) => {
+                let failures = check_expectations(test.expect.as_ref(), &run);
+                if failures.is_empty() {
+                    output.info(&format!("  {} {label}", green("\u{2713}")));
+                } else {
+                    failed += 1;
+                    output.info(&format!("  {} {label}", red("\u{2717}")));
+                    print_failures(&failures, &run, output);
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                output.info(&format!("  {} {label}", red("\u{2717}")));
+                output.info(&format!("      {}", red(&format!("{e:#}"))));
+            }
+        }
+    }
+
+    let passed = tests.len() - failed;
+    output.info("");
+    let summary = format!("{passed} passed, {failed} failed");
+    if failed == 0 {
+        output.success(&green(&summary));
+        Ok(())
+    } else {
+        output.error(&red(&summary));
+        crate::deprecations::exit(1);
+    }
+}
+
+/// What every sandbox is built from.
+struct SandboxSource<'a> {
+    worktree_root: &'a Path,
+    git_common_dir: std::path::PathBuf,
+    head: String,
+    branch: Option<String>,
+}
+
+impl<'a> SandboxSource<'a> {
+    fn resolve(worktree_root: &'a Path) -> Result<Self> {
+        let git_common_dir = crate::core::repo::git_common_dir_at(worktree_root)
+            .context("Could not determine git directory")?;
+        let head = git_stdout(worktree_root, &["rev-parse", "--verify", "HEAD"])
+            .context("Hook tests need a worktree with at least one commit")?
+            .trim()
+            .to_string();
+        let branch = git_stdout(worktree_root, &["branch", "--show-current"])
+            .ok()
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty());
+        Ok(Self {
+            worktree_root,
+            git_common_dir,
+            head,
+            branch,
+        })
+    }
+
+    /// Clone the repository into `dir`, check out `branch` (or the current
+    /// branch) at the worktree's HEAD, and copy the worktree's files over it.
+    fn create(&self, dir: &Path, branch: Option<&str>) -> Result<String> {
+        let parent = dir.parent().unwrap_or(dir);
+        git_run(
+            parent,
+            &[
+                "clone",
+                "--quiet",
+                "--no-checkout",
+                &self.git_common_dir.to_string_lossy(),
+                &dir.to_string_lossy(),
+            ],
+        )?;
+        let branch = branch.or(self.branch.as_deref());
+        match branch {
+            Some(branch) => git_run(dir, &["checkout", "--quiet", "-B", branch, &self.head])?,
+            None => git_run(dir, &["checkout", "--quiet", "--detach", &self.head])?,
+        }
+        git_run(dir, &["remote", "remove", "origin"])?;
+        copy_worktree_files(self.worktree_root, dir)?;
+        Ok(branch.unwrap_or("HEAD").to_string())
+    }
+}
+
+/// Copy the worktree's tracked and untracked files into the sandbox, so the
+/// test sees uncommitted edits. Ignored files — `daft.local.yml` included —
+/// stay out, matching what a CI checkout sees.
+fn copy_worktree_files(from: &Path, to: &Path) -> Result<()> {
+    let listing = git_command_at(from)
+        .args([
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ])
+        .output()
+        .context("Failed to list worktree files")?;
+    if !listing.status.success() {
+        bail!("git ls-files failed in {}", from.display());
+    }
+    for rel in listing.stdout.split(|b| *b == 0).filter(|p| !p.is_empty()) {
+        let rel = Path::new(std::str::from_utf8(rel).context("Non-UTF-8 path in worktree")?);
+        let src = from.join(rel);
+        let dest = to.join(rel);
+        let Ok(meta) = std::fs::symlink_metadata(&src) else {
+            // Deleted in the worktree but not yet committed.
+            let _ = std::fs::remove_file(&dest);
+            continue;
+        };
+        if meta.is_dir() {
+            continue;
+        }
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let _ = std::fs::remove_file(&dest);
+        #[cfg(unix)]
+        if meta.file_type().is_symlink() {
+            std::os::unix::fs::symlink(std::fs::read_link(&src)?, &dest)?;
+            continue;
+        }
+        std::fs::copy(&src, &dest).with_context(|| format!("Failed to copy {}", rel.display()))?;
+    }
+    Ok(())
+}
+
+/// Fire one test's hook in a fresh sandbox and record what its jobs did.
+fn run_test(test: &HookTest, source: &SandboxSource, output: &mut dyn Output) -> Result<TestRun> {
+    let hook_type = HookType::from_yaml_name(&test.hook)
+        .with_context(|| format!("'{}' is not a lifecycle hook", test.hook))?;
+
+    let tmp = tempfile::Builder::new()
+        .prefix("daft-hooks-test-")
+        .tempdir()
+        .context("Failed to create sandbox directory")?;
+    let repo = tmp.path().join("repo");
+    let branch = source.create(&repo, test.branch.as_deref())?;
+
+    let config: YamlConfig = yaml_config_loader::load_merged_config(&repo)?
+        .context("No daft.yml in the sandbox; is it gitignored?")?;
+    let hook_def = config
+        .hooks
+        .get(&test.hook)
+        .with_context(|| format!("Hook '{}' is not defined in daft.yml", test.hook))?;
+    let hook_def = foreground_only(hook_def);
+
+    let mut ctx = HookContext::new(
+        hook_type,
+        "hooks-test",
+        &repo,
+        repo.join(".git"),
+        "origin",
+        &repo,
+        &repo,
+        &branch,
+    )
+    .with_state_dir(tmp.path().join("state"))
+    .with_extra_env(test.env.clone().unwrap_or_default());
+    if matches!(hook_type, HookType::PreRemove | HookType::PostRemove) {
+        ctx.removal_reason = Some(RemovalReason::Manual);
+    }
+
+    let recorder = Arc::new(Recorder::default());
+    let presenter: Arc<dyn JobPresenter> = recorder.clone();
+    let env = HookEnvironment::from_context(&ctx);
+    let filter = JobFilter::default();
+    let cfg = HookExecutionContext {
+        source_dir: config.source_dir.as_deref().unwrap_or(".daft"),
+        working_dir: env.working_directory(&ctx),
+        rc: config.rc.as_deref(),
+        filter: &filter,
+        presenter: &presenter,
+        repo_log: config.log.as_ref(),
+        default_job_timeout: Some(JobSpec::DEFAULT_TIMEOUT),
+        cancel: None,
+        trigger_label: None,
+    };
+    let result =
+        yaml_executor::execute_yaml_hook_with_rc(&test.hook, &hook_def, &ctx, output, &cfg)?;
+
+    let exit_code = if result.success {
+        0
+    } else {
+        result.exit_code.unwrap_or(1)
+    };
+    let jobs = std::mem::take(&mut *recorder.jobs.lock().unwrap());
+    Ok(TestRun { exit_code, jobs })
+}
+
+/// `hook_def` with every job, group and the hook default moved to the
+/// foreground.
+fn foreground_only(hook_def: &HookDef) -> HookDef {
+    fn clear(jobs: &mut Option<Vec<JobDef>>) {
+        for job in jobs.iter_mut().flatten() {
+            job.background = None;
+            if let Some(group) = job.group.as_mut() {
+                clear(&mut group.jobs);
+            }
+        }
+    }
+    let mut def = hook_def.clone();
+    def.background = None;
+    clear(&mut def.jobs);
+    def
+}
+
+/// What a test's hook run did.
+#[derive(Debug, Default)]
+struct TestRun {
+    exit_code: i32,
+    jobs: BTreeMap<String, JobRecord>,
+}
+
+/// What one job did.
+#[derive(Debug, Default)]
+struct JobRecord {
+    outcome: JobOutcome,
+    output: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+enum JobOutcome {
+    #[default]
+    Running,
+    Succeeded,
+    Failed(Option<i32>),
+    Skipped(String),
+    Cancelled,
+}
+
+/// A presenter that records each job's outcome and output instead of
+/// rendering them.
+#[derive(Default)]
+struct Recorder {
+    jobs: Mutex<BTreeMap<String, JobRecord>>,
+}
+
+impl Recorder {
+    fn set(&self, name: &str, outcome: JobOutcome) {
+        self.jobs
+            .lock()
+            .unwrap()
+            .entry(name.to_string())
+            .or_default()
+            .outcome = outcome;
+    }
+}
+
+impl JobPresenter for Recorder {
+    fn on_phase_start(&self, _phase_name: &str, _target: Option<&str>) {}
+    fn on_job_start(&self, name: &str, _description: Option<&str>, _command: Option<&str>) {
+        self.set(name, JobOutcome::Running);
+    }
+    fn on_job_output(&self, name: &str, line: &str) {
+        let mut jobs = self.jobs.lock().unwrap();
+        let output = &mut jobs.entry(name.to_string()).or_default().output;
+        output.push_str(line);
+        output.push('\n');
+    }
+    fn on_job_success(&self, name: &str, _duration: Duration) {
+        self.set(name, JobOutcome::Succeeded);
+    }
+    fn on_job_failure(&self, name: &str, _duration: Duration) {
+        self.set(name, JobOutcome::Failed(None));
+    }
+    fn on_job_failure_with_exit(&self, name: &str, _duration: Duration, exit_code: Option<i32>) {
+        self.set(name, JobOutcome::Failed(exit_code));
+    }
+    fn on_job_skipped(
+        &self,
+        name: &str,
+        reason: &str,
+        _duration: Duration,
+        _show_duration: bool,
+        _command: Option<&str>,
+    ) {
+        self.set(name, JobOutcome::Skipped(reason.to_string()));
+    }
+    fn on_job_cancelled(&self, name: &str, _duration: Duration) {
+        self.set(name, JobOutcome::Cancelled);
+    }
+    fn on_job_background(&self, _name: &str, _description: Option<&str>) {}
+    fn on_message(&self, _msg: &str) {}
+    fn on_phase_complete(&self, _total_duration: Duration) {}
+    fn take_results(&self) -> Vec<JobResult> {
+        Vec::new()
+    }
+}
+
+/// One unmet expectation, with the job it concerns.
+#[derive(Debug)]
+struct Failure {
+    job: Option<String>,
+    message: String,
+}
+
+/// Compare a run against the test's expectations. Without an `expect:`
+/// section the hook is expected to succeed.
+fn check_expectations(expect: Option<&HookTestExpect>, run: &TestRun) -> Vec<Failure> {
+    let mut failures = Vec::new();
+    let mut fail = |job: Option<&str>, message: String| {
+        failures.push(Failure {
+            job: job.map(str::to_string),
+            message,
+        })
+    };
+
+    let expected_exit = expect.and_then(|e| e.exit_code).unwrap_or(0);
+    if run.exit_code != expected_exit {
+        fail(
+            None,
+            format!(
+                "expected the hook to exit {expected_exit}, got {}",
+                run.exit_code
+            ),
+        );
+    }
+
+    for (name, job) in expect.and_then(|e| e.jobs.as_ref()).into_iter().flatten() {
+        let Some(record) = run.jobs.get(name) else {
+            fail(Some(name), format!("job '{name}' did not run"));
+            continue;
+        };
+        let skipped = matches!(record.outcome, JobOutcome::Skipped(_));
+        match (job.skipped, &record.outcome) {
+            (Some(true), outcome) if !skipped => {
+                fail(
+                    Some(name),
+                    format!(
+                        "job '{name}' ran ({}), expected it to be skipped",
+                        describe(outcome)
+                    ),
+                );
+            }
+            (Some(false), JobOutcome::Skipped(reason)) => {
+                fail(Some(name), format!("job '{name}' was skipped: {reason}"));
+            }
+            _ => {}
+        }
+        if let Some(expected) = job.exit_code {
+            let actual = match record.outcome {
+                JobOutcome::Succeeded => Some(0),
+                JobOutcome::Failed(code) => code,
+                _ => None,
+            };
+            if actual != Some(expected) {
+                fail(
+                    Some(name),
+                    format!(
+                        "job '{name}' {}, expected exit {expected}",
+                        describe(&record.outcome)
+                    ),
+                );
+            }
+        }
+        for needle in job.output_contains.iter().flatten() {
+            if !record.output.contains(needle.as_str()) {
+                fail(
+                    Some(name),
+                    format!("job '{name}' output does not contain \"{needle}\""),
+                );
+            }
+        }
+        for needle in job.output_not_contains.iter().flatten() {
+            if record.output.contains(needle.as_str()) {
+                fail(
+                    Some(name),
+                    format!("job '{name}' output contains \"{needle}\""),
+                );
+            }
+        }
+    }
+    failures
+}
+
+fn describe(outcome: &JobOutcome) -> String {
+    match outcome {
+        JobOutcome::Running => "did not finish".into(),
+        JobOutcome::Succeeded => "exited 0".into(),
+        JobOutcome::Failed(Some(code)) => format!("exited {code}"),
+        JobOutcome::Failed(None) => "failed without an exit code".into(),
+        JobOutcome::Skipped(reason) => format!("was skipped: {reason}"),
+        JobOutcome::Cancelled => "was cancelled".into(),
+    }
+}
+
+/// Print unmet expectations, then the output of the jobs they concern (or of
+/// the failed jobs, when only the hook's exit code was off).
+fn print_failures(failures: &[Failure], run: &TestRun, output: &mut dyn Output) {
+    for failure in failures {
+        output.info(&format!("      {}", red(&failure.message)));
+    }
+    let mut shown: Vec<&str> = failures.iter().filter_map(|f| f.job.as_deref()).collect();
+    if shown.is_empty() {
+        shown = run
+            .jobs
+            .iter()
+            .filter(|(_, r)| matches!(r.outcome, JobOutcome::Failed(_)))
+            .map(|(name, _)| name.as_str())
+            .collect();
+    }
+    shown.dedup();
+    for name in shown {
+        let Some(record) = run.jobs.get(name) else {
+            continue;
+        };
+        if record.output.is_empty() {
+            continue;
+        }
+        output.info(&format!("      {} {}", bold("output of"), cyan(name)));
+        for line in record.output.lines() {
+            output.info(&format!("        {}", dim(line)));
+        }
+    }
+}
+
+/// Run git in `dir`, failing with its stderr.
+fn git_run(dir: &Path, args: &[&str]) -> Result<()> {
+    let out = git_command_at(dir)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args[0]))?;
+    if !out.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::yaml_config::{GroupDef, JobExpect};
+
+    fn run_with(jobs: Vec<(&str, JobOutcome, &str)>, exit_code: i32) -> TestRun {
+        TestRun {
+            exit_code,
+            jobs: jobs
+                .into_iter()
+                .map(|(name, outcome, output)| {
+                    let output = output.to_string();
+                    (name.to_string(), JobRecord { outcome, output })
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn a_missing_expect_section_requires_the_hook_to_succeed() {
+        let ok = run_with(vec![("install", JobOutcome::Succeeded, "")], 0);
+        assert!(check_expectations(None, &ok).is_empty());
+
+        let failed = run_with(vec![("install", JobOutcome::Failed(Some(2)), "")], 2);
+        let failures = check_expectations(None, &failed);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].message, "expected the hook to exit 0, got 2");
+    }
+
+    #[test]
+    fn job_expectations_check_exit_code_skip_and_output() {
+        let run = run_with(
+            vec![
+                ("install", JobOutcome::Succeeded, "added 12 packages\n"),
+                ("lint", JobOutcome::Failed(Some(1)), "error: unused\n"),
+                ("docs", JobOutcome::Skipped("no changes".into()), ""),
+            ],
+            1,
+        );
+        let mut jobs = BTreeMap::new();
+        jobs.insert(
+            "install".to_string(),
+            JobExpect {
+                output_contains: Some(vec!["added".into()]),
+                output_not_contains: Some(vec!["WARN".into()]),
+                ..Default::default()
+            },
+        );
+        jobs.insert(
+            "lint".to_string(),
+            JobExpect {
+                exit_code: Some(0),
+                ..Default::default()
+            },
+        );
+        jobs.insert(
+            "docs".to_string(),
+            JobExpect {
+                skipped: Some(true),
+                ..Default::default()
+            },
+        );
+        jobs.insert("deploy".to_string(), JobExpect::default());
+        let expect = HookTestExpect {
+            exit_code: Some(1),
+            jobs: Some(jobs),
+        };
+
+        let messages: Vec<String> = check_expectations(Some(&expect), &run)
+            .into_iter()
+            .map(|f| f.message)
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "job 'deploy' did not run",
+                "job 'lint' exited 1, expected exit 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn foreground_only_clears_background_everywhere() {
+        let hook = HookDef {
+            background: Some(true),
+            jobs: Some(vec![JobDef {
+                name: Some("warm".into()),
+                background: Some(true),
+                group: Some(GroupDef {
+                    jobs: Some(vec![JobDef {
+                        background: Some(true),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let def = foreground_only(&hook);
+        assert_eq!(def.background, None);
+        let job = &def.jobs.as_ref().unwrap()[0];
+        assert_eq!(job.background, None);
+        assert_eq!(
+            job.group.as_ref().unwrap().jobs.as_ref().unwrap()[0].background,
+            None
+        );
+    }
+}
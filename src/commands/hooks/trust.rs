@@ -394,6 +394,7 @@ pub(super) fn cmd_list(
     text.push_str(&title);
     text.push_str("\n\n");
 
+    let time_format = crate::output::time::configured();
     for (path, entry) in &repos {
         // Strip .git suffix if present to show repo path
         let repo_path = path.strip_suffix("/.git").unwrap_or(path);
@@ -403,7 +404,7 @@ pub(super) fn cmd_list(
         } else {
             repo_path.to_string()
         };
-        let display_time = entry.formatted_time();
+        let display_time = entry.formatted_time(time_format);
         text.push_str(&format!("  {display_path}\n"));
         text.push_str(&format!(
             "    Level: {}  {}\n",
@@ -452,6 +453,7 @@ fn build_trust_table(repos: &[(&str, &crate::hooks::TrustEntry)]) -> Table {
         "trust_level",
         "remote_fingerprint",
         "timestamp",
+        "timestamp_epoch",
     ]);
     for (path, entry) in repos {
         let repo_path = path.strip_suffix("/.git").unwrap_or(path);
@@ -465,6 +467,7 @@ fn build_trust_table(repos: &[(&str, &crate::hooks::TrustEntry)]) -> Table {
             Cell::str(entry.level.to_string()),
             Cell::str(entry.fingerprint.as_deref().unwrap_or("")),
             Cell::str(timestamp),
+            Cell::int(entry.granted_at),
         ]);
     }
     table
@@ -47,6 +47,6 @@ pub(super) fn cmd_validate(output: &mut dyn Output) -> Result<()> {
             result.errors.len(),
             result.warnings.len()
         ));
-        std::process::exit(1);
+        crate::deprecations::exit(1);
     }
 }
@@ -7,7 +7,10 @@ use crate::{
         worktree::init,
     },
     git::GitCommand,
-    hints::{LayoutPromptResult, maybe_prompt_layout_choice, maybe_show_shell_hint},
+    hints::{
+        LayoutPromptResult, NextStep, maybe_prompt_layout_choice, maybe_show_shell_hint,
+        show_next_steps,
+    },
     hooks::TrustDatabase,
     logging::init_logging,
     output::{CliOutput, Output, OutputConfig},
@@ -96,7 +99,9 @@ pub struct Args {
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-init"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-init",
+    ));
 
     init_logging(args.verbose);
 
@@ -169,6 +174,7 @@ pub fn run_with_output(args: &Args, output: &mut dyn Output) -> Result<()> {
         remote: args.remote.clone(),
         multi_remote_enabled: settings.multi_remote_enabled,
         multi_remote_default: settings.multi_remote_default.clone(),
+        nested_projects: settings.nested_projects,
     };
 
     output.start_spinner("Initializing repository...");
@@ -213,6 +219,7 @@ pub fn run_with_output(args: &Args, output: &mut dyn Output) -> Result<()> {
             output.cd_path(cd_target);
         }
         maybe_show_shell_hint(output)?;
+        show_next_steps(NextStep::Initialized, settings.ui_hints, output);
 
         // Propagate exec error after cd_path is written
         exec_result?;
@@ -12,8 +12,8 @@ use crate::{
         CliOutput, Output, OutputConfig,
         emit::{self, Cell, EmitArgs, EmitPayload, Table},
         format::{
-            ColumnContext, compute_column_values, format_ahead_behind, format_head_status,
-            format_human_size, format_remote_status, format_shorthand_age, relative_display_path,
+            ColumnContext, compute_column_values, format_age, format_ahead_behind,
+            format_head_status, format_human_size, format_remote_status, relative_display_path,
             shorthand_from_seconds, strip_ansi,
         },
     },
@@ -241,7 +241,9 @@ struct TableRow {
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-list"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-list",
+    ));
 
     init_logging(args.verbose);
 
@@ -288,10 +290,37 @@ pub fn run() -> Result<()> {
 
     // Settings are loaded inside `run_live`/`run_blocking`, co-located with each
     // path's `GitCommand` so they share a single repo discovery (#584).
+    let structured = args.emit.is_structured();
     if should_use_live(&args) {
-        crate::commands::list_live::run_live(args)
+        crate::commands::list_live::run_live(args)?;
     } else {
-        run_blocking(args)
+        run_blocking(args)?;
+    }
+    if !structured {
+        print_unmanaged_note();
+    }
+    Ok(())
+}
+
+/// In a plain git repository the table is still git's worktree list, but
+/// nothing daft adds (layout paths, identities, hooks) applies yet. Say so on
+/// stderr, where it cannot corrupt piped output, and point at adoption.
+fn print_unmanaged_note() {
+    let Ok(git_common_dir) = get_git_common_dir() else {
+        return;
+    };
+    if !crate::core::layout::detect::is_unmanaged_repo(&git_common_dir) {
+        return;
+    }
+    let note = format!(
+        "This repository is not managed by daft; showing git's worktrees. \
+         Run `{}` to convert it to a worktree layout.",
+        crate::daft_cmd("adopt")
+    );
+    if styles::colors_enabled_stderr() {
+        eprintln!("{}", styles::dim(&note));
+    } else {
+        eprintln!("{note}");
     }
 }
 
@@ -718,6 +747,7 @@ fn run_blocking(args: Args) -> Result<()> {
         &table_columns,
         &sort_spec,
         forge_lookup.as_ref(),
+        settings.ui_time_format,
     );
     Ok(())
 }
@@ -783,6 +813,7 @@ impl EmitColumns {
             h.push("is_sandbox".into());
             h.push("operation".into());
             h.push("identity_source".into());
+            h.push("pinned".into());
         }
         if self.status {
             h.push("status".into());
@@ -831,6 +862,7 @@ impl EmitColumns {
         }
         if self.age {
             h.push("branch_age".into());
+            h.push("branch_created_epoch".into());
         }
         if self.owner {
             h.push("owner_name".into());
@@ -842,6 +874,7 @@ impl EmitColumns {
         if self.last_commit {
             h.push("last_commit_age".into());
             h.push("last_commit_subject".into());
+            h.push("last_commit_epoch".into());
         }
         h
     }
@@ -886,6 +919,10 @@ fn build_emit_table(
                 Some(src) => row.push(Cell::str(src.as_str())),
                 None => row.push(Cell::null()),
             }
+            match &info.pinned {
+                Some(pinned) => row.push(Cell::str(pinned)),
+                None => row.push(Cell::null()),
+            }
         }
         if cols.status {
             let status = crate::output::format::format_worktree_status(info);
@@ -1033,6 +1070,11 @@ fn build_emit_table(
                 .map(|ts| shorthand_from_seconds(now - ts))
                 .unwrap_or_default();
             row.push(Cell::str(branch_age));
+            row.push(
+                info.branch_creation_timestamp
+                    .map(Cell::int)
+                    .unwrap_or(Cell::Null),
+            );
         }
         if cols.owner {
             match &info.owner {
@@ -1059,6 +1101,11 @@ fn build_emit_table(
                 .unwrap_or_default();
             row.push(Cell::str(last_commit_age));
             row.push(Cell::str(&info.last_commit_subject));
+            row.push(
+                info.last_commit_timestamp
+                    .map(Cell::int)
+                    .unwrap_or(Cell::Null),
+            );
         }
 
         table = table.row(row);
@@ -1158,6 +1205,7 @@ fn render_annotation(
             AnnotationGlyph::Current => styles::cyan(symbol),
             AnnotationGlyph::DefaultBranch => styles::bright_purple(symbol),
             AnnotationGlyph::Sandbox => styles::dim(symbol),
+            AnnotationGlyph::Pinned => styles::blue(symbol),
             // Operations and drift share the attention colour with the
             // status column.
             AnnotationGlyph::Operation(_) | AnnotationGlyph::Drift => styles::yellow(symbol),
@@ -1166,6 +1214,7 @@ fn render_annotation(
     out
 }
 
+#[allow(clippy::too_many_arguments)]
 fn print_table(
     infos: &[crate::core::worktree::list::WorktreeInfo],
     project_root: &std::path::Path,
@@ -1174,6 +1223,7 @@ fn print_table(
     selected_columns: &[ListColumn],
     sort_spec: &SortSpec,
     forge_lookup: Option<&crate::core::worktree::forge_ref::ForgePrLookup>,
+    time_format: crate::settings::TimeFormat,
 ) {
     if infos.is_empty() {
         let _ = crate::commands::list_empty::print(
@@ -1228,6 +1278,7 @@ fn print_table(
         stat,
         forge_prs: forge_lookup,
         colors: use_color,
+        time_format,
     };
 
     // Pre-compute plain column values for alignment and reuse
@@ -1309,10 +1360,11 @@ fn print_table(
                 )
             };
 
-            let branch_age = format_shorthand_age(info.branch_creation_timestamp, now, use_color);
+            let branch_age =
+                format_age(info.branch_creation_timestamp, now, time_format, use_color);
 
             // Combine last commit age + subject, with age right-padded for alignment
-            let commit_age = format_shorthand_age(info.last_commit_timestamp, now, use_color);
+            let commit_age = format_age(info.last_commit_timestamp, now, time_format, use_color);
             let last_commit = if vals.last_commit_age.is_empty() {
                 vals.last_commit_subject.clone()
             } else if vals.last_commit_subject.is_empty() {
@@ -1868,6 +1920,7 @@ mod tests {
             op: None,
             identity_source: None,
             drifted: false,
+            pinned: None,
             forge_ref: None,
         };
         let infos = [info("main", true), info("feat", false)];
@@ -1931,6 +1984,7 @@ mod tests {
             op: None,
             identity_source: None,
             drifted: false,
+            pinned: None,
             forge_ref: None,
         };
         let selected = &[ListColumn::Branch, ListColumn::Path, ListColumn::Size];
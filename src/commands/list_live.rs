@@ -132,6 +132,10 @@ pub fn run_live(args: Args) -> Result<()> {
             worktree_branches.insert(branch);
         }
     }
+    crate::core::worktree::pin::apply_pins(
+        &mut worktree_infos,
+        &crate::core::worktree::pin::read_pins(&git_common_dir),
+    );
 
     // Optionally enumerate non-worktree branches (sync — cheap git for-each-ref).
     if show_local || show_remote {
@@ -342,6 +346,7 @@ pub fn run_live(args: Args) -> Result<()> {
     // the one caller that decorates.
     state.live.cfg.forge_prs = forge_lookup;
     state.live.cfg.forge_prs_loading = forge_loading;
+    state.live.cfg.time_format = settings.ui_time_format;
 
     // Watch the detached refresh conclude while the table is live: poll the
     // store's health stamp (cheap reader-pool read, no network — the render
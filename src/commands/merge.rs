@@ -455,7 +455,9 @@ fn prompt_daft_conflict_side(filename: &str, keys: &[String]) -> Option<crate::c
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-merge"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-merge",
+    ));
     init_logging(args.verbose);
 
     if !is_git_repository()? {
@@ -899,7 +901,7 @@ pub fn run() -> Result<()> {
             );
             eprintln!("  Commit manually: git commit");
             eprintln!("  Or reset: git reset --merge");
-            std::process::exit(1);
+            crate::deprecations::exit(1);
         }
 
         // Ephemeral-promote path: a ref-only target was adopted into a
@@ -937,7 +939,7 @@ pub fn run() -> Result<()> {
         eprintln!("resolve in the target worktree, then run:");
         eprintln!("  daft merge --continue  # add <branch> if running from a different worktree");
         eprintln!("  daft merge --abort     # add <branch> if running from a different worktree");
-        std::process::exit(1);
+        crate::deprecations::exit(1);
     } else {
         // The merge landed and the consolidated daft files persist in the
         // target. Announce exactly what was adopted (a cross-worktree write
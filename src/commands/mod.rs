@@ -3,16 +3,22 @@
 /// Each module represents a Git extension command that can be invoked
 /// either directly or via symlink detection in the multicall binary.
 pub mod activate;
+pub mod adopt_worktree;
+pub mod auth;
+pub mod backup;
 pub mod branch_delete;
 pub mod carry;
 pub mod checkout;
 pub mod clone;
+pub mod clone_all;
 pub mod complete;
 pub mod completions;
 pub mod config;
+pub mod consolidate;
 pub mod docs;
 pub mod doctor;
 pub mod dump_store;
+pub mod env;
 pub mod exec;
 pub mod fetch;
 pub mod file;
@@ -28,9 +34,11 @@ pub mod list_empty;
 pub mod list_live;
 pub mod merge;
 pub mod multi_remote;
+pub mod pin;
 pub mod prune;
 pub mod push;
 pub mod release_notes;
+pub mod remote;
 pub mod repo;
 pub mod run;
 pub mod shared;
@@ -38,6 +46,11 @@ pub mod shell_init;
 pub mod shortcuts;
 pub mod size_cache;
 pub mod skill;
+pub mod stats;
 pub mod sync;
 pub(super) mod sync_shared;
+pub mod template;
+pub mod whats_new;
+pub mod which;
+pub mod workspace;
 pub mod worktree_branch;
@@ -0,0 +1,133 @@
+//! Command: `daft pin` — hold a worktree at a fixed ref.
+
+use anyhow::{Result, bail};
+use chrono::Utc;
+use clap::Parser;
+
+use crate::core::OutputSink;
+use crate::core::worktree::pin;
+use crate::git::GitCommand;
+use crate::output::{CliOutput, Output};
+use crate::styles::{bold, dim};
+use crate::{get_git_common_dir, is_git_repository};
+
+#[derive(Parser)]
+#[command(name = "daft-pin")]
+#[command(version = crate::VERSION)]
+#[command(about = "Hold a worktree at a fixed ref")]
+#[command(long_about = r#"
+Pin a branch's worktree to a ref, keeping a known-good checkout in place
+while you experiment elsewhere. The worktree is switched to a detached
+checkout of REF (a tag, a commit, another branch); the branch itself is left
+where it is.
+
+While pinned, the worktree is held still:
+
+  - `daft update` and `daft sync` never advance it
+  - `daft prune` never removes it or deletes its branch
+  - `daft list` shows the pin in the annotation column and, with
+    --columns +status, as "pinned @ REF"
+
+The worktree must have no uncommitted changes. Pinning a branch that is
+already pinned moves it to the new ref.
+
+With no arguments, lists the pinned worktrees. Use --remove to release a pin;
+the branch is checked out in its worktree again.
+"#)]
+pub struct Args {
+    /// Branch whose worktree to pin
+    #[arg(value_name = "BRANCH")]
+    branch: Option<String>,
+
+    /// Ref to hold the worktree at (tag, commit or branch)
+    #[arg(value_name = "REF", conflicts_with = "remove")]
+    pinned_ref: Option<String>,
+
+    /// Release the pin and check the branch out again
+    #[arg(long, requires = "branch")]
+    remove: bool,
+}
+
+pub fn run() -> Result<()> {
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    if !is_git_repository()? {
+        bail!("Not inside a Git repository");
+    }
+    let git = GitCommand::new(false);
+    let git_common_dir = get_git_common_dir()?;
+
+    match (args.branch, args.pinned_ref) {
+        (None, _) => run_list(&git_common_dir, &mut output),
+        (Some(branch), _) if args.remove => {
+            let path = pin::unpin(&git, &git_common_dir, &branch, &mut OutputSink(&mut output))?;
+            match path {
+                Some(path) => output.success(&format!(
+                    "Unpinned {} {}",
+                    bold(&branch),
+                    dim(&format!("({} is back on the branch)", path.display()))
+                )),
+                None => output.success(&format!(
+                    "Unpinned {} {}",
+                    bold(&branch),
+                    dim("(its worktree no longer exists)")
+                )),
+            }
+            Ok(())
+        }
+        (Some(branch), Some(pinned_ref)) => {
+            let result = pin::pin(
+                &git,
+                &git_common_dir,
+                &branch,
+                &pinned_ref,
+                &mut OutputSink(&mut output),
+            )?;
+            let commit: String = result.commit_sha.chars().take(7).collect();
+            let moved = match result.previous_ref {
+                Some(previous) => format!(" (was {previous})"),
+                None => String::new(),
+            };
+            output.success(&format!(
+                "Pinned {} to {} {}{moved}",
+                bold(&branch),
+                bold(&pinned_ref),
+                dim(&format!("@ {commit}"))
+            ));
+            Ok(())
+        }
+        (Some(branch), None) => bail!(
+            "Missing the ref to pin '{branch}' to; use '{}' or '{}'",
+            crate::daft_cmd(&format!("pin {branch} <ref>")),
+            crate::daft_cmd(&format!("pin --remove {branch}"))
+        ),
+    }
+}
+
+fn run_list(git_common_dir: &std::path::Path, output: &mut dyn Output) -> Result<()> {
+    let mut pins: Vec<_> = pin::read_pins(git_common_dir).into_values().collect();
+    if pins.is_empty() {
+        output.info(&dim("No pinned worktrees"));
+        return Ok(());
+    }
+    pins.sort_by(|a, b| a.branch.cmp(&b.branch));
+    let width = pins.iter().map(|p| p.branch.len()).max().unwrap_or(0);
+    let time_format = crate::output::time::configured();
+    let now = Utc::now().timestamp();
+    for p in &pins {
+        let commit: String = p.commit_sha.chars().take(7).collect();
+        output.info(&format!(
+            "{}  {} {}  {}",
+            bold(&format!("{:<width$}", p.branch)),
+            p.pinned_ref,
+            dim(&format!("@ {commit}")),
+            dim(&format!(
+                "pinned {}",
+                crate::output::time::ago(p.pinned_at.timestamp(), now, time_format)
+            ))
+        ));
+    }
+    Ok(())
+}
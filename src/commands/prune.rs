@@ -17,6 +17,7 @@ use crate::{
     },
     get_git_common_dir, get_project_root,
     git::GitCommand,
+    hints::{NextStep, show_next_steps},
     hooks::HookExecutor,
     is_git_repository,
     logging::init_logging,
@@ -121,7 +122,9 @@ pub struct Args {
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-prune"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-prune",
+    ));
 
     init_logging(args.verbose >= 2);
 
@@ -228,6 +231,12 @@ fn run_prune_inner(output: &mut dyn Output, settings: &DaftSettings, force: bool
     }
 
     sync_shared::render_prune_result(&result, output);
+    show_prune_next_steps(
+        &result.skipped_refined,
+        &result.skipped_unmerged,
+        settings.ui_hints,
+        output,
+    );
 
     // Write the cd target for the shell wrapper
     if let Some(ref cd_target) = result.cd_target {
@@ -244,6 +253,20 @@ fn run_prune_inner(output: &mut dyn Output, settings: &DaftSettings, force: bool
     Ok(())
 }
 
+/// Suggest the command that acts on the branches prune deliberately kept.
+fn show_prune_next_steps(
+    skipped_refined: &[String],
+    skipped_unmerged: &[String],
+    enabled: bool,
+    output: &mut dyn Output,
+) {
+    if !skipped_refined.is_empty() {
+        show_next_steps(NextStep::PruneKeptRefined, enabled, output);
+    } else if !skipped_unmerged.is_empty() {
+        show_next_steps(NextStep::PruneKeptUnmerged, enabled, output);
+    }
+}
+
 /// Interactive TUI execution path — parallel DAG executor with inline ratatui display.
 fn run_tui(args: Args, settings: DaftSettings) -> Result<()> {
     let git = GitCommand::new(false).with_gitoxide(settings.use_gitoxide);
@@ -445,6 +468,11 @@ fn run_tui(args: Args, settings: DaftSettings) -> Result<()> {
         Arc::new(std::sync::Mutex::new(Vec::new()));
     let skipped_unmerged_writer = Arc::clone(&skipped_unmerged);
 
+    // Refs the fetch phase moved, for the post-fetch hook after the TUI.
+    let fetched_refs: Arc<std::sync::Mutex<Vec<crate::hooks::post_fetch::RefUpdate>>> =
+        Arc::new(std::sync::Mutex::new(Vec::new()));
+    let fetched_refs_writer = Arc::clone(&fetched_refs);
+
     let orch_settings = Arc::clone(&shared_settings);
     let shared_hooks_config = Arc::new(hooks_config.clone());
     // One witness for the whole run: the workers share its single fetch, and
@@ -461,14 +489,16 @@ fn run_tui(args: Args, settings: DaftSettings) -> Result<()> {
 
     let orchestrator_handle = std::thread::spawn(move || {
         // ── Phase 1: Fetch ─────────────────────────────────────────────
-        if !sync_shared::run_fetch_phase(
+        let Some(updated_refs) = sync_shared::run_fetch_phase(
             &tx,
             orch_settings.use_gitoxide,
             &orch_settings.remote,
+            &shared_git_dir,
             None,
-        ) {
+        ) else {
             return;
-        }
+        };
+        *fetched_refs_writer.lock().unwrap() = updated_refs;
 
         // ── Refresh remote-derived cells now that fetch updated remote refs ──
         sync_shared::spawn_post_fetch_refresh(
@@ -633,6 +663,7 @@ fn run_tui(args: Args, settings: DaftSettings) -> Result<()> {
             verbosity: args.verbose,
             pin_default_branch: true,
             forge_prs: forge_lookup,
+            time_format: settings.ui_time_format,
             partition_by_owner: false, // External unowned_start_index drives the partition.
             seeded_fields,
         },
@@ -659,6 +690,17 @@ fn run_tui(args: Args, settings: DaftSettings) -> Result<()> {
         crate::hooks::trust_skip::flush_pending_notice(&git_dir, &mut post_tui_output);
     }
 
+    // ── Post-TUI: post-fetch hook ──────────────────────────────────────
+    sync_shared::run_post_tui_post_fetch(
+        &fetched_refs,
+        "prune",
+        &settings,
+        &project_root,
+        &git_dir,
+        &source_worktree,
+        &hooks_config,
+    );
+
     // ── Post-TUI: handle deferred branch (current worktree) ────────────
     sync_shared::handle_post_tui_deferred(
         &deferred_branch,
@@ -709,6 +751,7 @@ fn run_tui(args: Args, settings: DaftSettings) -> Result<()> {
             let config = OutputConfig::with_autocd(false, false, settings.autocd);
             let mut notes_output = CliOutput::new(config);
             sync_shared::render_prune_skip_notes(&refined, &unmerged, &mut notes_output);
+            show_prune_next_steps(&refined, &unmerged, settings.ui_hints, &mut notes_output);
         }
     }
 
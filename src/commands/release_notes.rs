@@ -294,13 +294,17 @@ fn output_list(releases: &[Release], no_pager: bool) -> Result<()> {
 
 /// Output full release notes
 fn output_full(releases: &[Release], no_pager: bool) -> Result<()> {
-    let markdown = build_full_markdown(releases);
+    page_markdown(&build_full_markdown(releases), no_pager)
+}
 
+/// Show release-notes markdown, rendered when stdout is a terminal, in the
+/// pager unless `no_pager`. Shared with `daft whats-new`.
+pub(crate) fn page_markdown(markdown: &str, no_pager: bool) -> Result<()> {
     // Render markdown if outputting to a terminal
     let output = if io::stdout().is_terminal() {
-        crate::output::markdown::render(&markdown)
+        crate::output::markdown::render(markdown)
     } else {
-        markdown
+        markdown.to_string()
     };
 
     display_with_pager(&output, no_pager)
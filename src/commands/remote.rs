@@ -0,0 +1,232 @@
+//! Command: `daft remote` — inspect the repository's remotes as daft sees them.
+
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+
+use crate::core::remote_url::{InsteadOf, RemoteUrl};
+use crate::core::repo::display_url;
+use crate::git::GitCommand;
+use crate::is_git_repository;
+use crate::output::{
+    CliOutput, Output,
+    emit::{self, Cell, EmitArgs, EmitPayload, Table},
+};
+use crate::styles::{bold, dim};
+
+#[derive(Parser)]
+#[command(name = "daft-remote")]
+#[command(version = crate::VERSION)]
+#[command(about = "Inspect the repository's remotes as daft parses them")]
+#[command(long_about = r#"
+Show the repository's remotes the way daft reads them when it names a
+clone, resolves a forge host, or matches a remote against the repo catalog.
+
+`daft remote show` prints each remote's configured URL and, when a
+url.<base>.insteadOf (or pushInsteadOf) rule rewrites it, the URL git
+actually connects to. With --parsed, the effective URL is also broken into
+its parts — form (url, scp, or local), user, host, port, and path — along
+with the owner, the repository name, and the same repository's HTTPS and
+SSH URLs.
+
+Credentials embedded in a URL are never printed.
+"#)]
+pub struct Args {
+    #[command(subcommand)]
+    command: RemoteCommand,
+}
+
+#[derive(Subcommand)]
+enum RemoteCommand {
+    /// Show remote URLs, with insteadOf rewrites applied
+    Show(ShowArgs),
+}
+
+#[derive(Parser)]
+struct ShowArgs {
+    /// Remote to show (default: all remotes)
+    name: Option<String>,
+
+    /// Break each URL into form, host, port, path, owner, and repository name
+    #[arg(long)]
+    parsed: bool,
+
+    #[command(flatten)]
+    emit: EmitArgs,
+}
+
+/// One remote's configured and effective URLs.
+struct RemoteEntry {
+    name: String,
+    url: String,
+    fetch_url: String,
+    push_url: String,
+    parsed: Option<RemoteUrl>,
+}
+
+pub fn run() -> Result<()> {
+    // Skip argv[0] so clap sees "remote" as the program name (same shape as
+    // `daft shared`).
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    match args.command {
+        RemoteCommand::Show(show) => run_show(&show, &mut output),
+    }
+}
+
+fn run_show(args: &ShowArgs, output: &mut dyn Output) -> Result<()> {
+    if !is_git_repository()? {
+        bail!("Not inside a Git repository");
+    }
+    let entries = collect(args.name.as_deref())?;
+
+    if args.emit.is_structured() {
+        return emit::emit_and_handle(
+            "remote show",
+            EmitPayload::Tabular(table(&entries)),
+            &args.emit,
+            &mut std::io::stdout(),
+        )
+        .map_err(|e| anyhow::anyhow!("{e}"));
+    }
+
+    if entries.is_empty() {
+        output.info("No remotes configured");
+        return Ok(());
+    }
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 && args.parsed {
+            output.info("");
+        }
+        print_entry(entry, args.parsed, output);
+    }
+    Ok(())
+}
+
+fn collect(only: Option<&str>) -> Result<Vec<RemoteEntry>> {
+    let git = GitCommand::new(true);
+    let remotes = git.remote_list()?;
+    let names: Vec<String> = match only {
+        Some(name) if remotes.iter().any(|r| r == name) => vec![name.to_string()],
+        Some(name) => bail!("No such remote: '{name}'"),
+        None => remotes,
+    };
+    let rules = InsteadOf::load(&std::env::current_dir()?);
+
+    let mut entries = Vec::new();
+    for name in names {
+        let Some(url) = git.config_get(&format!("remote.{name}.url"))? else {
+            continue;
+        };
+        let fetch_url = InsteadOf::rewrite(&url, &rules, false).unwrap_or_else(|| url.clone());
+        // An explicit pushurl is rewritten by pushInsteadOf too; without one
+        // the push side starts from the fetch URL.
+        let push_base = git
+            .config_get(&format!("remote.{name}.pushurl"))?
+            .unwrap_or_else(|| url.clone());
+        let push_url =
+            InsteadOf::rewrite(&push_base, &rules, true).unwrap_or_else(|| push_base.clone());
+        let parsed = RemoteUrl::parse(&fetch_url);
+        entries.push(RemoteEntry {
+            name,
+            url,
+            fetch_url,
+            push_url,
+            parsed,
+        });
+    }
+    Ok(entries)
+}
+
+fn print_entry(entry: &RemoteEntry, parsed: bool, output: &mut dyn Output) {
+    let url = display_url(&entry.url);
+    output.info(&format!("{}\t{url}", bold(&entry.name)));
+    if entry.fetch_url != entry.url {
+        output.info(&format!(
+            "  {} {}",
+            dim("fetch ->"),
+            display_url(&entry.fetch_url)
+        ));
+    }
+    if entry.push_url != entry.fetch_url {
+        output.info(&format!(
+            "  {} {}",
+            dim("push  ->"),
+            display_url(&entry.push_url)
+        ));
+    }
+    if !parsed {
+        return;
+    }
+    let Some(url) = &entry.parsed else {
+        output.info(&dim("  (unparseable)"));
+        return;
+    };
+    let port = url.port.map(|p| p.to_string());
+    let fields = [
+        ("form", Some(url.form.as_str().to_string())),
+        ("scheme", url.scheme.clone()),
+        ("user", url.user.clone()),
+        ("host", url.host.clone()),
+        ("port", port),
+        ("path", Some(url.path.clone())),
+        ("owner", url.owner()),
+        ("repo", url.repo_name().map(str::to_string)),
+        ("https", url.https_url()),
+        ("ssh", url.ssh_url()),
+    ];
+    for (key, value) in fields {
+        if let Some(value) = value {
+            output.info(&format!(
+                "  {}{value}",
+                dim(&format!("{:<8}", format!("{key}:")))
+            ));
+        }
+    }
+}
+
+fn table(entries: &[RemoteEntry]) -> Table {
+    fn opt(value: Option<String>) -> Cell {
+        value.map(Cell::str).unwrap_or_else(Cell::null)
+    }
+    let mut table = Table::new([
+        "name",
+        "url",
+        "fetch_url",
+        "push_url",
+        "form",
+        "scheme",
+        "user",
+        "host",
+        "port",
+        "path",
+        "owner",
+        "repo",
+        "https_url",
+        "ssh_url",
+    ]);
+    for entry in entries {
+        let parsed = entry.parsed.as_ref();
+        table = table.row([
+            Cell::str(&entry.name),
+            Cell::str(display_url(&entry.url)),
+            Cell::str(display_url(&entry.fetch_url)),
+            Cell::str(display_url(&entry.push_url)),
+            opt(parsed.map(|u| u.form.as_str().to_string())),
+            opt(parsed.and_then(|u| u.scheme.clone())),
+            opt(parsed.and_then(|u| u.user.clone())),
+            opt(parsed.and_then(|u| u.host.clone())),
+            parsed
+                .and_then(|u| u.port)
+                .map(Cell::int)
+                .unwrap_or_else(Cell::null),
+            opt(parsed.map(|u| u.path.clone())),
+            opt(parsed.and_then(RemoteUrl::owner)),
+            opt(parsed.and_then(|u| u.repo_name().map(str::to_string))),
+            opt(parsed.and_then(RemoteUrl::https_url)),
+            opt(parsed.and_then(RemoteUrl::ssh_url)),
+        ]);
+    }
+    table
+}
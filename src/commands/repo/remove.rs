@@ -759,6 +759,7 @@ fn run_tui(
             // loaders.
             seeded_fields: crate::core::worktree::info_field::FieldSet::ALL,
             forge_prs: None,
+            time_format: settings.ui_time_format,
         },
         None,
     );
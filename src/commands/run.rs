@@ -9,8 +9,10 @@
 //! Word resolution: the first word runs as a task when one matches its name
 //! (task names are single validated tokens, so the lookup is unambiguous);
 //! otherwise the whole word list is forwarded as arguments to the reserved
-//! `run` task. Everything after the first word is captured verbatim — flags
-//! included — so `daft run`'s own flags come before it, and a leading `--`
+//! `run` task. A hook name — lifecycle, custom, or a git hook installed in the
+//! repository's hooks directory — is refused rather than forwarded, and a near
+//! miss of a task name is pointed out either way. Everything after the first
+//! word is captured verbatim — flags included — so `daft run`'s own flags come before it, and a leading `--`
 //! forces all words to be forwarded without task-name matching. Forwarded
 //! words are shell-escaped and appended to the task's single job command
 //! (multi-job resolutions reject arguments).
@@ -58,7 +60,7 @@ const DEFAULT_TASK: &str = "run";
 #[command(about = "Run a named task defined in daft.yml")]
 #[command(long_about = "Run a named task from the current worktree's daft.yml.
 
-Tasks live under a top-level `tasks:` section and reuse the hook job schema (jobs, parallel/piped/follow, needs, env, root, skip/only, tags). Bare `git daft run` executes the reserved task named `run`; a first word that names a task runs that task, and any words after it are forwarded to the task as arguments. A first word that names no task is itself forwarded — the whole word list goes to the reserved `run` task. Words after the first are passed through verbatim, flags included, so this command reads its own flags (--list, --job, --tag) only before the first word; write `--` before the first word to forward every word without task-name matching.
+Tasks live under a top-level `tasks:` section and reuse the hook job schema (jobs, parallel/piped/follow, needs, env, root, skip/only, tags). Bare `git daft run` executes the reserved task named `run`; a first word that names a task runs that task, and any words after it are forwarded to the task as arguments. A first word that names no task is itself forwarded — the whole word list goes to the reserved `run` task — unless it names a hook, which is an error pointing at `git daft hooks run`, or a git hook installed in the repository's hooks directory, which git runs at its own stage. A first word close to a task name suggests it, whether or not a `run` task receives the word. Words after the first are passed through verbatim, flags included, so this command reads its own flags (--list, --job, --tag) only before the first word; write `--` before the first word to forward every word without task-name matching.
 
 Forwarded words are shell-escaped and appended to the task's command, which requires the task to resolve to exactly one foreground job (narrow a multi-job task with --job). A task resolving to a single job passes the terminal straight through to the command — no wrapping interface; a multi-job task renders one live row per job with the logs threaded beneath. Tasks run until they exit or you press Ctrl+C (press it twice to force-kill) — they have no execution timeout, which makes them the home for long-running dev servers, compose stacks, and watchers.
 
@@ -97,6 +99,49 @@ pub fn run() -> Result<()> {
     cmd_run(&args, forced_args, &mut output)
 }
 
+/// `daft __scheduled-run <task>` — what a timer installed by `daft hooks
+/// schedule install` runs. An explicit `daft run` counts as consent; a timer
+/// firing does not, so this refuses unless the repository is trusted right
+/// now and daft.yml still schedules the task.
+pub fn run_scheduled(task: &str) -> Result<()> {
+    let git_dir = get_git_common_dir().context("Could not determine git directory")?;
+    let level = TrustDatabase::load()
+        .unwrap_or_default()
+        .get_trust_level(&git_dir);
+    if level != TrustLevel::Allow {
+        bail!(
+            "scheduled task '{task}' not run: the repository is not trusted ({level}); run `{}` to allow it, or `{}` to remove the timers",
+            crate::daft_cmd("hooks trust"),
+            crate::daft_cmd("hooks schedule uninstall")
+        );
+    }
+    let worktree_path = get_current_worktree_path()
+        .context("Not in a git worktree. Run this command from within a worktree directory.")?;
+    let scheduled = yaml_config_loader::load_merged_config(&worktree_path)
+        .context("Failed to load daft.yml")?
+        .is_some_and(|config| {
+            config
+                .tasks
+                .get(task)
+                .is_some_and(|def| def.schedule.is_some())
+        });
+    if !scheduled {
+        bail!(
+            "scheduled task '{task}' not run: daft.yml no longer schedules it; run `{}` to update the timers",
+            crate::daft_cmd("hooks schedule install")
+        );
+    }
+
+    let args = Args {
+        words: vec![task.to_string()],
+        list: false,
+        job: None,
+        tag: Vec::new(),
+    };
+    let mut output = CliOutput::new(OutputConfig::default());
+    cmd_run(&args, false, &mut output)
+}
+
 /// Whether the invocation wrote `--` before the first bare word (`daft run --
 /// list`), forcing every word to be forwarded to the reserved task without
 /// task-name matching. Clap consumes that leading delimiter, so it is
@@ -153,6 +198,25 @@ fn cmd_run(args: &Args, forced_args: bool, output: &mut dyn Output) -> Result<()
 
     let (task_name, task_args, origin) =
         resolve_invocation(&config.tasks, &args.words, forced_args);
+    if origin == Origin::Fallback {
+        let word = &args.words[0];
+        let git_hooks = installed_git_hooks(&worktree_path);
+        if let Some(err) = hook_name_error(&config, &git_hooks, word) {
+            return Err(err);
+        }
+        // With a `run` task to receive it the word is forwarded, but a near
+        // miss of a task name is more often a typo than an argument.
+        if config.tasks.contains_key(DEFAULT_TASK)
+            && let Some(nearest) = similar_tasks(&config, word)
+                .into_iter()
+                .find(|name| name != DEFAULT_TASK)
+        {
+            output.notice(&format!(
+                "{} '{word}' is not a task; forwarding it to the '{DEFAULT_TASK}' task (did you mean: {nearest}?)",
+                dim("Note:")
+            ));
+        }
+    }
 
     let task_def = config
         .tasks
@@ -210,7 +274,11 @@ fn cmd_run(args: &Args, forced_args: bool, output: &mut dyn Output) -> Result<()
         "origin",
         &worktree_path,
         &branch_name,
-    );
+    )
+    .with_extra_env(crate::catalog::links::hook_env(
+        config.links.as_deref().unwrap_or_default(),
+        &branch_name,
+    ));
 
     // Tasks stream full output live (like docker compose / foreman); the
     // knobs also shape the non-TTY hook-block fallback, which prints a
@@ -311,7 +379,7 @@ fn cmd_run(args: &Args, forced_args: bool, output: &mut dyn Output) -> Result<()
     }
     if !result.success {
         // A cancelled task carries exit code 130 (128 + SIGINT).
-        std::process::exit(result.exit_code.unwrap_or(1));
+        crate::deprecations::exit(result.exit_code.unwrap_or(1));
     }
 
     Ok(())
@@ -481,15 +549,96 @@ fn unknown_task_error(
         Origin::Forced => anyhow::anyhow!(
             "no '{DEFAULT_TASK}' task defined in daft.yml (`--` forwards every word to the task named '{DEFAULT_TASK}')\nAvailable tasks: {available}"
         ),
-        Origin::Fallback => anyhow::anyhow!(
-            "unknown task: '{}' (no '{DEFAULT_TASK}' task defined to receive it as an argument)\nAvailable tasks: {available}",
-            words[0]
-        ),
+        Origin::Fallback => {
+            let suggestions = similar_tasks(config, &words[0]);
+            let hint = if suggestions.is_empty() {
+                String::new()
+            } else {
+                format!("\n  did you mean: {}", suggestions.join(", "))
+            };
+            anyhow::anyhow!(
+                "unknown task: '{}' (no '{DEFAULT_TASK}' task defined to receive it as an argument){hint}\nAvailable tasks: {available}",
+                words[0]
+            )
+        }
         // Named origins matched an existing task; a miss cannot carry one.
         Origin::Named => anyhow::anyhow!("unknown task\nAvailable tasks: {available}"),
     }
 }
 
+/// Task names close to `word`, nearest first.
+fn similar_tasks(config: &crate::hooks::yaml_config::YamlConfig, word: &str) -> Vec<String> {
+    let mut names: Vec<&str> = config.tasks.keys().map(String::as_str).collect();
+    names.sort_unstable();
+    crate::suggest::find_similar(word, &names, 3)
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+/// An error for a first word that names a hook rather than a task. Hooks run
+/// through `hooks run`; forwarding `post-clone` to the reserved task as an
+/// argument is almost never what was meant, so it takes an explicit `--`.
+/// A git hook installed in the hooks directory (`pre-commit` from lefthook,
+/// husky, and the like) is refused too: git runs it at its own stage.
+fn hook_name_error(
+    config: &crate::hooks::yaml_config::YamlConfig,
+    git_hooks: &[String],
+    word: &str,
+) -> Option<anyhow::Error> {
+    if crate::hooks::HookType::from_yaml_name(word).is_some() || config.hooks.contains_key(word) {
+        return Some(anyhow::anyhow!(
+            "'{word}' is a hook, not a task\nRun it with `{}`, or write `--` before it to forward it to the '{DEFAULT_TASK}' task",
+            crate::daft_cmd(&format!("hooks run {word}"))
+        ));
+    }
+    git_hooks.iter().any(|hook| hook == word).then(|| {
+        anyhow::anyhow!(
+            "'{word}' is a git hook installed in this repository, not a task\ngit runs it at its own stage; write `--` before it to forward it to the '{DEFAULT_TASK}' task"
+        )
+    })
+}
+
+/// Names of the executable git hooks installed for the repository at
+/// `worktree` — natively or through `core.hooksPath` — skipping git's
+/// `.sample` templates.
+fn installed_git_hooks(worktree: &std::path::Path) -> Vec<String> {
+    let Ok(out) = crate::utils::git_command_at(worktree)
+        .args(["rev-parse", "--git-path", "hooks"])
+        .stderr(std::process::Stdio::null())
+        .output()
+    else {
+        return Vec::new();
+    };
+    let rel = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    if !out.status.success() || rel.is_empty() {
+        return Vec::new();
+    }
+    let Ok(entries) = std::fs::read_dir(worktree.join(rel)) else {
+        return Vec::new();
+    };
+    let mut hooks: Vec<String> = entries
+        .flatten()
+        .filter(|entry| is_executable(&entry.path()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| !name.ends_with(".sample"))
+        .collect();
+    hooks.sort_unstable();
+    hooks
+}
+
+fn is_executable(path: &std::path::Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path).is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
 /// Render the `--list` output: task names with job counts.
 fn list_tasks(
     config: &crate::hooks::yaml_config::YamlConfig,
@@ -699,6 +848,65 @@ mod tests {
         assert!(msg.contains("`--` forwards"), "got: {msg}");
     }
 
+    #[test]
+    fn unknown_task_error_suggests_near_matches() {
+        let cfg = config_with_tasks(&["seed", "build"]);
+        let msg = unknown_task_error(&cfg, &words(&["biuld"]), Origin::Fallback).to_string();
+        assert!(msg.contains("did you mean: build"), "got: {msg}");
+    }
+
+    #[test]
+    fn hook_names_are_refused_instead_of_forwarded() {
+        let mut cfg = config_with_tasks(&["run"]);
+        cfg.hooks.insert("pre-deploy".into(), HookDef::default());
+        let msg = hook_name_error(&cfg, &[], "worktree-post-create")
+            .expect("lifecycle hook name")
+            .to_string();
+        assert!(
+            msg.contains("git daft hooks run worktree-post-create"),
+            "got: {msg}"
+        );
+        assert!(
+            hook_name_error(&cfg, &[], "pre-deploy").is_some(),
+            "custom hook"
+        );
+        assert!(hook_name_error(&cfg, &[], "hello").is_none());
+
+        let git_hooks = words(&["pre-commit"]);
+        let msg = hook_name_error(&cfg, &git_hooks, "pre-commit")
+            .expect("installed git hook")
+            .to_string();
+        assert!(msg.contains("is a git hook installed"), "got: {msg}");
+    }
+
+    #[test]
+    fn installed_git_hooks_skip_samples_and_non_executables() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::utils::git_command_at(dir.path())
+            .args(["init", "-q"])
+            .status()
+            .unwrap();
+        let hooks = dir.path().join(".git/hooks");
+        std::fs::create_dir_all(&hooks).unwrap();
+        std::fs::write(hooks.join("pre-commit"), "#!/bin/sh\n").unwrap();
+        std::fs::write(hooks.join("commit-msg"), "#!/bin/sh\n").unwrap();
+        std::fs::write(hooks.join("pre-push.sample"), "#!/bin/sh\n").unwrap();
+        #[cfg(unix)]
+        for name in ["pre-commit", "pre-push.sample"] {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(hooks.join(name), std::fs::Permissions::from_mode(0o755))
+                .unwrap();
+        }
+        let installed = installed_git_hooks(dir.path());
+        assert!(
+            installed.contains(&"pre-commit".to_string()),
+            "{installed:?}"
+        );
+        assert!(!installed.iter().any(|h| h.ends_with(".sample")));
+        #[cfg(unix)]
+        assert!(!installed.contains(&"commit-msg".to_string()));
+    }
+
     // ── passthrough gating ────────────────────────────────────────────────
 
     fn job(name: &str) -> JobDef {
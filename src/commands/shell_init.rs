@@ -1,6 +1,15 @@
-use anyhow::Result;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
 use clap::{Parser, ValueEnum};
 
+use super::env::{fish_quote, sh_quote};
+use crate::git::GitCommand;
+use crate::output::{CliOutput, Output};
+use crate::settings::keys;
+use crate::styles::{bold, dim};
+
 #[derive(Debug, Clone, ValueEnum)]
 pub enum Shell {
     Bash,
@@ -8,6 +17,17 @@ pub enum Shell {
     Fish,
 }
 
+/// A part of the integration that `shell-init` can emit on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Component {
+    /// Wrapper functions that cd into new worktrees
+    Cd,
+    /// Tab completions
+    Completions,
+    /// Keep Fig/Amazon Q autocomplete specs in sync
+    Fig,
+}
+
 #[derive(Parser)]
 #[command(name = "shell-init")]
 #[command(about = "Generate shell wrapper functions for daft commands")]
@@ -25,6 +45,26 @@ Add to your shell config:
 
 With short aliases (gwco, etc.):
   eval "$(daft shell-init bash --aliases)"
+
+Everything is emitted by default. To wire only some of it into your own
+dotfiles, pick components with --components, or set them once in git config:
+
+  cd           wrapper functions that cd into new worktrees
+  completions  tab completions
+  fig          keep Fig/Amazon Q autocomplete specs in sync
+
+  eval "$(daft shell-init zsh --components cd,completions)"
+  git config --global daft.shellInit.components cd,completions
+  git config --global daft.shellInit.fish.components completions
+
+The per-shell key wins over daft.shellInit.components, and the flag wins
+over both.
+
+In bash and zsh, tab completions are loaded on first use: the output only
+registers small stubs, and the first TAB on a daft command sources the full
+completion script. Fish loads completions when the output is sourced.
+
+Use --benchmark to measure how much the integration adds to shell startup.
 "#)]
 pub struct Args {
     #[arg(value_enum, help = "Target shell (bash, zsh, or fish)")]
@@ -32,8 +72,26 @@ pub struct Args {
 
     #[arg(long, help = "Include short aliases (gwco, etc.)")]
     aliases: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        value_name = "COMPONENTS",
+        help = "Emit only these components, comma-separated (cd, completions, fig)"
+    )]
+    components: Option<Vec<Component>>,
+
+    #[arg(
+        long,
+        help = "Measure the time the integration adds to shell startup instead of printing it"
+    )]
+    benchmark: bool,
 }
 
+/// Timed runs per variant in `--benchmark`; the median is reported.
+const BENCHMARK_RUNS: usize = 10;
+
 pub fn run() -> Result<()> {
     // Skip the first two args ("daft" and "shell-init") to let clap parse from "shell-init"
     let args: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
@@ -45,26 +103,85 @@ pub fn run() -> Result<()> {
         Shell::Fish => "fish",
     };
 
+    let components = resolve_components(args.components, shell_name);
+
+    if args.benchmark {
+        return benchmark(&args.shell, shell_name, args.aliases, &components);
+    }
+
     let mut output = match args.shell {
-        Shell::Bash | Shell::Zsh => generate_bash_zsh(args.aliases),
-        Shell::Fish => generate_fish(args.aliases),
+        Shell::Bash | Shell::Zsh => {
+            generate_bash_zsh(components.contains(&Component::Cd), args.aliases)
+        }
+        Shell::Fish => generate_fish(components.contains(&Component::Cd), args.aliases),
     };
 
     // Append shell completions so users get tab completion out of the box
-    output.push_str("\n# Shell completions\n");
-    output.push_str(&super::completions::generate_all_completions(shell_name)?);
+    if components.contains(&Component::Completions) {
+        output.push_str("\n# Shell completions\n");
+        let completions = super::completions::generate_all_completions(shell_name)?;
+        match args.shell {
+            Shell::Bash | Shell::Zsh => {
+                output.push_str(&lazy_completions(&completions, shell_name))
+            }
+            Shell::Fish => output.push_str(&completions),
+        }
+    }
 
     println!("{output}");
 
     // Silently install Fig/Amazon Q specs if the autocomplete directory exists
-    super::completions::maybe_install_fig_specs();
+    if components.contains(&Component::Fig) {
+        super::completions::maybe_install_fig_specs();
+    }
 
     Ok(())
 }
 
-fn generate_bash_zsh(include_aliases: bool) -> String {
-    let mut output = String::from(BASH_ZSH_WRAPPER);
+/// The components to emit: the flag, else `daft.shellInit.<shell>.components`,
+/// else `daft.shellInit.components`, else all of them. Only global config is
+/// read, since a new shell usually starts outside any repository.
+fn resolve_components(flag: Option<Vec<Component>>, shell_name: &str) -> Vec<Component> {
+    if let Some(components) = flag {
+        return components;
+    }
+    let git = GitCommand::new(true);
+    for key in [
+        keys::shell_init::components_key(shell_name),
+        keys::shell_init::COMPONENTS.to_string(),
+    ] {
+        if let Ok(Some(value)) = git.config_get_global(&key)
+            && !value.trim().is_empty()
+        {
+            return parse_components(&key, &value);
+        }
+    }
+    Component::value_variants().to_vec()
+}
+
+/// Parse a comma-separated component list, warning about (and dropping)
+/// names it doesn't know rather than failing the whole shell startup.
+fn parse_components(key: &str, value: &str) -> Vec<Component> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .filter_map(|name| match Component::from_str(name, true) {
+            Ok(component) => Some(component),
+            Err(_) => {
+                eprintln!("daft: unknown shell-init component in {key}: {name:?} — ignoring it");
+                None
+            }
+        })
+        .collect()
+}
+
+fn generate_bash_zsh(include_wrappers: bool, include_aliases: bool) -> String {
+    let mut output = String::new();
 
+    if include_wrappers {
+        output.push_str(BASH_ZSH_WRAPPER);
+    }
     if include_aliases {
         output.push_str(BASH_ZSH_ALIASES);
     }
@@ -72,9 +189,12 @@ fn generate_bash_zsh(include_aliases: bool) -> String {
     output
 }
 
-fn generate_fish(include_aliases: bool) -> String {
-    let mut output = String::from(FISH_WRAPPER);
+fn generate_fish(include_wrappers: bool, include_aliases: bool) -> String {
+    let mut output = String::new();
 
+    if include_wrappers {
+        output.push_str(FISH_WRAPPER);
+    }
     if include_aliases {
         output.push_str(FISH_ALIASES);
     }
@@ -82,6 +202,180 @@ fn generate_fish(include_aliases: bool) -> String {
     output
 }
 
+/// Replace a bash/zsh completion script with stubs that load it on first use.
+///
+/// Sourcing the full script costs every new shell for functions most sessions
+/// never call. Only its `complete -F` / `compdef` registrations are kept; each
+/// registered function becomes a stub that evals `daft completions <shell>`,
+/// which redefines it, and then calls itself again.
+fn lazy_completions(script: &str, shell_name: &str) -> String {
+    let registrations: Vec<&str> = script
+        .lines()
+        .filter(|line| line.starts_with("complete -F ") || line.starts_with("compdef "))
+        .collect();
+
+    let mut functions: Vec<&str> = Vec::new();
+    for line in &registrations {
+        // `complete -F <function> <command>` / `compdef <function> <command>`
+        if let Some(function) = line.split_whitespace().rev().nth(1)
+            && !functions.contains(&function)
+        {
+            functions.push(function);
+        }
+    }
+
+    let mut output = LAZY_COMPLETIONS_LOADER.replace("{shell}", shell_name);
+    for function in functions {
+        output.push_str(&format!(
+            "{function}() {{ __daft_load_completions && {function} \"$@\"; }}\n"
+        ));
+    }
+    output.push('\n');
+    for line in registrations {
+        output.push_str(line);
+        output.push('\n');
+    }
+    output
+}
+
+const LAZY_COMPLETIONS_LOADER: &str = r#"# Completions load on first use. Each function below is a stub: the first
+# TAB sources `daft completions {shell}`, which replaces every stub with the
+# real function, and the stub then calls itself again. The flag makes a
+# failed load fall through instead of recursing.
+__daft_load_completions() {
+    [ -z "$__daft_completions_loaded" ] || return 1
+    __daft_completions_loaded=1
+    local daft_bin
+    daft_bin=$(unset -f daft 2>/dev/null; command -v daft 2>/dev/null)
+    [ -n "$daft_bin" ] || return 1
+    eval "$("$daft_bin" completions {shell})"
+}
+
+"#;
+
+/// Time shell startup without and with the integration and print the
+/// difference. Each variant runs once untimed to warm caches first.
+fn benchmark(
+    shell: &Shell,
+    shell_name: &str,
+    aliases: bool,
+    components: &[Component],
+) -> Result<()> {
+    let exe = std::env::current_exe().context("Cannot locate the daft executable")?;
+    let exe = exe.to_string_lossy();
+    if components.is_empty() {
+        bail!("No shell-init components selected; nothing to time");
+    }
+    // Time the same output the user's config would produce.
+    let names: Vec<String> = components
+        .iter()
+        .filter_map(|c| c.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect();
+    let mut init_flags = format!(" --components={}", names.join(","));
+    if aliases {
+        init_flags.push_str(" --aliases");
+    }
+    let lazy_completions = components.contains(&Component::Completions);
+
+    // Startup files are skipped so only daft's share is measured. zsh needs
+    // compinit for `compdef`, so every zsh variant pays for it equally.
+    let (flags, prelude, init, load): (&[&str], &str, String, Option<String>) = match shell {
+        Shell::Bash => (
+            &["--norc", "--noprofile"],
+            "",
+            format!("eval \"$({} shell-init bash{init_flags})\"", sh_quote(&exe)),
+            Some(format!("eval \"$({} completions bash)\"", sh_quote(&exe))),
+        ),
+        Shell::Zsh => (
+            &["-f"],
+            "autoload -Uz compinit && compinit -D -u; ",
+            format!("eval \"$({} shell-init zsh{init_flags})\"", sh_quote(&exe)),
+            Some(format!("eval \"$({} completions zsh)\"", sh_quote(&exe))),
+        ),
+        Shell::Fish => (
+            &["--no-config"],
+            "",
+            format!("{} shell-init fish{init_flags} | source", fish_quote(&exe)),
+            None,
+        ),
+    };
+
+    let mut output = CliOutput::default_output();
+    output.start_spinner(&format!("Timing {shell_name} startup..."));
+    let timings = (|| -> Result<_> {
+        let without = time_shell(shell_name, flags, &format!("{prelude}:"))?;
+        let with = time_shell(shell_name, flags, &format!("{prelude}{init}"))?;
+        let loaded = match load.as_ref().filter(|_| lazy_completions) {
+            Some(load) => Some(time_shell(
+                shell_name,
+                flags,
+                &format!("{prelude}{init}; {load}"),
+            )?),
+            None => None,
+        };
+        Ok((without, with, loaded))
+    })();
+    output.finish_spinner();
+    let (without, with, loaded) = timings?;
+
+    output.info(&format!(
+        "{} {}",
+        bold(&format!("{shell_name} startup")),
+        dim(&format!("(median of {BENCHMARK_RUNS} runs)"))
+    ));
+    output.info(&format!("  without daft  {}", millis(without)));
+    output.info(&format!(
+        "  with daft     {}  {}",
+        millis(with),
+        bold(&format!("+{}", millis(with.saturating_sub(without))))
+    ));
+    if let Some(loaded) = loaded {
+        output.info(&format!(
+            "  first TAB     +{}  {}",
+            millis(loaded.saturating_sub(with)),
+            dim("(completions load once per shell)")
+        ));
+    }
+    Ok(())
+}
+
+/// Median wall time of `shell <flags> -c <script>` over [`BENCHMARK_RUNS`].
+fn time_shell(shell: &str, flags: &[&str], script: &str) -> Result<Duration> {
+    let run = || {
+        Command::new(shell)
+            .args(flags)
+            .arg("-c")
+            .arg(script)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .output()
+    };
+
+    let warmup = run().with_context(|| format!("Failed to run {shell}; is it installed?"))?;
+    if !warmup.status.success() {
+        bail!(
+            "{shell} exited with {} while timing startup:\n{}",
+            warmup.status,
+            String::from_utf8_lossy(&warmup.stderr).trim_end()
+        );
+    }
+
+    let mut samples = Vec::with_capacity(BENCHMARK_RUNS);
+    for _ in 0..BENCHMARK_RUNS {
+        let start = Instant::now();
+        run().with_context(|| format!("Failed to run {shell}"))?;
+        samples.push(start.elapsed());
+    }
+    samples.sort();
+    Ok(samples[BENCHMARK_RUNS / 2])
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.1} ms", duration.as_secs_f64() * 1000.0)
+}
+
 const BASH_ZSH_WRAPPER: &str = r#"# daft shell wrappers - enables cd into new worktrees
 # Generated by: daft shell-init
 
@@ -231,13 +525,15 @@ daft() {
             shift; __daft_wrapper git-worktree-flow-eject "${__daft_pre[@]}" "$@" ;;
         worktree-sync|sync)
             shift; __daft_wrapper git-worktree-sync "${__daft_pre[@]}" "$@" ;;
-        layout|repo)
+        layout|repo|adopt-worktree)
             # `daft layout` (transform) and `daft repo remove` both need cd
             # support — repo-remove writes DAFT_CD_FILE when the user invoked
             # it from inside the worktree being deleted, so the shell can
             # `cd` to a safe parent before the cwd's inode is gone. Both are
             # subcommands of `daft` (not separate binaries) so we can't use
             # `exec -a`; mirror the per-subcommand pattern used for layout.
+            # `adopt-worktree --move` relocates the worktree the shell may be
+            # standing in, so it follows the same path.
             # The case keys on the first arg, so read-only `repo list`/`info`/
             # `add` route here too — harmlessly: they leave DAFT_CD_FILE
             # unwritten, so the temp file is created and discarded. Splitting by
@@ -505,13 +801,15 @@ function daft --wraps daft
             __daft_wrapper git-worktree-flow-eject $pre $argv[2..-1]
         case worktree-sync sync
             __daft_wrapper git-worktree-sync $pre $argv[2..-1]
-        case layout repo
+        case layout repo adopt-worktree
             # `daft layout` (transform) and `daft repo remove` both need cd
             # support — repo-remove writes DAFT_CD_FILE when the user invoked
             # it from inside the worktree being deleted, so the shell can
             # `cd` to a safe parent before the cwd's inode is gone. Both are
             # subcommands of `daft` (not separate binaries) so we can't use
             # `exec -a`; mirror the per-subcommand pattern used for layout.
+            # `adopt-worktree --move` relocates the worktree the shell may be
+            # standing in, so it follows the same path.
             set -l cd_file (mktemp (set -q TMPDIR; and echo $TMPDIR; or echo /tmp)/daft-cd.XXXXXX 2>/dev/null)
             if test -n "$cd_file"
                 DAFT_CD_FILE=$cd_file command daft $pre $argv
@@ -645,3 +943,30 @@ alias gwco='git-worktree-checkout'
 alias gwcarry='git-worktree-carry'
 alias gwprune='git-worktree-prune'
 "#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_components_skips_unknown_names() {
+        assert_eq!(
+            parse_components("k", " cd, Completions ,,prompt"),
+            vec![Component::Cd, Component::Completions]
+        );
+        assert!(parse_components("k", "").is_empty());
+    }
+
+    #[test]
+    fn lazy_completions_stub_each_registered_function_once() {
+        let script =
+            "_a() {\n    body_of_a\n}\ncomplete -F _a a\ncomplete -F _a a2\ncompdef _b b\n";
+        let lazy = lazy_completions(script, "bash");
+        assert!(lazy.contains("_a() { __daft_load_completions && _a \"$@\"; }\n"));
+        assert!(lazy.contains("_b() { __daft_load_completions && _b \"$@\"; }\n"));
+        assert_eq!(lazy.matches("_a() {").count(), 1);
+        assert!(lazy.contains("complete -F _a a2\n"));
+        assert!(lazy.contains("completions bash"));
+        assert!(!lazy.contains("body_of_a"));
+    }
+}
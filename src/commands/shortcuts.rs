@@ -234,6 +234,24 @@ fn remove_symlink(
     Ok(())
 }
 
+/// Remove every shortcut symlink that points at daft in `install_dir`.
+/// Returns the aliases removed (or, with `dry_run`, that would be).
+pub fn remove_installed_shortcuts(
+    install_dir: &Path,
+    dry_run: bool,
+    output: &mut dyn Output,
+) -> Result<Vec<&'static str>> {
+    let mut removed = Vec::new();
+    for (alias, _) in get_installed_shortcuts(install_dir) {
+        // An alias shared by two styles is listed once per style.
+        if !removed.contains(&alias) {
+            remove_symlink(alias, install_dir, dry_run, output)?;
+            removed.push(alias);
+        }
+    }
+    Ok(removed)
+}
+
 /// Check write permission to a directory.
 fn check_write_permission(dir: &Path) -> Result<()> {
     let test_file = dir.join(".daft-write-test");
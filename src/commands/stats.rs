@@ -0,0 +1,153 @@
+//! Command: `daft stats` — numbers about the current project.
+
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+
+use crate::core::storage::{LinkState, StorageReport};
+use crate::output::format::format_human_size;
+use crate::output::{CliOutput, Output};
+use crate::styles::{bold, dim, green, red, yellow};
+use crate::{get_git_common_dir, get_project_root, is_git_repository};
+
+#[derive(Parser)]
+#[command(name = "daft-stats")]
+#[command(version = crate::VERSION)]
+#[command(about = "Show numbers about the current project")]
+pub struct Args {
+    #[command(subcommand)]
+    command: StatsCommand,
+}
+
+#[derive(Subcommand)]
+enum StatsCommand {
+    /// Show the disk saved by sharing one object store, and check the sharing
+    #[command(long_about = r#"
+Show how much disk the project's worktrees save by sharing one object
+store, compared with the same checkouts as independent clones, and verify
+that the sharing is intact:
+
+  - each worktree's .git link leads back to the shared store;
+  - every path in objects/info/alternates exists;
+  - no worktree, and no directory in the project, carries a full .git
+    directory of its own.
+
+Exits with status 1 when a problem is found.
+"#)]
+    Storage,
+}
+
+pub fn run() -> Result<()> {
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    if !is_git_repository()? {
+        bail!("Not inside a Git repository");
+    }
+    match args.command {
+        StatsCommand::Storage => run_storage(&mut output),
+    }
+}
+
+fn run_storage(output: &mut dyn Output) -> Result<()> {
+    let root = get_project_root()?;
+    output.start_spinner("Measuring storage...");
+    let report = StorageReport::collect(&get_git_common_dir()?, &root);
+    output.finish_spinner();
+    let report = report?;
+
+    let shared = report.shared_count();
+    let objects = format_human_size(report.object_bytes);
+    output.info(&format!(
+        "{}  {}",
+        bold("Object store"),
+        dim(&report.common_dir.join("objects").display().to_string())
+    ));
+    output.info(&format!("  size          {objects}"));
+    output.info(&format!(
+        "  shared by     {shared} {}",
+        if shared == 1 { "worktree" } else { "worktrees" }
+    ));
+    output.info(&format!(
+        "  as clones     ~{} {}",
+        format_human_size(report.object_bytes.saturating_mul(shared.max(1) as u64)),
+        dim(&format!("({shared} x {objects})"))
+    ));
+    output.info(&format!(
+        "  saved         ~{}",
+        green(&format_human_size(report.saved_bytes()))
+    ));
+
+    output.info("");
+    output.info(&bold("Worktrees"));
+    let width = report
+        .worktrees
+        .iter()
+        .map(|w| label(w.branch.as_deref()).len())
+        .max()
+        .unwrap_or(0);
+    for worktree in &report.worktrees {
+        let name = format!("{:<width$}", label(worktree.branch.as_deref()));
+        let status = match &worktree.link {
+            LinkState::Shared => format!("{} {name}  {}", green("ok"), dim("shared")),
+            LinkState::Broken(reason) => format!("{} {name}  {reason}", red("broken")),
+            LinkState::OwnGitDir(bytes) => format!(
+                "{} {name}  carries its own .git ({}), not the shared store",
+                yellow("duplicate"),
+                format_human_size(*bytes)
+            ),
+        };
+        output.info(&format!("  {status}"));
+    }
+
+    if !report.alternates.is_empty() {
+        output.info("");
+        output.info(&bold("Alternates"));
+        for alternate in &report.alternates {
+            let mark = if alternate.exists {
+                green("ok")
+            } else {
+                red("missing")
+            };
+            output.info(&format!("  {mark} {}", alternate.path.display()));
+        }
+    }
+
+    if !report.strays.is_empty() {
+        output.info("");
+        output.info(&bold("Separate clones in the project"));
+        for stray in &report.strays {
+            output.info(&format!(
+                "  {} {} {}",
+                yellow("duplicate"),
+                stray
+                    .path
+                    .strip_prefix(&root)
+                    .unwrap_or(&stray.path)
+                    .display(),
+                dim(&format!("({})", format_human_size(stray.bytes)))
+            ));
+        }
+    }
+
+    let problems = report.problem_count();
+    if problems > 0 {
+        output.info("");
+        let noun = if problems == 1 { "problem" } else { "problems" };
+        let duplicated = report.duplicated_bytes();
+        if duplicated > 0 {
+            output.warning(&format!(
+                "{problems} storage {noun} found; {} held in duplicate object stores",
+                format_human_size(duplicated)
+            ));
+        } else {
+            output.warning(&format!("{problems} storage {noun} found"));
+        }
+        crate::deprecations::exit(1);
+    }
+    Ok(())
+}
+
+fn label(branch: Option<&str>) -> &str {
+    branch.unwrap_or("(detached)")
+}
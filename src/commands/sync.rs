@@ -267,7 +267,9 @@ fn resolve_push_governor(
 }
 
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-sync"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-sync",
+    ));
 
     init_logging(args.verbose >= 2);
 
@@ -378,6 +380,14 @@ fn exit_cancelled_with_cd(output: &mut CliOutput, cd_target: Option<&PathBuf>) -
     exit_cancelled(0, 0);
 }
 
+fn record_force_deprecated() {
+    crate::deprecations::record(
+        crate::deprecations::DeprecationKind::Flag,
+        "sync --force",
+        "--force is deprecated, use --prune-dirty (or -f) instead",
+    );
+}
+
 /// Sequential (non-TTY) execution path — the original sync flow.
 ///
 /// Cancellation here is coarser than the TUI path: the active git
@@ -394,7 +404,7 @@ fn run_sequential(args: Args, settings: DaftSettings, cancel: &Arc<CancelFlag>)
     }
 
     if args.force_deprecated {
-        output.warning("--force is deprecated, use --prune-dirty (or -f) instead");
+        record_force_deprecated();
     }
 
     let force = args.force();
@@ -672,7 +682,7 @@ fn run_tui(
 
     // ── Create TUI state with known phases and worktrees ───────────────
     if args.force_deprecated {
-        eprintln!("warning: --force is deprecated, use --prune-dirty (or -f) instead");
+        record_force_deprecated();
     }
 
     let force = args.force();
@@ -869,6 +879,11 @@ fn run_tui(
         Arc::new(std::sync::Mutex::new(Vec::new()));
     let skipped_unmerged_writer = Arc::clone(&skipped_unmerged);
 
+    // Refs the fetch phase moved, for the post-fetch hook after the TUI.
+    let fetched_refs: Arc<std::sync::Mutex<Vec<crate::hooks::post_fetch::RefUpdate>>> =
+        Arc::new(std::sync::Mutex::new(Vec::new()));
+    let fetched_refs_writer = Arc::clone(&fetched_refs);
+
     let shared_base_branch = Arc::new(base_branch.clone());
 
     // Clone values needed by orchestrator. Whole-WorktreeInfo snapshots are no
@@ -887,14 +902,16 @@ fn run_tui(
 
     let orchestrator_handle = std::thread::spawn(move || {
         // ── Phase 1: Fetch ─────────────────────────────────────────────
-        if !sync_shared::run_fetch_phase(
+        let Some(updated_refs) = sync_shared::run_fetch_phase(
             &tx,
             orch_settings.use_gitoxide,
             &orch_settings.remote,
+            &shared_git_dir,
             Some(&orch_cancel),
-        ) {
+        ) else {
             return;
-        }
+        };
+        *fetched_refs_writer.lock().unwrap() = updated_refs;
 
         // Cancelled during (or right after) the fetch: skip the refresh
         // and never build the DAG — nothing per-branch has started yet.
@@ -1333,6 +1350,7 @@ fn run_tui(
             partition_by_owner: false, // External unowned_start_index drives the partition.
             seeded_fields,
             forge_prs: forge_lookup,
+            time_format: settings.ui_time_format,
         },
         unowned_start_index,
     )
@@ -1397,6 +1415,17 @@ fn run_tui(
         crate::hooks::trust_skip::flush_pending_notice(&git_dir, &mut post_tui_output);
     }
 
+    // ── Post-TUI: post-fetch hook ──────────────────────────────────────
+    sync_shared::run_post_tui_post_fetch(
+        &fetched_refs,
+        "sync",
+        &settings,
+        &project_root,
+        &git_dir,
+        &source_worktree,
+        &hooks_config,
+    );
+
     // ── Post-TUI: handle deferred branch (current worktree) ────────────
     sync_shared::handle_post_tui_deferred(
         &deferred_branch,
@@ -5,7 +5,7 @@
 use crate::{
     CD_FILE_ENV,
     core::{
-        CommandBridge, TuiBridge,
+        CommandBridge, HookRunner, ProgressSink, TuiBridge,
         worktree::{
             info_field::FieldSet,
             list::{EntryKind, Stat},
@@ -14,7 +14,10 @@ use crate::{
         },
     },
     git::GitCommand,
-    hooks::{HookExecutor, HooksConfig},
+    hooks::{
+        HookExecutor, HooksConfig,
+        post_fetch::{self, RefSnapshot, RefUpdate},
+    },
     output::{
         CliOutput, Output, OutputConfig,
         tui::{FinalStatus, WorktreeRow, WorktreeStatus},
@@ -219,6 +222,54 @@ pub fn handle_post_tui_deferred(
     }
 }
 
+/// Run the post-fetch hook once the TUI has exited, for the refs the fetch
+/// phase moved. The TUI has no place to render a hook that belongs to no
+/// row, so the hook waits for the table to close; it still runs before the
+/// deferred prune, while the worktree it runs in is certain to exist.
+#[allow(clippy::too_many_arguments)]
+pub fn run_post_tui_post_fetch(
+    updated_refs: &std::sync::Mutex<Vec<RefUpdate>>,
+    command: &str,
+    settings: &DaftSettings,
+    project_root: &std::path::Path,
+    git_dir: &std::path::Path,
+    source_worktree: &std::path::Path,
+    hooks_config: &HooksConfig,
+) {
+    let updated_refs = std::mem::take(&mut *updated_refs.lock().unwrap());
+    if updated_refs.is_empty() {
+        return;
+    }
+    let config = OutputConfig::with_autocd(false, false, settings.autocd);
+    let mut cli_output = CliOutput::new(config);
+    let branch = GitCommand::new(true)
+        .with_gitoxide(settings.use_gitoxide)
+        .symbolic_ref_short_head()
+        .unwrap_or_default();
+    let hook_ctx = match crate::hooks::post_fetch::hook_context(
+        command,
+        project_root,
+        git_dir,
+        &settings.remote,
+        source_worktree,
+        &branch,
+        &updated_refs,
+    ) {
+        Ok(ctx) => ctx,
+        Err(e) => {
+            cli_output.warning(&format!("Post-fetch hook skipped: {e:#}"));
+            return;
+        }
+    };
+    let Ok(executor) = HookExecutor::new(hooks_config.clone()) else {
+        return;
+    };
+    let mut sink = CommandBridge::new(&mut cli_output, executor);
+    if let Err(e) = sink.run_hook(&hook_ctx) {
+        sink.on_warning(&format!("Post-fetch hook failed: {e}"));
+    }
+}
+
 /// Check if any TUI tasks failed and bail if so.
 ///
 /// This variant is intentionally tolerant of hook failures: a row whose
@@ -278,13 +329,15 @@ pub fn check_tui_failures_strict(rows: &[WorktreeRow]) -> anyhow::Result<()> {
 ///
 /// Sends `TaskStarted(Fetch)`, runs `git fetch --prune`, and sends
 /// `TaskCompleted` on success or `TaskCompleted(Failed)` + `AllDone` on failure.
-/// Returns `true` if the fetch succeeded.
+/// Returns the refs the fetch moved (for the post-fetch hook), or `None` if
+/// the fetch failed.
 pub fn run_fetch_phase(
     tx: &std::sync::mpsc::Sender<DagEvent>,
     use_gitoxide: bool,
     remote: &str,
+    git_dir: &std::path::Path,
     cancel: Option<&std::sync::Arc<crate::git::cancel::CancelFlag>>,
-) -> bool {
+) -> Option<Vec<RefUpdate>> {
     let _ = tx.send(DagEvent::TaskStarted {
         phase: OperationPhase::Fetch,
         branch_name: String::new(),
@@ -294,6 +347,7 @@ pub fn run_fetch_phase(
     if let Some(cancel) = cancel {
         fetch_git = fetch_git.with_cancel(std::sync::Arc::clone(cancel));
     }
+    let refs_before = RefSnapshot::capture(git_dir, remote);
     let fetch_result = fetch_git.fetch(remote, true);
 
     if let Err(e) = fetch_result {
@@ -304,7 +358,7 @@ pub fn run_fetch_phase(
             message: TaskMessage::Failed(format!("fetch failed: {e}")),
         });
         let _ = tx.send(DagEvent::AllDone);
-        return false;
+        return None;
     }
 
     let _ = tx.send(DagEvent::TaskCompleted {
@@ -314,7 +368,10 @@ pub fn run_fetch_phase(
         message: TaskMessage::Ok("fetched".into()),
     });
 
-    true
+    Some(post_fetch::diff(
+        &refs_before,
+        &RefSnapshot::capture(git_dir, remote),
+    ))
 }
 
 /// After the Fetch phase completes, re-run the streaming collector
@@ -554,7 +611,9 @@ fn run_remove_hook_best_effort(
         &entry.path,
         entry.branch.clone().unwrap_or_default(),
     )
-    .with_removal_reason(RemovalReason::Manual);
+    .with_removal_reason(RemovalReason::Manual)
+    // The whole repository goes, branches included.
+    .with_branch_exists(false);
 
     let presenter = TuiPresenter::new(tx.clone(), label.to_string(), hook_type);
     let mut output = BufferingOutput::new();
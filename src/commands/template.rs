@@ -0,0 +1,150 @@
+//! Command: `daft template` — snapshot ignored build directories so new
+//! worktrees start warm.
+
+use anyhow::{Result, bail};
+use chrono::Utc;
+use clap::{Parser, Subcommand};
+
+use crate::core::template;
+use crate::output::format::format_human_size;
+use crate::output::{CliOutput, Output};
+use crate::styles::{bold, dim, yellow};
+use crate::{get_current_worktree_path, is_git_repository};
+
+#[derive(Parser)]
+#[command(name = "daft-template")]
+#[command(version = crate::VERSION)]
+#[command(about = "Pre-seed new worktrees with build directories from a snapshot")]
+#[command(long_about = r#"
+Capture ignored build directories (node_modules, .venv, ...) from a
+reference worktree into a compressed template, and restore them into every
+new worktree before its post-create hooks run. An install hook then only has
+to reconcile the difference instead of starting from nothing.
+
+The directories come from the `template:` list in daft.yml:
+
+  template:
+    - node_modules
+    - .venv
+
+Only directories that exist and are ignored by git are captured. A new
+worktree gets a directory from the template when it is still listed in its
+own daft.yml and the worktree doesn't already have it. The template is
+stored at .git/.daft/template.tar.gz, with the list of captured directories
+in .git/.daft/template.json, and is replaced by each snapshot.
+"#)]
+pub struct Args {
+    #[command(subcommand)]
+    command: TemplateCommand,
+}
+
+#[derive(Subcommand)]
+enum TemplateCommand {
+    /// Capture the configured directories from a worktree
+    Snapshot {
+        /// Branch whose worktree to capture (default: the current worktree)
+        #[arg(long, value_name = "BRANCH")]
+        from: Option<String>,
+    },
+    /// Show the current template
+    Status,
+    /// Delete the template
+    Clear,
+}
+
+pub fn run() -> Result<()> {
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    if !is_git_repository()? {
+        bail!("Not inside a Git repository");
+    }
+    match args.command {
+        TemplateCommand::Snapshot { from } => run_snapshot(from, &mut output),
+        TemplateCommand::Status => run_status(&mut output),
+        TemplateCommand::Clear => run_clear(&mut output),
+    }
+}
+
+fn run_snapshot(from: Option<String>, output: &mut dyn Output) -> Result<()> {
+    let source = match from {
+        Some(branch) => {
+            let cwd = std::env::current_dir()?;
+            match crate::core::repo::find_worktree_for_branch(&cwd, &branch) {
+                Some(path) => path,
+                None => bail!("No worktree is checked out on '{branch}'"),
+            }
+        }
+        None => get_current_worktree_path()?,
+    };
+
+    output.start_spinner(&format!("Capturing template from {}...", source.display()));
+    let result = template::snapshot(&source);
+    output.finish_spinner();
+    let report = result?;
+
+    for (path, reason) in &report.skipped {
+        output.info(&format!(
+            "  {} {path} {}",
+            yellow("skipped"),
+            dim(&format!("({reason})"))
+        ));
+    }
+    output.success(&format!(
+        "Captured {} into the template ({})",
+        bold(&report.captured.join(", ")),
+        format_human_size(report.bytes)
+    ));
+    if let Some(ref reason) = report.unrecorded {
+        output.warning(&format!(
+            "Could not record where the template came from ({reason}); new worktrees still restore from it, but `{}` won't show its source",
+            crate::daft_cmd("template status")
+        ));
+    }
+    Ok(())
+}
+
+fn run_status(output: &mut dyn Output) -> Result<()> {
+    let Some(snapshot) = template::status(&std::env::current_dir()?)? else {
+        output.info(&format!(
+            "Template: {}",
+            dim("none (create one with `daft template snapshot`)")
+        ));
+        return Ok(());
+    };
+    let commit: String = snapshot.source_commit.chars().take(7).collect();
+    output.info(&format!("Template: {}", bold(&snapshot.paths.join(", "))));
+    if snapshot.source_branch.is_empty() {
+        output.info(&format!("Source:   {}", dim("unknown (not recorded)")));
+    } else {
+        output.info(&format!(
+            "Source:   {} {}",
+            snapshot.source_branch,
+            dim(&format!("@ {commit}"))
+        ));
+    }
+    let time_format = crate::output::time::configured();
+    output.info(&format!(
+        "Taken:    {}",
+        crate::output::time::ago(
+            snapshot.created_at.timestamp(),
+            Utc::now().timestamp(),
+            time_format
+        )
+    ));
+    output.info(&format!(
+        "Size:     {}",
+        format_human_size(snapshot.bytes.max(0) as u64)
+    ));
+    Ok(())
+}
+
+fn run_clear(output: &mut dyn Output) -> Result<()> {
+    if template::clear(&std::env::current_dir()?)? {
+        output.success("Deleted the template");
+    } else {
+        output.info("No template to delete");
+    }
+    Ok(())
+}
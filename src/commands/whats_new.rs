@@ -0,0 +1,60 @@
+/// Release notes for the daft version an update would install.
+///
+/// The update notification names the pending version and a one-line
+/// highlight; this command shows that version's full notes in the pager.
+use anyhow::Result;
+use clap::Parser;
+
+use crate::update_check;
+
+#[derive(Parser)]
+#[command(name = "daft-whats-new")]
+#[command(version = crate::VERSION, disable_version_flag = true)]
+#[command(about = "Show the release notes of the pending daft update")]
+#[command(long_about = r#"
+Shows the full release notes of the newest daft release, when it is newer
+than the version you are running, in the system pager.
+
+The notes come from the same GitHub release check that drives the update
+notification. A cached check less than a day old is reused; otherwise the
+latest release is fetched now.
+
+When you are already on the newest release, says so. Use daft release-notes
+for the notes of this and earlier versions.
+"#)]
+pub struct Args {
+    /// Disable pager, print directly to stdout
+    #[arg(long)]
+    no_pager: bool,
+}
+
+pub fn run() -> Result<()> {
+    let mut args_vec: Vec<String> = crate::cli::argv().to_vec();
+    if args_vec.len() >= 2 && args_vec[1] == "whats-new" {
+        args_vec.remove(1);
+    }
+    let args = Args::parse_from(&args_vec);
+
+    let Some(release) = update_check::pending_release()? else {
+        println!(
+            "daft {} is the latest release. Run '{}' for its notes.",
+            crate::VERSION,
+            crate::daft_cmd("release-notes")
+        );
+        return Ok(());
+    };
+
+    let mut markdown = match release.release_date {
+        Some(ref date) => format!("## [{}] - {date}\n\n", release.version),
+        None => format!("## [{}]\n\n", release.version),
+    };
+    markdown.push_str(
+        release
+            .notes
+            .as_deref()
+            .unwrap_or("This release has no notes."),
+    );
+    markdown.push('\n');
+
+    super::release_notes::page_markdown(&markdown, args.no_pager)
+}
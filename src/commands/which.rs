@@ -0,0 +1,68 @@
+//! Command: `daft which` — print the worktree path for a branch.
+
+use anyhow::{Result, bail};
+use clap::Parser;
+
+use crate::git::GitCommand;
+use crate::is_git_repository;
+use crate::output::CliOutput;
+
+#[derive(Parser)]
+#[command(name = "daft-which")]
+#[command(version = crate::VERSION)]
+#[command(about = "Print the worktree path for a branch")]
+#[command(long_about = r#"
+Print the absolute path of the worktree checked out on BRANCH, and nothing
+else, so it can be used in scripts:
+
+    cd "$(daft which feature/x)"
+
+A worktree paused mid-rebase still counts as BRANCH's worktree. When the
+branch has no worktree, daft which prints nothing on stdout and exits
+non-zero.
+
+With --create, a branch without a worktree is checked out into one first,
+exactly as `daft go` would (fetching, hooks, and layout included), and the
+new path is printed. Progress stays off stdout; warnings and errors go to
+stderr.
+"#)]
+pub struct Args {
+    /// Branch to look up
+    #[arg(value_name = "BRANCH")]
+    branch: String,
+
+    /// Check the branch out into a new worktree if it has none
+    #[arg(long)]
+    create: bool,
+}
+
+pub fn run() -> Result<()> {
+    // Skip argv[0] so clap sees "which" as the program name (same shape as
+    // `daft shared`).
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+
+    if !is_git_repository()? {
+        bail!("Not inside a Git repository");
+    }
+
+    let git = GitCommand::new(true);
+    if let Some(path) = git.find_worktree_for_branch(&args.branch)? {
+        println!("{}", path.display());
+        return Ok(());
+    }
+
+    if !args.create {
+        bail!(
+            "no worktree for branch '{}'\n  tip: `{}` creates one",
+            args.branch,
+            crate::daft_cmd(&format!("which --create {}", args.branch))
+        );
+    }
+
+    // Quiet: stdout carries only the path.
+    let mut output = CliOutput::quiet();
+    let path = super::checkout::provision_worktree(&args.branch, &mut output)?;
+    println!("{}", path.display());
+    Ok(())
+}
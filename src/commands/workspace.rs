@@ -0,0 +1,421 @@
+//! Command: `daft workspace` — open and inspect groups of daft projects.
+
+use anyhow::{Context, Result, bail};
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::core::workspace::{self, OpenWith, ResolvedMember, Workspace};
+use crate::core::worktree::list::{count_changed_files, get_upstream_ahead_behind};
+use crate::output::format::tilde_path;
+use crate::output::{
+    CliOutput, Output,
+    emit::{self, Cell, EmitArgs, EmitPayload, Table},
+};
+use crate::styles::{bold, cyan, dim, green, red, yellow};
+
+#[derive(Parser)]
+#[command(name = "daft-workspace")]
+#[command(version = crate::VERSION)]
+#[command(about = "Open and inspect groups of daft projects")]
+#[command(long_about = r#"
+Group several daft projects into a named workspace and work on them together.
+
+A workspace is a file in ~/.config/daft/workspaces/<name>.yml listing its
+member repos (by catalog name, uuid, or path) and, optionally, the branch
+whose worktree to use for each; members without a branch use the repo's
+default branch:
+
+    description: API, worker, and the shared schema
+    open: tmux
+    members:
+      - repo: api
+      - repo: worker
+        branch: feat/queue
+
+`open` brings the members' worktrees up together: `tmux` creates (or
+re-attaches to) a session named daft-<name> with one window per member;
+`editor` runs the workspace's `editor` command (default $VISUAL, then
+$EDITOR) once with every member's worktree. `--print` writes one
+repo/branch/path line per member for scripts instead.
+
+`status` shows each member's branch, worktree, uncommitted changes, and
+position against its upstream. Members whose repo or worktree can't be
+found are reported and skipped rather than failing the whole command.
+
+Without a subcommand, lists the defined workspaces.
+"#)]
+pub struct Args {
+    #[command(subcommand)]
+    command: Option<WorkspaceCommand>,
+}
+
+#[derive(Subcommand)]
+enum WorkspaceCommand {
+    /// List the defined workspaces
+    List,
+    /// Open every member's worktree in tmux or an editor
+    Open(OpenArgs),
+    /// Show the state of every member's worktree
+    Status(StatusArgs),
+}
+
+#[derive(Parser)]
+struct OpenArgs {
+    /// Workspace to open
+    name: String,
+
+    /// Override the workspace's `open` setting
+    #[arg(long, value_enum, value_name = "HOW")]
+    with: Option<OpenWith>,
+
+    /// Print repo, branch, and worktree path per member instead of opening
+    #[arg(long, conflicts_with = "with")]
+    print: bool,
+}
+
+#[derive(Parser)]
+struct StatusArgs {
+    /// Workspace to inspect (all workspaces when omitted)
+    name: Option<String>,
+
+    #[command(flatten)]
+    emit: EmitArgs,
+}
+
+pub fn run() -> Result<()> {
+    // Skip argv[0] so clap sees "workspace" as the program name (same shape
+    // as `daft shared`).
+    let args_raw: Vec<String> = crate::cli::argv().iter().skip(1).cloned().collect();
+    let args = Args::parse_from(args_raw);
+    let mut output = CliOutput::default_output();
+
+    match args.command {
+        None | Some(WorkspaceCommand::List) => run_list(&mut output),
+        Some(WorkspaceCommand::Open(open_args)) => run_open(&open_args, &mut output),
+        Some(WorkspaceCommand::Status(status_args)) => run_status(&status_args, &mut output),
+    }
+}
+
+fn run_list(output: &mut dyn Output) -> Result<()> {
+    let names = workspace::list_names()?;
+    if names.is_empty() {
+        output.info(&dim(&format!(
+            "No workspaces defined. Create one in {}/<name>.yml",
+            tilde_path(&workspace::workspaces_dir()?.to_string_lossy())
+        )));
+        return Ok(());
+    }
+    let width = names.iter().map(String::len).max().unwrap_or(0);
+    for name in &names {
+        match workspace::load(name) {
+            Ok(ws) => {
+                let count = ws.members.len();
+                let members = format!("{count} member{}", if count == 1 { "" } else { "s" });
+                let description = ws
+                    .description
+                    .as_deref()
+                    .map(|d| format!("  {}", dim(d)))
+                    .unwrap_or_default();
+                output.info(&format!(
+                    "{}  {}{description}",
+                    bold(&format!("{name:<width$}")),
+                    members
+                ));
+            }
+            Err(e) => output.info(&format!(
+                "{}  {}",
+                bold(&format!("{name:<width$}")),
+                red(&format!("invalid: {}", e.root_cause()))
+            )),
+        }
+    }
+    Ok(())
+}
+
+fn run_open(args: &OpenArgs, output: &mut dyn Output) -> Result<()> {
+    let ws = workspace::load(&args.name)?;
+    let members: Vec<ResolvedMember> = ws.members.iter().map(workspace::resolve_member).collect();
+
+    let mut ready: Vec<(&ResolvedMember, PathBuf)> = Vec::new();
+    for member in &members {
+        match (&member.worktree, &member.problem) {
+            (Some(path), None) => ready.push((member, path.clone())),
+            _ => output.warning(&format!(
+                "{}: {} — skipped",
+                member.repo,
+                member.problem.as_deref().unwrap_or("unavailable")
+            )),
+        }
+    }
+
+    if args.print {
+        for (member, path) in &ready {
+            output.raw(&format!(
+                "{}\t{}\t{}\n",
+                member.repo,
+                member.branch.as_deref().unwrap_or(""),
+                path.display()
+            ));
+        }
+        return Ok(());
+    }
+
+    if ready.is_empty() {
+        bail!("no member of workspace '{}' has a usable worktree", ws.name);
+    }
+
+    match args.with.or(ws.open).unwrap_or(OpenWith::Tmux) {
+        OpenWith::Tmux => open_tmux(&ws, &ready, output),
+        OpenWith::Editor => open_editor(&ws, &ready),
+    }
+}
+
+/// Create the workspace's tmux session (one window per member) unless it
+/// already exists, then attach — or switch to it from inside tmux.
+fn open_tmux(
+    ws: &Workspace,
+    ready: &[(&ResolvedMember, PathBuf)],
+    output: &mut dyn Output,
+) -> Result<()> {
+    let session = ws.session_name();
+    let exists = Command::new("tmux")
+        .args(["has-session", "-t", &format!("={session}")])
+        .output()
+        .context("could not run tmux — is it installed?")?
+        .status
+        .success();
+
+    if exists {
+        output.step(&format!("Attaching to existing tmux session {session}"));
+    } else {
+        for (i, (member, path)) in ready.iter().enumerate() {
+            let path = path.to_string_lossy();
+            let mut cmd = Command::new("tmux");
+            if i == 0 {
+                cmd.args(["new-session", "-d", "-s", &session]);
+            } else {
+                cmd.args(["new-window", "-t", &format!("={session}:")]);
+            }
+            let status = cmd
+                .args(["-n", &member.repo, "-c", &path])
+                .status()
+                .context("could not run tmux")?;
+            if !status.success() {
+                bail!("tmux failed to open a window for {}", member.repo);
+            }
+        }
+        output.step(&format!(
+            "Created tmux session {session} with {} window{}",
+            ready.len(),
+            if ready.len() == 1 { "" } else { "s" }
+        ));
+    }
+
+    let verb = if std::env::var_os("TMUX").is_some() {
+        "switch-client"
+    } else {
+        "attach-session"
+    };
+    let status = Command::new("tmux")
+        .args([verb, "-t", &format!("={session}")])
+        .status()
+        .context("could not run tmux")?;
+    if !status.success() {
+        bail!("tmux {verb} failed for session {session}");
+    }
+    Ok(())
+}
+
+/// Run the editor once with every member's worktree (multi-root editors
+/// such as VS Code open them as one window).
+fn open_editor(ws: &Workspace, ready: &[(&ResolvedMember, PathBuf)]) -> Result<()> {
+    let editor = ws
+        .editor
+        .clone()
+        .or_else(|| std::env::var("VISUAL").ok())
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|e| !e.trim().is_empty())
+        .with_context(|| {
+            format!(
+                "no editor configured for workspace '{}'\n  tip: set `editor:` in its file, or $VISUAL/$EDITOR",
+                ws.name
+            )
+        })?;
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or_default();
+    let status = Command::new(program)
+        .args(words)
+        .args(ready.iter().map(|(_, path)| path))
+        .status()
+        .with_context(|| format!("could not run editor '{program}'"))?;
+    if !status.success() {
+        bail!("editor '{program}' exited with {status}");
+    }
+    Ok(())
+}
+
+/// One member's row in `workspace status`.
+struct StatusRow {
+    workspace: String,
+    member: ResolvedMember,
+    staged: usize,
+    unstaged: usize,
+    untracked: usize,
+    ahead_behind: Option<(usize, usize)>,
+}
+
+fn run_status(args: &StatusArgs, output: &mut dyn Output) -> Result<()> {
+    let workspaces: Vec<Workspace> = match &args.name {
+        Some(name) => vec![workspace::load(name)?],
+        None => workspace::list_names()?
+            .iter()
+            .map(|name| workspace::load(name))
+            .collect::<Result<_>>()?,
+    };
+
+    let rows: Vec<StatusRow> = workspaces
+        .iter()
+        .flat_map(|ws| {
+            ws.members.iter().map(|member| {
+                let member = workspace::resolve_member(member);
+                let (changes, ahead_behind) = match (&member.worktree, &member.branch) {
+                    (Some(path), Some(branch)) => (
+                        count_changed_files(path),
+                        get_upstream_ahead_behind(branch, path),
+                    ),
+                    _ => (Default::default(), None),
+                };
+                StatusRow {
+                    workspace: ws.name.clone(),
+                    member,
+                    staged: changes.staged,
+                    unstaged: changes.unstaged,
+                    untracked: changes.untracked,
+                    ahead_behind,
+                }
+            })
+        })
+        .collect();
+
+    if args.emit.is_structured() {
+        return emit::emit_and_handle(
+            "workspace status",
+            EmitPayload::Tabular(build_status_table(&rows)),
+            &args.emit,
+            &mut std::io::stdout(),
+        )
+        .map_err(|e| anyhow::anyhow!("{e}"));
+    }
+
+    if workspaces.is_empty() {
+        return run_list(output);
+    }
+
+    for ws in &workspaces {
+        let header = match &ws.description {
+            Some(d) => format!("{}  {}", bold(&ws.name), dim(d)),
+            None => bold(&ws.name),
+        };
+        output.info(&header);
+        let members: Vec<&StatusRow> = rows.iter().filter(|r| r.workspace == ws.name).collect();
+        let width = members
+            .iter()
+            .map(|r| r.member.repo.len())
+            .max()
+            .unwrap_or(0);
+        for row in members {
+            output.info(&format!(
+                "  {:<width$}  {}",
+                row.member.repo,
+                describe_row(row)
+            ));
+        }
+        output.info("");
+    }
+    Ok(())
+}
+
+fn describe_row(row: &StatusRow) -> String {
+    let member = &row.member;
+    let branch = cyan(member.branch.as_deref().unwrap_or("-"));
+    let (Some(path), None) = (&member.worktree, &member.problem) else {
+        return format!(
+            "{branch}  {}",
+            red(member.problem.as_deref().unwrap_or("unavailable"))
+        );
+    };
+
+    let mut state = Vec::new();
+    if row.staged > 0 {
+        state.push(green(&format!("+{}", row.staged)));
+    }
+    if row.unstaged > 0 {
+        state.push(yellow(&format!("~{}", row.unstaged)));
+    }
+    if row.untracked > 0 {
+        state.push(dim(&format!("?{}", row.untracked)));
+    }
+    if state.is_empty() {
+        state.push(dim("clean"));
+    }
+    match row.ahead_behind {
+        Some((0, 0)) => {}
+        Some((ahead, behind)) => {
+            if ahead > 0 {
+                state.push(format!("↑{ahead}"));
+            }
+            if behind > 0 {
+                state.push(format!("↓{behind}"));
+            }
+        }
+        None => state.push(dim("no upstream")),
+    }
+    format!(
+        "{branch}  {}  {}",
+        dim(&tilde_path(&path.to_string_lossy())),
+        state.join(" ")
+    )
+}
+
+fn build_status_table(rows: &[StatusRow]) -> Table {
+    let mut table = Table::new([
+        "workspace",
+        "repo",
+        "branch",
+        "path",
+        "staged",
+        "unstaged",
+        "untracked",
+        "ahead",
+        "behind",
+        "problem",
+    ]);
+    let opt_str = |s: Option<String>| s.map(Cell::str).unwrap_or_else(Cell::null);
+    let opt_int = |n: Option<usize>| {
+        n.map(|n| Cell::int(i64::try_from(n).unwrap_or(i64::MAX)))
+            .unwrap_or_else(Cell::null)
+    };
+    for row in rows {
+        let member = &row.member;
+        let usable = member.problem.is_none();
+        table = table.row([
+            Cell::str(&row.workspace),
+            Cell::str(&member.repo),
+            opt_str(member.branch.clone()),
+            opt_str(
+                member
+                    .worktree
+                    .as_ref()
+                    .map(|p| p.to_string_lossy().into_owned()),
+            ),
+            opt_int(usable.then_some(row.staged)),
+            opt_int(usable.then_some(row.unstaged)),
+            opt_int(usable.then_some(row.untracked)),
+            opt_int(row.ahead_behind.map(|(a, _)| a)),
+            opt_int(row.ahead_behind.map(|(_, b)| b)),
+            opt_str(member.problem.clone()),
+        ]);
+    }
+    table
+}
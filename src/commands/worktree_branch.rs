@@ -144,7 +144,9 @@ pub struct Args {
 
 /// Entry point for `git-worktree-branch`.
 pub fn run() -> Result<()> {
-    let args = Args::parse_from(crate::get_clap_args("git-worktree-branch"));
+    let args = Args::parse_from(crate::get_clap_args_with_defaults::<Args>(
+        "git-worktree-branch",
+    ));
     run_with_args(args)
 }
 
@@ -234,7 +236,7 @@ pub struct RemoveArgs {
 
 /// Entry point for `daft remove`.
 pub fn run_remove() -> Result<()> {
-    let mut raw = crate::get_clap_args("daft-remove");
+    let mut raw = crate::get_clap_args_with_defaults::<RemoveArgs>("daft-remove");
     raw[0] = "daft remove".to_string();
     let mut remove_args = RemoveArgs::parse_from(raw);
 
@@ -385,7 +387,7 @@ pub struct RenameArgs {
 
 /// Entry point for `daft rename`.
 pub fn run_rename() -> Result<()> {
-    let mut raw = crate::get_clap_args("daft-rename");
+    let mut raw = crate::get_clap_args_with_defaults::<RenameArgs>("daft-rename");
     raw[0] = "daft rename".to_string();
     let mut rename_args = RenameArgs::parse_from(raw);
 
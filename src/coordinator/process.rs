@@ -605,7 +605,7 @@ fn run_single_background_job(
     // 6. Call run_command() to execute the shell command (now also
     //    forwarding `pid_tx` so the registrar above can pick up the PID).
     let cmd_result = run_command(
-        &job.command,
+        &job.shell_command(),
         &job.env,
         &job.working_dir,
         job.timeout,
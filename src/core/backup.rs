@@ -0,0 +1,671 @@
+//! Project backups behind `daft backup`.
+//!
+//! A backup copies the work that exists only on this machine — every local
+//! branch, every stash entry, and a snapshot of each dirty worktree's
+//! uncommitted changes — to a backup remote or a bundle file. Everything is
+//! stored under one ref namespace, [`BACKUP_NAMESPACE`]:
+//!
+//! ```text
+//! refs/daft-backup/heads/<branch>   local branch tips
+//! refs/daft-backup/stash/<n>        stash@{n}
+//! refs/daft-backup/wip/<branch>     uncommitted changes in <branch>'s worktree
+//! refs/daft-backup/wip/detached/<worktree>
+//!                                   uncommitted changes in a detached worktree
+//! ```
+//!
+//! so a backup never touches the destination's own branches, and each run
+//! replaces the previous one (refs that no longer exist locally are deleted
+//! from a remote; a bundle is rewritten). Worktree snapshots come from
+//! `git stash create`, which records tracked changes without touching the
+//! worktree or the stash list; untracked files are not included. A worktree
+//! that can't be snapshotted (its index is locked, say) fails the run rather
+//! than passing for clean, which would delete its previous snapshot.
+//!
+//! Restoring fetches the namespace back and recreates what is missing:
+//! branches that don't exist locally, branches the backup is ahead of, and
+//! stash entries (worktree snapshots come back as stash entries too).
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+
+use crate::core::repo::{existing_store, git_stdout, repo_hash};
+use crate::core::worktree::porcelain::parse_worktree_list_porcelain;
+use crate::git::{GitCommand, PushOptions};
+use crate::store::models::BackupRunRow;
+use crate::store::paths;
+use crate::store::pool::Pool;
+use crate::store::repos::BackupRunsRepo;
+use crate::utils::git_command_at;
+
+/// Ref namespace a backup is written under.
+pub const BACKUP_NAMESPACE: &str = "refs/daft-backup/";
+
+/// Where restored refs are fetched to before they are applied. Deleted again
+/// once the restore finishes.
+const RESTORE_NAMESPACE: &str = "refs/daft-restore/";
+
+/// Where a backup goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+    /// A configured remote of this repository.
+    Remote(String),
+    /// A bundle file.
+    Bundle(PathBuf),
+}
+
+impl Destination {
+    /// Read `spec` as a remote name when this repository has a remote by
+    /// that name, else as a bundle path, expanded by
+    /// [`crate::settings::expand_path`] with errors naming `key`. A path
+    /// naming an existing directory means `<dir>/<project>.bundle`.
+    pub fn resolve(spec: &str, key: &str, repo_dir: &Path, project: &str) -> Result<Self> {
+        let remotes = git_stdout(repo_dir, &["remote"])?;
+        if remotes.lines().any(|r| r == spec) {
+            return Ok(Self::Remote(spec.to_string()));
+        }
+        let path = crate::settings::expand_path(key, spec)?;
+        let path = if path.is_relative() {
+            std::env::current_dir()?.join(path)
+        } else {
+            path
+        };
+        if path.is_dir() {
+            return Ok(Self::Bundle(path.join(format!("{project}.bundle"))));
+        }
+        Ok(Self::Bundle(path))
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Remote(name) => format!("remote '{name}'"),
+            Self::Bundle(path) => path.display().to_string(),
+        }
+    }
+
+    /// The argument `git fetch` reads this destination from.
+    fn fetch_source(&self) -> &std::ffi::OsStr {
+        match self {
+            Self::Remote(name) => name.as_ref(),
+            Self::Bundle(path) => path.as_os_str(),
+        }
+    }
+}
+
+/// The refs one backup run copies, keyed by their name under
+/// [`BACKUP_NAMESPACE`].
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    pub refs: BTreeMap<String, String>,
+    pub branches: usize,
+    pub stashes: usize,
+    pub snapshots: usize,
+}
+
+impl Snapshot {
+    /// Gather branches, stash entries, and dirty-worktree snapshots of the
+    /// repository `repo_dir` belongs to.
+    pub fn collect(repo_dir: &Path) -> Result<Self> {
+        let mut snapshot = Self::default();
+
+        let heads = git_stdout(
+            repo_dir,
+            &[
+                "for-each-ref",
+                "--format=%(objectname) %(refname)",
+                "refs/heads/",
+            ],
+        )?;
+        for line in heads.lines() {
+            if let Some((sha, name)) = line.split_once(' ')
+                && let Some(branch) = name.strip_prefix("refs/heads/")
+            {
+                snapshot.refs.insert(format!("heads/{branch}"), sha.into());
+                snapshot.branches += 1;
+            }
+        }
+
+        // An empty stash list (or no refs/stash at all) prints nothing.
+        let stashes = git_stdout(repo_dir, &["stash", "list", "--format=%H"]).unwrap_or_default();
+        for (index, sha) in stashes.lines().filter(|l| !l.is_empty()).enumerate() {
+            snapshot.refs.insert(format!("stash/{index}"), sha.into());
+            snapshot.stashes += 1;
+        }
+
+        let porcelain = git_stdout(repo_dir, &["worktree", "list", "--porcelain"])?;
+        for worktree in parse_worktree_list_porcelain(&porcelain) {
+            if worktree.is_bare || !worktree.path.is_dir() {
+                continue;
+            }
+            // An unborn HEAD has nothing to snapshot against.
+            if git_stdout(
+                &worktree.path,
+                &["rev-parse", "--verify", "--quiet", "HEAD"],
+            )
+            .is_err()
+            {
+                continue;
+            }
+            // Prints nothing for a clean worktree.
+            let sha = git_stdout(&worktree.path, &["stash", "create"]).with_context(|| {
+                format!(
+                    "Could not snapshot uncommitted changes in {}",
+                    worktree.path.display()
+                )
+            })?;
+            let sha = sha.trim();
+            if sha.is_empty() {
+                continue;
+            }
+            let name = match &worktree.branch {
+                Some(branch) => format!("wip/{branch}"),
+                None => detached_snapshot_name(&worktree.path, &snapshot.refs),
+            };
+            snapshot.refs.insert(name, sha.into());
+            snapshot.snapshots += 1;
+        }
+
+        if snapshot.refs.is_empty() {
+            bail!("Nothing to back up: the repository has no branches");
+        }
+        Ok(snapshot)
+    }
+}
+
+/// `wip/detached/<dir>` for a detached worktree, named after its directory
+/// with anything a ref name can't hold replaced, and numbered when two
+/// detached worktrees share a directory name.
+fn detached_snapshot_name(path: &Path, taken: &BTreeMap<String, String>) -> String {
+    let dir: String = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "-_".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let base = format!("wip/detached/{}", dir.trim_matches('-'));
+    (1..)
+        .map(|n| match n {
+            1 => base.clone(),
+            n => format!("{base}-{n}"),
+        })
+        .find(|name| !taken.contains_key(name))
+        .expect("an unused name")
+}
+
+/// Write `snapshot` to `destination`, replacing the previous backup there.
+pub fn write(repo_dir: &Path, destination: &Destination, snapshot: &Snapshot) -> Result<()> {
+    match destination {
+        Destination::Remote(remote) => push_to_remote(repo_dir, remote, snapshot),
+        Destination::Bundle(path) => write_bundle(repo_dir, path, snapshot),
+    }
+}
+
+fn push_to_remote(repo_dir: &Path, remote: &str, snapshot: &Snapshot) -> Result<()> {
+    let listed = git_stdout(
+        repo_dir,
+        &[
+            "ls-remote",
+            "--refs",
+            remote,
+            &format!("{BACKUP_NAMESPACE}*"),
+        ],
+    )
+    .with_context(|| format!("Could not reach remote '{remote}'"))?;
+    let mut refspecs: Vec<String> = snapshot
+        .refs
+        .iter()
+        .map(|(name, sha)| format!("+{sha}:{BACKUP_NAMESPACE}{name}"))
+        .collect();
+    for line in listed.lines() {
+        if let Some((_, full)) = line.split_once('\t')
+            && let Some(name) = full.strip_prefix(BACKUP_NAMESPACE)
+            && !snapshot.refs.contains_key(name)
+        {
+            refspecs.push(format!(":{full}"));
+        }
+    }
+
+    // Skip pre-push: a backup is not a publish, so the repo's pre-push
+    // checks have no business gating it.
+    let opts = PushOptions {
+        verify: false,
+        on_output: None,
+    };
+    let pushed = GitCommand::new(true).push_refspecs_from(remote, &refspecs, repo_dir, &opts)?;
+    if !pushed.success {
+        bail!("git push to '{remote}' failed: {}", pushed.stderr.trim());
+    }
+    Ok(())
+}
+
+fn write_bundle(repo_dir: &Path, path: &Path, snapshot: &Snapshot) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    // A bundle records refs by name, so the snapshot is staged under the
+    // namespace locally for the duration of `git bundle create`.
+    clear_namespace(repo_dir, BACKUP_NAMESPACE)?;
+    let staged = update_refs(
+        repo_dir,
+        snapshot
+            .refs
+            .iter()
+            .map(|(name, sha)| format!("update {BACKUP_NAMESPACE}{name} {sha}\n")),
+    );
+    let tmp = path.with_extension("bundle.tmp");
+    let result = staged.and_then(|()| {
+        let output = git_command_at(repo_dir)
+            .args(["bundle", "create", "--quiet"])
+            .arg(&tmp)
+            .arg(format!("--glob={BACKUP_NAMESPACE}*"))
+            .output()
+            .context("Failed to run git bundle")?;
+        if !output.status.success() {
+            bail!(
+                "git bundle create failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        std::fs::rename(&tmp, path).with_context(|| format!("Failed to write {}", path.display()))
+    });
+    let _ = std::fs::remove_file(&tmp);
+    clear_namespace(repo_dir, BACKUP_NAMESPACE)?;
+    result
+}
+
+/// What a restore changed.
+#[derive(Debug, Default)]
+pub struct RestoreReport {
+    /// Branches that did not exist locally.
+    pub created: Vec<String>,
+    /// Branches the backup was ahead of.
+    pub fast_forwarded: Vec<String>,
+    /// Branches whose local tip differs from the backup and was kept.
+    pub kept: Vec<String>,
+    /// Stash entries added back, worktree snapshots included.
+    pub stashes: usize,
+}
+
+/// Bring the backup at `source` back into the repository `repo_dir`
+/// belongs to. Existing work is never discarded: a branch that has diverged
+/// from its backup keeps its local tip, and a stash entry already in the
+/// stash list is not added twice.
+pub fn restore(repo_dir: &Path, source: &Destination) -> Result<RestoreReport> {
+    clear_namespace(repo_dir, RESTORE_NAMESPACE)?;
+    let output = git_command_at(repo_dir)
+        .args(["fetch", "--quiet", "--no-tags"])
+        .arg(source.fetch_source())
+        .arg(format!("+{BACKUP_NAMESPACE}*:{RESTORE_NAMESPACE}*"))
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run git fetch")?;
+    if !output.status.success() {
+        bail!(
+            "Could not read the backup from {}: {}",
+            source.describe(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let result = apply_restore(repo_dir);
+    clear_namespace(repo_dir, RESTORE_NAMESPACE)?;
+    result
+}
+
+fn apply_restore(repo_dir: &Path) -> Result<RestoreReport> {
+    let fetched = list_namespace(repo_dir, RESTORE_NAMESPACE)?;
+    if fetched.is_empty() {
+        bail!("The destination holds no daft backup");
+    }
+    let mut report = RestoreReport::default();
+
+    let checked_out: HashSet<String> =
+        parse_worktree_list_porcelain(&git_stdout(repo_dir, &["worktree", "list", "--porcelain"])?)
+            .into_iter()
+            .filter_map(|w| w.branch)
+            .collect();
+    for (name, sha) in &fetched {
+        let Some(branch) = name.strip_prefix("heads/") else {
+            continue;
+        };
+        let local = git_stdout(
+            repo_dir,
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("refs/heads/{branch}"),
+            ],
+        )
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+        match local {
+            None => {
+                update_refs(
+                    repo_dir,
+                    [format!("create refs/heads/{branch} {sha}\n")].into_iter(),
+                )?;
+                report.created.push(branch.to_string());
+            }
+            Some(local) if local == *sha => {}
+            Some(local) if !checked_out.contains(branch) && is_ancestor(repo_dir, &local, sha) => {
+                update_refs(
+                    repo_dir,
+                    [format!("update refs/heads/{branch} {sha} {local}\n")].into_iter(),
+                )?;
+                report.fast_forwarded.push(branch.to_string());
+            }
+            Some(_) => report.kept.push(branch.to_string()),
+        }
+    }
+
+    // `git stash store` pushes onto the top, so the oldest entry goes first
+    // to keep stash@{n} numbering; worktree snapshots land on top.
+    let existing: HashSet<String> = git_stdout(repo_dir, &["stash", "list", "--format=%H"])
+        .unwrap_or_default()
+        .lines()
+        .map(str::to_string)
+        .collect();
+    let mut stashes: Vec<(usize, &String)> = fetched
+        .iter()
+        .filter_map(|(name, sha)| Some((name.strip_prefix("stash/")?.parse().ok()?, sha)))
+        .collect();
+    stashes.sort_by_key(|&(n, _)| std::cmp::Reverse(n));
+    let snapshots = fetched.iter().filter_map(|(name, sha)| {
+        let wip = name.strip_prefix("wip/")?;
+        let message = match wip.strip_prefix("detached/") {
+            Some(dir) => format!("daft backup: uncommitted changes in detached worktree {dir}"),
+            None => format!("daft backup: uncommitted changes on {wip}"),
+        };
+        Some((message, sha))
+    });
+    let entries = stashes
+        .into_iter()
+        .map(|(_, sha)| {
+            let message = git_stdout(repo_dir, &["log", "-1", "--format=%s", sha])
+                .map(|s| s.trim().to_string())
+                .unwrap_or_default();
+            (message, sha)
+        })
+        .chain(snapshots);
+    for (message, sha) in entries {
+        if existing.contains(sha.as_str()) {
+            continue;
+        }
+        let output = git_command_at(repo_dir)
+            .args(["stash", "store", "-m", &message, sha])
+            .output()
+            .context("Failed to run git stash store")?;
+        if output.status.success() {
+            report.stashes += 1;
+        }
+    }
+    Ok(report)
+}
+
+/// Run [`Snapshot::collect`] and [`write`], recording the run (success or
+/// failure) in the repository's store. Only one backup of a repository runs
+/// at a time, so a manual run and a scheduled one can't interleave pushes.
+pub fn run(repo_dir: &Path, destination: &Destination) -> Result<Snapshot> {
+    let lock_path = paths::for_repo(&repo_hash(repo_dir)?)?.with_file_name("backup.lock");
+    let lock_file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(&lock_path)
+        .context("Failed to open the backup lock file")?;
+    use fs2::FileExt;
+    if lock_file.try_lock_exclusive().is_err() {
+        bail!("Another backup of this repository is already running");
+    }
+
+    let started_at = Utc::now();
+    let result = Snapshot::collect(repo_dir)
+        .and_then(|snapshot| write(repo_dir, destination, &snapshot).map(|()| snapshot));
+    let (branches, stashes, snapshots) = result.as_ref().map_or((0, 0, 0), |s| {
+        (s.branches as i64, s.stashes as i64, s.snapshots as i64)
+    });
+    record(
+        repo_dir,
+        &BackupRunRow {
+            destination: destination.describe(),
+            started_at,
+            finished_at: Utc::now(),
+            branches,
+            stashes,
+            snapshots,
+            error: result.as_ref().err().map(|e| format!("{e:#}")),
+        },
+    );
+    result
+}
+
+/// The newest run and the newest successful run, without creating a store
+/// for a repository that has none.
+pub fn history(repo_dir: &Path) -> Result<(Option<BackupRunRow>, Option<BackupRunRow>)> {
+    let Some(db_path) = existing_store(repo_dir) else {
+        return Ok((None, None));
+    };
+    let pool = Pool::open(&db_path)?;
+    let conn = pool.reader()?;
+    Ok((
+        BackupRunsRepo::latest(&conn)?,
+        BackupRunsRepo::latest_success(&conn)?,
+    ))
+}
+
+fn record(repo_dir: &Path, row: &BackupRunRow) {
+    let _ = (|| -> Result<()> {
+        let pool = Pool::open(&paths::for_repo(&repo_hash(repo_dir)?)?)?;
+        let conn = pool.writer()?;
+        BackupRunsRepo::record(&conn, row)?;
+        Ok(())
+    })();
+}
+
+/// The refs under `namespace`, keyed by their name below it.
+fn list_namespace(repo_dir: &Path, namespace: &str) -> Result<BTreeMap<String, String>> {
+    let listed = git_stdout(
+        repo_dir,
+        &[
+            "for-each-ref",
+            "--format=%(objectname) %(refname)",
+            namespace,
+        ],
+    )?;
+    Ok(listed
+        .lines()
+        .filter_map(|line| {
+            let (sha, full) = line.split_once(' ')?;
+            Some((full.strip_prefix(namespace)?.to_string(), sha.to_string()))
+        })
+        .collect())
+}
+
+fn clear_namespace(repo_dir: &Path, namespace: &str) -> Result<()> {
+    let refs = list_namespace(repo_dir, namespace)?;
+    update_refs(
+        repo_dir,
+        refs.keys()
+            .map(|name| format!("delete {namespace}{name}\n")),
+    )
+}
+
+/// Apply `git update-ref --stdin` commands as one transaction.
+fn update_refs(repo_dir: &Path, commands: impl Iterator<Item = String>) -> Result<()> {
+    let input: String = commands.collect();
+    if input.is_empty() {
+        return Ok(());
+    }
+    let mut child = git_command_at(repo_dir)
+        .args(["update-ref", "--stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run git update-ref")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "git update-ref failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn is_ancestor(repo_dir: &Path, ancestor: &str, descendant: &str) -> bool {
+    git_command_at(repo_dir)
+        .args(["merge-base", "--is-ancestor", ancestor, descendant])
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        git_stdout(dir, args).unwrap().trim().to_string()
+    }
+
+    fn repo(tmp: &TempDir) -> PathBuf {
+        let dir = tmp.path().join("repo");
+        std::fs::create_dir_all(&dir).unwrap();
+        git(&dir, &["init", "-q", "-b", "main"]);
+        git(&dir, &["config", "user.email", "t@example.com"]);
+        git(&dir, &["config", "user.name", "t"]);
+        std::fs::write(dir.join("a.txt"), "one\n").unwrap();
+        git(&dir, &["add", "a.txt"]);
+        git(&dir, &["commit", "-q", "-m", "one"]);
+        dir
+    }
+
+    #[test]
+    fn bundle_round_trip_restores_branches_stashes_and_snapshots() {
+        let tmp = TempDir::new().unwrap();
+        let dir = repo(&tmp);
+        git(&dir, &["branch", "feature"]);
+        std::fs::write(dir.join("a.txt"), "stashed\n").unwrap();
+        git(&dir, &["stash", "push", "-q", "-m", "parked"]);
+        std::fs::write(dir.join("a.txt"), "dirty\n").unwrap();
+
+        let bundle = Destination::Bundle(tmp.path().join("out/repo.bundle"));
+        let snapshot = Snapshot::collect(&dir).unwrap();
+        assert_eq!(
+            (snapshot.branches, snapshot.stashes, snapshot.snapshots),
+            (2, 1, 1)
+        );
+        write(&dir, &bundle, &snapshot).unwrap();
+        assert!(list_namespace(&dir, BACKUP_NAMESPACE).unwrap().is_empty());
+        // The snapshot leaves the worktree and stash list alone.
+        assert_eq!(
+            std::fs::read_to_string(dir.join("a.txt")).unwrap(),
+            "dirty\n"
+        );
+        assert_eq!(
+            git(&dir, &["stash", "list", "--format=%H"]).lines().count(),
+            1
+        );
+
+        // A fresh repository gets every branch and both stash entries back.
+        let fresh = tmp.path().join("fresh");
+        std::fs::create_dir_all(&fresh).unwrap();
+        git(&fresh, &["init", "-q", "-b", "trunk"]);
+        let report = restore(&fresh, &bundle).unwrap();
+        assert_eq!(report.created, ["feature", "main"]);
+        assert_eq!(report.stashes, 2);
+        let messages = git(&fresh, &["stash", "list", "--format=%gs"]);
+        assert!(messages.contains("parked"), "{messages}");
+        assert!(
+            messages.contains("uncommitted changes on main"),
+            "{messages}"
+        );
+        assert!(
+            list_namespace(&fresh, RESTORE_NAMESPACE)
+                .unwrap()
+                .is_empty()
+        );
+
+        // Restoring again changes nothing.
+        let again = restore(&fresh, &bundle).unwrap();
+        assert!(again.created.is_empty() && again.fast_forwarded.is_empty());
+        assert_eq!(again.stashes, 0);
+    }
+
+    #[test]
+    fn detached_worktrees_are_snapshotted_and_a_failed_snapshot_fails_the_run() {
+        let tmp = TempDir::new().unwrap();
+        let dir = repo(&tmp);
+        let pinned = tmp.path().join("pinned v1");
+        git(
+            &dir,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                "--detach",
+                pinned.to_str().unwrap(),
+            ],
+        );
+        std::fs::write(pinned.join("a.txt"), "dirty\n").unwrap();
+
+        let snapshot = Snapshot::collect(&dir).unwrap();
+        assert_eq!(snapshot.snapshots, 1);
+        assert!(
+            snapshot.refs.contains_key("wip/detached/pinned-v1"),
+            "{:?}",
+            snapshot.refs
+        );
+
+        // A locked index must not read as a clean worktree.
+        let git_dir = git(&pinned, &["rev-parse", "--absolute-git-dir"]);
+        std::fs::write(Path::new(&git_dir).join("index.lock"), "").unwrap();
+        let err = Snapshot::collect(&dir).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("Could not snapshot uncommitted changes"),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    fn restore_fast_forwards_but_keeps_diverged_branches() {
+        let tmp = TempDir::new().unwrap();
+        let dir = repo(&tmp);
+        git(&dir, &["branch", "behind"]);
+        git(&dir, &["branch", "diverged"]);
+        git(&dir, &["commit", "-q", "--allow-empty", "-m", "two"]);
+        let two = git(&dir, &["rev-parse", "HEAD"]);
+        git(&dir, &["branch", "-f", "behind", &two]);
+        git(&dir, &["branch", "-f", "diverged", &two]);
+
+        let bundle = Destination::Bundle(tmp.path().join("repo.bundle"));
+        write(&dir, &bundle, &Snapshot::collect(&dir).unwrap()).unwrap();
+
+        git(&dir, &["branch", "-f", "behind", "HEAD~1"]);
+        git(&dir, &["checkout", "-q", "-b", "side", "HEAD~1"]);
+        git(&dir, &["commit", "-q", "--allow-empty", "-m", "local"]);
+        git(&dir, &["branch", "-f", "diverged", "side"]);
+
+        let report = restore(&dir, &bundle).unwrap();
+        assert_eq!(report.fast_forwarded, ["behind"]);
+        assert_eq!(report.kept, ["diverged"]);
+        assert_eq!(git(&dir, &["rev-parse", "behind"]), two);
+    }
+}
@@ -0,0 +1,195 @@
+//! Clone manifests: the repos `daft clone-all` brings onto a machine.
+//!
+//! A manifest is a YAML file, usually kept in a dotfiles repo, listing remote
+//! URLs — either bare or with per-repo clone options:
+//!
+//! ```yaml
+//! repos:
+//!   - git@github.com:acme/api.git
+//!   - url: git@github.com:acme/web.git
+//!     layout: sibling
+//!     branch: develop
+//! ```
+//!
+//! A top-level list without the `repos:` key is accepted too. The same
+//! entries can come from a forge org listing instead of a file
+//! ([`crate::forge::org`]).
+//!
+//! Each entry's destination is where `daft clone` would put it
+//! ([`clone_target_dir`](crate::core::repo::clone_target_dir)), so the plan
+//! knows which repos are already present before cloning anything.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::core::remote_url::InsteadOf;
+use crate::core::repo::clone_target_dir_for_effective;
+
+/// One repo to clone.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManifestEntry {
+    pub url: String,
+    /// Layout for this clone; the usual layout resolution when unset.
+    #[serde(default)]
+    pub layout: Option<String>,
+    /// Branch to check out instead of the default branch.
+    #[serde(default)]
+    pub branch: Option<String>,
+}
+
+impl ManifestEntry {
+    pub fn from_url(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            layout: None,
+            branch: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawEntry {
+    Url(String),
+    Full(ManifestEntry),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawManifest {
+    List(Vec<RawEntry>),
+    Keyed { repos: Vec<RawEntry> },
+}
+
+/// Parse a manifest's contents.
+pub fn parse(contents: &str) -> Result<Vec<ManifestEntry>> {
+    let raw: RawManifest = serde_yaml::from_str(contents).context(
+        "expected a list of repo URLs, or a 'repos:' list of URLs or {url, layout, branch} entries",
+    )?;
+    let entries: Vec<ManifestEntry> = match raw {
+        RawManifest::List(list) | RawManifest::Keyed { repos: list } => list
+            .into_iter()
+            .map(|entry| match entry {
+                RawEntry::Url(url) => ManifestEntry::from_url(url),
+                RawEntry::Full(entry) => entry,
+            })
+            .collect(),
+    };
+    if let Some(blank) = entries.iter().position(|e| e.url.trim().is_empty()) {
+        anyhow::bail!("entry {} has an empty url", blank + 1);
+    }
+    Ok(entries)
+}
+
+/// Read and parse the manifest at `path`.
+pub fn load(path: &Path) -> Result<Vec<ManifestEntry>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+    parse(&contents).with_context(|| format!("Invalid manifest {}", path.display()))
+}
+
+/// What `daft clone-all` will do with one entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanAction {
+    /// Clone into `dir`.
+    Clone,
+    /// `dir` already exists.
+    Present,
+    /// An earlier entry already lands in `dir`.
+    Duplicate,
+    /// The URL yields no usable directory name.
+    Invalid(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannedClone {
+    pub entry: ManifestEntry,
+    /// Destination relative to the clone root; empty for [`PlanAction::Invalid`].
+    pub dir: PathBuf,
+    pub action: PlanAction,
+}
+
+/// Work out each entry's destination under `root` with the
+/// `daft.clone.nameTemplate` `template`, and whether it still needs cloning.
+pub fn plan(entries: Vec<ManifestEntry>, root: &Path, template: &str) -> Vec<PlannedClone> {
+    let rules = InsteadOf::load(root);
+    plan_with(entries, template, &rules, |dir| root.join(dir).exists())
+}
+
+fn plan_with(
+    entries: Vec<ManifestEntry>,
+    template: &str,
+    rules: &[InsteadOf],
+    exists: impl Fn(&Path) -> bool,
+) -> Vec<PlannedClone> {
+    let mut seen = std::collections::HashSet::new();
+    entries
+        .into_iter()
+        .map(|entry| {
+            let url = InsteadOf::rewrite(&entry.url, rules, false);
+            let url = url.as_deref().unwrap_or(&entry.url);
+            let (dir, action) = match clone_target_dir_for_effective(url, template) {
+                Err(e) => (PathBuf::new(), PlanAction::Invalid(format!("{e:#}"))),
+                Ok(dir) if !seen.insert(dir.clone()) => (dir, PlanAction::Duplicate),
+                Ok(dir) if exists(&dir) => (dir, PlanAction::Present),
+                Ok(dir) => (dir, PlanAction::Clone),
+            };
+            PlannedClone { entry, dir, action }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_urls_and_full_entries() {
+        let entries = parse(
+            "repos:\n  - git@github.com:acme/api.git\n  - url: git@github.com:acme/web.git\n    layout: sibling\n    branch: develop\n",
+        )
+        .unwrap();
+        assert_eq!(
+            entries[0],
+            ManifestEntry::from_url("git@github.com:acme/api.git")
+        );
+        assert_eq!(entries[1].layout.as_deref(), Some("sibling"));
+        assert_eq!(entries[1].branch.as_deref(), Some("develop"));
+
+        let entries = parse("- https://example.com/a.git\n- https://example.com/b.git\n").unwrap();
+        assert_eq!(entries.len(), 2);
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_blank_urls() {
+        assert!(parse("repos:\n  - url: a\n    brnach: main\n").is_err());
+        assert!(parse("repos:\n  - ''\n").is_err());
+        assert!(parse("name: nope\n").is_err());
+    }
+
+    #[test]
+    fn plan_skips_present_and_duplicate_destinations() {
+        let entries = vec![
+            ManifestEntry::from_url("git@github.com:acme/api.git"),
+            ManifestEntry::from_url("git@github.com:globex/api.git"),
+            ManifestEntry::from_url("git@github.com:acme/web.git"),
+            ManifestEntry::from_url("https://github.com/"),
+        ];
+        let planned = plan_with(entries.clone(), "{repo}", &[], |dir| {
+            dir == Path::new("web")
+        });
+        let actions: Vec<_> = planned.iter().map(|p| p.action.clone()).collect();
+        assert_eq!(actions[0], PlanAction::Clone);
+        assert_eq!(actions[1], PlanAction::Duplicate);
+        assert_eq!(actions[2], PlanAction::Present);
+        assert!(matches!(actions[3], PlanAction::Invalid(_)));
+
+        // Owner-qualified names keep same-named repos apart.
+        let planned = plan_with(entries, "{org}-{repo}", &[], |_| false);
+        assert_eq!(planned[1].dir, PathBuf::from("globex-api"));
+        assert_eq!(planned[1].action, PlanAction::Clone);
+    }
+}
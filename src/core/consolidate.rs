@@ -0,0 +1,824 @@
+//! Folding sibling clones into one repository, behind `daft consolidate`.
+//!
+//! A directory often holds several full clones of the same repository
+//! (`app`, `app-2`, `app-hotfix`), each with its own copy of the history.
+//! Consolidating keeps the clone with the largest object store as the
+//! *primary* and turns every other clone into a linked worktree of it:
+//!
+//! 1. the clone's branches and tags are fetched into the primary (missing
+//!    refs are created, refs the clone is ahead on are fast-forwarded);
+//! 2. its uncommitted changes are captured with `git stash create` and
+//!    fetched too, so every blob its index refers to exists in the primary;
+//! 3. its Git LFS objects are copied into the primary's `lfs/objects`;
+//! 4. a `worktrees/<name>` admin directory is written in the primary, with
+//!    the clone's `HEAD` and `index`, and the clone's `.git` directory is
+//!    swapped for a `gitdir:` file pointing at it.
+//!
+//! The working tree is never touched, so the checked-out branch, staged and
+//! unstaged changes, and untracked files all stay as they were. The swap is
+//! verified by comparing `git status` before and after, and rolled back if
+//! they differ. The old `.git` directory is parked beside the clone rather
+//! than deleted — it still holds the branch reflogs and anything else the
+//! primary didn't take — and only [`discard_parked`] removes it, once the
+//! user has confirmed.
+//!
+//! A clone is skipped, with the reasons reported, when consolidating it would
+//! lose something: stash entries, an operation in progress, worktrees or
+//! submodules of its own, refs other than branches, tags and remote-tracking
+//! refs (notes, replace refs, custom namespaces), a tag or branch that
+//! differs from the primary's, hooks, `info/exclude` or `info/attributes`
+//! patterns, a sparse checkout, or repository-local settings the primary
+//! doesn't share. The clone's remotes and branch upstreams are the only
+//! settings carried over.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context, Result, bail};
+
+use crate::catalog::normalize::normalize_url;
+use crate::core::repo::git_stdout;
+use crate::core::worktree::porcelain::parse_worktree_list_porcelain;
+use crate::utils::git_command_at;
+
+/// Where a clone's refs land in the primary before they are applied.
+/// Deleted again once the clone is absorbed.
+const IMPORT_NAMESPACE: &str = "refs/daft-consolidate/";
+
+/// Clones of one repository found under the scanned directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloneGroup {
+    /// The normalized remote URL, or `root <sha>` for a clone without remotes.
+    pub identity: String,
+    /// The clone the others are folded into.
+    pub primary: PathBuf,
+    /// The clones to turn into worktrees of `primary`, sorted by path.
+    pub others: Vec<PathBuf>,
+}
+
+/// What happened to one clone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The clone is now a worktree of the primary. Its old `.git` directory
+    /// is kept at `parked` until [`discard_parked`] removes it.
+    Absorbed { parked: PathBuf },
+    /// The clone was left alone, for these reasons.
+    Skipped(Vec<String>),
+}
+
+/// Find every repository with two or more clones directly under `dir`.
+/// `sizes` gives the on-disk size of a clone's `.git` directory; the largest
+/// becomes the primary (ties go to the first path in sort order).
+pub fn discover(dir: &Path, sizes: impl Fn(&[PathBuf]) -> Vec<u64>) -> Result<Vec<CloneGroup>> {
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+    let mut by_identity: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.join(".git").is_dir() || !is_non_bare(&path) {
+            continue;
+        }
+        let path = path.canonicalize().unwrap_or(path);
+        if let Some(identity) = identity(&path) {
+            by_identity.entry(identity).or_default().push(path);
+        }
+    }
+
+    let mut groups = Vec::new();
+    for (identity, mut clones) in by_identity {
+        if clones.len() < 2 {
+            continue;
+        }
+        clones.sort();
+        let git_dirs: Vec<PathBuf> = clones.iter().map(|c| c.join(".git")).collect();
+        let sizes = sizes(&git_dirs);
+        let primary_idx = (0..clones.len())
+            .max_by_key(|&i| (sizes.get(i).copied().unwrap_or(0), std::cmp::Reverse(i)))
+            .unwrap_or(0);
+        let primary = clones.remove(primary_idx);
+        groups.push(CloneGroup {
+            identity,
+            primary,
+            others: clones,
+        });
+    }
+    Ok(groups)
+}
+
+fn is_non_bare(dir: &Path) -> bool {
+    git_stdout(dir, &["rev-parse", "--is-bare-repository"]).is_ok_and(|out| out.trim() == "false")
+}
+
+/// What makes two clones "the same repository": the URL of `origin` (else the
+/// first remote), or the root commit when there is no remote.
+fn identity(dir: &Path) -> Option<String> {
+    let remotes = git_stdout(dir, &["remote"]).ok()?;
+    let remote = remotes
+        .lines()
+        .find(|r| *r == "origin")
+        .or_else(|| remotes.lines().next());
+    if let Some(remote) = remote
+        && let Ok(url) = git_stdout(dir, &["remote", "get-url", remote])
+    {
+        let normalized = normalize_url(url.trim());
+        if !normalized.is_empty() {
+            return Some(normalized);
+        }
+    }
+    let roots = git_stdout(dir, &["rev-list", "--max-parents=0", "HEAD"]).ok()?;
+    roots.lines().min().map(|root| format!("root {root}"))
+}
+
+/// Why `clone` can't be folded into `primary` without losing work. Empty
+/// when it can. Reads both repositories and changes neither.
+pub fn blockers(primary: &Path, clone: &Path) -> Result<Vec<String>> {
+    let git_dir = clone.join(".git");
+    let mut reasons = Vec::new();
+
+    if git_stdout(clone, &["rev-parse", "--verify", "-q", "HEAD"]).is_err() {
+        reasons.push("has no commits".to_string());
+        return Ok(reasons);
+    }
+    let stashes = git_stdout(clone, &["stash", "list", "--format=%H"])?
+        .lines()
+        .count();
+    if stashes > 0 {
+        reasons.push(format!(
+            "has {stashes} stash {}",
+            if stashes == 1 { "entry" } else { "entries" }
+        ));
+    }
+    for (marker, operation) in [
+        ("rebase-merge", "a rebase"),
+        ("rebase-apply", "a rebase"),
+        ("MERGE_HEAD", "a merge"),
+        ("CHERRY_PICK_HEAD", "a cherry-pick"),
+        ("REVERT_HEAD", "a revert"),
+        ("BISECT_LOG", "a bisect"),
+    ] {
+        if git_dir.join(marker).exists() {
+            reasons.push(format!("is in the middle of {operation}"));
+            break;
+        }
+    }
+    if has_entries(&git_dir.join("worktrees")) {
+        reasons.push("has worktrees of its own".to_string());
+    }
+    if has_entries(&git_dir.join("modules")) {
+        reasons.push("has submodules".to_string());
+    }
+    if has_own_hooks(&git_dir.join("hooks")) {
+        reasons.push("has hooks in .git/hooks".to_string());
+    }
+    if has_own_excludes(&git_dir.join("info").join("exclude")) {
+        reasons.push("has patterns in .git/info/exclude".to_string());
+    }
+    if has_own_excludes(&git_dir.join("info").join("attributes")) {
+        reasons.push("has patterns in .git/info/attributes".to_string());
+    }
+    if git_dir.join("info").join("sparse-checkout").exists() {
+        reasons.push("has a sparse checkout".to_string());
+    }
+    let settings = uncarried_settings(primary, clone)?;
+    if !settings.is_empty() {
+        let names: Vec<&str> = settings.iter().map(String::as_str).collect();
+        reasons.push(format!(
+            "has repository settings that would not be carried over ({})",
+            summarize(&names)
+        ));
+    }
+
+    let clone_refs = list_refs(clone, "refs/")?;
+    let foreign: Vec<&str> = clone_refs
+        .keys()
+        .map(String::as_str)
+        .filter(|name| {
+            *name != "refs/stash"
+                && !["refs/heads/", "refs/tags/", "refs/remotes/"]
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+        })
+        .collect();
+    if !foreign.is_empty() {
+        reasons.push(format!(
+            "has refs that would not be carried over ({})",
+            summarize(&foreign)
+        ));
+    }
+    let primary_tags = list_refs(primary, "refs/tags/")?;
+    let conflicting: Vec<&str> = clone_refs
+        .iter()
+        .filter_map(|(name, tip)| {
+            let theirs = primary_tags.get(name)?;
+            (theirs != tip).then(|| name.trim_start_matches("refs/tags/"))
+        })
+        .collect();
+    match conflicting.as_slice() {
+        [] => {}
+        [tag] => reasons.push(format!("tag '{tag}' points elsewhere in the primary")),
+        tags => reasons.push(format!(
+            "tags {} point elsewhere in the primary",
+            summarize(tags)
+        )),
+    }
+
+    let current = current_branch(clone)?;
+    let checked_out = checked_out_branches(primary)?;
+    if let Some(branch) = &current
+        && let Some(path) = checked_out.get(branch)
+    {
+        reasons.push(format!(
+            "'{branch}' is already checked out at {}",
+            path.display()
+        ));
+    }
+
+    let primary_heads = heads(primary)?;
+    for (branch, tip) in heads(clone)? {
+        let Some(primary_tip) = primary_heads.get(&branch) else {
+            continue;
+        };
+        if *primary_tip == tip {
+            continue;
+        }
+        let clone_ahead = has_object(clone, primary_tip) && is_ancestor(clone, primary_tip, &tip);
+        let clone_behind = has_object(primary, &tip) && is_ancestor(primary, &tip, primary_tip);
+        if clone_ahead {
+            if let Some(path) = checked_out.get(&branch)
+                && current.as_ref() != Some(&branch)
+            {
+                reasons.push(format!(
+                    "'{branch}' is ahead of the copy checked out at {}",
+                    path.display()
+                ));
+            }
+        } else if clone_behind {
+            if current.as_ref() == Some(&branch) {
+                reasons.push(format!("checked-out '{branch}' is behind the primary's"));
+            }
+        } else {
+            reasons.push(format!("'{branch}' has diverged from the primary's"));
+        }
+    }
+    Ok(reasons)
+}
+
+fn has_entries(dir: &Path) -> bool {
+    std::fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some())
+}
+
+/// Whether `hooks_dir` holds anything but the `*.sample` files `git init`
+/// writes. The primary's hooks run for every worktree, so the clone's would
+/// silently stop.
+fn has_own_hooks(hooks_dir: &Path) -> bool {
+    std::fs::read_dir(hooks_dir).is_ok_and(|entries| {
+        entries
+            .flatten()
+            .any(|e| !e.file_name().to_string_lossy().ends_with(".sample"))
+    })
+}
+
+/// Whether an `info/exclude` (or `info/attributes`) file has patterns beyond
+/// comments and daft's own managed block, which the primary already carries.
+fn has_own_excludes(exclude: &Path) -> bool {
+    use crate::core::git_exclude::{BLOCK_BEGIN, BLOCK_END};
+    let Ok(content) = std::fs::read_to_string(exclude) else {
+        return false;
+    };
+    let mut in_block = false;
+    content.lines().map(str::trim).any(|line| {
+        if line == BLOCK_BEGIN {
+            in_block = true;
+        } else if line == BLOCK_END {
+            in_block = false;
+        } else if !in_block && !line.is_empty() && !line.starts_with('#') {
+            return true;
+        }
+        false
+    })
+}
+
+/// Keys in the clone's `.git/config` that would be lost: set differently
+/// than in the primary's, and not a remote or branch upstream that
+/// [`copy_remote_config`] adds.
+fn uncarried_settings(primary: &Path, clone: &Path) -> Result<Vec<String>> {
+    let ours = local_config(primary)?;
+    let theirs = local_config(clone)?;
+    let carried = |key: &str, values: &[String]| {
+        if let Some(rest) = key.strip_prefix("remote.")
+            && let Some((remote, var)) = rest.rsplit_once('.')
+            && !ours.contains_key(&format!("remote.{remote}.url"))
+        {
+            return var == "url"
+                || (var == "fetch"
+                    && values == [format!("+refs/heads/*:refs/remotes/{remote}/*")]);
+        }
+        key.strip_prefix("branch.")
+            .and_then(|rest| rest.rsplit_once('.'))
+            .is_some_and(|(_, var)| (var == "remote" || var == "merge") && !ours.contains_key(key))
+    };
+    Ok(theirs
+        .iter()
+        .filter(|(key, values)| ours.get(*key) != Some(*values) && !carried(key, values))
+        .map(|(key, _)| key.clone())
+        .collect())
+}
+
+/// `git config --local --list`, as key → values.
+fn local_config(dir: &Path) -> Result<BTreeMap<String, Vec<String>>> {
+    let out = git_stdout(dir, &["config", "--local", "--list", "--null"])?;
+    let mut config: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for entry in out.split('\0').filter(|e| !e.is_empty()) {
+        let (key, value) = entry.split_once('\n').unwrap_or((entry, ""));
+        config
+            .entry(key.to_string())
+            .or_default()
+            .push(value.to_string());
+    }
+    Ok(config)
+}
+
+/// `a, b, c and 2 more`.
+fn summarize(names: &[&str]) -> String {
+    const SHOWN: usize = 3;
+    let mut out = names[..names.len().min(SHOWN)].join(", ");
+    if names.len() > SHOWN {
+        out.push_str(&format!(" and {} more", names.len() - SHOWN));
+    }
+    out
+}
+
+/// Turn `clone` into a linked worktree of `primary`, unless [`blockers`]
+/// finds a reason not to.
+pub fn absorb(primary: &Path, clone: &Path) -> Result<Outcome> {
+    let reasons = blockers(primary, clone)?;
+    if !reasons.is_empty() {
+        return Ok(Outcome::Skipped(reasons));
+    }
+    let parked = parked_path(clone);
+    if parked.exists() {
+        bail!(
+            "{} is left from an earlier run; remove it first",
+            parked.display()
+        );
+    }
+    let status_before = git_stdout(clone, &["status", "--porcelain=v1", "-z"])?;
+
+    import_refs(primary, clone)?;
+    copy_remote_config(primary, clone)?;
+    copy_missing_files(
+        &clone.join(".git").join("lfs").join("objects"),
+        &primary.join(".git").join("lfs").join("objects"),
+    )
+    .context("Failed to copy the clone's Git LFS objects")?;
+    attach_worktree(primary, clone, &parked, &status_before)?;
+    Ok(Outcome::Absorbed { parked })
+}
+
+/// Delete a converted clone's parked `.git` directory.
+pub fn discard_parked(parked: &Path) -> Result<()> {
+    std::fs::remove_dir_all(parked)
+        .with_context(|| format!("Failed to remove {}", parked.display()))
+}
+
+/// Where the clone's old `.git` directory is kept: beside the clone, not
+/// inside it, where it would show up as untracked.
+fn parked_path(clone: &Path) -> PathBuf {
+    let clone_name = clone
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    clone
+        .parent()
+        .unwrap_or(clone)
+        .join(format!(".{clone_name}.git-consolidate"))
+}
+
+/// Fetch the clone's branches, tags and uncommitted changes into the primary
+/// and create or fast-forward the primary's refs from them.
+fn import_refs(primary: &Path, clone: &Path) -> Result<()> {
+    let wip = git_stdout(clone, &["stash", "create"])?.trim().to_string();
+    let wip_ref = format!("{IMPORT_NAMESPACE}wip");
+    if !wip.is_empty() {
+        git_stdout(clone, &["update-ref", &wip_ref, &wip])?;
+    }
+
+    let source = clone.to_string_lossy();
+    let heads_spec = format!("+refs/heads/*:{IMPORT_NAMESPACE}heads/*");
+    let tags_spec = format!("+refs/tags/*:{IMPORT_NAMESPACE}tags/*");
+    let wip_spec = format!("+{wip_ref}:{wip_ref}");
+    let mut fetch = vec!["fetch", "-q", "--no-tags", &source, &heads_spec, &tags_spec];
+    if !wip.is_empty() {
+        fetch.push(&wip_spec);
+    }
+    let fetched = git_stdout(primary, &fetch);
+    if !wip.is_empty() {
+        git_stdout(clone, &["update-ref", "-d", &wip_ref])?;
+    }
+    if let Err(e) = fetched {
+        clear_namespace(primary)?;
+        return Err(e);
+    }
+
+    let imported = list_refs(primary, IMPORT_NAMESPACE)?;
+    let existing = list_refs(primary, "refs/")?;
+    let mut commands = Vec::new();
+    for (name, tip) in &imported {
+        let target = if let Some(branch) = name.strip_prefix(&format!("{IMPORT_NAMESPACE}heads/")) {
+            format!("refs/heads/{branch}")
+        } else if let Some(tag) = name.strip_prefix(&format!("{IMPORT_NAMESPACE}tags/")) {
+            format!("refs/tags/{tag}")
+        } else {
+            continue;
+        };
+        match existing.get(&target) {
+            None => commands.push(format!("create {target} {tip}")),
+            Some(old) if old != tip && target.starts_with("refs/heads/") => {
+                if is_ancestor(primary, old, tip) {
+                    commands.push(format!("update {target} {tip} {old}"));
+                }
+            }
+            // `blockers` refused clones whose tags differ from the primary's
+            // and whose branches can't fast-forward, so nothing is dropped.
+            Some(_) => {}
+        }
+    }
+    let applied = update_refs(primary, &commands);
+    clear_namespace(primary)?;
+    applied
+}
+
+/// Copy every file under `from` that `to` doesn't have yet. Git LFS objects
+/// are named by their hash, so an existing file is the same object.
+fn copy_missing_files(from: &Path, to: &Path) -> Result<()> {
+    let Ok(entries) = std::fs::read_dir(from) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_missing_files(&entry.path(), &target)?;
+        } else if !target.exists() {
+            std::fs::create_dir_all(to)?;
+            std::fs::copy(entry.path(), &target)
+                .with_context(|| format!("Failed to copy {}", entry.path().display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Add the clone's remotes the primary lacks, and the upstream of each
+/// branch the primary has no upstream for.
+fn copy_remote_config(primary: &Path, clone: &Path) -> Result<()> {
+    let primary_remotes: HashSet<String> = git_stdout(primary, &["remote"])?
+        .lines()
+        .map(str::to_string)
+        .collect();
+    for remote in git_stdout(clone, &["remote"])?.lines() {
+        if primary_remotes.contains(remote) {
+            continue;
+        }
+        let url = git_stdout(clone, &["remote", "get-url", remote])?;
+        git_stdout(primary, &["remote", "add", remote, url.trim()])?;
+    }
+
+    for branch in heads(clone)?.keys() {
+        for key in ["remote", "merge"] {
+            let name = format!("branch.{branch}.{key}");
+            if git_stdout(primary, &["config", "--get", &name]).is_ok() {
+                continue;
+            }
+            if let Ok(value) = git_stdout(clone, &["config", "--get", &name]) {
+                git_stdout(primary, &["config", &name, value.trim()])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write the worktree admin directory and swap the clone's `.git` directory
+/// for a `gitdir:` file, moving the old one to `parked`. Rolls back when
+/// `git status` no longer matches `status_before`.
+fn attach_worktree(primary: &Path, clone: &Path, parked: &Path, status_before: &str) -> Result<()> {
+    let common_dir = primary.join(".git");
+    let clone_git = clone.join(".git");
+    let admin = unique_admin_dir(&common_dir, clone)?;
+    std::fs::create_dir_all(admin.join("logs"))
+        .with_context(|| format!("Failed to create {}", admin.display()))?;
+    std::fs::write(admin.join("gitdir"), format!("{}\n", clone_git.display()))?;
+    std::fs::write(admin.join("commondir"), "../..\n")?;
+    std::fs::copy(clone_git.join("HEAD"), admin.join("HEAD"))
+        .context("Failed to copy the clone's HEAD")?;
+    for (from, to) in [("index", "index"), ("logs/HEAD", "logs/HEAD")] {
+        if clone_git.join(from).is_file() {
+            std::fs::copy(clone_git.join(from), admin.join(to))
+                .with_context(|| format!("Failed to copy the clone's {from}"))?;
+        }
+    }
+
+    if let Err(e) = std::fs::rename(&clone_git, parked) {
+        let _ = std::fs::remove_dir_all(&admin);
+        return Err(e).with_context(|| format!("Failed to move {} aside", clone_git.display()));
+    }
+    let attached = std::fs::write(&clone_git, format!("gitdir: {}\n", admin.display()))
+        .map_err(anyhow::Error::from)
+        .and_then(|()| git_stdout(clone, &["status", "--porcelain=v1", "-z"]));
+
+    match attached {
+        Ok(status) if status == status_before => Ok(()),
+        other => {
+            let _ = std::fs::remove_file(&clone_git);
+            std::fs::rename(parked, &clone_git)
+                .with_context(|| format!("Failed to restore {}", clone_git.display()))?;
+            let _ = std::fs::remove_dir_all(&admin);
+            match other {
+                Err(e) => Err(e.context("the clone did not check out as a worktree")),
+                Ok(_) => bail!("the worktree's status differs from the clone's; left as a clone"),
+            }
+        }
+    }
+}
+
+/// `<common-dir>/worktrees/<clone dir name>`, suffixed until it is free.
+fn unique_admin_dir(common_dir: &Path, clone: &Path) -> Result<PathBuf> {
+    let base: String = clone
+        .file_name()
+        .context("clone path has no name")?
+        .to_string_lossy()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "-_.".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let worktrees = common_dir.join("worktrees");
+    let mut candidate = worktrees.join(&base);
+    let mut n = 1;
+    while candidate.exists() {
+        candidate = worktrees.join(format!("{base}{n}"));
+        n += 1;
+    }
+    Ok(candidate)
+}
+
+fn current_branch(dir: &Path) -> Result<Option<String>> {
+    let head = git_stdout(dir, &["symbolic-ref", "-q", "HEAD"]).unwrap_or_default();
+    Ok(head.trim().strip_prefix("refs/heads/").map(str::to_string))
+}
+
+/// Branch → path of every worktree of `dir`'s repository with a branch out.
+fn checked_out_branches(dir: &Path) -> Result<BTreeMap<String, PathBuf>> {
+    let porcelain = git_stdout(dir, &["worktree", "list", "--porcelain"])?;
+    Ok(parse_worktree_list_porcelain(&porcelain)
+        .into_iter()
+        .filter_map(|entry| entry.branch.map(|branch| (branch, entry.path)))
+        .collect())
+}
+
+fn heads(dir: &Path) -> Result<BTreeMap<String, String>> {
+    Ok(list_refs(dir, "refs/heads/")?
+        .into_iter()
+        .filter_map(|(name, tip)| {
+            name.strip_prefix("refs/heads/")
+                .map(|branch| (branch.to_string(), tip))
+        })
+        .collect())
+}
+
+fn list_refs(dir: &Path, prefix: &str) -> Result<BTreeMap<String, String>> {
+    let out = git_stdout(
+        dir,
+        &["for-each-ref", "--format=%(objectname) %(refname)", prefix],
+    )?;
+    Ok(out
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .map(|(tip, name)| (name.to_string(), tip.to_string()))
+        .collect())
+}
+
+fn clear_namespace(dir: &Path) -> Result<()> {
+    let commands: Vec<String> = list_refs(dir, IMPORT_NAMESPACE)?
+        .keys()
+        .map(|name| format!("delete {name}"))
+        .collect();
+    update_refs(dir, &commands)
+}
+
+fn update_refs(dir: &Path, commands: &[String]) -> Result<()> {
+    if commands.is_empty() {
+        return Ok(());
+    }
+    let mut child = git_command_at(dir)
+        .args(["update-ref", "--stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run git update-ref")?;
+    {
+        let mut stdin = child.stdin.take().context("git update-ref has no stdin")?;
+        for command in commands {
+            writeln!(stdin, "{command}")?;
+        }
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "git update-ref failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn has_object(dir: &Path, sha: &str) -> bool {
+    git_command_at(dir)
+        .args(["cat-file", "-e", &format!("{sha}^{{commit}}")])
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+fn is_ancestor(dir: &Path, ancestor: &str, descendant: &str) -> bool {
+    git_command_at(dir)
+        .args(["merge-base", "--is-ancestor", ancestor, descendant])
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        git_stdout(dir, args).unwrap().trim().to_string()
+    }
+
+    /// An upstream with one commit and two clones of it under `<tmp>/work`.
+    fn clones(tmp: &TempDir) -> (PathBuf, PathBuf) {
+        let upstream = tmp.path().join("upstream");
+        std::fs::create_dir_all(&upstream).unwrap();
+        git(&upstream, &["init", "-q", "-b", "main"]);
+        git(&upstream, &["config", "user.email", "t@example.com"]);
+        git(&upstream, &["config", "user.name", "t"]);
+        std::fs::write(upstream.join("a.txt"), "one\n").unwrap();
+        git(&upstream, &["add", "a.txt"]);
+        git(&upstream, &["commit", "-q", "-m", "one"]);
+
+        let work = tmp.path().join("work");
+        std::fs::create_dir_all(&work).unwrap();
+        let url = upstream.to_string_lossy().into_owned();
+        for name in ["app", "app-2"] {
+            git(&work, &["clone", "-q", &url, name]);
+            let clone = work.join(name);
+            git(&clone, &["config", "user.email", "t@example.com"]);
+            git(&clone, &["config", "user.name", "t"]);
+        }
+        (
+            work.join("app").canonicalize().unwrap(),
+            work.join("app-2").canonicalize().unwrap(),
+        )
+    }
+
+    #[test]
+    fn absorb_keeps_branch_and_uncommitted_changes() {
+        let tmp = TempDir::new().unwrap();
+        let (app, app2) = clones(&tmp);
+        git(&app2, &["checkout", "-q", "-b", "feature"]);
+        git(
+            &app2,
+            &["commit", "-q", "--allow-empty", "-m", "feature work"],
+        );
+        std::fs::write(app2.join("a.txt"), "staged\n").unwrap();
+        git(&app2, &["add", "a.txt"]);
+        std::fs::write(app2.join("a.txt"), "unstaged\n").unwrap();
+        std::fs::write(app2.join("new.txt"), "untracked\n").unwrap();
+        let status = git(&app2, &["status", "--porcelain"]);
+        let lfs_object = ".git/lfs/objects/ab/cd/abcd1234";
+        std::fs::create_dir_all(app2.join(lfs_object).parent().unwrap()).unwrap();
+        std::fs::write(app2.join(lfs_object), "large\n").unwrap();
+
+        let groups = discover(tmp.path().join("work").as_path(), |dirs| {
+            dirs.iter()
+                .map(|d| u64::from(d.ends_with("app/.git")))
+                .collect()
+        })
+        .unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].primary, app);
+        assert_eq!(groups[0].others, std::slice::from_ref(&app2));
+
+        let Outcome::Absorbed { parked } = absorb(&app, &app2).unwrap() else {
+            panic!("expected the clone to be absorbed");
+        };
+        assert!(app2.join(".git").is_file());
+        // The old .git stays until discarded, reflogs and all.
+        assert!(parked.join("logs/refs/heads/feature").is_file());
+        assert_eq!(
+            PathBuf::from(git(&app2, &["rev-parse", "--git-common-dir"]))
+                .canonicalize()
+                .unwrap(),
+            app.join(".git")
+        );
+        assert_eq!(git(&app2, &["symbolic-ref", "--short", "HEAD"]), "feature");
+        assert_eq!(git(&app2, &["status", "--porcelain"]), status);
+        assert_eq!(git(&app2, &["diff", "--cached", "--name-only"]), "a.txt");
+        assert_eq!(
+            git(&app, &["log", "-1", "--format=%s", "feature"]),
+            "feature work"
+        );
+        assert!(list_refs(&app, IMPORT_NAMESPACE).unwrap().is_empty());
+        assert_eq!(
+            std::fs::read_to_string(app.join(lfs_object)).unwrap(),
+            "large\n"
+        );
+
+        discard_parked(&parked).unwrap();
+        assert!(!parked.exists());
+        assert_eq!(git(&app2, &["status", "--porcelain"]), status);
+    }
+
+    #[test]
+    fn refs_and_repo_files_that_would_not_carry_over_block() {
+        let tmp = TempDir::new().unwrap();
+        let (app, app2) = clones(&tmp);
+        git(&app2, &["checkout", "-q", "-b", "feature"]);
+        git(&app2, &["notes", "add", "-m", "reviewed", "HEAD"]);
+        git(&app, &["tag", "v1"]);
+        git(&app2, &["commit", "-q", "--allow-empty", "-m", "later"]);
+        git(&app2, &["tag", "v1"]);
+        std::fs::write(app2.join(".git/hooks/pre-commit"), "#!/bin/sh\n").unwrap();
+        std::fs::write(app2.join(".git/info/exclude"), "# comment\nscratch/\n").unwrap();
+        std::fs::write(app2.join(".git/info/attributes"), "*.bin binary\n").unwrap();
+        std::fs::write(app2.join(".git/info/sparse-checkout"), "/*\n").unwrap();
+        git(&app2, &["config", "core.hooksPath", "tools/hooks"]);
+        git(&app2, &["config", "user.email", "other@example.com"]);
+
+        let Outcome::Skipped(reasons) = absorb(&app, &app2).unwrap() else {
+            panic!("expected the clone to be skipped");
+        };
+        assert_eq!(
+            reasons,
+            [
+                "has hooks in .git/hooks",
+                "has patterns in .git/info/exclude",
+                "has patterns in .git/info/attributes",
+                "has a sparse checkout",
+                "has repository settings that would not be carried over (core.hookspath, user.email)",
+                "has refs that would not be carried over (refs/notes/commits)",
+                "tag 'v1' points elsewhere in the primary",
+            ]
+        );
+        assert!(app2.join(".git").is_dir());
+        assert!(list_refs(&app, "refs/notes/").unwrap().is_empty());
+    }
+
+    #[test]
+    fn daft_exclude_block_does_not_block() {
+        use crate::core::git_exclude::{BLOCK_BEGIN, BLOCK_END};
+        let tmp = TempDir::new().unwrap();
+        let exclude = tmp.path().join("exclude");
+        std::fs::write(
+            &exclude,
+            format!("# git ls-files --others\n\n{BLOCK_BEGIN}\n/daft.local.yml\n{BLOCK_END}\n"),
+        )
+        .unwrap();
+        assert!(!has_own_excludes(&exclude));
+    }
+
+    #[test]
+    fn clones_that_would_lose_work_are_skipped() {
+        let tmp = TempDir::new().unwrap();
+        let (app, app2) = clones(&tmp);
+        // Same branch checked out in both.
+        assert!(blockers(&app, &app2).unwrap()[0].contains("'main' is already checked out"));
+
+        git(&app2, &["checkout", "-q", "-b", "topic"]);
+        std::fs::write(app2.join("a.txt"), "parked\n").unwrap();
+        git(&app2, &["stash", "-q"]);
+        git(&app, &["branch", "topic"]);
+        git(&app, &["checkout", "-q", "topic"]);
+        git(&app, &["commit", "-q", "--allow-empty", "-m", "primary"]);
+        git(&app2, &["commit", "-q", "--allow-empty", "-m", "clone"]);
+        git(&app, &["checkout", "-q", "main"]);
+
+        let Outcome::Skipped(reasons) = absorb(&app, &app2).unwrap() else {
+            panic!("expected the clone to be skipped");
+        };
+        assert_eq!(
+            reasons,
+            [
+                "has 1 stash entry",
+                "'topic' has diverged from the primary's"
+            ]
+        );
+        assert!(app2.join(".git").is_dir());
+    }
+}
@@ -0,0 +1,205 @@
+//! The daft-managed block in the repository's `.git/info/exclude`.
+//!
+//! daft puts files into worktrees that belong to the clone, not the project —
+//! today the personal `daft.local.yml` overlay (and its aliases), which
+//! visitor propagation copies into every new worktree. Left alone they show
+//! up as untracked noise in `git status`. Rather than touch the tracked
+//! `.gitignore` (a team file), daft maintains one marked block in the local
+//! exclude file, which git reads for every worktree of the repo:
+//!
+//! ```text
+//! # BEGIN daft (managed; changes inside this block are overwritten)
+//! /daft.local.yml
+//! ...
+//! # END daft
+//! ```
+//!
+//! The block is rewritten in place when its contents fall behind
+//! [`PATTERNS`]; lines outside it are never touched. Worktree creation and
+//! clone refresh it best-effort, and `daft doctor` reports (and with `--fix`
+//! repairs) a block that is missing or stale.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use crate::utils::git_command_at;
+
+/// First line of the managed block.
+pub const BLOCK_BEGIN: &str = "# BEGIN daft (managed; changes inside this block are overwritten)";
+
+/// Last line of the managed block.
+pub const BLOCK_END: &str = "# END daft";
+
+/// Files daft writes into worktree roots that must stay out of `git status`.
+pub const PATTERNS: &[&str] = &[
+    "/daft.local.yml",
+    "/daft.local.yaml",
+    "/.daft.local.yml",
+    "/.daft.local.yaml",
+    "/daft-local.yml",
+    "/daft-local.yaml",
+    "/.daft-local.yml",
+    "/.daft-local.yaml",
+];
+
+/// State of the managed block in an exclude file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockStatus {
+    /// The block is present and lists exactly [`PATTERNS`].
+    Current,
+    /// The block is present but its contents differ from [`PATTERNS`].
+    Stale,
+    /// There is no block (or the exclude file does not exist).
+    Missing,
+}
+
+/// Resolve the repository's local exclude file (`.git/info/exclude`) for the
+/// repo containing `dir`. `git rev-parse --git-path` resolves the gitlink
+/// indirection of linked worktrees to the shared common dir. The returned
+/// path can be relative to the `-C` dir, so join it onto `dir` (mirrors
+/// `resolve_common_dir_cli` in remove_repo.rs).
+pub fn exclude_path(dir: &Path) -> Result<PathBuf> {
+    let out = git_command_at(dir)
+        .args(["rev-parse", "--git-path", "info/exclude"])
+        .stderr(Stdio::null())
+        .output()
+        .context("Failed to run `git rev-parse --git-path info/exclude`")?;
+    if !out.status.success() {
+        anyhow::bail!("{} is not inside a git repository", dir.display());
+    }
+    let raw = String::from_utf8(out.stdout)
+        .context("git rev-parse output is not UTF-8")?
+        .trim()
+        .to_string();
+    let p = PathBuf::from(&raw);
+    Ok(if p.is_absolute() { p } else { dir.join(p) })
+}
+
+/// Status of the managed block for the repo containing `dir`.
+pub fn status(dir: &Path) -> Result<BlockStatus> {
+    let path = exclude_path(dir)?;
+    Ok(block_status(&fs::read_to_string(&path).unwrap_or_default()))
+}
+
+/// Write or refresh the managed block for the repo containing `dir`.
+///
+/// Returns `true` when the exclude file was changed. An up-to-date block
+/// leaves the file untouched.
+pub fn ensure(dir: &Path) -> Result<bool> {
+    let path = exclude_path(dir)?;
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    if block_status(&existing) == BlockStatus::Current {
+        return Ok(false);
+    }
+    crate::state_file::write_atomic(&path, with_block(&existing).as_bytes())?;
+    Ok(true)
+}
+
+/// [`ensure`] for worktree-creating paths: failures are logged, never
+/// surfaced — a missing exclude entry is cosmetic, a failed checkout is not.
+pub fn ensure_best_effort(dir: &Path) {
+    if let Err(e) = ensure(dir) {
+        crate::log_debug!("could not update the daft block in info/exclude: {e:#}");
+    }
+}
+
+fn block_status(content: &str) -> BlockStatus {
+    match find_block(content) {
+        None => BlockStatus::Missing,
+        Some((begin, end)) => {
+            let lines: Vec<&str> = content.lines().collect();
+            let body: Vec<&str> = lines[begin + 1..end]
+                .iter()
+                .map(|l| l.trim())
+                .filter(|l| !l.is_empty())
+                .collect();
+            if body == PATTERNS {
+                BlockStatus::Current
+            } else {
+                BlockStatus::Stale
+            }
+        }
+    }
+}
+
+/// `content` with the managed block replaced (or appended when absent).
+fn with_block(content: &str) -> String {
+    let mut block = vec![BLOCK_BEGIN];
+    block.extend_from_slice(PATTERNS);
+    block.push(BLOCK_END);
+
+    let lines: Vec<&str> = content.lines().collect();
+    let out: Vec<&str> = match find_block(content) {
+        Some((begin, end)) => {
+            let mut out = lines[..begin].to_vec();
+            out.extend(&block);
+            out.extend(lines.get(end + 1..).unwrap_or_default());
+            out
+        }
+        None => {
+            let mut out = lines.clone();
+            if out.last().is_some_and(|l| !l.trim().is_empty()) {
+                out.push("");
+            }
+            out.extend(&block);
+            out
+        }
+    };
+    out.join("\n") + "\n"
+}
+
+/// Line indices of the block's BEGIN and END markers. A BEGIN without a
+/// matching END is treated as running to the end of the file, so a
+/// truncated block is replaced rather than duplicated.
+fn find_block(content: &str) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = content.lines().collect();
+    let begin = lines
+        .iter()
+        .position(|l| l.trim_start().starts_with("# BEGIN daft"))?;
+    let end = lines[begin + 1..]
+        .iter()
+        .position(|l| l.trim() == BLOCK_END)
+        .map(|i| begin + 1 + i)
+        .unwrap_or(lines.len());
+    Some((begin, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_is_appended_after_existing_lines_and_then_current() {
+        let original = "# git ls-files --others --exclude-from=.git/info/exclude\n*.swp\n";
+        let updated = with_block(original);
+        assert!(
+            updated.starts_with(original),
+            "user lines are kept in place"
+        );
+        assert_eq!(block_status(&updated), BlockStatus::Current);
+        assert_eq!(with_block(&updated), updated, "a current block is stable");
+        assert_eq!(block_status(original), BlockStatus::Missing);
+    }
+
+    #[test]
+    fn stale_block_is_replaced_without_touching_surrounding_lines() {
+        let stale = format!("*.swp\n{BLOCK_BEGIN}\n/daft.local.yml\n{BLOCK_END}\n/build\n");
+        assert_eq!(block_status(&stale), BlockStatus::Stale);
+
+        let updated = with_block(&stale);
+        assert_eq!(block_status(&updated), BlockStatus::Current);
+        assert!(updated.starts_with("*.swp\n# BEGIN daft"));
+        assert!(updated.ends_with(&format!("{BLOCK_END}\n/build\n")));
+        assert_eq!(updated.matches("# BEGIN daft").count(), 1);
+    }
+
+    #[test]
+    fn truncated_block_is_replaced_not_duplicated() {
+        let truncated = format!("{BLOCK_BEGIN}\n/daft.local.yml\n");
+        let updated = with_block(&truncated);
+        assert_eq!(updated.matches("# BEGIN daft").count(), 1);
+        assert_eq!(block_status(&updated), BlockStatus::Current);
+    }
+}
@@ -114,6 +114,20 @@ impl GlobalConfig {
             .with_context(|| format!("Failed to write config to {}", path.display()))
     }
 
+    /// Create the config file, with only a header comment, if it does not
+    /// exist yet. Returns its path.
+    pub fn ensure_file() -> Result<PathBuf> {
+        let path = Self::default_path()?;
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, "# daft global configuration\n")
+                .with_context(|| format!("Failed to write config to {}", path.display()))?;
+        }
+        Ok(path)
+    }
+
     /// Remove the default layout from the config file.
     ///
     /// Reverts to the built-in default (sibling). Only removes lines under
@@ -201,38 +201,12 @@ fn git_ignore_status(worktree_root: &Path, relpath: &str) -> IgnoreStatus {
     }
 }
 
-/// Resolve the repository's local exclude file (`.git/info/exclude`) for the
-/// repo containing `worktree_root`. `git rev-parse --git-path` resolves the
-/// gitlink indirection of linked worktrees to the shared common dir. The
-/// returned path can be relative to the `-C` dir, so join it onto
-/// `worktree_root` (mirrors `resolve_common_dir_cli` in remove_repo.rs).
-fn git_exclude_path(worktree_root: &Path) -> Result<PathBuf> {
-    let out = git_command_at(worktree_root)
-        .args(["rev-parse", "--git-path", "info/exclude"])
-        .stderr(Stdio::null())
-        .output()
-        .context("Failed to run `git rev-parse --git-path info/exclude`")?;
-    if !out.status.success() {
-        anyhow::bail!("{} is not inside a git repository", worktree_root.display());
-    }
-    let raw = String::from_utf8(out.stdout)
-        .context("git rev-parse output is not UTF-8")?
-        .trim()
-        .to_string();
-    let p = PathBuf::from(&raw);
-    Ok(if p.is_absolute() {
-        p
-    } else {
-        worktree_root.join(p)
-    })
-}
-
 /// Append `pattern` to the repo's `.git/info/exclude`, idempotently.
 ///
 /// Returns the exclude file path that was written (for messaging). If the
 /// pattern is already present on its own line the file is left untouched.
 fn add_to_git_exclude(worktree_root: &Path, pattern: &str) -> Result<PathBuf> {
-    let exclude_path = git_exclude_path(worktree_root)?;
+    let exclude_path = crate::core::git_exclude::exclude_path(worktree_root)?;
 
     let existing = fs::read_to_string(&exclude_path).unwrap_or_default();
     if existing.lines().any(|line| line.trim() == pattern) {
@@ -10,7 +10,7 @@ use std::path::{Path, PathBuf};
 use super::{BuiltinLayout, Layout};
 use crate::core::global_config::GlobalConfig;
 use crate::core::layout::resolver::DetectionResult;
-use crate::core::layout::template::{render, resolve_path};
+use crate::core::layout::template::render_path;
 use crate::core::multi_remote::path::build_template_context;
 
 /// Information about a single worktree from `git worktree list --porcelain`.
@@ -102,8 +102,7 @@ pub fn match_templates(
         for wt in &linked_worktrees {
             let branch = wt.branch.as_deref().unwrap(); // safe: filtered above
             let ctx = build_template_context(project_root, branch);
-            if let Ok(rendered) = render(&layout.template, &ctx)
-                && let Ok(expected_path) = resolve_path(&rendered, project_root)
+            if let Ok(expected_path) = render_path(&layout.template, &ctx)
                 && expected_path == wt.path
             {
                 matched.push(*wt);
@@ -270,7 +269,8 @@ pub fn detect_layout_from_porcelain(
 ///
 /// Detection order:
 /// 1. Read `core.bare` via git config.
-/// 2. Derive `project_root` as `git_common_dir.parent()`.
+/// 2. Derive `project_root` via `project_root_for` (normally
+///    `git_common_dir.parent()`).
 /// 3. Fetch the worktree list via `git worktree list --porcelain`.
 /// 4. Try `detect_layout_from_porcelain` with the direct parent as
 ///    `project_root`.
@@ -278,7 +278,6 @@ pub fn detect_layout_from_porcelain(
 ///    `project_root` (contained-classic case where `.git` lives at
 ///    `wrapper/<branch>/.git`).
 pub fn detect_layout(git_common_dir: &Path, global_config: &GlobalConfig) -> DetectionResult {
-    use crate::git::GitCommand;
     use crate::settings::DaftSettings;
 
     // Skip detection for multi-remote repos — template matching doesn't
@@ -289,23 +288,36 @@ pub fn detect_layout(git_common_dir: &Path, global_config: &GlobalConfig) -> Det
         return DetectionResult::NoMatch;
     }
 
-    let git = GitCommand::new(true);
+    detect_layout_with_bare(git_common_dir, read_is_bare(), global_config)
+}
 
-    let is_bare = git
+/// `core.bare` of the current repository, `false` when unset or unreadable.
+fn read_is_bare() -> bool {
+    crate::git::GitCommand::new(true)
         .config_get("core.bare")
         .ok()
         .flatten()
-        .map(|v| v == "true")
-        .unwrap_or(false);
+        .is_some_and(|v| v == "true")
+}
+
+/// [`detect_layout`] steps 2-5, for a caller that has already checked
+/// multi-remote mode and read `core.bare`.
+fn detect_layout_with_bare(
+    git_common_dir: &Path,
+    is_bare: bool,
+    global_config: &GlobalConfig,
+) -> DetectionResult {
+    let git = crate::git::GitCommand::new(true);
 
     let porcelain = match git.worktree_list_porcelain() {
         Ok(p) => p,
         Err(_) => return DetectionResult::NoWorktrees,
     };
 
-    // Primary project_root: direct parent of git_common_dir.
-    let project_root = match git_common_dir.parent() {
-        Some(p) => p.to_path_buf(),
+    // Primary project_root: direct parent of git_common_dir (or a standalone
+    // bare repository itself).
+    let project_root = match crate::core::repo::project_root_for(git_common_dir) {
+        Some(p) => p,
         None => return DetectionResult::NoWorktrees,
     };
 
@@ -342,6 +354,56 @@ pub fn detect_layout(git_common_dir: &Path, global_config: &GlobalConfig) -> Det
     result
 }
 
+/// Whether a repository is a plain git repository daft does not manage: no
+/// layout recorded for it by clone/init/eject, not bare, and no worktree
+/// structure that matches a known layout. Inspection commands still work
+/// there, with a note pointing at `daft adopt`.
+pub fn is_unmanaged(
+    stored_layout: Option<&str>,
+    is_bare: bool,
+    detection: &DetectionResult,
+) -> bool {
+    stored_layout.is_none()
+        && !is_bare
+        && matches!(
+            detection,
+            DetectionResult::NoWorktrees | DetectionResult::NoMatch
+        )
+}
+
+/// [`is_unmanaged`] for the repository at `git_common_dir`, read live.
+///
+/// `daft list` asks this on every run, so the cheap answers come first: a
+/// recorded layout, multi-remote mode, or a bare repository settles it
+/// without listing worktrees, and layout detection only runs for a non-bare
+/// repository daft has no record of.
+pub fn is_unmanaged_repo(git_common_dir: &Path) -> bool {
+    use crate::settings::DaftSettings;
+
+    let stored = crate::hooks::TrustDatabase::load()
+        .ok()
+        .and_then(|db| db.get_layout(git_common_dir).map(String::from));
+    if stored.is_some() {
+        return false;
+    }
+    // Multi-remote repos never match structurally (see `detect_layout`), so
+    // a missing match says nothing about them.
+    if let Ok(settings) = DaftSettings::load_global()
+        && settings.multi_remote_enabled
+    {
+        return false;
+    }
+    if read_is_bare() {
+        return false;
+    }
+    let global_config = GlobalConfig::load().unwrap_or_default();
+    is_unmanaged(
+        None,
+        false,
+        &detect_layout_with_bare(git_common_dir, false, &global_config),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -692,4 +754,27 @@ branch refs/heads/develop
         );
         assert!(matches!(result, DetectionResult::NoWorktrees));
     }
+
+    // ── is_unmanaged tests ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_is_unmanaged_plain_clone() {
+        assert!(is_unmanaged(None, false, &DetectionResult::NoWorktrees));
+        assert!(is_unmanaged(None, false, &DetectionResult::NoMatch));
+    }
+
+    #[test]
+    fn test_is_unmanaged_false_for_recorded_bare_or_detected() {
+        assert!(!is_unmanaged(
+            Some("sibling"),
+            false,
+            &DetectionResult::NoWorktrees
+        ));
+        assert!(!is_unmanaged(None, true, &DetectionResult::NoWorktrees));
+        assert!(!is_unmanaged(
+            None,
+            false,
+            &DetectionResult::Detected(BuiltinLayout::Sibling.to_layout())
+        ));
+    }
 }
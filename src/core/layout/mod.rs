@@ -52,8 +52,7 @@ impl Layout {
     }
 
     pub fn worktree_path(&self, ctx: &TemplateContext) -> Result<PathBuf> {
-        let rendered = template::render(&self.template, ctx)?;
-        template::resolve_path(&rendered, &ctx.repo_path)
+        template::render_path(&self.template, ctx)
     }
 }
 
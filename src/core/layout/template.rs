@@ -64,10 +64,21 @@ fn resolve_expression(expr: &str, ctx: &TemplateContext) -> Result<String> {
     Ok(value)
 }
 
+/// Render `template` for `ctx` and resolve it to an absolute path.
+///
+/// `~` and environment variables in the template's own text expand as in any
+/// other path-valued setting (see [`crate::settings::expand_path`]). That
+/// happens before `{{ }}` substitution, so a `$` or `%` in a branch name or
+/// the repo path stays literal.
+pub fn render_path(template: &str, ctx: &TemplateContext) -> Result<PathBuf> {
+    let expanded = crate::settings::expand_path("layout", template)?;
+    let rendered = render(&expanded.to_string_lossy(), ctx)?;
+    resolve_path(&rendered, &ctx.repo_path)
+}
+
 /// Resolve a rendered template path to an absolute PathBuf.
 ///
 /// - Absolute paths (starting with `/`) are used as-is.
-/// - Home-relative paths (starting with `~/`) expand to the home directory.
 /// - Relative paths are resolved against the **parent directory** of
 ///   `repo_path` — i.e., the directory that contains the repository.
 ///
@@ -77,19 +88,15 @@ fn resolve_expression(expr: &str, ctx: &TemplateContext) -> Result<String> {
 ///
 /// All paths are normalized (`..` components resolved without filesystem access).
 pub fn resolve_path(rendered: &str, repo_path: &Path) -> Result<PathBuf> {
-    if rendered.starts_with('/') {
-        return Ok(normalize_path(Path::new(rendered)));
-    }
-    if let Some(rest_of_path) = rendered.strip_prefix("~/") {
-        let home = dirs::home_dir()
-            .ok_or_else(|| anyhow::anyhow!("Could not determine home directory"))?;
-        return Ok(normalize_path(&home.join(rest_of_path)));
+    let path = Path::new(rendered);
+    if path.is_absolute() {
+        return Ok(normalize_path(path));
     }
     // Relative paths resolve against the parent of repo_path
     let parent = repo_path
         .parent()
         .ok_or_else(|| anyhow::anyhow!("Repository path has no parent directory"))?;
-    Ok(normalize_path(&parent.join(rendered)))
+    Ok(normalize_path(&parent.join(path)))
 }
 
 /// Normalize a path by resolving `.` and `..` components without filesystem access.
@@ -244,14 +251,54 @@ mod tests {
         assert_eq!(resolved, PathBuf::from("/tmp/worktrees/feature-auth"));
     }
 
+    fn ctx(branch: &str) -> TemplateContext {
+        TemplateContext {
+            repo_path: PathBuf::from("/home/user/myproject"),
+            repo: "myproject".into(),
+            branch: branch.into(),
+        }
+    }
+
     #[test]
-    fn test_resolve_path_home_expansion() {
-        let repo = Path::new("/home/user/myproject");
-        let resolved = resolve_path("~/worktrees/myproject/main", repo).unwrap();
+    fn test_render_path_home_expansion() {
+        let resolved = render_path("~/worktrees/{{ repo }}/{{ branch }}", &ctx("main")).unwrap();
         assert!(!resolved.starts_with("~"));
         assert!(resolved.ends_with("worktrees/myproject/main"));
     }
 
+    #[test]
+    #[serial]
+    fn test_render_path_env_expansion() {
+        unsafe {
+            env::set_var("DAFT_TEST_WORKTREE_ROOT", "/srv/worktrees");
+        }
+        let resolved = render_path(
+            "$DAFT_TEST_WORKTREE_ROOT/{{ repo }}/{{ branch }}",
+            &ctx("main"),
+        );
+        unsafe {
+            env::remove_var("DAFT_TEST_WORKTREE_ROOT");
+        }
+        assert_eq!(
+            resolved.unwrap(),
+            PathBuf::from("/srv/worktrees/myproject/main")
+        );
+    }
+
+    #[test]
+    fn test_render_path_keeps_variables_in_branch_names_literal() {
+        let resolved = render_path("{{ repo_path }}/{{ branch | sanitize }}", &ctx("z%HOME%"));
+        assert_eq!(
+            resolved.unwrap(),
+            PathBuf::from("/home/user/myproject/z%HOME%")
+        );
+        let resolved = render_path("{{ repo }}.{{ branch }}", &ctx("y$NOPE-${NOPE}"));
+        assert_eq!(
+            resolved.unwrap(),
+            PathBuf::from("/home/user/myproject.y$NOPE-${NOPE}")
+        );
+    }
+
     #[test]
     fn test_resolve_path_absolute_with_repo_path() {
         // Templates using {{ repo_path }} render to absolute paths
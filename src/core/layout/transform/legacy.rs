@@ -841,7 +841,9 @@ fn remove_worktrees(
             &wt.path,
             branch,
         )
-        .with_removal_reason(RemovalReason::Ejecting);
+        .with_removal_reason(RemovalReason::Ejecting)
+        .with_target_cwd(project_root)
+        .with_branch_exists(true);
 
         if let Err(e) = sink.run_hook(&pre_ctx) {
             sink.on_warning(&format!("Pre-remove hook failed for {branch}: {e}"));
@@ -880,7 +882,9 @@ fn remove_worktrees(
             &wt.path,
             branch,
         )
-        .with_removal_reason(RemovalReason::Ejecting);
+        .with_removal_reason(RemovalReason::Ejecting)
+        .with_target_cwd(project_root)
+        .with_branch_exists(true);
 
         if let Err(e) = sink.run_hook(&post_ctx) {
             sink.on_warning(&format!("Post-remove hook failed for {branch}: {e}"));
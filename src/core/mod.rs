@@ -4,16 +4,22 @@
 //! report progress and trigger hooks without depending on specific UI
 //! implementations (CLI, TUI, tests, etc.).
 
+pub mod backup;
 pub mod cache;
+pub mod clone_manifest;
 pub mod columns;
 pub mod config;
+pub mod consolidate;
+pub mod git_exclude;
 pub mod global_config;
 pub mod install;
 pub mod layout;
 pub mod multi_remote;
+pub mod nesting;
 pub mod ownership;
 mod progress;
 pub mod remote;
+pub mod remote_url;
 pub mod repo;
 pub mod repo_identity;
 pub mod settings;
@@ -21,7 +27,10 @@ pub mod shared;
 pub mod size_walk;
 pub mod sort;
 pub mod stage;
+pub mod storage;
+pub mod template;
 mod tui_bridge;
+pub mod workspace;
 pub mod worktree;
 
 pub use tui_bridge::TuiBridge;
@@ -244,6 +253,39 @@ pub trait ConsolidationPrompter {
 impl ConsolidationPrompter for NullBridge {}
 impl ConsolidationPrompter for NullSink {}
 
+// ─────────────────────────────────────────────────────────────────────────
+// Occupied worktree paths
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Decision surface for a worktree target path that is already occupied by
+/// something git would refuse. Non-interactive contexts use the default —
+/// `None`, which aborts with a hint naming `--on-conflict` — so nothing is
+/// adopted or relocated without an explicit answer.
+pub trait PathConflictPrompter {
+    fn on_path_conflict(
+        &mut self,
+        _conflict: &worktree::path_conflict::PathConflict<'_>,
+    ) -> Option<worktree::path_conflict::OnConflict> {
+        None
+    }
+
+    /// The path a new branch's worktree is about to be created at, and
+    /// whether it follows the branch name. Interactive sinks show it and ask
+    /// first when it doesn't; `false` aborts before anything is created. The
+    /// default proceeds silently, so non-interactive runs never block on it.
+    fn confirm_worktree_path(
+        &mut self,
+        _branch: &str,
+        _path: &std::path::Path,
+        _follows_branch: bool,
+    ) -> bool {
+        true
+    }
+}
+
+impl PathConflictPrompter for NullBridge {}
+impl PathConflictPrompter for NullSink {}
+
 // ─────────────────────────────────────────────────────────────────────────
 // Test support
 // ─────────────────────────────────────────────────────────────────────────
@@ -298,3 +340,6 @@ impl HookRunner for RecordingStageSink {
 
 #[cfg(test)]
 impl ConsolidationPrompter for RecordingStageSink {}
+
+#[cfg(test)]
+impl PathConflictPrompter for RecordingStageSink {}
@@ -102,9 +102,16 @@ pub fn extract_branch_from_path(
 ///
 /// Used by layout-aware commands to compute worktree paths from templates.
 pub fn build_template_context(repo_path: &Path, branch_name: &str) -> TemplateContext {
+    // A standalone bare repository (`proj.git`) is its own project root;
+    // templates name the project, not the directory suffix.
     let repo = repo_path
         .file_name()
         .and_then(|n| n.to_str())
+        .map(|n| {
+            n.strip_suffix(".git")
+                .filter(|s| !s.is_empty())
+                .unwrap_or(n)
+        })
         .unwrap_or("unknown")
         .to_string();
     TemplateContext {
@@ -138,6 +145,13 @@ mod tests {
         assert_eq!(result, PathBuf::from("/home/user/project/upstream/main"));
     }
 
+    #[test]
+    fn test_build_template_context_strips_bare_suffix() {
+        let ctx = build_template_context(Path::new("/home/user/project.git"), "feat");
+        assert_eq!(ctx.repo, "project");
+        assert_eq!(ctx.repo_path, PathBuf::from("/home/user/project.git"));
+    }
+
     #[test]
     fn test_extract_remote_from_path() {
         let project_root = Path::new("/home/user/project");
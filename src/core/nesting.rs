@@ -0,0 +1,135 @@
+//! Detection of projects nested inside other repositories.
+//!
+//! daft resolves a project from the directory it runs in, so a clone created
+//! inside another repository's worktree (or next to its bare `.git`) sits in
+//! two projects at once: commands run from the inner one can resolve the
+//! outer one wherever the inner layout leaves a gap, and the outer one sees
+//! the whole inner clone as untracked files. `daft clone` and `daft init`
+//! check the target before creating anything, governed by
+//! `daft.nestedProjects`, and `daft doctor` reports existing nesting.
+//!
+//! A repository's own worktrees are not nesting — the `nested` layout puts
+//! them inside the project root on purpose — so only a *different*
+//! repository counts as enclosing.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+
+use crate::settings::{NestedProjects, keys};
+
+/// The repository a path would be nested in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnclosingProject {
+    /// Project root of the enclosing repository.
+    pub root: PathBuf,
+    /// Its git common directory.
+    pub git_common_dir: PathBuf,
+    /// Whether daft manages it (a recorded layout or a bare-based project),
+    /// as opposed to a plain git repository.
+    pub managed: bool,
+}
+
+impl EnclosingProject {
+    /// "daft project" or "git repository", for messages.
+    pub fn kind(&self) -> &'static str {
+        if self.managed {
+            "daft project"
+        } else {
+            "git repository"
+        }
+    }
+}
+
+/// The repository enclosing `path`, if any. `path` need not exist yet: the
+/// lookup starts from its nearest existing ancestor.
+pub fn enclosing_project(path: &Path) -> Option<EnclosingProject> {
+    let existing = path.ancestors().find(|p| p.is_dir())?;
+    let git_common_dir = crate::core::repo::git_common_dir_at(existing)?;
+    let root = crate::core::repo::project_root_for(&git_common_dir)?;
+    let managed = crate::core::repo::is_bare_git_dir(&git_common_dir)
+        || crate::hooks::TrustDatabase::load()
+            .ok()
+            .is_some_and(|db| db.get_layout(&git_common_dir).is_some());
+    Some(EnclosingProject {
+        root,
+        git_common_dir,
+        managed,
+    })
+}
+
+/// Check a new project's `target` directory against `policy`. Returns the
+/// warning to show when the target is nested and the policy is `warn`;
+/// fails when it is `deny`.
+pub fn check_new_project(target: &Path, policy: NestedProjects) -> Result<Option<String>> {
+    if policy == NestedProjects::Allow {
+        return Ok(None);
+    }
+    let Some(outer) = enclosing_project(target) else {
+        return Ok(None);
+    };
+    let message = format!(
+        "'{}' is inside the {} at {}",
+        target.display(),
+        outer.kind(),
+        outer.root.display()
+    );
+    if policy == NestedProjects::Deny {
+        bail!(
+            "{message}.\nCreate the project outside {}, or allow nesting with:\n  \
+             git config --global {} warn",
+            outer.root.display(),
+            keys::NESTED_PROJECTS
+        );
+    }
+    Ok(Some(format!(
+        "{message}; commands run there may resolve the outer repository. \
+         Set {}=deny to refuse nested projects.",
+        keys::NESTED_PROJECTS
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::git_command_at;
+    use tempfile::TempDir;
+
+    fn init_repo(dir: &Path) {
+        std::fs::create_dir_all(dir).unwrap();
+        let status = git_command_at(dir)
+            .args(["init", "-q", "-b", "main"])
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn target_inside_a_worktree_is_enclosed_by_it() {
+        let tmp = TempDir::new().unwrap();
+        let outer = tmp.path().join("outer");
+        init_repo(&outer);
+
+        let found = enclosing_project(&outer.join("vendor/inner")).unwrap();
+        assert_eq!(found.root, outer.canonicalize().unwrap());
+        assert!(!found.managed);
+        assert!(enclosing_project(&tmp.path().join("sibling")).is_none());
+    }
+
+    #[test]
+    fn policy_decides_between_warning_and_refusal() {
+        let tmp = TempDir::new().unwrap();
+        let outer = tmp.path().join("outer");
+        init_repo(&outer);
+        let target = outer.join("inner");
+
+        let warning = check_new_project(&target, NestedProjects::Warn).unwrap();
+        assert!(warning.unwrap().contains("inside the git repository"));
+        assert!(check_new_project(&target, NestedProjects::Deny).is_err());
+        assert!(
+            check_new_project(&target, NestedProjects::Allow)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -1,8 +1,9 @@
 //! Adapters bridging core traits to the command layer.
 
+use super::worktree::path_conflict::{OnConflict, PathConflict};
 use super::{
     ConflictSide, ConsolidationChoice, ConsolidationPrompter, ConsolidationRequest, HookOutcome,
-    HookRunner, ProgressSink,
+    HookRunner, PathConflictPrompter, ProgressSink,
 };
 use crate::executor::cli_presenter::CliPresenter;
 use crate::executor::presenter::JobPresenter;
@@ -222,6 +223,125 @@ fn prompt_conflict_side(output: &mut dyn Output, filename: &str, keys: &[String]
     }
 }
 
+/// Interactive occupied-path prompt, shared by both bridges (see
+/// [`prompt_refined`]). Adopt is only offered for plain directories — the
+/// other occupants carry git metadata of their own.
+fn prompt_path_conflict(
+    output: &mut dyn Output,
+    conflict: &PathConflict<'_>,
+) -> Option<OnConflict> {
+    use super::worktree::path_conflict::Occupant;
+    use std::io::IsTerminal;
+    if !std::io::stdin().is_terminal() && std::env::var("DAFT_TESTING").is_err() {
+        return None;
+    }
+    output.pause_spinner();
+    let can_adopt = conflict.occupant == Occupant::Directory;
+    output.info(&format!(
+        "'{}' already exists and is not a worktree of this repository.",
+        conflict.path.display()
+    ));
+    let mut options = Vec::new();
+    if can_adopt {
+        output.info("  adopt  — make it the worktree; its contents become uncommitted changes");
+        options.push(PromptOption {
+            key: 'a',
+            label: "adopt",
+            is_default: false,
+        });
+    }
+    output.info(&format!(
+        "  suffix — create the worktree at '{}'",
+        conflict.suffixed.display()
+    ));
+    options.push(PromptOption {
+        key: 's',
+        label: "suffix",
+        is_default: false,
+    });
+    options.push(PromptOption {
+        key: 'q',
+        label: "abort",
+        is_default: true,
+    });
+    if can_adopt {
+        eprint!("Adopt, suffix, or abort? [a/s/Q] ");
+    } else {
+        eprint!("Suffix or abort? [s/Q] ");
+    }
+    let result = single_key_select(&PromptConfig {
+        options,
+        cancel_message: Some("Aborted.".to_string()),
+    });
+    eprintln!();
+    output.resume_spinner();
+    match result {
+        PromptResult::Selected('a') if can_adopt => Some(OnConflict::Adopt),
+        PromptResult::Selected('s') => Some(OnConflict::Suffix),
+        PromptResult::Selected(_) => Some(OnConflict::Fail),
+        PromptResult::Cancelled => None,
+    }
+}
+
+/// Show the derived worktree path, shared by both bridges, and ask for
+/// confirmation when it does not follow its branch name. Proceeds without
+/// asking when stdin is not a terminal.
+fn prompt_worktree_path(
+    output: &mut dyn Output,
+    branch: &str,
+    path: &std::path::Path,
+    follows_branch: bool,
+) -> bool {
+    use std::io::IsTerminal;
+    if follows_branch {
+        output.info(&format!("Worktree for '{branch}': {}", path.display()));
+        return true;
+    }
+    output.pause_spinner();
+    output.info(&format!(
+        "The worktree for '{branch}' will be created at '{}', which does not follow the branch name.",
+        path.display()
+    ));
+    if !std::io::stdin().is_terminal() {
+        output.resume_spinner();
+        return true;
+    }
+    eprint!("Create it there? [Y/n] ");
+    let result = single_key_select(&PromptConfig {
+        options: vec![
+            PromptOption {
+                key: 'y',
+                label: "yes",
+                is_default: true,
+            },
+            PromptOption {
+                key: 'n',
+                label: "no",
+                is_default: false,
+            },
+        ],
+        cancel_message: Some("Aborted.".to_string()),
+    });
+    eprintln!();
+    output.resume_spinner();
+    matches!(result, PromptResult::Selected('y'))
+}
+
+impl PathConflictPrompter for CommandBridge<'_> {
+    fn on_path_conflict(&mut self, conflict: &PathConflict<'_>) -> Option<OnConflict> {
+        prompt_path_conflict(self.output, conflict)
+    }
+
+    fn confirm_worktree_path(
+        &mut self,
+        branch: &str,
+        path: &std::path::Path,
+        follows_branch: bool,
+    ) -> bool {
+        prompt_worktree_path(self.output, branch, path, follows_branch)
+    }
+}
+
 impl ConsolidationPrompter for CommandBridge<'_> {
     fn on_refined(&mut self, req: &ConsolidationRequest) -> ConsolidationChoice {
         prompt_refined(self.output, req)
@@ -530,6 +650,26 @@ impl ConsolidationPrompter for TimelineBridge<'_> {
     }
 }
 
+impl PathConflictPrompter for TimelineBridge<'_> {
+    // Fires while the planning face is up, like the consolidation prompts.
+    fn on_path_conflict(&mut self, conflict: &PathConflict<'_>) -> Option<OnConflict> {
+        let handle = self.timeline.handle();
+        let output = &mut *self.output;
+        handle.suspend_for_prompt(|| prompt_path_conflict(output, conflict))
+    }
+
+    fn confirm_worktree_path(
+        &mut self,
+        branch: &str,
+        path: &std::path::Path,
+        follows_branch: bool,
+    ) -> bool {
+        let handle = self.timeline.handle();
+        let output = &mut *self.output;
+        handle.suspend_for_prompt(|| prompt_worktree_path(output, branch, path, follows_branch))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,19 +49,203 @@ pub fn git_common_dir_at(path: &Path) -> Option<PathBuf> {
     Some(std::fs::canonicalize(common).unwrap_or_else(|_| common.to_path_buf()))
 }
 
+/// Run git at `dir` and return its stdout as is (untrimmed: porcelain output
+/// can start with significant whitespace). A non-zero exit is an error
+/// carrying git's stderr.
+pub fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = crate::utils::git_command_at(dir)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// The repo id that keys per-repository state for the repository at
+/// `repo_dir` (any of its worktrees).
+pub fn repo_hash(repo_dir: &Path) -> Result<String> {
+    let common_dir = git_common_dir_at(repo_dir).context("Not inside a Git repository")?;
+    crate::core::repo_identity::compute_repo_id_from_common_dir(&common_dir)
+}
+
+/// The repository's per-repo store database, if it has been created. Lets
+/// read-only callers skip opening (and so creating) one.
+pub fn existing_store(repo_dir: &Path) -> Option<PathBuf> {
+    use crate::store::paths;
+    let db_path = crate::daft_state_dir()
+        .ok()?
+        .join(paths::JOBS_SUBDIR)
+        .join(repo_hash(repo_dir).ok()?)
+        .join(paths::COORDINATOR_DB);
+    db_path.exists().then_some(db_path)
+}
+
 /// Return the path to the current worktree.
 pub fn get_current_worktree_path() -> Result<PathBuf> {
     let git = GitCommand::new(false);
     git.get_current_worktree_path()
 }
 
-/// Return the project root directory (parent of the git common dir).
+/// Return the project root directory (see [`project_root_for`]).
+///
+/// Run from inside the git directory, this also steps out to the project root
+/// first, with a notice, so every command that places or reads
+/// worktrees relative to the root does so from where the layout expects.
 pub fn get_project_root() -> Result<PathBuf> {
     let git_common_dir = get_git_common_dir()?;
-    let project_root = git_common_dir
-        .parent()
-        .context("Failed to determine project root directory")?;
-    Ok(project_root.to_path_buf())
+    leave_git_dir(&git_common_dir)?;
+    project_root_for(&git_common_dir).context("Failed to determine project root directory")
+}
+
+/// Step out of the git directory, once per process.
+///
+/// From inside `.git` (or a linked worktree's admin dir under it) there is no
+/// current worktree: `carry` has nothing to stash from, and `checkout`
+/// resolves `daft.yml` layout and hooks without one. When the current
+/// directory is inside the git common dir, this changes to the project root
+/// and prints a notice saying so; otherwise it leaves the directory alone.
+///
+/// A standalone bare repository is its own project root, so working inside it
+/// is left as is.
+fn leave_git_dir(git_common_dir: &Path) -> Result<()> {
+    static CHECKED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+    if CHECKED.swap(true, std::sync::atomic::Ordering::Relaxed) {
+        return Ok(());
+    }
+    let cwd = crate::utils::get_current_directory()?;
+    let cwd = cwd.canonicalize().unwrap_or(cwd);
+    let Some(root) = git_dir_escape_target(&cwd, git_common_dir) else {
+        return Ok(());
+    };
+    crate::utils::change_directory(&root)?;
+    use crate::output::Output;
+    crate::output::CliOutput::new(crate::output::OutputConfig::default()).notice(&format!(
+        "Running from the project root {} (the current directory is inside the git directory)",
+        root.display()
+    ));
+    Ok(())
+}
+
+/// Where [`leave_git_dir`] moves `cwd` to, if `cwd` is inside the git dir.
+fn git_dir_escape_target(cwd: &Path, git_common_dir: &Path) -> Option<PathBuf> {
+    if !cwd.starts_with(git_common_dir) {
+        return None;
+    }
+    project_root_for(git_common_dir).filter(|root| root != git_common_dir)
+}
+
+/// The project root a git common dir belongs to.
+///
+/// Normally the parent of the `.git` directory — the clone root, or the
+/// container of a contained layout. A standalone bare repository
+/// (`git clone --bare <url> proj.git`) is its own project root: nothing
+/// encloses it, so worktrees are placed relative to the bare directory rather
+/// than to whatever directory it happens to sit in.
+pub fn project_root_for(git_common_dir: &Path) -> Option<PathBuf> {
+    if git_common_dir.file_name().is_some_and(|n| n != ".git") && is_bare_git_dir(git_common_dir) {
+        return Some(git_common_dir.to_path_buf());
+    }
+    git_common_dir.parent().map(Path::to_path_buf)
+}
+
+/// Entries git (and daft) keep at the top of a git directory.
+const GIT_DIR_ENTRIES: &[&str] = &[
+    "HEAD",
+    "FETCH_HEAD",
+    "ORIG_HEAD",
+    "MERGE_HEAD",
+    "MERGE_MSG",
+    "MERGE_MODE",
+    "CHERRY_PICK_HEAD",
+    "REVERT_HEAD",
+    "BISECT_LOG",
+    "COMMIT_EDITMSG",
+    "AUTO_MERGE",
+    "branches",
+    "config",
+    "config.worktree",
+    "daft-id",
+    "description",
+    "gitk.cache",
+    "hooks",
+    "index",
+    "info",
+    "lfs",
+    "logs",
+    "modules",
+    "objects",
+    "packed-refs",
+    "rebase-apply",
+    "rebase-merge",
+    "refs",
+    "rr-cache",
+    "sequencer",
+    "shallow",
+    "worktrees",
+];
+
+/// Refuse a worktree path that lands on one of git's own entries.
+///
+/// Only reachable when a standalone bare repository is its own project root
+/// (see [`project_root_for`]) and the layout places worktrees inside it: a
+/// branch named `refs` or `objects` would then be checked out over the
+/// repository's internals. Comparison ignores case so the check also holds on
+/// case-insensitive filesystems.
+pub fn ensure_outside_git_internals(worktree_path: &Path, git_common_dir: &Path) -> Result<()> {
+    let Ok(rest) = worktree_path.strip_prefix(git_common_dir) else {
+        return Ok(());
+    };
+    let Some(first) = rest.components().next() else {
+        anyhow::bail!(
+            "Cannot create a worktree at '{}': that is the git directory itself",
+            worktree_path.display()
+        );
+    };
+    let name = first.as_os_str().to_string_lossy();
+    if GIT_DIR_ENTRIES
+        .iter()
+        .any(|entry| entry.eq_ignore_ascii_case(&name))
+        || name.ends_with(".lock")
+    {
+        anyhow::bail!(
+            "Cannot create a worktree at '{}': '{name}' is part of the git directory {}\n\
+             Choose another branch name or pass --at <path>",
+            worktree_path.display(),
+            git_common_dir.display()
+        );
+    }
+    Ok(())
+}
+
+/// Whether the git directory's own config sets `core.bare = true`.
+///
+/// Reads the config file directly rather than asking git: from inside a
+/// worktree git reports the worktree's view, not the shared directory's.
+pub fn is_bare_git_dir(git_common_dir: &Path) -> bool {
+    let config_path = git_common_dir.join("config");
+    if let Ok(content) = std::fs::read_to_string(config_path) {
+        // Look for bare = true in the [core] section
+        let mut in_core = false;
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                in_core = trimmed.starts_with("[core]");
+            } else if in_core && let Some(value) = trimmed.strip_prefix("bare") {
+                let value = value.trim().strip_prefix('=').map(|v| v.trim());
+                if value == Some("true") {
+                    return true;
+                }
+            }
+        }
+    }
+    false
 }
 
 /// Return the name of the currently checked-out branch.
@@ -266,37 +450,106 @@ pub(crate) fn find_representative_worktree(cwd: &Path) -> Option<PathBuf> {
 
 /// Extract a repository name from a URL (SSH, HTTPS, or shorthand).
 ///
-/// The extracted name is sanitized for security (path traversal, injection, etc.).
+/// The URL is parsed with [`RemoteUrl`](crate::core::remote_url::RemoteUrl),
+/// so ports (`ssh://git@host:2222/team/repo.git`) and trailing slashes don't
+/// leak into the name. The extracted name is sanitized for security (path
+/// traversal, injection, etc.).
 pub fn extract_repo_name(repo_url: &str) -> Result<String> {
-    let repo_name = if repo_url.contains(':') {
-        let parts: Vec<&str> = repo_url.split(':').collect();
-        if parts.len() >= 2 {
-            Path::new(parts[1])
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .context("Failed to extract repository name from shorthand URL")?
-                .to_string()
-        } else {
-            anyhow::bail!("Invalid repository URL format");
-        }
-    } else {
-        Path::new(repo_url)
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .context("Failed to extract repository name from URL")?
-            .to_string()
-    };
-
-    if repo_name.is_empty() {
-        anyhow::bail!("Could not extract repository name from URL: '{}'", repo_url);
-    }
+    let parsed = crate::core::remote_url::RemoteUrl::parse(repo_url)
+        .with_context(|| format!("Invalid repository URL: '{repo_url}'"))?;
+    let repo_name = parsed
+        .repo_name()
+        .with_context(|| format!("Could not extract repository name from URL: '{repo_url}'"))?;
 
     // Security: Sanitize the extracted repository name
-    let sanitized_name = sanitize_extracted_name(&repo_name)?;
+    let sanitized_name = sanitize_extracted_name(repo_name)?;
 
     Ok(sanitized_name)
 }
 
+/// [`extract_repo_name`] for a URL about to be cloned: the user's
+/// `url.<base>.insteadOf` rules are applied first, as git will when it
+/// connects, so an alias like `work:` that expands to a full remote still
+/// names the clone after the repository.
+pub fn extract_clone_repo_name(repo_url: &str) -> Result<String> {
+    extract_repo_name(&effective_clone_url(repo_url))
+}
+
+/// The directory a clone of `repo_url` lands in, relative to the current
+/// directory, rendered from `daft.clone.nameTemplate`.
+///
+/// The template names the repository with `{repo}`, its owner (org, user,
+/// or GitLab group path) with `{org}`, and the forge host with `{host}`; a
+/// `/` in the template builds a directory tree (`{host}/{org}/{repo}`).
+/// A placeholder the URL has no value for (a local path has no host) renders
+/// empty, and separators left dangling by it are dropped. Every path segment
+/// goes through the same sanitizing as a bare repository name.
+pub fn clone_target_dir(repo_url: &str, template: &str) -> Result<PathBuf> {
+    clone_target_dir_for_effective(&effective_clone_url(repo_url), template)
+}
+
+/// [`clone_target_dir`] for a URL whose insteadOf rewrites were already
+/// applied.
+pub fn clone_target_dir_for_effective(url: &str, template: &str) -> Result<PathBuf> {
+    let repo = extract_repo_name(url)?;
+    let parsed = crate::core::remote_url::RemoteUrl::parse(url);
+    let org = parsed.as_ref().and_then(|u| u.owner()).unwrap_or_default();
+    let host = parsed
+        .as_ref()
+        .and_then(|u| u.host.clone())
+        .unwrap_or_default();
+    render_clone_dir(template, &repo, &org, &host)
+}
+
+fn effective_clone_url(repo_url: &str) -> String {
+    use crate::core::remote_url::InsteadOf;
+    let rules = InsteadOf::load(Path::new("."));
+    InsteadOf::rewrite(repo_url, &rules, false).unwrap_or_else(|| repo_url.to_string())
+}
+
+/// Pure core of [`clone_target_dir`].
+fn render_clone_dir(template: &str, repo: &str, org: &str, host: &str) -> Result<PathBuf> {
+    let mut rendered = String::new();
+    let mut rest = template;
+    let mut has_repo = false;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .map(|i| start + i)
+            .with_context(|| {
+                format!("Unclosed placeholder in daft.clone.nameTemplate '{template}'")
+            })?;
+        match &rest[start + 1..end] {
+            "repo" => {
+                has_repo = true;
+                rendered.push_str(repo);
+            }
+            "org" => rendered.push_str(org),
+            "host" => rendered.push_str(host),
+            other => anyhow::bail!(
+                "Unknown placeholder '{{{other}}}' in daft.clone.nameTemplate '{template}' \
+                 (expected {{repo}}, {{org}}, or {{host}})"
+            ),
+        }
+        rest = &rest[end + 1..];
+    }
+    rendered.push_str(rest);
+    if !has_repo {
+        anyhow::bail!("daft.clone.nameTemplate '{template}' must contain {{repo}}");
+    }
+
+    let mut dir = PathBuf::new();
+    for segment in rendered.split('/') {
+        let segment = segment.trim_matches(|c| matches!(c, '-' | '_' | '.'));
+        if segment.is_empty() {
+            continue;
+        }
+        dir.push(sanitize_extracted_name(segment)?);
+    }
+    Ok(dir)
+}
+
 /// Sanitizes an extracted repository name for security.
 ///
 /// Applies security measures to prevent injection attacks, path traversal,
@@ -387,6 +640,46 @@ pub fn check_dependencies() -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_git_dir_escape_target() {
+        let dir = tempfile::tempdir().unwrap();
+        let common = dir.path().join("proj/.git");
+        std::fs::create_dir_all(&common).unwrap();
+        let root = dir.path().join("proj");
+
+        assert_eq!(git_dir_escape_target(&common, &common), Some(root.clone()));
+        assert_eq!(
+            git_dir_escape_target(&common.join("worktrees/feat"), &common),
+            Some(root.clone())
+        );
+        assert_eq!(git_dir_escape_target(&root, &common), None);
+        assert_eq!(git_dir_escape_target(&root.join("main"), &common), None);
+
+        // A standalone bare repo is the project root; stay put inside it.
+        let bare = dir.path().join("proj.git");
+        std::fs::create_dir_all(&bare).unwrap();
+        std::fs::write(bare.join("config"), "[core]\n\tbare = true\n").unwrap();
+        assert_eq!(git_dir_escape_target(&bare.join("refs"), &bare), None);
+    }
+
+    #[test]
+    fn test_ensure_outside_git_internals() {
+        let bare = Path::new("/work/proj.git");
+        assert!(ensure_outside_git_internals(&bare.join("feature"), bare).is_ok());
+        assert!(ensure_outside_git_internals(&bare.join("feature/refs"), bare).is_ok());
+        assert!(ensure_outside_git_internals(Path::new("/work/refs"), bare).is_ok());
+        for name in [
+            "refs", "objects", "hooks", "info", "logs", "Objects", "HEAD",
+        ] {
+            assert!(
+                ensure_outside_git_internals(&bare.join(name), bare).is_err(),
+                "{name} should be refused"
+            );
+        }
+        assert!(ensure_outside_git_internals(&bare.join("refs/nested"), bare).is_err());
+        assert!(ensure_outside_git_internals(bare, bare).is_err());
+    }
+
     #[test]
     fn test_extract_repo_name_ssh() {
         let url = "git@github.com:user/repo.git";
@@ -408,6 +701,97 @@ mod tests {
         assert_eq!(name, "repo");
     }
 
+    #[test]
+    fn test_render_clone_dir_templates() {
+        let render = |t| render_clone_dir(t, "widget", "acme", "github.com").unwrap();
+        assert_eq!(render("{repo}"), PathBuf::from("widget"));
+        assert_eq!(render("{org}-{repo}"), PathBuf::from("acme-widget"));
+        assert_eq!(
+            render("{host}/{org}/{repo}"),
+            PathBuf::from("github.com/acme/widget")
+        );
+        // Missing values leave no dangling separators.
+        assert_eq!(
+            render_clone_dir("{org}-{repo}", "widget", "", "").unwrap(),
+            PathBuf::from("widget")
+        );
+        assert_eq!(
+            render_clone_dir("{host}/{org}/{repo}", "widget", "", "").unwrap(),
+            PathBuf::from("widget")
+        );
+        // A GitLab group path nests.
+        assert_eq!(
+            render_clone_dir("{org}/{repo}", "widget", "group/sub", "").unwrap(),
+            PathBuf::from("group/sub/widget")
+        );
+    }
+
+    #[test]
+    fn test_render_clone_dir_rejects_bad_templates() {
+        assert!(render_clone_dir("{org}", "widget", "acme", "").is_err());
+        assert!(render_clone_dir("{repo}-{branch}", "widget", "acme", "").is_err());
+        assert!(render_clone_dir("{repo", "widget", "acme", "").is_err());
+        // `..` from a hostile owner can't escape the current directory.
+        assert!(
+            render_clone_dir("{org}/{repo}", "widget", "../..", "").is_ok_and(|p| p
+                .components()
+                .all(|c| matches!(c, std::path::Component::Normal(_))))
+        );
+    }
+
+    #[test]
+    fn test_extract_repo_name_ssh_with_port_and_trailing_slash() {
+        let name = extract_repo_name("ssh://git@host:2222/team/repo.git").unwrap();
+        assert_eq!(name, "repo");
+        let name = extract_repo_name("https://host/team/repo/").unwrap();
+        assert_eq!(name, "repo");
+    }
+
+    #[test]
+    fn test_is_bare_git_dir_true() {
+        let temp = tempfile::tempdir().unwrap();
+        let config = temp.path().join("config");
+        std::fs::write(
+            &config,
+            "[core]\n\trepositoryformatversion = 0\n\tbare = true\n",
+        )
+        .unwrap();
+        assert!(is_bare_git_dir(temp.path()));
+    }
+
+    #[test]
+    fn test_is_bare_git_dir_false() {
+        let temp = tempfile::tempdir().unwrap();
+        let config = temp.path().join("config");
+        std::fs::write(
+            &config,
+            "[core]\n\trepositoryformatversion = 0\n\tbare = false\n",
+        )
+        .unwrap();
+        assert!(!is_bare_git_dir(temp.path()));
+    }
+
+    #[test]
+    fn test_is_bare_git_dir_no_config() {
+        let temp = tempfile::tempdir().unwrap();
+        assert!(!is_bare_git_dir(temp.path()));
+    }
+
+    #[test]
+    fn test_project_root_for_standalone_bare_repo_is_itself() {
+        let temp = tempfile::tempdir().unwrap();
+        let bare = temp.path().join("proj.git");
+        std::fs::create_dir(&bare).unwrap();
+        std::fs::write(bare.join("config"), "[core]\n\tbare = true\n").unwrap();
+        assert_eq!(project_root_for(&bare), Some(bare.clone()));
+
+        // A contained layout's bare `.git` still belongs to its container.
+        let dot_git = temp.path().join("proj").join(".git");
+        std::fs::create_dir_all(&dot_git).unwrap();
+        std::fs::write(dot_git.join("config"), "[core]\n\tbare = true\n").unwrap();
+        assert_eq!(project_root_for(&dot_git), Some(temp.path().join("proj")));
+    }
+
     #[test]
     fn display_url_strips_userinfo_from_scheme_urls() {
         // Tokens ride the username slot as often as the password slot.
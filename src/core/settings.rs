@@ -14,16 +14,22 @@
 //! | `daft.checkout.upstream` | `true` | Set upstream tracking |
 //! | `daft.checkout.pushVerify` | `auto` | When the auto-upstream push runs the repo's pre-push hook (`auto`, `always` or `never`) |
 //! | `daft.remote` | `"origin"` | Default remote name |
+//! | `daft.clone.nameTemplate` | `{repo}` | Directory a clone lands in (`{repo}`, `{org}`, `{host}`) |
+//! | `daft.backup.to` | _unset_ | Default `daft backup` destination (a remote name or bundle path) |
+//! | `daft.backup.interval` | _unset_ | Back up in the background once this much time has passed (e.g. `1d`) |
 //! | `daft.checkoutBranch.carry` | `true` | Default carry for checkout-branch |
 //! | `daft.checkout.carry` | `false` | Default carry for checkout |
 //! | `daft.go.autoStart` | `false` | Auto-create worktree when branch not found in go |
-//! | `daft.prune.cdTarget` | `root` | Where to cd after pruning current worktree (`root` or `default-branch`) |
+//! | `daft.prune.afterRemoveCd` | `project-root` | Where to cd after removing the current worktree (`default-worktree`, `project-root` or `previous`; replaces `daft.prune.cdTarget`) |
 //! | `daft.list.stat` | `summary` | Default statistics mode for list command (`summary` or `lines`) |
 //! | `daft.list.sort` | `branch` | Default sort order for list command |
 //! | `daft.list.sizeConcurrency` | _auto_ | Max concurrent size walks for `--columns +size` (env `DAFT_SIZE_WALK_JOBS` overrides) |
 //! | `daft.sync.sort` | `branch` | Default sort order for sync command |
 //! | `daft.prune.sort` | `branch` | Default sort order for prune command |
 //! | `daft.updateCheck` | `true` | Enable/disable new version notifications |
+//! | `daft.updateCheck.interval` | `1d` | How often to look for a new version (a duration like `12h` or `7d`) |
+//! | `daft.ui.hints` | `true` | Print "next steps" hints after key commands |
+//! | `daft.ui.timeFormat` | `relative` | How displayed times read (`relative`, `iso`, or `locale`) |
 //! | `daft.branchDelete.remote` | `false` | Delete remote branch when removing |
 //! | `daft.ownership.strategy` | `recency-plurality` | Branch ownership detection strategy (`tip`, `any`, `first`, `plurality`, `majority`, `recency-plurality`) |
 //! | `daft.sync.pushTimeout` | `30m` | Wall-clock budget per push (git + pre-push hook); `off` disables |
@@ -40,10 +46,13 @@
 //! | `daft.hooks.enabled` | `true` | Master switch for all hooks |
 //! | `daft.hooks.defaultTrust` | `deny` | Default trust level for unknown repos |
 //! | `daft.hooks.timeout` | `300` | Timeout for hook execution in seconds |
+//! | `daft.hooks.untrustedWasm` | `false` | Run `wasm` jobs, sandboxed, in untrusted repos (experimental) |
+//! | `daft.hooks.untrustedNotice` | `true` | Print a notice when an untrusted repo's hooks are skipped |
 //! | `daft.hooks.output.quiet` | `false` | Suppress hook stdout/stderr |
 //! | `daft.hooks.output.timerDelay` | `5` | Seconds before showing elapsed timer |
 //! | `daft.hooks.output.tailLines` | `6` | Rolling output tail lines per job (0 = none) |
 //! | `daft.hooks.output.verbose` | `false` | Show skipped jobs and their reasons |
+//! | `daft.hooks.output.layout` | `stacked` | Live view of parallel jobs (`stacked`/`split`) |
 //! | `daft.hooks.<hookName>.enabled` | `true` | Enable/disable specific hook |
 //! | `daft.hooks.<hookName>.failMode` | varies | Behavior on hook failure (abort/warn) |
 //!
@@ -66,24 +75,80 @@
 use crate::core::worktree::list::Stat;
 use crate::git::GitCommand;
 use crate::hooks::{FailMode, HookConfig, HookType, HooksConfig, TrustLevel};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::path::PathBuf;
 
-/// Where to cd after pruning the user's current worktree.
+/// Where to cd after removing the worktree the user is standing in.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PruneCdTarget {
     /// CD to the project root directory.
     Root,
     /// CD to the default branch worktree directory.
     DefaultBranch,
+    /// CD to the worktree `daft go -` would return to, falling back to the
+    /// project root.
+    Previous,
 }
 
 impl PruneCdTarget {
-    /// Parse a string value into a PruneCdTarget.
+    /// Parse a string value into a PruneCdTarget. Accepts the
+    /// `daft.prune.afterRemoveCd` spellings and the older
+    /// `daft.prune.cdTarget` ones.
     pub fn parse(value: &str) -> Option<Self> {
         match value.to_lowercase().as_str() {
-            "root" => Some(Self::Root),
-            "default-branch" => Some(Self::DefaultBranch),
+            "project-root" | "root" => Some(Self::Root),
+            "default-worktree" | "default-branch" => Some(Self::DefaultBranch),
+            "previous" => Some(Self::Previous),
+            _ => None,
+        }
+    }
+}
+
+/// What `daft clone` and `daft init` do when the new project would land
+/// inside another repository or worktree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NestedProjects {
+    /// Create the project, but warn about the enclosing one.
+    Warn,
+    /// Refuse to create the project.
+    Deny,
+    /// Create the project silently.
+    Allow,
+}
+
+impl NestedProjects {
+    /// Parse a `daft.nestedProjects` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            "allow" => Some(Self::Allow),
+            _ => None,
+        }
+    }
+}
+
+/// How human-facing timestamps read (`daft.ui.timeFormat`). Structured
+/// output is unaffected: it always carries RFC 3339 strings and epochs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeFormat {
+    /// Ages relative to now (`3d`, `2h ago`).
+    #[default]
+    Relative,
+    /// ISO 8601 in the local timezone (`2026-10-17T14:03:00+02:00`).
+    Iso,
+    /// Date and time in the order of the user's locale (`LC_ALL`, `LC_TIME`,
+    /// `LANG`), in the local timezone.
+    Locale,
+}
+
+impl TimeFormat {
+    /// Parse a `daft.ui.timeFormat` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "relative" => Some(Self::Relative),
+            "iso" => Some(Self::Iso),
+            "locale" => Some(Self::Locale),
             _ => None,
         }
     }
@@ -251,7 +316,8 @@ impl MemoryReserve {
     }
 }
 
-/// Parse `daft.sync.pushTimeout`: `off`/`0` disables (outer `Some(None)`);
+/// Parse `daft.sync.pushTimeout` (and `daft.backup.interval`, which shares
+/// the syntax): `off`/`0` disables (outer `Some(None)`);
 /// otherwise a positive duration with an optional case-insensitive
 /// `s`/`m`/`h`/`d` suffix (bare numbers are seconds). Outer `None` =
 /// unparseable (caller warns and keeps the default) — including an
@@ -281,7 +347,8 @@ pub fn parse_push_timeout(value: &str) -> Option<Option<std::time::Duration>> {
 /// Default values for settings.
 pub mod defaults {
     use super::{
-        GovernorJobs, GovernorMode, MemoryReserve, PruneCdTarget, PushHookStrategy, PushVerify,
+        GovernorJobs, GovernorMode, MemoryReserve, NestedProjects, PruneCdTarget, PushHookStrategy,
+        PushVerify, TimeFormat,
     };
     use crate::core::worktree::list::Stat;
 
@@ -341,7 +408,7 @@ pub mod defaults {
     /// Default value for multiRemote.defaultRemote setting.
     pub const MULTI_REMOTE_DEFAULT_REMOTE: &str = "origin";
 
-    /// Default value for prune.cdTarget setting.
+    /// Default value for prune.afterRemoveCd setting.
     pub const PRUNE_CD_TARGET: PruneCdTarget = PruneCdTarget::Root;
 
     /// Default value for the `daft.gitoxide` setting. Stable default
@@ -349,12 +416,24 @@ pub mod defaults {
     /// the key is an opt-out — `false` forces the git-subprocess backend.
     pub const USE_GITOXIDE: bool = true;
 
+    /// Default value for clone.nameTemplate setting.
+    pub const CLONE_NAME_TEMPLATE: &str = "{repo}";
+
+    /// Default for `daft.nestedProjects`.
+    pub const NESTED_PROJECTS: NestedProjects = NestedProjects::Warn;
+
     /// Default value for go.autoStart setting.
     pub const GO_AUTO_START: bool = false;
 
     /// Default value for go.fetchOnMiss setting.
     pub const GO_FETCH_ON_MISS: bool = true;
 
+    /// Default value for ui.hints setting.
+    pub const UI_HINTS: bool = true;
+
+    /// Default value for ui.timeFormat setting.
+    pub const UI_TIME_FORMAT: TimeFormat = TimeFormat::Relative;
+
     /// Default value for list.stat setting.
     pub const LIST_STAT: Stat = Stat::Summary;
 
@@ -451,6 +530,10 @@ pub mod keys {
     /// Deprecated config key for update.args (migration fallback).
     pub const FETCH_ARGS_DEPRECATED: &str = "daft.fetch.args";
 
+    /// Config key listing deprecation-warning ids to silence
+    /// (`hook-rename`, `config-rename`, `flag`, `command`, or `all`).
+    pub const SUPPRESS_WARNINGS: &str = "daft.suppressWarnings";
+
     /// Multi-remote config keys.
     pub mod multi_remote {
         /// Config key for multiRemote.enabled setting.
@@ -460,18 +543,42 @@ pub mod keys {
         pub const DEFAULT_REMOTE: &str = "daft.multiRemote.defaultRemote";
     }
 
-    /// Config key for prune.cdTarget setting.
-    pub const PRUNE_CD_TARGET: &str = "daft.prune.cdTarget";
+    /// Config key for prune.afterRemoveCd setting.
+    pub const PRUNE_CD_TARGET: &str = "daft.prune.afterRemoveCd";
+
+    /// Deprecated config key for prune.afterRemoveCd (was daft.prune.cdTarget).
+    pub const PRUNE_CD_TARGET_DEPRECATED: &str = "daft.prune.cdTarget";
 
     /// Config key for updateCheck setting.
     pub const UPDATE_CHECK: &str = "daft.updateCheck";
 
+    /// Config key for updateCheck.interval setting.
+    pub const UPDATE_CHECK_INTERVAL: &str = "daft.updateCheck.interval";
+
+    /// Config key for clone.nameTemplate setting.
+    pub const CLONE_NAME_TEMPLATE: &str = "daft.clone.nameTemplate";
+
+    /// Whether clone/init may create a project inside another repository.
+    pub const NESTED_PROJECTS: &str = "daft.nestedProjects";
+
+    /// Config key for backup.to setting.
+    pub const BACKUP_TO: &str = "daft.backup.to";
+
+    /// Config key for backup.interval setting.
+    pub const BACKUP_INTERVAL: &str = "daft.backup.interval";
+
     /// Config key for go.autoStart setting.
     pub const GO_AUTO_START: &str = "daft.go.autoStart";
 
     /// Config key for go.fetchOnMiss setting.
     pub const GO_FETCH_ON_MISS: &str = "daft.go.fetchOnMiss";
 
+    /// Config key for ui.hints setting.
+    pub const UI_HINTS: &str = "daft.ui.hints";
+
+    /// Config key for ui.timeFormat setting.
+    pub const UI_TIME_FORMAT: &str = "daft.ui.timeFormat";
+
     /// Config key for list.stat setting.
     pub const LIST_STAT: &str = "daft.list.stat";
 
@@ -598,15 +705,42 @@ pub mod keys {
         /// Config key for hooks.output.verbose setting.
         pub const OUTPUT_VERBOSE: &str = "daft.hooks.output.verbose";
 
+        /// Config key for hooks.output.layout setting.
+        pub const OUTPUT_LAYOUT: &str = "daft.hooks.output.layout";
+
         /// Config key for hooks.trustPrune setting (auto-prune stale trust entries).
         pub const TRUST_PRUNE: &str = "daft.hooks.trustPrune";
 
+        /// Config key for hooks.executeDeprecated setting (run hooks found
+        /// only under their pre-`worktree-` filenames).
+        pub const EXECUTE_DEPRECATED: &str = "daft.hooks.executeDeprecated";
+
+        /// Config key for hooks.untrustedWasm setting (run a hook's `wasm`
+        /// jobs, sandboxed, in repositories that aren't trusted).
+        pub const UNTRUSTED_WASM: &str = "daft.hooks.untrustedWasm";
+
+        /// Config key for hooks.untrustedNotice setting (the notice printed
+        /// when an untrusted repository's hooks are skipped).
+        pub const UNTRUSTED_NOTICE: &str = "daft.hooks.untrustedNotice";
+
         /// Generate a config key for a hook-specific setting.
         pub fn hook_key(hook_name: &str, setting: &str) -> String {
             format!("daft.hooks.{hook_name}.{setting}")
         }
     }
 
+    /// shell-init config keys.
+    pub mod shell_init {
+        /// Config key for the components `daft shell-init` emits.
+        pub const COMPONENTS: &str = "daft.shellInit.components";
+
+        /// Per-shell override of [`COMPONENTS`], e.g.
+        /// `daft.shellInit.zsh.components`.
+        pub fn components_key(shell: &str) -> String {
+            format!("daft.shellInit.{shell}.components")
+        }
+    }
+
     /// Completions config keys.
     pub mod completions {
         /// Config key for completions.branches.columns setting.
@@ -657,7 +791,7 @@ pub struct DaftSettings {
     /// Default carry setting for checkout command.
     pub checkout_carry: bool,
 
-    /// Where to cd after pruning the user's current worktree.
+    /// Where to cd after removing the user's current worktree.
     pub prune_cd_target: PruneCdTarget,
 
     /// Default arguments for git pull in update command (same-branch mode).
@@ -673,6 +807,18 @@ pub struct DaftSettings {
     /// `daft.gitoxide = false` opts out to the git-subprocess backend.
     pub use_gitoxide: bool,
 
+    /// Directory template for new clones (`{repo}`, `{org}`, `{host}`).
+    pub clone_name_template: String,
+
+    /// What to do when a new project would be nested in another repository.
+    pub nested_projects: NestedProjects,
+
+    /// Default `daft backup` destination: a remote name or a bundle path.
+    pub backup_to: Option<String>,
+
+    /// How often the background backup runs; `None` = only on demand.
+    pub backup_interval: Option<std::time::Duration>,
+
     /// Automatically create worktree when branch not found in go command.
     pub go_auto_start: bool,
 
@@ -680,6 +826,13 @@ pub struct DaftSettings {
     /// prefix has no local matches. Controlled by `daft.go.fetchOnMiss`.
     pub go_fetch_on_miss: bool,
 
+    /// Print "next steps" hints after key commands. Controlled by
+    /// `daft.ui.hints`.
+    pub ui_hints: bool,
+
+    /// How displayed times read. Controlled by `daft.ui.timeFormat`.
+    pub ui_time_format: TimeFormat,
+
     /// Default statistics mode for list command.
     pub list_stat: Stat,
 
@@ -807,8 +960,14 @@ impl Default for DaftSettings {
             multi_remote_enabled: defaults::MULTI_REMOTE_ENABLED,
             multi_remote_default: defaults::MULTI_REMOTE_DEFAULT_REMOTE.to_string(),
             use_gitoxide: defaults::USE_GITOXIDE,
+            clone_name_template: defaults::CLONE_NAME_TEMPLATE.to_string(),
+            nested_projects: defaults::NESTED_PROJECTS,
+            backup_to: None,
+            backup_interval: None,
             go_auto_start: defaults::GO_AUTO_START,
             go_fetch_on_miss: defaults::GO_FETCH_ON_MISS,
+            ui_hints: defaults::UI_HINTS,
+            ui_time_format: defaults::UI_TIME_FORMAT,
             list_stat: defaults::LIST_STAT,
             sync_stat: defaults::SYNC_STAT,
             prune_stat: defaults::PRUNE_STAT,
@@ -902,15 +1061,26 @@ impl DaftSettings {
             settings.checkout_carry = parse_bool(&value, defaults::CHECKOUT_CARRY);
         }
 
-        if let Some(value) = git.config_get(keys::PRUNE_CD_TARGET)?
-            && let Some(target) = PruneCdTarget::parse(&value)
-        {
+        // Try new key first, fall back to deprecated key for migration
+        let prune_cd_value = match git.config_get(keys::PRUNE_CD_TARGET)? {
+            Some(value) => Some(value),
+            None => git
+                .config_get(keys::PRUNE_CD_TARGET_DEPRECATED)?
+                .inspect(|_| {
+                    record_deprecated_key(keys::PRUNE_CD_TARGET_DEPRECATED, keys::PRUNE_CD_TARGET)
+                }),
+        };
+        if let Some(target) = prune_cd_value.as_deref().and_then(PruneCdTarget::parse) {
             settings.prune_cd_target = target;
         }
 
         // Try new key first, fall back to deprecated key for migration
-        let update_args_value = git.config_get(keys::UPDATE_ARGS)?;
-        let update_args_value = update_args_value.or(git.config_get(keys::FETCH_ARGS_DEPRECATED)?);
+        let update_args_value = match git.config_get(keys::UPDATE_ARGS)? {
+            Some(value) => Some(value),
+            None => git
+                .config_get(keys::FETCH_ARGS_DEPRECATED)?
+                .inspect(|_| record_deprecated_key(keys::FETCH_ARGS_DEPRECATED, keys::UPDATE_ARGS)),
+        };
         if let Some(value) = update_args_value
             && !value.is_empty()
         {
@@ -931,6 +1101,39 @@ impl DaftSettings {
             settings.use_gitoxide = parse_bool(&value, defaults::USE_GITOXIDE);
         }
 
+        if let Some(value) = git.config_get(keys::CLONE_NAME_TEMPLATE)?
+            && !value.is_empty()
+        {
+            settings.clone_name_template = value;
+        }
+
+        if let Some(policy) = git
+            .config_get(keys::NESTED_PROJECTS)?
+            .as_deref()
+            .and_then(NestedProjects::parse)
+        {
+            settings.nested_projects = policy;
+        }
+
+        if let Some(value) = git.config_get(keys::BACKUP_TO)?
+            && !value.is_empty()
+        {
+            settings.backup_to = Some(value);
+        }
+
+        if let Some(value) = git.config_get(keys::BACKUP_INTERVAL)?
+            && !value.is_empty()
+        {
+            match parse_push_timeout(&value) {
+                Some(interval) => settings.backup_interval = interval,
+                None => eprintln!(
+                    "daft: unknown value for {}: {:?} — using default",
+                    keys::BACKUP_INTERVAL,
+                    value
+                ),
+            }
+        }
+
         if let Some(value) = git.config_get(keys::GO_AUTO_START)? {
             settings.go_auto_start = parse_bool(&value, defaults::GO_AUTO_START);
         }
@@ -939,6 +1142,16 @@ impl DaftSettings {
             settings.go_fetch_on_miss = parse_bool(&value, defaults::GO_FETCH_ON_MISS);
         }
 
+        if let Some(value) = git.config_get(keys::UI_HINTS)? {
+            settings.ui_hints = parse_bool(&value, defaults::UI_HINTS);
+        }
+
+        if let Some(value) = git.config_get(keys::UI_TIME_FORMAT)?
+            && let Some(format) = TimeFormat::parse(&value)
+        {
+            settings.ui_time_format = format;
+        }
+
         if let Some(value) = git.config_get(keys::LIST_STAT)?
             && let Some(stat) = Stat::parse(&value)
         {
@@ -1155,16 +1368,26 @@ impl DaftSettings {
             settings.checkout_carry = parse_bool(&value, defaults::CHECKOUT_CARRY);
         }
 
-        if let Some(value) = git.config_get_global(keys::PRUNE_CD_TARGET)?
-            && let Some(target) = PruneCdTarget::parse(&value)
-        {
+        // Try new key first, fall back to deprecated key for migration
+        let prune_cd_value = match git.config_get_global(keys::PRUNE_CD_TARGET)? {
+            Some(value) => Some(value),
+            None => git
+                .config_get_global(keys::PRUNE_CD_TARGET_DEPRECATED)?
+                .inspect(|_| {
+                    record_deprecated_key(keys::PRUNE_CD_TARGET_DEPRECATED, keys::PRUNE_CD_TARGET)
+                }),
+        };
+        if let Some(target) = prune_cd_value.as_deref().and_then(PruneCdTarget::parse) {
             settings.prune_cd_target = target;
         }
 
         // Try new key first, fall back to deprecated key for migration
-        let update_args_value = git.config_get_global(keys::UPDATE_ARGS)?;
-        let update_args_value =
-            update_args_value.or(git.config_get_global(keys::FETCH_ARGS_DEPRECATED)?);
+        let update_args_value = match git.config_get_global(keys::UPDATE_ARGS)? {
+            Some(value) => Some(value),
+            None => git
+                .config_get_global(keys::FETCH_ARGS_DEPRECATED)?
+                .inspect(|_| record_deprecated_key(keys::FETCH_ARGS_DEPRECATED, keys::UPDATE_ARGS)),
+        };
         if let Some(value) = update_args_value
             && !value.is_empty()
         {
@@ -1185,6 +1408,39 @@ impl DaftSettings {
             settings.use_gitoxide = parse_bool(&value, defaults::USE_GITOXIDE);
         }
 
+        if let Some(value) = git.config_get_global(keys::CLONE_NAME_TEMPLATE)?
+            && !value.is_empty()
+        {
+            settings.clone_name_template = value;
+        }
+
+        if let Some(policy) = git
+            .config_get_global(keys::NESTED_PROJECTS)?
+            .as_deref()
+            .and_then(NestedProjects::parse)
+        {
+            settings.nested_projects = policy;
+        }
+
+        if let Some(value) = git.config_get_global(keys::BACKUP_TO)?
+            && !value.is_empty()
+        {
+            settings.backup_to = Some(value);
+        }
+
+        if let Some(value) = git.config_get_global(keys::BACKUP_INTERVAL)?
+            && !value.is_empty()
+        {
+            match parse_push_timeout(&value) {
+                Some(interval) => settings.backup_interval = interval,
+                None => eprintln!(
+                    "daft: unknown value for {}: {:?} — using default",
+                    keys::BACKUP_INTERVAL,
+                    value
+                ),
+            }
+        }
+
         if let Some(value) = git.config_get_global(keys::GO_AUTO_START)? {
             settings.go_auto_start = parse_bool(&value, defaults::GO_AUTO_START);
         }
@@ -1193,6 +1449,16 @@ impl DaftSettings {
             settings.go_fetch_on_miss = parse_bool(&value, defaults::GO_FETCH_ON_MISS);
         }
 
+        if let Some(value) = git.config_get_global(keys::UI_HINTS)? {
+            settings.ui_hints = parse_bool(&value, defaults::UI_HINTS);
+        }
+
+        if let Some(value) = git.config_get_global(keys::UI_TIME_FORMAT)?
+            && let Some(format) = TimeFormat::parse(&value)
+        {
+            settings.ui_time_format = format;
+        }
+
         if let Some(value) = git.config_get_global(keys::LIST_STAT)?
             && let Some(stat) = Stat::parse(&value)
         {
@@ -1436,6 +1702,78 @@ pub(crate) fn parse_bool(value: &str, default: bool) -> bool {
     }
 }
 
+/// Expand a path-valued setting: a leading `~`, `$VAR` / `${VAR}`, and
+/// `%VAR%` (on every platform, so a config shared between Windows and Unix
+/// machines reads the same everywhere).
+///
+/// Errors name `key` — the config key or flag the value came from — so a
+/// typo is reported where it was made rather than by whatever filesystem
+/// call later trips over the unexpanded path.
+pub fn expand_path(key: &str, value: &str) -> Result<PathBuf> {
+    expand_path_with(key, value, dirs::home_dir(), |name| {
+        std::env::var(name).ok()
+    })
+}
+
+fn expand_path_with(
+    key: &str,
+    value: &str,
+    home: Option<PathBuf>,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<PathBuf> {
+    if value.trim().is_empty() {
+        anyhow::bail!("{key} is set to an empty path");
+    }
+    let lookup = |name: &str| {
+        var(name).with_context(|| {
+            format!("{key}: environment variable '{name}' in '{value}' is not set")
+        })
+    };
+
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    if let Some(after) = rest.strip_prefix('~')
+        && (after.is_empty() || after.starts_with(['/', '\\']))
+    {
+        let home = home
+            .with_context(|| format!("{key}: cannot expand '~' in '{value}': no home directory"))?;
+        out.push_str(&home.to_string_lossy());
+        rest = after;
+    }
+
+    while let Some(pos) = rest.find(['$', '%']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        let is_name = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        if let Some(braced) = tail.strip_prefix("${") {
+            let end = braced
+                .find('}')
+                .with_context(|| format!("{key}: unclosed '${{' in '{value}'"))?;
+            out.push_str(&lookup(&braced[..end])?);
+            rest = &braced[end + 1..];
+        } else if let Some(after) = tail.strip_prefix('$')
+            && after.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        {
+            let end = after.find(|c: char| !is_name(c)).unwrap_or(after.len());
+            out.push_str(&lookup(&after[..end])?);
+            rest = &after[end..];
+        } else if let Some(after) = tail.strip_prefix('%')
+            && let Some(end) = after.find('%')
+            && end > 0
+            && after[..end].chars().all(is_name)
+        {
+            out.push_str(&lookup(&after[..end])?);
+            rest = &after[end + 1..];
+        } else {
+            // A lone `$` or `%` is literal.
+            out.push_str(&tail[..1]);
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    Ok(PathBuf::from(out))
+}
+
 /// Parse one pushVerify config value, warning (and yielding `None`) on an
 /// unknown spelling so the caller falls back to the resolution defaults.
 ///
@@ -1504,6 +1842,28 @@ fn resolve_push_verify(
     }
 }
 
+/// How the interactive hook renderer lays out jobs that run at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HookOutputLayout {
+    /// One spinner per job with a few rolling tail lines under it.
+    #[default]
+    Stacked,
+    /// A fixed region per running job, with keys to expand one job's full
+    /// output live.
+    Split,
+}
+
+impl HookOutputLayout {
+    /// Parse a `daft.hooks.output.layout` value.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "stacked" => Some(Self::Stacked),
+            "split" => Some(Self::Split),
+            _ => None,
+        }
+    }
+}
+
 /// Configuration for hook output display.
 #[derive(Debug, Clone)]
 pub struct HookOutputConfig {
@@ -1523,6 +1883,8 @@ pub struct HookOutputConfig {
     /// default `"daft hooks"`; `daft run` sets `"daft run"` so a task doesn't
     /// mislabel itself as a hook.
     pub banner: &'static str,
+    /// Live layout for jobs running in parallel on a terminal.
+    pub layout: HookOutputLayout,
 }
 
 impl Default for HookOutputConfig {
@@ -1534,6 +1896,7 @@ impl Default for HookOutputConfig {
             verbose: false,
             compact_finalization: false,
             banner: "daft hooks",
+            layout: HookOutputLayout::Stacked,
         }
     }
 }
@@ -1577,17 +1940,7 @@ pub fn load_hooks_config_with(git: &GitCommand) -> Result<HooksConfig> {
     if let Some(value) = git.config_get(keys::hooks::USER_DIRECTORY)?
         && !value.is_empty()
     {
-        // Expand ~ to home directory
-        let expanded = if let Some(stripped) = value.strip_prefix("~/") {
-            if let Some(home) = dirs::home_dir() {
-                home.join(stripped)
-            } else {
-                PathBuf::from(&value)
-            }
-        } else {
-            PathBuf::from(&value)
-        };
-        config.user_directory = expanded;
+        config.user_directory = expand_path(keys::hooks::USER_DIRECTORY, &value)?;
     }
 
     if let Some(value) = git.config_get(keys::hooks::TIMEOUT)?
@@ -1596,6 +1949,18 @@ pub fn load_hooks_config_with(git: &GitCommand) -> Result<HooksConfig> {
         config.timeout_seconds = timeout;
     }
 
+    if let Some(value) = git.config_get(keys::hooks::EXECUTE_DEPRECATED)? {
+        config.execute_deprecated = parse_bool(&value, true);
+    }
+
+    if let Some(value) = git.config_get(keys::hooks::UNTRUSTED_WASM)? {
+        config.untrusted_wasm = parse_bool(&value, false);
+    }
+
+    if let Some(value) = git.config_get(keys::hooks::UNTRUSTED_NOTICE)? {
+        config.untrusted_notice = parse_bool(&value, true);
+    }
+
     // Load output settings
     if let Some(value) = git.config_get(keys::hooks::OUTPUT_QUIET)? {
         config.output.quiet = parse_bool(&value, false);
@@ -1613,6 +1978,11 @@ pub fn load_hooks_config_with(git: &GitCommand) -> Result<HooksConfig> {
     if let Some(value) = git.config_get(keys::hooks::OUTPUT_VERBOSE)? {
         config.output.verbose = parse_bool(&value, false);
     }
+    if let Some(value) = git.config_get(keys::hooks::OUTPUT_LAYOUT)?
+        && let Some(layout) = HookOutputLayout::parse(&value)
+    {
+        config.output.layout = layout;
+    }
 
     // Load per-hook settings
     for hook_type in HookType::all() {
@@ -1644,16 +2014,7 @@ pub fn load_hooks_config_global() -> Result<HooksConfig> {
     if let Some(value) = git.config_get_global(keys::hooks::USER_DIRECTORY)?
         && !value.is_empty()
     {
-        let expanded = if let Some(stripped) = value.strip_prefix("~/") {
-            if let Some(home) = dirs::home_dir() {
-                home.join(stripped)
-            } else {
-                PathBuf::from(&value)
-            }
-        } else {
-            PathBuf::from(&value)
-        };
-        config.user_directory = expanded;
+        config.user_directory = expand_path(keys::hooks::USER_DIRECTORY, &value)?;
     }
 
     if let Some(value) = git.config_get_global(keys::hooks::TIMEOUT)?
@@ -1662,6 +2023,18 @@ pub fn load_hooks_config_global() -> Result<HooksConfig> {
         config.timeout_seconds = timeout;
     }
 
+    if let Some(value) = git.config_get_global(keys::hooks::EXECUTE_DEPRECATED)? {
+        config.execute_deprecated = parse_bool(&value, true);
+    }
+
+    if let Some(value) = git.config_get_global(keys::hooks::UNTRUSTED_WASM)? {
+        config.untrusted_wasm = parse_bool(&value, false);
+    }
+
+    if let Some(value) = git.config_get_global(keys::hooks::UNTRUSTED_NOTICE)? {
+        config.untrusted_notice = parse_bool(&value, true);
+    }
+
     // Load output settings
     if let Some(value) = git.config_get_global(keys::hooks::OUTPUT_QUIET)? {
         config.output.quiet = parse_bool(&value, false);
@@ -1679,6 +2052,11 @@ pub fn load_hooks_config_global() -> Result<HooksConfig> {
     if let Some(value) = git.config_get_global(keys::hooks::OUTPUT_VERBOSE)? {
         config.output.verbose = parse_bool(&value, false);
     }
+    if let Some(value) = git.config_get_global(keys::hooks::OUTPUT_LAYOUT)?
+        && let Some(layout) = HookOutputLayout::parse(&value)
+    {
+        config.output.layout = layout;
+    }
 
     // Load per-hook settings from global config
     for hook_type in HookType::all() {
@@ -1689,6 +2067,24 @@ pub fn load_hooks_config_global() -> Result<HooksConfig> {
     Ok(config)
 }
 
+/// Record that a renamed config key was read under its old name.
+fn record_deprecated_key(old: &str, new: &str) {
+    crate::deprecations::record(
+        crate::deprecations::DeprecationKind::ConfigRename,
+        old,
+        format!("config key '{old}' is deprecated; rename it to '{new}'"),
+    );
+}
+
+/// Record that a hook setting was read under its pre-`worktree-` key.
+fn record_deprecated_hook_key(old: &str, new: &str) {
+    crate::deprecations::record(
+        crate::deprecations::DeprecationKind::HookRename,
+        old,
+        format!("config key '{old}' is deprecated; rename it to '{new}'"),
+    );
+}
+
 /// Load configuration for a specific hook type.
 ///
 /// Falls back to deprecated config keys if the new key is not found.
@@ -1703,7 +2099,15 @@ fn load_hook_type_config(
         hook_type.deprecated_config_key(),
     ) {
         (Some(v), _) => Some(v),
-        (None, Some(dep)) => git.config_get(&keys::hooks::hook_key(dep, "enabled"))?,
+        (None, Some(dep)) => {
+            let dep_key = keys::hooks::hook_key(dep, "enabled");
+            git.config_get(&dep_key)?.inspect(|_| {
+                record_deprecated_hook_key(
+                    &dep_key,
+                    &keys::hooks::hook_key(hook_type.config_key(), "enabled"),
+                )
+            })
+        }
         (None, None) => None,
     };
     if let Some(value) = enabled_value {
@@ -1716,7 +2120,15 @@ fn load_hook_type_config(
         hook_type.deprecated_config_key(),
     ) {
         (Some(v), _) => Some(v),
-        (None, Some(dep)) => git.config_get(&keys::hooks::hook_key(dep, "failMode"))?,
+        (None, Some(dep)) => {
+            let dep_key = keys::hooks::hook_key(dep, "failMode");
+            git.config_get(&dep_key)?.inspect(|_| {
+                record_deprecated_hook_key(
+                    &dep_key,
+                    &keys::hooks::hook_key(hook_type.config_key(), "failMode"),
+                )
+            })
+        }
         (None, None) => None,
     };
     if let Some(value) = fail_mode_value
@@ -1742,7 +2154,15 @@ fn load_hook_type_config_global(
         hook_type.deprecated_config_key(),
     ) {
         (Some(v), _) => Some(v),
-        (None, Some(dep)) => git.config_get_global(&keys::hooks::hook_key(dep, "enabled"))?,
+        (None, Some(dep)) => {
+            let dep_key = keys::hooks::hook_key(dep, "enabled");
+            git.config_get_global(&dep_key)?.inspect(|_| {
+                record_deprecated_hook_key(
+                    &dep_key,
+                    &keys::hooks::hook_key(hook_type.config_key(), "enabled"),
+                )
+            })
+        }
         (None, None) => None,
     };
     if let Some(value) = enabled_value {
@@ -1755,7 +2175,15 @@ fn load_hook_type_config_global(
         hook_type.deprecated_config_key(),
     ) {
         (Some(v), _) => Some(v),
-        (None, Some(dep)) => git.config_get_global(&keys::hooks::hook_key(dep, "failMode"))?,
+        (None, Some(dep)) => {
+            let dep_key = keys::hooks::hook_key(dep, "failMode");
+            git.config_get_global(&dep_key)?.inspect(|_| {
+                record_deprecated_hook_key(
+                    &dep_key,
+                    &keys::hooks::hook_key(hook_type.config_key(), "failMode"),
+                )
+            })
+        }
         (None, None) => None,
     };
     if let Some(value) = fail_mode_value
@@ -1787,6 +2215,10 @@ mod tests {
         assert_eq!(settings.multi_remote_default, "origin");
         assert!(settings.use_gitoxide);
         assert!(!settings.go_auto_start);
+        assert_eq!(settings.clone_name_template, "{repo}");
+        assert_eq!(settings.nested_projects, NestedProjects::Warn);
+        assert!(settings.backup_to.is_none());
+        assert!(settings.backup_interval.is_none());
         assert_eq!(settings.list_stat, Stat::Summary);
         assert!(!settings.branch_delete_remote);
         assert_eq!(
@@ -2112,10 +2544,72 @@ mod tests {
             PruneCdTarget::parse("Default-Branch"),
             Some(PruneCdTarget::DefaultBranch)
         );
+        assert_eq!(
+            PruneCdTarget::parse("project-root"),
+            Some(PruneCdTarget::Root)
+        );
+        assert_eq!(
+            PruneCdTarget::parse("default-worktree"),
+            Some(PruneCdTarget::DefaultBranch)
+        );
+        assert_eq!(
+            PruneCdTarget::parse("previous"),
+            Some(PruneCdTarget::Previous)
+        );
         assert_eq!(PruneCdTarget::parse("invalid"), None);
         assert_eq!(PruneCdTarget::parse(""), None);
     }
 
+    #[test]
+    fn test_expand_path_home_and_variables() {
+        let home = Some(PathBuf::from("/home/ana"));
+        let var = |name: &str| match name {
+            "XDG_DATA_HOME" => Some("/data".to_string()),
+            "USERPROFILE" => Some("C:\\Users\\ana".to_string()),
+            _ => None,
+        };
+        let expand = |value: &str| expand_path_with("daft.test", value, home.clone(), var);
+
+        assert_eq!(expand("~/hooks").unwrap(), PathBuf::from("/home/ana/hooks"));
+        assert_eq!(expand("~").unwrap(), PathBuf::from("/home/ana"));
+        assert_eq!(expand("~ana/x").unwrap(), PathBuf::from("~ana/x"));
+        assert_eq!(
+            expand("$XDG_DATA_HOME/daft").unwrap(),
+            PathBuf::from("/data/daft")
+        );
+        assert_eq!(
+            expand("${XDG_DATA_HOME}daft").unwrap(),
+            PathBuf::from("/datadaft")
+        );
+        assert_eq!(
+            expand("%USERPROFILE%\\hooks").unwrap(),
+            PathBuf::from("C:\\Users\\ana\\hooks")
+        );
+        assert_eq!(expand("/a/100%/$5").unwrap(), PathBuf::from("/a/100%/$5"));
+    }
+
+    #[test]
+    fn test_expand_path_errors_name_the_key() {
+        let expand = |value: &str| {
+            expand_path_with("daft.hooks.userDirectory", value, None, |_| None)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(expand("$NOPE/x").contains("daft.hooks.userDirectory"));
+        assert!(expand("$NOPE/x").contains("'NOPE'"));
+        assert!(expand("${NOPE").contains("unclosed"));
+        assert!(expand("~/x").contains("no home directory"));
+        assert!(expand("  ").contains("empty path"));
+    }
+
+    #[test]
+    fn test_nested_projects_parse() {
+        assert_eq!(NestedProjects::parse("warn"), Some(NestedProjects::Warn));
+        assert_eq!(NestedProjects::parse("Deny"), Some(NestedProjects::Deny));
+        assert_eq!(NestedProjects::parse("ALLOW"), Some(NestedProjects::Allow));
+        assert_eq!(NestedProjects::parse("error"), None);
+    }
+
     #[test]
     fn test_push_verify_parse() {
         assert_eq!(PushVerify::parse("auto"), Some(PushVerify::Auto));
@@ -2181,6 +2675,20 @@ mod tests {
         assert!(!config.quiet);
         assert_eq!(config.timer_delay_secs, 5);
         assert_eq!(config.tail_lines, 6);
+        assert_eq!(config.layout, HookOutputLayout::Stacked);
+    }
+
+    #[test]
+    fn test_hook_output_layout_parse() {
+        assert_eq!(
+            HookOutputLayout::parse("split"),
+            Some(HookOutputLayout::Split)
+        );
+        assert_eq!(
+            HookOutputLayout::parse("Stacked"),
+            Some(HookOutputLayout::Stacked)
+        );
+        assert_eq!(HookOutputLayout::parse("grid"), None);
     }
 
     #[test]
@@ -2201,6 +2709,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_settings_show_hints() {
+        assert!(DaftSettings::default().ui_hints);
+    }
+
+    #[test]
+    fn time_format_parses_case_insensitively() {
+        assert_eq!(DaftSettings::default().ui_time_format, TimeFormat::Relative);
+        assert_eq!(TimeFormat::parse("ISO"), Some(TimeFormat::Iso));
+        assert_eq!(TimeFormat::parse("locale"), Some(TimeFormat::Locale));
+        assert_eq!(TimeFormat::parse("epoch"), None);
+    }
+
     #[test]
     fn default_ownership_strategy_is_recency_plurality() {
         let settings = DaftSettings::default();
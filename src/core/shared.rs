@@ -286,6 +286,13 @@ pub fn read_shared_paths(worktree_root: &Path) -> Result<Vec<String>> {
     Ok(config.and_then(|c| c.shared).unwrap_or_default())
 }
 
+/// Read the `template:` list from daft.yml, with the same fallback as
+/// [`read_shared_paths`].
+pub fn read_template_paths(worktree_root: &Path) -> Result<Vec<String>> {
+    let config = load_yaml_config_with_fallback(worktree_root)?;
+    Ok(config.and_then(|c| c.template).unwrap_or_default())
+}
+
 /// Read the `shared:` list from a committed daft config blob
 /// (`<reference>:daft.yml` and the other accepted names), for probing before
 /// any worktree exists — clone plans its shared section right after the bare
@@ -116,7 +116,8 @@ impl StageId {
     }
 
     /// The plan stage a lifecycle hook renders as. `None` for hook types the
-    /// timeline never plans (merge hooks — merge keeps its own output).
+    /// timeline never plans (merge hooks — merge keeps its own output — and
+    /// post-fetch, which runs outside any creation or removal plan).
     pub fn for_hook_type(hook_type: crate::hooks::HookType) -> Option<Self> {
         use crate::hooks::HookType;
         match hook_type {
@@ -125,7 +126,7 @@ impl StageId {
             HookType::PreRemove => Some(Self::PreRemoveHooks),
             HookType::PostRemove => Some(Self::PostRemoveHooks),
             HookType::PostClone => Some(Self::PostCloneHooks),
-            HookType::PreMerge | HookType::PostMerge => None,
+            HookType::PreMerge | HookType::PostMerge | HookType::PostFetch => None,
         }
     }
 }
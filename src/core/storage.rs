@@ -0,0 +1,310 @@
+//! Disk accounting behind `daft stats storage`.
+//!
+//! Every worktree of a project reads objects from one store in the git common
+//! directory, so N checkouts cost one copy of the history instead of N. The
+//! report measures that store, estimates what the same checkouts would take
+//! as independent clones, and checks that the sharing actually holds:
+//!
+//! - each worktree's `.git` file leads to an admin directory whose
+//!   `commondir` resolves back to this store;
+//! - every path in `objects/info/alternates` still exists;
+//! - no worktree, and no directory elsewhere in the project, carries a full
+//!   `.git` directory of its own (a copied checkout or a stray clone).
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::core::size_walk;
+use crate::core::worktree::porcelain::parse_worktree_list_porcelain;
+use crate::utils::git_command_at;
+
+/// How deep below the project root stray `.git` directories are looked for.
+/// Deep enough for `<root>/feature/x/.git`.
+const STRAY_SCAN_DEPTH: usize = 3;
+
+/// Whether a worktree reads from the shared store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkState {
+    /// Linked to the shared store (or is the store's own checkout).
+    Shared,
+    /// The link is broken; the reason says where.
+    Broken(String),
+    /// The worktree has a full `.git` directory of this many bytes.
+    OwnGitDir(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeStorage {
+    pub path: PathBuf,
+    pub branch: Option<String>,
+    pub link: LinkState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alternate {
+    pub path: PathBuf,
+    pub exists: bool,
+}
+
+/// A directory in the project with its own `.git` directory that is not a
+/// registered worktree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrayClone {
+    pub path: PathBuf,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageReport {
+    pub common_dir: PathBuf,
+    /// Size of `<common-dir>/objects`.
+    pub object_bytes: u64,
+    pub worktrees: Vec<WorktreeStorage>,
+    pub alternates: Vec<Alternate>,
+    pub strays: Vec<StrayClone>,
+}
+
+impl StorageReport {
+    /// Measure the project whose common directory is `common_dir` and whose
+    /// worktrees live under `project_root`.
+    pub fn collect(common_dir: &Path, project_root: &Path) -> Result<Self> {
+        let common_dir = common_dir
+            .canonicalize()
+            .with_context(|| format!("Cannot access {}", common_dir.display()))?;
+        let jobs = size_walk::resolve_jobs(None);
+        let size = |path: &Path| size_walk::walk_all(&[path.to_path_buf()], None, jobs)[0];
+
+        let porcelain = git_command_at(&common_dir)
+            .args(["worktree", "list", "--porcelain"])
+            .output()
+            .context("Failed to run git worktree list")?;
+        let entries = parse_worktree_list_porcelain(&String::from_utf8_lossy(&porcelain.stdout));
+        let worktrees: Vec<WorktreeStorage> = entries
+            .into_iter()
+            .filter(|entry| !entry.is_bare)
+            .map(|entry| {
+                let link = link_state(&entry.path, &common_dir, &size);
+                WorktreeStorage {
+                    path: entry.path,
+                    branch: entry.branch,
+                    link,
+                }
+            })
+            .collect();
+
+        let registered: Vec<PathBuf> = worktrees
+            .iter()
+            .filter_map(|w| w.path.canonicalize().ok())
+            .collect();
+        let mut strays = Vec::new();
+        find_strays(project_root, &common_dir, &registered, 0, &mut |path| {
+            let bytes = size(&path.join(".git")).unwrap_or(0);
+            strays.push(StrayClone { path, bytes });
+        });
+        strays.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(Self {
+            object_bytes: size(&common_dir.join("objects")).unwrap_or(0),
+            alternates: alternates(&common_dir),
+            common_dir,
+            worktrees,
+            strays,
+        })
+    }
+
+    /// Checkouts reading from the shared store.
+    pub fn shared_count(&self) -> usize {
+        self.worktrees
+            .iter()
+            .filter(|w| w.link == LinkState::Shared)
+            .count()
+    }
+
+    /// What the shared checkouts would take as independent clones, less what
+    /// they take now: one extra copy of the objects per checkout beyond the
+    /// first.
+    pub fn saved_bytes(&self) -> u64 {
+        self.object_bytes
+            .saturating_mul(self.shared_count().saturating_sub(1) as u64)
+    }
+
+    /// Bytes held by full `.git` directories that duplicate the store.
+    pub fn duplicated_bytes(&self) -> u64 {
+        let own: u64 = self
+            .worktrees
+            .iter()
+            .filter_map(|w| match w.link {
+                LinkState::OwnGitDir(bytes) => Some(bytes),
+                _ => None,
+            })
+            .sum();
+        own + self.strays.iter().map(|s| s.bytes).sum::<u64>()
+    }
+
+    /// Number of broken links, missing alternates, and duplicate stores.
+    pub fn problem_count(&self) -> usize {
+        self.worktrees
+            .iter()
+            .filter(|w| w.link != LinkState::Shared)
+            .count()
+            + self.alternates.iter().filter(|a| !a.exists).count()
+            + self.strays.len()
+    }
+}
+
+fn link_state(
+    worktree: &Path,
+    common_dir: &Path,
+    size: &impl Fn(&Path) -> Option<u64>,
+) -> LinkState {
+    let dot_git = worktree.join(".git");
+    if dot_git.is_dir() {
+        // The store's own checkout (a non-bare main worktree) is the store.
+        if dot_git.canonicalize().ok().as_deref() == Some(common_dir) {
+            return LinkState::Shared;
+        }
+        return LinkState::OwnGitDir(size(&dot_git).unwrap_or(0));
+    }
+    let Ok(contents) = std::fs::read_to_string(&dot_git) else {
+        return LinkState::Broken(if worktree.exists() {
+            ".git is missing".to_string()
+        } else {
+            "the worktree directory is missing".to_string()
+        });
+    };
+    let Some(admin) = contents.trim().strip_prefix("gitdir: ") else {
+        return LinkState::Broken(".git is not a gitdir link".to_string());
+    };
+    let admin = worktree.join(admin);
+    if !admin.is_dir() {
+        return LinkState::Broken(format!(".git points to missing {}", admin.display()));
+    }
+    let commondir = std::fs::read_to_string(admin.join("commondir")).unwrap_or_default();
+    let resolved = admin.join(commondir.trim()).canonicalize().ok();
+    if resolved.as_deref() != Some(common_dir) {
+        return LinkState::Broken(format!(
+            "{} does not lead back to the shared store",
+            admin.display()
+        ));
+    }
+    LinkState::Shared
+}
+
+fn alternates(common_dir: &Path) -> Vec<Alternate> {
+    let objects = common_dir.join("objects");
+    let contents = std::fs::read_to_string(objects.join("info/alternates")).unwrap_or_default();
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let path = objects.join(line);
+            Alternate {
+                exists: path.is_dir(),
+                path,
+            }
+        })
+        .collect()
+}
+
+/// Report every directory below `dir` (to [`STRAY_SCAN_DEPTH`]) that has a
+/// `.git` directory and is neither the store nor a registered worktree. Stops
+/// descending at anything with a `.git` entry.
+fn find_strays(
+    dir: &Path,
+    common_dir: &Path,
+    registered: &[PathBuf],
+    depth: usize,
+    found: &mut dyn FnMut(PathBuf),
+) {
+    if depth > STRAY_SCAN_DEPTH {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !entry.file_type().is_ok_and(|t| t.is_dir()) || path.file_name() == Some(".git".as_ref())
+        {
+            continue;
+        }
+        let dot_git = path.join(".git");
+        if dot_git.is_dir() {
+            let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+            let is_store = dot_git.canonicalize().ok().as_deref() == Some(common_dir);
+            if !is_store && !registered.contains(&canonical) {
+                found(path);
+            }
+        } else if !dot_git.exists() {
+            find_strays(&path, common_dir, registered, depth + 1, found);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let out = git_command_at(dir).args(args).output().unwrap();
+        assert!(
+            out.status.success(),
+            "{}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+
+    #[test]
+    fn report_flags_broken_links_and_stray_clones() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().canonicalize().unwrap();
+        let main = root.join("main");
+        std::fs::create_dir_all(&main).unwrap();
+        git(&main, &["init", "-q", "-b", "main"]);
+        git(
+            &main,
+            &[
+                "-c",
+                "user.name=t",
+                "-c",
+                "user.email=t@example.com",
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                "one",
+            ],
+        );
+        git(
+            &main,
+            &["worktree", "add", "-q", "../feature", "-b", "feature"],
+        );
+        git(
+            &main,
+            &["worktree", "add", "-q", "../broken", "-b", "broken"],
+        );
+        std::fs::write(root.join("broken/.git"), "gitdir: /nowhere\n").unwrap();
+        git(&root, &["clone", "-q", "main", "copy"]);
+
+        let report = StorageReport::collect(&main.join(".git"), &root).unwrap();
+        let link = |branch: &str| {
+            report
+                .worktrees
+                .iter()
+                .find(|w| w.branch.as_deref() == Some(branch))
+                .map(|w| w.link.clone())
+                .unwrap()
+        };
+        assert_eq!(link("main"), LinkState::Shared);
+        assert_eq!(link("feature"), LinkState::Shared);
+        assert!(matches!(link("broken"), LinkState::Broken(_)));
+        assert_eq!(report.strays.len(), 1);
+        assert_eq!(report.strays[0].path, root.join("copy"));
+        assert_eq!(report.shared_count(), 2);
+        assert_eq!(report.saved_bytes(), report.object_bytes);
+        assert_eq!(report.problem_count(), 2);
+    }
+}
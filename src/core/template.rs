@@ -0,0 +1,467 @@
+//! Worktree templates behind `daft template`.
+//!
+//! A template is a compressed snapshot of ignored build directories
+//! (`node_modules`, `.venv`, ...) taken from a reference worktree. The
+//! directories to capture come from the `template:` list in daft.yml:
+//!
+//! ```yaml
+//! template:
+//!   - node_modules
+//!   - .venv
+//! ```
+//!
+//! The archive lives at `<git-common-dir>/.daft/template.tar.gz`, next to
+//! shared-file storage, and every new worktree restores from it before
+//! post-create hooks run — so an install hook starts from a warm tree and
+//! only has to reconcile the difference. A path is restored only when the
+//! new worktree doesn't already have it, and only if it is still listed in
+//! the new worktree's `template:` config.
+//!
+//! Archives are written and read with the system `tar`, which every
+//! supported platform ships. A manifest next to the archive
+//! (`template.json`) lists the captured paths, so restoring depends on
+//! nothing but the two files; an archive without one is listed with
+//! `tar -tzf` instead. Provenance (source branch and commit, size) is
+//! recorded in the coordinator store for `daft template status` only.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::repo::{existing_store, git_stdout, repo_hash};
+use crate::store::models::WorktreeTemplateRow;
+use crate::store::paths;
+use crate::store::pool::Pool;
+use crate::store::repos::WorktreeTemplatesRepo;
+use crate::utils::git_command_at;
+
+/// Archive filename inside `<git-common-dir>/.daft/`.
+const ARCHIVE_FILE: &str = "template.tar.gz";
+
+/// Manifest filename inside `<git-common-dir>/.daft/`.
+const MANIFEST_FILE: &str = "template.json";
+
+/// Current manifest schema version.
+const MANIFEST_VERSION: u32 = 1;
+
+/// The paths an archive holds, written next to it by [`snapshot`].
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    version: u32,
+    paths: Vec<String>,
+}
+
+/// Return the path to `.git/.daft/template.tar.gz`.
+pub fn archive_path(git_common_dir: &Path) -> PathBuf {
+    git_common_dir.join(".daft").join(ARCHIVE_FILE)
+}
+
+/// What a snapshot captured and what it had to leave out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotReport {
+    /// Paths written into the archive.
+    pub captured: Vec<String>,
+    /// Configured paths that were left out, with the reason.
+    pub skipped: Vec<(String, &'static str)>,
+    /// Compressed archive size.
+    pub bytes: u64,
+    /// Why provenance couldn't be recorded for `daft template status`, if it
+    /// couldn't. The template itself is saved and restores regardless.
+    pub unrecorded: Option<String>,
+}
+
+/// Capture the configured `template:` paths of `source_worktree` into the
+/// repository's template archive, replacing any previous one.
+pub fn snapshot(source_worktree: &Path) -> Result<SnapshotReport> {
+    let configured = crate::core::shared::read_template_paths(source_worktree)?;
+    if configured.is_empty() {
+        bail!("No template paths configured: add a `template:` list to daft.yml");
+    }
+    let (captured, skipped) = capturable_paths(source_worktree, &configured);
+    if captured.is_empty() {
+        bail!(
+            "Nothing to capture in {}: none of the template paths exist as ignored directories",
+            source_worktree.display()
+        );
+    }
+
+    let common_dir = crate::core::repo::git_common_dir_at(source_worktree)
+        .context("Not inside a Git repository")?;
+    let archive = archive_path(&common_dir);
+    let bytes = create_archive(source_worktree, &archive, &captured)?;
+    let manifest = Manifest {
+        version: MANIFEST_VERSION,
+        paths: captured.clone(),
+    };
+    crate::state_file::write_atomic(
+        &manifest_path(&common_dir),
+        serde_json::to_string_pretty(&manifest)?.as_bytes(),
+    )
+    .context("Failed to write the template manifest")?;
+
+    let unrecorded = record(
+        source_worktree,
+        &WorktreeTemplateRow {
+            source_branch: git_stdout(source_worktree, &["rev-parse", "--abbrev-ref", "HEAD"])
+                .map(|out| out.trim().to_string())
+                .unwrap_or_default(),
+            source_commit: git_stdout(source_worktree, &["rev-parse", "HEAD"])
+                .map(|out| out.trim().to_string())
+                .unwrap_or_default(),
+            paths: captured.clone(),
+            bytes: bytes as i64,
+            created_at: Utc::now(),
+        },
+    )
+    .err()
+    .map(|e| format!("{e:#}"));
+
+    Ok(SnapshotReport {
+        captured,
+        skipped,
+        bytes,
+        unrecorded,
+    })
+}
+
+/// The newest snapshot's provenance, if the repository has a template
+/// archive. Never creates a store; when it holds no provenance, the paths,
+/// size, and time come from the archive itself and the source is left
+/// empty.
+pub fn status(repo_dir: &Path) -> Result<Option<WorktreeTemplateRow>> {
+    let common_dir =
+        crate::core::repo::git_common_dir_at(repo_dir).context("Not inside a Git repository")?;
+    let archive = archive_path(&common_dir);
+    if !archive.exists() {
+        return Ok(None);
+    }
+    if let Some(db_path) = existing_store(repo_dir) {
+        let pool = Pool::open(&db_path)?;
+        let conn = pool.reader()?;
+        if let Some(row) = WorktreeTemplatesRepo::latest(&conn)? {
+            return Ok(Some(row));
+        }
+    }
+    let metadata = std::fs::metadata(&archive)
+        .with_context(|| format!("Failed to read {}", archive.display()))?;
+    Ok(Some(WorktreeTemplateRow {
+        source_branch: String::new(),
+        source_commit: String::new(),
+        paths: archived_paths(&common_dir)?,
+        bytes: metadata.len() as i64,
+        created_at: metadata
+            .modified()
+            .map(DateTime::<Utc>::from)
+            .unwrap_or_else(|_| Utc::now()),
+    }))
+}
+
+/// Delete the template archive and its recorded provenance. Returns whether
+/// an archive existed.
+pub fn clear(repo_dir: &Path) -> Result<bool> {
+    let common_dir =
+        crate::core::repo::git_common_dir_at(repo_dir).context("Not inside a Git repository")?;
+    let archive = archive_path(&common_dir);
+    let existed = archive.exists();
+    if existed {
+        std::fs::remove_file(&archive)
+            .with_context(|| format!("Failed to remove {}", archive.display()))?;
+    }
+    let manifest = manifest_path(&common_dir);
+    if manifest.exists() {
+        std::fs::remove_file(&manifest)
+            .with_context(|| format!("Failed to remove {}", manifest.display()))?;
+    }
+    if let Some(db_path) = existing_store(repo_dir) {
+        let pool = Pool::open(&db_path)?;
+        let conn = pool.writer()?;
+        WorktreeTemplatesRepo::clear(&conn)?;
+    }
+    Ok(existed)
+}
+
+/// Restore template paths into a freshly created worktree. Returns the paths
+/// restored; empty when the repository has no template, the worktree's
+/// config lists none, or every listed path is already present.
+pub fn restore_on_create(worktree_path: &Path, git_common_dir: &Path) -> Result<Vec<String>> {
+    let archive = archive_path(git_common_dir);
+    if !archive.exists() {
+        return Ok(Vec::new());
+    }
+    let configured = crate::core::shared::read_template_paths(worktree_path)?;
+    if configured.is_empty() {
+        return Ok(Vec::new());
+    }
+    let archived = archived_paths(git_common_dir)?;
+    let wanted: Vec<String> = configured
+        .into_iter()
+        .filter(|p| archived.contains(p) && !worktree_path.join(p).exists())
+        .collect();
+    if wanted.is_empty() {
+        return Ok(Vec::new());
+    }
+    extract_archive(&archive, worktree_path, &wanted)?;
+    Ok(wanted)
+}
+
+fn manifest_path(git_common_dir: &Path) -> PathBuf {
+    git_common_dir.join(".daft").join(MANIFEST_FILE)
+}
+
+/// The paths the template archive holds: the manifest's list, or, for an
+/// archive without a readable manifest, its top-level entries.
+fn archived_paths(git_common_dir: &Path) -> Result<Vec<String>> {
+    let manifest: Option<Manifest> = crate::state_file::load_json_checked(
+        &manifest_path(git_common_dir),
+        "template manifest",
+        MANIFEST_VERSION,
+    )?;
+    if let Some(manifest) = manifest {
+        return Ok(manifest.paths);
+    }
+    list_archive(&archive_path(git_common_dir))
+}
+
+/// Top-level entries of `archive`, via `tar -tzf`.
+fn list_archive(archive: &Path) -> Result<Vec<String>> {
+    let output = Command::new("tar")
+        .arg("-tzf")
+        .arg(archive)
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run tar")?;
+    if !output.status.success() {
+        bail!(
+            "tar failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let mut paths: Vec<String> = Vec::new();
+    for entry in String::from_utf8_lossy(&output.stdout).lines() {
+        let entry = entry.trim_start_matches("./").trim_end_matches('/');
+        if entry.is_empty() || paths.iter().any(|p| entry.starts_with(&format!("{p}/"))) {
+            continue;
+        }
+        paths.push(entry.to_string());
+    }
+    Ok(paths)
+}
+
+/// Split `configured` into paths that can be captured from `worktree` —
+/// relative, present, and ignored by git — and those that can't.
+fn capturable_paths(
+    worktree: &Path,
+    configured: &[String],
+) -> (Vec<String>, Vec<(String, &'static str)>) {
+    let mut captured = Vec::new();
+    let mut skipped = Vec::new();
+    for rel in configured {
+        let path = Path::new(rel);
+        let reason = if path.is_absolute()
+            || path
+                .components()
+                .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            Some("not inside the worktree")
+        } else if !worktree.join(path).exists() {
+            Some("does not exist")
+        } else if !is_ignored(worktree, rel) {
+            Some("not ignored by git")
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => skipped.push((rel.clone(), reason)),
+            None => captured.push(rel.clone()),
+        }
+    }
+    (captured, skipped)
+}
+
+/// Write `paths` (relative to `source`) into `archive` via a temporary file,
+/// so a failed run leaves the previous template intact. Returns the archive
+/// size.
+fn create_archive(source: &Path, archive: &Path, paths: &[String]) -> Result<u64> {
+    let dir = archive.parent().context("Template archive has no parent")?;
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let tmp = archive.with_extension("gz.tmp");
+    let output = Command::new("tar")
+        .arg("-czf")
+        .arg(&tmp)
+        .arg("-C")
+        .arg(source)
+        .arg("--")
+        .args(paths)
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run tar")?;
+    if !output.status.success() {
+        let _ = std::fs::remove_file(&tmp);
+        bail!(
+            "tar failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    std::fs::rename(&tmp, archive)
+        .with_context(|| format!("Failed to write {}", archive.display()))?;
+    Ok(std::fs::metadata(archive)?.len())
+}
+
+fn extract_archive(archive: &Path, dest: &Path, paths: &[String]) -> Result<()> {
+    let output = Command::new("tar")
+        .arg("-xzf")
+        .arg(archive)
+        .arg("-C")
+        .arg(dest)
+        .arg("--")
+        .args(paths)
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run tar")?;
+    if !output.status.success() {
+        bail!(
+            "tar failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn is_ignored(worktree: &Path, rel: &str) -> bool {
+    git_command_at(worktree)
+        .args(["check-ignore", "-q", "--", rel])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|s| s.success())
+}
+
+fn record(repo_dir: &Path, row: &WorktreeTemplateRow) -> Result<()> {
+    let pool = Pool::open(&paths::for_repo(&repo_hash(repo_dir)?)?)?;
+    let conn = pool.writer()?;
+    WorktreeTemplatesRepo::record(&conn, row)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn repo(tmp: &TempDir) -> PathBuf {
+        let dir = tmp.path().join("repo");
+        std::fs::create_dir_all(&dir).unwrap();
+        git_stdout(&dir, &["init", "-q", "-b", "main"]).unwrap();
+        std::fs::write(dir.join(".gitignore"), "node_modules/\n").unwrap();
+        std::fs::create_dir_all(dir.join("node_modules/left-pad")).unwrap();
+        std::fs::write(dir.join("node_modules/left-pad/index.js"), "pad\n").unwrap();
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/main.js"), "main\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn only_present_ignored_relative_paths_are_capturable() {
+        let tmp = TempDir::new().unwrap();
+        let dir = repo(&tmp);
+        let configured: Vec<String> = ["node_modules", "src", ".venv", "../elsewhere"]
+            .map(String::from)
+            .into();
+        let (captured, skipped) = capturable_paths(&dir, &configured);
+        assert_eq!(captured, ["node_modules"]);
+        assert_eq!(
+            skipped,
+            [
+                ("src".to_string(), "not ignored by git"),
+                (".venv".to_string(), "does not exist"),
+                ("../elsewhere".to_string(), "not inside the worktree"),
+            ]
+        );
+    }
+
+    #[test]
+    fn archive_round_trip_restores_directory_contents() {
+        let tmp = TempDir::new().unwrap();
+        let dir = repo(&tmp);
+        let archive = tmp.path().join("common/.daft").join(ARCHIVE_FILE);
+        let bytes = create_archive(&dir, &archive, &["node_modules".to_string()]).unwrap();
+        assert!(bytes > 0);
+        assert!(!archive.with_extension("gz.tmp").exists());
+
+        let fresh = tmp.path().join("fresh");
+        std::fs::create_dir_all(&fresh).unwrap();
+        extract_archive(&archive, &fresh, &["node_modules".to_string()]).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(fresh.join("node_modules/left-pad/index.js")).unwrap(),
+            "pad\n"
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn restore_reads_the_archive_without_a_store() {
+        let tmp = TempDir::new().unwrap();
+        let dir = repo(&tmp);
+        std::fs::write(dir.join("daft.yml"), "template:\n  - node_modules\n").unwrap();
+        git_stdout(&dir, &["add", "."]).unwrap();
+        git_stdout(
+            &dir,
+            &[
+                "-c",
+                "user.name=t",
+                "-c",
+                "user.email=t@t",
+                "commit",
+                "-qm",
+                "init",
+            ],
+        )
+        .unwrap();
+
+        let state_dir = tmp.path().join("state");
+        let prev_state_dir = std::env::var("DAFT_STATE_DIR").ok();
+        unsafe {
+            std::env::set_var("DAFT_STATE_DIR", &state_dir);
+        }
+        let report = snapshot(&dir);
+        match prev_state_dir {
+            Some(v) => unsafe { std::env::set_var("DAFT_STATE_DIR", v) },
+            None => unsafe { std::env::remove_var("DAFT_STATE_DIR") },
+        }
+        assert_eq!(report.unwrap().captured, ["node_modules"]);
+        // The provenance store is gone; restore must not care.
+        std::fs::remove_dir_all(&state_dir).unwrap();
+
+        let common = crate::core::repo::git_common_dir_at(&dir).unwrap();
+        let restored_file = "node_modules/left-pad/index.js";
+        let feat = tmp.path().join("feat");
+        git_stdout(
+            &dir,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                "-b",
+                "feat",
+                feat.to_str().unwrap(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(restore_on_create(&feat, &common).unwrap(), ["node_modules"]);
+        assert!(feat.join(restored_file).exists());
+
+        // Without the manifest, the archive's own listing decides.
+        std::fs::remove_file(manifest_path(&common)).unwrap();
+        let fix = tmp.path().join("fix");
+        git_stdout(
+            &dir,
+            &["worktree", "add", "-q", "-b", "fix", fix.to_str().unwrap()],
+        )
+        .unwrap();
+        assert_eq!(restore_on_create(&fix, &common).unwrap(), ["node_modules"]);
+        assert!(fix.join(restored_file).exists());
+    }
+}
@@ -60,6 +60,7 @@ impl ProgressSink for TuiBridge {
 // TUI workers cannot prompt (raw-mode terminal is owned by the TUI): the
 // default Abort answers make refined daft files refuse removal there.
 impl crate::core::ConsolidationPrompter for TuiBridge {}
+impl crate::core::PathConflictPrompter for TuiBridge {}
 
 impl HookRunner for TuiBridge {
     fn hook_phase_has_work(
@@ -0,0 +1,258 @@
+//! Workspaces: named groups of daft projects.
+//!
+//! A workspace is a machine-local file, `~/.config/daft/workspaces/<name>.yml`,
+//! listing the repos that make up one area of work and which branch of each
+//! to open:
+//!
+//! ```yaml
+//! description: API, worker, and the shared schema
+//! open: tmux            # or `editor`
+//! editor: code          # used by `open: editor`; defaults to $VISUAL/$EDITOR
+//! members:
+//!   - repo: api         # a catalog name, uuid, or path
+//!   - repo: worker
+//!     branch: feat/queue
+//! ```
+//!
+//! Unlike the relations manifest — committed, keyed by remote URL, shared
+//! with a team — a workspace is personal: it names repos the way the local
+//! catalog knows them. Members resolve through the catalog to the worktree
+//! for their branch (the repo's default branch when unset); nothing here
+//! creates worktrees or touches the catalog.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::catalog::worktrees::worktree_children;
+
+/// Directory under the daft config dir that holds workspace files.
+const WORKSPACES_DIR: &str = "workspaces";
+
+/// How `daft workspace open` presents the members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OpenWith {
+    /// One tmux session, one window per member.
+    Tmux,
+    /// One editor invocation with every member's worktree.
+    Editor,
+}
+
+/// One repo in a workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WorkspaceMember {
+    /// Catalog name, uuid, or path (`~` and environment variables expand).
+    pub repo: String,
+    /// Branch whose worktree to use; the repo's default branch when unset.
+    #[serde(default)]
+    pub branch: Option<String>,
+}
+
+/// A parsed workspace file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Workspace {
+    /// File stem — the name `daft workspace open <name>` takes.
+    #[serde(skip)]
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub open: Option<OpenWith>,
+    #[serde(default)]
+    pub editor: Option<String>,
+    pub members: Vec<WorkspaceMember>,
+}
+
+impl Workspace {
+    /// Parse a workspace file's contents.
+    pub fn parse(name: &str, contents: &str) -> Result<Self> {
+        let mut workspace: Workspace = serde_yaml::from_str(contents)?;
+        if workspace.members.is_empty() {
+            anyhow::bail!("'members' is empty");
+        }
+        workspace.name = name.to_string();
+        Ok(workspace)
+    }
+
+    /// tmux session name for this workspace. tmux reserves `.` and `:` in
+    /// target names, so they become `-`.
+    pub fn session_name(&self) -> String {
+        let name: String = self
+            .name
+            .chars()
+            .map(|c| if matches!(c, '.' | ':') { '-' } else { c })
+            .collect();
+        format!("daft-{name}")
+    }
+}
+
+/// A member resolved against this machine's catalog.
+#[derive(Debug, Clone)]
+pub struct ResolvedMember {
+    /// The catalog name when the repo resolved, the configured needle
+    /// otherwise.
+    pub repo: String,
+    pub branch: Option<String>,
+    pub worktree: Option<PathBuf>,
+    /// Why the member has no usable worktree.
+    pub problem: Option<String>,
+}
+
+/// `~/.config/daft/workspaces`.
+pub fn workspaces_dir() -> Result<PathBuf> {
+    Ok(crate::daft_config_dir()?.join(WORKSPACES_DIR))
+}
+
+/// Names of every workspace file, sorted.
+pub fn list_names() -> Result<Vec<String>> {
+    list_names_in(&workspaces_dir()?)
+}
+
+fn list_names_in(dir: &Path) -> Result<Vec<String>> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let ext = path.extension()?.to_str()?;
+            if ext != "yml" && ext != "yaml" {
+                return None;
+            }
+            Some(path.file_stem()?.to_string_lossy().into_owned())
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+/// Load the workspace called `name`, with a did-you-mean on a miss.
+pub fn load(name: &str) -> Result<Workspace> {
+    load_in(&workspaces_dir()?, name)
+}
+
+fn load_in(dir: &Path, name: &str) -> Result<Workspace> {
+    let path = ["yml", "yaml"]
+        .iter()
+        .map(|ext| dir.join(format!("{name}.{ext}")))
+        .find(|p| p.is_file());
+    let Some(path) = path else {
+        let names = list_names_in(dir)?;
+        let suggestions = crate::suggest::find_similar(name, &names, 3);
+        let mut msg = format!("no workspace named '{name}'");
+        if !suggestions.is_empty() {
+            msg.push_str(&format!("\n  did you mean: {}", suggestions.join(", ")));
+        }
+        msg.push_str(&format!(
+            "\n  tip: workspaces are defined in {}/<name>.yml",
+            crate::output::format::tilde_path(&dir.to_string_lossy())
+        ));
+        anyhow::bail!(msg);
+    };
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Workspace::parse(name, &contents).with_context(|| format!("Invalid {}", path.display()))
+}
+
+/// Resolve a member to its worktree on this machine. Never fails: a member
+/// that can't be used carries a `problem` so callers can report it and go on
+/// with the rest.
+pub fn resolve_member(member: &WorkspaceMember) -> ResolvedMember {
+    let needle = crate::settings::expand_path("repo", &member.repo)
+        .map(|path| path.to_string_lossy().into_owned());
+    let row = match needle.and_then(|needle| crate::catalog::resolve_repo_arg(&needle)) {
+        Ok(row) => row,
+        Err(e) => {
+            return ResolvedMember {
+                repo: member.repo.clone(),
+                branch: member.branch.clone(),
+                worktree: None,
+                // The first line is the diagnosis; the tips that follow are
+                // written for a single-repo command line.
+                problem: Some(first_line(&format!("{e:#}"))),
+            };
+        }
+    };
+    let branch = member
+        .branch
+        .clone()
+        .or_else(|| crate::catalog::effective_default_branch(&row));
+    let Some(branch) = branch else {
+        return ResolvedMember {
+            repo: row.name,
+            branch: None,
+            worktree: None,
+            problem: Some("no branch set and no default branch known".into()),
+        };
+    };
+    let worktree = worktree_children(&row, None).and_then(|children| {
+        children
+            .into_iter()
+            .find(|c| c.branch.as_deref() == Some(branch.as_str()))
+            .map(|c| PathBuf::from(c.path))
+    });
+    let problem = worktree
+        .is_none()
+        .then(|| format!("no worktree for '{branch}'"));
+    ResolvedMember {
+        repo: row.name,
+        branch: Some(branch),
+        worktree,
+        problem,
+    }
+}
+
+fn first_line(message: &str) -> String {
+    message.lines().next().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parses_members_and_open_mode() {
+        let ws = Workspace::parse(
+            "backend",
+            "open: editor\neditor: code -n\nmembers:\n  - repo: api\n  - repo: worker\n    branch: feat/q\n",
+        )
+        .unwrap();
+        assert_eq!(ws.name, "backend");
+        assert_eq!(ws.open, Some(OpenWith::Editor));
+        assert_eq!(ws.editor.as_deref(), Some("code -n"));
+        assert_eq!(ws.members.len(), 2);
+        assert_eq!(ws.members[1].branch.as_deref(), Some("feat/q"));
+    }
+
+    #[test]
+    fn rejects_empty_members_and_unknown_keys() {
+        assert!(Workspace::parse("x", "members: []\n").is_err());
+        assert!(Workspace::parse("x", "members:\n  - repo: api\n    brnach: main\n").is_err());
+    }
+
+    #[test]
+    fn session_names_avoid_tmux_separators() {
+        let ws = Workspace::parse("v1.2:api", "members:\n  - repo: api\n").unwrap();
+        assert_eq!(ws.session_name(), "daft-v1-2-api");
+    }
+
+    #[test]
+    fn load_lists_files_and_suggests_near_names() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("backend.yml"), "members:\n  - repo: api\n").unwrap();
+        std::fs::write(dir.path().join("web.yaml"), "members:\n  - repo: ui\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+        assert_eq!(list_names_in(dir.path()).unwrap(), vec!["backend", "web"]);
+        assert_eq!(load_in(dir.path(), "web").unwrap().members[0].repo, "ui");
+        let err = load_in(dir.path(), "backnd").unwrap_err().to_string();
+        assert!(err.contains("did you mean: backend"), "got: {err}");
+    }
+}
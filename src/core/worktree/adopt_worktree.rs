@@ -0,0 +1,310 @@
+//! Core logic for `daft adopt-worktree`.
+//!
+//! A worktree created with plain `git worktree add` is a valid worktree, but
+//! daft never saw it being born: no identity record, no visitor config or
+//! shared-file links, no `worktree-post-create` hook (so no direnv/mise
+//! setup), and possibly a path outside the repo's layout. Adoption replays
+//! the bookkeeping `daft go`/`daft start` would have done at creation time,
+//! optionally moving the worktree to the path the layout computes for its
+//! branch first.
+
+use crate::core::layout::Layout;
+use crate::core::multi_remote::path::{
+    build_template_context, calculate_worktree_path, resolve_remote_for_branch,
+};
+use crate::core::worktree::path_conflict;
+use crate::core::worktree::porcelain::{WorktreeListEntry, parse_worktree_list_porcelain};
+use crate::core::worktree::previous;
+use crate::core::{HookOutcome, HookRunner, ProgressSink};
+use crate::git::GitCommand;
+use crate::hooks::{HookContext, HookType};
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// Input parameters for the adopt-worktree operation.
+pub struct AdoptWorktreeParams {
+    /// Path to the worktree, absolute or relative to the current directory.
+    pub path: PathBuf,
+    /// Move the worktree to its canonical layout path before adopting it.
+    pub move_to_layout: bool,
+    /// Remote name (from settings).
+    pub remote_name: String,
+    /// Layout used to compute the canonical path. `None` falls back to the
+    /// legacy `<project-root>/<branch>` computation.
+    pub layout: Option<Layout>,
+    /// Whether multi-remote mode is enabled.
+    pub multi_remote_enabled: bool,
+    /// Default remote for multi-remote mode.
+    pub multi_remote_default: String,
+}
+
+/// Result of an adopt-worktree operation.
+pub struct AdoptWorktreeResult {
+    /// The branch checked out in the adopted worktree.
+    pub branch: String,
+    /// Where the worktree lives now.
+    pub worktree_path: PathBuf,
+    /// Where the layout would place the worktree.
+    pub canonical_path: PathBuf,
+    /// The worktree's path before `--move`, when it was moved.
+    pub moved_from: Option<PathBuf>,
+    /// Visitor daft files copied into the worktree.
+    pub files_propagated: Vec<String>,
+    /// Where to cd if the cwd was inside the moved worktree.
+    pub cd_target: Option<PathBuf>,
+    /// Outcome of the post-create hook.
+    pub post_hook_outcome: HookOutcome,
+}
+
+impl AdoptWorktreeResult {
+    /// The worktree stayed somewhere the layout would not have put it.
+    pub fn off_layout(&self) -> bool {
+        !same_path(&self.worktree_path, &self.canonical_path)
+    }
+}
+
+pub fn execute(
+    params: &AdoptWorktreeParams,
+    git: &GitCommand,
+    project_root: &Path,
+    git_dir: &Path,
+    sink: &mut (impl ProgressSink + HookRunner),
+) -> Result<AdoptWorktreeResult> {
+    let entries = parse_worktree_list_porcelain(&git.worktree_list_porcelain()?);
+    let (path, branch) = resolve_target(&params.path, &entries)?;
+    sink.on_step(&format!(
+        "Resolved '{}' to worktree of branch '{}'",
+        params.path.display(),
+        branch
+    ));
+
+    let canonical_path = canonical_path(params, git, project_root, &branch)?;
+
+    let mut worktree_path = path.clone();
+    let mut moved_from = None;
+    let mut cd_target = None;
+    if params.move_to_layout && !same_path(&path, &canonical_path) {
+        if let Some(occupant) = path_conflict::occupant(&canonical_path) {
+            anyhow::bail!(
+                "cannot move the worktree to '{}': the path is already taken by {}",
+                canonical_path.display(),
+                match occupant {
+                    path_conflict::Occupant::File => "a file",
+                    _ => "a non-empty directory",
+                }
+            );
+        }
+        if let Some(parent) = canonical_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory '{}'", parent.display()))?;
+        }
+        let cwd_inside = std::env::current_dir().is_ok_and(|cwd| {
+            let cwd = std::fs::canonicalize(&cwd).unwrap_or(cwd);
+            cwd.starts_with(std::fs::canonicalize(&path).unwrap_or_else(|_| path.clone()))
+        });
+
+        sink.on_step(&format!(
+            "Moving worktree from '{}' to '{}'...",
+            path.display(),
+            canonical_path.display()
+        ));
+        git.worktree_move(&path, &canonical_path).with_context(|| {
+            format!(
+                "Failed to move worktree from '{}' to '{}'",
+                path.display(),
+                canonical_path.display()
+            )
+        })?;
+
+        // `daft go -` would otherwise hop to a path that no longer exists.
+        if let Ok(Some(prev)) = previous::load(git_dir)
+            && same_path(&prev, &path)
+        {
+            let _ = previous::save(git_dir, &canonical_path);
+        }
+        if cwd_inside {
+            cd_target = Some(canonical_path.clone());
+        }
+        worktree_path = canonical_path.clone();
+        moved_from = Some(path);
+    }
+
+    if let Some(store) = crate::core::worktree::identity_store::IdentityStore::open(git_dir) {
+        store.record(&worktree_path, &branch);
+    }
+
+    // Same creation-time sequence as checkout: visitor propagation, then
+    // shared-file links, then the post-create hook (see checkout_branch.rs
+    // for why the order is load-bearing).
+    let source_worktree = crate::core::worktree::checkout_branch::resolve_source_worktree(
+        git,
+        git_dir,
+        &params.remote_name,
+        None,
+    )?;
+    let mut files_propagated = Vec::new();
+    if !same_path(&source_worktree, &worktree_path) {
+        match crate::hooks::visitor_propagation::propagate(&source_worktree, &worktree_path) {
+            Ok(result) => {
+                if !result.files_propagated.is_empty()
+                    && let Some(seeds) = crate::hooks::visitor_seeds::SeedsContext::open(git_dir)
+                {
+                    seeds.record_seeds(&branch, &worktree_path, &result.files_propagated);
+                }
+                files_propagated = result.files_propagated;
+            }
+            Err(e) => {
+                sink.on_warning(&format!("visitor-config propagation failed: {e}"));
+            }
+        }
+    }
+
+    let link_result =
+        crate::core::shared::link_shared_files_on_create(&worktree_path, git_dir, project_root);
+    crate::core::shared::report_link_results(&link_result, &[], sink);
+
+    let post_hook_ctx = HookContext::new(
+        HookType::PostCreate,
+        "adopt-worktree",
+        project_root,
+        git_dir,
+        &params.remote_name,
+        &source_worktree,
+        &worktree_path,
+        &branch,
+    )
+    .with_new_branch(false);
+    let post_hook_outcome = sink.run_hook(&post_hook_ctx)?;
+
+    Ok(AdoptWorktreeResult {
+        branch,
+        worktree_path,
+        canonical_path,
+        moved_from,
+        files_propagated,
+        cd_target,
+        post_hook_outcome,
+    })
+}
+
+/// Find the registered worktree at `path` and its branch. The main worktree
+/// (or bare entry) is refused: daft created it with the repository.
+fn resolve_target(path: &Path, entries: &[WorktreeListEntry]) -> Result<(PathBuf, String)> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir()?.join(path)
+    };
+    if !absolute.exists() {
+        anyhow::bail!("'{}' does not exist", path.display());
+    }
+
+    let Some(index) = entries.iter().position(|e| same_path(&e.path, &absolute)) else {
+        anyhow::bail!(
+            "'{}' is not a worktree of this repository.\n  \
+             tip: to turn a plain directory into a worktree, run `{}` with \
+             --on-conflict adopt",
+            path.display(),
+            crate::daft_cmd("go <branch>")
+        );
+    };
+    let entry = &entries[index];
+    if index == 0 || entry.is_bare {
+        anyhow::bail!(
+            "'{}' is the repository's main worktree; only linked worktrees can be adopted",
+            entry.path.display()
+        );
+    }
+    match &entry.branch {
+        Some(branch) => Ok((entry.path.clone(), branch.clone())),
+        None => anyhow::bail!(
+            "worktree at '{}' has a detached HEAD; check out a branch in it first",
+            entry.path.display()
+        ),
+    }
+}
+
+/// The path `daft go <branch>` would have created the worktree at.
+fn canonical_path(
+    params: &AdoptWorktreeParams,
+    git: &GitCommand,
+    project_root: &Path,
+    branch: &str,
+) -> Result<PathBuf> {
+    if let Some(ref layout) = params.layout {
+        // Wrapped non-bare layouts template against the wrapper directory,
+        // not the clone inside it (mirrors checkout).
+        let effective_root = if layout.needs_wrapper() {
+            project_root.parent().unwrap_or(project_root)
+        } else {
+            project_root
+        };
+        return layout.worktree_path(&build_template_context(effective_root, branch));
+    }
+    let remote_for_path = if params.multi_remote_enabled {
+        resolve_remote_for_branch(git, branch, None, &params.multi_remote_default)?
+    } else {
+        params.remote_name.clone()
+    };
+    Ok(calculate_worktree_path(
+        project_root,
+        branch,
+        &remote_for_path,
+        params.multi_remote_enabled,
+    ))
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    let canon = |p: &Path| std::fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    canon(a) == canon(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &Path, branch: Option<&str>, is_bare: bool) -> WorktreeListEntry {
+        WorktreeListEntry {
+            path: path.to_path_buf(),
+            branch: branch.map(String::from),
+            is_bare,
+            is_detached: branch.is_none() && !is_bare,
+        }
+    }
+
+    #[test]
+    fn resolves_a_linked_worktree_by_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let main = tmp.path().join("main");
+        let feat = tmp.path().join("elsewhere");
+        std::fs::create_dir_all(&main).unwrap();
+        std::fs::create_dir_all(&feat).unwrap();
+        let entries = [
+            entry(&main, Some("main"), false),
+            entry(&feat, Some("feat"), false),
+        ];
+
+        let (path, branch) = resolve_target(&feat, &entries).unwrap();
+        assert_eq!(path, feat);
+        assert_eq!(branch, "feat");
+    }
+
+    #[test]
+    fn refuses_main_detached_and_unregistered() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bare = tmp.path().join(".git");
+        let detached = tmp.path().join("detached");
+        let stray = tmp.path().join("stray");
+        for dir in [&bare, &detached, &stray] {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        let entries = [entry(&bare, None, true), entry(&detached, None, false)];
+
+        let err = resolve_target(&bare, &entries).unwrap_err().to_string();
+        assert!(err.contains("main worktree"), "got: {err}");
+        let err = resolve_target(&detached, &entries).unwrap_err().to_string();
+        assert!(err.contains("detached HEAD"), "got: {err}");
+        let err = resolve_target(&stray, &entries).unwrap_err().to_string();
+        assert!(err.contains("--on-conflict adopt"), "got: {err}");
+    }
+}
@@ -229,8 +229,13 @@ pub fn execute(
     }
 
     // Resolve arguments: each arg can be a branch name or a worktree path.
-    let resolved =
-        resolve_branch_args(&params.branches, &worktree_entries, &ctx.project_root, sink)?;
+    let resolved = resolve_branch_args(
+        &params.branches,
+        &worktree_entries,
+        &ctx.project_root,
+        &ctx.git_dir,
+        sink,
+    )?;
 
     // Detect current worktree context for is_current_worktree flagging.
     let current_wt_path = git.get_current_worktree_path().ok();
@@ -462,6 +467,7 @@ fn resolve_branch_args(
     args: &[String],
     worktree_entries: &[WorktreeListEntry],
     project_root: &Path,
+    git_dir: &Path,
     sink: &mut dyn ProgressSink,
 ) -> Result<Vec<String>> {
     let mut resolved = Vec::with_capacity(args.len());
@@ -476,10 +482,7 @@ fn resolve_branch_args(
                 resolved.push(arg.clone());
             }
             ResolveResult::DetachedHead(path) => {
-                anyhow::bail!(
-                    "worktree at '{}' has a detached HEAD; specify a branch name instead",
-                    path.display()
-                );
+                return Err(super::pin::detached_worktree_error(git_dir, &path));
             }
         }
     }
@@ -1131,10 +1134,11 @@ fn execute_deletions(
             branch.name
         ));
 
-        if branch.worktree_path.is_some() {
+        if let Some(worktree_path) = &branch.worktree_path {
             // Resolve CD target BEFORE removing the worktree.
-            let target = resolve_prune_cd_target(
+            let target = super::prune::resolve_prune_cd_target(
                 params.prune_cd_target,
+                worktree_path,
                 &ctx.project_root,
                 &ctx.git_dir,
                 &ctx.remote_name,
@@ -1236,6 +1240,7 @@ fn delete_single_branch(
             ctx,
             wt_path,
             &branch.name,
+            keep_local_branch || branch.worktree_only || remote_only,
             command_label,
             sink,
         );
@@ -1498,6 +1503,7 @@ fn delete_single_branch(
             ctx,
             wt_path,
             &branch.name,
+            !result.branch_deleted,
             command_label,
             sink,
         );
@@ -1523,15 +1529,20 @@ fn delete_single_branch(
 // ── Hook execution ─────────────────────────────────────────────────────────
 
 /// Run a lifecycle hook (pre-remove or post-remove) for a worktree.
+///
+/// The current worktree is removed last, after the process has already
+/// changed into its cd target, so the current directory is where the shell
+/// lands in every case.
 fn run_removal_hook(
     hook_type: HookType,
     ctx: &BranchDeleteContext,
     worktree_path: &Path,
     branch_name: &str,
+    branch_exists: bool,
     command_label: &str,
     sink: &mut (impl ProgressSink + HookRunner),
 ) {
-    let hook_ctx = HookContext::new(
+    let mut hook_ctx = HookContext::new(
         hook_type,
         command_label,
         &ctx.project_root,
@@ -1541,7 +1552,11 @@ fn run_removal_hook(
         worktree_path,
         branch_name,
     )
-    .with_removal_reason(RemovalReason::Manual);
+    .with_removal_reason(RemovalReason::Manual)
+    .with_branch_exists(branch_exists);
+    if let Ok(cwd) = std::env::current_dir() {
+        hook_ctx = hook_ctx.with_target_cwd(cwd);
+    }
 
     if let Err(e) = sink.run_hook(&hook_ctx) {
         sink.on_warning(&format!(
@@ -1568,42 +1583,6 @@ fn parse_worktree_list(git: &GitCommand) -> Result<Vec<WorktreeListEntry>> {
     Ok(parse_worktree_list_porcelain(&porcelain_output))
 }
 
-/// Resolve where to cd after deleting the user's current worktree.
-fn resolve_prune_cd_target(
-    cd_target: PruneCdTarget,
-    project_root: &Path,
-    git_dir: &Path,
-    remote_name: &str,
-    use_gitoxide: bool,
-    sink: &mut dyn ProgressSink,
-) -> PathBuf {
-    match cd_target {
-        PruneCdTarget::Root => project_root.to_path_buf(),
-        PruneCdTarget::DefaultBranch => {
-            match get_default_branch_local(git_dir, remote_name, use_gitoxide) {
-                Ok(default_branch) => {
-                    let branch_dir = project_root.join(&default_branch);
-                    if branch_dir.is_dir() {
-                        branch_dir
-                    } else {
-                        sink.on_step(&format!(
-                            "Default branch worktree directory '{}' not found, falling back to project root",
-                            branch_dir.display()
-                        ));
-                        project_root.to_path_buf()
-                    }
-                }
-                Err(e) => {
-                    sink.on_warning(&format!(
-                        "Cannot determine default branch for cd target: {e}. Falling back to project root."
-                    ));
-                    project_root.to_path_buf()
-                }
-            }
-        }
-    }
-}
-
 /// Clean up empty parent directories after removing a worktree.
 fn cleanup_empty_parent_dirs(
     project_root: &Path,
@@ -1,12 +1,16 @@
 //! Core logic for the `git-worktree-carry` command.
 //!
 //! Transfers uncommitted changes from the current worktree to one or more
-//! target worktrees via git stash.
+//! existing target worktrees via git stash. Targets are checked before
+//! anything is stashed: a target whose own uncommitted changes touch the
+//! same paths as the carried ones is refused, so a carry never has to merge
+//! two sets of edits. Staged changes arrive staged (`stash apply --index`).
 
 use crate::core::ProgressSink;
 use crate::git::GitCommand;
-use crate::utils::{change_directory, get_current_directory};
-use anyhow::Result;
+use crate::utils::{change_directory, get_current_directory, git_command_at};
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 /// Input parameters for the carry operation.
@@ -51,6 +55,28 @@ pub struct CarryResult {
     pub no_valid_targets: bool,
     /// Errors from target resolution (before any changes were made).
     pub resolution_errors: Vec<String>,
+    /// Targets whose index could not take the staged changes as staged;
+    /// the changes arrived there unstaged.
+    pub unstaged_targets: Vec<String>,
+    /// True when a failed move put the changes back in the source worktree.
+    pub restored_to_source: bool,
+}
+
+impl CarryResult {
+    fn early(cd_target: PathBuf) -> Self {
+        Self {
+            successes: Vec::new(),
+            failures: Vec::new(),
+            copy_mode: false,
+            cd_target,
+            stash_preserved: false,
+            no_changes: false,
+            no_valid_targets: false,
+            resolution_errors: Vec::new(),
+            unstaged_targets: Vec::new(),
+            restored_to_source: false,
+        }
+    }
 }
 
 /// Execute the carry operation.
@@ -69,20 +95,18 @@ pub fn execute(
     // Check for uncommitted changes
     if !git.has_uncommitted_changes()? {
         return Ok(CarryResult {
-            successes: Vec::new(),
-            failures: Vec::new(),
-            copy_mode: false,
-            cd_target: source_worktree,
-            stash_preserved: false,
             no_changes: true,
-            no_valid_targets: false,
-            resolution_errors: Vec::new(),
+            ..CarryResult::early(source_worktree)
         });
     }
+    let carried = changed_paths(&source_worktree)?;
 
     // Resolve all targets upfront (fail fast if any are invalid)
     let mut resolved_targets: Vec<CarryTarget> = Vec::new();
     let mut resolution_errors: Vec<String> = Vec::new();
+    // Each target's own uncommitted paths, to tell a failed apply that left
+    // the target untouched from one that left it half-applied.
+    let mut target_paths: Vec<BTreeSet<String>> = Vec::new();
 
     for target in &params.targets {
         match git.resolve_worktree_path(target, project_root) {
@@ -92,6 +116,22 @@ pub fn execute(
                         .on_warning(&format!("Skipping '{}': already in this worktree", target));
                     continue;
                 }
+                let own = match changed_paths(&path) {
+                    Ok(own) => own,
+                    Err(e) => {
+                        resolution_errors.push(format!("'{}': {:#}", target, e));
+                        continue;
+                    }
+                };
+                let overlap: Vec<&str> = own.intersection(&carried).map(String::as_str).collect();
+                if !overlap.is_empty() {
+                    resolution_errors.push(format!(
+                        "'{}': its uncommitted changes touch the same files ({}); commit or stash them there first",
+                        target,
+                        overlap.join(", ")
+                    ));
+                    continue;
+                }
                 let name = path
                     .strip_prefix(project_root)
                     .ok()
@@ -99,6 +139,7 @@ pub fn execute(
                     .unwrap_or("unknown")
                     .to_string();
                 resolved_targets.push(CarryTarget { name, path });
+                target_paths.push(own);
             }
             Err(e) => {
                 resolution_errors.push(format!("'{}': {}", target, e));
@@ -109,28 +150,16 @@ pub fn execute(
     // If there are resolution errors, bail before making changes
     if !resolution_errors.is_empty() {
         return Ok(CarryResult {
-            successes: Vec::new(),
-            failures: Vec::new(),
-            copy_mode: false,
-            cd_target: source_worktree,
-            stash_preserved: false,
-            no_changes: false,
-            no_valid_targets: false,
             resolution_errors,
+            ..CarryResult::early(source_worktree)
         });
     }
 
     // If no valid targets remain, exit
     if resolved_targets.is_empty() {
         return Ok(CarryResult {
-            successes: Vec::new(),
-            failures: Vec::new(),
-            copy_mode: false,
-            cd_target: source_worktree,
-            stash_preserved: false,
-            no_changes: false,
             no_valid_targets: true,
-            resolution_errors: Vec::new(),
+            ..CarryResult::early(source_worktree)
         });
     }
 
@@ -144,8 +173,9 @@ pub fn execute(
     // Apply to each target
     let mut successes: Vec<CarryTarget> = Vec::new();
     let mut failures: Vec<CarryFailure> = Vec::new();
+    let mut unstaged_targets: Vec<String> = Vec::new();
 
-    for target in resolved_targets {
+    for (target, own) in resolved_targets.into_iter().zip(target_paths) {
         progress.on_step(&format!("Applying changes to '{}'...", target.name));
 
         if let Err(e) = change_directory(&target.path) {
@@ -156,7 +186,24 @@ pub fn execute(
             continue;
         }
 
-        if let Err(e) = git.stash_apply() {
+        // Keep staged changes staged. `--index` refuses when the target's
+        // index cannot take them; only if that left the target untouched is
+        // a plain apply safe to try.
+        let applied = match git.stash_apply_index() {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                progress.on_debug(&format!("apply --index in '{}': {e}", target.name));
+                if changed_paths(&target.path).is_ok_and(|now| now == own) {
+                    git.stash_apply().inspect(|_| {
+                        unstaged_targets.push(target.name.clone());
+                    })
+                } else {
+                    Err(e)
+                }
+            }
+        };
+
+        if let Err(e) = applied {
             failures.push(CarryFailure {
                 name: target.name.clone(),
                 error: format!(
@@ -172,7 +219,8 @@ pub fn execute(
     }
 
     // Handle stash cleanup based on mode
-    let stash_preserved;
+    let mut stash_preserved = false;
+    let mut restored_to_source = false;
     if copy_mode {
         progress.on_step("Restoring changes in source worktree...");
         change_directory(&source_worktree)?;
@@ -182,16 +230,27 @@ pub fn execute(
                 e
             ));
             stash_preserved = true;
-        } else {
-            stash_preserved = false;
+        }
+    } else if successes.is_empty() {
+        // Move mode with nothing applied: the stash holds the only copy of
+        // the changes, so put them back where they came from.
+        progress.on_step("Restoring changes in source worktree...");
+        change_directory(&source_worktree)?;
+        match git.stash_pop() {
+            Ok(()) => restored_to_source = true,
+            Err(e) => {
+                progress.on_warning(&format!(
+                    "Failed to restore stashed changes: {}. Run 'git stash pop' to restore.",
+                    e
+                ));
+                stash_preserved = true;
+            }
         }
     } else {
         // Move mode: drop the stash since we moved the changes
         if let Err(e) = git.stash_drop() {
             progress.on_warning(&format!("Failed to drop stash: {}", e));
             stash_preserved = true;
-        } else {
-            stash_preserved = false;
         }
     }
 
@@ -203,7 +262,7 @@ pub fn execute(
 
     change_directory(&last_target_path)?;
     let cd_target = get_current_directory()?;
-    let has_failures = !failures.is_empty();
+    let has_failures = !failures.is_empty() && !restored_to_source;
 
     Ok(CarryResult {
         successes,
@@ -214,5 +273,58 @@ pub fn execute(
         no_changes: false,
         no_valid_targets: false,
         resolution_errors: Vec::new(),
+        unstaged_targets,
+        restored_to_source,
     })
 }
+
+/// Every path with uncommitted changes in `worktree` — staged, unstaged and
+/// untracked, both sides of a rename.
+fn changed_paths(worktree: &Path) -> Result<BTreeSet<String>> {
+    let output = git_command_at(worktree)
+        .args(["status", "--porcelain", "-z", "--untracked-files=all"])
+        .output()
+        .context("Failed to execute git status command")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git status failed in {}: {}",
+            worktree.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(parse_status_paths(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Paths named by `git status --porcelain -z` output. Rename and copy
+/// entries carry their source path as the following NUL-separated field.
+fn parse_status_paths(porcelain: &str) -> BTreeSet<String> {
+    let mut paths = BTreeSet::new();
+    let mut fields = porcelain.split('\0').filter(|f| !f.is_empty());
+    while let Some(entry) = fields.next() {
+        let (Some(status), Some(path)) = (entry.get(..2), entry.get(3..)) else {
+            continue;
+        };
+        paths.insert(path.to_string());
+        if (status.contains('R') || status.contains('C'))
+            && let Some(from) = fields.next()
+        {
+            paths.insert(from.to_string());
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_paths_include_both_sides_of_a_rename() {
+        let porcelain = "M  src/a.rs\0R  new.rs\0old.rs\0?? notes/todo.md\0 D gone.txt\0";
+        let paths: Vec<String> = parse_status_paths(porcelain).into_iter().collect();
+        assert_eq!(
+            paths,
+            ["gone.txt", "new.rs", "notes/todo.md", "old.rs", "src/a.rs"]
+        );
+    }
+}
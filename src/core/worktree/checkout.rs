@@ -4,7 +4,8 @@
 
 use crate::core::layout::{Layout, auto_gitignore_if_needed};
 use crate::core::stage::{PlanCommit, Row, StageEvent, StageId, StepKey, StepSpec};
-use crate::core::{HookOutcome, HookRunner, ProgressSink};
+use crate::core::worktree::path_conflict::{self, Adoption, OnConflict};
+use crate::core::{HookOutcome, HookRunner, PathConflictPrompter, ProgressSink};
 use crate::git::GitCommand;
 use crate::hooks::{HookContext, HookType};
 use crate::multi_remote::path::{
@@ -92,6 +93,9 @@ pub struct CheckoutParams {
     /// facts core needs (the fork fetch + tracking config, rail annotations).
     /// The command layer also forces `checkout_fetch = true` for forge targets.
     pub forge: Option<ForgeCheckout>,
+    /// How to resolve a target path occupied by a non-worktree (`--on-conflict`).
+    /// `None` asks the sink's prompter.
+    pub on_conflict: Option<OnConflict>,
 }
 
 /// A resolved forge PR/MR checkout threaded from the command layer into
@@ -167,7 +171,7 @@ pub fn execute(
     params: &CheckoutParams,
     git: &GitCommand,
     project_root: &Path,
-    sink: &mut (impl ProgressSink + HookRunner),
+    sink: &mut (impl ProgressSink + HookRunner + PathConflictPrompter),
 ) -> Result<CheckoutResult, CheckoutError> {
     validate_branch_name(&params.branch_name)?;
 
@@ -264,10 +268,17 @@ pub fn execute(
             "Worktree directory '{}' already exists, switching to it",
             worktree_path.display()
         ));
-        sink.on_warning(
-            "Worktree may be in detached HEAD state (e.g., from an interrupted rebase). \
-             Run 'git status' to check, and 'git rebase --abort' or 'git checkout <branch>' to recover.",
-        );
+        match super::pin::read_pins(&git_dir).get(&params.branch_name) {
+            Some(pin) => sink.on_warning(&format!(
+                "Worktree is pinned at '{}'; run '{}' to check the branch out again.",
+                pin.pinned_ref,
+                crate::daft_cmd(&format!("pin --remove {}", params.branch_name))
+            )),
+            None => sink.on_warning(
+                "Worktree may be in detached HEAD state (e.g., from an interrupted rebase). \
+                 Run 'git status' to check, and 'git rebase --abort' or 'git checkout <branch>' to recover.",
+            ),
+        }
         change_directory(&worktree_path)?;
 
         return Ok(CheckoutResult {
@@ -288,6 +299,15 @@ pub fn execute(
         });
     }
 
+    crate::core::repo::ensure_outside_git_internals(&worktree_path, &git_dir)?;
+
+    // Anything else occupying the path would make `git worktree add` refuse
+    // it: adopt, relocate, or abort before any plan commits.
+    let path_conflict::Resolution {
+        path: worktree_path,
+        adopt,
+    } = path_conflict::resolve(&worktree_path, params.on_conflict, sink)?;
+
     // Forge PR/MR facts (resolved by the command layer). `fork` is Some only
     // for a cross-repo PR/MR, whose head lives at a base-repo ref rather than a
     // normal remote branch. It is rebound when a same-repo checkout falls back
@@ -580,6 +600,19 @@ pub fn execute(
     // and the worktree in one call; the two plan rows resolve around it as a
     // cosmetic split of the same operation.
     sink.on_stage(&StepKey::new(StageId::CheckOut), StageEvent::Started);
+    if adopt {
+        sink.on_step(&format!(
+            "Adopting existing directory '{}'",
+            worktree_path.display()
+        ));
+    }
+    let adoption = match adopt.then(|| Adoption::stage(&worktree_path)).transpose() {
+        Ok(adoption) => adoption,
+        Err(e) => {
+            restore_stash_on_failure(stash_created, git, sink);
+            return Err(e.into());
+        }
+    };
     let worktree_result = if use_local_branch {
         git.worktree_add(&worktree_path, &params.branch_name)
     } else if let Some(fk) = fork {
@@ -601,9 +634,17 @@ pub fn execute(
                 detail: "failed (see below)".to_string(),
             },
         );
+        if let Some(adoption) = adoption
+            && let Err(restore_err) = adoption.restore()
+        {
+            sink.on_warning(&format!("{restore_err:#}"));
+        }
         restore_stash_on_failure(stash_created, git, sink);
         return Err(anyhow::anyhow!("Failed to create git worktree: {}", e).into());
     }
+    if let Some(adoption) = adoption {
+        adoption.complete()?;
+    }
     sink.on_stage(
         &StepKey::new(StageId::CheckOut),
         StageEvent::Completed { annotation: None },
@@ -631,7 +672,9 @@ pub fn execute(
     }
     sink.on_stage(
         &StepKey::new(StageId::CreateWorktree),
-        StageEvent::Completed { annotation: None },
+        StageEvent::Completed {
+            annotation: adopt.then(|| "adopted existing directory".to_string()),
+        },
     );
 
     // Auto-add worktree parent directory to .gitignore for in-repo layouts
@@ -677,6 +720,8 @@ pub fn execute(
     // Propagation entry point: this site creates a new worktree from an
     // existing source worktree. See checkout_branch.rs for the canonical audit
     // comment covering all worktree-creating entry points.
+    // Keep what propagation writes (daft.local.yml) out of `git status`.
+    crate::core::git_exclude::ensure_best_effort(&worktree_path);
     match crate::hooks::visitor_propagation::propagate(&source_worktree, &worktree_path) {
         Ok(result) => {
             for filename in &result.files_propagated {
@@ -711,6 +756,22 @@ pub fn execute(
         crate::core::shared::link_shared_files_on_create(&worktree_path, &git_dir, project_root);
     crate::core::shared::report_link_results(&link_result, &planned_shared, sink);
 
+    // `links:` symlinks to other repos' worktrees, in the same window and
+    // for the same reason: hooks may build against them.
+    for warning in crate::catalog::links::link_paths_on_create(&worktree_path, &params.branch_name)
+    {
+        sink.on_warning(&warning);
+    }
+
+    // Restore `template:` directories last, so hooks start from a warm tree.
+    match crate::core::template::restore_on_create(&worktree_path, &git_dir) {
+        Ok(restored) if !restored.is_empty() => {
+            sink.on_step(&format!("Restored from template: {}", restored.join(", ")));
+        }
+        Ok(_) => {}
+        Err(e) => sink.on_warning(&format!("template restore failed: {e:#}")),
+    }
+
     // Run post-create hook
     let post_hook_ctx = HookContext::new(
         HookType::PostCreate,
@@ -1111,6 +1172,7 @@ mod timeline_tests {
             at_path: Some(at),
             defer_plan_until_branch_known: false,
             forge: None,
+            on_conflict: None,
         }
     }
 
@@ -6,11 +6,12 @@ use crate::config::git::{COMMITS_AHEAD_THRESHOLD, DEFAULT_COMMIT_COUNT};
 use crate::core::layout::{Layout, auto_gitignore_if_needed};
 use crate::core::settings::PushVerify;
 use crate::core::stage::{PlanCommit, Row, StageEvent, StageId, StepKey, StepSpec};
+use crate::core::worktree::path_conflict::{self, Adoption, OnConflict};
 use crate::core::worktree::ports::NoopStageRunner;
 use crate::core::worktree::push::{
     HookVerdict, PushAction, PushPayload, push_with_hooks, resolve_pre_push_plan,
 };
-use crate::core::{HookOutcome, HookRunner, ProgressSink};
+use crate::core::{HookOutcome, HookRunner, PathConflictPrompter, ProgressSink};
 use crate::executor::presenter::JobPresenter;
 use crate::git::GitCommand;
 use crate::hooks::{HookContext, HookType};
@@ -59,6 +60,9 @@ pub struct CheckoutBranchParams {
     /// Explicit path override for worktree placement (`--at` flag).
     /// When `Some`, takes priority over both `layout` and the default path computation.
     pub at_path: Option<PathBuf>,
+    /// How to resolve a target path occupied by a non-worktree (`--on-conflict`).
+    /// `None` asks the sink's prompter.
+    pub on_conflict: Option<OnConflict>,
 }
 
 /// Result of a checkout-branch operation.
@@ -75,6 +79,22 @@ pub struct CheckoutBranchResult {
     pub post_hook_outcome: HookOutcome,
 }
 
+/// Whether a derived worktree path reads as its branch: it ends with the
+/// branch's components (`…/feature/x`), with the sanitized name
+/// (`…/feature-x`), or with the sanitized name after the repo's
+/// (`…/repo.feature-x`, as the sibling layout renders it).
+pub(crate) fn path_follows_branch(path: &Path, branch: &str) -> bool {
+    let sanitized = crate::core::layout::template::sanitize(branch);
+    path.ends_with(branch)
+        || path.file_name().is_some_and(|name| {
+            let name = name.to_string_lossy();
+            name == sanitized
+                || name
+                    .strip_suffix(sanitized.as_str())
+                    .is_some_and(|repo| repo.len() > 1 && repo.ends_with('.'))
+        })
+}
+
 /// Execute the checkout-branch operation.
 ///
 /// `presenter` reports the pre-push hook run on the automatic upstream push
@@ -84,7 +104,7 @@ pub fn execute(
     git: &GitCommand,
     project_root: &Path,
     presenter: Option<&Arc<dyn JobPresenter>>,
-    sink: &mut (impl ProgressSink + HookRunner),
+    sink: &mut (impl ProgressSink + HookRunner + PathConflictPrompter),
 ) -> Result<CheckoutBranchResult> {
     validate_branch_name(&params.new_branch_name)?;
 
@@ -124,6 +144,26 @@ pub fn execute(
             params.multi_remote_enabled,
         )
     };
+    crate::core::repo::ensure_outside_git_internals(&worktree_path, &git_dir)?;
+    // Show where the worktree goes before anything is created, and confirm
+    // it when the path template no longer reads as the branch (a fixed
+    // directory, a rewritten name). An explicit `--at` is taken as meant.
+    if params.at_path.is_none()
+        && !sink.confirm_worktree_path(
+            &params.new_branch_name,
+            &worktree_path,
+            path_follows_branch(&worktree_path, &params.new_branch_name),
+        )
+    {
+        anyhow::bail!(
+            "Aborted: not creating the worktree at '{}'",
+            worktree_path.display()
+        );
+    }
+    let path_conflict::Resolution {
+        path: worktree_path,
+        adopt,
+    } = path_conflict::resolve(&worktree_path, params.on_conflict, sink)?;
 
     // Commit the execution plan (#651): the requested base and the worktree
     // path are resolved, and everything left to do is planned work — the
@@ -230,6 +270,19 @@ pub fn execute(
     // the worktree in one call; the three plan rows resolve around it as a
     // cosmetic split of the same operation.
     sink.on_stage(&StepKey::new(StageId::CreateBranch), StageEvent::Started);
+    if adopt {
+        sink.on_step(&format!(
+            "Adopting existing directory '{}'",
+            worktree_path.display()
+        ));
+    }
+    let adoption = match adopt.then(|| Adoption::stage(&worktree_path)).transpose() {
+        Ok(adoption) => adoption,
+        Err(e) => {
+            restore_stash_on_failure(stash_created, carry_source.as_deref(), git, sink);
+            return Err(e);
+        }
+    };
     if let Err(e) = git.worktree_add_new_branch(
         &worktree_path,
         &params.new_branch_name,
@@ -242,9 +295,17 @@ pub fn execute(
                 detail: "failed (see below)".to_string(),
             },
         );
+        if let Some(adoption) = adoption
+            && let Err(restore_err) = adoption.restore()
+        {
+            sink.on_warning(&format!("{restore_err:#}"));
+        }
         restore_stash_on_failure(stash_created, carry_source.as_deref(), git, sink);
         anyhow::bail!("Failed to create git worktree: {}", e);
     }
+    if let Some(adoption) = adoption {
+        adoption.complete()?;
+    }
     // Remember what this worktree is for (see checkout.rs). Best-effort.
     if let Some(store) = crate::core::worktree::identity_store::IdentityStore::open(&git_dir) {
         store.record(&worktree_path, &params.new_branch_name);
@@ -274,7 +335,9 @@ pub fn execute(
     }
     sink.on_stage(
         &StepKey::new(StageId::CreateWorktree),
-        StageEvent::Completed { annotation: None },
+        StageEvent::Completed {
+            annotation: adopt.then(|| "adopted existing directory".to_string()),
+        },
     );
 
     // Auto-add worktree parent directory to .gitignore for in-repo layouts
@@ -317,6 +380,8 @@ pub fn execute(
     //   - checkout's early-return paths (existing worktree for branch / existing dir
     //     on disk): navigate to an already-materialized worktree — no new worktree
     //     is created, no propagation step.
+    // Keep what propagation writes (daft.local.yml) out of `git status`.
+    crate::core::git_exclude::ensure_best_effort(&worktree_path);
     match crate::hooks::visitor_propagation::propagate(&source_worktree, &worktree_path) {
         Ok(result) => {
             for filename in &result.files_propagated {
@@ -351,6 +416,23 @@ pub fn execute(
         crate::core::shared::link_shared_files_on_create(&worktree_path, &git_dir, project_root);
     crate::core::shared::report_link_results(&link_result, &planned_shared, sink);
 
+    // `links:` symlinks to other repos' worktrees, in the same window and
+    // for the same reason: hooks may build against them.
+    for warning in
+        crate::catalog::links::link_paths_on_create(&worktree_path, &params.new_branch_name)
+    {
+        sink.on_warning(&warning);
+    }
+
+    // Restore `template:` directories last, so hooks start from a warm tree.
+    match crate::core::template::restore_on_create(&worktree_path, &git_dir) {
+        Ok(restored) if !restored.is_empty() => {
+            sink.on_step(&format!("Restored from template: {}", restored.join(", ")));
+        }
+        Ok(_) => {}
+        Err(e) => sink.on_warning(&format!("template restore failed: {e:#}")),
+    }
+
     // Run post-create hook
     let post_hook_ctx = HookContext::new(
         HookType::PostCreate,
@@ -904,7 +986,7 @@ fn push_if_enabled(
 
 #[cfg(test)]
 mod tests {
-    use super::{CheckoutBranchParams, push_if_enabled};
+    use super::{CheckoutBranchParams, path_follows_branch, push_if_enabled};
     use crate::core::ProgressSink;
     use crate::core::settings::PushVerify;
     use crate::core::stage::{StageEvent, StageId, StepKey};
@@ -919,6 +1001,40 @@ mod tests {
     // The pure `resolve_pre_push` decision tests live with the fn in
     // `core::worktree::push` since it moved there for the delete sites (#747).
 
+    #[test]
+    fn path_follows_branch_accepts_nested_and_sanitized_names() {
+        assert!(path_follows_branch(Path::new("/r/feature/x"), "feature/x"));
+        assert!(path_follows_branch(
+            Path::new("/r/origin/feature/x"),
+            "feature/x"
+        ));
+        assert!(path_follows_branch(
+            Path::new("/wt/r/feature-x"),
+            "feature/x"
+        ));
+        // sibling: {{ repo }}.{{ branch | sanitize }}
+        assert!(path_follows_branch(
+            Path::new("/src/src.feature-x"),
+            "feature/x"
+        ));
+        // contained-classic: {{ repo_path }}/{{ branch | repo }}
+        assert!(path_follows_branch(
+            Path::new("/src/feature/x"),
+            "feature/x"
+        ));
+        assert!(!path_follows_branch(
+            Path::new("/r/.feature-x"),
+            "feature/x"
+        ));
+        assert!(!path_follows_branch(
+            Path::new("/r/src-feature-x"),
+            "feature/x"
+        ));
+        assert!(!path_follows_branch(Path::new("/r/scratch"), "feature/x"));
+        assert!(!path_follows_branch(Path::new("/r/x"), "feature/x"));
+        assert!(!path_follows_branch(Path::new("/r/FEATURE-X"), "feature/x"));
+    }
+
     // --- integration: spinner coordination around the pre-push render (#679) ---
 
     /// Records the spinner-control calls `push_if_enabled` makes so a test can
@@ -1013,6 +1129,7 @@ mod tests {
             checkout_fetch: false,
             layout: None,
             at_path: None,
+            on_conflict: None,
         }
     }
 
@@ -1196,6 +1313,112 @@ mod timeline_tests {
         }
     }
 
+    fn local_params(
+        branch: &str,
+        at: PathBuf,
+        on_conflict: Option<OnConflict>,
+    ) -> CheckoutBranchParams {
+        CheckoutBranchParams {
+            new_branch_name: branch.to_string(),
+            base_branch_name: Some("main".to_string()),
+            carry: false,
+            no_carry: true,
+            remote: None,
+            remote_name: "origin".to_string(),
+            multi_remote_enabled: false,
+            multi_remote_default: "origin".to_string(),
+            checkout_branch_carry: false,
+            checkout_push: false,
+            no_verify: false,
+            push_verify: PushVerify::Auto,
+            push_verify_key: crate::settings::keys::PUSH_VERIFY,
+            checkout_fetch: false,
+            layout: None,
+            at_path: Some(at),
+            on_conflict,
+        }
+    }
+
+    /// `--on-conflict adopt` turns an occupied non-worktree directory into
+    /// the worktree: the branch lands in the index, the user's files stay on
+    /// disk as uncommitted changes, and no plan commits for a refusal.
+    #[test]
+    #[serial]
+    fn occupied_path_is_adopted_or_refused() {
+        let _state = crate::store::paths::IsolatedStateDir::new();
+        let _cwd = CwdGuard::new();
+        let tmp = tempfile::tempdir().unwrap();
+        git(tmp.path(), &["init", "-q", "-b", "main"]);
+        std::fs::write(tmp.path().join("tracked.txt"), "base\n").unwrap();
+        git(tmp.path(), &["add", "tracked.txt"]);
+        git(tmp.path(), &["commit", "-q", "-m", "init"]);
+        std::env::set_current_dir(tmp.path()).unwrap();
+
+        let occupied = tmp.path().join("feat-x");
+        std::fs::create_dir(&occupied).unwrap();
+        std::fs::write(occupied.join("tracked.txt"), "edited\n").unwrap();
+        std::fs::write(occupied.join("notes.txt"), "mine\n").unwrap();
+
+        let git_cmd = GitCommand::new(true);
+        let mut sink = RecordingStageSink::default();
+        let refused = execute(
+            &local_params("feat-x", occupied.clone(), None),
+            &git_cmd,
+            tmp.path(),
+            None,
+            &mut sink,
+        );
+        assert!(refused.is_err(), "non-interactive default refuses");
+        assert!(sink.plan.is_none(), "refusal happens before the plan");
+        assert!(occupied.join("notes.txt").is_file(), "nothing was moved");
+
+        let mut sink = RecordingStageSink::default();
+        execute(
+            &local_params("feat-x", occupied.clone(), Some(OnConflict::Adopt)),
+            &git_cmd,
+            tmp.path(),
+            None,
+            &mut sink,
+        )
+        .expect("adoption succeeds");
+        assert!(occupied.join(".git").is_file(), "registered as a worktree");
+        assert_eq!(
+            std::fs::read_to_string(occupied.join("tracked.txt")).unwrap(),
+            "edited\n",
+            "the user's copy wins over the checkout"
+        );
+        assert!(occupied.join("notes.txt").is_file());
+    }
+
+    /// `--on-conflict suffix` leaves the occupant alone and creates the
+    /// worktree at the first free `-N` sibling.
+    #[test]
+    #[serial]
+    fn occupied_path_suffix_creates_a_sibling() {
+        let _state = crate::store::paths::IsolatedStateDir::new();
+        let _cwd = CwdGuard::new();
+        let tmp = tempfile::tempdir().unwrap();
+        git(tmp.path(), &["init", "-q", "-b", "main"]);
+        git(tmp.path(), &["commit", "--allow-empty", "-q", "-m", "init"]);
+        std::env::set_current_dir(tmp.path()).unwrap();
+
+        let occupied = tmp.path().join("feat-x");
+        std::fs::write(&occupied, "not a directory").unwrap();
+
+        let git_cmd = GitCommand::new(true);
+        let mut sink = RecordingStageSink::default();
+        let result = execute(
+            &local_params("feat-x", occupied.clone(), Some(OnConflict::Suffix)),
+            &git_cmd,
+            tmp.path(),
+            None,
+            &mut sink,
+        )
+        .expect("suffixed creation succeeds");
+        assert_eq!(result.worktree_path, tmp.path().join("feat-x-2"));
+        assert!(occupied.is_file(), "the occupant is untouched");
+    }
+
     /// The plan commits with the locked row set, the header carries the
     /// requested base, and events narrate the cosmetic
     /// branch/checkout/worktree split plus the expected push skip (#651).
@@ -1229,6 +1452,7 @@ mod timeline_tests {
             checkout_fetch: false,
             layout: None,
             at_path: Some(worktree_path.clone()),
+            on_conflict: None,
         };
 
         let git_cmd = GitCommand::new(true);
@@ -1331,6 +1555,7 @@ mod timeline_tests {
             checkout_fetch: true,
             layout: None,
             at_path: Some(worktree_path.clone()),
+            on_conflict: None,
         };
 
         let git_cmd = GitCommand::new(true);
@@ -1432,6 +1657,7 @@ mod timeline_tests {
             checkout_fetch: false,
             layout: None,
             at_path: Some(worktree_path.clone()),
+            on_conflict: None,
         };
 
         let git_cmd = GitCommand::new(true);
@@ -1543,6 +1769,7 @@ mod timeline_tests {
             checkout_fetch: false,
             layout: None,
             at_path: Some(new_wt.clone()),
+            on_conflict: None,
         };
         let git_cmd = GitCommand::new(true);
         let mut sink = RecordingStageSink::default();
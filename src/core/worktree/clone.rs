@@ -27,6 +27,11 @@ pub struct BareCloneParams {
     pub multi_remote_default: String,
     pub checkout_upstream: bool,
     pub use_gitoxide: bool,
+    /// `daft.clone.nameTemplate` — where the clone lands.
+    pub name_template: String,
+    /// `daft.nestedProjects` — whether the clone may land inside another
+    /// repository.
+    pub nested_projects: crate::settings::NestedProjects,
 }
 
 /// Result of the bare clone phase.
@@ -36,6 +41,10 @@ pub struct BareCloneParams {
 pub struct BareCloneResult {
     pub repo_name: String,
     pub parent_dir: PathBuf,
+    /// The outermost directory the clone created: `parent_dir`, or the top
+    /// of the tree a nested `daft.clone.nameTemplate` made for it. Cleanup
+    /// after a failed clone removes this.
+    pub created_dir: PathBuf,
     pub git_dir: PathBuf,
     pub default_branch: String,
     pub target_branch: String,
@@ -93,19 +102,36 @@ pub fn clone_bare_phase(
     params: &BareCloneParams,
     progress: &mut dyn ProgressSink,
 ) -> Result<BareCloneResult> {
-    let repo_name = crate::extract_repo_name(&params.repository_url)?;
+    let repo_name = crate::core::repo::extract_clone_repo_name(&params.repository_url)?;
     progress.on_step(&format!("Repository name detected: '{repo_name}'"));
 
     let (default_branch, target_branch, branch_exists, is_empty) =
         detect_branches(params, progress)?;
 
-    let parent_dir = PathBuf::from(&repo_name);
+    let parent_dir =
+        crate::core::repo::clone_target_dir(&params.repository_url, &params.name_template)?;
 
     if path_exists(&parent_dir) {
-        anyhow::bail!("Target path './{} already exists.", parent_dir.display());
+        let hint = if params.name_template == crate::settings::defaults::CLONE_NAME_TEMPLATE {
+            "\nTo keep same-named repositories from different owners apart, name clones \
+             after their owner:\n  git config --global daft.clone.nameTemplate '{org}-{repo}'"
+        } else {
+            ""
+        };
+        anyhow::bail!(
+            "Target path './{} already exists.{hint}",
+            parent_dir.display()
+        );
+    }
+
+    let target = get_current_directory()?.join(&parent_dir);
+    if let Some(warning) = crate::core::nesting::check_new_project(&target, params.nested_projects)?
+    {
+        progress.on_warning(&warning);
     }
 
     progress.on_step("Creating repository directory...");
+    let created_dir = crate::utils::first_missing_ancestor(&parent_dir);
     create_directory(&parent_dir)?;
 
     let git_dir = parent_dir.join(".git");
@@ -117,7 +143,7 @@ pub fn clone_bare_phase(
     ));
 
     if let Err(e) = git.clone_bare(&params.repository_url, &git_dir) {
-        remove_directory(&parent_dir).ok();
+        remove_directory(&created_dir).ok();
         return Err(e.context("Git clone failed"));
     }
 
@@ -154,6 +180,7 @@ pub fn clone_bare_phase(
     Ok(BareCloneResult {
         repo_name,
         parent_dir,
+        created_dir,
         git_dir,
         default_branch,
         target_branch,
@@ -391,6 +418,9 @@ pub fn setup_bare_worktrees(
         // (single / orphan / all-branches), and they are attached at this
         // point, which is the only state safe to record from. Best-effort.
         record_clone_identities(&git, &bare_result.git_dir);
+        // Clone writes no daft.local.yml itself, but every later worktree
+        // inherits the exclude file, so lay the managed block down now.
+        crate::core::git_exclude::ensure_best_effort(&bare_result.git_dir);
 
         progress.on_step(&format!(
             "Changing directory to worktree: './{}'",
@@ -5,8 +5,10 @@
 
 use crate::core::ProgressSink;
 use crate::git::GitCommand;
+use crate::store::models::WorktreePinRow;
 use crate::utils::*;
 use anyhow::Result;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 /// A parsed refspec describing which remote branch to pull into which worktree.
@@ -137,8 +139,14 @@ pub fn execute(
     let remote_name = &params.remote_name;
     let original_dir = get_current_directory()?;
 
+    // Pinned worktrees are detached, so `--all` passes over them already;
+    // the pins are for targets that name one explicitly.
+    let pins = crate::core::repo::get_git_common_dir()
+        .map(|dir| super::pin::read_pins(&dir))
+        .unwrap_or_default();
+
     // Determine refspecs and their resolved worktree paths
-    let refspecs = determine_refspecs(params, git, project_root, progress)?;
+    let refspecs = determine_refspecs(params, git, project_root, &pins, progress)?;
 
     if refspecs.is_empty() {
         return Ok(FetchResult {
@@ -172,6 +180,21 @@ pub fn execute(
             .unwrap_or("unknown")
             .to_string();
 
+        if let Some(pin) = pins.get(&refspec.destination) {
+            progress.on_warning(&format!(
+                "Skipping '{worktree_name}': pinned to {}",
+                pin.pinned_ref
+            ));
+            results.push(WorktreeFetchResult {
+                worktree_name,
+                success: true,
+                message: format!("Skipped: pinned to {}", pin.pinned_ref),
+                skipped: true,
+                ..Default::default()
+            });
+            continue;
+        }
+
         let result = process_worktree(
             git,
             target_path,
@@ -200,6 +223,7 @@ fn determine_refspecs(
     params: &FetchParams,
     git: &GitCommand,
     project_root: &Path,
+    pins: &HashMap<String, WorktreePinRow>,
     progress: &mut dyn ProgressSink,
 ) -> Result<Vec<(UpdateRefSpec, PathBuf)>> {
     if params.all {
@@ -216,8 +240,19 @@ fn determine_refspecs(
             })
             .collect())
     } else if params.targets.is_empty() {
-        // No args: self-referencing refspec for current worktree
+        // No args: self-referencing refspec for current worktree. A pinned
+        // worktree has no branch checked out; name it by its pin so it is
+        // reported as pinned rather than as a detached HEAD error.
         let current = git.get_current_worktree_path()?;
+        if let Some(pin) = pins.values().find(|pin| {
+            super::pin::locate(git, pin).ok().flatten().as_deref() == Some(current.as_path())
+        }) {
+            let refspec = UpdateRefSpec {
+                source: pin.branch.clone(),
+                destination: pin.branch.clone(),
+            };
+            return Ok(vec![(refspec, current)]);
+        }
         let branch = git.symbolic_ref_short_head()?;
         let refspec = UpdateRefSpec {
             source: branch.clone(),
@@ -231,6 +266,12 @@ fn determine_refspecs(
 
         for target in &params.targets {
             let refspec = parse_refspec(target);
+            if let Some(pin) = pins.get(&refspec.destination)
+                && let Ok(Some(path)) = super::pin::locate(git, pin)
+            {
+                resolved.push((refspec, path));
+                continue;
+            }
             // Resolve the destination branch to a worktree path
             match git.resolve_worktree_path(&refspec.destination, project_root) {
                 Ok(path) => resolved.push((refspec, path)),
@@ -24,6 +24,9 @@ pub struct InitParams {
     pub multi_remote_enabled: bool,
     /// Default remote name for multi-remote.
     pub multi_remote_default: String,
+    /// `daft.nestedProjects` — whether the repository may be created inside
+    /// another one.
+    pub nested_projects: crate::settings::NestedProjects,
 }
 
 /// Result of an init operation.
@@ -87,6 +90,12 @@ pub fn execute(
         anyhow::bail!("Target path './{} already exists.", parent_dir.display());
     }
 
+    let target = get_current_directory()?.join(&parent_dir);
+    if let Some(warning) = crate::core::nesting::check_new_project(&target, params.nested_projects)?
+    {
+        progress.on_warning(&warning);
+    }
+
     progress.on_step("Creating repository directory...");
     create_directory(&parent_dir)?;
 
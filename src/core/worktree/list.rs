@@ -133,6 +133,8 @@ pub struct WorktreeInfo {
     pub identity_source: Option<super::identity::IdentitySource>,
     /// The recorded intended branch disagrees with what is checked out.
     pub drifted: bool,
+    /// The ref this worktree is pinned to by `daft pin`, if any.
+    pub pinned: Option<String>,
     /// The PR/MR this branch tracks (from `branch.<name>.merge`), or `None`.
     /// Local config only — no network.
     pub forge_ref: Option<super::forge_ref::ForgeBranchRef>,
@@ -175,6 +177,7 @@ impl WorktreeInfo {
             op: None,
             identity_source: None,
             drifted: false,
+            pinned: None,
             forge_ref: None,
         }
     }
@@ -214,6 +217,7 @@ impl WorktreeInfo {
             op: None,
             identity_source: None,
             drifted: false,
+            pinned: None,
             forge_ref: None,
         }
     }
@@ -841,8 +845,10 @@ pub fn collect_worktree_info(
     // is. Bare entries resolve to `None` and are skipped.
     // Persisted records are a pure read: absent store, absent records, and
     // the resolution falls through to live state exactly as before.
-    let records = crate::core::repo::get_git_common_dir()
-        .map(|dir| super::identity_store::read_identities(&dir))
+    let common_dir = crate::core::repo::get_git_common_dir().ok();
+    let records = common_dir
+        .as_deref()
+        .map(super::identity_store::read_identities)
         .unwrap_or_default();
     let identities = super::identity::resolve_identities_with(&entries, &records);
     let mut infos = Vec::new();
@@ -989,10 +995,15 @@ pub fn collect_worktree_info(
             op: identity.op,
             identity_source: Some(identity.source),
             drifted: identity.drifted,
+            pinned: None,
             forge_ref,
         });
     }
 
+    if let Some(dir) = &common_dir {
+        super::pin::apply_pins(&mut infos, &super::pin::read_pins(dir));
+    }
+
     // Size walk: batched across all worktrees so their trees walk concurrently
     // under one shared job budget (see core::size_walk), instead of the old
     // one-worktree-at-a-time sequential walk. `size_jobs` is resolved by the
@@ -1136,6 +1147,7 @@ pub fn collect_branch_info(
                 op: None,
                 identity_source: None,
                 drifted: false,
+                pinned: None,
                 forge_ref: None,
             });
         }
@@ -1226,6 +1238,7 @@ pub fn collect_branch_info(
                 op: None,
                 identity_source: None,
                 drifted: false,
+                pinned: None,
                 forge_ref: None,
             });
         }
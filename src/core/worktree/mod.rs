@@ -9,6 +9,7 @@
 //! [`crate::core::layout::transform`]. New code should call that module
 //! directly.
 
+pub mod adopt_worktree;
 pub mod branch_delete;
 pub mod branch_source;
 pub mod carry;
@@ -30,6 +31,8 @@ pub mod list_stream;
 pub mod merge;
 pub mod merge_set_default;
 pub mod merged;
+pub mod path_conflict;
+pub mod pin;
 pub mod porcelain;
 pub mod ports;
 pub mod pr_rows;
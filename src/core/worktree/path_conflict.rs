@@ -0,0 +1,347 @@
+//! Occupied worktree target paths.
+//!
+//! `git worktree add` refuses a non-empty target directory, so a path the
+//! layout computes for a branch can be blocked by something that is not a
+//! worktree of this repository: a leftover build directory, a hand-copied
+//! checkout, a stray file. Instead of failing on git's error, the creation
+//! cores resolve the collision here — adopt the directory's contents as the
+//! new worktree, place the worktree at a suffixed sibling path, or abort —
+//! either from `--on-conflict` or by asking the user.
+
+use crate::core::PathConflictPrompter;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// How to resolve an occupied worktree target path (`--on-conflict`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OnConflict {
+    /// Turn the existing directory into the worktree, keeping its contents
+    /// as uncommitted changes against the branch.
+    Adopt,
+    /// Create the worktree at the first free `<path>-N` sibling instead.
+    Suffix,
+    /// Abort without touching anything.
+    Fail,
+}
+
+/// What occupies a target path that git would refuse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Occupant {
+    /// A non-empty directory with no git metadata of its own.
+    Directory,
+    /// A directory with its own `.git` directory — a separate clone.
+    Clone,
+    /// A directory whose `.git` file points at some worktree registration
+    /// that is not this branch's.
+    ForeignWorktree,
+    /// A file or symlink.
+    File,
+}
+
+impl Occupant {
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Directory => "a directory that is not a worktree",
+            Self::Clone => "a separate git clone",
+            Self::ForeignWorktree => "a worktree that git does not list for this branch",
+            Self::File => "a file",
+        }
+    }
+}
+
+/// One collision, as shown to the prompter.
+pub struct PathConflict<'a> {
+    pub path: &'a Path,
+    pub occupant: Occupant,
+    /// Where `suffix` would place the worktree.
+    pub suffixed: &'a Path,
+}
+
+/// Where the worktree goes once the collision is resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolution {
+    pub path: PathBuf,
+    /// The path holds a directory whose contents become the worktree's.
+    pub adopt: bool,
+}
+
+/// Classify what occupies `path`. `None` means git can create the worktree
+/// there as-is: the path is missing or an empty directory.
+pub fn occupant(path: &Path) -> Option<Occupant> {
+    let meta = std::fs::symlink_metadata(path).ok()?;
+    if !meta.is_dir() {
+        return Some(Occupant::File);
+    }
+    // An empty directory is fine by git.
+    let _first_entry = std::fs::read_dir(path).ok()?.next()?;
+    let dot_git = path.join(".git");
+    if dot_git.is_dir() {
+        Some(Occupant::Clone)
+    } else if dot_git.is_file() {
+        Some(Occupant::ForeignWorktree)
+    } else {
+        Some(Occupant::Directory)
+    }
+}
+
+/// First `<path>-N` (N ≥ 2) that git can create a worktree at.
+pub fn suffixed_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    (2..)
+        .map(|n| path.with_file_name(format!("{name}-{n}")))
+        .find(|candidate| occupant(candidate).is_none())
+        .expect("unbounded suffix search always finds a free path")
+}
+
+/// Resolve the worktree target path. An explicit `policy` wins; otherwise the
+/// prompter decides, and a prompter without an answer (non-interactive
+/// contexts) fails with a message naming the flag.
+pub fn resolve(
+    path: &Path,
+    policy: Option<OnConflict>,
+    prompter: &mut impl PathConflictPrompter,
+) -> Result<Resolution> {
+    let Some(occupant) = occupant(path) else {
+        return Ok(Resolution {
+            path: path.to_path_buf(),
+            adopt: false,
+        });
+    };
+    let suffixed = suffixed_path(path);
+    let choice = policy.or_else(|| {
+        prompter.on_path_conflict(&PathConflict {
+            path,
+            occupant,
+            suffixed: &suffixed,
+        })
+    });
+    match choice {
+        Some(OnConflict::Suffix) => Ok(Resolution {
+            path: suffixed,
+            adopt: false,
+        }),
+        Some(OnConflict::Adopt) if occupant == Occupant::Directory => Ok(Resolution {
+            path: path.to_path_buf(),
+            adopt: true,
+        }),
+        Some(OnConflict::Adopt) => anyhow::bail!(
+            "cannot adopt '{}': it is {}, which carries its own git metadata.\n  \
+             tip: move it aside, or pass --on-conflict suffix to create the worktree at '{}'",
+            path.display(),
+            occupant.describe(),
+            suffixed.display()
+        ),
+        Some(OnConflict::Fail) | None => anyhow::bail!(
+            "'{}' already exists and is {}.\n  \
+             tip: pass --on-conflict suffix to create the worktree at '{}'{}",
+            path.display(),
+            occupant.describe(),
+            suffixed.display(),
+            if occupant == Occupant::Directory {
+                ", or --on-conflict adopt to turn the directory into the worktree"
+            } else {
+                ""
+            }
+        ),
+    }
+}
+
+/// An adopted directory moved aside while `git worktree add` populates its
+/// path. Callers either [`complete`](Self::complete) it once the worktree
+/// exists or [`restore`](Self::restore) it when creation failed.
+pub struct Adoption {
+    target: PathBuf,
+    staged: PathBuf,
+}
+
+impl Adoption {
+    /// Move the directory at `target` to a hidden sibling so git sees a free
+    /// path.
+    pub fn stage(target: &Path) -> Result<Self> {
+        let name = target
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let staged = target.with_file_name(format!(".{name}.daft-adopt"));
+        if staged.exists() {
+            anyhow::bail!(
+                "cannot adopt '{}': '{}' is left over from an interrupted adoption — \
+                 restore or remove it first",
+                target.display(),
+                staged.display()
+            );
+        }
+        std::fs::rename(target, &staged)
+            .with_context(|| format!("could not move '{}' aside for adoption", target.display()))?;
+        Ok(Self {
+            target: target.to_path_buf(),
+            staged,
+        })
+    }
+
+    /// Replace the fresh checkout's files with the adopted directory's, so
+    /// the index holds the branch and the working tree holds what the user
+    /// had: differences surface as ordinary uncommitted changes.
+    pub fn complete(self) -> Result<()> {
+        for entry in std::fs::read_dir(&self.target)? {
+            let entry = entry?;
+            if entry.file_name() == ".git" {
+                continue;
+            }
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                std::fs::remove_dir_all(&path)?;
+            } else {
+                std::fs::remove_file(&path)?;
+            }
+        }
+        for entry in std::fs::read_dir(&self.staged)? {
+            let entry = entry?;
+            std::fs::rename(entry.path(), self.target.join(entry.file_name()))?;
+        }
+        std::fs::remove_dir(&self.staged)
+            .with_context(|| format!("could not remove '{}'", self.staged.display()))
+    }
+
+    /// Put the directory back where it was after a failed creation.
+    pub fn restore(self) -> Result<()> {
+        if self.target.is_dir() {
+            let _ = std::fs::remove_dir(&self.target);
+        }
+        std::fs::rename(&self.staged, &self.target).with_context(|| {
+            format!(
+                "could not restore '{}' from '{}'",
+                self.target.display(),
+                self.staged.display()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NullSink;
+
+    struct Answer(Option<OnConflict>);
+
+    impl PathConflictPrompter for Answer {
+        fn on_path_conflict(&mut self, _conflict: &PathConflict<'_>) -> Option<OnConflict> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn missing_and_empty_paths_are_free() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(occupant(&tmp.path().join("nope")), None);
+        std::fs::create_dir(tmp.path().join("empty")).unwrap();
+        assert_eq!(occupant(&tmp.path().join("empty")), None);
+    }
+
+    #[test]
+    fn occupants_are_classified() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("dir");
+        std::fs::create_dir(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "a").unwrap();
+        assert_eq!(occupant(&dir), Some(Occupant::Directory));
+
+        let clone = tmp.path().join("clone");
+        std::fs::create_dir_all(clone.join(".git")).unwrap();
+        assert_eq!(occupant(&clone), Some(Occupant::Clone));
+
+        let wt = tmp.path().join("wt");
+        std::fs::create_dir(&wt).unwrap();
+        std::fs::write(wt.join(".git"), "gitdir: /elsewhere").unwrap();
+        assert_eq!(occupant(&wt), Some(Occupant::ForeignWorktree));
+
+        let file = tmp.path().join("file");
+        std::fs::write(&file, "x").unwrap();
+        assert_eq!(occupant(&file), Some(Occupant::File));
+    }
+
+    #[test]
+    fn suffix_skips_occupied_siblings() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("feat");
+        std::fs::write(&path, "x").unwrap();
+        std::fs::write(tmp.path().join("feat-2"), "x").unwrap();
+        assert_eq!(suffixed_path(&path), tmp.path().join("feat-3"));
+    }
+
+    #[test]
+    fn non_interactive_collision_fails_naming_the_flag() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("feat");
+        std::fs::create_dir(&path).unwrap();
+        std::fs::write(path.join("a.txt"), "a").unwrap();
+        let err = resolve(&path, None, &mut NullSink).unwrap_err().to_string();
+        assert!(err.contains("--on-conflict suffix"), "got: {err}");
+        assert!(err.contains("--on-conflict adopt"), "got: {err}");
+    }
+
+    #[test]
+    fn policy_overrides_prompter() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("feat");
+        std::fs::write(&path, "x").unwrap();
+        let resolution = resolve(
+            &path,
+            Some(OnConflict::Suffix),
+            &mut Answer(Some(OnConflict::Fail)),
+        )
+        .unwrap();
+        assert_eq!(resolution.path, tmp.path().join("feat-2"));
+        assert!(!resolution.adopt);
+    }
+
+    #[test]
+    fn adopt_refuses_a_clone() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("feat");
+        std::fs::create_dir_all(path.join(".git")).unwrap();
+        let err = resolve(&path, None, &mut Answer(Some(OnConflict::Adopt)))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("cannot adopt"), "got: {err}");
+    }
+
+    #[test]
+    fn adoption_keeps_user_files_over_checkout() {
+        let tmp = tempfile::tempdir().unwrap();
+        let target = tmp.path().join("feat");
+        std::fs::create_dir(&target).unwrap();
+        std::fs::write(target.join("mine.txt"), "mine").unwrap();
+
+        let adoption = Adoption::stage(&target).unwrap();
+        assert!(!target.exists());
+        // Stand-in for `git worktree add`.
+        std::fs::create_dir(&target).unwrap();
+        std::fs::write(target.join(".git"), "gitdir: x").unwrap();
+        std::fs::write(target.join("tracked.txt"), "from branch").unwrap();
+        adoption.complete().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(target.join("mine.txt")).unwrap(),
+            "mine"
+        );
+        assert!(!target.join("tracked.txt").exists());
+        assert!(target.join(".git").is_file());
+        assert!(!tmp.path().join(".feat.daft-adopt").exists());
+    }
+
+    #[test]
+    fn adoption_restore_puts_directory_back() {
+        let tmp = tempfile::tempdir().unwrap();
+        let target = tmp.path().join("feat");
+        std::fs::create_dir(&target).unwrap();
+        std::fs::write(target.join("mine.txt"), "mine").unwrap();
+
+        Adoption::stage(&target).unwrap().restore().unwrap();
+        assert!(target.join("mine.txt").is_file());
+    }
+}
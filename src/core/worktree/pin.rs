@@ -0,0 +1,356 @@
+//! Core logic for `daft pin`: holding a worktree at a fixed ref.
+//!
+//! Pinning detaches the branch's worktree at the ref and records the pin.
+//! The branch ref itself is untouched — only the checkout is held — so
+//! unpinning is a plain checkout of the branch again. Because a pinned
+//! worktree is detached, the update, sync and push paths that walk attached
+//! worktrees pass over it on their own; the record is what lets the paths
+//! that take a branch *by name* (`daft update <branch>`, prune's gone-branch
+//! scan) recognise it and stay away, and what lets `daft list` say why the
+//! worktree is detached.
+//!
+//! Before detaching, the worktree's intended branch is recorded in the
+//! identity store ([`super::identity_store`]), so the list keeps naming the
+//! row after the branch rather than showing an anonymous sandbox.
+//!
+//! Reads are pure — they never create the store. Writes are not best-effort:
+//! a pin that was not recorded would silently stop protecting the worktree.
+
+use super::identity_store::{IdentityStore, worktree_id_for};
+use super::list::WorktreeInfo;
+use super::porcelain::parse_worktree_list_porcelain;
+use crate::core::ProgressSink;
+use crate::git::GitCommand;
+use crate::store::models::WorktreePinRow;
+use crate::store::repos::WorktreePinsRepo;
+use crate::store::{Pool, paths};
+use crate::utils::git_command_at;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+/// Outcome of pinning a branch's worktree.
+pub struct PinResult {
+    /// The worktree that was pinned.
+    pub path: PathBuf,
+    /// The commit the worktree now holds.
+    pub commit_sha: String,
+    /// The ref the worktree was pinned to before, when this replaced a pin.
+    pub previous_ref: Option<String>,
+}
+
+/// Pin `branch`'s worktree to `pinned_ref`.
+///
+/// The worktree must be clean: detaching carries local changes along, and a
+/// pinned checkout with edits in it is no longer the known-good state the
+/// pin promises. Re-pinning an already pinned branch moves it to the new ref.
+pub fn pin(
+    git: &GitCommand,
+    git_common_dir: &Path,
+    branch: &str,
+    pinned_ref: &str,
+    sink: &mut dyn ProgressSink,
+) -> Result<PinResult> {
+    let repo_hash = crate::core::repo_identity::compute_repo_id_from_common_dir(git_common_dir)?;
+    let existing = read_pins(git_common_dir).remove(branch);
+
+    let path = match &existing {
+        Some(pin) => locate(git, pin)?.with_context(|| {
+            format!(
+                "The pinned worktree for '{branch}' no longer exists; release the pin with '{}'",
+                crate::daft_cmd(&format!("pin --remove {branch}"))
+            )
+        })?,
+        None => attached_worktree(git, branch)?,
+    };
+    let worktree_id = worktree_id_for(&path);
+    if worktree_id.is_none() {
+        anyhow::bail!(
+            "'{branch}' is checked out in the repository's main worktree, which cannot be pinned"
+        );
+    }
+
+    let commit_sha = resolve_commit(&path, pinned_ref)?;
+
+    if git.has_uncommitted_changes_in(&path)? {
+        anyhow::bail!(
+            "Worktree '{}' has uncommitted changes; commit or stash them before pinning",
+            path.display()
+        );
+    }
+
+    // Record what the worktree is for while it is still attached, so the
+    // list can name it once it is detached.
+    if existing.is_none()
+        && let Some(store) = IdentityStore::open(git_common_dir)
+    {
+        store.record(&path, branch);
+    }
+
+    sink.on_step(&format!(
+        "Checking out {pinned_ref} ({}) in '{}'...",
+        short(&commit_sha),
+        path.display()
+    ));
+    checkout(&path, &["--detach", &commit_sha])?;
+
+    let row = WorktreePinRow {
+        repo_hash: repo_hash.clone(),
+        branch: branch.to_string(),
+        pinned_ref: pinned_ref.to_string(),
+        commit_sha: commit_sha.clone(),
+        worktree_id,
+        worktree_path: path.display().to_string(),
+        pinned_at: chrono::Utc::now(),
+    };
+    if let Err(e) = write(&repo_hash, |conn| WorktreePinsRepo::upsert(conn, &row)) {
+        // Without the record nothing protects the worktree; put the branch
+        // back rather than leave an unexplained detached checkout.
+        if existing.is_none() {
+            let _ = checkout(&path, &[branch]);
+        }
+        return Err(e).context("Failed to record the pin");
+    }
+
+    Ok(PinResult {
+        path,
+        commit_sha,
+        previous_ref: existing.map(|p| p.pinned_ref),
+    })
+}
+
+/// Release `branch`'s pin and check the branch out in its worktree again.
+///
+/// Returns the worktree path, or `None` when the worktree had already gone
+/// and only the record was removed.
+pub fn unpin(
+    git: &GitCommand,
+    git_common_dir: &Path,
+    branch: &str,
+    sink: &mut dyn ProgressSink,
+) -> Result<Option<PathBuf>> {
+    let repo_hash = crate::core::repo_identity::compute_repo_id_from_common_dir(git_common_dir)?;
+    let Some(pin) = read_pins(git_common_dir).remove(branch) else {
+        anyhow::bail!("'{branch}' is not pinned");
+    };
+
+    let path = locate(git, &pin)?;
+    if let Some(path) = &path {
+        sink.on_step(&format!("Checking out {branch} in '{}'...", path.display()));
+        checkout(path, &[branch])?;
+    }
+    write(&repo_hash, |conn| {
+        WorktreePinsRepo::delete(conn, &repo_hash, branch).map(|_| ())
+    })
+    .context("Failed to remove the pin")?;
+    Ok(path)
+}
+
+/// Every pin for the repo whose git common dir is `git_common_dir`, keyed by
+/// branch.
+///
+/// A **pure read**: a repo that never pinned anything (or a build that
+/// predates the table) yields an empty map without creating a store.
+pub fn read_pins(git_common_dir: &Path) -> HashMap<String, WorktreePinRow> {
+    read_inner(git_common_dir).unwrap_or_default()
+}
+
+fn read_inner(git_common_dir: &Path) -> Option<HashMap<String, WorktreePinRow>> {
+    let repo_hash =
+        crate::core::repo_identity::compute_repo_id_from_common_dir(git_common_dir).ok()?;
+    let db_path = crate::daft_state_dir()
+        .ok()?
+        .join(paths::JOBS_SUBDIR)
+        .join(&repo_hash)
+        .join(paths::COORDINATOR_DB);
+    if !db_path.exists() {
+        return None;
+    }
+    let pool = Pool::open(&db_path).ok()?;
+    let conn = pool.reader().ok()?;
+    let rows = WorktreePinsRepo::list_for_repo(&conn, &repo_hash).ok()?;
+    Some(
+        rows.into_iter()
+            .map(|row| (row.branch.clone(), row))
+            .collect(),
+    )
+}
+
+/// The pin holding the worktree at `path`, if any. Matches on the worktree's
+/// private-gitdir id first and the recorded path second, like [`locate`].
+pub fn pin_for_worktree(git_common_dir: &Path, path: &Path) -> Option<WorktreePinRow> {
+    let pins = read_pins(git_common_dir);
+    if pins.is_empty() {
+        return None;
+    }
+    let id = worktree_id_for(path);
+    let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    pins.into_values().find(|pin| {
+        (id.is_some() && pin.worktree_id == id)
+            || Path::new(&pin.worktree_path)
+                .canonicalize()
+                .is_ok_and(|recorded| recorded == canonical)
+    })
+}
+
+/// The error for a worktree argument that resolved to a detached worktree.
+/// A pinned worktree is detached on purpose, so the error points at the pin
+/// rather than suggesting a branch name.
+pub fn detached_worktree_error(git_common_dir: &Path, path: &Path) -> anyhow::Error {
+    match pin_for_worktree(git_common_dir, path) {
+        Some(pin) => anyhow::anyhow!(
+            "worktree at '{}' is pinned at '{}'; release it with `{}` first",
+            path.display(),
+            pin.pinned_ref,
+            crate::daft_cmd(&format!("pin --remove {}", pin.branch))
+        ),
+        None => anyhow::anyhow!(
+            "worktree at '{}' has a detached HEAD; specify a branch name instead",
+            path.display()
+        ),
+    }
+}
+
+/// Mark the listed worktrees that are pinned.
+///
+/// Matches on the resolved branch of rows that are not attached to it — a
+/// pinned worktree is detached by construction, and an attached row with the
+/// same name is a worktree someone checked the branch out in since. A pinned
+/// row is not a sandbox: the pin explains the detachment.
+pub fn apply_pins(infos: &mut [WorktreeInfo], pins: &HashMap<String, WorktreePinRow>) {
+    if pins.is_empty() {
+        return;
+    }
+    for info in infos.iter_mut() {
+        if info.identity_source != Some(super::identity::IdentitySource::Persisted) {
+            continue;
+        }
+        if let Some(pin) = pins.get(&info.name) {
+            info.pinned = Some(pin.pinned_ref.clone());
+            info.is_sandbox = false;
+        }
+    }
+}
+
+/// Where the pinned worktree is now: found by private-gitdir id, which
+/// survives `git worktree move`, falling back to the recorded path. `None`
+/// when neither matches a live worktree.
+pub fn locate(git: &GitCommand, pin: &WorktreePinRow) -> Result<Option<PathBuf>> {
+    let entries = parse_worktree_list_porcelain(&git.worktree_list_porcelain()?);
+    if let Some(id) = &pin.worktree_id
+        && let Some(entry) = entries
+            .iter()
+            .find(|e| !e.is_bare && worktree_id_for(&e.path).as_deref() == Some(id))
+    {
+        return Ok(Some(entry.path.clone()));
+    }
+    let recorded = Path::new(&pin.worktree_path);
+    Ok(entries
+        .iter()
+        .find(|e| !e.is_bare && e.path == recorded)
+        .map(|e| e.path.clone()))
+}
+
+fn attached_worktree(git: &GitCommand, branch: &str) -> Result<PathBuf> {
+    parse_worktree_list_porcelain(&git.worktree_list_porcelain()?)
+        .into_iter()
+        .find(|e| e.branch.as_deref() == Some(branch))
+        .map(|e| e.path)
+        .with_context(|| {
+            format!(
+                "No worktree has '{branch}' checked out; create one with '{}'",
+                crate::daft_cmd(&format!("go {branch}"))
+            )
+        })
+}
+
+fn resolve_commit(dir: &Path, rev: &str) -> Result<String> {
+    let output = git_command_at(dir)
+        .args(["rev-parse", "--verify", "--quiet", "--end-of-options"])
+        .arg(format!("{rev}^{{commit}}"))
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run git rev-parse")?;
+    if !output.status.success() {
+        anyhow::bail!("'{rev}' does not name a commit");
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn checkout(dir: &Path, args: &[&str]) -> Result<()> {
+    let output = git_command_at(dir)
+        .args(["checkout", "--quiet"])
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .context("Failed to run git checkout")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git checkout failed in '{}': {}",
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn write(
+    repo_hash: &str,
+    f: impl FnOnce(&rusqlite::Connection) -> crate::store::error::Result<()>,
+) -> Result<()> {
+    let pool = Pool::open(&paths::for_repo(repo_hash)?)?;
+    let conn = pool.writer()?;
+    f(&conn)?;
+    Ok(())
+}
+
+fn short(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::worktree::identity::IdentitySource;
+
+    fn pin_row(branch: &str) -> WorktreePinRow {
+        WorktreePinRow {
+            repo_hash: "repo".into(),
+            branch: branch.into(),
+            pinned_ref: "v1.0".into(),
+            commit_sha: "0123456789abcdef0123456789abcdef01234567".into(),
+            worktree_id: Some(branch.into()),
+            worktree_path: String::from("/tmp/wt/") + branch,
+            pinned_at: chrono::Utc::now(),
+        }
+    }
+
+    fn row(name: &str, source: IdentitySource) -> WorktreeInfo {
+        let mut info = WorktreeInfo::empty(name);
+        info.identity_source = Some(source);
+        info.is_sandbox = source == IdentitySource::Persisted;
+        info
+    }
+
+    #[test]
+    fn only_detached_rows_named_by_their_record_take_the_pin() {
+        let pins: HashMap<String, WorktreePinRow> = [("release".to_string(), pin_row("release"))]
+            .into_iter()
+            .collect();
+        let mut infos = vec![
+            row("release", IdentitySource::Persisted),
+            row("main", IdentitySource::Attached),
+        ];
+        apply_pins(&mut infos, &pins);
+        assert_eq!(infos[0].pinned.as_deref(), Some("v1.0"));
+        assert!(!infos[0].is_sandbox, "the pin explains the detachment");
+        assert_eq!(infos[1].pinned, None);
+
+        // Someone checked the branch out again by hand: the row is attached,
+        // so it is not the pinned checkout.
+        let mut attached = vec![row("release", IdentitySource::Attached)];
+        apply_pins(&mut attached, &pins);
+        assert_eq!(attached[0].pinned, None);
+    }
+}
@@ -2,53 +2,73 @@
 //!
 //! Stores the absolute path of the last worktree the user switched away from,
 //! enabling `cd -`–style toggling between two worktrees. State is persisted
-//! per-repository at `<git-common-dir>/.daft/previous-worktree`.
+//! per-repository at `<git-common-dir>/.daft/previous-worktree` as a small
+//! versioned JSON document; files written before it was versioned hold the
+//! bare path and are still read.
 
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 const STATE_DIR: &str = ".daft";
 const STATE_FILE: &str = "previous-worktree";
 
+/// Current previous-worktree state schema version.
+const STATE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PreviousWorktree {
+    version: u32,
+    path: PathBuf,
+}
+
 /// Load the previously visited worktree path, if any.
 ///
 /// Returns `Ok(None)` when no previous worktree has been recorded (file
-/// missing or empty). Returns `Err` only on unexpected I/O failures.
+/// missing or empty) or the file was unreadable and has been backed up (see
+/// [`crate::state_file`]). Returns `Err` only on unexpected I/O failures.
 pub fn load(git_common_dir: &Path) -> Result<Option<PathBuf>> {
     let file = git_common_dir.join(STATE_DIR).join(STATE_FILE);
 
-    match std::fs::read_to_string(&file) {
-        Ok(content) => {
-            let trimmed = content.trim();
-            if trimmed.is_empty() {
-                Ok(None)
-            } else {
-                Ok(Some(PathBuf::from(trimmed)))
-            }
+    let content = match std::fs::read_to_string(&file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(e).with_context(|| {
+                format!(
+                    "Failed to read previous worktree state from {}",
+                    file.display()
+                )
+            });
         }
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
-        Err(e) => Err(e).with_context(|| {
-            format!(
-                "Failed to read previous worktree state from {}",
-                file.display()
-            )
-        }),
+    };
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
     }
+    if !trimmed.starts_with('{') {
+        // Unversioned file from an older daft: the bare path.
+        return Ok(Some(PathBuf::from(trimmed)));
+    }
+    let state: Option<PreviousWorktree> =
+        crate::state_file::load_json_checked(&file, "previous worktree state", STATE_VERSION)?;
+    Ok(state.map(|state| state.path))
 }
 
 /// Save the worktree path as the "previous" for later `daft go -` use.
 ///
 /// Creates the `.daft/` directory inside the git common dir if it does not
-/// already exist.
+/// already exist. The write is atomic, so a concurrent `daft go -` never
+/// reads a half-written path.
 pub fn save(git_common_dir: &Path, worktree_path: &Path) -> Result<()> {
-    let dir = git_common_dir.join(STATE_DIR);
-    if !dir.exists() {
-        std::fs::create_dir_all(&dir)
-            .with_context(|| format!("Failed to create state directory {}", dir.display()))?;
-    }
-
-    let file = dir.join(STATE_FILE);
-    std::fs::write(&file, worktree_path.to_string_lossy().as_bytes()).with_context(|| {
+    let file = git_common_dir.join(STATE_DIR).join(STATE_FILE);
+    let state = PreviousWorktree {
+        version: STATE_VERSION,
+        path: worktree_path.to_path_buf(),
+    };
+    let contents =
+        serde_json::to_string(&state).context("Failed to serialize previous worktree state")?;
+    crate::state_file::write_atomic(&file, contents.as_bytes()).with_context(|| {
         format!(
             "Failed to write previous worktree state to {}",
             file.display()
@@ -100,6 +120,33 @@ mod tests {
         assert_eq!(loaded, None);
     }
 
+    #[test]
+    fn test_unversioned_file_is_read_as_a_path() {
+        let dir = tempdir().unwrap();
+        let state_dir = dir.path().join(STATE_DIR);
+        std::fs::create_dir_all(&state_dir).unwrap();
+        std::fs::write(state_dir.join(STATE_FILE), "/projects/repo/main\n").unwrap();
+
+        let loaded = load(dir.path()).unwrap();
+        assert_eq!(loaded, Some(PathBuf::from("/projects/repo/main")));
+    }
+
+    #[test]
+    fn test_newer_version_is_backed_up() {
+        let dir = tempdir().unwrap();
+        let state_dir = dir.path().join(STATE_DIR);
+        std::fs::create_dir_all(&state_dir).unwrap();
+        let contents = format!(
+            r#"{{"version": {}, "path": "/projects/repo/main"}}"#,
+            STATE_VERSION + 1
+        );
+        std::fs::write(state_dir.join(STATE_FILE), contents).unwrap();
+
+        assert_eq!(load(dir.path()).unwrap(), None);
+        assert!(!state_dir.join(STATE_FILE).exists());
+        assert_eq!(crate::state_file::corrupt_backups(&state_dir).len(), 1);
+    }
+
     #[test]
     fn test_overwrite() {
         let dir = tempdir().unwrap();
@@ -4,6 +4,7 @@
 
 use crate::core::{HookRunner, ProgressSink};
 use crate::git::GitCommand;
+use crate::hooks::post_fetch::{self, RefSnapshot};
 use crate::hooks::{HookContext, HookType, RemovalReason};
 use crate::remote::{get_default_branch_local, remote_branch_exists};
 use crate::settings::PruneCdTarget;
@@ -149,8 +150,14 @@ pub fn execute(
         "Fetching from remote {} and pruning stale remote-tracking branches...",
         ctx.remote_name
     ));
+    let refs_before = RefSnapshot::capture(&ctx.git_dir, &ctx.remote_name);
     git.fetch(&ctx.remote_name, true)
         .context("git fetch failed")?;
+    let updated_refs = post_fetch::diff(
+        &refs_before,
+        &RefSnapshot::capture(&ctx.git_dir, &ctx.remote_name),
+    );
+    run_post_fetch_hook(&ctx, &updated_refs, sink);
 
     // Parse worktree list once upfront
     let worktree_entries = parse_worktree_list(&git)?;
@@ -516,6 +523,13 @@ pub fn identify_gone_branches(
         Some(default) => name == default,
         None => name == "master" || name == "main",
     };
+    // A pinned branch is held on purpose (`daft pin`): its worktree is
+    // detached, so deleting the branch would strand the pin with nothing to
+    // return to.
+    let pins = crate::core::repo::get_git_common_dir()
+        .map(|dir| super::pin::read_pins(&dir))
+        .unwrap_or_default();
+    let is_pinned = |name: &str| pins.contains_key(name);
 
     // Method 1: git branch -vv to find branches with gone upstream
     let branch_output = git.branch_list_verbose()?;
@@ -530,6 +544,10 @@ pub fn identify_gone_branches(
                 && !name.is_empty()
                 && !is_default_branch(name)
             {
+                if is_pinned(name) {
+                    sink.on_step(&format!("Skipping pinned branch {name}"));
+                    continue;
+                }
                 gone_branches.push(name.to_string());
             }
         }
@@ -777,6 +795,7 @@ fn process_deferred_branch(
 
     let cd_target = resolve_prune_cd_target(
         params.prune_cd_target,
+        wt_path,
         &ctx.project_root,
         &ctx.git_dir,
         &ctx.remote_name,
@@ -996,6 +1015,10 @@ fn remove_worktree_and_delete_branch(
 }
 
 /// Run a pre-remove or post-remove hook for a worktree.
+///
+/// Prune always deletes the branch along with its worktree, and removes the
+/// current worktree only after changing into its cd target, so the current
+/// directory is where the shell lands.
 fn run_removal_hook(
     hook_type: HookType,
     ctx: &PruneContext,
@@ -1003,7 +1026,7 @@ fn run_removal_hook(
     branch_name: &str,
     sink: &mut (impl ProgressSink + HookRunner),
 ) {
-    let hook_ctx = HookContext::new(
+    let mut hook_ctx = HookContext::new(
         hook_type,
         "prune",
         &ctx.project_root,
@@ -1013,7 +1036,11 @@ fn run_removal_hook(
         worktree_path,
         branch_name,
     )
-    .with_removal_reason(RemovalReason::RemoteDeleted);
+    .with_removal_reason(RemovalReason::RemoteDeleted)
+    .with_branch_exists(false);
+    if let Ok(cwd) = std::env::current_dir() {
+        hook_ctx = hook_ctx.with_target_cwd(cwd);
+    }
 
     if let Err(e) = sink.run_hook(&hook_ctx) {
         sink.on_warning(&format!(
@@ -1027,6 +1054,36 @@ fn run_removal_hook(
     }
 }
 
+/// Run the post-fetch hook when the fetch moved any refs.
+fn run_post_fetch_hook(
+    ctx: &PruneContext,
+    updated_refs: &[post_fetch::RefUpdate],
+    sink: &mut (impl ProgressSink + HookRunner),
+) {
+    if updated_refs.is_empty() {
+        return;
+    }
+    let branch = ctx.git.symbolic_ref_short_head().unwrap_or_default();
+    let hook_ctx = match post_fetch::hook_context(
+        "prune",
+        &ctx.project_root,
+        &ctx.git_dir,
+        &ctx.remote_name,
+        &ctx.source_worktree,
+        &branch,
+        updated_refs,
+    ) {
+        Ok(hook_ctx) => hook_ctx,
+        Err(e) => {
+            sink.on_warning(&format!("Post-fetch hook skipped: {e:#}"));
+            return;
+        }
+    };
+    if let Err(e) = sink.run_hook(&hook_ctx) {
+        sink.on_warning(&format!("Post-fetch hook failed: {e}"));
+    }
+}
+
 // ── Branch operations ──────────────────────────────────────────────────────
 
 /// Delete a local branch with force. Returns true on success.
@@ -1093,9 +1150,11 @@ pub fn parse_worktree_list(git: &GitCommand) -> Result<Vec<WorktreeEntry>> {
     Ok(crate::core::worktree::porcelain::parse_worktree_list_porcelain(&porcelain_output))
 }
 
-/// Resolve where to cd after pruning the user's current worktree.
-fn resolve_prune_cd_target(
+/// Resolve where to cd after removing the user's current worktree, which
+/// lives at `removing`. Shared with `daft remove`.
+pub(crate) fn resolve_prune_cd_target(
     cd_target: PruneCdTarget,
+    removing: &Path,
     project_root: &Path,
     git_dir: &Path,
     remote_name: &str,
@@ -1126,6 +1185,16 @@ fn resolve_prune_cd_target(
                 }
             }
         }
+        PruneCdTarget::Previous => {
+            let same = |a: &Path, b: &Path| a.canonicalize().ok() == b.canonicalize().ok();
+            match super::previous::load(git_dir) {
+                Ok(Some(previous)) if previous.is_dir() && !same(&previous, removing) => previous,
+                _ => {
+                    sink.on_step("No previous worktree to return to, falling back to project root");
+                    project_root.to_path_buf()
+                }
+            }
+        }
     }
 }
 
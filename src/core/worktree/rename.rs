@@ -95,8 +95,13 @@ pub fn execute(
 
     // Step 1: Resolve source to branch name + worktree path.
     let worktree_entries = parse_worktree_list(&git)?;
-    let (old_branch, old_path) =
-        resolve_source(&params.source, &worktree_entries, &project_root, sink)?;
+    let (old_branch, old_path) = resolve_source(
+        &params.source,
+        &worktree_entries,
+        &project_root,
+        &git_dir,
+        sink,
+    )?;
 
     sink.on_step(&format!(
         "Resolved source to branch '{}' at '{}'",
@@ -489,6 +494,7 @@ fn resolve_source(
     source: &str,
     worktree_entries: &[WorktreeListEntry],
     project_root: &Path,
+    git_dir: &Path,
     sink: &mut dyn ProgressSink,
 ) -> Result<(String, PathBuf)> {
     // Try as a path first (absolute, relative to cwd, or relative to project root).
@@ -521,10 +527,7 @@ fn resolve_source(
                             Ok((branch.clone(), entry.path.clone()))
                         }
                         None => {
-                            anyhow::bail!(
-                                "Worktree at '{}' has a detached HEAD; specify a branch name instead",
-                                entry.path.display()
-                            );
+                            return Err(super::pin::detached_worktree_error(git_dir, &entry.path));
                         }
                     };
                 }
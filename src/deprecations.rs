@@ -0,0 +1,252 @@
+//! Deprecation warnings, collected and printed once per run.
+//!
+//! Deprecated spellings — hook filenames, config keys, flags, command names —
+//! keep working for a release cycle, and each use deserves one reminder, not
+//! one per worktree a hook fires in or per settings load. Call sites
+//! [`record`] a warning under a stable key; `main` calls [`flush`] after the
+//! command finishes, which drops the kinds listed in `daft.suppressWarnings`
+//! and prints the rest through [`Output`].
+//!
+//! With `--format json`, the emit dispatcher takes the pending deprecations
+//! and adds them to the command's result as a top-level `deprecations` key;
+//! `--format ndjson` gets them as a final `{"deprecations": [...]}` record.
+//! A JSON result whose root is an array has no room for the key, so those
+//! (and anything recorded after the result was written) reach stderr as that
+//! same object rather than as human-readable lines. Commands that end with
+//! `std::process::exit` go through [`exit`] so the warnings are not lost.
+
+use crate::git::GitCommand;
+use crate::output::Output;
+use crate::settings::keys;
+use serde::Serialize;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Category of a deprecation, named by the id `daft.suppressWarnings` takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DeprecationKind {
+    /// A hook file or hook config key under its pre-`worktree-` name.
+    HookRename,
+    /// A renamed config key or config file.
+    ConfigRename,
+    /// A renamed or retired command-line flag.
+    Flag,
+    /// A retired command name.
+    Command,
+}
+
+impl DeprecationKind {
+    pub const ALL: [Self; 4] = [
+        Self::HookRename,
+        Self::ConfigRename,
+        Self::Flag,
+        Self::Command,
+    ];
+
+    /// Id used in `daft.suppressWarnings` and the JSON record.
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::HookRename => "hook-rename",
+            Self::ConfigRename => "config-rename",
+            Self::Flag => "flag",
+            Self::Command => "command",
+        }
+    }
+}
+
+/// One recorded deprecation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Deprecation {
+    #[serde(rename = "id")]
+    pub kind: DeprecationKind,
+    /// What triggered it (a path, a key); only used to collapse repeats.
+    #[serde(skip)]
+    pub key: String,
+    pub message: String,
+}
+
+static COLLECTOR: Mutex<Collector> = Mutex::new(Collector::new());
+
+/// Record a deprecation. Repeats with the same kind and key within a run are
+/// collapsed, so callers can record from loops without bookkeeping.
+pub fn record(kind: DeprecationKind, key: impl Into<String>, message: impl Into<String>) {
+    lock().record(Deprecation {
+        kind,
+        key: key.into(),
+        message: message.into(),
+    });
+}
+
+/// Note that the command wrote JSON or NDJSON to stdout. Called by the emit
+/// dispatcher.
+pub fn mark_structured_output() {
+    lock().structured = true;
+}
+
+/// Take the collected deprecations, minus the suppressed kinds, for a
+/// structured result. Clears the collection, so the end-of-run [`flush`]
+/// does not report them again.
+pub fn take() -> Vec<Deprecation> {
+    let items = std::mem::take(&mut lock().items);
+    if items.is_empty() {
+        return items;
+    }
+    let suppressed = load_suppressed();
+    items
+        .into_iter()
+        .filter(|d| !is_suppressed(d.kind, &suppressed))
+        .collect()
+}
+
+/// Print the collected deprecations, minus the suppressed kinds, and clear
+/// the collection.
+pub fn flush(output: &mut dyn Output) {
+    let structured = lock().structured;
+    let items = take();
+    if items.is_empty() {
+        return;
+    }
+
+    if structured {
+        let record = serde_json::json!({ "deprecations": items });
+        eprintln!("{record}");
+        return;
+    }
+    for item in &items {
+        output.warning(&item.message);
+    }
+}
+
+/// Flush the collected deprecations, then exit with `code`. For commands
+/// that report their outcome through the exit status: `std::process::exit`
+/// skips the flush `main` runs after the command returns.
+pub fn exit(code: i32) -> ! {
+    flush(&mut crate::output::CliOutput::new(
+        crate::output::OutputConfig::default(),
+    ));
+    std::process::exit(code)
+}
+
+/// Parse a `daft.suppressWarnings` value: ids separated by commas or
+/// whitespace, with optional surrounding brackets (`[hook-rename, flag]`).
+pub fn parse_suppressed(value: &str) -> Vec<String> {
+    value
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|id| id.trim().to_ascii_lowercase())
+        .filter(|id| !id.is_empty())
+        .collect()
+}
+
+/// Whether `kind` is silenced by the parsed suppression list. `all` silences
+/// every kind.
+pub fn is_suppressed(kind: DeprecationKind, suppressed: &[String]) -> bool {
+    suppressed.iter().any(|id| id == "all" || id == kind.id())
+}
+
+fn load_suppressed() -> Vec<String> {
+    // Outside a repository only the global config is readable.
+    let git = GitCommand::new(true);
+    let value = git
+        .config_get(keys::SUPPRESS_WARNINGS)
+        .or_else(|_| git.config_get_global(keys::SUPPRESS_WARNINGS));
+    match value {
+        Ok(Some(value)) => parse_suppressed(&value),
+        _ => Vec::new(),
+    }
+}
+
+fn lock() -> MutexGuard<'static, Collector> {
+    COLLECTOR.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Pure collection logic, kept separate from the global so tests don't
+/// contend over process-wide state.
+struct Collector {
+    items: Vec<Deprecation>,
+    structured: bool,
+}
+
+impl Collector {
+    const fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            structured: false,
+        }
+    }
+
+    fn record(&mut self, deprecation: Deprecation) {
+        let seen = self
+            .items
+            .iter()
+            .any(|d| d.kind == deprecation.kind && d.key == deprecation.key);
+        if !seen {
+            self.items.push(deprecation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deprecation(kind: DeprecationKind, key: &str) -> Deprecation {
+        Deprecation {
+            kind,
+            key: key.into(),
+            message: format!("{key} is deprecated"),
+        }
+    }
+
+    #[test]
+    fn repeats_collapse_by_kind_and_key() {
+        let mut collector = Collector::new();
+        collector.record(deprecation(DeprecationKind::HookRename, "post-create"));
+        collector.record(deprecation(DeprecationKind::HookRename, "post-create"));
+        collector.record(deprecation(DeprecationKind::HookRename, "pre-remove"));
+        collector.record(deprecation(DeprecationKind::Flag, "post-create"));
+        assert_eq!(collector.items.len(), 3);
+    }
+
+    #[test]
+    fn suppression_list_accepts_commas_spaces_and_brackets() {
+        assert_eq!(
+            parse_suppressed("[hook-rename, Flag]"),
+            vec!["hook-rename", "flag"]
+        );
+        assert_eq!(
+            parse_suppressed("hook-rename command"),
+            vec!["hook-rename", "command"]
+        );
+        assert!(parse_suppressed("  ").is_empty());
+    }
+
+    #[test]
+    fn suppression_matches_ids_and_all() {
+        let list = parse_suppressed("hook-rename");
+        assert!(is_suppressed(DeprecationKind::HookRename, &list));
+        assert!(!is_suppressed(DeprecationKind::ConfigRename, &list));
+
+        let all = parse_suppressed("all");
+        assert!(
+            DeprecationKind::ALL
+                .iter()
+                .all(|kind| is_suppressed(*kind, &all))
+        );
+    }
+
+    #[test]
+    fn json_record_uses_ids_and_omits_keys() {
+        let items = vec![deprecation(
+            DeprecationKind::ConfigRename,
+            "daft.fetch.args",
+        )];
+        let record = serde_json::json!({ "deprecations": items }).to_string();
+        assert_eq!(
+            record,
+            r#"{"deprecations":[{"id":"config-rename","message":"daft.fetch.args is deprecated"}]}"#
+        );
+    }
+}
@@ -118,7 +118,10 @@ fn check_cli(bin: &str, label: &str, install_url: &str) -> CheckResult {
             label,
             &format!("installed but not authenticated{version_note}"),
         )
-        .with_suggestion(&format!("Run `{bin} auth login` to enable PR/MR checkout"))
+        .with_suggestion(&format!(
+            "Run `{}` (or `{bin} auth login`) to enable PR/MR checkout",
+            crate::daft_cmd("auth login")
+        ))
     }
 }
 
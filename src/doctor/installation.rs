@@ -2,7 +2,7 @@
 //!
 //! Verifies that daft and its dependencies are correctly installed:
 //! binary in PATH, command symlinks, git, man pages, shell integration,
-//! shortcut symlinks, and shell wrappers.
+//! shortcut symlinks, shell wrappers, and readable state files.
 
 use crate::doctor::{CheckResult, FixAction};
 use crate::shortcuts::{ShortcutStyle, shortcuts_for_style};
@@ -569,6 +569,63 @@ fn get_man_search_paths() -> Vec<PathBuf> {
     paths
 }
 
+/// Check daft's state files in the config directory: an unreadable trust
+/// registry, and backups of corrupt state files left behind by recovery.
+pub fn check_state_files() -> CheckResult {
+    match crate::daft_config_dir() {
+        Ok(dir) => check_state_files_in(&dir),
+        Err(e) => CheckResult::skipped("State files", &format!("no config directory: {e}")),
+    }
+}
+
+/// [`check_state_files`] against an explicit config directory.
+pub fn check_state_files_in(dir: &Path) -> CheckResult {
+    use crate::hooks::TrustDatabase;
+
+    let registry = ["repos.json", "trust.json"]
+        .into_iter()
+        .map(|name| dir.join(name))
+        .find(|p| p.exists());
+    if let Some(registry) = registry
+        && let Err(e) = TrustDatabase::load_from(&registry)
+    {
+        let fix_dir = dir.to_path_buf();
+        let dry_dir = dir.to_path_buf();
+        return CheckResult::warning(
+            "State files",
+            &format!("trust registry {} is unreadable", registry.display()),
+        )
+        .with_details(vec![format!("{e:#}")])
+        .with_suggestion(
+            "Run 'daft doctor --fix' to back it up and start a fresh registry, then re-grant trust",
+        )
+        .with_fix(Box::new(move || {
+            TrustDatabase::update_in(&fix_dir, |_| Ok(())).map_err(|e| format!("{e:#}"))
+        }))
+        .with_dry_run_fix(Box::new(move || {
+            vec![FixAction {
+                description: format!(
+                    "Back up the unreadable registry in {} and start a fresh one",
+                    dry_dir.display()
+                ),
+                would_succeed: true,
+                failure_reason: None,
+            }]
+        }));
+    }
+
+    let backups = crate::state_file::corrupt_backups(dir);
+    if backups.is_empty() {
+        return CheckResult::pass("State files", "all readable");
+    }
+    CheckResult::warning(
+        "State files",
+        &format!("{} backup(s) of corrupt state files", backups.len()),
+    )
+    .with_details(backups.iter().map(|p| p.display().to_string()).collect())
+    .with_suggestion("Recover anything you need from the backups, then delete them")
+}
+
 /// Check the user-global agent-skill copy (`~/.claude/skills`) against the
 /// skill embedded in this binary.
 pub fn check_agent_skill() -> CheckResult {
@@ -983,4 +1040,23 @@ mod agent_skill_tests {
             "user suggestion should be the global install: {user_suggestion}"
         );
     }
+
+    #[test]
+    fn unreadable_registry_is_fixed_and_its_backup_reported() {
+        let temp = tempfile::tempdir().unwrap();
+        std::fs::write(temp.path().join("repos.json"), "{ truncated").unwrap();
+
+        let result = check_state_files_in(temp.path());
+        assert_eq!(result.status, CheckStatus::Warning);
+        assert!(result.message.contains("unreadable"));
+        (result.fix.expect("registry check is fixable"))().unwrap();
+
+        let result = check_state_files_in(temp.path());
+        assert_eq!(result.status, CheckStatus::Warning);
+        assert!(result.message.contains("1 backup(s)"));
+        assert!(
+            crate::hooks::TrustDatabase::load_from(&temp.path().join("repos.json")).is_ok(),
+            "the fix writes a fresh registry"
+        );
+    }
 }
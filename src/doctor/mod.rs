@@ -195,6 +195,106 @@ impl DoctorSummary {
     }
 }
 
+/// Category selectors `--only`/`--skip` accept, beside single check names.
+pub const CATEGORY_NAMES: [&str; 5] = ["installation", "forge", "catalog", "repository", "hooks"];
+
+/// Fold a category title, check name, or selector to one comparable form:
+/// lowercase, with every run of non-alphanumerics as a single dash — so
+/// `Shell integration`, `shell-integration` and `SHELL_INTEGRATION` agree.
+fn slug(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            out.extend(c.to_lowercase());
+        } else if !out.is_empty() && !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_end_matches('-').to_string()
+}
+
+/// Whether `selector` (already slugged) names the category titled `title`.
+/// Per-repo titles (`Repository — api`) answer to their base name, and a
+/// multi-word title also answers to its first word (`forge`).
+fn category_matches(title: &str, selector: &str) -> bool {
+    let base = slug(title.split(" \u{2014} ").next().unwrap_or(title));
+    base == selector || base.split('-').next() == Some(selector)
+}
+
+fn is_category(selector: &str) -> bool {
+    CATEGORY_NAMES.contains(&selector) || selector == "forge-integration"
+}
+
+/// Checks picked by `daft doctor --only` / `--skip`. Each selector names a
+/// category or a single check by its displayed name; a check runs when
+/// `--only` is empty or selects it, and `--skip` does not.
+#[derive(Debug, Default)]
+pub struct CheckSelection {
+    only: Vec<String>,
+    skip: Vec<String>,
+}
+
+impl CheckSelection {
+    pub fn new(only: &[String], skip: &[String]) -> Self {
+        let fold = |list: &[String]| {
+            list.iter()
+                .map(|s| slug(s))
+                .filter(|s| !s.is_empty())
+                .collect()
+        };
+        Self {
+            only: fold(only),
+            skip: fold(skip),
+        }
+    }
+
+    /// Whether any check in the category titled `title` can be selected, so
+    /// a category the selection rules out is never run at all.
+    pub fn runs_category(&self, title: &str) -> bool {
+        if self.skip.iter().any(|s| category_matches(title, s)) {
+            return false;
+        }
+        self.only.is_empty()
+            || self
+                .only
+                .iter()
+                .any(|s| category_matches(title, s) || !is_category(s))
+    }
+
+    fn selects(&self, title: &str, name: &str) -> bool {
+        let name = slug(name);
+        let hit = |s: &String| category_matches(title, s) || *s == name;
+        (self.only.is_empty() || self.only.iter().any(hit)) && !self.skip.iter().any(hit)
+    }
+
+    /// Keep the selected checks, dropping categories left empty. A selector
+    /// that names neither a category nor a check that ran is an error: a
+    /// typo must not turn a gating `--only` into a silent pass.
+    pub fn apply(&self, categories: Vec<CheckCategory>) -> Result<Vec<CheckCategory>, String> {
+        for selector in self.only.iter().chain(&self.skip) {
+            let known = is_category(selector)
+                || categories
+                    .iter()
+                    .flat_map(|c| &c.results)
+                    .any(|r| slug(&r.name) == *selector);
+            if !known {
+                return Err(format!(
+                    "'{selector}' names no doctor category ({}) and no check that ran here",
+                    CATEGORY_NAMES.join(", ")
+                ));
+            }
+        }
+        Ok(categories
+            .into_iter()
+            .filter_map(|mut category| {
+                let title = category.title.clone();
+                category.results.retain(|r| self.selects(&title, &r.name));
+                (!category.results.is_empty()).then_some(category)
+            })
+            .collect())
+    }
+}
+
 /// Returns the status symbol for a check result (with brackets).
 pub fn status_symbol(status: CheckStatus) -> String {
     use crate::styles::{dim, green, red, yellow};
@@ -322,6 +422,76 @@ mod tests {
         assert_eq!(actions[0].description, "Would do thing");
     }
 
+    fn sample_categories() -> Vec<CheckCategory> {
+        vec![
+            CheckCategory {
+                title: "Installation".to_string(),
+                results: vec![
+                    CheckResult::pass("Shell integration", "ok"),
+                    CheckResult::fail("Git", "missing"),
+                ],
+            },
+            CheckCategory {
+                title: "Repository \u{2014} api".to_string(),
+                results: vec![CheckResult::warning("Fetch refspec", "off")],
+            },
+            CheckCategory {
+                title: "Forge integration".to_string(),
+                results: vec![CheckResult::pass("GitHub CLI", "ok")],
+            },
+        ]
+    }
+
+    fn selected(only: &[&str], skip: &[&str]) -> Vec<(String, Vec<String>)> {
+        let owned = |l: &[&str]| l.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        CheckSelection::new(&owned(only), &owned(skip))
+            .apply(sample_categories())
+            .unwrap()
+            .into_iter()
+            .map(|c| (c.title, c.results.into_iter().map(|r| r.name).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn selection_by_category_and_check_name() {
+        assert_eq!(selected(&[], &[]).len(), 3);
+        let only_repo = selected(&["repository"], &[]);
+        assert_eq!(only_repo.len(), 1);
+        assert_eq!(only_repo[0].1, vec!["Fetch refspec"]);
+        let by_name = selected(&["shell-integration", "FORGE"], &[]);
+        assert_eq!(by_name[0].1, vec!["Shell integration"]);
+        assert_eq!(by_name[1].0, "Forge integration");
+    }
+
+    #[test]
+    fn skip_wins_over_only() {
+        let result = selected(&["installation"], &["Git"]);
+        assert_eq!(
+            result,
+            vec![("Installation".into(), vec!["Shell integration".into()])]
+        );
+        assert_eq!(selected(&[], &["installation", "forge"]).len(), 1);
+    }
+
+    #[test]
+    fn unknown_selector_is_an_error() {
+        let err = CheckSelection::new(&["instalation".into()], &[])
+            .apply(sample_categories())
+            .err()
+            .expect("unknown selector should be rejected");
+        assert!(err.contains("'instalation'"), "{err}");
+    }
+
+    #[test]
+    fn categories_ruled_out_do_not_run() {
+        let only_hooks = CheckSelection::new(&["hooks".into()], &[]);
+        assert!(only_hooks.runs_category("Hooks"));
+        assert!(!only_hooks.runs_category("Installation"));
+        let by_check = CheckSelection::new(&["git".into()], &["catalog".into()]);
+        assert!(by_check.runs_category("Installation"));
+        assert!(!by_check.runs_category("Catalog"));
+    }
+
     #[test]
     fn test_doctor_summary_no_failures() {
         let categories = vec![CheckCategory {
@@ -6,7 +6,7 @@
 use crate::core::worktree::porcelain::{WorktreeListEntry, parse_worktree_list_porcelain};
 use crate::doctor::{CheckResult, FixAction};
 use crate::git::GitCommand;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 /// Context for repository checks - gathered once and shared.
 pub struct RepoContext {
@@ -16,31 +16,6 @@ pub struct RepoContext {
     pub is_bare: bool,
 }
 
-/// Check if the git common dir is a bare repository by reading its config.
-///
-/// `git rev-parse --is-bare-repository` returns false inside a worktree,
-/// even when the underlying repo IS bare. Instead we check the git config
-/// at the common dir level.
-fn is_common_dir_bare(git_common_dir: &Path) -> bool {
-    let config_path = git_common_dir.join("config");
-    if let Ok(content) = std::fs::read_to_string(config_path) {
-        // Look for bare = true in the [core] section
-        let mut in_core = false;
-        for line in content.lines() {
-            let trimmed = line.trim();
-            if trimmed.starts_with('[') {
-                in_core = trimmed.starts_with("[core]");
-            } else if in_core && let Some(value) = trimmed.strip_prefix("bare") {
-                let value = value.trim().strip_prefix('=').map(|v| v.trim());
-                if value == Some("true") {
-                    return true;
-                }
-            }
-        }
-    }
-    false
-}
-
 /// Try to build a RepoContext for the current directory.
 /// Returns None if not in a git repository.
 pub fn get_repo_context() -> Option<RepoContext> {
@@ -49,8 +24,8 @@ pub fn get_repo_context() -> Option<RepoContext> {
     }
 
     let git_common_dir = crate::get_git_common_dir().ok()?;
-    let project_root = git_common_dir.parent()?.to_path_buf();
-    let is_bare = is_common_dir_bare(&git_common_dir);
+    let project_root = crate::core::repo::project_root_for(&git_common_dir)?;
+    let is_bare = crate::core::repo::is_bare_git_dir(&git_common_dir);
 
     // Resolve the worktree to inspect for config/hooks repo-awarely, not as the
     // raw cwd. Running `daft doctor` from a worktree subdir, or from the bare
@@ -102,6 +77,20 @@ pub fn check_worktree_layout(ctx: &RepoContext) -> CheckResult {
     let git = GitCommand::new(true);
 
     if !ctx.is_bare {
+        // A plain clone is healthy as far as git is concerned; reporting it
+        // as a failure would fail doctor for every repo daft merely visits.
+        if crate::core::layout::detect::is_unmanaged_repo(&ctx.git_common_dir) {
+            return CheckResult::pass(
+                "Worktree layout",
+                &format!(
+                    "plain git repository, not managed by daft; `{}` converts it",
+                    crate::daft_cmd("adopt")
+                ),
+            )
+            .with_details(vec![
+                "Inspection commands work here; layout-aware features apply after adoption.".into(),
+            ]);
+        }
         return CheckResult::pass("Worktree layout", "standard repository");
     }
 
@@ -261,6 +250,53 @@ pub fn check_worktree_consistency(_ctx: &RepoContext) -> CheckResult {
     }
 }
 
+/// Check that neither the project nor any of its worktrees sits inside
+/// another repository.
+///
+/// Only a *different* repository counts: a worktree inside its own project
+/// root is what the `nested` layout does on purpose.
+pub fn check_nesting(ctx: &RepoContext) -> CheckResult {
+    let own =
+        std::fs::canonicalize(&ctx.git_common_dir).unwrap_or_else(|_| ctx.git_common_dir.clone());
+
+    let mut paths = vec![ctx.project_root.clone()];
+    if let Ok(porcelain) = GitCommand::new(true).worktree_list_porcelain() {
+        paths.extend(
+            parse_worktree_list_porcelain(&porcelain)
+                .into_iter()
+                .filter(|e| !e.is_bare && e.path.exists())
+                .map(|e| e.path),
+        );
+    }
+
+    let details: Vec<String> = paths
+        .iter()
+        .filter_map(|path| {
+            let outer = crate::core::nesting::enclosing_project(path.parent()?)?;
+            (outer.git_common_dir != own).then(|| {
+                format!(
+                    "{} is inside the {} at {}",
+                    path.display(),
+                    outer.kind(),
+                    outer.root.display()
+                )
+            })
+        })
+        .collect();
+
+    if details.is_empty() {
+        return CheckResult::pass("Nesting", "not inside another repository");
+    }
+    CheckResult::warning(
+        "Nesting",
+        &format!("{} path(s) inside another repository", details.len()),
+    )
+    .with_suggestion(
+        "Move the project outside the enclosing repository; commands run there may resolve the wrong one",
+    )
+    .with_details(details)
+}
+
 /// Fix orphaned worktree entries by running git worktree prune.
 pub fn fix_worktree_consistency() -> Result<(), String> {
     let output = std::process::Command::new("git")
@@ -389,6 +425,46 @@ pub fn check_remote_sync_config(_ctx: &RepoContext) -> CheckResult {
     }
 }
 
+/// Check that `.git/info/exclude` carries daft's managed block, so the files
+/// daft writes into worktrees (`daft.local.yml` and its aliases) stay out of
+/// `git status`.
+pub fn check_git_exclude_block(ctx: &RepoContext) -> CheckResult {
+    use crate::core::git_exclude::{self, BlockStatus};
+
+    let status = match git_exclude::status(&ctx.git_common_dir) {
+        Ok(status) => status,
+        Err(e) => {
+            return CheckResult::warning(
+                "Git exclude block",
+                &format!("could not read info/exclude: {e}"),
+            );
+        }
+    };
+    let message = match status {
+        BlockStatus::Current => {
+            return CheckResult::pass("Git exclude block", "daft files are excluded");
+        }
+        BlockStatus::Stale => "out of date in info/exclude",
+        BlockStatus::Missing => "missing from info/exclude",
+    };
+    // Fixes run after doctor restores the original cwd, so capture the dir.
+    let fix_dir = ctx.git_common_dir.clone();
+    CheckResult::warning("Git exclude block", message)
+        .with_suggestion("Run with --fix to rewrite daft's block in .git/info/exclude")
+        .with_fix(Box::new(move || {
+            git_exclude::ensure(&fix_dir)
+                .map(|_| ())
+                .map_err(|e| format!("Failed to update info/exclude: {e:#}"))
+        }))
+        .with_dry_run_fix(Box::new(|| {
+            vec![FixAction {
+                description: "Rewrite daft's block in .git/info/exclude".to_string(),
+                would_succeed: true,
+                failure_reason: None,
+            }]
+        }))
+}
+
 /// Check that remote HEAD (refs/remotes/origin/HEAD) is set.
 pub fn check_remote_head(ctx: &RepoContext) -> CheckResult {
     if !ctx.is_bare {
@@ -443,36 +519,7 @@ pub fn dry_run_remote_head() -> Vec<FixAction> {
 mod tests {
     use super::*;
     use crate::doctor::CheckStatus;
-
-    #[test]
-    fn test_is_common_dir_bare_true() {
-        let temp = tempfile::tempdir().unwrap();
-        let config = temp.path().join("config");
-        std::fs::write(
-            &config,
-            "[core]\n\trepositoryformatversion = 0\n\tbare = true\n",
-        )
-        .unwrap();
-        assert!(is_common_dir_bare(temp.path()));
-    }
-
-    #[test]
-    fn test_is_common_dir_bare_false() {
-        let temp = tempfile::tempdir().unwrap();
-        let config = temp.path().join("config");
-        std::fs::write(
-            &config,
-            "[core]\n\trepositoryformatversion = 0\n\tbare = false\n",
-        )
-        .unwrap();
-        assert!(!is_common_dir_bare(temp.path()));
-    }
-
-    #[test]
-    fn test_is_common_dir_bare_no_config() {
-        let temp = tempfile::tempdir().unwrap();
-        assert!(!is_common_dir_bare(temp.path()));
-    }
+    use std::path::Path;
 
     /// The deferred --fix closure must write into the repo the check was
     /// built from, not whatever the process cwd names when apply_fixes later
@@ -612,4 +659,26 @@ mod tests {
             result.message
         );
     }
+
+    #[test]
+    fn test_check_git_exclude_block_fix_keeps_local_config_untracked() {
+        let dir = tempfile::tempdir().unwrap();
+        git(dir.path(), &["init", "-q", "-b", "main"]);
+        std::fs::write(dir.path().join("daft.local.yml"), "hooks: {}").unwrap();
+
+        let result = check_git_exclude_block(&ctx_for(dir.path()));
+        assert_eq!(result.status, CheckStatus::Warning);
+        result.fix.expect("a missing block offers a fix")().unwrap();
+
+        let result = check_git_exclude_block(&ctx_for(dir.path()));
+        assert_eq!(result.status, CheckStatus::Pass);
+        let out = crate::utils::git_command_at(dir.path())
+            .args(["status", "--porcelain"])
+            .output()
+            .unwrap();
+        assert!(
+            !String::from_utf8_lossy(&out.stdout).contains("daft.local.yml"),
+            "daft.local.yml is excluded"
+        );
+    }
 }
@@ -251,6 +251,17 @@ pub fn run_command_interactive(
     }
 }
 
+/// Wait for an already-spawned child, killing it once `timeout` elapses.
+///
+/// For callers that need their own stdio wiring (a payload piped to stdin,
+/// say) but the same deadline semantics as [`run_command`].
+pub fn wait_with_timeout(child: &mut std::process::Child, timeout: Duration) -> Result<ExitStatus> {
+    match wait_child(child, Some(timeout), None)? {
+        WaitOutcome::Exited(status) => Ok(status),
+        WaitOutcome::Cancelled => unreachable!("no cancel flag was given"),
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // Private helpers
 // ─────────────────────────────────────────────────────────────────────────
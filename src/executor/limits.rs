@@ -0,0 +1,167 @@
+//! Per-job resource limits.
+//!
+//! A job's `limits:` keep a heavyweight command (a post-create build, a
+//! dependency install) from freezing the user's machine. They are applied
+//! by a short shell prelude run in the job's own `sh` before the command, so
+//! every descendant inherits them and no pre-exec hook is needed:
+//!
+//! - `nice` lowers the shell's scheduling priority with `renice`.
+//! - `cpus` pins the shell to the first N CPUs with `taskset` (Linux only;
+//!   silently skipped where `taskset` is missing).
+//! - `memory` caps the address space with `ulimit -v`.
+//!
+//! A limit that can't be applied never fails the job: it is best-effort
+//! containment, not a sandbox.
+
+use std::fmt;
+
+use crate::coordinator::clean_policy::parse_size;
+
+/// Resource limits for one job, from its `limits:` key in `daft.yml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceLimits {
+    /// Niceness increment, 0-19 (higher yields more CPU to everything else).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+
+    /// Number of CPUs the job may run on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<u32>,
+
+    /// Address-space ceiling, e.g. `4GB` or `512MB`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+}
+
+impl ResourceLimits {
+    /// Whether no limit is set.
+    pub fn is_empty(&self) -> bool {
+        self.nice.is_none() && self.cpus.is_none() && self.memory.is_none()
+    }
+
+    /// Problems with the configured values, one message per bad key.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Some(nice) = self.nice
+            && !(0..=19).contains(&nice)
+        {
+            problems.push(format!(
+                "'limits.nice' must be between 0 and 19, got {nice}"
+            ));
+        }
+        if self.cpus == Some(0) {
+            problems.push("'limits.cpus' must be at least 1".to_string());
+        }
+        if let Some(memory) = &self.memory {
+            match parse_size(memory) {
+                Ok(0) => problems.push("'limits.memory' must be greater than zero".to_string()),
+                Ok(_) => {}
+                Err(_) => problems.push(format!(
+                    "'limits.memory' must be a size like 512MB or 4GB, got '{memory}'"
+                )),
+            }
+        }
+        problems
+    }
+
+    /// Shell lines that apply the limits to the current shell (`$$`) and so
+    /// to everything it starts. Empty when no limit is set.
+    pub fn shell_prelude(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(nice) = self.nice.filter(|n| *n > 0) {
+            lines.push(format!("renice -n {nice} -p $$ >/dev/null 2>&1 || true"));
+        }
+        if cfg!(target_os = "linux")
+            && let Some(cpus) = self.cpus.filter(|n| *n > 0)
+        {
+            lines.push(format!(
+                "command -v taskset >/dev/null 2>&1 && taskset -cp 0-{} $$ >/dev/null 2>&1 || true",
+                cpus - 1
+            ));
+        }
+        if let Some(kib) = self.memory_kib() {
+            lines.push(format!("ulimit -v {kib} 2>/dev/null || true"));
+        }
+        lines.join("\n")
+    }
+
+    fn memory_kib(&self) -> Option<u64> {
+        let bytes = parse_size(self.memory.as_deref()?).ok()?;
+        (bytes > 0).then(|| bytes.div_ceil(1024))
+    }
+}
+
+/// The limits as shown in verbose output, e.g. `nice 10, 2 CPUs, memory 4GB`.
+/// A limit this platform can't apply is marked as such.
+impl fmt::Display for ResourceLimits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(nice) = self.nice {
+            parts.push(format!("nice {nice}"));
+        }
+        if let Some(cpus) = self.cpus {
+            let unit = if cpus == 1 { "CPU" } else { "CPUs" };
+            if cfg!(target_os = "linux") {
+                parts.push(format!("{cpus} {unit}"));
+            } else {
+                parts.push(format!("{cpus} {unit} (unsupported here)"));
+            }
+        }
+        if let Some(memory) = &self.memory {
+            parts.push(format!("memory {memory}"));
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(nice: Option<i32>, cpus: Option<u32>, memory: Option<&str>) -> ResourceLimits {
+        ResourceLimits {
+            nice,
+            cpus,
+            memory: memory.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn prelude_applies_each_limit_and_never_fails() {
+        let prelude = limits(Some(10), Some(2), Some("1GB")).shell_prelude();
+        assert!(prelude.contains("renice -n 10 -p $$"));
+        assert!(prelude.contains("ulimit -v 1048576"));
+        if cfg!(target_os = "linux") {
+            assert!(prelude.contains("taskset -cp 0-1 $$"));
+        }
+        assert!(prelude.lines().all(|line| line.ends_with("|| true")));
+        assert_eq!(ResourceLimits::default().shell_prelude(), "");
+    }
+
+    #[test]
+    fn problems_name_each_bad_key() {
+        let problems = limits(Some(-5), Some(0), Some("lots")).problems();
+        assert_eq!(problems.len(), 3, "{problems:?}");
+        assert!(problems[0].contains("limits.nice"));
+        assert!(problems[1].contains("limits.cpus"));
+        assert!(problems[2].contains("limits.memory"));
+        assert!(
+            limits(Some(19), Some(4), Some("512MB"))
+                .problems()
+                .is_empty()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn limits_reach_the_command() {
+        let prelude = limits(Some(5), None, Some("512MB")).shell_prelude();
+        let out = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(format!("{prelude}\nulimit -v"))
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&out.stdout).trim(), "524288");
+    }
+}
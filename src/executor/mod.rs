@@ -7,12 +7,15 @@
 pub mod cli_presenter;
 pub mod command;
 pub mod dag;
+pub mod limits;
 pub mod log_sink;
 pub mod presenter;
 pub mod runner;
 
+pub use limits::ResourceLimits;
 pub use log_sink::{BufferingLogSink, LogSink};
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -109,6 +112,10 @@ pub struct JobSpec {
     /// (`daft hooks jobs cancel --tag <tag>`). Default empty.
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Resource limits (from `JobDef.limits`), applied by a shell prelude
+    /// when the command is spawned. `None` runs the command unrestricted.
+    #[serde(default)]
+    pub limits: Option<ResourceLimits>,
 }
 
 /// `Option<Duration> <-> u64 seconds | null` serde adapter for
@@ -133,6 +140,26 @@ mod opt_duration_secs {
 impl JobSpec {
     /// Default timeout for non-interactive jobs (5 minutes).
     pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+
+    /// The shell text to spawn: `command`, behind the prelude that applies
+    /// `limits` when any are set.
+    pub fn shell_command(&self) -> Cow<'_, str> {
+        match self.limits.as_ref().map(ResourceLimits::shell_prelude) {
+            Some(prelude) if !prelude.is_empty() => {
+                Cow::Owned(format!("{prelude}\n{}", self.command))
+            }
+            _ => Cow::Borrowed(&self.command),
+        }
+    }
+
+    /// The command as shown in progress output, with the job's limits
+    /// appended so verbose runs report them.
+    pub fn command_preview(&self) -> Cow<'_, str> {
+        match self.limits.as_ref().filter(|l| !l.is_empty()) {
+            Some(limits) => Cow::Owned(format!("{}  [limits: {limits}]", self.command)),
+            None => Cow::Borrowed(&self.command),
+        }
+    }
 }
 
 impl Default for JobSpec {
@@ -151,6 +178,7 @@ impl Default for JobSpec {
             background_output: None,
             log_config: None,
             tags: Vec::new(),
+            limits: None,
         }
     }
 }
@@ -269,6 +297,7 @@ mod tests {
             background_output: None,
             log_config: None,
             tags: vec!["slow".into()],
+            limits: None,
         };
 
         assert_eq!(spec.name, "install");
@@ -285,6 +314,32 @@ mod tests {
         assert!(spec.log_config.is_none());
     }
 
+    #[test]
+    fn job_spec_limits_wrap_the_command_and_its_preview() {
+        let plain = JobSpec {
+            command: "make".into(),
+            ..Default::default()
+        };
+        assert_eq!(plain.shell_command(), "make");
+        assert_eq!(plain.command_preview(), "make");
+
+        let limited = JobSpec {
+            command: "make".into(),
+            limits: Some(ResourceLimits {
+                nice: Some(10),
+                memory: Some("2GB".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(limited.shell_command().starts_with("renice -n 10"));
+        assert!(limited.shell_command().ends_with("\nmake"));
+        assert_eq!(
+            limited.command_preview(),
+            "make  [limits: nice 10, memory 2GB]"
+        );
+    }
+
     #[test]
     fn job_spec_timeout_serde_round_trips_some_and_none() {
         let some = JobSpec {
@@ -104,7 +104,11 @@ fn run_sequential(
             return Ok(results);
         }
 
-        presenter.on_job_start(&job.name, job.description.as_deref(), Some(&job.command));
+        presenter.on_job_start(
+            &job.name,
+            job.description.as_deref(),
+            Some(&job.command_preview()),
+        );
         if let Some(s) = sink {
             s.on_job_start(job);
         }
@@ -270,7 +274,11 @@ fn run_dag_execution(
                 return NodeStatus::Cancelled;
             }
 
-            presenter.on_job_start(name, job.description.as_deref(), Some(&job.command));
+            presenter.on_job_start(
+                name,
+                job.description.as_deref(),
+                Some(&job.command_preview()),
+            );
             if let Some(ref s) = sink_for_closure {
                 s.on_job_start(job);
             }
@@ -360,7 +368,11 @@ fn run_dag_sequential_exec(
             return NodeStatus::Cancelled;
         }
 
-        presenter.on_job_start(name, job.description.as_deref(), Some(&job.command));
+        presenter.on_job_start(
+            name,
+            job.description.as_deref(),
+            Some(&job.command_preview()),
+        );
         if let Some(ref s) = sink_for_closure {
             s.on_job_start(job);
         }
@@ -443,7 +455,7 @@ fn execute_single_job(
     cancel: Option<&CancelFlag>,
 ) -> Result<CommandResult> {
     if job.interactive {
-        run_command_interactive(&job.command, &job.env, &job.working_dir, cancel)
+        run_command_interactive(&job.shell_command(), &job.env, &job.working_dir, cancel)
     } else {
         let (tx, rx) = mpsc::channel::<(OutputKind, String)>();
 
@@ -465,7 +477,7 @@ fn execute_single_job(
         });
 
         let result = run_command(
-            &job.command,
+            &job.shell_command(),
             &job.env,
             &job.working_dir,
             job.timeout,
@@ -507,7 +519,7 @@ fn report_completion(job: &JobSpec, result: &JobResult, presenter: &Arc<dyn JobP
             presenter.on_job_success(&job.name, result.duration);
         }
         NodeStatus::Failed => {
-            presenter.on_job_failure(&job.name, result.duration);
+            presenter.on_job_failure_with_exit(&job.name, result.duration, result.exit_code);
             if let Some(code) = result.exit_code {
                 presenter.on_message(&format!("Job '{}' failed (exit code: {code})", job.name));
             } else {
@@ -0,0 +1,273 @@
+//! Forge login management behind `daft auth`.
+//!
+//! daft still owns no credentials: `daft auth login` runs the forge CLI's own
+//! login, which keeps the token in the OS keychain, and every forge feature
+//! keeps shelling out to that same CLI. What this module adds is choosing the
+//! right CLI and host for the repository (the same resolution PR checkout
+//! uses), and reporting where the CLI's token will come from — a token
+//! environment variable overrides the stored login.
+
+use std::process::{Command, ExitStatus, Stdio};
+
+use anyhow::{Result, bail};
+
+use crate::forge::{ForgeConfig, remote_forge_hosts};
+use crate::git::GitCommand;
+
+/// The forge family a login is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Platform {
+    Github,
+    Gitlab,
+}
+
+impl Platform {
+    pub fn label(self) -> &'static str {
+        match self {
+            Platform::Github => "GitHub",
+            Platform::Gitlab => "GitLab",
+        }
+    }
+
+    fn default_host(self) -> &'static str {
+        match self {
+            Platform::Github => "github.com",
+            Platform::Gitlab => "gitlab.com",
+        }
+    }
+
+    fn default_tool(self) -> &'static str {
+        match self {
+            Platform::Github => "gh",
+            Platform::Gitlab => "glab",
+        }
+    }
+
+    fn install_url(self) -> &'static str {
+        match self {
+            Platform::Github => "https://cli.github.com/",
+            Platform::Gitlab => "https://gitlab.com/gitlab-org/cli#installation",
+        }
+    }
+
+    /// Token variables the CLI reads ahead of its stored login, in its own
+    /// precedence order. GitHub splits them by host: the `GH_`/`GITHUB_`
+    /// pair applies to github.com, the `_ENTERPRISE_` pair to anything else.
+    fn token_vars(self, host: &str) -> &'static [&'static str] {
+        match self {
+            Platform::Github if is_github_dotcom(host) => &["GH_TOKEN", "GITHUB_TOKEN"],
+            Platform::Github => &["GH_ENTERPRISE_TOKEN", "GITHUB_ENTERPRISE_TOKEN"],
+            Platform::Gitlab => &["GITLAB_TOKEN", "GITLAB_ACCESS_TOKEN", "OAUTH_TOKEN"],
+        }
+    }
+}
+
+fn is_github_dotcom(host: &str) -> bool {
+    host.eq_ignore_ascii_case("github.com") || host.to_ascii_lowercase().ends_with(".ghe.com")
+}
+
+/// Which CLI to run for which host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthTarget {
+    pub platform: Platform,
+    pub host: String,
+    /// The CLI binary, honoring `daft.forge.githubCli` / `gitlabCli`.
+    pub tool: String,
+}
+
+impl AuthTarget {
+    /// Resolve the target for an optional explicit host and platform.
+    ///
+    /// The host is the argument, else `daft.forge.hostname`, else the first
+    /// forge host among the repository's remotes, else the platform's public
+    /// host. The platform is the argument, else `daft.forge.platform`, else
+    /// whatever the host names (`gitlab` in it means GitLab), else GitHub.
+    pub fn resolve(
+        git: &GitCommand,
+        config: &ForgeConfig,
+        host: Option<&str>,
+        platform: Option<Platform>,
+    ) -> Result<Self> {
+        let configured = match config.platform.as_deref().map(str::to_ascii_lowercase) {
+            None => None,
+            Some(p) if p == "github" => Some(Platform::Github),
+            Some(p) if p == "gitlab" => Some(Platform::Gitlab),
+            Some(other) => {
+                bail!("invalid forge.platform {other:?}; expected `github` or `gitlab`")
+            }
+        };
+        let host = host
+            .map(str::to_string)
+            .or_else(|| config.hostname.clone())
+            .or_else(|| {
+                let hosts = remote_forge_hosts(git);
+                hosts
+                    .iter()
+                    .find(|h| h.contains("github") || h.contains("gitlab"))
+                    .or(hosts.first())
+                    .cloned()
+            });
+        Ok(Self::pick(host, platform.or(configured), config))
+    }
+
+    /// Pure core of [`AuthTarget::resolve`] once the candidates are known.
+    fn pick(host: Option<String>, platform: Option<Platform>, config: &ForgeConfig) -> Self {
+        let platform = platform.unwrap_or_else(|| match &host {
+            Some(h) if h.to_ascii_lowercase().contains("gitlab") => Platform::Gitlab,
+            _ => Platform::Github,
+        });
+        let host = host.unwrap_or_else(|| platform.default_host().to_string());
+        let tool = match platform {
+            Platform::Github => config.github_cli.clone(),
+            Platform::Gitlab => config.gitlab_cli.clone(),
+        }
+        .unwrap_or_else(|| platform.default_tool().to_string());
+        Self {
+            platform,
+            host,
+            tool,
+        }
+    }
+
+    /// The token variable that is set and will override the stored login,
+    /// if any.
+    pub fn token_env(&self) -> Option<&'static str> {
+        self.platform
+            .token_vars(&self.host)
+            .iter()
+            .copied()
+            .find(|var| std::env::var(var).is_ok_and(|v| !v.is_empty()))
+    }
+
+    /// Run the CLI's interactive login for this host. With `with_token`, the
+    /// CLI reads a token from stdin instead of prompting. The CLI stores the
+    /// token in the OS keychain.
+    pub fn login(&self, with_token: bool) -> Result<ExitStatus> {
+        let mut args = vec!["auth", "login", "--hostname", self.host.as_str()];
+        if self.platform == Platform::Gitlab {
+            // gh keeps tokens in the keychain by default; glab has to be asked.
+            args.push("--use-keyring");
+        }
+        if with_token {
+            args.push(match self.platform {
+                Platform::Github => "--with-token",
+                Platform::Gitlab => "--stdin",
+            });
+        }
+        self.run_interactive(&args)
+    }
+
+    /// Run the CLI's logout for this host.
+    pub fn logout(&self) -> Result<ExitStatus> {
+        self.run_interactive(&["auth", "logout", "--hostname", &self.host])
+    }
+
+    /// Ask the CLI whether it holds a working login for this host. Returns
+    /// whether it does and the CLI's own report.
+    pub fn status(&self) -> Result<(bool, String)> {
+        let output = Command::new(&self.tool)
+            .args(["auth", "status", "--hostname", &self.host])
+            .output()
+            .map_err(|e| self.spawn_error(e))?;
+        let report = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok((output.status.success(), report.trim().to_string()))
+    }
+
+    fn run_interactive(&self, args: &[&str]) -> Result<ExitStatus> {
+        Command::new(&self.tool)
+            .args(args)
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .stderr(Stdio::inherit())
+            .status()
+            .map_err(|e| self.spawn_error(e))
+    }
+
+    fn spawn_error(&self, error: std::io::Error) -> anyhow::Error {
+        if error.kind() == std::io::ErrorKind::NotFound {
+            anyhow::anyhow!(
+                "{} CLI ({}) is not installed. Install it from {}",
+                self.platform.label(),
+                self.tool,
+                self.platform.install_url()
+            )
+        } else {
+            anyhow::Error::from(error).context(format!("failed to run {}", self.tool))
+        }
+    }
+}
+
+/// `host` as typed, reduced to a bare hostname: a pasted `https://` URL or a
+/// trailing path is accepted for convenience.
+pub fn normalize_host(host: &str) -> Result<String> {
+    let bare = host
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .split('/')
+        .next()
+        .unwrap_or("")
+        .to_string();
+    if bare.is_empty() || bare.contains(char::is_whitespace) {
+        bail!("not a forge hostname: {host:?}");
+    }
+    Ok(bare)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pick_infers_platform_from_host_and_defaults_to_github() {
+        let config = ForgeConfig::default();
+        let target = AuthTarget::pick(Some("gitlab.example.com".into()), None, &config);
+        assert_eq!(target.platform, Platform::Gitlab);
+        assert_eq!(target.tool, "glab");
+
+        let target = AuthTarget::pick(None, None, &config);
+        assert_eq!(
+            (target.platform, target.host.as_str()),
+            (Platform::Github, "github.com")
+        );
+
+        let target = AuthTarget::pick(None, Some(Platform::Gitlab), &config);
+        assert_eq!(target.host, "gitlab.com");
+    }
+
+    #[test]
+    fn pick_honors_the_configured_cli() {
+        let config = ForgeConfig {
+            github_cli: Some("gh-ent".into()),
+            ..Default::default()
+        };
+        let target = AuthTarget::pick(Some("ghe.corp.example".into()), None, &config);
+        assert_eq!(target.tool, "gh-ent");
+    }
+
+    #[test]
+    fn github_token_vars_depend_on_the_host() {
+        assert_eq!(
+            Platform::Github.token_vars("github.com"),
+            &["GH_TOKEN", "GITHUB_TOKEN"]
+        );
+        assert_eq!(
+            Platform::Github.token_vars("ghe.corp.example")[0],
+            "GH_ENTERPRISE_TOKEN"
+        );
+    }
+
+    #[test]
+    fn normalize_host_accepts_urls() {
+        assert_eq!(
+            normalize_host("https://gitlab.example.com/group/repo").unwrap(),
+            "gitlab.example.com"
+        );
+        assert_eq!(normalize_host("github.com").unwrap(), "github.com");
+        assert!(normalize_host("  ").is_err());
+    }
+}
@@ -0,0 +1,422 @@
+//! Response cache and rate-limit backoff in front of [`cli::run_cli_api`].
+//!
+//! Every provider call goes through [`run_cached`], so PR checkout
+//! (`fetch_info`), `daft list`'s PR annotation (the background snapshot
+//! refresh) and the prune/branch-delete merge sweep share one cache and one
+//! backoff per repo. Still pure CLI passthrough — the cache stores what
+//! `gh`/`glab` printed, and conditional requests are plain `--header` flags.
+//!
+//! - `api` requests revalidate: the cached `ETag` goes out as
+//!   `If-None-Match`, and a 304 serves the stored body (on GitHub a 304 costs
+//!   no rate-limit quota).
+//! - A rate-limit answer records a backoff for the tool, honoring the forge's
+//!   `Retry-After` / reset headers; until it lapses the tool isn't called at
+//!   all and cached bodies are served.
+//! - A transport failure (offline, DNS, timeout) serves the cached body.
+//!
+//! The store is best-effort throughout: a cache that can't be read or written
+//! never fails a call, it only stops helping.
+
+use std::path::Path;
+use std::process::Output;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::forge::cli::{self, CliApiRequest};
+use crate::store::Pool;
+use crate::store::models::{ForgeBackoffRow, ForgeResponseRow};
+use crate::store::paths;
+use crate::store::repos::ForgeResponsesRepo;
+
+/// Backoff when the forge says "rate limited" without saying for how long.
+const DEFAULT_BACKOFF: Duration = Duration::seconds(60);
+
+/// Longest backoff honored, whatever the reset header claims — a clock skew
+/// or a misread header must not silence the forge for a day.
+const MAX_BACKOFF: Duration = Duration::hours(1);
+
+/// Run a forge CLI request through the repo's response cache. Same contract
+/// as [`cli::run_cli_api`]: `Ok(Output)` whenever an answer exists — fresh,
+/// revalidated, or served from cache — with a non-zero status only for a
+/// failure the cache couldn't cover.
+pub fn run_cached(request: CliApiRequest<'_>) -> Result<Output> {
+    let Some(store) = CacheStore::for_root(request.repo_root) else {
+        return cli::run_cli_api(request);
+    };
+    let key = request_key(&request);
+    let now = Utc::now();
+    let cached = store.read(|conn| ForgeResponsesRepo::get(conn, &key));
+
+    if let Some(backoff) = store.read(|conn| ForgeResponsesRepo::backoff(conn, request.tool))
+        && backoff.until > now
+    {
+        return match cached {
+            Some(row) => Ok(served(&row)),
+            None => Err(anyhow::anyhow!(
+                "{} is rate-limited until {}: {}",
+                request.tool,
+                backoff
+                    .until
+                    .with_timezone(&chrono::Local)
+                    .format("%H:%M:%S"),
+                backoff.reason
+            )),
+        };
+    }
+
+    let conditional = request.args.first() == Some(&"api");
+    let if_none_match = cached
+        .as_ref()
+        .and_then(|row| row.etag.as_deref())
+        .map(|etag| format!("If-None-Match: {etag}"));
+    let mut args: Vec<&str> = request.args.to_vec();
+    if conditional {
+        args.push("--include");
+        if let Some(header) = &if_none_match {
+            args.extend(["--header", header.as_str()]);
+        }
+    }
+    let tool = request.tool;
+    let mut output = cli::run_cli_api(CliApiRequest {
+        args: &args,
+        ..request
+    })?;
+
+    let head = if conditional {
+        let (head, body) = split_include(&output.stdout);
+        output.stdout = body;
+        head
+    } else {
+        None
+    };
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+
+    if let (Some(head), Some(row)) = (&head, &cached)
+        && head.status == 304
+    {
+        store.write(|conn| ForgeResponsesRepo::touch(conn, &key, now));
+        return Ok(served(row));
+    }
+
+    if let Some(wait) = rate_limit_backoff(head.as_ref(), &stderr, now) {
+        let reason = cli::error_details(&output);
+        store.write(|conn| {
+            ForgeResponsesRepo::set_backoff(
+                conn,
+                &ForgeBackoffRow {
+                    tool: tool.to_string(),
+                    until: now + wait,
+                    reason: reason.clone(),
+                },
+            )
+        });
+        return Ok(cached.as_ref().map(served).unwrap_or(output));
+    }
+
+    if output.status.success() {
+        if let Ok(body) = std::str::from_utf8(&output.stdout) {
+            let row = ForgeResponseRow {
+                request_key: key,
+                etag: head
+                    .as_ref()
+                    .and_then(|h| h.header("etag"))
+                    .map(String::from),
+                body: body.to_string(),
+                fetched_at: now,
+            };
+            store.write(|conn| ForgeResponsesRepo::put(conn, &row));
+        }
+        return Ok(output);
+    }
+
+    if head.is_none() && is_transport_failure(&stderr) {
+        return Ok(cached.as_ref().map(served).unwrap_or(output));
+    }
+    Ok(output)
+}
+
+/// The repo's coordinator store, located from the directory a CLI runs in.
+struct CacheStore {
+    repo_hash: String,
+}
+
+impl CacheStore {
+    fn for_root(repo_root: &Path) -> Option<Self> {
+        let common_dir = crate::core::repo::git_common_dir_at(repo_root)?;
+        let repo_hash =
+            crate::core::repo_identity::compute_repo_id_from_common_dir(&common_dir).ok()?;
+        Some(Self { repo_hash })
+    }
+
+    /// A read that never materializes a store and reads any failure as a
+    /// miss.
+    fn read<T>(
+        &self,
+        f: impl FnOnce(&rusqlite::Connection) -> crate::store::error::Result<Option<T>>,
+    ) -> Option<T> {
+        let db_path = crate::daft_state_dir()
+            .ok()?
+            .join(paths::JOBS_SUBDIR)
+            .join(&self.repo_hash)
+            .join(paths::COORDINATOR_DB);
+        if !db_path.exists() {
+            return None;
+        }
+        let pool = Pool::open(&db_path).ok()?;
+        let conn = pool.reader().ok()?;
+        f(&conn).ok().flatten()
+    }
+
+    /// A write that gives up quickly rather than hold a forge call behind a
+    /// busy writer, and swallows failure.
+    fn write(&self, f: impl FnOnce(&rusqlite::Connection) -> crate::store::error::Result<()>) {
+        let _ = (|| -> anyhow::Result<()> {
+            let pool = Pool::open(&paths::for_repo(&self.repo_hash)?)?;
+            let conn = pool.writer()?;
+            conn.busy_timeout(std::time::Duration::from_millis(
+                crate::store::connection::READER_BUSY_TIMEOUT_MS as u64,
+            ))?;
+            f(&conn)?;
+            Ok(())
+        })();
+    }
+}
+
+/// Identity of a request: the tool, its arguments, and the per-call env
+/// (`GH_HOST` changes the answer without changing the arguments).
+fn request_key(request: &CliApiRequest<'_>) -> String {
+    let mut key = request.tool.to_string();
+    for arg in request.args {
+        key.push('\u{1f}');
+        key.push_str(arg);
+    }
+    for (name, value) in request.extra_env {
+        key.push('\u{1e}');
+        key.push_str(name);
+        key.push('=');
+        key.push_str(value);
+    }
+    key
+}
+
+/// A cached body dressed as a successful CLI run.
+fn served(row: &ForgeResponseRow) -> Output {
+    Output {
+        status: cli::exit_status_with_code(0),
+        stdout: row.body.as_bytes().to_vec(),
+        stderr: Vec::new(),
+    }
+}
+
+/// The status line and headers `--include` prepends to an `api` response.
+#[derive(Debug)]
+struct HttpHead {
+    status: u16,
+    headers: Vec<(String, String)>,
+}
+
+impl HttpHead {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Split `--include` output into its HTTP head and the body. No head (the
+/// request never got an answer) leaves the output untouched as the body.
+fn split_include(stdout: &[u8]) -> (Option<HttpHead>, Vec<u8>) {
+    let text = String::from_utf8_lossy(stdout);
+    let Some(status) = text
+        .lines()
+        .next()
+        .filter(|line| line.starts_with("HTTP/"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+    else {
+        return (None, stdout.to_vec());
+    };
+    let (head, body) = match text
+        .find("\r\n\r\n")
+        .map(|i| (i, 4))
+        .or_else(|| text.find("\n\n").map(|i| (i, 2)))
+    {
+        Some((i, sep)) => (&text[..i], &text[i + sep..]),
+        None => (&text[..], ""),
+    };
+    let headers = head
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect();
+    (Some(HttpHead { status, headers }), body.as_bytes().to_vec())
+}
+
+/// How long to back off, when the answer was a rate limit. Recognizes a 429,
+/// a 403 with the quota exhausted, and the CLIs' own "rate limit" errors
+/// (`gh pr list` is GraphQL and carries no head). The wait comes from
+/// `Retry-After`, else the reset header (GitHub's `X-RateLimit-Reset`,
+/// GitLab's `RateLimit-Reset`), else [`DEFAULT_BACKOFF`].
+fn rate_limit_backoff(
+    head: Option<&HttpHead>,
+    stderr: &str,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    let exhausted = head.is_some_and(|h| {
+        h.status == 429
+            || (h.status == 403
+                && ["x-ratelimit-remaining", "ratelimit-remaining"]
+                    .iter()
+                    .any(|name| h.header(name) == Some("0")))
+    });
+    let lower = stderr.to_ascii_lowercase();
+    if !exhausted && !lower.contains("rate limit") && !lower.contains("submitted too quickly") {
+        return None;
+    }
+    let header_secs = |name: &str| head.and_then(|h| h.header(name)?.parse::<i64>().ok());
+    let wait = header_secs("retry-after")
+        .map(Duration::seconds)
+        .or_else(|| {
+            header_secs("x-ratelimit-reset")
+                .or_else(|| header_secs("ratelimit-reset"))
+                .and_then(|epoch| DateTime::from_timestamp(epoch, 0))
+                .map(|reset| reset - now)
+        })
+        .filter(|wait| *wait > Duration::zero())
+        .unwrap_or(DEFAULT_BACKOFF);
+    Some(wait.min(MAX_BACKOFF))
+}
+
+/// Whether a failed run never reached the forge — the failures a cached
+/// answer is a fair stand-in for. Matches the Go network errors `gh`/`glab`
+/// surface.
+fn is_transport_failure(stderr: &str) -> bool {
+    let lower = stderr.to_ascii_lowercase();
+    [
+        "error connecting to",
+        "dial tcp",
+        "no such host",
+        "could not resolve host",
+        "connection refused",
+        "network is unreachable",
+        "i/o timeout",
+        "tls handshake timeout",
+        "context deadline exceeded",
+    ]
+    .iter()
+    .any(|needle| lower.contains(needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn split_include_separates_head_from_body() {
+        let (head, body) = split_include(
+            b"HTTP/2.0 200 OK\r\nEtag: W/\"abc\"\r\nX-Github-Request-Id: 1\r\n\r\n{\"n\":1}",
+        );
+        let head = head.unwrap();
+        assert_eq!(head.status, 200);
+        assert_eq!(head.header("ETag"), Some("W/\"abc\""));
+        assert_eq!(body, b"{\"n\":1}");
+
+        let (head, body) = split_include(b"HTTP/1.1 304 Not Modified\nEtag: \"abc\"\n\n");
+        assert_eq!(head.unwrap().status, 304);
+        assert!(body.is_empty());
+
+        let (head, body) = split_include(b"[]");
+        assert!(head.is_none(), "no head means no answer");
+        assert_eq!(body, b"[]");
+    }
+
+    #[test]
+    fn rate_limit_backoff_honors_forge_headers() {
+        let head = |status, headers: &[(&str, &str)]| HttpHead {
+            status,
+            headers: headers
+                .iter()
+                .map(|(n, v)| (n.to_string(), v.to_string()))
+                .collect(),
+        };
+        let reset = (now() + Duration::minutes(5)).timestamp().to_string();
+        assert_eq!(
+            rate_limit_backoff(
+                Some(&head(
+                    403,
+                    &[
+                        ("X-Ratelimit-Remaining", "0"),
+                        ("X-Ratelimit-Reset", &reset)
+                    ]
+                )),
+                "",
+                now()
+            ),
+            Some(Duration::minutes(5))
+        );
+        assert_eq!(
+            rate_limit_backoff(Some(&head(429, &[("Retry-After", "30")])), "", now()),
+            Some(Duration::seconds(30))
+        );
+        assert_eq!(
+            rate_limit_backoff(Some(&head(429, &[("Retry-After", "999999")])), "", now()),
+            Some(MAX_BACKOFF)
+        );
+        assert_eq!(
+            rate_limit_backoff(
+                None,
+                "GraphQL: API rate limit exceeded for user ID 1.",
+                now()
+            ),
+            Some(DEFAULT_BACKOFF)
+        );
+        // A permissions 403 is not a rate limit.
+        assert_eq!(
+            rate_limit_backoff(
+                Some(&head(403, &[("X-Ratelimit-Remaining", "4999")])),
+                "",
+                now()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn only_transport_failures_fall_back() {
+        assert!(is_transport_failure(
+            "error connecting to api.github.com\ncheck your internet connection"
+        ));
+        assert!(is_transport_failure(
+            "Get \"https://x/api\": dial tcp: lookup x: no such host"
+        ));
+        assert!(!is_transport_failure("HTTP 404: Not Found"));
+        assert!(!is_transport_failure(
+            "To get started with GitHub CLI, please run:  gh auth login"
+        ));
+    }
+
+    #[test]
+    fn request_key_separates_hosts() {
+        let request = |env: &'static [(&'static str, &'static str)]| CliApiRequest {
+            tool: "gh",
+            args: &["pr", "list"],
+            repo_root: Path::new("."),
+            prompt_env: ("GH_PROMPT_DISABLED", "1"),
+            extra_env: env,
+            install_hint: "",
+            run_context: "",
+        };
+        assert_ne!(
+            request_key(&request(&[])),
+            request_key(&request(&[("GH_HOST", "ghe.example.com")]))
+        );
+    }
+}
@@ -142,11 +142,11 @@ pub fn host_from_url(url: &str) -> Result<String> {
 }
 
 /// Construct an [`ExitStatus`](std::process::ExitStatus) carrying a specific
-/// exit `code`, portably across Unix and Windows, for tests that exercise
-/// forge-CLI failure classification. Unix packs the code into the high byte of
-/// the wait status; Windows stores it directly — both yield
-/// `.code() == Some(code)` and `.success() == (code == 0)`.
-#[cfg(test)]
+/// exit `code`, portably across Unix and Windows — for answers served from the
+/// response cache, and for tests that exercise forge-CLI failure
+/// classification. Unix packs the code into the high byte of the wait status;
+/// Windows stores it directly — both yield `.code() == Some(code)` and
+/// `.success() == (code == 0)`.
 pub(crate) fn exit_status_with_code(code: i32) -> std::process::ExitStatus {
     #[cfg(unix)]
     use std::os::unix::process::ExitStatusExt;
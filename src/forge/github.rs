@@ -4,13 +4,14 @@ use anyhow::{Context, Result, bail};
 use serde::Deserialize;
 
 use crate::core::worktree::forge_ref::ForgeRefKind;
+use crate::forge::cache;
 use crate::forge::cli::{self, CliApiRequest};
 use crate::forge::info::{BaseRepo, CiStatus, PrListEntry, RemoteRefInfo, parse_forge_timestamp};
 use crate::forge::provider::{ForgeContext, RemoteRefProvider, RepoCoords};
 use crate::git::GitCommand;
 
-const GH_PROMPT_ENV: (&str, &str) = ("GH_PROMPT_DISABLED", "1");
-const INSTALL_HINT: &str = "GitHub CLI (gh) is not installed. Install it from https://cli.github.com/ and run `gh auth login`.";
+pub(crate) const GH_PROMPT_ENV: (&str, &str) = ("GH_PROMPT_DISABLED", "1");
+pub(crate) const INSTALL_HINT: &str = "GitHub CLI (gh) is not installed. Install it from https://cli.github.com/ and run `gh auth login`.";
 
 pub struct GitHubProvider;
 
@@ -33,7 +34,7 @@ impl RemoteRefProvider for GitHubProvider {
             args.extend(["--hostname", host]);
         }
 
-        let output = cli::run_cli_api(CliApiRequest {
+        let output = cache::run_cached(CliApiRequest {
             tool,
             args: &args,
             repo_root: ctx.repo_root,
@@ -91,7 +92,7 @@ fn run_pr_list(
         base_fields.to_string()
     };
 
-    let output = cli::run_cli_api(CliApiRequest {
+    let output = cache::run_cached(CliApiRequest {
         tool: ctx.tool_or("gh"),
         args: &[
             "pr", "list", "--state", state, "--limit", limit, "--json", &fields,
@@ -10,12 +10,13 @@ use anyhow::{Context, Result, bail};
 use serde::Deserialize;
 
 use crate::core::worktree::forge_ref::ForgeRefKind;
+use crate::forge::cache;
 use crate::forge::cli::{self, CliApiRequest};
 use crate::forge::info::{BaseRepo, PrListEntry, RemoteRefInfo, parse_forge_timestamp};
 use crate::forge::provider::{ForgeContext, RemoteRefProvider};
 
-const GLAB_PROMPT_ENV: (&str, &str) = ("GLAB_NO_PROMPT", "1");
-const INSTALL_HINT: &str = "GitLab CLI (glab) is not installed. Install it from https://gitlab.com/gitlab-org/cli#installation and run `glab auth login`.";
+pub(crate) const GLAB_PROMPT_ENV: (&str, &str) = ("GLAB_NO_PROMPT", "1");
+pub(crate) const INSTALL_HINT: &str = "GitLab CLI (glab) is not installed. Install it from https://gitlab.com/gitlab-org/cli#installation and run `glab auth login`.";
 
 pub struct GitLabProvider;
 
@@ -42,7 +43,7 @@ impl RemoteRefProvider for GitLabProvider {
             args.extend(["--hostname", host]);
         }
 
-        let output = cli::run_cli_api(CliApiRequest {
+        let output = cache::run_cached(CliApiRequest {
             tool: ctx.tool_or("glab"),
             args: &args,
             repo_root: ctx.repo_root,
@@ -87,7 +88,7 @@ fn run_mr_list(ctx: &ForgeContext<'_>, api_path: &str) -> Result<Vec<PrListEntry
         args.extend(["--hostname", host]);
     }
 
-    let output = cli::run_cli_api(CliApiRequest {
+    let output = cache::run_cached(CliApiRequest {
         tool: ctx.tool_or("glab"),
         args: &args,
         repo_root: ctx.repo_root,
@@ -170,7 +171,7 @@ struct GlabMrListItem {
 
 /// Percent-encode the `/` separators in a project path (`group/sub/repo` →
 /// `group%2Fsub%2Frepo`) so it's a single path segment for the projects API.
-fn encode_project(path: &str) -> String {
+pub(crate) fn encode_project(path: &str) -> String {
     path.replace('/', "%2F")
 }
 
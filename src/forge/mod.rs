@@ -7,11 +7,16 @@
 //! remote (the `pr`/`mr` prefix is a friendly alias, not the platform selector),
 //! ask the CLI for the PR/MR metadata, and hand back a platform-neutral
 //! [`RemoteRefInfo`] plus the local remote its head ref is fetched from.
+//! Answers are cached per repo ([`cache`]) so an offline or rate-limited
+//! forge degrades to the last known answer rather than an error.
 
+pub mod auth;
+pub mod cache;
 pub mod cli;
 pub mod github;
 pub mod gitlab;
 pub mod info;
+pub mod org;
 pub mod parse;
 pub mod provider;
 
@@ -46,10 +51,18 @@ pub struct ForgeConfig {
 impl ForgeConfig {
     /// Load daft's forge config from git config (`daft.forge.*`). Auth is never
     /// read here — it stays in `gh`/`glab`. Cheap enough to read on the (rare)
-    /// PR-checkout path rather than bloating `DaftSettings`.
+    /// PR-checkout path rather than bloating `DaftSettings`. Outside a
+    /// repository (`daft auth`, `daft clone-all --org`) only the global
+    /// config is read.
     pub fn load(git: &GitCommand) -> Self {
         use crate::core::settings::keys;
-        let get = |key: &str| git.config_get(key).ok().flatten().filter(|v| !v.is_empty());
+        let get = |key: &str| {
+            git.config_get(key)
+                .or_else(|_| git.config_get_global(key))
+                .ok()
+                .flatten()
+                .filter(|v| !v.is_empty())
+        };
         Self {
             platform: get(keys::FORGE_PLATFORM),
             github_cli: get(keys::FORGE_GITHUB_CLI),
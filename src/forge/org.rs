@@ -0,0 +1,186 @@
+//! Org repository listings for `daft clone-all --org`.
+//!
+//! Like every other forge feature this shells out to the platform CLI
+//! (`gh repo list` / `glab api`), so listing private repos works with the
+//! user's existing login. Listings are not cached: they run once per
+//! onboarding, and a stale list would silently miss new repos.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+
+use crate::forge::auth::{AuthTarget, Platform};
+use crate::forge::cli::{self, CliApiRequest};
+use crate::forge::{github, gitlab};
+
+/// One repository in an org (GitHub) or group (GitLab) listing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrgRepo {
+    pub name: String,
+    pub ssh_url: String,
+    pub https_url: String,
+    pub archived: bool,
+}
+
+impl OrgRepo {
+    pub fn clone_url(&self, https: bool) -> &str {
+        if https {
+            &self.https_url
+        } else {
+            &self.ssh_url
+        }
+    }
+}
+
+/// List the repositories owned by `owner` on the target's host. GitLab
+/// listings include subgroups; an `owner` that is not a group is listed as
+/// a user namespace.
+pub fn list(target: &AuthTarget, owner: &str, cwd: &Path) -> Result<Vec<OrgRepo>> {
+    match target.platform {
+        Platform::Github => list_github(target, owner, cwd),
+        Platform::Gitlab => list_gitlab(target, owner, cwd),
+    }
+}
+
+fn list_github(target: &AuthTarget, owner: &str, cwd: &Path) -> Result<Vec<OrgRepo>> {
+    let mut extra_env: Vec<(&str, &str)> = Vec::new();
+    if target.host != "github.com" {
+        // `gh repo list` has no `--hostname` flag.
+        extra_env.push(("GH_HOST", &target.host));
+    }
+    let output = cli::run_cli_api(CliApiRequest {
+        tool: &target.tool,
+        args: &[
+            "repo",
+            "list",
+            owner,
+            "--limit",
+            "4000",
+            "--json",
+            "name,sshUrl,url,isArchived",
+        ],
+        repo_root: cwd,
+        prompt_env: github::GH_PROMPT_ENV,
+        extra_env: &extra_env,
+        install_hint: github::INSTALL_HINT,
+        run_context: "failed to run gh repo list",
+    })?;
+    if !output.status.success() {
+        bail!(
+            "could not list repositories of '{owner}' via gh: {}",
+            cli::error_details(&output)
+        );
+    }
+    parse_gh_list(&output.stdout)
+}
+
+fn list_gitlab(target: &AuthTarget, owner: &str, cwd: &Path) -> Result<Vec<OrgRepo>> {
+    let encoded = gitlab::encode_project(owner);
+    let run = |path: &str| {
+        cli::run_cli_api(CliApiRequest {
+            tool: &target.tool,
+            args: &["api", "--paginate", "--hostname", &target.host, path],
+            repo_root: cwd,
+            prompt_env: gitlab::GLAB_PROMPT_ENV,
+            extra_env: &[],
+            install_hint: gitlab::INSTALL_HINT,
+            run_context: "failed to run glab api",
+        })
+    };
+    let mut output = run(&format!(
+        "groups/{encoded}/projects?include_subgroups=true&per_page=100"
+    ))?;
+    if !output.status.success() && cli::error_details(&output).contains("404") {
+        output = run(&format!("users/{encoded}/projects?per_page=100"))?;
+    }
+    if !output.status.success() {
+        bail!(
+            "could not list projects of '{owner}' via glab: {}",
+            cli::error_details(&output)
+        );
+    }
+    parse_glab_pages(&output.stdout)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhRepo {
+    name: String,
+    ssh_url: String,
+    url: String,
+    #[serde(default)]
+    is_archived: bool,
+}
+
+/// Parse `gh repo list --json`. Pure, so the JSON shape unit-tests without a
+/// subprocess.
+fn parse_gh_list(json: &[u8]) -> Result<Vec<OrgRepo>> {
+    let repos: Vec<GhRepo> = serde_json::from_slice(json)
+        .context("could not parse the gh repository listing (a GitHub CLI change?)")?;
+    Ok(repos
+        .into_iter()
+        .map(|repo| OrgRepo {
+            name: repo.name,
+            ssh_url: repo.ssh_url,
+            https_url: repo.url,
+            archived: repo.is_archived,
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct GlabProject {
+    path: String,
+    ssh_url_to_repo: String,
+    http_url_to_repo: String,
+    #[serde(default)]
+    archived: bool,
+}
+
+/// Parse `glab api --paginate` output: one JSON array per page, back to back.
+fn parse_glab_pages(json: &[u8]) -> Result<Vec<OrgRepo>> {
+    let mut repos = Vec::new();
+    for page in serde_json::Deserializer::from_slice(json).into_iter::<Vec<GlabProject>>() {
+        let page =
+            page.context("could not parse the glab project listing (a GitLab API change?)")?;
+        repos.extend(page.into_iter().map(|project| OrgRepo {
+            name: project.path,
+            ssh_url: project.ssh_url_to_repo,
+            https_url: project.http_url_to_repo,
+            archived: project.archived,
+        }));
+    }
+    Ok(repos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_gh_repo_list() {
+        let repos = parse_gh_list(
+            br#"[{"name":"api","sshUrl":"git@github.com:acme/api.git","url":"https://github.com/acme/api","isArchived":true}]"#,
+        )
+        .unwrap();
+        assert_eq!(repos[0].name, "api");
+        assert_eq!(repos[0].clone_url(false), "git@github.com:acme/api.git");
+        assert_eq!(repos[0].clone_url(true), "https://github.com/acme/api");
+        assert!(repos[0].archived);
+    }
+
+    #[test]
+    fn parses_concatenated_glab_pages() {
+        let page = |name: &str| {
+            format!(
+                r#"[{{"path":"{name}","ssh_url_to_repo":"git@gitlab.com:g/{name}.git","http_url_to_repo":"https://gitlab.com/g/{name}.git"}}]"#
+            )
+        };
+        let json = format!("{}\n{}", page("a"), page("b"));
+        let repos = parse_glab_pages(json.as_bytes()).unwrap();
+        let names: Vec<_> = repos.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["a", "b"]);
+        assert!(!repos[1].archived);
+    }
+}
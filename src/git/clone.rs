@@ -12,7 +12,7 @@ impl GitCommand {
             cmd.arg("--quiet");
         }
 
-        cmd.arg(repo_url).arg(target_dir);
+        cmd.arg("--").arg(repo_url).arg(target_dir);
 
         let output = cmd
             .output()
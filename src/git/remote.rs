@@ -359,6 +359,20 @@ impl GitCommand {
         }
     }
 
+    /// Push explicit refspecs (`+src:dst`, `:dst` deletions) to `remote`,
+    /// running from a specific directory.
+    pub fn push_refspecs_from(
+        &self,
+        remote: &str,
+        refspecs: &[String],
+        cwd: &Path,
+        opts: &PushOptions,
+    ) -> Result<PushIo> {
+        let mut args = vec![remote];
+        args.extend(refspecs.iter().map(String::as_str));
+        self.run_push(&args, cwd, opts)
+    }
+
     /// Delete a remote branch via `git push <remote> --delete <branch>`,
     /// running from a specific directory.
     pub fn push_delete_from(
@@ -532,7 +546,7 @@ impl GitCommand {
         // down on the first Ctrl+C; unsupervised callers (clone) pass no
         // flag and get a classic blocking run.
         let mut cmd = Command::new("git");
-        cmd.args(["ls-remote", "--heads", remote]);
+        cmd.args(["ls-remote", "--heads", "--", remote]);
 
         let output = cancel::output_with_cancel(&mut cmd, self.cancel_flag())
             .context("Failed to execute git ls-remote command")?;
@@ -556,7 +570,7 @@ impl GitCommand {
     /// call site that would benefit from a gix arm here.
     pub fn ls_remote_symref(&self, remote: &str) -> Result<String> {
         let output = Command::new("git")
-            .args(["ls-remote", "--symref", remote, "HEAD"])
+            .args(["ls-remote", "--symref", "--", remote, "HEAD"])
             .output()
             .context("Failed to execute git ls-remote command")?;
 
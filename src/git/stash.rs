@@ -113,6 +113,28 @@ impl GitCommand {
         Ok(())
     }
 
+    /// Apply the top stash without removing it, restoring what was staged
+    /// to the index as well (`git stash apply --index`)
+    pub fn stash_apply_index(&self) -> Result<()> {
+        let mut cmd = Command::new("git");
+        cmd.args(["stash", "apply", "--index"]);
+
+        if self.quiet {
+            cmd.arg("--quiet");
+        }
+
+        let output = cmd
+            .output()
+            .context("Failed to execute git stash apply command")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Git stash apply failed: {}", stderr);
+        }
+
+        Ok(())
+    }
+
     /// Drop the top stash entry
     pub fn stash_drop(&self) -> Result<()> {
         let mut cmd = Command::new("git");
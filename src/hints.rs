@@ -1,7 +1,9 @@
 //! First-run hints for improved user experience.
 //!
 //! This module provides a system for showing one-time hints to users
-//! who haven't yet activated optional features like shell integration.
+//! who haven't yet activated optional features like shell integration,
+//! and the "next steps" hints printed after key commands (see
+//! [`show_next_steps`]).
 
 use crate::CD_FILE_ENV;
 use crate::output::Output;
@@ -9,13 +11,15 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::env;
-use std::fs;
 use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Environment variable to suppress all hints.
 pub const NO_HINTS_ENV: &str = "DAFT_NO_HINTS";
 
+/// Current hints state schema version.
+const STATE_VERSION: u32 = 1;
+
 /// State file for tracking which hints have been shown.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HintsState {
@@ -28,13 +32,13 @@ pub struct HintsState {
 }
 
 fn default_version() -> u32 {
-    1
+    STATE_VERSION
 }
 
 impl Default for HintsState {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: STATE_VERSION,
             shown: HashSet::new(),
         }
     }
@@ -47,17 +51,10 @@ impl HintsState {
         Self::load_from(&path)
     }
 
-    /// Load the hints state from a specific path.
-    pub fn load_from(path: &PathBuf) -> Result<Self> {
-        if !path.exists() {
-            return Ok(Self::default());
-        }
-
-        let contents = fs::read_to_string(path)
-            .with_context(|| format!("Failed to read hints state from {}", path.display()))?;
-
-        serde_json::from_str(&contents)
-            .with_context(|| format!("Failed to parse hints state from {}", path.display()))
+    /// Load the hints state from a specific path. An unparseable file is
+    /// backed up and replaced by defaults (see [`crate::state_file`]).
+    pub fn load_from(path: &Path) -> Result<Self> {
+        crate::state_file::load_json_or_recover(path, "hints state", STATE_VERSION)
     }
 
     /// Save the hints state to the default location.
@@ -67,20 +64,11 @@ impl HintsState {
     }
 
     /// Save the hints state to a specific path.
-    pub fn save_to(&self, path: &PathBuf) -> Result<()> {
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
-        }
-
+    pub fn save_to(&self, path: &Path) -> Result<()> {
         let contents =
             serde_json::to_string_pretty(self).context("Failed to serialize hints state")?;
-
-        fs::write(path, contents)
-            .with_context(|| format!("Failed to write hints state to {}", path.display()))?;
-
-        Ok(())
+        crate::state_file::write_atomic(path, contents.as_bytes())
+            .context("Failed to write hints state")
     }
 
     /// Get the default path for the hints state file.
@@ -164,6 +152,81 @@ pub fn maybe_show_shell_hint(output: &mut dyn Output) -> Result<bool> {
     Ok(true)
 }
 
+/// A point after a command finishes where daft suggests what to do next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NextStep {
+    /// A repository was cloned into a worktree layout.
+    Cloned,
+    /// A new repository was initialized.
+    Initialized,
+    /// `prune` kept gone-but-unmerged branches it would delete with `--force`.
+    PruneKeptUnmerged,
+    /// `prune` kept worktrees holding refined daft files.
+    PruneKeptRefined,
+}
+
+/// The rules table: for each situation, the commands to suggest (arguments
+/// to [`crate::daft_cmd`]) and what each does.
+const NEXT_STEPS: &[(NextStep, &[(&str, &str)])] = &[
+    (
+        NextStep::Cloned,
+        &[
+            ("start <new-branch>", "create a worktree for a new branch"),
+            ("go <branch>", "open a worktree for an existing branch"),
+            ("list", "see this repository's worktrees"),
+        ],
+    ),
+    (
+        NextStep::Initialized,
+        &[
+            ("start <new-branch>", "create a worktree for a new branch"),
+            ("list", "see this repository's worktrees"),
+        ],
+    ),
+    (
+        NextStep::PruneKeptUnmerged,
+        &[("prune --force", "delete the unmerged branches anyway")],
+    ),
+    (
+        NextStep::PruneKeptRefined,
+        &[
+            ("file merge", "consolidate the refined files"),
+            ("prune --force", "remove the worktrees anyway"),
+        ],
+    ),
+];
+
+/// The suggestions for `step`, as `(command, description)` pairs.
+pub fn next_steps(step: NextStep) -> Vec<(String, &'static str)> {
+    NEXT_STEPS
+        .iter()
+        .filter(|(rule, _)| *rule == step)
+        .flat_map(|(_, steps)| steps.iter())
+        .map(|(args, what)| (crate::daft_cmd(args), *what))
+        .collect()
+}
+
+/// Print the "next steps" block for `step`.
+///
+/// `enabled` is the `daft.ui.hints` setting. Like the other hints, the
+/// block is also suppressed by `DAFT_NO_HINTS` and in quiet mode.
+pub fn show_next_steps(step: NextStep, enabled: bool, output: &mut dyn Output) {
+    if !enabled || hints_disabled() || output.is_quiet() {
+        return;
+    }
+    let steps = next_steps(step);
+    let width = steps.iter().map(|(cmd, _)| cmd.len()).max().unwrap_or(0);
+    output.info("");
+    output.info("hint: Next steps:");
+    for (cmd, what) in &steps {
+        output.info(&format!("hint:   {cmd:<width$}  {what}"));
+    }
+    output.info(&format!(
+        "hint: Disable these hints with `git config --global {} false`",
+        crate::core::settings::keys::UI_HINTS
+    ));
+}
+
 /// Result of the layout prompt.
 pub enum LayoutPromptResult {
     /// User chose a layout (e.g., "contained").
@@ -354,6 +417,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn next_steps_follow_the_rules_table_and_the_setting() {
+        let steps = next_steps(NextStep::PruneKeptUnmerged);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].0, "git daft prune --force");
+
+        let mut output = crate::output::TestOutput::new();
+        show_next_steps(NextStep::Cloned, false, &mut output);
+        assert!(output.entries().is_empty());
+
+        show_next_steps(NextStep::Cloned, true, &mut output);
+        let infos = output.infos();
+        assert!(infos.contains(&"hint: Next steps:"), "{infos:?}");
+        assert!(
+            infos
+                .iter()
+                .any(|line| line.contains("git daft start <new-branch>"))
+        );
+    }
+
     #[test]
     fn test_hints_state_default() {
         let state = HintsState::default();
@@ -0,0 +1,159 @@
+//! Stable entry points for running a repository's `daft.yml` hooks from
+//! outside daft's own commands.
+//!
+//! Everything else under `daft::` is internal and may change in any release.
+//! The items re-exported or defined here follow semver: they only change
+//! incompatibly in a major version. That covers [`HookContext`]'s
+//! constructors and `with_*` builders, [`HookEnvironment`]'s accessors,
+//! reading [`HookResult`]'s fields, and [`HookRunner`]. The context, result,
+//! and hook-type types are `#[non_exhaustive]`, so new fields and variants
+//! can arrive in minor versions; build contexts through the constructors
+//! rather than struct literals. Implementing [`Output`] outside daft is not
+//! covered — pass a [`CliOutput`].
+//!
+//! A CI wrapper that wants a repo's `worktree-post-create` jobs, with their
+//! `skip:`/`only:` conditions and daft's usual rendering, without going
+//! through `daft start`:
+//!
+//! ```no_run
+//! use daft::hooks::api::{CliOutput, HookContext, HookRunner, HookType};
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let ctx = HookContext::for_worktree(HookType::PostCreate, "ci", "/builds/app/main")?;
+//! let result = HookRunner::from_git_config()?
+//!     .trusted(true)
+//!     .run(&ctx, &mut CliOutput::default_output())?;
+//! if !result.success && !result.skipped {
+//!     std::process::exit(result.exit_code.unwrap_or(1));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use super::HookExecutor;
+use super::yaml_executor::JobFilter;
+use crate::core::repo::git_stdout;
+use crate::executor::cli_presenter::CliPresenter;
+
+pub use super::{HookContext, HookEnvironment, HookResult, HookType, HooksConfig, RemovalReason};
+pub use crate::output::{CliOutput, Output};
+
+impl HookContext {
+    /// Context for running `hook_type` against an existing worktree, with
+    /// the project root, git dir, and branch read from the worktree itself
+    /// rather than the current directory. `command` is what the hook sees
+    /// as `DAFT_COMMAND`. The worktree is both source and target, as with
+    /// `daft hooks run`; a detached HEAD reports the branch as `HEAD`.
+    pub fn for_worktree(
+        hook_type: HookType,
+        command: impl Into<String>,
+        worktree: impl AsRef<Path>,
+    ) -> Result<Self> {
+        let worktree = worktree.as_ref();
+        let toplevel = git_stdout(worktree, &["rev-parse", "--show-toplevel"])
+            .with_context(|| format!("{} is not inside a git worktree", worktree.display()))?;
+        let worktree_path = PathBuf::from(toplevel.trim());
+        let git_dir = crate::core::repo::git_common_dir_at(&worktree_path)
+            .context("Could not determine git directory")?;
+        let project_root = crate::core::repo::project_root_for(&git_dir)
+            .context("Could not determine project root")?;
+        let branch = git_stdout(&worktree_path, &["symbolic-ref", "--short", "-q", "HEAD"])
+            .map(|out| out.trim().to_string())
+            .unwrap_or_else(|_| "HEAD".to_string());
+        Ok(Self::new(
+            hook_type,
+            command,
+            project_root,
+            git_dir,
+            "origin",
+            &worktree_path,
+            &worktree_path,
+            branch,
+        ))
+    }
+}
+
+/// Runs one hook from the `daft.yml` (or legacy `.daft/hooks/` scripts) of
+/// the worktree a [`HookContext`] names, the way daft's lifecycle commands
+/// do: trust gate, `skip:`/`only:` conditions, job ordering, fail modes, and
+/// the same progress rendering.
+pub struct HookRunner {
+    config: HooksConfig,
+    trusted: bool,
+    filter: JobFilter,
+}
+
+impl HookRunner {
+    /// A runner with daft's default hook settings.
+    pub fn new() -> Self {
+        Self::with_config(HooksConfig::default())
+    }
+
+    /// A runner with the user's `daft.hooks.*` git config, read from the
+    /// current directory's repository and the global config.
+    pub fn from_git_config() -> Result<Self> {
+        Ok(Self::with_config(
+            crate::core::settings::load_hooks_config()?
+        ))
+    }
+
+    /// A runner with explicit hook settings.
+    pub fn with_config(config: HooksConfig) -> Self {
+        Self {
+            config,
+            trusted: false,
+            filter: JobFilter::default(),
+        }
+    }
+
+    /// Run the hook even when the repository isn't trusted, as `daft hooks
+    /// run` does. Off by default: an untrusted repository's hooks are
+    /// skipped (or prompted for, per its trust level).
+    pub fn trusted(mut self, trusted: bool) -> Self {
+        self.trusted = trusted;
+        self
+    }
+
+    /// Run only the job with this name.
+    pub fn only_job(mut self, name: impl Into<String>) -> Self {
+        self.filter.only_job_name = Some(name.into());
+        self
+    }
+
+    /// Run only jobs carrying one of these tags.
+    pub fn only_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.filter.only_tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Show skipped jobs and their reasons.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.config.output.verbose = verbose;
+        self
+    }
+
+    /// Run `ctx.hook_type` for the worktree in `ctx`. A hook the worktree
+    /// doesn't define comes back as a skipped result; a failing hook comes
+    /// back as `Err` or an unsuccessful result according to its fail mode.
+    pub fn run(&self, ctx: &HookContext, output: &mut dyn Output) -> Result<HookResult> {
+        let presenter = CliPresenter::auto(&self.config.output);
+        HookExecutor::new(self.config.clone())?
+            .with_bypass_trust(self.trusted)
+            .with_job_filter(self.filter.clone())
+            .execute(ctx, output, presenter)
+    }
+}
+
+impl Default for HookRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,10 +3,15 @@
 //! Evaluates `skip` and `only` conditions at both the hook and job level.
 //! - `skip`: If any rule matches, the hook/job is skipped.
 //! - `only`: If any rule does NOT match, the hook/job is skipped.
+//!
+//! Apart from `run`, every condition is evaluated in-process without side
+//! effects: the declarative primitives (`exists`, `command_available`,
+//! `env_set`, `git_config`) read the filesystem, `PATH`, the environment, or
+//! git config, so configs can state intent without shelling out.
 
 use super::yaml_config::{
-    JobDef, OnlyCondition, OnlyRule, OnlyRuleStructured, SkipCondition, SkipRule,
-    SkipRuleStructured, TargetOs,
+    GitConfigCondition, JobDef, OnlyCondition, OnlyRule, OnlyRuleStructured, SkipCondition,
+    SkipRule, SkipRuleStructured, TargetOs,
 };
 use crate::git::op_state::{OpKind, probe_op_state};
 use std::path::Path;
@@ -201,6 +206,25 @@ fn eval_structured_skip(rule: &SkipRuleStructured, worktree: &Path) -> Option<Sk
         });
     }
 
+    if let Some(primitive) = primitives(
+        rule.env_set.as_deref(),
+        rule.exists.as_deref(),
+        rule.command_available.as_deref(),
+        rule.git_config.as_ref(),
+        worktree,
+    )
+    .into_iter()
+    .find(|p| p.holds)
+    {
+        return Some(SkipInfo {
+            reason: rule
+                .desc
+                .clone()
+                .unwrap_or_else(|| format!("skip: {}", primitive.described)),
+            ran_command: false,
+        });
+    }
+
     if let Some(ref cmd) = rule.run
         && run_check_command(cmd, worktree)
     {
@@ -245,6 +269,25 @@ fn eval_structured_only(rule: &OnlyRuleStructured, worktree: &Path) -> Option<Sk
         });
     }
 
+    if let Some(primitive) = primitives(
+        rule.env_set.as_deref(),
+        rule.exists.as_deref(),
+        rule.command_available.as_deref(),
+        rule.git_config.as_ref(),
+        worktree,
+    )
+    .into_iter()
+    .find(|p| !p.holds)
+    {
+        return Some(SkipInfo {
+            reason: rule
+                .desc
+                .clone()
+                .unwrap_or_else(|| format!("only: {}", primitive.described)),
+            ran_command: false,
+        });
+    }
+
     if let Some(ref cmd) = rule.run
         && !run_check_command(cmd, worktree)
     {
@@ -260,6 +303,78 @@ fn eval_structured_only(rule: &OnlyRuleStructured, worktree: &Path) -> Option<Sk
     None
 }
 
+/// One evaluated declarative primitive.
+struct Primitive {
+    holds: bool,
+    /// What was found, e.g. `'Cargo.toml' exists` or `'pnpm' is not on PATH`.
+    described: String,
+}
+
+/// Evaluate the declarative primitives a structured rule sets, in a fixed
+/// order. None of them runs a command.
+fn primitives(
+    env_set: Option<&str>,
+    exists: Option<&str>,
+    command_available: Option<&str>,
+    git_config: Option<&GitConfigCondition>,
+    worktree: &Path,
+) -> Vec<Primitive> {
+    let primitive = |holds: bool, yes: String, no: String| Primitive {
+        holds,
+        described: if holds { yes } else { no },
+    };
+    let mut out = Vec::new();
+    if let Some(var) = env_set {
+        out.push(primitive(
+            std::env::var_os(var).is_some(),
+            format!("env ${var} is set"),
+            format!("env ${var} is not set"),
+        ));
+    }
+    if let Some(path) = exists {
+        out.push(primitive(
+            worktree.join(path).exists(),
+            format!("'{path}' exists"),
+            format!("'{path}' does not exist"),
+        ));
+    }
+    if let Some(program) = command_available {
+        out.push(primitive(
+            which::which(program).is_ok(),
+            format!("'{program}' is on PATH"),
+            format!("'{program}' is not on PATH"),
+        ));
+    }
+    if let Some(condition) = git_config {
+        let value = git_config_value(&condition.key, worktree);
+        let key = &condition.key;
+        out.push(match &condition.equals {
+            Some(expected) => primitive(
+                value.as_deref() == Some(expected.as_str()),
+                format!("git config {key} is '{expected}'"),
+                format!("git config {key} is not '{expected}'"),
+            ),
+            None => primitive(
+                value.is_some(),
+                format!("git config {key} is set"),
+                format!("git config {key} is not set"),
+            ),
+        });
+    }
+    out
+}
+
+/// The value of a git config key as seen from `worktree`, if set.
+fn git_config_value(key: &str, worktree: &Path) -> Option<String> {
+    crate::utils::git_command_at(worktree)
+        .args(["config", "--get", key])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim_end_matches('\n').to_string())
+}
+
 /// Check if an environment variable is set and truthy.
 fn is_env_truthy(var: &str) -> bool {
     std::env::var(var)
@@ -408,6 +523,7 @@ mod tests {
             env: None,
             run: Some("true".to_string()),
             desc: None,
+            ..Default::default()
         })]);
         assert!(should_skip(&cond, Path::new(".")).is_some());
     }
@@ -419,6 +535,7 @@ mod tests {
             env: None,
             run: Some("false".to_string()),
             desc: None,
+            ..Default::default()
         })]);
         assert!(should_skip(&cond, Path::new(".")).is_none());
     }
@@ -471,6 +588,7 @@ mod tests {
             env: None,
             run: Some("true".to_string()),
             desc: Some("Brew is already installed".to_string()),
+            ..Default::default()
         })]);
         let info = should_skip(&cond, Path::new(".")).unwrap();
         assert_eq!(info.reason, "Brew is already installed");
@@ -484,6 +602,7 @@ mod tests {
             env: None,
             run: Some("true".to_string()),
             desc: None,
+            ..Default::default()
         })]);
         let info = should_skip(&cond, Path::new(".")).unwrap();
         assert!(info.reason.starts_with("skip: command succeeded:"));
@@ -497,6 +616,7 @@ mod tests {
             env: None,
             run: Some("false".to_string()),
             desc: Some("Only when package.json exists".to_string()),
+            ..Default::default()
         })]);
         let info = should_only_skip(&cond, Path::new(".")).unwrap();
         assert_eq!(info.reason, "Only when package.json exists");
@@ -519,6 +639,7 @@ mod tests {
                 env: None,
                 run: Some("true".to_string()),
                 desc: Some("already installed".to_string()),
+                ..Default::default()
             })],
         );
         let cond = SkipCondition::Platform(map);
@@ -542,6 +663,7 @@ mod tests {
                 env: None,
                 run: Some("true".to_string()),
                 desc: Some("already installed".to_string()),
+                ..Default::default()
             })],
         );
         let cond = SkipCondition::Platform(map);
@@ -605,4 +727,63 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn declarative_primitives_need_no_command() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), "").unwrap();
+
+        let skip = SkipCondition::Rules(vec![SkipRule::Structured(SkipRuleStructured {
+            exists: Some("Cargo.toml".to_string()),
+            ..Default::default()
+        })]);
+        let info = should_skip(&skip, tmp.path()).unwrap();
+        assert_eq!(info.reason, "skip: 'Cargo.toml' exists");
+        assert!(!info.ran_command);
+
+        let only = OnlyCondition::Rules(vec![OnlyRule::Structured(OnlyRuleStructured {
+            command_available: Some("sh".to_string()),
+            exists: Some("package.json".to_string()),
+            ..Default::default()
+        })]);
+        let info = should_only_skip(&only, tmp.path()).unwrap();
+        assert_eq!(info.reason, "only: 'package.json' does not exist");
+
+        let only = OnlyCondition::Rules(vec![OnlyRule::Structured(OnlyRuleStructured {
+            command_available: Some("daft-no-such-binary".to_string()),
+            ..Default::default()
+        })]);
+        let info = should_only_skip(&only, tmp.path()).unwrap();
+        assert_eq!(info.reason, "only: 'daft-no-such-binary' is not on PATH");
+    }
+
+    #[test]
+    fn git_config_condition_compares_the_value() {
+        let tmp = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            assert!(
+                crate::utils::git_command_at(tmp.path())
+                    .args(args)
+                    .status()
+                    .unwrap()
+                    .success()
+            );
+        };
+        git(&["init", "-q"]);
+        git(&["config", "daft.test.mode", "fast"]);
+
+        let rule = |key: &str, equals: Option<&str>| {
+            SkipCondition::Rules(vec![SkipRule::Structured(SkipRuleStructured {
+                git_config: Some(GitConfigCondition {
+                    key: key.to_string(),
+                    equals: equals.map(str::to_string),
+                }),
+                ..Default::default()
+            })])
+        };
+        assert!(should_skip(&rule("daft.test.mode", Some("fast")), tmp.path()).is_some());
+        assert!(should_skip(&rule("daft.test.mode", Some("slow")), tmp.path()).is_none());
+        assert!(should_skip(&rule("daft.test.mode", None), tmp.path()).is_some());
+        assert!(should_skip(&rule("daft.test.other", None), tmp.path()).is_none());
+    }
 }
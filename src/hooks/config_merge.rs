@@ -28,8 +28,12 @@ pub fn merge_configs(base: YamlConfig, overlay: YamlConfig) -> YamlConfig {
         source_dir_local,
         layout,
         shared,
+        template,
         log,
         relations,
+        links,
+        notify,
+        tests,
         hooks,
         tasks,
     } = overlay;
@@ -67,9 +71,21 @@ pub fn merge_configs(base: YamlConfig, overlay: YamlConfig) -> YamlConfig {
     if shared.is_some() {
         merged.shared = shared;
     }
+    if template.is_some() {
+        merged.template = template;
+    }
     if relations.is_some() {
         merged.relations = relations;
     }
+    if links.is_some() {
+        merged.links = links;
+    }
+    if notify.is_some() {
+        merged.notify = notify;
+    }
+    if tests.is_some() {
+        merged.tests = tests;
+    }
 
     // Merge log config (field-level merge)
     merged.log = match (merged.log, log) {
@@ -138,6 +154,7 @@ pub fn merge_hook_defs(base: HookDef, overlay: HookDef) -> HookDef {
         only,
         jobs,
         commands,
+        schedule,
     } = overlay;
 
     let mut merged = base;
@@ -167,6 +184,9 @@ pub fn merge_hook_defs(base: HookDef, overlay: HookDef) -> HookDef {
     if only.is_some() {
         merged.only = only;
     }
+    if schedule.is_some() {
+        merged.schedule = schedule;
+    }
 
     // Jobs: merge named jobs by name, append unnamed
     if let Some(overlay_jobs) = jobs {
@@ -266,8 +286,12 @@ pub fn merge3(base: &YamlConfig, ours: &YamlConfig, theirs: &YamlConfig) -> Merg
         source_dir_local: b_source_dir_local,
         layout: b_layout,
         shared: b_shared,
+        template: b_template,
         log: b_log,
         relations: b_relations,
+        links: b_links,
+        notify: b_notify,
+        tests: b_tests,
         hooks: b_hooks,
         tasks: b_tasks,
     } = base;
@@ -282,8 +306,12 @@ pub fn merge3(base: &YamlConfig, ours: &YamlConfig, theirs: &YamlConfig) -> Merg
         source_dir_local: o_source_dir_local,
         layout: o_layout,
         shared: o_shared,
+        template: o_template,
         log: o_log,
         relations: o_relations,
+        links: o_links,
+        notify: o_notify,
+        tests: o_tests,
         hooks: o_hooks,
         tasks: o_tasks,
     } = ours;
@@ -298,8 +326,12 @@ pub fn merge3(base: &YamlConfig, ours: &YamlConfig, theirs: &YamlConfig) -> Merg
         source_dir_local: t_source_dir_local,
         layout: t_layout,
         shared: t_shared,
+        template: t_template,
         log: t_log,
         relations: t_relations,
+        links: t_links,
+        notify: t_notify,
+        tests: t_tests,
         hooks: t_hooks,
         tasks: t_tasks,
     } = theirs;
@@ -333,6 +365,7 @@ pub fn merge3(base: &YamlConfig, ours: &YamlConfig, theirs: &YamlConfig) -> Merg
         ),
         layout: pick3("layout", b_layout, o_layout, t_layout, &mut tally),
         shared: pick3("shared", b_shared, o_shared, t_shared, &mut tally),
+        template: pick3("template", b_template, o_template, t_template, &mut tally),
         log: merge3_log(b_log, o_log, t_log, &mut tally),
         relations: pick3(
             "relations",
@@ -341,6 +374,9 @@ pub fn merge3(base: &YamlConfig, ours: &YamlConfig, theirs: &YamlConfig) -> Merg
             t_relations,
             &mut tally,
         ),
+        links: pick3("links", b_links, o_links, t_links, &mut tally),
+        notify: pick3("notify", b_notify, o_notify, t_notify, &mut tally),
+        tests: pick3("tests", b_tests, o_tests, t_tests, &mut tally),
         hooks: merge3_hook_maps("hooks", b_hooks, o_hooks, t_hooks, &mut tally),
         tasks: merge3_hook_maps("tasks", b_tasks, o_tasks, t_tasks, &mut tally),
     };
@@ -527,6 +563,7 @@ fn merge3_hook_defs(
         only: b_only,
         jobs: b_jobs,
         commands: b_commands,
+        schedule: b_schedule,
     } = base;
 
     HookDef {
@@ -596,6 +633,13 @@ fn merge3_hook_defs(
             &theirs.commands,
             tally,
         ),
+        schedule: pick3(
+            &format!("{prefix}.schedule"),
+            b_schedule,
+            &ours.schedule,
+            &theirs.schedule,
+            tally,
+        ),
     }
 }
 
@@ -849,6 +893,7 @@ mod tests {
             source_dir_local: Some(".daft-local".to_string()),
             layout: Some("contained".to_string()),
             shared: Some(vec![".env".to_string()]),
+            template: Some(vec!["node_modules".to_string()]),
             log: Some(LogConfig {
                 retention: Some("7d".to_string()),
                 ..Default::default()
@@ -858,6 +903,22 @@ mod tests {
                 name: Some("client".to_string()),
                 kind: Some("consumer".to_string()),
             }]),
+            links: Some(vec![crate::catalog::links::LinkEntry {
+                url: "git@example.com:org/ui-kit.git".to_string(),
+                name: None,
+                path: Some("vendor/ui-kit".to_string()),
+                env: Some("UI_KIT_PATH".to_string()),
+                create: None,
+            }]),
+            notify: Some(vec![crate::hooks::yaml_config::NotifySink {
+                webhook: Some("https://dash.example.com/hooks".to_string()),
+                ..Default::default()
+            }]),
+            tests: Some(vec![crate::hooks::yaml_config::HookTest {
+                name: "installs".to_string(),
+                hook: "worktree-post-create".to_string(),
+                ..Default::default()
+            }]),
             hooks,
             tasks,
         };
@@ -1294,6 +1355,7 @@ mod tests {
             source_dir_local: Some(".daft-local".to_string()),
             layout: Some("contained".to_string()),
             shared: Some(vec![".env".to_string()]),
+            template: Some(vec!["node_modules".to_string()]),
             log: Some(LogConfig {
                 retention: Some("7d".to_string()),
                 ..Default::default()
@@ -1303,6 +1365,22 @@ mod tests {
                 name: Some("client".to_string()),
                 kind: Some("consumer".to_string()),
             }]),
+            links: Some(vec![crate::catalog::links::LinkEntry {
+                url: "git@example.com:org/ui-kit.git".to_string(),
+                name: None,
+                path: Some("vendor/ui-kit".to_string()),
+                env: Some("UI_KIT_PATH".to_string()),
+                create: None,
+            }]),
+            notify: Some(vec![crate::hooks::yaml_config::NotifySink {
+                webhook: Some("https://dash.example.com/hooks".to_string()),
+                ..Default::default()
+            }]),
+            tests: Some(vec![crate::hooks::yaml_config::HookTest {
+                name: "installs".to_string(),
+                hook: "worktree-post-create".to_string(),
+                ..Default::default()
+            }]),
             hooks,
             tasks,
         };
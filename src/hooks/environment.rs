@@ -13,6 +13,7 @@ use std::path::{Path, PathBuf};
 /// This struct captures all the relevant context about a worktree operation
 /// that hooks might need to perform their tasks.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct HookContext {
     /// The type of hook being executed.
     pub hook_type: HookType,
@@ -53,6 +54,16 @@ pub struct HookContext {
     /// Reason for removal (for remove hooks).
     pub removal_reason: Option<RemovalReason>,
 
+    /// Where the user's shell ends up once the removal completes (for
+    /// remove hooks): the cd target when the removed worktree held the
+    /// current directory, otherwise the current directory unchanged.
+    pub target_cwd: Option<PathBuf>,
+
+    /// Whether the local branch outlives the removal (for remove hooks).
+    /// Pre-remove hooks see what the command intends; post-remove hooks see
+    /// what actually happened.
+    pub branch_exists: Option<bool>,
+
     /// Whether this hook is executing as part of a move operation.
     pub is_move: bool,
     /// The worktree path before the move (set in all four move phases).
@@ -85,6 +96,7 @@ pub struct HookContext {
 
 /// Reason why a worktree is being removed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum RemovalReason {
     /// Remote tracking branch was deleted.
     RemoteDeleted,
@@ -132,6 +144,8 @@ impl HookContext {
             repository_url: None,
             default_branch: None,
             removal_reason: None,
+            target_cwd: None,
+            branch_exists: None,
             is_move: false,
             old_worktree_path: None,
             old_branch_name: None,
@@ -220,6 +234,18 @@ impl HookContext {
         self.removal_reason = Some(reason);
         self
     }
+
+    /// Set where the shell lands after the removal (for remove hooks).
+    pub fn with_target_cwd(mut self, path: impl Into<PathBuf>) -> Self {
+        self.target_cwd = Some(path.into());
+        self
+    }
+
+    /// Set whether the local branch survives the removal (for remove hooks).
+    pub fn with_branch_exists(mut self, exists: bool) -> Self {
+        self.branch_exists = Some(exists);
+        self
+    }
 }
 
 /// Builder for hook environment variables.
@@ -276,6 +302,12 @@ impl HookEnvironment {
         if let Some(reason) = ctx.removal_reason {
             env.set("DAFT_REMOVAL_REASON", reason.as_str());
         }
+        if let Some(ref target) = ctx.target_cwd {
+            env.set("DAFT_TARGET_CWD", target.display());
+        }
+        if let Some(exists) = ctx.branch_exists {
+            env.set("DAFT_BRANCH_EXISTS", if exists { "true" } else { "false" });
+        }
 
         // Move-specific variables
         if ctx.is_move {
@@ -327,10 +359,14 @@ impl HookEnvironment {
     /// For most hooks, this is the target worktree path.
     /// For pre-create hooks, the target worktree doesn't exist yet,
     /// so we use the source worktree — unless this is a move operation,
-    /// in which case the target already exists.
+    /// in which case the target already exists. Post-remove hooks likewise
+    /// run where the shell lands, since the target worktree is gone.
     pub fn working_directory<'a>(&self, ctx: &'a HookContext) -> &'a Path {
         match ctx.hook_type {
             HookType::PreCreate if !ctx.is_move => &ctx.source_worktree,
+            HookType::PostRemove if !ctx.is_move => {
+                ctx.target_cwd.as_deref().unwrap_or(&ctx.source_worktree)
+            }
             _ => &ctx.worktree_path,
         }
     }
@@ -458,11 +494,22 @@ mod tests {
             "/project/feature/old",
             "feature/old",
         )
-        .with_removal_reason(RemovalReason::RemoteDeleted);
+        .with_removal_reason(RemovalReason::RemoteDeleted)
+        .with_target_cwd("/project/main")
+        .with_branch_exists(false);
 
         let env = HookEnvironment::from_context(&ctx);
 
         assert_eq!(env.get("DAFT_REMOVAL_REASON"), Some("remote-deleted"));
+        assert_eq!(env.get("DAFT_TARGET_CWD"), Some("/project/main"));
+        assert_eq!(env.get("DAFT_BRANCH_EXISTS"), Some("false"));
+    }
+
+    #[test]
+    fn test_hook_environment_removal_vars_absent_outside_removal() {
+        let env = HookEnvironment::from_context(&make_test_context());
+        assert_eq!(env.get("DAFT_TARGET_CWD"), None);
+        assert_eq!(env.get("DAFT_BRANCH_EXISTS"), None);
     }
 
     #[test]
@@ -495,6 +542,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_working_directory_post_remove_uses_target_cwd() {
+        // The removed worktree no longer exists; run where the shell lands.
+        let ctx = HookContext::new(
+            HookType::PostRemove,
+            "remove",
+            "/project",
+            "/project/.git",
+            "origin",
+            "/project/main",
+            "/project/feature/old",
+            "feature/old",
+        );
+        let env = HookEnvironment::from_context(&ctx);
+        assert_eq!(env.working_directory(&ctx), Path::new("/project/main"));
+
+        let ctx = ctx.with_target_cwd("/project");
+        assert_eq!(env.working_directory(&ctx), Path::new("/project"));
+    }
+
     #[test]
     fn test_removal_reason_as_str() {
         assert_eq!(RemovalReason::RemoteDeleted.as_str(), "remote-deleted");
@@ -555,6 +622,8 @@ mod tests {
             repository_url: None,
             default_branch: None,
             removal_reason: None,
+            target_cwd: None,
+            branch_exists: None,
             is_move: true,
             old_worktree_path: Some(PathBuf::from("/project/old-wt")),
             old_branch_name: Some("feat/old-name".to_string()),
@@ -591,6 +660,8 @@ mod tests {
             repository_url: None,
             default_branch: None,
             removal_reason: None,
+            target_cwd: None,
+            branch_exists: None,
             is_move: false,
             old_worktree_path: None,
             old_branch_name: None,
@@ -3,22 +3,27 @@
 //! This module provides the `HookExecutor` which handles discovering,
 //! validating, and executing hooks with proper security checks.
 
+use super::notify;
+use super::trust_audit::{self, Decision};
 use super::trust_skip::{self, SkipSource};
+use super::wasm;
 use super::yaml_config_loader;
 use super::yaml_executor::{self, JobFilter};
 use super::{
     DEPRECATED_HOOK_REMOVAL_VERSION, FailMode, HookConfig, HookContext, HookEnvironment, HookType,
     HooksConfig, TrustDatabase, TrustLevel, find_hooks,
 };
+use crate::deprecations::DeprecationKind;
 use crate::executor::presenter::JobPresenter;
 use crate::output::Output;
-use crate::store::models::invocation::SKIP_REASON_PROMPT_UNAVAILABLE;
+use crate::store::models::invocation::{SKIP_REASON_PROMPT_UNAVAILABLE, SKIP_REASON_UNTRUSTED};
 use anyhow::Result;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Result of a hook execution.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct HookResult {
     /// Whether the hook succeeded (exit code 0).
     pub success: bool,
@@ -143,6 +148,8 @@ pub(crate) fn get_hook_source_worktree(ctx: &HookContext) -> PathBuf {
         // merge is (or was) taking place, and also where `daft.yml` is
         // most naturally located (the branch being merged into).
         HookType::PreMerge | HookType::PostMerge => ctx.worktree_path.clone(),
+        // Post-fetch reads from the worktree the command ran in.
+        HookType::PostFetch => ctx.worktree_path.clone(),
     }
 }
 
@@ -151,14 +158,16 @@ pub(crate) fn get_hook_source_worktree(ctx: &HookContext) -> PathBuf {
 ///
 /// Worktree-scoped phases get the branch they're acting on so multi-source
 /// flows make it obvious which worktree the hooks are touching. Project-
-/// scoped phases (`pre-merge` / `post-merge` / `post-clone`) return `None`
+/// scoped phases (`pre-merge` / `post-merge` / `post-clone` / `post-fetch`) return `None`
 /// because the title isn't tied to a single worktree.
 pub(crate) fn header_target_for_ctx(ctx: &HookContext) -> Option<&str> {
     match ctx.hook_type {
         HookType::PreCreate | HookType::PostCreate | HookType::PreRemove | HookType::PostRemove => {
             Some(ctx.branch_name.as_str())
         }
-        HookType::PreMerge | HookType::PostMerge | HookType::PostClone => None,
+        HookType::PreMerge | HookType::PostMerge | HookType::PostClone | HookType::PostFetch => {
+            None
+        }
     }
 }
 
@@ -169,6 +178,9 @@ pub struct HookExecutor {
     prompt_callback: Option<PromptCallback>,
     bypass_trust: bool,
     job_filter: JobFilter,
+    /// Config dir whose trust audit log records trust-gate decisions; `None`
+    /// for an injected trust database, which has no registry to audit.
+    audit_dir: Option<PathBuf>,
 }
 
 impl HookExecutor {
@@ -181,6 +193,7 @@ impl HookExecutor {
             prompt_callback: None,
             bypass_trust: false,
             job_filter: JobFilter::default(),
+            audit_dir: crate::daft_config_dir().ok(),
         })
     }
 
@@ -192,6 +205,7 @@ impl HookExecutor {
             prompt_callback: None,
             bypass_trust: false,
             job_filter: JobFilter::default(),
+            audit_dir: None,
         }
     }
 
@@ -352,44 +366,73 @@ impl HookExecutor {
 
         let hook_name = ctx.hook_type.yaml_name();
 
-        let hook_def = match yaml_config.hooks.get(hook_name) {
+        let mut hook_def = match yaml_config.hooks.get(hook_name) {
             Some(def) => def,
             None => {
                 return Ok(None);
             }
         };
 
+        // Set when an untrusted repository runs only its sandboxed `wasm`
+        // jobs: no rc file, no notify commands, nothing else from the repo
+        // that would reach a shell.
+        let sandboxed_def;
+        let mut sandboxed = false;
+
         // Check trust level (unless bypassed by explicit invocation)
         if !self.bypass_trust {
             let trust_level = self.get_verified_trust_level(&ctx.git_dir, output);
             match trust_level {
                 TrustLevel::Deny => {
-                    if !self.user_requested_skip(ctx.hook_type) {
+                    let wasm_only = if self.config.untrusted_wasm {
+                        wasm::sandboxed_hook(hook_def)
+                    } else {
+                        None
+                    };
+                    let dropped_jobs = wasm_only.as_ref().map_or(1, |(_, dropped)| *dropped);
+                    if dropped_jobs > 0 && !self.user_requested_skip(ctx.hook_type) {
                         let configured_hooks: Vec<String> = yaml_config
                             .hooks
                             .keys()
                             .filter(|name| HookType::from_yaml_name(name).is_some())
                             .cloned()
                             .collect();
-                        trust_skip::notify_and_record(
+                        self.report_untrusted_skip(
                             ctx,
                             SkipSource::Yaml { configured_hooks },
                             output,
                         );
                     }
-                    output.debug(&format!(
-                        "Skipping {hook_name} YAML hooks: repository not trusted"
-                    ));
-                    return Ok(Some(HookResult::skipped("Repository not trusted")));
+                    match wasm_only {
+                        Some((def, _)) => {
+                            self.audit(ctx, Decision::Sandboxed);
+                            output.debug(&format!(
+                                "Running only sandboxed wasm jobs of {hook_name}: repository not trusted"
+                            ));
+                            sandboxed_def = def;
+                            hook_def = &sandboxed_def;
+                            sandboxed = true;
+                        }
+                        None => {
+                            self.audit(ctx, Decision::Denied);
+                            output.debug(&format!(
+                                "Skipping {hook_name} YAML hooks: repository not trusted"
+                            ));
+                            return Ok(Some(HookResult::skipped("Repository not trusted")));
+                        }
+                    }
                 }
                 TrustLevel::Prompt => {
                     let prompt_msg =
                         format!("Repository has YAML hook config for '{hook_name}'. Execute?");
                     if let Some(ref callback) = self.prompt_callback {
                         if !callback(&prompt_msg) {
+                            self.audit(ctx, Decision::PromptDeclined);
                             return Ok(Some(HookResult::skipped("User declined hook execution")));
                         }
+                        self.audit(ctx, Decision::PromptAccepted);
                     } else {
+                        self.audit(ctx, Decision::PromptUnavailable);
                         output.warning(&format!(
                             "Repository trust is set to 'prompt' but no interactive prompt is available — skipping {hook_name}. Run '{}' to allow hooks.",
                             crate::daft_cmd("hooks trust")
@@ -398,8 +441,10 @@ impl HookExecutor {
                         return Ok(Some(HookResult::skipped("No permission callback")));
                     }
                 }
-                TrustLevel::Allow => {}
+                TrustLevel::Allow => self.audit(ctx, Decision::Allowed),
             }
+        } else {
+            self.audit(ctx, Decision::Explicit);
         }
 
         // The trust gate passed (Allow, prompt accepted, or explicit bypass):
@@ -407,10 +452,30 @@ impl HookExecutor {
         // is now stale — the upcoming fire supersedes it regardless of how
         // that fire ends (failure and `skip:` conditions are post-trust
         // outcomes, captured by job records instead).
-        trust_skip::clear_skips(ctx);
+        if !sandboxed {
+            trust_skip::clear_skips(ctx);
+        }
 
         let source_dir = yaml_config.source_dir.as_deref().unwrap_or(".daft");
-        let rc = yaml_config.rc.as_deref();
+        let rc = yaml_config.rc.as_deref().filter(|_| !sandboxed);
+
+        // `links:` env vars resolve per run: the linked worktree for this
+        // branch may have been provisioned since the config was written.
+        // Context-supplied vars win on a name clash.
+        let linked_ctx;
+        let ctx = match yaml_config.links.as_deref() {
+            Some(links) if !links.is_empty() => {
+                let mut extra = crate::catalog::links::hook_env(links, &ctx.branch_name);
+                if extra.is_empty() {
+                    ctx
+                } else {
+                    extra.extend(ctx.extra_env.clone());
+                    linked_ctx = ctx.clone().with_extra_env(extra);
+                    &linked_ctx
+                }
+            }
+            _ => ctx,
+        };
 
         let env = HookEnvironment::from_context(ctx);
         let working_dir = env.working_directory(ctx);
@@ -428,9 +493,18 @@ impl HookExecutor {
             cancel: None,
             trigger_label: None,
         };
+        let started = std::time::Instant::now();
         let result =
             yaml_executor::execute_yaml_hook_with_rc(hook_name, hook_def, ctx, output, &cfg)?;
 
+        if let Some(ref sinks) = yaml_config.notify
+            && !result.skipped
+            && !sandboxed
+        {
+            let summary = notify::HookRunSummary::new(ctx, hook_name, &result, started.elapsed());
+            notify::dispatch(sinks, &summary, ctx, working_dir);
+        }
+
         // Return the raw result — failure translation (Abort → Err, Warn →
         // logged-and-continue) is the caller's responsibility via
         // `handle_hook_failure` in `execute`. Doing it here would
@@ -451,10 +525,10 @@ impl HookExecutor {
         // Discover hooks (handles deprecated filename resolution)
         let discovery = find_hooks(ctx.hook_type, hook_source_worktree, &self.config);
 
-        // Emit deprecation warnings
+        // Record deprecation warnings; they print once, after the command.
         for warning in &discovery.deprecation_warnings {
-            if warning.new_name_also_exists {
-                output.warning(&format!(
+            let message = if warning.new_name_also_exists {
+                format!(
                     "Both '{}' and '{}' exist in '{}'. Using '{}'; remove '{}' or run '{}'.",
                     warning.new_name,
                     warning.old_name,
@@ -466,9 +540,9 @@ impl HookExecutor {
                     warning.new_name,
                     warning.old_name,
                     crate::daft_cmd("hooks migrate"),
-                ));
+                )
             } else {
-                output.warning(&format!(
+                format!(
                     "Hook '{}' uses deprecated name '{}'. Rename to '{}' or run '{}'. \
                      Deprecated names will stop working in daft v{}.",
                     warning.path.display(),
@@ -476,8 +550,13 @@ impl HookExecutor {
                     warning.new_name,
                     crate::daft_cmd("hooks migrate"),
                     DEPRECATED_HOOK_REMOVAL_VERSION
-                ));
-            }
+                )
+            };
+            crate::deprecations::record(
+                DeprecationKind::HookRename,
+                warning.path.display().to_string(),
+                message,
+            );
         }
 
         if discovery.hooks.is_empty() {
@@ -512,12 +591,13 @@ impl HookExecutor {
                                 .filter_map(|n| n.to_str())
                                 .map(String::from)
                                 .collect();
-                            trust_skip::notify_and_record(
+                            self.report_untrusted_skip(
                                 ctx,
                                 SkipSource::Scripts { hook_files },
                                 output,
                             );
                         }
+                        self.audit(ctx, Decision::Denied);
                         output.debug(&format!(
                             "Skipping {} hooks: repository not trusted",
                             ctx.hook_type
@@ -529,11 +609,11 @@ impl HookExecutor {
                             return Ok(HookResult::skipped("User declined hook execution"));
                         }
                     }
-                    TrustLevel::Allow => {
-                        // Proceed without prompting
-                    }
+                    TrustLevel::Allow => self.audit(ctx, Decision::Allowed),
                 }
             }
+        } else {
+            self.audit(ctx, Decision::Explicit);
         }
 
         // Trust gate passed (or only user-level hooks are involved): drop any
@@ -607,8 +687,18 @@ impl HookExecutor {
                 hook_list.join(", ")
             );
 
-            callback(&prompt)
+            let accepted = callback(&prompt);
+            self.audit(
+                ctx,
+                if accepted {
+                    Decision::PromptAccepted
+                } else {
+                    Decision::PromptDeclined
+                },
+            );
+            accepted
         } else {
+            self.audit(ctx, Decision::PromptUnavailable);
             // Default: don't execute without explicit permission
             output.warning(&format!(
                 "Repository trust is set to 'prompt' but no interactive prompt is available — skipping {} hooks. Run '{}' to allow hooks.",
@@ -664,6 +754,29 @@ impl HookExecutor {
         self.job_filter.skip.all || self.job_filter.skip.hook_types.contains(&hook_type)
     }
 
+    /// Report a fire the trust gate blocked: the once-per-command notice,
+    /// unless the repository silenced it (`daft.hooks.untrustedNotice`), and
+    /// the skip record `hooks trust` offers to replay.
+    fn report_untrusted_skip(
+        &self,
+        ctx: &HookContext,
+        source: SkipSource,
+        output: &mut dyn Output,
+    ) {
+        if self.config.untrusted_notice {
+            trust_skip::notify_and_record(ctx, source, output);
+        } else {
+            trust_skip::record_skip(ctx, SKIP_REASON_UNTRUSTED);
+        }
+    }
+
+    /// Record a trust-gate decision in the audit log.
+    fn audit(&self, ctx: &HookContext, decision: Decision) {
+        if let Some(dir) = &self.audit_dir {
+            trust_audit::record_decision(dir, &ctx.git_dir, ctx.hook_type.yaml_name(), decision);
+        }
+    }
+
     /// Get the trust level for a repository.
     pub fn get_trust_level(&self, git_dir: &Path) -> TrustLevel {
         self.trust_db.get_trust_level(git_dir)
@@ -1047,6 +1160,30 @@ mod tests {
         assert_eq!(rows[0].skip_reason.as_deref(), Some("untrusted"));
     }
 
+    #[test]
+    fn test_executor_untrusted_notice_off_still_records() {
+        let temp_dir = tempdir().unwrap();
+        let worktree = temp_dir.path().join("main");
+        fs::create_dir_all(&worktree).unwrap();
+
+        create_test_hook(&worktree, "worktree-post-create", "#!/bin/bash\necho test");
+
+        let config = HooksConfig {
+            untrusted_notice: false,
+            ..HooksConfig::default()
+        };
+        let executor = HookExecutor::with_trust_db(config, TrustDatabase::default());
+        let mut output = TestOutput::default();
+
+        let ctx = test_ctx_with_state(temp_dir.path(), &worktree, HookType::PostCreate, "main");
+
+        let presenter = NullPresenter::arc();
+        let result = executor.execute(&ctx, &mut output, presenter).unwrap();
+        assert!(result.skipped);
+        assert!(output.notices().is_empty() && output.warnings().is_empty());
+        assert_eq!(skip_rows(&ctx).len(), 1, "the skip stays replayable");
+    }
+
     #[test]
     fn test_executor_user_requested_skip_suppresses_notice_and_record() {
         let temp_dir = tempdir().unwrap();
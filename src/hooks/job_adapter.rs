@@ -341,7 +341,8 @@ pub fn yaml_jobs_to_specs(
 
         let cmd = super::yaml_executor::resolve_command(job, ctx, Some(&name), source_dir);
 
-        let cmd = match rc {
+        // The rc file is shell setup; a sandboxed module gets none of it.
+        let cmd = match rc.filter(|_| job.wasm.is_none()) {
             Some(rc_path) => format!("source {rc_path} && {cmd}"),
             None => cmd,
         };
@@ -380,6 +381,7 @@ pub fn yaml_jobs_to_specs(
             background_output: job.background_output.clone(),
             log_config: merge_job_log(job.log.clone(), repo_log),
             tags: job.tags.clone().unwrap_or_default(),
+            limits: job.limits.clone(),
         });
     }
 
@@ -41,19 +41,25 @@
 //!
 //! User-global hooks can be placed at `~/.config/daft/hooks/`.
 
+pub mod api;
 pub mod conditions;
 pub mod config_merge;
 mod environment;
 mod executor;
 pub mod job_adapter;
 pub mod move_hooks;
+pub mod notify;
+pub mod post_fetch;
+pub mod schedule;
 pub mod template;
 pub mod tracking;
 mod trust;
+pub mod trust_audit;
 mod trust_dto;
 pub mod trust_skip;
 pub mod visitor_propagation;
 pub mod visitor_seeds;
+pub mod wasm;
 pub mod yaml_config;
 pub mod yaml_config_loader;
 pub mod yaml_config_validate;
@@ -69,6 +75,7 @@ use std::path::Path;
 
 /// Hook types that can be executed during worktree lifecycle events.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum HookType {
     /// Runs after `git worktree-clone` completes.
     /// Hook file is read from the new default branch worktree.
@@ -99,6 +106,12 @@ pub enum HookType {
     /// Failure is logged but does not roll back the merge.
     /// Hook file is read from the target worktree.
     PostMerge,
+
+    /// Runs after a repository-wide fetch (`daft sync`, `daft prune`) that
+    /// moved at least one ref. The updated refs are written to a JSON file
+    /// named by `DAFT_FETCH_REFS_FILE`.
+    /// Hook file is read from the current worktree.
+    PostFetch,
 }
 
 impl HookType {
@@ -112,6 +125,7 @@ impl HookType {
             HookType::PostRemove => "worktree-post-remove",
             HookType::PreMerge => "pre-merge",
             HookType::PostMerge => "post-merge",
+            HookType::PostFetch => "post-fetch",
         }
     }
 
@@ -127,6 +141,7 @@ impl HookType {
             HookType::PostRemove => "worktree-post-remove",
             HookType::PreMerge => "pre-merge",
             HookType::PostMerge => "post-merge",
+            HookType::PostFetch => "post-fetch",
         }
     }
 
@@ -142,6 +157,7 @@ impl HookType {
             "worktree-post-remove" => Some(HookType::PostRemove),
             "pre-merge" => Some(HookType::PreMerge),
             "post-merge" => Some(HookType::PostMerge),
+            "post-fetch" => Some(HookType::PostFetch),
             _ => None,
         }
     }
@@ -149,14 +165,17 @@ impl HookType {
     /// Returns the deprecated filename for this hook type, if it was renamed.
     ///
     /// Returns `None` for hooks that were not renamed (`post-clone`,
-    /// `pre-merge`, `post-merge`).
+    /// `pre-merge`, `post-merge`, `post-fetch`).
     pub fn deprecated_filename(&self) -> Option<&'static str> {
         match self {
             HookType::PreCreate => Some("pre-create"),
             HookType::PostCreate => Some("post-create"),
             HookType::PreRemove => Some("pre-remove"),
             HookType::PostRemove => Some("post-remove"),
-            HookType::PostClone | HookType::PreMerge | HookType::PostMerge => None,
+            HookType::PostClone
+            | HookType::PreMerge
+            | HookType::PostMerge
+            | HookType::PostFetch => None,
         }
     }
 
@@ -172,6 +191,7 @@ impl HookType {
             "worktree-post-remove" | "post-remove" => Some(HookType::PostRemove),
             "pre-merge" => Some(HookType::PreMerge),
             "post-merge" => Some(HookType::PostMerge),
+            "post-fetch" => Some(HookType::PostFetch),
             _ => None,
         }
     }
@@ -186,20 +206,24 @@ impl HookType {
             HookType::PostRemove => "worktreePostRemove",
             HookType::PreMerge => "preMerge",
             HookType::PostMerge => "postMerge",
+            HookType::PostFetch => "postFetch",
         }
     }
 
     /// Returns the deprecated config key for this hook type, if it was renamed.
     ///
     /// Returns `None` for hooks that were not renamed (`postClone`,
-    /// `preMerge`, `postMerge`).
+    /// `preMerge`, `postMerge`, `postFetch`).
     pub fn deprecated_config_key(&self) -> Option<&'static str> {
         match self {
             HookType::PreCreate => Some("preCreate"),
             HookType::PostCreate => Some("postCreate"),
             HookType::PreRemove => Some("preRemove"),
             HookType::PostRemove => Some("postRemove"),
-            HookType::PostClone | HookType::PreMerge | HookType::PostMerge => None,
+            HookType::PostClone
+            | HookType::PreMerge
+            | HookType::PostMerge
+            | HookType::PostFetch => None,
         }
     }
 
@@ -232,6 +256,7 @@ impl HookType {
             HookType::PostRemove,
             HookType::PreMerge,
             HookType::PostMerge,
+            HookType::PostFetch,
         ]
     }
 }
@@ -293,6 +318,7 @@ impl HookConfig {
 
 /// Global hooks configuration.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct HooksConfig {
     /// Master switch for all hooks.
     pub enabled: bool,
@@ -302,6 +328,18 @@ pub struct HooksConfig {
     pub user_directory: std::path::PathBuf,
     /// Timeout for hook execution in seconds.
     pub timeout_seconds: u32,
+    /// Whether hooks found only under their deprecated filenames run
+    /// (`daft.hooks.executeDeprecated`). Defaults to `true` until
+    /// [`DEPRECATED_HOOK_REMOVAL_VERSION`]; setting it to `false` rehearses
+    /// the cutover.
+    pub execute_deprecated: bool,
+    /// Whether an untrusted repository's `wasm` jobs still run, sandboxed,
+    /// while its shell jobs are skipped (`daft.hooks.untrustedWasm`,
+    /// experimental).
+    pub untrusted_wasm: bool,
+    /// Whether skipping an untrusted repository's hooks prints a notice
+    /// (`daft.hooks.untrustedNotice`). The skip is recorded either way.
+    pub untrusted_notice: bool,
     /// Output display configuration.
     pub output: HookOutputConfig,
     /// Per-hook configurations.
@@ -312,6 +350,7 @@ pub struct HooksConfig {
     pub worktree_post_remove: HookConfig,
     pub pre_merge: HookConfig,
     pub post_merge: HookConfig,
+    pub post_fetch: HookConfig,
 }
 
 impl Default for HooksConfig {
@@ -321,6 +360,9 @@ impl Default for HooksConfig {
             default_trust: TrustLevel::Deny,
             user_directory: default_user_hooks_dir(),
             timeout_seconds: 300,
+            execute_deprecated: true,
+            untrusted_wasm: false,
+            untrusted_notice: true,
             output: HookOutputConfig::default(),
             post_clone: HookConfig::new(HookType::PostClone),
             worktree_pre_create: HookConfig::new(HookType::PreCreate),
@@ -329,6 +371,7 @@ impl Default for HooksConfig {
             worktree_post_remove: HookConfig::new(HookType::PostRemove),
             pre_merge: HookConfig::new(HookType::PreMerge),
             post_merge: HookConfig::new(HookType::PostMerge),
+            post_fetch: HookConfig::new(HookType::PostFetch),
         }
     }
 }
@@ -344,6 +387,7 @@ impl HooksConfig {
             HookType::PostRemove => &self.worktree_post_remove,
             HookType::PreMerge => &self.pre_merge,
             HookType::PostMerge => &self.post_merge,
+            HookType::PostFetch => &self.post_fetch,
         }
     }
 
@@ -357,6 +401,7 @@ impl HooksConfig {
             HookType::PostRemove => &mut self.worktree_post_remove,
             HookType::PreMerge => &mut self.pre_merge,
             HookType::PostMerge => &mut self.post_merge,
+            HookType::PostFetch => &mut self.post_fetch,
         }
     }
 }
@@ -374,10 +419,6 @@ pub const PROJECT_HOOKS_DIR: &str = ".daft/hooks";
 /// Version in which deprecated hook names will stop being executed.
 pub const DEPRECATED_HOOK_REMOVAL_VERSION: &str = "2.0.0";
 
-/// Whether to execute hooks found under their deprecated filenames.
-/// Set to `false` in v2.0.0+ to stop executing deprecated hooks.
-const EXECUTE_DEPRECATED_HOOKS: bool = true;
-
 /// A warning about a deprecated hook filename that was discovered.
 #[derive(Debug, Clone)]
 pub struct DeprecationWarning {
@@ -408,7 +449,7 @@ pub struct HookDiscovery {
 /// For hooks that have been renamed, the discovery logic is:
 /// 1. If only the new-name file exists: use it, no warning.
 /// 2. If only the old-name file exists: warn deprecated. Include in hooks
-///    list only if `EXECUTE_DEPRECATED_HOOKS` is `true`.
+///    list only if `config.execute_deprecated` is set.
 /// 3. If both exist: use the new-name file, warn that old-name is ignored.
 pub fn find_hooks(
     hook_type: HookType,
@@ -422,6 +463,7 @@ pub fn find_hooks(
     discover_hook_in_dir(
         hook_type,
         &worktree_path.join(PROJECT_HOOKS_DIR),
+        config.execute_deprecated,
         &mut hooks,
         &mut deprecation_warnings,
     );
@@ -430,6 +472,7 @@ pub fn find_hooks(
     discover_hook_in_dir(
         hook_type,
         &config.user_directory,
+        config.execute_deprecated,
         &mut hooks,
         &mut deprecation_warnings,
     );
@@ -444,6 +487,7 @@ pub fn find_hooks(
 fn discover_hook_in_dir(
     hook_type: HookType,
     dir: &Path,
+    execute_deprecated: bool,
     hooks: &mut Vec<std::path::PathBuf>,
     warnings: &mut Vec<DeprecationWarning>,
 ) {
@@ -478,7 +522,7 @@ fn discover_hook_in_dir(
                     path: old_path.clone(),
                     new_name_also_exists: false,
                 });
-                if EXECUTE_DEPRECATED_HOOKS {
+                if execute_deprecated {
                     hooks.push(old_path);
                 }
             }
@@ -735,7 +779,7 @@ mod tests {
     #[test]
     fn test_hook_type_all() {
         let all = HookType::all();
-        assert_eq!(all.len(), 7);
+        assert_eq!(all.len(), 8);
         assert!(all.contains(&HookType::PostClone));
         assert!(all.contains(&HookType::PreCreate));
         assert!(all.contains(&HookType::PostCreate));
@@ -743,6 +787,7 @@ mod tests {
         assert!(all.contains(&HookType::PostRemove));
         assert!(all.contains(&HookType::PreMerge));
         assert!(all.contains(&HookType::PostMerge));
+        assert!(all.contains(&HookType::PostFetch));
     }
 
     #[test]
@@ -915,7 +960,7 @@ mod tests {
         let config = HooksConfig::default();
         let discovery = find_hooks(HookType::PostCreate, &worktree, &config);
 
-        // Should discover the hook (EXECUTE_DEPRECATED_HOOKS is true)
+        // Should discover the hook (execute_deprecated defaults to true)
         assert_eq!(discovery.hooks.len(), 1);
         // Should have a deprecation warning
         assert_eq!(discovery.deprecation_warnings.len(), 1);
@@ -926,6 +971,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_find_hooks_deprecated_name_not_executed_when_disabled() {
+        let temp_dir = tempdir().unwrap();
+        let worktree = temp_dir.path().join("main");
+        fs::create_dir_all(&worktree).unwrap();
+
+        create_executable_hook(&worktree, "post-create");
+
+        let config = HooksConfig {
+            execute_deprecated: false,
+            ..HooksConfig::default()
+        };
+        let discovery = find_hooks(HookType::PostCreate, &worktree, &config);
+
+        // Still warned about, but left out of the execution list
+        assert!(discovery.hooks.is_empty());
+        assert_eq!(discovery.deprecation_warnings.len(), 1);
+    }
+
     #[test]
     fn test_find_hooks_both_new_and_deprecated_exist() {
         let temp_dir = tempdir().unwrap();
@@ -0,0 +1,206 @@
+//! Changed-refs payload for the `post-fetch` hook.
+//!
+//! A repository-wide fetch is bracketed by two [`RefSnapshot`]s of the
+//! remote's tracking refs and the tags; [`diff`] turns the pair into the list
+//! of refs the fetch created, moved or deleted. That list is written as JSON
+//! to `<git-common-dir>/.daft/post-fetch-refs.json` and the hook finds it via
+//! `DAFT_FETCH_REFS_FILE`:
+//!
+//! ```json
+//! {
+//!   "remote": "origin",
+//!   "refs": [
+//!     { "ref": "refs/remotes/origin/main", "old": "1a2b…", "new": "3c4d…" },
+//!     { "ref": "refs/remotes/origin/feat", "old": null, "new": "5e6f…" }
+//!   ]
+//! }
+//! ```
+//!
+//! `old` is `null` for a ref the fetch created, `new` for one it pruned. The
+//! file describes the most recent fetch and is overwritten by the next one.
+//! A fetch that moved nothing fires no hook.
+
+use super::{HookContext, HookType};
+use crate::utils::git_command_at;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+/// Environment variable naming the payload file.
+pub const REFS_FILE_ENV: &str = "DAFT_FETCH_REFS_FILE";
+
+/// Environment variable holding the number of updated refs.
+pub const REFS_COUNT_ENV: &str = "DAFT_FETCH_REFS_COUNT";
+
+/// Payload filename inside `<git-common-dir>/.daft/`.
+const PAYLOAD_FILE: &str = "post-fetch-refs.json";
+
+/// The object each of a remote's tracking refs and the tags pointed at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefSnapshot(BTreeMap<String, String>);
+
+impl RefSnapshot {
+    /// Read the refs a fetch from `remote` can change, from the repository
+    /// containing `dir`. Best-effort: an unreadable ref list yields an empty
+    /// snapshot, which only makes the diff report more refs as created.
+    pub fn capture(dir: &Path, remote: &str) -> Self {
+        let output = git_command_at(dir)
+            .args(["for-each-ref", "--format=%(objectname) %(refname)"])
+            .arg(format!("refs/remotes/{remote}/"))
+            .arg("refs/tags/")
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output();
+        match output {
+            Ok(out) if out.status.success() => Self::parse(&String::from_utf8_lossy(&out.stdout)),
+            _ => Self::default(),
+        }
+    }
+
+    fn parse(for_each_ref: &str) -> Self {
+        Self(
+            for_each_ref
+                .lines()
+                .filter_map(|line| line.split_once(' '))
+                .filter(|(_, name)| !name.ends_with("/HEAD"))
+                .map(|(sha, name)| (name.to_string(), sha.to_string()))
+                .collect(),
+        )
+    }
+}
+
+/// One ref the fetch changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RefUpdate {
+    /// Full ref name, e.g. `refs/remotes/origin/main`.
+    #[serde(rename = "ref")]
+    pub refname: String,
+    /// Object before the fetch; `None` when the fetch created the ref.
+    pub old: Option<String>,
+    /// Object after the fetch; `None` when the fetch pruned the ref.
+    pub new: Option<String>,
+}
+
+/// The refs that differ between two snapshots, sorted by name.
+pub fn diff(before: &RefSnapshot, after: &RefSnapshot) -> Vec<RefUpdate> {
+    let names: BTreeSet<&String> = before.0.keys().chain(after.0.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let old = before.0.get(name);
+            let new = after.0.get(name);
+            (old != new).then(|| RefUpdate {
+                refname: name.clone(),
+                old: old.cloned(),
+                new: new.cloned(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Serialize)]
+struct Payload<'a> {
+    remote: &'a str,
+    refs: &'a [RefUpdate],
+}
+
+/// Write the payload for `updates` and return its path.
+pub fn write_payload(
+    git_common_dir: &Path,
+    remote: &str,
+    updates: &[RefUpdate],
+) -> Result<PathBuf> {
+    let dir = git_common_dir.join(".daft");
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let path = dir.join(PAYLOAD_FILE);
+    let json = serde_json::to_string_pretty(&Payload {
+        remote,
+        refs: updates,
+    })?;
+    std::fs::write(&path, json + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Build the `post-fetch` hook context for `updates`, writing the payload.
+///
+/// The hook runs in `worktree` — the worktree the command was invoked from —
+/// which is also where its config is read.
+pub fn hook_context(
+    command: &str,
+    project_root: &Path,
+    git_common_dir: &Path,
+    remote: &str,
+    worktree: &Path,
+    branch: &str,
+    updates: &[RefUpdate],
+) -> Result<HookContext> {
+    let payload = write_payload(git_common_dir, remote, updates)?;
+    let extra = BTreeMap::from([
+        (REFS_FILE_ENV.to_string(), payload.display().to_string()),
+        (REFS_COUNT_ENV.to_string(), updates.len().to_string()),
+    ]);
+    Ok(HookContext::new(
+        HookType::PostFetch,
+        command,
+        project_root,
+        git_common_dir,
+        remote,
+        worktree,
+        worktree,
+        branch,
+    )
+    .with_extra_env(extra))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_reports_created_moved_and_pruned_refs_only() {
+        let before = RefSnapshot::parse(
+            "aaa refs/remotes/origin/main\n\
+             bbb refs/remotes/origin/gone\n\
+             ccc refs/remotes/origin/same\n\
+             ddd refs/remotes/origin/HEAD\n",
+        );
+        let after = RefSnapshot::parse(
+            "eee refs/remotes/origin/main\n\
+             ccc refs/remotes/origin/same\n\
+             fff refs/tags/v1.0\n",
+        );
+        let updates = diff(&before, &after);
+        let summary: Vec<(&str, Option<&str>, Option<&str>)> = updates
+            .iter()
+            .map(|u| (u.refname.as_str(), u.old.as_deref(), u.new.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("refs/remotes/origin/gone", Some("bbb"), None),
+                ("refs/remotes/origin/main", Some("aaa"), Some("eee")),
+                ("refs/tags/v1.0", None, Some("fff")),
+            ]
+        );
+    }
+
+    #[test]
+    fn payload_serializes_null_for_missing_sides() {
+        let dir = tempfile::tempdir().unwrap();
+        let updates = [RefUpdate {
+            refname: "refs/remotes/origin/feat".into(),
+            old: None,
+            new: Some("abc".into()),
+        }];
+        let path = write_payload(dir.path(), "origin", &updates).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(json["remote"], "origin");
+        assert_eq!(json["refs"][0]["ref"], "refs/remotes/origin/feat");
+        assert!(json["refs"][0]["old"].is_null());
+        assert_eq!(json["refs"][0]["new"], "abc");
+    }
+}
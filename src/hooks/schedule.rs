@@ -0,0 +1,734 @@
+//! Scheduled tasks: the `schedule:` key of a `daft.yml` task.
+//!
+//! A schedule is a five-field cron expression (`minute hour day month
+//! weekday`) or one of the `@hourly`/`@daily`/`@weekly`/`@monthly`
+//! shorthands. daft doesn't run a scheduler of its own: `daft hooks schedule
+//! install` renders each scheduled task as a user-level systemd timer (Linux)
+//! or launchd agent (macOS) that runs `daft __scheduled-run <task>` in the
+//! worktree, and the OS takes it from there. That entry point re-checks trust
+//! every time it fires, so revoking trust stops the timers without
+//! uninstalling them. `daft backup schedule install` uses the same
+//! [`Backend`] for its hourly backup check.
+//!
+//! Fields accept `*`, numbers, lists (`1,15`), ranges (`1-5`), and steps
+//! (`*/15`, `0-30/10`); months and weekdays also accept three-letter names.
+//! Unlike cron, a schedule may not restrict both the day of the month and the
+//! weekday — cron ORs the two, systemd ANDs them, and neither reading is what
+//! a reader of the file would reliably expect.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAYS: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// launchd has no range syntax, so every combination of restricted fields
+/// becomes its own calendar entry. Past this many the schedule is refused
+/// rather than rendered into a plist nobody can read.
+const MAX_LAUNCHD_ENTRIES: usize = 512;
+
+/// A parsed `schedule:` value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    source: String,
+    minute: Field,
+    hour: Field,
+    day: Field,
+    month: Field,
+    /// 0 = Sunday.
+    weekday: Field,
+}
+
+/// One cron field: `None` matches every value, otherwise the sorted values.
+type Field = Option<Vec<u32>>;
+
+impl FromStr for Schedule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let source = s.trim();
+        let expanded = match source {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other if other.starts_with('@') => {
+                bail!("unknown shorthand '{other}' (use @hourly, @daily, @weekly, or @monthly)")
+            }
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            bail!(
+                "expected 5 fields (minute hour day month weekday), got {}",
+                fields.len()
+            );
+        };
+        let schedule = Schedule {
+            source: source.to_string(),
+            minute: parse_field(minute, "minute", 0, 59, &[])?,
+            hour: parse_field(hour, "hour", 0, 23, &[])?,
+            day: parse_field(day, "day", 1, 31, &[])?,
+            month: parse_field(month, "month", 1, 12, &MONTHS)?,
+            weekday: parse_field(weekday, "weekday", 0, 7, &WEEKDAYS)?.map(|days| {
+                // 7 is cron's second spelling of Sunday.
+                let mut days: Vec<u32> = days.into_iter().map(|d| d % 7).collect();
+                days.sort_unstable();
+                days.dedup();
+                days
+            }),
+        };
+        if schedule.day.is_some() && schedule.weekday.is_some() {
+            bail!("restrict the day of the month or the weekday, not both");
+        }
+        Ok(schedule)
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl Schedule {
+    /// The schedule as a systemd `OnCalendar=` expression, e.g.
+    /// `Mon,Fri *-*-* 03:00:00`.
+    pub fn on_calendar(&self) -> String {
+        let list = |field: &Field, width: usize| match field {
+            None => "*".to_string(),
+            Some(values) => values
+                .iter()
+                .map(|v| format!("{v:0width$}"))
+                .collect::<Vec<_>>()
+                .join(","),
+        };
+        let date_time = format!(
+            "*-{}-{} {}:{}:00",
+            list(&self.month, 2),
+            list(&self.day, 2),
+            list(&self.hour, 2),
+            list(&self.minute, 2)
+        );
+        match &self.weekday {
+            None => date_time,
+            Some(days) => {
+                let names: Vec<String> = days
+                    .iter()
+                    .map(|d| {
+                        let name = WEEKDAYS[*d as usize];
+                        format!("{}{}", name[..1].to_uppercase(), &name[1..])
+                    })
+                    .collect();
+                format!("{} {date_time}", names.join(","))
+            }
+        }
+    }
+
+    /// The schedule as launchd `StartCalendarInterval` entries: one dict per
+    /// combination of the restricted fields.
+    pub fn launchd_intervals(&self) -> Result<Vec<BTreeMap<&'static str, u32>>> {
+        let mut entries = vec![BTreeMap::new()];
+        for (key, field) in [
+            ("Minute", &self.minute),
+            ("Hour", &self.hour),
+            ("Day", &self.day),
+            ("Month", &self.month),
+            ("Weekday", &self.weekday),
+        ] {
+            let Some(values) = field else { continue };
+            entries = entries
+                .into_iter()
+                .flat_map(|entry| {
+                    values.iter().map(move |v| {
+                        let mut entry = entry.clone();
+                        entry.insert(key, *v);
+                        entry
+                    })
+                })
+                .collect();
+            if entries.len() > MAX_LAUNCHD_ENTRIES {
+                bail!(
+                    "schedule '{}' expands to more than {MAX_LAUNCHD_ENTRIES} launchd calendar entries",
+                    self.source
+                );
+            }
+        }
+        Ok(entries)
+    }
+}
+
+fn parse_field(raw: &str, what: &str, min: u32, max: u32, names: &[&str]) -> Result<Field> {
+    if raw == "*" {
+        return Ok(None);
+    }
+    let value = |token: &str| -> Result<u32> {
+        if let Some(pos) = names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(token))
+        {
+            // Month names count from 1, weekday names from 0 (Sunday).
+            return Ok(pos as u32 + min);
+        }
+        let n: u32 = token
+            .parse()
+            .map_err(|_| anyhow::anyhow!("invalid {what} '{token}'"))?;
+        if !(min..=max).contains(&n) {
+            bail!("{what} {n} is out of range ({min}-{max})");
+        }
+        Ok(n)
+    };
+
+    let mut values = Vec::new();
+    for part in raw.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| anyhow::anyhow!("invalid {what} step in '{part}'"))?;
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => (value(a)?, value(b)?),
+                None if step > 1 => (value(range)?, max),
+                None => {
+                    let v = value(range)?;
+                    (v, v)
+                }
+            },
+        };
+        if start > end {
+            bail!("invalid {what} range '{range}'");
+        }
+        values.extend((start..=end).step_by(step as usize));
+    }
+    values.sort_unstable();
+    values.dedup();
+    Ok(Some(values))
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Generated units
+// ─────────────────────────────────────────────────────────────────────────
+
+/// A scheduled task rendered for one worktree.
+#[derive(Debug, Clone)]
+pub struct ScheduledTask {
+    pub task: String,
+    pub schedule: Schedule,
+    /// Where the task runs.
+    pub worktree: PathBuf,
+    /// The `daft` binary the unit invokes.
+    pub daft: PathBuf,
+    /// Arguments to `daft`, e.g. `["run", "<task>"]`.
+    pub args: Vec<String>,
+    /// Unit-name prefix, e.g. [`unit_prefix`] of the worktree.
+    pub prefix: String,
+    /// What the generated files say to edit instead of them.
+    pub origin: &'static str,
+    /// `PATH` for the job, captured at install time so the task finds the
+    /// same toolchains it does from the user's shell.
+    pub path_env: String,
+}
+
+/// [`ScheduledTask::origin`] of `daft.yml` tasks.
+pub const TASK_ORIGIN: &str = "Generated by `daft hooks schedule install`; edit daft.yml instead.";
+
+/// The unit-name prefix shared by every task scheduled from `worktree`, e.g.
+/// `daft-myproject-1a2b3c4d-`. The hash keeps two checkouts of the same
+/// project apart; it is FNV-1a so it stays stable across daft releases.
+pub fn unit_prefix(worktree: &Path) -> String {
+    let name = worktree
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let hash = worktree
+        .to_string_lossy()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+            (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("daft-{}-{:08x}-", unit_safe(&name), hash as u32)
+}
+
+impl ScheduledTask {
+    /// A `daft.yml` task: runs `daft __scheduled-run <task>` in `worktree`.
+    pub fn for_task(
+        worktree: &Path,
+        task: &str,
+        schedule: &Schedule,
+        daft: PathBuf,
+        path_env: String,
+    ) -> Self {
+        ScheduledTask {
+            task: task.to_string(),
+            schedule: schedule.clone(),
+            worktree: worktree.to_path_buf(),
+            daft,
+            args: vec!["__scheduled-run".to_string(), task.to_string()],
+            prefix: unit_prefix(worktree),
+            origin: TASK_ORIGIN,
+            path_env,
+        }
+    }
+
+    /// The unit name without extension, e.g. `daft-myproject-1a2b3c4d-nightly`.
+    pub fn unit_name(&self) -> String {
+        format!("{}{}", self.prefix, unit_safe(&self.task))
+    }
+
+    /// The systemd `.service` unit that runs the task once.
+    pub fn systemd_service(&self) -> String {
+        format!(
+            "# {origin}\n\
+             [Unit]\n\
+             Description=daft {description} in {worktree}\n\
+             \n\
+             [Service]\n\
+             Type=oneshot\n\
+             WorkingDirectory={worktree}\n\
+             Environment={path}\n\
+             ExecStart={command}\n",
+            origin = self.origin,
+            description = systemd_escape(&self.args.join(" ")),
+            worktree = systemd_escape(&self.worktree.to_string_lossy()),
+            path = systemd_word(&format!("PATH={}", self.path_env)),
+            command = std::iter::once(self.daft.to_string_lossy().into_owned())
+                .chain(self.args.iter().cloned())
+                .map(|word| systemd_word(&word).replace('$', "$$"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    /// The systemd `.timer` unit that fires the service on schedule.
+    pub fn systemd_timer(&self) -> String {
+        format!(
+            "# {origin}\n\
+             # schedule: {schedule}\n\
+             [Unit]\n\
+             Description=Scheduled daft {description} in {worktree}\n\
+             \n\
+             [Timer]\n\
+             OnCalendar={calendar}\n\
+             Persistent=true\n\
+             \n\
+             [Install]\n\
+             WantedBy=timers.target\n",
+            origin = self.origin,
+            schedule = self.schedule,
+            description = systemd_escape(&self.args.join(" ")),
+            worktree = systemd_escape(&self.worktree.to_string_lossy()),
+            calendar = self.schedule.on_calendar(),
+        )
+    }
+
+    /// The launchd agent plist; output goes to `log`.
+    pub fn launchd_plist(&self, log: &Path) -> Result<String> {
+        let mut intervals = String::new();
+        for entry in self.schedule.launchd_intervals()? {
+            intervals.push_str("        <dict>\n");
+            for (key, value) in entry {
+                intervals.push_str(&format!(
+                    "            <key>{key}</key><integer>{value}</integer>\n"
+                ));
+            }
+            intervals.push_str("        </dict>\n");
+        }
+        Ok(format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<!-- {origin} schedule: {schedule} -->
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{label}</string>
+    <key>ProgramArguments</key>
+    <array>
+        <string>{daft}</string>
+{args}    </array>
+    <key>WorkingDirectory</key>
+    <string>{worktree}</string>
+    <key>EnvironmentVariables</key>
+    <dict>
+        <key>PATH</key>
+        <string>{path}</string>
+    </dict>
+    <key>StartCalendarInterval</key>
+    <array>
+{intervals}    </array>
+    <key>StandardOutPath</key>
+    <string>{log}</string>
+    <key>StandardErrorPath</key>
+    <string>{log}</string>
+</dict>
+</plist>
+"#,
+            origin = xml_escape(self.origin),
+            schedule = xml_escape(&self.schedule.to_string()),
+            label = self.unit_name(),
+            daft = xml_escape(&self.daft.to_string_lossy()),
+            args = self
+                .args
+                .iter()
+                .map(|arg| format!("        <string>{}</string>\n", xml_escape(arg)))
+                .collect::<String>(),
+            worktree = xml_escape(&self.worktree.to_string_lossy()),
+            path = xml_escape(&self.path_env),
+            log = xml_escape(&log.to_string_lossy()),
+        ))
+    }
+}
+
+/// Lowercase ASCII alphanumerics and dashes; everything else becomes `-`.
+fn unit_safe(raw: &str) -> String {
+    raw.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Escape `%`, which starts a specifier in almost every unit setting.
+fn systemd_escape(raw: &str) -> String {
+    raw.replace('%', "%%")
+}
+
+/// One word of a unit setting that splits on whitespace (`ExecStart=`,
+/// `Environment=`): specifiers escaped, and double-quoted when it holds
+/// anything the unit parser would otherwise split or interpret.
+fn systemd_word(raw: &str) -> String {
+    let escaped = systemd_escape(raw);
+    let plain = !raw.is_empty()
+        && raw
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "/._-+=:,@%$".contains(c));
+    if plain {
+        escaped
+    } else {
+        format!("\"{}\"", escaped.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// Refuse tasks whose names map to the same unit (`build.all` and
+/// `build-all`, `Build` and `build`): one would silently replace the other.
+pub fn check_unit_names(tasks: &[ScheduledTask]) -> Result<()> {
+    let mut seen: BTreeMap<String, &str> = BTreeMap::new();
+    for task in tasks {
+        if let Some(other) = seen.insert(task.unit_name(), &task.task) {
+            bail!(
+                "tasks '{other}' and '{}' would both be installed as '{}'; rename one of them",
+                task.task,
+                task.unit_name()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn xml_escape(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Installing units
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Where the generated units go and how they are (de)activated.
+pub enum Backend {
+    /// `~/.config/systemd/user`: a `.service` + `.timer` pair per task.
+    Systemd { dir: PathBuf },
+    /// `~/Library/LaunchAgents`: one plist per task, output in `logs`.
+    Launchd { dir: PathBuf, logs: PathBuf },
+}
+
+impl Backend {
+    pub fn detect() -> Result<Self> {
+        if cfg!(target_os = "macos") {
+            let home = dirs::home_dir().context("Could not determine the home directory")?;
+            Ok(Backend::Launchd {
+                dir: home.join("Library/LaunchAgents"),
+                logs: home.join("Library/Logs/daft"),
+            })
+        } else if cfg!(target_os = "linux") {
+            let config = dirs::config_dir().context("Could not determine the config directory")?;
+            Ok(Backend::Systemd {
+                dir: config.join("systemd/user"),
+            })
+        } else {
+            bail!("scheduled tasks need systemd (Linux) or launchd (macOS)");
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        match self {
+            Backend::Systemd { dir } | Backend::Launchd { dir, .. } => dir,
+        }
+    }
+
+    /// The files to write for `task`, with their contents.
+    pub fn files(&self, task: &ScheduledTask) -> Result<Vec<(PathBuf, String)>> {
+        let name = task.unit_name();
+        Ok(match self {
+            Backend::Systemd { dir } => vec![
+                (dir.join(format!("{name}.service")), task.systemd_service()),
+                (dir.join(format!("{name}.timer")), task.systemd_timer()),
+            ],
+            Backend::Launchd { dir, logs } => {
+                let log = logs.join(format!("{name}.log"));
+                vec![(dir.join(format!("{name}.plist")), task.launchd_plist(&log)?)]
+            }
+        })
+    }
+
+    /// Write the files of every task in `tasks`.
+    pub fn write(&self, tasks: &[ScheduledTask]) -> Result<()> {
+        check_unit_names(tasks)?;
+        std::fs::create_dir_all(self.dir())
+            .with_context(|| format!("Failed to create {}", self.dir().display()))?;
+        if let Backend::Launchd { logs, .. } = self {
+            std::fs::create_dir_all(logs)
+                .with_context(|| format!("Failed to create {}", logs.display()))?;
+        }
+        for task in tasks {
+            for (path, contents) in self.files(task)? {
+                std::fs::write(&path, contents)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The unit a scheduler activates for `name` (the timer, or the plist).
+    fn activation_file(&self, name: &str) -> PathBuf {
+        match self {
+            Backend::Systemd { dir } => dir.join(format!("{name}.timer")),
+            Backend::Launchd { dir, .. } => dir.join(format!("{name}.plist")),
+        }
+    }
+
+    /// Unit names installed under `prefix`.
+    pub fn installed(&self, prefix: &str) -> Vec<String> {
+        let extension = match self {
+            Backend::Systemd { .. } => ".timer",
+            Backend::Launchd { .. } => ".plist",
+        };
+        let mut names: Vec<String> = std::fs::read_dir(self.dir())
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let file = entry.file_name().to_string_lossy().into_owned();
+                file.strip_suffix(extension)
+                    .filter(|name| name.starts_with(prefix))
+                    .map(str::to_string)
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Load `names` into the scheduler. Returns the command to run by hand
+    /// when the scheduler can't be reached.
+    pub fn activate(&self, names: &[String]) -> Option<String> {
+        match self {
+            Backend::Systemd { .. } => {
+                let timers: Vec<String> = names.iter().map(|n| format!("{n}.timer")).collect();
+                let mut enable = vec!["--user", "enable", "--now"];
+                enable.extend(timers.iter().map(String::as_str));
+                let ok = scheduler("systemctl", &["--user", "daemon-reload"])
+                    && scheduler("systemctl", &enable);
+                (!ok).then(|| {
+                    format!(
+                        "systemctl --user daemon-reload && systemctl --user enable --now {}",
+                        timers.join(" ")
+                    )
+                })
+            }
+            Backend::Launchd { .. } => {
+                let mut failed = Vec::new();
+                for name in names {
+                    let plist = self.activation_file(name);
+                    let plist = plist.to_string_lossy();
+                    // Reloading picks up a changed schedule.
+                    scheduler("launchctl", &["unload", &plist]);
+                    if !scheduler("launchctl", &["load", "-w", &plist]) {
+                        failed.push(format!("launchctl load -w {plist}"));
+                    }
+                }
+                (!failed.is_empty()).then(|| failed.join(" && "))
+            }
+        }
+    }
+
+    /// Stop `name` and delete its files.
+    pub fn remove(&self, name: &str) -> Result<()> {
+        match self {
+            Backend::Systemd { dir } => {
+                scheduler(
+                    "systemctl",
+                    &["--user", "disable", "--now", &format!("{name}.timer")],
+                );
+                for extension in ["timer", "service"] {
+                    remove_if_exists(&dir.join(format!("{name}.{extension}")))?;
+                }
+            }
+            Backend::Launchd { dir, .. } => {
+                let plist = dir.join(format!("{name}.plist"));
+                scheduler("launchctl", &["unload", "-w", &plist.to_string_lossy()]);
+                remove_if_exists(&plist)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run a scheduler CLI quietly; `false` if it is missing or fails.
+fn scheduler(program: &str, args: &[&str]) -> bool {
+    Command::new(program)
+        .args(args)
+        .output()
+        .is_ok_and(|out| out.status.success())
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(e).with_context(|| format!("Failed to remove {}", path.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Schedule {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn cron_fields_render_as_on_calendar() {
+        assert_eq!(parse("0 3 * * *").on_calendar(), "*-*-* 03:00:00");
+        assert_eq!(parse("@hourly").on_calendar(), "*-*-* *:00:00");
+        assert_eq!(
+            parse("*/15 9-17 * * mon-fri").on_calendar(),
+            "Mon,Tue,Wed,Thu,Fri *-*-* 09,10,11,12,13,14,15,16,17:00,15,30,45:00"
+        );
+        assert_eq!(
+            parse("30 4 1,15 jan *").on_calendar(),
+            "*-01-01,15 04:30:00"
+        );
+        assert_eq!(parse("0 0 * * 7").on_calendar(), "Sun *-*-* 00:00:00");
+    }
+
+    #[test]
+    fn invalid_schedules_are_rejected_with_the_reason() {
+        let err = |s: &str| s.parse::<Schedule>().unwrap_err().to_string();
+        assert!(err("0 3 * *").contains("expected 5 fields"));
+        assert!(err("61 * * * *").contains("minute 61 is out of range"));
+        assert!(err("0 3 1 * mon").contains("not both"));
+        assert!(err("*/0 * * * *").contains("step"));
+        assert!(err("@often").contains("unknown shorthand"));
+    }
+
+    #[test]
+    fn launchd_intervals_expand_restricted_fields() {
+        let entries = parse("0 9,18 * * 1").launchd_intervals().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1]["Hour"], 18);
+        assert_eq!(entries[1]["Weekday"], 1);
+        assert!(!entries[0].contains_key("Day"));
+        // An empty dict is launchd's "every minute".
+        assert_eq!(parse("* * * * *").launchd_intervals().unwrap().len(), 1);
+        assert!(parse("* 0-23 1-31 * *").launchd_intervals().is_err());
+    }
+
+    #[test]
+    fn units_invoke_the_scheduled_entry_point_in_the_worktree() {
+        let task = ScheduledTask::for_task(
+            Path::new("/work/My Project"),
+            "fetch-all",
+            &parse("@daily"),
+            PathBuf::from("/usr/local/bin/daft"),
+            "/usr/bin:/bin".into(),
+        );
+        let name = task.unit_name();
+        assert!(name.starts_with("daft-my-project-"), "{name}");
+        assert!(name.ends_with("-fetch-all"));
+        assert_eq!(unit_prefix(&task.worktree), unit_prefix(&task.worktree));
+        assert!(
+            task.systemd_service()
+                .contains("ExecStart=/usr/local/bin/daft __scheduled-run fetch-all")
+        );
+        assert!(task.systemd_timer().contains("OnCalendar=*-*-* 00:00:00"));
+        let plist = task.launchd_plist(Path::new("/tmp/x.log")).unwrap();
+        assert!(plist.contains("<string>/work/My Project</string>"));
+        assert!(plist.contains(
+            "        <string>__scheduled-run</string>\n        <string>fetch-all</string>\n"
+        ));
+        assert!(plist.contains("<key>Hour</key><integer>0</integer>"));
+    }
+
+    #[test]
+    fn systemd_units_escape_specifiers_variables_and_spaces() {
+        let task = ScheduledTask::for_task(
+            Path::new("/work/100% $HOME"),
+            "fetch",
+            &parse("@daily"),
+            PathBuf::from("/opt/my tools/daft"),
+            "/usr/bin:/bin".into(),
+        );
+        let service = task.systemd_service();
+        assert!(
+            service.contains("ExecStart=\"/opt/my tools/daft\" __scheduled-run fetch\n"),
+            "{service}"
+        );
+        assert!(
+            service.contains("WorkingDirectory=/work/100%% $HOME\n"),
+            "{service}"
+        );
+        assert!(task.systemd_timer().contains("in /work/100%% $HOME"));
+        assert_eq!(systemd_word("a\"b$%"), "\"a\\\"b$%%\"");
+    }
+
+    #[test]
+    fn tasks_sharing_a_unit_name_are_refused() {
+        let task = |name: &str| {
+            ScheduledTask::for_task(
+                Path::new("/work/app"),
+                name,
+                &parse("@daily"),
+                PathBuf::from("/usr/bin/daft"),
+                String::new(),
+            )
+        };
+        check_unit_names(&[task("build-all"), task("lint")]).unwrap();
+        let err = check_unit_names(&[task("build.all"), task("Build-All")]).unwrap_err();
+        assert!(
+            err.to_string().contains("'build.all' and 'Build-All'"),
+            "{err}"
+        );
+    }
+}
@@ -248,6 +248,8 @@ mod tests {
             repository_url: None,
             default_branch: None,
             removal_reason: None,
+            target_cwd: None,
+            branch_exists: None,
             is_move: true,
             old_worktree_path: Some(PathBuf::from("/project/old-wt")),
             old_branch_name: Some("feat/old".to_string()),
@@ -284,6 +286,8 @@ mod tests {
             repository_url: None,
             default_branch: None,
             removal_reason: None,
+            target_cwd: None,
+            branch_exists: None,
             is_move: false,
             old_worktree_path: None,
             old_branch_name: None,
@@ -108,13 +108,8 @@ impl TrustEntry {
     }
 
     /// Format the granted_at timestamp for display.
-    pub fn formatted_time(&self) -> String {
-        use chrono::{Local, TimeZone};
-        Local
-            .timestamp_opt(self.granted_at, 0)
-            .single()
-            .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
-            .unwrap_or_else(|| "unknown".to_string())
+    pub fn formatted_time(&self, format: crate::settings::TimeFormat) -> String {
+        crate::output::time::stamp(self.granted_at, format)
     }
 }
 
@@ -266,13 +261,6 @@ impl TrustDatabase {
     /// sees a torn file.
     pub fn save_to(&self, path: &Path) -> Result<()> {
         use super::trust_dto::{RepoEntryV3_0_0, TrustEntryV2_0_0};
-        use std::io::Write;
-
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
-            fs::create_dir_all(parent)
-                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
-        }
 
         let mut entries: HashMap<String, RepoEntryV3_0_0> = HashMap::new();
 
@@ -310,26 +298,11 @@ impl TrustDatabase {
         let contents =
             serde_json::to_string_pretty(&json).context("Failed to serialize trust database")?;
 
-        // Atomic replace: a same-directory temp file with a random name, then
-        // rename over the destination. Same-dir keeps the rename atomic (no
-        // cross-filesystem copy); the random name means concurrent writers never
-        // collide on a fixed temp path (#666).
-        let tmp_dir = path
-            .parent()
-            .filter(|p| !p.as_os_str().is_empty())
-            .unwrap_or_else(|| Path::new("."));
-        let mut tmp = tempfile::NamedTempFile::new_in(tmp_dir)
-            .with_context(|| format!("Failed to create temp file in {}", tmp_dir.display()))?;
-        tmp.write_all(contents.as_bytes())
-            .with_context(|| format!("Failed to write trust database to {}", path.display()))?;
-        // Flush data to disk before the rename so a crash can't leave the
-        // renamed-into-place file pointing at unwritten (zero/garbage) blocks.
-        tmp.as_file()
-            .sync_all()
-            .with_context(|| format!("Failed to flush trust database to {}", path.display()))?;
-        tmp.persist(path)
-            .map_err(|e| e.error)
-            .with_context(|| format!("Failed to write trust database to {}", path.display()))?;
+        // Atomic replace (see `state_file::write_atomic`): the random temp
+        // name means concurrent writers never collide on a fixed temp path
+        // (#666).
+        crate::state_file::write_atomic(path, contents.as_bytes())
+            .context("Failed to write trust database")?;
 
         Ok(())
     }
@@ -424,6 +397,7 @@ impl TrustDatabase {
                 Err(e) => (Self::default(), Some(e)),
             };
 
+            let before = db.clone();
             let (changed, out) = f(&mut db)?;
             if changed {
                 if let Some(e) = corrupt {
@@ -435,7 +409,7 @@ impl TrustDatabase {
                     // a trust mutation today, but that is a property of the
                     // current call graph, not something this side of the lock
                     // can check (#720).
-                    let backup = back_up_corrupt(&src)?;
+                    let backup = crate::state_file::back_up_corrupt(&src)?;
                     deferred_warn::warn(format!(
                         "warning: daft trust registry at {} was unreadable ({e:#}); \
                          backed it up to {} and started a fresh registry. \
@@ -449,6 +423,9 @@ impl TrustDatabase {
                 if src == legacy && legacy.exists() {
                     let _ = fs::remove_file(&legacy);
                 }
+                // Still under the lock, so audit entries land in the same
+                // order as the registry writes they describe.
+                super::trust_audit::record_changes(dir, &before, &db);
             }
             Ok(out)
         })()
@@ -689,25 +666,6 @@ impl TrustDatabase {
     }
 }
 
-/// Rename a corrupt registry file aside so it isn't lost when we start fresh.
-/// Returns the backup path. Mirrors the manual recovery done in the #666
-/// incident (`repos.json.corrupt-<ts>.bak`).
-fn back_up_corrupt(path: &Path) -> Result<PathBuf> {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let ts = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .unwrap_or(0);
-    let name = path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("repos.json");
-    let backup = path.with_file_name(format!("{name}.corrupt-{ts}.bak"));
-    fs::rename(path, &backup)
-        .with_context(|| format!("Failed to back up corrupt registry {}", path.display()))?;
-    Ok(backup)
-}
-
 /// Get the remote "origin" URL for a repository given its `.git` directory.
 ///
 /// Returns `None` if the remote cannot be queried (no remote configured,
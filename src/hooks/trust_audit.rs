@@ -0,0 +1,273 @@
+//! Append-only audit log of hook trust.
+//!
+//! Two kinds of events are recorded, each with a timestamp, the repository,
+//! and the daft invocation that caused it:
+//!
+//! - **Trust changes.** [`TrustDatabase`]'s locked write path diffs the
+//!   registry before and after every mutation, so each level set, removed, or
+//!   pattern rule added or removed is logged no matter which command made it
+//!   (`daft hooks trust`, a clone prompt, `daft hooks prune`, ...).
+//! - **Execution decisions.** The hook executor records what the trust gate
+//!   decided each time a repository's hooks were about to run: allowed,
+//!   denied, prompted (with the answer), or explicitly run via
+//!   `daft hooks run`.
+//!
+//! The log lives next to the trust registry (`<config>/audit/trust.db`) and
+//! is read with `daft hooks audit`. Recording is best-effort: a failure is a
+//! deferred warning, never a reason to block a hook or lose a trust change.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+
+use super::trust::{TrustDatabase, TrustLevel};
+use crate::output::deferred_warn;
+use crate::store::models::TrustAuditRow;
+use crate::store::paths;
+use crate::store::pool::Pool;
+use crate::store::repos::TrustAuditRepo;
+use crate::store::{connection, migrate};
+
+/// What the trust gate decided for one hook fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    /// Trust level `allow`.
+    Allowed,
+    /// Trust level `deny`: nothing ran.
+    Denied,
+    /// Trust level `deny`, but the hook's sandboxed wasm jobs ran.
+    Sandboxed,
+    /// Trust level `prompt`, and the user said yes.
+    PromptAccepted,
+    /// Trust level `prompt`, and the user said no.
+    PromptDeclined,
+    /// Trust level `prompt` with no terminal to ask on: nothing ran.
+    PromptUnavailable,
+    /// Run explicitly with `daft hooks run`, which bypasses the trust gate.
+    Explicit,
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Decision::Allowed => "allowed",
+            Decision::Denied => "denied",
+            Decision::Sandboxed => "sandboxed",
+            Decision::PromptAccepted => "prompted: accepted",
+            Decision::PromptDeclined => "prompted: declined",
+            Decision::PromptUnavailable => "prompted: no terminal",
+            Decision::Explicit => "explicit run",
+        })
+    }
+}
+
+/// Record a trust-gate decision for `git_dir`'s `hook` in the audit log under
+/// `config_dir`.
+pub fn record_decision(config_dir: &Path, git_dir: &Path, hook: &str, decision: Decision) {
+    let row = TrustAuditRow {
+        recorded_at: Utc::now(),
+        event: "hook".to_string(),
+        repo: canonical_key(git_dir),
+        hook: Some(hook.to_string()),
+        outcome: decision.to_string(),
+        previous: None,
+        command: invocation(),
+    };
+    append(config_dir, &[row]);
+}
+
+/// Record every difference between two states of the trust registry.
+/// Called by the registry's locked write path once the new state is saved.
+pub(super) fn record_changes(config_dir: &Path, before: &TrustDatabase, after: &TrustDatabase) {
+    let rows = diff(before, after);
+    if !rows.is_empty() {
+        append(config_dir, &rows);
+    }
+}
+
+/// Entries of the audit log under `config_dir`, oldest first: all of them or
+/// only `repo`'s, and with `limit` only the newest `limit`. An audit log that
+/// doesn't exist yet reads as empty and is not created.
+pub fn entries(
+    config_dir: &Path,
+    repo: Option<&Path>,
+    limit: Option<usize>,
+) -> anyhow::Result<Vec<TrustAuditRow>> {
+    let path = config_dir
+        .join(paths::AUDIT_SUBDIR)
+        .join(paths::TRUST_AUDIT_DB);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let conn = connection::open_read_only(&path, connection::READER_BUSY_TIMEOUT_MS)?;
+    let on_disk: i64 = conn.query_row("PRAGMA user_version", [], |r| r.get(0))?;
+    if on_disk > migrate::trust_audit_set().current_version() {
+        anyhow::bail!(
+            "the trust audit log at {} was written by a newer daft",
+            path.display()
+        );
+    }
+    let repo = repo.map(canonical_key);
+    Ok(TrustAuditRepo::list(&conn, repo.as_deref(), limit)?)
+}
+
+/// The registry key for `git_dir`: canonicalized the same way
+/// [`TrustDatabase`] does, so `--repo` filters match trust-change entries.
+pub fn canonical_key(git_dir: &Path) -> String {
+    git_dir
+        .canonicalize()
+        .unwrap_or_else(|_| git_dir.to_path_buf())
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn diff(before: &TrustDatabase, after: &TrustDatabase) -> Vec<TrustAuditRow> {
+    let now = Utc::now();
+    let command = invocation();
+    let row =
+        |event: &str, repo: &str, outcome: String, previous: Option<TrustLevel>| TrustAuditRow {
+            recorded_at: now,
+            event: event.to_string(),
+            repo: repo.to_string(),
+            hook: None,
+            outcome,
+            previous: previous.map(|level| level.to_string()),
+            command: command.clone(),
+        };
+
+    let mut rows = Vec::new();
+    let levels = |db: &TrustDatabase| -> BTreeMap<String, TrustLevel> {
+        db.repositories
+            .iter()
+            .map(|(path, entry)| (path.clone(), entry.level))
+            .collect()
+    };
+    let (old, new) = (levels(before), levels(after));
+    for (repo, level) in &new {
+        let previous = old.get(repo).copied();
+        if previous != Some(*level) {
+            rows.push(row("trust", repo, level.to_string(), previous));
+        }
+    }
+    for (repo, level) in &old {
+        if !new.contains_key(repo) {
+            rows.push(row("trust", repo, "removed".to_string(), Some(*level)));
+        }
+    }
+
+    let patterns = |db: &TrustDatabase| -> BTreeMap<String, TrustLevel> {
+        db.patterns
+            .iter()
+            .map(|p| (p.pattern.clone(), p.level))
+            .collect()
+    };
+    let (old, new) = (patterns(before), patterns(after));
+    for (pattern, level) in &new {
+        let previous = old.get(pattern).copied();
+        if previous != Some(*level) {
+            rows.push(row("pattern", pattern, level.to_string(), previous));
+        }
+    }
+    for (pattern, level) in &old {
+        if !new.contains_key(pattern) {
+            rows.push(row("pattern", pattern, "removed".to_string(), Some(*level)));
+        }
+    }
+    rows
+}
+
+fn append(config_dir: &Path, rows: &[TrustAuditRow]) {
+    let result = (|| -> anyhow::Result<()> {
+        let path: PathBuf = paths::trust_audit_db_under(config_dir)?;
+        let pool = Pool::open_with(&path, &migrate::trust_audit_set())?;
+        let conn = pool.writer()?;
+        for row in rows {
+            TrustAuditRepo::append(&conn, row)?;
+        }
+        Ok(())
+    })();
+    if let Err(e) = result {
+        deferred_warn::warn(format!("daft: failed to write the trust audit log: {e:#}"));
+    }
+}
+
+/// The daft invocation behind an event, e.g. `daft hooks trust --force`.
+fn invocation() -> String {
+    let Some(argv) = crate::cli::try_argv() else {
+        return "daft".to_string();
+    };
+    let program = argv
+        .first()
+        .and_then(|arg0| Path::new(arg0).file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "daft".to_string());
+    std::iter::once(program)
+        .chain(argv.iter().skip(1).cloned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn registry_updates_are_audited_and_filterable_by_repo() {
+        let config = tempdir().unwrap();
+        let repos = tempdir().unwrap();
+        let a = repos.path().join("a.git");
+        let b = repos.path().join("b.git");
+        std::fs::create_dir_all(&a).unwrap();
+        std::fs::create_dir_all(&b).unwrap();
+
+        TrustDatabase::update_in(config.path(), |db| {
+            db.set_trust_level(&a, TrustLevel::Allow);
+            db.set_trust_level(&b, TrustLevel::Prompt);
+            Ok(())
+        })
+        .unwrap();
+        // Re-granting the same level is not a change.
+        TrustDatabase::update_in(config.path(), |db| {
+            db.set_trust_level(&a, TrustLevel::Allow);
+            Ok(())
+        })
+        .unwrap();
+        TrustDatabase::update_in(config.path(), |db| {
+            db.remove_trust(&a);
+            db.add_pattern("/work/*".into(), TrustLevel::Allow, None);
+            Ok(())
+        })
+        .unwrap();
+        record_decision(config.path(), &a, "worktree-post-create", Decision::Denied);
+
+        let all = entries(config.path(), None, None).unwrap();
+        let summary: Vec<_> = all
+            .iter()
+            .map(|e| (e.event.as_str(), e.outcome.as_str(), e.previous.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("trust", "allow", None),
+                ("trust", "prompt", None),
+                ("trust", "removed", Some("allow")),
+                ("pattern", "allow", None),
+                ("hook", "denied", None),
+            ]
+        );
+
+        let only_a = entries(config.path(), Some(&a), None).unwrap();
+        assert_eq!(only_a.len(), 3);
+        assert_eq!(only_a[2].hook.as_deref(), Some("worktree-post-create"));
+    }
+
+    #[test]
+    fn missing_log_reads_as_empty_without_creating_it() {
+        let config = tempdir().unwrap();
+        assert!(entries(config.path(), None, None).unwrap().is_empty());
+        assert!(!config.path().join(paths::AUDIT_SUBDIR).exists());
+    }
+}
@@ -419,6 +419,12 @@ fn format_notice(content: &NoticeContent, show_hints: bool, colorize: bool) -> S
                 "\n{INDENT}{dim}{label:<LABEL_W$}{reset}  {cyan}{exe} hooks run {replay}{reset}{suffix}"
             ));
         }
+        // A repo the user keeps untrusted on purpose can opt out for good.
+        let label = "To hide this notice for this repo:";
+        out.push_str(&format!(
+            "\n{INDENT}{dim}{label:<LABEL_W$}{reset}  {cyan}git config {} false{reset}",
+            crate::settings::keys::hooks::UNTRUSTED_NOTICE
+        ));
     }
     out
 }
@@ -496,6 +502,7 @@ mod tests {
         assert!(msg.starts_with("Untrusted repo — 2 daft.yml hooks not run: "));
         assert!(msg.contains("git daft hooks trust"));
         assert!(msg.contains("git daft hooks run worktree-post-create"));
+        assert!(msg.contains("git config daft.hooks.untrustedNotice false"));
         assert!(
             !msg.contains(" for feat/x"),
             "single-branch notices do not name the branch"
@@ -0,0 +1,254 @@
+//! Experimental `wasm:` jobs — WASI modules run with a restricted
+//! capability set.
+//!
+//! A `wasm` job names a module under the hook source directory (like
+//! `script:`) and runs it through the [`RUNTIME`] CLI instead of a shell:
+//!
+//! ```yaml
+//! hooks:
+//!   worktree-post-create:
+//!     jobs:
+//!       - name: gen-config
+//!         wasm: hooks/gen-config.wasm
+//!         args: --profile dev
+//!         grants: [network]   # optional; nothing beyond the worktree otherwise
+//! ```
+//!
+//! The module sees the job's working directory (the worktree, or its `root:`)
+//! mounted as `.`, the hook's `DAFT_*` variables and the job's `env:`, and
+//! nothing else: no other files, no network unless granted, no subprocesses.
+//! `args:` is split into words like a shell would, and each word reaches the
+//! module as one literal argument — quotes group, nothing else is special.
+//! Because of that, `daft.hooks.untrustedWasm` lets a repository that isn't
+//! trusted still run its `wasm` jobs — see [`sandboxed_hook`] — while its
+//! shell jobs stay skipped.
+
+use std::collections::HashSet;
+
+use super::yaml_config::{
+    HookDef, JobDef, OnlyCondition, OnlyRule, SkipCondition, SkipRule, WasmGrant,
+};
+use super::yaml_config_loader::get_effective_jobs;
+
+/// The WASI runtime `wasm` jobs run under. Must be on `PATH`.
+pub const RUNTIME: &str = "wasmtime";
+
+/// Job `env:` names withheld from sandboxed jobs: they change how the shell
+/// that launches [`RUNTIME`] finds and loads it, or how the runtime itself
+/// is configured, rather than what the module sees.
+const LAUNCHER_ENV: &[&str] = &["PATH", "ENV", "BASH_ENV", "IFS", "CDPATH", "SHELLOPTS"];
+
+/// Prefixes of [`LAUNCHER_ENV`]-like names (dynamic loader and runtime
+/// configuration).
+const LAUNCHER_ENV_PREFIXES: &[&str] = &["LD_", "DYLD_", "WASMTIME_"];
+
+/// Split a job's `args:` into words, as a POSIX shell would. `None` when the
+/// quoting is unbalanced.
+pub(crate) fn split_args(args: &str) -> Option<Vec<String>> {
+    shlex::split(args)
+}
+
+/// Shell command that fails the job with `reason`, for a `wasm` job that
+/// cannot be run as written.
+pub(crate) fn refusal(reason: &str) -> String {
+    format!("echo {} >&2; exit 2", quote(&format!("daft: {reason}")))
+}
+
+fn quote(s: &str) -> String {
+    // try_quote only fails on NUL, which no argv can carry anyway.
+    shlex::try_quote(s)
+        .map(|q| q.into_owned())
+        .unwrap_or_else(|_| "''".to_string())
+}
+
+/// Shell command that runs `module` under [`RUNTIME`] from the job's working
+/// directory, passing through the named environment variables and nothing
+/// else the module wasn't granted. Every element of `args` is quoted, so it
+/// reaches the module as exactly one argument.
+pub(crate) fn command<'a>(
+    module: &str,
+    args: &[String],
+    env_names: impl IntoIterator<Item = &'a str>,
+    grants: &[WasmGrant],
+) -> String {
+    let mut parts = vec![format!("exec {RUNTIME} run --dir .")];
+    let mut names: Vec<&str> = env_names.into_iter().collect();
+    names.sort_unstable();
+    names.dedup();
+    for name in names {
+        parts.push(format!("--env {}", quote(name)));
+    }
+    if grants.contains(&WasmGrant::Network) {
+        parts.push("-S inherit-network=y -S allow-ip-name-lookup=y".to_string());
+    }
+    parts.push(quote(module));
+    parts.extend(args.iter().map(|a| quote(a)));
+    format!(
+        "command -v {RUNTIME} >/dev/null 2>&1 || {{ echo \"daft: wasm jobs need {RUNTIME} on PATH\" >&2; exit 127; }}; {}",
+        parts.join(" ")
+    )
+}
+
+/// The part of `hook` that may run in an untrusted repository: its `wasm`
+/// jobs, with grants withdrawn. Returns the reduced hook and how many jobs
+/// were left out, or `None` when nothing qualifies.
+///
+/// Anything that would run a shell command disqualifies: a hook-level
+/// `skip:`/`only:` with a `run:` rule drops the whole hook, and a job's own
+/// `run:` condition drops that job. `needs:` on a dropped job is treated as
+/// satisfied, as it is for skipped jobs.
+///
+/// Kept jobs lose whatever reaches past the module: `grants:`, `root:` (the
+/// mounted directory is always the worktree), `run:`/`script:`/`runner:`,
+/// `interactive:`, and `env:` entries that steer the launching shell or the
+/// runtime (`PATH`, `LD_*`, `WASMTIME_*`, ...).
+pub fn sandboxed_hook(hook: &HookDef) -> Option<(HookDef, usize)> {
+    if hook.skip.as_ref().is_some_and(skip_runs_command)
+        || hook.only.as_ref().is_some_and(only_runs_command)
+    {
+        return None;
+    }
+    let jobs = get_effective_jobs(hook);
+    let total = jobs.len();
+    let mut kept: Vec<JobDef> = jobs
+        .into_iter()
+        .filter(|job| {
+            job.wasm.is_some()
+                && job.group.is_none()
+                && !job.skip.as_ref().is_some_and(skip_runs_command)
+                && !job.only.as_ref().is_some_and(only_runs_command)
+        })
+        .map(|job| JobDef {
+            grants: None,
+            root: None,
+            run: None,
+            script: None,
+            runner: None,
+            interactive: None,
+            env: job.env.map(|env| {
+                env.into_iter()
+                    .filter(|(name, _)| !is_launcher_env(name))
+                    .collect()
+            }),
+            ..job
+        })
+        .collect();
+    if kept.is_empty() {
+        return None;
+    }
+    let names: HashSet<String> = kept.iter().filter_map(|j| j.name.clone()).collect();
+    for job in &mut kept {
+        if let Some(needs) = job.needs.as_mut() {
+            needs.retain(|n| names.contains(n));
+        }
+    }
+    let dropped = total - kept.len();
+    Some((
+        HookDef {
+            jobs: Some(kept),
+            commands: None,
+            ..hook.clone()
+        },
+        dropped,
+    ))
+}
+
+fn is_launcher_env(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    LAUNCHER_ENV.contains(&upper.as_str())
+        || LAUNCHER_ENV_PREFIXES.iter().any(|p| upper.starts_with(p))
+}
+
+fn skip_runs_command(cond: &SkipCondition) -> bool {
+    let rule_runs = |r: &SkipRule| matches!(r, SkipRule::Structured(s) if s.run.is_some());
+    match cond {
+        SkipCondition::Rules(rules) => rules.iter().any(rule_runs),
+        SkipCondition::Platform(map) => map.values().flatten().any(rule_runs),
+        SkipCondition::Bool(_) | SkipCondition::EnvVar(_) => false,
+    }
+}
+
+fn only_runs_command(cond: &OnlyCondition) -> bool {
+    let rule_runs = |r: &OnlyRule| matches!(r, OnlyRule::Structured(s) if s.run.is_some());
+    match cond {
+        OnlyCondition::Rules(rules) => rules.iter().any(rule_runs),
+        OnlyCondition::Platform(map) => map.values().flatten().any(rule_runs),
+        OnlyCondition::Bool(_) | OnlyCondition::EnvVar(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_passes_env_by_name_and_grants_network_only_when_asked() {
+        let cmd = command(
+            ".daft/hooks/gen.wasm",
+            &["--dev".to_string()],
+            ["DAFT_BRANCH_NAME", "API_URL", "DAFT_BRANCH_NAME"],
+            &[],
+        );
+        assert!(cmd.contains(
+            "exec wasmtime run --dir . --env API_URL --env DAFT_BRANCH_NAME .daft/hooks/gen.wasm --dev"
+        ));
+        assert!(!cmd.contains("inherit-network"));
+        let networked = command("m.wasm", &[], [], &[WasmGrant::Network]);
+        assert!(networked.contains("-S inherit-network=y"));
+    }
+
+    #[test]
+    fn sandboxed_hook_keeps_only_command_free_wasm_jobs() {
+        let hook: HookDef = serde_yaml::from_str(
+            r#"
+jobs:
+  - name: shell
+    run: npm install
+  - name: gen
+    wasm: hooks/gen.wasm
+    grants: [network]
+    needs: [shell]
+  - name: probe
+    wasm: hooks/probe.wasm
+    skip:
+      - run: test -f .skip
+"#,
+        )
+        .unwrap();
+        let (sandboxed, dropped) = sandboxed_hook(&hook).unwrap();
+        let jobs = sandboxed.jobs.unwrap();
+        assert_eq!(dropped, 2);
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].name.as_deref(), Some("gen"));
+        assert_eq!(jobs[0].grants, None);
+        assert_eq!(jobs[0].needs, Some(vec![]));
+
+        let shell_only: HookDef = serde_yaml::from_str("jobs:\n  - run: make\n").unwrap();
+        assert!(sandboxed_hook(&shell_only).is_none());
+    }
+
+    #[test]
+    fn sandboxed_hook_ignores_root_and_launcher_env() {
+        let hook: HookDef = serde_yaml::from_str(
+            r#"
+jobs:
+  - name: gen
+    wasm: hooks/gen.wasm
+    root: ../../
+    interactive: true
+    env:
+      PATH: ./evil-bin
+      LD_PRELOAD: ./evil.so
+      WASMTIME_CONFIG: ./evil.toml
+      API_URL: https://example.com
+"#,
+        )
+        .unwrap();
+        let (sandboxed, _) = sandboxed_hook(&hook).unwrap();
+        let job = &sandboxed.jobs.unwrap()[0];
+        assert_eq!(job.root, None);
+        assert_eq!(job.interactive, None);
+        let env = job.env.as_ref().unwrap();
+        assert_eq!(env.keys().collect::<Vec<_>>(), ["API_URL"]);
+    }
+}
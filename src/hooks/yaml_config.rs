@@ -17,6 +17,7 @@ pub const KNOWN_HOOK_NAMES: &[&str] = &[
     "worktree-post-remove",
     "pre-merge",
     "post-merge",
+    "post-fetch",
 ];
 
 /// Top-level YAML configuration.
@@ -75,6 +76,12 @@ pub struct YamlConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shared: Option<Vec<String>>,
 
+    /// Ignored directories captured by `daft template snapshot` (e.g.
+    /// "node_modules", ".venv") and restored into new worktrees before
+    /// post-create hooks run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<Vec<String>>,
+
     /// Log configuration (retention, etc.).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub log: Option<LogConfig>,
@@ -88,6 +95,20 @@ pub struct YamlConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relations: Option<Vec<crate::catalog::relations::RelationEntry>>,
 
+    /// Cross-project links: repos whose matching-branch worktree this
+    /// worktree uses, exposed as a symlink and/or an environment variable
+    /// and provisioned on demand by `daft start`/`daft go`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<crate::catalog::links::LinkEntry>>,
+
+    /// Sinks notified with a structured summary after each hook run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify: Option<Vec<NotifySink>>,
+
+    /// Test cases for the hook config itself, run by `daft hooks test`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tests: Option<Vec<HookTest>>,
+
     /// Hook definitions, keyed by hook name.
     pub hooks: HashMap<String, HookDef>,
 
@@ -113,8 +134,103 @@ pub enum OutputSetting {
     Hooks(Vec<String>),
 }
 
+/// One `notify:` entry: where a hook-run summary goes, and for which runs.
+///
+/// Exactly one of `command`, `webhook`, or `slack` is set (enforced by
+/// validation). URLs may reference environment variables as `${NAME}` so
+/// secrets stay out of the committed file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct NotifySink {
+    /// Shell command run with the summary JSON on stdin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// URL the summary JSON is POSTed to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<String>,
+
+    /// Slack-compatible incoming-webhook URL; receives a `{"text": ...}` line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slack: Option<String>,
+
+    /// Which outcomes to report (default: every run that was not skipped).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub when: Option<NotifyWhen>,
+
+    /// Restrict the sink to these hook names (default: all hooks).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hooks: Option<Vec<String>>,
+}
+
+/// One `tests:` entry: a hook to fire in a sandbox and what should happen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct HookTest {
+    /// Name shown in the report and matched by `daft hooks test <NAME>`.
+    pub name: String,
+
+    /// Hook to fire (e.g. "worktree-post-create").
+    pub hook: String,
+
+    /// Branch the sandbox worktree is on (default: the current branch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+
+    /// Extra environment variables for the hook's jobs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<std::collections::BTreeMap<String, String>>,
+
+    /// Expected outcome (default: the hook succeeds).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expect: Option<HookTestExpect>,
+}
+
+/// Expected outcome of a hook test.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct HookTestExpect {
+    /// Expected exit code of the hook as a whole (default: 0).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+
+    /// Per-job expectations, keyed by job name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jobs: Option<std::collections::BTreeMap<String, JobExpect>>,
+}
+
+/// Expected outcome of one job in a hook test.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct JobExpect {
+    /// Expected exit code of the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+
+    /// Whether the job is expected to be skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<bool>,
+
+    /// Substrings the job's output must contain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_contains: Option<Vec<String>>,
+
+    /// Substrings the job's output must not contain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_not_contains: Option<Vec<String>>,
+}
+
+/// Outcome filter for a notify sink.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyWhen {
+    Always,
+    Success,
+    Failure,
+}
+
 // Re-export from executor so that format-agnostic types are defined once.
-pub use crate::executor::{BackgroundOutput, LogConfig};
+pub use crate::executor::{BackgroundOutput, LogConfig, ResourceLimits};
 
 /// Definition for a single hook type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -159,6 +275,11 @@ pub struct HookDef {
     /// Legacy alias for jobs (commands map).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commands: Option<HashMap<String, CommandDef>>,
+
+    /// Cron-like schedule for a task (`0 3 * * *`, `@daily`); installed as a
+    /// systemd timer or launchd agent by `daft hooks schedule install`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
 }
 
 /// Target operating system for platform constraints.
@@ -295,6 +416,15 @@ pub struct JobDef {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub runner: Option<String>,
 
+    /// WASI module to run in a sandbox (relative to source_dir).
+    /// Experimental; see [`crate::hooks::wasm`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<String>,
+
+    /// Capabilities granted to a `wasm` job beyond its working directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grants: Option<Vec<WasmGrant>>,
+
     /// Arguments to pass to the script.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub args: Option<String>,
@@ -359,6 +489,18 @@ pub struct JobDef {
     /// Log configuration for this job.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log: Option<LogConfig>,
+
+    /// CPU, memory, and scheduling limits for this job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<ResourceLimits>,
+}
+
+/// A capability a `wasm` job may be granted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WasmGrant {
+    /// Outbound sockets and DNS lookups.
+    Network,
 }
 
 /// Legacy command definition (alias for JobDef).
@@ -420,7 +562,7 @@ pub enum SkipRule {
 }
 
 /// Structured skip rule.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct SkipRuleStructured {
     /// Skip if current ref matches this pattern.
     #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
@@ -428,6 +570,18 @@ pub struct SkipRuleStructured {
     /// Skip if this env var is set and truthy.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub env: Option<String>,
+    /// Skip if this env var is set, whatever its value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env_set: Option<String>,
+    /// Skip if this path (relative to the worktree) exists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exists: Option<String>,
+    /// Skip if this executable is on `PATH`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_available: Option<String>,
+    /// Skip if this git config key matches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_config: Option<GitConfigCondition>,
     /// Skip if this command exits 0.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run: Option<String>,
@@ -436,6 +590,17 @@ pub struct SkipRuleStructured {
     pub desc: Option<String>,
 }
 
+/// A `git_config:` condition: the key is set, or set to `equals`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GitConfigCondition {
+    /// Config key, e.g. `core.fsmonitor`.
+    pub key: String,
+    /// Required value; when absent the key only has to be set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equals: Option<String>,
+}
+
 /// Only condition: mirrors SkipCondition but with inverse semantics.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -461,7 +626,7 @@ pub enum OnlyRule {
 }
 
 /// Structured only rule.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct OnlyRuleStructured {
     /// Only run if current ref matches this pattern.
     #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
@@ -469,6 +634,18 @@ pub struct OnlyRuleStructured {
     /// Only run if this env var is set and truthy.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub env: Option<String>,
+    /// Only run if this env var is set, whatever its value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env_set: Option<String>,
+    /// Only run if this path (relative to the worktree) exists.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exists: Option<String>,
+    /// Only run if this executable is on `PATH`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_available: Option<String>,
+    /// Only run if this git config key matches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_config: Option<GitConfigCondition>,
     /// Only run if this command exits 0.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run: Option<String>,
@@ -84,10 +84,12 @@ pub fn find_local_config(main_config: &Path) -> Option<PathBuf> {
     let deprecated_name = format!("{stem}-local{ext}");
     let deprecated = parent.join(&deprecated_name);
     if deprecated.is_file() {
-        crate::log_warning!(
-            "deprecated local config name '{}' — rename to '{}'",
-            deprecated_name,
-            preferred_name
+        crate::deprecations::record(
+            crate::deprecations::DeprecationKind::ConfigRename,
+            deprecated.display().to_string(),
+            format!(
+                "deprecated local config name '{deprecated_name}' — rename to '{preferred_name}'"
+            ),
         );
         return Some(deprecated);
     }
@@ -3,8 +3,9 @@
 //! Validates a parsed `YamlConfig` for semantic correctness beyond
 //! what serde can enforce.
 
-use super::yaml_config::{HookDef, JobDef, YamlConfig};
+use super::yaml_config::{HookDef, HookTest, JobDef, NotifySink, YamlConfig};
 use crate::VERSION;
+use crate::catalog::links::LinkEntry;
 use anyhow::Result;
 
 /// A validation warning (non-fatal).
@@ -94,9 +95,102 @@ pub fn validate_config(config: &YamlConfig) -> Result<ValidationResult> {
         validate_hook_def("tasks", task_name, task_def, &mut result);
     }
 
+    for (i, sink) in config.notify.iter().flatten().enumerate() {
+        validate_notify_sink(&format!("notify[{i}]"), sink, &mut result);
+    }
+
+    for (i, link) in config.links.iter().flatten().enumerate() {
+        validate_link(&format!("links[{i}]"), link, &mut result);
+    }
+
+    let mut test_names = std::collections::HashSet::new();
+    for (i, test) in config.tests.iter().flatten().enumerate() {
+        let path = format!("tests[{i}]");
+        if !test.name.is_empty() && !test_names.insert(test.name.as_str()) {
+            result.error(&path, format!("duplicate test name '{}'", test.name));
+        }
+        validate_hook_test(&path, test, config, &mut result);
+    }
+
     Ok(result)
 }
 
+/// A notify sink names exactly one destination, and its hook filter names
+/// real lifecycle hooks.
+fn validate_notify_sink(path: &str, sink: &NotifySink, result: &mut ValidationResult) {
+    let destinations = [&sink.command, &sink.webhook, &sink.slack]
+        .iter()
+        .filter(|d| d.is_some())
+        .count();
+    if destinations != 1 {
+        result.error(path, "set exactly one of 'command', 'webhook', or 'slack'");
+    }
+    for url in [&sink.webhook, &sink.slack].into_iter().flatten() {
+        let lower = url.to_ascii_lowercase();
+        if !lower.starts_with("https://") && !lower.starts_with("http://") && !url.starts_with("${")
+        {
+            result.error(path, format!("'{url}' is not an http(s) URL"));
+        }
+    }
+    for hook in sink.hooks.iter().flatten() {
+        if crate::hooks::HookType::from_yaml_name(hook).is_none() {
+            result.warn(
+                format!("{path}.hooks"),
+                format!("'{hook}' is not a lifecycle hook; this filter never matches"),
+            );
+        }
+    }
+}
+
+/// A hook test is named and fires a lifecycle hook the config defines.
+fn validate_hook_test(
+    path: &str,
+    test: &HookTest,
+    config: &YamlConfig,
+    result: &mut ValidationResult,
+) {
+    if test.name.is_empty() {
+        result.error(path, "missing 'name'");
+    }
+    if crate::hooks::HookType::from_yaml_name(&test.hook).is_none() {
+        result.error(
+            format!("{path}.hook"),
+            format!("'{}' is not a lifecycle hook", test.hook),
+        );
+    } else if !config.hooks.contains_key(&test.hook) {
+        result.error(
+            format!("{path}.hook"),
+            format!("hook '{}' is not defined in this config", test.hook),
+        );
+    }
+}
+
+/// A link's `path` stays inside the worktree and its `env` is a usable
+/// variable name.
+fn validate_link(path: &str, link: &LinkEntry, result: &mut ValidationResult) {
+    if let Some(rel) = &link.path
+        && !crate::catalog::links::path_is_contained(rel)
+    {
+        result.error(
+            format!("{path}.path"),
+            format!("'{rel}' must be a relative path inside the worktree"),
+        );
+    }
+    if let Some(name) = &link.env {
+        let valid = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            result.error(
+                format!("{path}.env"),
+                format!("'{name}' is not a valid environment variable name"),
+            );
+        }
+    }
+}
+
 /// Validate a task name for CLI and shell-completion safety.
 ///
 /// A task name is typed as a bare `daft run <name>` argument and completed on
@@ -177,6 +271,19 @@ fn validate_hook_def(section: &str, name: &str, hook: &HookDef, result: &mut Val
         detect_foreground_promotions(section, name, hook, result);
     }
 
+    // Only tasks can be scheduled: a lifecycle hook needs the event it
+    // belongs to.
+    if let Some(ref schedule) = hook.schedule {
+        if section != "tasks" {
+            result.error(
+                &path,
+                "'schedule' only applies to tasks; move the jobs under 'tasks:'",
+            );
+        } else if let Err(e) = schedule.parse::<crate::hooks::schedule::Schedule>() {
+            result.error(&path, format!("invalid schedule '{schedule}': {e}"));
+        }
+    }
+
     // Warn if both jobs and commands are set
     if hook.jobs.is_some() && hook.commands.is_some() {
         result.warn(
@@ -188,17 +295,36 @@ fn validate_hook_def(section: &str, name: &str, hook: &HookDef, result: &mut Val
 
 /// Validate a single job definition.
 fn validate_job(path: &str, job: &JobDef, result: &mut ValidationResult) {
-    // Must have either run or script (but not both), unless it's a group
+    // Must have exactly one of run, script, or wasm, unless it's a group
     let has_run = job.run.is_some();
     let has_script = job.script.is_some();
+    let has_wasm = job.wasm.is_some();
     let has_group = job.group.is_some();
 
     if has_run && has_script {
         result.error(path, "'run' and 'script' are mutually exclusive");
     }
 
-    if !has_run && !has_script && !has_group {
-        result.error(path, "Job must have 'run', 'script', or 'group'");
+    if has_wasm && (has_run || has_script) {
+        result.error(path, "'wasm' cannot be combined with 'run' or 'script'");
+    }
+
+    if !has_run && !has_script && !has_wasm && !has_group {
+        result.error(path, "Job must have 'run', 'script', 'wasm', or 'group'");
+    }
+
+    if job.grants.is_some() && !has_wasm {
+        result.error(path, "'grants' only applies to 'wasm' jobs");
+    }
+
+    if let Some(ref limits) = job.limits {
+        for problem in limits.problems() {
+            result.error(path, problem);
+        }
+    }
+
+    if has_wasm && job.runner.is_some() {
+        result.warn(path, "'runner' is ignored for 'wasm' jobs");
     }
 
     // script requires runner
@@ -239,9 +365,12 @@ fn validate_job(path: &str, job: &JobDef, result: &mut ValidationResult) {
             result.warn(&group_path, "Group has no jobs");
         }
 
-        // A group job shouldn't also have run/script
-        if has_run || has_script {
-            result.error(path, "'group' cannot be combined with 'run' or 'script'");
+        // A group job shouldn't also have run/script/wasm
+        if has_run || has_script || has_wasm {
+            result.error(
+                path,
+                "'group' cannot be combined with 'run', 'script', or 'wasm'",
+            );
         }
     }
 }
@@ -908,6 +1037,87 @@ hooks:
         );
     }
 
+    #[test]
+    fn test_wasm_jobs_exclusive_and_grants_need_wasm() {
+        let yaml = r#"
+hooks:
+  worktree-post-create:
+    jobs:
+      - name: ok
+        wasm: hooks/gen.wasm
+        grants: [network]
+      - name: both
+        wasm: hooks/gen.wasm
+        run: echo hi
+      - name: stray
+        run: echo hi
+        grants: [network]
+"#;
+        let config: YamlConfig = serde_yaml::from_str(yaml).unwrap();
+        let result = validate_config(&config).unwrap();
+        assert_eq!(result.errors.len(), 2, "{:?}", result.errors);
+        assert!(
+            result.errors[0]
+                .message
+                .contains("'wasm' cannot be combined")
+        );
+        assert!(result.errors[1].message.contains("'grants' only applies"));
+    }
+
+    #[test]
+    fn test_schedule_only_on_valid_tasks() {
+        let yaml = r#"
+hooks:
+  post-clone:
+    schedule: "@daily"
+    jobs:
+      - run: echo hi
+tasks:
+  nightly:
+    schedule: "0 3 * * *"
+    jobs:
+      - run: git fetch --all
+  broken:
+    schedule: "0 25 * * *"
+    jobs:
+      - run: echo hi
+"#;
+        let config: YamlConfig = serde_yaml::from_str(yaml).unwrap();
+        let result = validate_config(&config).unwrap();
+        assert_eq!(result.errors.len(), 2, "{:?}", result.errors);
+        assert!(
+            result.errors.iter().any(
+                |e| e.path == "hooks.post-clone" && e.message.contains("only applies to tasks")
+            )
+        );
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.path == "tasks.broken" && e.message.contains("hour 25 is out of range"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_limits_rejected() {
+        let yaml = r#"
+hooks:
+  worktree-post-create:
+    jobs:
+      - name: ok
+        run: make
+        limits: { nice: 10, cpus: 2, memory: 4GB }
+      - name: bad
+        run: make
+        limits: { nice: 40, memory: lots }
+"#;
+        let config: YamlConfig = serde_yaml::from_str(yaml).unwrap();
+        let result = validate_config(&config).unwrap();
+        assert_eq!(result.errors.len(), 2, "{:?}", result.errors);
+        assert!(result.errors[0].message.contains("limits.nice"));
+        assert!(result.errors[1].message.contains("limits.memory"));
+    }
+
     #[test]
     fn test_valid_background_output_values() {
         let yaml = r#"
@@ -1115,4 +1325,81 @@ tasks:
         let result = validate_config(&config).unwrap();
         assert!(result.is_ok(), "unexpected errors: {:?}", result.errors);
     }
+
+    #[test]
+    fn test_notify_sinks_validated() {
+        let yaml = r#"
+notify:
+  - webhook: https://dash.example.com/hooks
+    when: failure
+    hooks: [worktree-post-create, post-create-typo]
+  - command: ./report.sh
+    slack: https://hooks.slack.com/services/x
+  - slack: hooks.slack.com/services/x
+  - webhook: "-K/etc/passwd"
+hooks: {}
+"#;
+        let config: YamlConfig = serde_yaml::from_str(yaml).unwrap();
+        let result = validate_config(&config).unwrap();
+        let errors: Vec<String> = result.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors.len(), 3, "got: {errors:?}");
+        assert!(errors[0].starts_with("notify[1]: set exactly one"));
+        assert!(errors[1].contains("not an http(s) URL"));
+        assert!(errors[2].starts_with("notify[3]: '-K/etc/passwd' is not an http(s) URL"));
+        assert!(
+            result
+                .warnings
+                .iter()
+                .any(|w| w.path == "notify[0].hooks" && w.message.contains("post-create-typo"))
+        );
+    }
+
+    #[test]
+    fn test_hook_tests_validated() {
+        let yaml = r#"
+hooks:
+  worktree-post-create:
+    jobs:
+      - name: install
+        run: npm install
+tests:
+  - name: installs
+    hook: worktree-post-create
+  - name: installs
+    hook: worktree-post-remove
+  - hook: post-create-typo
+"#;
+        let config: YamlConfig = serde_yaml::from_str(yaml).unwrap();
+        let result = validate_config(&config).unwrap();
+        let errors: Vec<String> = result.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors.len(), 4, "got: {errors:?}");
+        assert!(errors[0].starts_with("tests[1]: duplicate test name"));
+        assert!(errors[1].contains("'worktree-post-remove' is not defined"));
+        assert_eq!(errors[2], "tests[2]: missing 'name'");
+        assert!(errors[3].contains("not a lifecycle hook"));
+    }
+
+    #[test]
+    fn test_link_paths_and_env_names_validated() {
+        let yaml = r#"
+links:
+  - url: git@example.com:org/ui-kit.git
+    path: vendor/ui-kit
+    env: UI_KIT_PATH
+  - url: git@example.com:org/schema.git
+    path: ../schema
+    env: 1SCHEMA
+hooks: {}
+"#;
+        let config: YamlConfig = serde_yaml::from_str(yaml).unwrap();
+        let result = validate_config(&config).unwrap();
+        let errors: Vec<String> = result.errors.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            errors,
+            vec![
+                "links[1].path: '../schema' must be a relative path inside the worktree",
+                "links[1].env: '1SCHEMA' is not a valid environment variable name",
+            ]
+        );
+    }
 }
@@ -938,6 +938,27 @@ pub(crate) fn resolve_command(
     job_name: Option<&str>,
     source_dir: &str,
 ) -> String {
+    if let Some(ref module) = job.wasm {
+        let env = super::environment::HookEnvironment::from_context(ctx);
+        let job_env = job.env.iter().flat_map(|e| e.keys());
+        // Split before substituting, so a branch name or other value that
+        // lands in `args:` stays inside the word it was written in.
+        let words = match job.args.as_deref().map(super::wasm::split_args) {
+            None => Vec::new(),
+            Some(Some(words)) => words,
+            Some(None) => return super::wasm::refusal("wasm job args: has unbalanced quotes"),
+        };
+        let args: Vec<String> = words
+            .iter()
+            .map(|w| template::substitute(w, ctx, job_name))
+            .collect();
+        return super::wasm::command(
+            &format!("{source_dir}/{module}"),
+            &args,
+            env.vars().keys().chain(job_env).map(String::as_str),
+            job.grants.as_deref().unwrap_or_default(),
+        );
+    }
     if let Some(ref run) = job.run {
         match run.resolve_for_current_os() {
             Some(cmd) => template::substitute(&cmd, ctx, job_name),
@@ -1134,6 +1155,77 @@ mod tests {
         assert_eq!(cmd, "bash .daft/hooks/setup.sh --verbose");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_command_wasm_passes_each_arg_as_one_literal_word() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let bin = dir.path().join("bin");
+        std::fs::create_dir(&bin).unwrap();
+        let fake = bin.join("wasmtime");
+        std::fs::write(
+            &fake,
+            "#!/bin/sh\nfor a in \"$@\"; do printf '%s\\n' \"$a\"; done\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&fake, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let ctx = HookContext::new(
+            HookType::PostCreate,
+            "checkout",
+            "/project",
+            dir.path(),
+            "origin",
+            "/project/main",
+            "/project/feat",
+            "feat/$(touch pwned-branch)",
+        );
+        let job = JobDef {
+            wasm: Some("gen.wasm".to_string()),
+            args: Some("--name {branch} 'x; touch pwned-args' $(id)".to_string()),
+            ..Default::default()
+        };
+        let cmd = resolve_command(&job, &ctx, Some("gen"), "hooks");
+
+        let out = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(&cmd)
+            .current_dir(dir.path())
+            .env("PATH", format!("{}:/usr/bin:/bin", bin.display()))
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "{cmd}");
+        let argv: Vec<String> = String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .map(String::from)
+            .collect();
+        let module_at = argv.iter().position(|a| a == "hooks/gen.wasm").unwrap();
+        assert_eq!(
+            argv[module_at + 1..],
+            [
+                "--name",
+                "feat/$(touch pwned-branch)",
+                "x; touch pwned-args",
+                "$(id)",
+            ]
+        );
+        assert!(!dir.path().join("pwned-branch").exists());
+        assert!(!dir.path().join("pwned-args").exists());
+    }
+
+    #[test]
+    fn test_resolve_command_wasm_refuses_unbalanced_args() {
+        let job = JobDef {
+            wasm: Some("gen.wasm".to_string()),
+            args: Some("'unterminated".to_string()),
+            ..Default::default()
+        };
+        let cmd = resolve_command(&job, &make_ctx(), Some("gen"), "hooks");
+        assert!(cmd.ends_with("exit 2"), "{cmd}");
+        assert!(!cmd.contains("wasmtime run"));
+    }
+
     #[test]
     fn test_execute_yaml_hook_empty_jobs() {
         let hook_def = HookDef::default();
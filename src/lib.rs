@@ -127,6 +127,25 @@ pub fn daft_state_dir() -> anyhow::Result<std::path::PathBuf> {
     Ok(state_dir.join("daft"))
 }
 
+/// [`get_clap_args`] with the user's `daft.defaults.<command>` flags spliced
+/// in front of the command-line arguments (see [`cli::defaults`]).
+///
+/// The key is read from the current repository's config when there is one,
+/// so a repo can set its own defaults, and from global config otherwise.
+pub fn get_clap_args_with_defaults<T: clap::CommandFactory>(expected_cmd: &str) -> Vec<String> {
+    let args = get_clap_args(expected_cmd);
+    let key = cli::defaults::config_key(expected_cmd);
+    let git = git::GitCommand::new(true);
+    let value = match git.config_get(&key) {
+        Ok(value) => value,
+        Err(_) => git.config_get_global(&key).ok().flatten(),
+    };
+    let Some(defaults) = value.and_then(|v| cli::defaults::split(&key, &v)) else {
+        return args;
+    };
+    cli::defaults::apply(&T::command(), &key, &defaults, &args)
+}
+
 /// Daft verb aliases that route through to worktree commands.
 const DAFT_VERBS: &[&str] = &[
     "adopt", "carry", "clone", "eject", "exec", "go", "init", "list", "merge", "prune", "push",
@@ -267,6 +286,7 @@ fn should_skip_background_tasks_impl(
     skip_startup_tasks || is_coordinator || is_test_mode
 }
 
+pub mod auto_backup;
 pub mod catalog;
 pub mod cli;
 pub mod commands;
@@ -274,6 +294,7 @@ pub mod completion_spinner;
 pub mod coordinator;
 pub mod core;
 pub mod cow_copy;
+pub mod deprecations;
 pub mod doctor;
 pub mod exec;
 pub mod executor;
@@ -290,6 +311,7 @@ pub mod output;
 pub mod prompt;
 pub mod shortcuts;
 pub mod skill;
+pub mod state_file;
 pub mod store;
 pub mod styles;
 pub mod suggest;
@@ -63,6 +63,12 @@ fn main() -> Result<()> {
         eprintln!("  -> {dir}");
     }
 
+    // First interactive run on a fresh machine: offer the setup wizard
+    // before the command, so its answers (layout, autocd) already apply.
+    if !skip_background {
+        commands::config::setup::maybe_run_first_run(argv);
+    }
+
     // Check for updates (reads cache, spawns background check if stale)
     let update_notification = if !skip_background {
         daft::update_check::maybe_check_for_update()
@@ -80,6 +86,11 @@ fn main() -> Result<()> {
         daft::log_clean::maybe_clean_logs();
     }
 
+    // Back up the repository when its daft.backup.interval has elapsed
+    if !skip_background {
+        daft::auto_backup::maybe_backup();
+    }
+
     // Route to the appropriate command based on invocation name
     let result = match resolved {
         // Git worktree extension commands (via symlinks)
@@ -129,6 +140,23 @@ fn main() -> Result<()> {
                         let _ = daft::log_clean::run_clean_logs();
                         return Ok(());
                     }
+                    "__backup" => {
+                        let _ = daft::auto_backup::run_scheduled_backup();
+                        return Ok(());
+                    }
+                    "__scheduled-run" => {
+                        // Internal: what `daft hooks schedule install` timers
+                        // run. argv[2] is the task name.
+                        let Some(task) = args.get(2) else {
+                            eprintln!("daft: __scheduled-run requires a task name");
+                            std::process::exit(2);
+                        };
+                        if let Err(e) = commands::run::run_scheduled(task) {
+                            eprintln!("daft __scheduled-run: {e:#}");
+                            std::process::exit(1);
+                        }
+                        return Ok(());
+                    }
                     "__refresh-forge" => {
                         let _ = commands::forge_cache::run_refresh_forge();
                         return Ok(());
@@ -198,8 +226,10 @@ fn main() -> Result<()> {
                     "multi-remote" => commands::multi_remote::run(),
                     "shared" => commands::shared::run(),
                     "release-notes" => commands::release_notes::run(),
+                    "whats-new" => commands::whats_new::run(),
                     "repo" => commands::repo::run(),
                     "skill" => commands::skill::run(),
+                    "workspace" => commands::workspace::run(),
                     "activate" => {
                         // Check for activate subcommands
                         if args.len() > 2 && args[2] == "shortcuts" {
@@ -209,6 +239,16 @@ fn main() -> Result<()> {
                         }
                     }
                     "shell-init" => commands::shell_init::run(),
+                    "env" => commands::env::run(),
+                    "auth" => commands::auth::run(),
+                    "remote" => commands::remote::run(),
+                    "clone-all" => commands::clone_all::run(),
+                    "backup" => commands::backup::run(),
+                    "which" => commands::which::run(),
+                    "consolidate" => commands::consolidate::run(),
+                    "stats" => commands::stats::run(),
+                    "template" => commands::template::run(),
+                    "pin" => commands::pin::run(),
                     // Daft verb aliases (short names)
                     "clone" => commands::clone::run(),
                     "init" => commands::init::run(),
@@ -227,6 +267,7 @@ fn main() -> Result<()> {
                     "eject" => commands::flow_eject::run(),
                     "exec" => commands::exec::run(),
                     "run" => commands::run::run(),
+                    "adopt-worktree" => commands::adopt_worktree::run(),
                     "file" => commands::file::run(),
                     // Worktree commands accessible via `daft worktree-<command>`
                     "worktree-clone" => commands::clone::run(),
@@ -265,6 +306,11 @@ fn main() -> Result<()> {
         }
     };
 
+    // Deprecations recorded during the run print once, after its output.
+    daft::deprecations::flush(&mut daft::output::CliOutput::new(
+        daft::output::OutputConfig::default(),
+    ));
+
     // Show update notification after command output (if available)
     if let Some(ref notification) = update_notification {
         daft::update_check::print_notification(notification);
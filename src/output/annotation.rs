@@ -25,6 +25,8 @@ pub enum AnnotationGlyph {
     DefaultBranch,
     /// A detached checkout nothing explains.
     Sandbox,
+    /// A detached checkout held at a ref by `daft pin`.
+    Pinned,
     /// A paused git operation.
     Operation(OpKind),
     /// The recorded intended branch disagrees with the checked-out branch.
@@ -38,6 +40,7 @@ impl AnnotationGlyph {
             Self::Current => styles::CURRENT_WORKTREE_SYMBOL,
             Self::DefaultBranch => styles::DEFAULT_BRANCH_SYMBOL,
             Self::Sandbox => styles::SANDBOX_SYMBOL,
+            Self::Pinned => styles::PINNED_SYMBOL,
             Self::Operation(op) => op.symbol(),
             Self::Drift => styles::DRIFT_SYMBOL,
         }
@@ -54,7 +57,8 @@ impl AnnotationGlyph {
 pub struct AnnotationSlots {
     /// Slot 1 — the current-worktree marker.
     pub current: bool,
-    /// Slot 2 — what this row *is*: default branch, or unexplained detachment.
+    /// Slot 2 — what this row *is*: default branch, pinned checkout, or
+    /// unexplained detachment.
     pub identity: bool,
     /// Slot 3 — what is *happening* to it: a paused operation.
     pub state: bool,
@@ -65,7 +69,9 @@ impl AnnotationSlots {
     pub fn for_rows(infos: &[WorktreeInfo]) -> Self {
         Self {
             current: infos.iter().any(|i| i.is_current),
-            identity: infos.iter().any(|i| i.is_default_branch || i.is_sandbox),
+            identity: infos
+                .iter()
+                .any(|i| i.is_default_branch || i.is_sandbox || i.pinned.is_some()),
             state: infos.iter().any(|i| i.op.is_some() || i.drifted),
         }
     }
@@ -98,11 +104,13 @@ impl AnnotationSlots {
             out.push(info.is_current.then_some(AnnotationGlyph::Current));
         }
         if self.identity {
-            // The default-branch marker outranks the sandbox marker: a row can
-            // only be one of them in practice, and this keeps the precedence
+            // The default-branch marker outranks the others: a row can only
+            // be one of them in practice, and this keeps the precedence
             // explicit rather than accidental.
             out.push(if info.is_default_branch {
                 Some(AnnotationGlyph::DefaultBranch)
+            } else if info.pinned.is_some() {
+                Some(AnnotationGlyph::Pinned)
             } else if info.is_sandbox {
                 Some(AnnotationGlyph::Sandbox)
             } else {
@@ -262,6 +270,7 @@ mod tests {
         // And none of them collides with the identity markers sharing the row.
         for s in &symbols {
             assert_ne!(*s, styles::SANDBOX_SYMBOL);
+            assert_ne!(*s, styles::PINNED_SYMBOL);
             assert_ne!(*s, styles::DEFAULT_BRANCH_SYMBOL);
             assert_ne!(*s, styles::CURRENT_WORKTREE_SYMBOL);
         }
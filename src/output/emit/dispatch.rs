@@ -92,10 +92,33 @@ pub fn emit<W: Write>(
         });
     }
 
+    if matches!(format, Format::Json | Format::Ndjson) {
+        // Deprecations that can't join the result (an array root, or recorded
+        // after it was written) go to stderr as JSON at the end of the run.
+        crate::deprecations::mark_structured_output();
+    }
+
     let headers = !args.no_headers;
     match (format, &payload) {
-        (Format::Json, p) => formats::json::emit(p, writer),
-        (Format::Ndjson, p) => formats::ndjson::emit(p, writer),
+        (Format::Json, p) => {
+            let mut value = formats::json::to_json_value(p);
+            if let serde_json::Value::Object(ref mut map) = value {
+                let deprecations = crate::deprecations::take();
+                if !deprecations.is_empty() {
+                    map.insert("deprecations".into(), serde_json::to_value(deprecations)?);
+                }
+            }
+            formats::json::emit(&value, writer)
+        }
+        (Format::Ndjson, p) => {
+            formats::ndjson::emit(p, writer)?;
+            let deprecations = crate::deprecations::take();
+            if !deprecations.is_empty() {
+                let record = serde_json::json!({ "deprecations": deprecations });
+                writeln!(writer, "{record}")?;
+            }
+            Ok(())
+        }
         (Format::Tsv, p) => formats::tsv::emit(p, headers, writer),
         (Format::Csv, p) => formats::csv::emit(p, headers, writer),
         (Format::Yaml, p) => formats::yaml::emit(p, writer),
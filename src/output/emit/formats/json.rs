@@ -4,9 +4,10 @@ use crate::output::emit::dispatch::EmitError;
 use crate::output::emit::payload::{Cell, EmitPayload, Matrix, Section, Table};
 use std::io::Write;
 
-pub fn emit<W: Write>(payload: &EmitPayload, writer: &mut W) -> Result<(), EmitError> {
-    let value = to_json_value(payload);
-    let rendered = serde_json::to_string_pretty(&value)?;
+/// Write a value built by [`to_json_value`] (plus anything the dispatcher
+/// adds to it).
+pub fn emit<W: Write>(value: &serde_json::Value, writer: &mut W) -> Result<(), EmitError> {
+    let rendered = serde_json::to_string_pretty(value)?;
     writer.write_all(rendered.as_bytes())?;
     writer.write_all(b"\n")?;
     Ok(())
@@ -74,7 +75,7 @@ mod tests {
 
     fn render(p: &EmitPayload) -> String {
         let mut buf = Vec::new();
-        emit(p, &mut buf).unwrap();
+        emit(&to_json_value(p), &mut buf).unwrap();
         String::from_utf8(buf).unwrap()
     }
 
@@ -5,6 +5,7 @@
 
 use crate::core::worktree::forge_ref::{ForgePrLookup, PrDecoration, PrStatus};
 use crate::core::worktree::list::{Stat, WorktreeInfo};
+use crate::settings::TimeFormat;
 use crate::styles;
 use pathdiff::diff_paths;
 use std::path::Path;
@@ -269,12 +270,18 @@ pub fn shorthand_from_seconds(secs: i64) -> String {
     }
 }
 
-/// Format a Unix timestamp as a shorthand age string, with optional dim styling.
-pub fn format_shorthand_age(timestamp: Option<i64>, now: i64, use_color: bool) -> String {
+/// Format a Unix timestamp as an age cell (see [`super::time::age`]), dimmed
+/// when older than a week.
+pub fn format_age(
+    timestamp: Option<i64>,
+    now: i64,
+    time_format: TimeFormat,
+    use_color: bool,
+) -> String {
     match timestamp {
         Some(ts) => {
             let secs = now - ts;
-            let text = shorthand_from_seconds(secs);
+            let text = super::time::age(ts, now, time_format);
             if use_color && is_old_seconds(secs) {
                 styles::dim(&text)
             } else {
@@ -389,6 +396,8 @@ pub struct ColumnContext<'a> {
     /// status glyph appended to the cell text (`#723 ✓`) so the signal
     /// survives `NO_COLOR` and pipes.
     pub colors: bool,
+    /// How the age cells read (`daft.ui.timeFormat`).
+    pub time_format: TimeFormat,
 }
 
 /// Format head status using line-level counts: combined staged+unstaged
@@ -454,6 +463,9 @@ pub fn format_worktree_status(info: &WorktreeInfo) -> String {
         if info.drifted {
             return "drifted".to_string();
         }
+        if let Some(pinned) = &info.pinned {
+            return String::from("pinned @ ") + pinned;
+        }
         if info.identity_source == Some(crate::core::worktree::identity::IdentitySource::Persisted)
         {
             return match &info.last_commit_hash {
@@ -522,14 +534,14 @@ pub fn compute_column_values(info: &WorktreeInfo, ctx: &ColumnContext) -> Column
     let branch_age_secs = info.branch_creation_timestamp.map(|ts| ctx.now - ts);
     let branch_age = info
         .branch_creation_timestamp
-        .map(|ts| shorthand_from_seconds(ctx.now - ts))
+        .map(|ts| super::time::age(ts, ctx.now, ctx.time_format))
         .unwrap_or_default();
     let is_old_branch = branch_age_secs.is_some_and(is_old_seconds);
 
     let commit_age_secs = info.last_commit_timestamp.map(|ts| ctx.now - ts);
     let last_commit_age = info
         .last_commit_timestamp
-        .map(|ts| shorthand_from_seconds(ctx.now - ts))
+        .map(|ts| super::time::age(ts, ctx.now, ctx.time_format))
         .unwrap_or_default();
     let is_old_commit = commit_age_secs.is_some_and(is_old_seconds);
 
@@ -603,6 +615,7 @@ mod tests {
             project_root: Path::new("/"),
             cwd: Path::new("/"),
             now: 0,
+            time_format: TimeFormat::Relative,
             stat: Stat::Summary,
             forge_prs: None,
             colors: false,
@@ -665,6 +678,7 @@ mod tests {
             project_root: Path::new("/"),
             cwd: Path::new("/"),
             now: 0,
+            time_format: TimeFormat::Relative,
             stat: Stat::Summary,
             forge_prs: Some(&lookup),
             colors: false,
@@ -717,6 +731,7 @@ mod tests {
             project_root: Path::new("/"),
             cwd: Path::new("/"),
             now: 0,
+            time_format: TimeFormat::Relative,
             stat: Stat::Summary,
             forge_prs: Some(&lookup),
             colors: true,
@@ -753,6 +768,7 @@ mod tests {
             project_root: Path::new("/"),
             cwd: Path::new("/"),
             now: 0,
+            time_format: TimeFormat::Relative,
             stat: Stat::Summary,
             forge_prs: Some(&lookup),
             colors: true,
@@ -1079,6 +1095,7 @@ mod tests {
             project_root: Path::new("/"),
             cwd: Path::new("/"),
             now: 0,
+            time_format: TimeFormat::Relative,
             stat: Stat::Summary,
             forge_prs: None,
             colors: false,
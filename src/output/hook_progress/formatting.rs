@@ -86,6 +86,107 @@ pub(super) fn format_header_lines(
     }
 }
 
+/// The `┃` gutter that prefixes every line of a job's block.
+pub(super) fn pipe(use_color: bool) -> String {
+    if use_color {
+        format!("{ORANGE}\u{2503}{}", styles::RESET)
+    } else {
+        "\u{2503}".to_string()
+    }
+}
+
+/// The `❯` between a job's name and its command.
+pub(super) fn arrow(use_color: bool) -> String {
+    if use_color {
+        format!("{ORANGE}\u{276f}{}", styles::RESET)
+    } else {
+        "\u{276f}".to_string()
+    }
+}
+
+/// Permanent lines for a finished job: the `┃  name ❯` heading, the job's
+/// full output (or `No output`), and a blank separator. `quiet` keeps only
+/// the heading and separator.
+pub(super) fn format_finished_lines(
+    name: &str,
+    output: &[String],
+    quiet: bool,
+    use_color: bool,
+) -> Vec<String> {
+    let pipe = pipe(use_color);
+    let finished_name = if use_color {
+        format!("{ORANGE}{name}{}", styles::RESET)
+    } else {
+        name.to_string()
+    };
+    let mut lines = vec![format!("{pipe}  {finished_name} {}", arrow(use_color))];
+
+    if !quiet && !output.is_empty() {
+        lines.extend(output.iter().map(|line| format!("{pipe}  {line}")));
+    }
+    if !quiet && output.is_empty() {
+        lines.push(if use_color {
+            format!("{pipe}  {DARK_GREY}{ITALIC}No output{}", styles::RESET)
+        } else {
+            format!("{pipe}  No output")
+        });
+    }
+
+    lines.push(String::new());
+    lines
+}
+
+/// The permanent `┃  name (skip) reason` line for a skipped job.
+pub(super) fn format_skip_line(name: &str, reason: &str, use_color: bool) -> String {
+    let pipe = pipe(use_color);
+    if use_color {
+        format!(
+            "{pipe}  {ORANGE}{name}{} {DARK_GREY}(skip){} {YELLOW}{reason}{}",
+            styles::RESET,
+            styles::RESET,
+            styles::RESET
+        )
+    } else {
+        format!("{pipe}  {name} (skip) {reason}")
+    }
+}
+
+/// Permanent lines announcing a job dispatched to the background.
+pub(super) fn format_background_lines(
+    name: &str,
+    description: Option<&str>,
+    use_color: bool,
+) -> Vec<String> {
+    let cyan = "\x1b[38;5;80m";
+
+    let blue_pipe = if use_color {
+        format!("{BLUE}\u{2503}{}", styles::RESET)
+    } else {
+        "\u{2503}".to_string()
+    };
+
+    let mut lines = vec![if use_color {
+        format!(
+            "{blue_pipe}  {BLUE}{name}{} {cyan}(background){}",
+            styles::RESET,
+            styles::RESET
+        )
+    } else {
+        format!("{blue_pipe}  {name} (background)")
+    }];
+
+    if let Some(desc) = description {
+        lines.push(if use_color {
+            format!("{blue_pipe}  {DARK_GREY}{desc}{}", styles::RESET)
+        } else {
+            format!("{blue_pipe}  {desc}")
+        });
+    }
+
+    lines.push(String::new());
+    lines
+}
+
 /// Generate the summary lines (separator + totals + per-job results).
 pub(super) fn format_summary_lines(
     jobs: &[super::JobResultEntry],
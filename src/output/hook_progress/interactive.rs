@@ -1,6 +1,6 @@
 //! Rich (indicatif) renderer for interactive terminals.
 
-use super::formatting::{BLUE, DARK_GREY, ITALIC, ORANGE};
+use super::formatting::{DARK_GREY, ORANGE};
 use super::{JobOutcome, JobResultEntry};
 use crate::settings::HookOutputConfig;
 use crate::styles;
@@ -88,17 +88,8 @@ impl HookProgressRenderer {
     }
 
     fn create(config: &HookOutputConfig, mp: MultiProgress, use_color: bool) -> Self {
-        let pipe_str = if use_color {
-            format!("{ORANGE}\u{2503}{}", styles::RESET)
-        } else {
-            "\u{2503}".to_string()
-        };
-
-        let arrow = if use_color {
-            format!("{ORANGE}\u{276f}{}", styles::RESET)
-        } else {
-            "\u{276f}".to_string()
-        };
+        let pipe_str = super::formatting::pipe(use_color);
+        let arrow = super::formatting::arrow(use_color);
 
         let spinner_style = ProgressStyle::with_template(&format!(
             "{pipe_str}  {{spinner}} {{msg}}"
@@ -397,8 +388,6 @@ impl HookProgressRenderer {
         show_duration: bool,
         command_preview: Option<&str>,
     ) {
-        use super::formatting::YELLOW;
-
         let stored_preview = if let Some(state) = self.jobs.remove(name) {
             self.remove_job_bars(&state);
             state.command_preview
@@ -418,18 +407,13 @@ impl HookProgressRenderer {
                 ))
                 .ok();
         } else {
-            let msg = if self.use_color {
-                format!(
-                    "{}  {ORANGE}{name}{} {DARK_GREY}(skip){} {YELLOW}{reason}{}",
-                    self.pipe_str,
-                    styles::RESET,
-                    styles::RESET,
-                    styles::RESET
-                )
-            } else {
-                format!("{}  {name} (skip) {reason}", self.pipe_str)
-            };
-            self.mp.println(msg).ok();
+            self.mp
+                .println(super::formatting::format_skip_line(
+                    name,
+                    reason,
+                    self.use_color,
+                ))
+                .ok();
         }
 
         self.finished_jobs.push(JobResultEntry {
@@ -491,44 +475,18 @@ impl HookProgressRenderer {
                 ))
                 .ok();
         } else {
-            // Print heading as a permanent line. Because the spinner is already
-            // cleared, mp.println() inserts this above remaining *active*
-            // spinners only — i.e. after all previously finished jobs' output.
-            let finished_name = if self.use_color {
-                format!("{ORANGE}{name}{}", styles::RESET)
-            } else {
-                name.to_string()
-            };
-            self.mp
-                .println(format!(
-                    "{}  {finished_name} {}",
-                    self.pipe_str, self.arrow_str
-                ))
-                .ok();
-
-            // Print full output as permanent lines below the heading
-            let has_output = !state.output_buffer.is_empty();
-            if !self.config.quiet && has_output {
-                for line in &state.output_buffer {
-                    self.mp.println(format!("{}  {line}", self.pipe_str)).ok();
-                }
+            // Print the heading and full output as permanent lines. Because
+            // the spinner is already cleared, mp.println() inserts them above
+            // remaining *active* spinners only — i.e. after all previously
+            // finished jobs' output.
+            for line in super::formatting::format_finished_lines(
+                name,
+                &state.output_buffer,
+                self.config.quiet,
+                self.use_color,
+            ) {
+                self.mp.println(line).ok();
             }
-
-            if !self.config.quiet && !has_output {
-                let msg = if self.use_color {
-                    format!(
-                        "{}  {DARK_GREY}{ITALIC}No output{}",
-                        self.pipe_str,
-                        styles::RESET
-                    )
-                } else {
-                    format!("{}  No output", self.pipe_str)
-                };
-                self.mp.println(msg).ok();
-            }
-
-            // Empty line after each job's section
-            self.mp.println(String::new()).ok();
         }
 
         // Record for summary
@@ -563,35 +521,9 @@ impl HookProgressRenderer {
     /// Uses `mp.println()` for permanent output (same as `finish_job`),
     /// so lines survive MultiProgress redraws and appear reliably.
     pub fn show_background_job(&self, name: &str, description: Option<&str>) {
-        let cyan = "\x1b[38;5;80m";
-
-        let blue_pipe = if self.use_color {
-            format!("{BLUE}\u{2503}{}", styles::RESET)
-        } else {
-            "\u{2503}".to_string()
-        };
-
-        let name_line = if self.use_color {
-            format!(
-                "{blue_pipe}  {BLUE}{name}{} {cyan}(background){}",
-                styles::RESET,
-                styles::RESET
-            )
-        } else {
-            format!("{blue_pipe}  {name} (background)")
-        };
-        self.mp.println(name_line).ok();
-
-        if let Some(desc) = description {
-            let desc_line = if self.use_color {
-                format!("{blue_pipe}  {DARK_GREY}{desc}{}", styles::RESET)
-            } else {
-                format!("{blue_pipe}  {desc}")
-            };
-            self.mp.println(desc_line).ok();
+        for line in super::formatting::format_background_lines(name, description, self.use_color) {
+            self.mp.println(line).ok();
         }
-
-        self.mp.println(String::new()).ok();
     }
 
     /// Extract finished job results (for use in callers that need them).
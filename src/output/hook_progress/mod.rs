@@ -3,13 +3,15 @@
 mod formatting;
 mod interactive;
 mod plain;
+mod split;
 
 pub(crate) use formatting::DEFAULT_NAME_COLUMN_WIDTH;
 pub(crate) use formatting::format_duration;
 pub use interactive::HookProgressRenderer;
 pub use plain::PlainHookRenderer;
+pub use split::SplitHookRenderer;
 
-use crate::settings::HookOutputConfig;
+use crate::settings::{HookOutputConfig, HookOutputLayout};
 use std::time::Duration;
 
 /// Outcome of a completed job.
@@ -42,10 +44,13 @@ pub enum HookRenderer {
     Progress(Box<HookProgressRenderer>),
     /// Plain text output for CI, pipes, and non-TTY environments.
     Plain(PlainHookRenderer),
+    /// A fixed region per running job (`daft.hooks.output.layout = split`).
+    Split(Box<SplitHookRenderer>),
 }
 
 impl HookRenderer {
     /// Auto-detect: use rich renderer if stderr is a TTY, plain otherwise.
+    /// The split layout also needs stdin to be a TTY for its keybindings.
     /// Returns a hidden renderer when `DAFT_TESTING` is set to keep test output clean.
     pub fn auto(config: &HookOutputConfig) -> Self {
         if formatting::output_suppressed() {
@@ -53,6 +58,12 @@ impl HookRenderer {
         }
         use std::io::IsTerminal;
         if std::io::stderr().is_terminal() {
+            if config.layout == HookOutputLayout::Split
+                && !config.quiet
+                && std::io::stdin().is_terminal()
+            {
+                return HookRenderer::Split(Box::new(SplitHookRenderer::new(config)));
+            }
             HookRenderer::Progress(Box::new(HookProgressRenderer::new(config)))
         } else {
             let mut plain = PlainHookRenderer::with_verbose(config.verbose);
@@ -95,6 +106,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.print_header(hook_name, target),
             HookRenderer::Plain(r) => r.print_header(hook_name, target),
+            HookRenderer::Split(r) => r.print_header(hook_name, target),
         }
     }
 
@@ -102,6 +114,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.start_job(name, None),
             HookRenderer::Plain(r) => r.start_job(name, None),
+            HookRenderer::Split(r) => r.start_job(name, None),
         }
     }
 
@@ -118,6 +131,9 @@ impl HookRenderer {
             HookRenderer::Plain(r) => {
                 r.start_job_with_description(name, description, command_preview);
             }
+            HookRenderer::Split(r) => {
+                r.start_job_with_description(name, description, command_preview);
+            }
         }
     }
 
@@ -125,6 +141,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.update_job_output(name, line),
             HookRenderer::Plain(r) => r.update_job_output(name, line),
+            HookRenderer::Split(r) => r.update_job_output(name, line),
         }
     }
 
@@ -132,6 +149,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.finish_job_success(name, duration),
             HookRenderer::Plain(r) => r.finish_job_success(name, duration),
+            HookRenderer::Split(r) => r.finish_job_success(name, duration),
         }
     }
 
@@ -139,6 +157,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.finish_job_failure(name, duration),
             HookRenderer::Plain(r) => r.finish_job_failure(name, duration),
+            HookRenderer::Split(r) => r.finish_job_failure(name, duration),
         }
     }
 
@@ -157,6 +176,9 @@ impl HookRenderer {
             HookRenderer::Plain(r) => {
                 r.finish_job_skipped(name, reason, duration, show_duration, command_preview);
             }
+            HookRenderer::Split(r) => {
+                r.finish_job_skipped(name, reason, duration, show_duration, command_preview);
+            }
         }
     }
 
@@ -164,6 +186,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.finish_job_cancelled(name, duration),
             HookRenderer::Plain(r) => r.finish_job_cancelled(name, duration),
+            HookRenderer::Split(r) => r.finish_job_cancelled(name, duration),
         }
     }
 
@@ -171,6 +194,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.set_name_column_width(width),
             HookRenderer::Plain(r) => r.set_name_column_width(width),
+            HookRenderer::Split(r) => r.set_name_column_width(width),
         }
     }
 
@@ -178,6 +202,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.show_background_job(name, description),
             HookRenderer::Plain(r) => r.show_background_job(name, description),
+            HookRenderer::Split(r) => r.show_background_job(name, description),
         }
     }
 
@@ -192,6 +217,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.push_finished_job(entry),
             HookRenderer::Plain(r) => r.push_finished_job(entry),
+            HookRenderer::Split(r) => r.push_finished_job(entry),
         }
     }
 
@@ -199,6 +225,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.print_summary(total_duration),
             HookRenderer::Plain(r) => r.print_summary(total_duration),
+            HookRenderer::Split(r) => r.print_summary(total_duration),
         }
     }
 
@@ -206,6 +233,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.take_finished_jobs(),
             HookRenderer::Plain(r) => r.take_finished_jobs(),
+            HookRenderer::Split(r) => r.take_finished_jobs(),
         }
     }
 
@@ -213,6 +241,7 @@ impl HookRenderer {
         match self {
             HookRenderer::Progress(r) => r.println(msg),
             HookRenderer::Plain(r) => r.println(msg),
+            HookRenderer::Split(r) => r.println(msg),
         }
     }
 }
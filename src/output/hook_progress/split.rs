@@ -0,0 +1,803 @@
+//! Split-view renderer for interactive terminals.
+//!
+//! Selected by `daft.hooks.output.layout = split`. Each running job owns a
+//! fixed pane — its spinner row plus `tailLines` rows of its latest output —
+//! that stays in place while jobs run side by side, the way `docker buildx`
+//! lays out parallel steps. `1`-`9` expand that job to fill the view with its
+//! output as it streams, `Tab`/`Shift-Tab` cycle through jobs, and `Esc`
+//! returns to the split. A finished job leaves the view and its full output
+//! prints permanently above it, exactly as the stacked renderer prints it.
+//!
+//! The view runs on the inline TUI driver in a thread of its own while at
+//! least one job is running; output between jobs (the header, the summary)
+//! goes straight to stderr.
+
+use super::formatting::{self, DARK_GREY, ORANGE, RowState};
+use super::{JobOutcome, JobResultEntry};
+use crate::output::tui::{LiveScreen, TuiRenderer, ansi_line, enable_raw_mode_guard};
+use crate::settings::HookOutputConfig;
+use crate::styles;
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::{Frame, layout::Position, text::Line, widgets::Paragraph};
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Same frames as the stacked renderer's spinner.
+const SPINNER: [char; 10] = [
+    '\u{2807}', '\u{2819}', '\u{2839}', '\u{2838}', '\u{283c}', '\u{2834}', '\u{2826}', '\u{2827}',
+    '\u{2807}', '\u{280f}',
+];
+
+enum SplitEvent {
+    Start {
+        name: String,
+        description: Option<String>,
+        preview: Option<String>,
+    },
+    Output {
+        name: String,
+        line: String,
+    },
+    Finish {
+        name: String,
+        end: JobEnd,
+    },
+    Print(Vec<String>),
+}
+
+/// How a job left the view.
+#[derive(Debug, Clone)]
+enum JobEnd {
+    Success(Duration),
+    Failure(Duration),
+    Cancelled(Duration),
+    Skipped {
+        reason: String,
+        preview: Option<String>,
+    },
+}
+
+/// The permanent lines a finished job leaves behind — the same lines the
+/// stacked renderer prints for it.
+#[derive(Debug, Clone)]
+struct EndFormat {
+    compact: bool,
+    quiet: bool,
+    name_column_width: usize,
+    use_color: bool,
+}
+
+impl EndFormat {
+    fn lines(
+        &self,
+        name: &str,
+        end: &JobEnd,
+        preview: Option<&str>,
+        output: &[String],
+    ) -> Vec<String> {
+        let row = |state: RowState, preview: Option<&str>| {
+            vec![formatting::format_compact_row(
+                name,
+                preview,
+                state,
+                self.name_column_width,
+                self.use_color,
+            )]
+        };
+        match end {
+            JobEnd::Success(_) | JobEnd::Failure(_) if !self.compact => {
+                formatting::format_finished_lines(name, output, self.quiet, self.use_color)
+            }
+            JobEnd::Success(duration) => row(
+                RowState::Success {
+                    duration: *duration,
+                },
+                preview,
+            ),
+            JobEnd::Failure(duration) => row(
+                RowState::Failure {
+                    duration: *duration,
+                },
+                preview,
+            ),
+            // Cancellation is only reachable from exec paths, which always
+            // enable compact finalization.
+            JobEnd::Cancelled(_) if !self.compact => Vec::new(),
+            JobEnd::Cancelled(duration) => row(
+                RowState::Cancelled {
+                    duration: *duration,
+                },
+                preview,
+            ),
+            JobEnd::Skipped {
+                preview: skip_preview,
+                ..
+            } if self.compact => row(RowState::Skipped, skip_preview.as_deref().or(preview)),
+            JobEnd::Skipped { reason, .. } => {
+                vec![formatting::format_skip_line(name, reason, self.use_color)]
+            }
+        }
+    }
+}
+
+struct Pane {
+    name: String,
+    description: Option<String>,
+    preview: Option<String>,
+    started: Instant,
+    output: Vec<String>,
+}
+
+/// The live view: one pane per running job, plus the permanent lines
+/// waiting to be printed above it.
+struct SplitScreen {
+    panes: Vec<Pane>,
+    /// Name of the expanded job, if any.
+    focus: Option<String>,
+    scrollback: Vec<String>,
+    height: u16,
+    tail_lines: usize,
+    timer_delay: Duration,
+    end_format: EndFormat,
+    tick: usize,
+    cancelled: bool,
+}
+
+impl SplitScreen {
+    fn new(config: &HookOutputConfig, end_format: EndFormat, height: u16) -> Self {
+        Self {
+            panes: Vec::new(),
+            focus: None,
+            scrollback: Vec::new(),
+            height,
+            tail_lines: config.tail_lines as usize,
+            timer_delay: Duration::from_secs(u64::from(config.timer_delay_secs)),
+            end_format,
+            tick: 0,
+            cancelled: false,
+        }
+    }
+
+    fn use_color(&self) -> bool {
+        self.end_format.use_color
+    }
+
+    fn focused(&self) -> Option<usize> {
+        let focus = self.focus.as_ref()?;
+        self.panes.iter().position(|p| &p.name == focus)
+    }
+
+    fn cycle(&mut self, forward: bool) {
+        let count = self.panes.len();
+        if count == 0 {
+            return;
+        }
+        let next = match (self.focused(), forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        };
+        self.focus = Some(self.panes[next].name.clone());
+    }
+
+    /// The view's rows, top to bottom: the panes, then a key-hint row.
+    fn rows(&self, now: Instant) -> Vec<String> {
+        if self.panes.is_empty() {
+            return Vec::new();
+        }
+        let room = usize::from(self.height).saturating_sub(1);
+        let count = self.panes.len();
+        let mut rows = Vec::new();
+
+        if let Some(focused) = self.focused() {
+            let body = room.saturating_sub(count);
+            for (i, pane) in self.panes.iter().enumerate() {
+                rows.push(self.header(i, pane, now));
+                if i == focused {
+                    rows.extend(self.body(pane, body));
+                }
+            }
+        } else if count > room {
+            let shown = room.saturating_sub(1);
+            for (i, pane) in self.panes.iter().enumerate().take(shown) {
+                rows.push(self.header(i, pane, now));
+            }
+            rows.push(self.dim(&format!("\u{2026} {} more running", count - shown)));
+        } else {
+            let per_pane = self.tail_lines.min((room - count) / count);
+            for (i, pane) in self.panes.iter().enumerate() {
+                rows.push(self.header(i, pane, now));
+                rows.extend(self.body(pane, per_pane));
+            }
+        }
+
+        rows.truncate(room);
+        rows.push(self.key_hints());
+        rows
+    }
+
+    fn header(&self, index: usize, pane: &Pane, now: Instant) -> String {
+        let use_color = self.use_color();
+        let spinner = SPINNER[self.tick % SPINNER.len()];
+        let key = if index < 9 {
+            (index + 1).to_string()
+        } else {
+            " ".to_string()
+        };
+        let mut header = if use_color {
+            format!(
+                "{}  {spinner} {DARK_GREY}{key}{} {ORANGE}{}{}  {}",
+                formatting::pipe(true),
+                styles::RESET,
+                pane.name,
+                styles::RESET,
+                formatting::arrow(true)
+            )
+        } else {
+            format!("\u{2503}  {spinner} {key} {}  \u{276f}", pane.name)
+        };
+        if let Some(preview) = &pane.preview {
+            header.push(' ');
+            header.push_str(preview);
+        }
+        if let Some(description) = &pane.description {
+            header.push_str("  ");
+            header.push_str(&self.dim(description));
+        }
+        let elapsed = now.saturating_duration_since(pane.started);
+        if elapsed >= self.timer_delay {
+            let secs = elapsed.as_secs();
+            header.push_str(&format!(
+                " [{:02}:{:02}:{:02}]",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            ));
+        }
+        header
+    }
+
+    /// The last `rows` output lines of a job, padded so its pane keeps its
+    /// size while the job is quiet.
+    fn body(&self, pane: &Pane, rows: usize) -> Vec<String> {
+        let pipe = formatting::pipe(self.use_color());
+        let start = pane.output.len().saturating_sub(rows);
+        let mut lines: Vec<String> = pane.output[start..]
+            .iter()
+            .map(|line| format!("{pipe}  {line}"))
+            .collect();
+        lines.resize(rows, pipe);
+        lines
+    }
+
+    fn key_hints(&self) -> String {
+        let hints = match self.focused() {
+            Some(_) => "esc back to all jobs \u{b7} tab next job",
+            None => "1-9 expand a job \u{b7} tab cycle",
+        };
+        self.dim(&format!("   {hints}"))
+    }
+
+    fn dim(&self, text: &str) -> String {
+        if self.use_color() {
+            format!("{DARK_GREY}{text}{}", styles::RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+impl LiveScreen for SplitScreen {
+    type Event = SplitEvent;
+
+    fn viewport_height(&self, _extra_rows: u16) -> u16 {
+        self.height
+    }
+
+    fn render(&self, frame: &mut Frame<'_>, final_frame: bool) {
+        let area = frame.area();
+        if final_frame {
+            // Leave the region blank with the cursor at its top, so what
+            // prints next (the summary) follows the finished jobs directly.
+            frame.set_cursor_position(Position { x: 0, y: area.y });
+            return;
+        }
+        let lines: Vec<Line<'static>> = self
+            .rows(Instant::now())
+            .iter()
+            .map(|row| ansi_line(row))
+            .collect();
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    fn apply_event(&mut self, event: &SplitEvent) {
+        match event {
+            SplitEvent::Start {
+                name,
+                description,
+                preview,
+            } => self.panes.push(Pane {
+                name: name.clone(),
+                description: description.clone(),
+                preview: preview.clone(),
+                started: Instant::now(),
+                output: Vec::new(),
+            }),
+            SplitEvent::Output { name, line } => {
+                if let Some(pane) = self.panes.iter_mut().find(|p| &p.name == name) {
+                    pane.output.push(line.clone());
+                }
+            }
+            SplitEvent::Finish { name, end } => {
+                let pane = self
+                    .panes
+                    .iter()
+                    .position(|p| &p.name == name)
+                    .map(|i| self.panes.remove(i));
+                if self.focus.as_ref() == Some(name) {
+                    self.focus = None;
+                }
+                let (preview, output) = pane.map(|p| (p.preview, p.output)).unwrap_or_default();
+                let lines = self
+                    .end_format
+                    .lines(name, end, preview.as_deref(), &output);
+                self.scrollback.extend(lines);
+            }
+            SplitEvent::Print(lines) => self.scrollback.extend(lines.iter().cloned()),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.cancelled
+    }
+
+    fn mark_cancelled(&mut self) {
+        self.cancelled = true;
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    fn on_tick(&mut self, render_start_elapsed: Duration) {
+        self.tick = (render_start_elapsed.as_millis() / 80) as usize;
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if let Some(pane) = self.panes.get(index) {
+                    self.focus = Some(pane.name.clone());
+                }
+            }
+            KeyCode::Char('0') | KeyCode::Esc => self.focus = None,
+            KeyCode::Tab => self.cycle(true),
+            KeyCode::BackTab => self.cycle(false),
+            _ => {}
+        }
+    }
+
+    fn take_scrollback(&mut self) -> Vec<Line<'static>> {
+        std::mem::take(&mut self.scrollback)
+            .iter()
+            .map(|line| ansi_line(line))
+            .collect()
+    }
+}
+
+struct View {
+    sender: mpsc::Sender<SplitEvent>,
+    handle: JoinHandle<()>,
+}
+
+/// Split-view hook renderer. See the module docs.
+pub struct SplitHookRenderer {
+    config: HookOutputConfig,
+    use_color: bool,
+    name_column_width: usize,
+    running: Vec<String>,
+    finished_jobs: Vec<JobResultEntry>,
+    view: Option<View>,
+}
+
+impl SplitHookRenderer {
+    pub fn new(config: &HookOutputConfig) -> Self {
+        Self {
+            config: config.clone(),
+            use_color: styles::colors_enabled_stderr(),
+            name_column_width: formatting::DEFAULT_NAME_COLUMN_WIDTH,
+            running: Vec::new(),
+            finished_jobs: Vec::new(),
+            view: None,
+        }
+    }
+
+    fn end_format(&self) -> EndFormat {
+        EndFormat {
+            compact: self.config.compact_finalization,
+            quiet: self.config.quiet,
+            name_column_width: self.name_column_width,
+            use_color: self.use_color,
+        }
+    }
+
+    /// Two thirds of the terminal, so the lines above stay in sight.
+    fn view_height() -> u16 {
+        let rows = crossterm::terminal::size().map_or(24, |(_, rows)| rows);
+        (rows * 2 / 3).max(6).min(rows.saturating_sub(1).max(1))
+    }
+
+    fn start_view(&mut self) {
+        let screen = SplitScreen::new(&self.config, self.end_format(), Self::view_height());
+        let (sender, receiver) = mpsc::channel();
+        let handle = std::thread::spawn(move || {
+            let raw_mode = enable_raw_mode_guard();
+            let result = TuiRenderer::new(screen, receiver).run();
+            drop(raw_mode);
+            if result.is_ok_and(|screen| screen.is_cancelled()) {
+                reraise_interrupt();
+            }
+        });
+        self.view = Some(View { sender, handle });
+    }
+
+    fn stop_view(&mut self) {
+        if let Some(view) = self.view.take() {
+            drop(view.sender);
+            let _ = view.handle.join();
+        }
+    }
+
+    /// Send an event to the live view. Returns it back when no view is
+    /// running (or the view was interrupted).
+    fn send(&self, event: SplitEvent) -> Result<(), SplitEvent> {
+        match &self.view {
+            Some(view) => view.sender.send(event).map_err(|e| e.0),
+            None => Err(event),
+        }
+    }
+
+    /// Print permanent lines: above the live view while one runs, straight
+    /// to stderr otherwise.
+    fn emit(&self, lines: Vec<String>) {
+        if let Err(SplitEvent::Print(lines)) = self.send(SplitEvent::Print(lines)) {
+            for line in lines {
+                eprintln!("{line}");
+            }
+        }
+    }
+
+    pub fn set_name_column_width(&mut self, width: usize) {
+        self.name_column_width = width;
+    }
+
+    pub fn print_header(&self, hook_name: &str, target: Option<&str>) {
+        self.emit(formatting::format_header_lines(
+            self.config.banner,
+            hook_name,
+            target,
+            self.use_color,
+            false,
+        ));
+    }
+
+    pub fn start_job(&mut self, name: &str, command_preview: Option<&str>) {
+        self.start_job_with_description(name, None, command_preview);
+    }
+
+    pub fn start_job_with_description(
+        &mut self,
+        name: &str,
+        description: Option<&str>,
+        command_preview: Option<&str>,
+    ) {
+        if self.view.is_none() {
+            self.start_view();
+        }
+        self.running.push(name.to_string());
+        let _ = self.send(SplitEvent::Start {
+            name: name.to_string(),
+            description: description.map(String::from),
+            preview: command_preview.map(String::from),
+        });
+    }
+
+    pub fn update_job_output(&mut self, name: &str, line: &str) {
+        let _ = self.send(SplitEvent::Output {
+            name: name.to_string(),
+            line: line.to_string(),
+        });
+    }
+
+    pub fn finish_job_success(&mut self, name: &str, duration: Duration) {
+        self.finish(
+            name,
+            JobEnd::Success(duration),
+            JobOutcome::Success,
+            duration,
+        );
+    }
+
+    pub fn finish_job_failure(&mut self, name: &str, duration: Duration) {
+        self.finish(
+            name,
+            JobEnd::Failure(duration),
+            JobOutcome::Failed,
+            duration,
+        );
+    }
+
+    pub fn finish_job_cancelled(&mut self, name: &str, duration: Duration) {
+        // JobOutcome has no Cancelled variant; record as Failed so callers
+        // that inspect finished_jobs treat a cancelled step as non-success.
+        self.finish(
+            name,
+            JobEnd::Cancelled(duration),
+            JobOutcome::Failed,
+            duration,
+        );
+    }
+
+    pub fn finish_job_skipped(
+        &mut self,
+        name: &str,
+        reason: &str,
+        duration: Duration,
+        show_duration: bool,
+        command_preview: Option<&str>,
+    ) {
+        self.finish(
+            name,
+            JobEnd::Skipped {
+                reason: reason.to_string(),
+                preview: command_preview.map(String::from),
+            },
+            JobOutcome::Skipped {
+                reason: reason.to_string(),
+                show_duration,
+            },
+            duration,
+        );
+    }
+
+    fn finish(&mut self, name: &str, end: JobEnd, outcome: JobOutcome, duration: Duration) {
+        self.running.retain(|n| n != name);
+        if let Err(SplitEvent::Finish { end, .. }) = self.send(SplitEvent::Finish {
+            name: name.to_string(),
+            end,
+        }) {
+            // A job that never reached the view (skipped before it started).
+            for line in self.end_format().lines(name, &end, None, &[]) {
+                eprintln!("{line}");
+            }
+        }
+        if self.running.is_empty() {
+            self.stop_view();
+        }
+        self.finished_jobs.push(JobResultEntry {
+            name: name.to_string(),
+            outcome,
+            duration,
+        });
+    }
+
+    pub fn print_summary(&self, total_duration: Duration) {
+        self.emit(formatting::format_summary_lines(
+            &self.finished_jobs,
+            total_duration,
+            self.use_color,
+        ));
+    }
+
+    /// Add a pre-built result entry (e.g., for background jobs).
+    pub fn push_finished_job(&mut self, entry: JobResultEntry) {
+        self.finished_jobs.push(entry);
+    }
+
+    pub fn show_background_job(&self, name: &str, description: Option<&str>) {
+        self.emit(formatting::format_background_lines(
+            name,
+            description,
+            self.use_color,
+        ));
+    }
+
+    pub fn take_finished_jobs(&mut self) -> Vec<JobResultEntry> {
+        std::mem::take(&mut self.finished_jobs)
+    }
+
+    pub fn println(&self, msg: &str) {
+        self.emit(vec![msg.to_string()]);
+    }
+}
+
+impl Drop for SplitHookRenderer {
+    fn drop(&mut self) {
+        self.stop_view();
+    }
+}
+
+/// Ctrl-C reached the view as a key press (raw mode). Deliver it as the
+/// SIGINT it would have been, so the command's interrupt handling runs.
+fn reraise_interrupt() {
+    #[cfg(unix)]
+    let _ = nix::sys::signal::raise(nix::sys::signal::Signal::SIGINT);
+    #[cfg(not(unix))]
+    std::process::exit(130);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn screen(height: u16, tail_lines: u32) -> SplitScreen {
+        let config = HookOutputConfig {
+            tail_lines,
+            timer_delay_secs: 3600,
+            ..Default::default()
+        };
+        let end_format = EndFormat {
+            compact: false,
+            quiet: false,
+            name_column_width: formatting::DEFAULT_NAME_COLUMN_WIDTH,
+            use_color: false,
+        };
+        SplitScreen::new(&config, end_format, height)
+    }
+
+    fn start(screen: &mut SplitScreen, name: &str) {
+        screen.apply_event(&SplitEvent::Start {
+            name: name.to_string(),
+            description: None,
+            preview: None,
+        });
+    }
+
+    fn output(screen: &mut SplitScreen, name: &str, lines: usize) {
+        for i in 0..lines {
+            screen.apply_event(&SplitEvent::Output {
+                name: name.to_string(),
+                line: format!("{name} {i}"),
+            });
+        }
+    }
+
+    fn key(screen: &mut SplitScreen, code: KeyCode) {
+        screen.on_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    fn view_rows(screen: &SplitScreen) -> Vec<String> {
+        screen.rows(Instant::now())
+    }
+
+    #[test]
+    fn each_job_gets_a_fixed_pane() {
+        let mut s = screen(20, 3);
+        start(&mut s, "lint");
+        start(&mut s, "test");
+        output(&mut s, "lint", 5);
+
+        let rows = view_rows(&s);
+        assert_eq!(rows.len(), 2 * (1 + 3) + 1);
+        assert!(rows[0].ends_with("1 lint  \u{276f}"));
+        assert_eq!(
+            &rows[1..4],
+            ["\u{2503}  lint 2", "\u{2503}  lint 3", "\u{2503}  lint 4"]
+        );
+        assert!(rows[4].ends_with("2 test  \u{276f}"));
+        assert_eq!(&rows[5..8], ["\u{2503}", "\u{2503}", "\u{2503}"]);
+        assert!(rows[8].contains("1-9 expand a job"));
+    }
+
+    #[test]
+    fn panes_shrink_to_fit_the_view() {
+        let mut s = screen(7, 6);
+        for name in ["a", "b", "c"] {
+            start(&mut s, name);
+        }
+        // 6 rows for panes: 3 headers + 1 output row each.
+        let rows = view_rows(&s);
+        assert_eq!(rows.len(), 7);
+        assert!(rows[2].ends_with("2 b  \u{276f}"));
+
+        let mut s = screen(4, 6);
+        for name in ["a", "b", "c", "d", "e"] {
+            start(&mut s, name);
+        }
+        let rows = view_rows(&s);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[2], "\u{2026} 3 more running");
+    }
+
+    #[test]
+    fn expanding_a_job_gives_it_the_view() {
+        let mut s = screen(10, 2);
+        start(&mut s, "lint");
+        start(&mut s, "test");
+        output(&mut s, "test", 20);
+
+        key(&mut s, KeyCode::Char('2'));
+        let rows = view_rows(&s);
+        assert_eq!(rows.len(), 10);
+        assert!(rows[0].ends_with("1 lint  \u{276f}"));
+        assert!(rows[1].ends_with("2 test  \u{276f}"));
+        // 9 rows for panes, 2 headers: 7 rows of the expanded job's output.
+        assert_eq!(rows[2], "\u{2503}  test 13");
+        assert_eq!(rows[8], "\u{2503}  test 19");
+        assert!(rows[9].contains("esc back to all jobs"));
+
+        key(&mut s, KeyCode::Esc);
+        let rows = view_rows(&s);
+        assert_eq!(rows.len(), 2 * (1 + 2) + 1);
+        assert!(rows[6].contains("1-9 expand a job"));
+    }
+
+    #[test]
+    fn tab_cycles_and_unknown_keys_are_ignored() {
+        let mut s = screen(10, 2);
+        start(&mut s, "a");
+        start(&mut s, "b");
+
+        key(&mut s, KeyCode::Char('7'));
+        assert_eq!(s.focused(), None);
+        key(&mut s, KeyCode::Tab);
+        assert_eq!(s.focused(), Some(0));
+        key(&mut s, KeyCode::Tab);
+        assert_eq!(s.focused(), Some(1));
+        key(&mut s, KeyCode::Tab);
+        assert_eq!(s.focused(), Some(0));
+        key(&mut s, KeyCode::BackTab);
+        assert_eq!(s.focused(), Some(1));
+        key(&mut s, KeyCode::Char('x'));
+        assert_eq!(s.focused(), Some(1));
+    }
+
+    #[test]
+    fn a_finished_job_leaves_its_output_in_scrollback() {
+        let mut s = screen(10, 2);
+        start(&mut s, "lint");
+        start(&mut s, "test");
+        output(&mut s, "lint", 2);
+        key(&mut s, KeyCode::Char('1'));
+
+        s.apply_event(&SplitEvent::Finish {
+            name: "lint".to_string(),
+            end: JobEnd::Success(Duration::from_secs(1)),
+        });
+
+        assert_eq!(s.focused(), None);
+        assert_eq!(s.panes.len(), 1);
+        let scrollback: Vec<String> = s.take_scrollback().iter().map(|l| l.to_string()).collect();
+        assert_eq!(
+            scrollback,
+            [
+                "\u{2503}  lint \u{276f}",
+                "\u{2503}  lint 0",
+                "\u{2503}  lint 1",
+                ""
+            ]
+        );
+        assert!(s.take_scrollback().is_empty());
+    }
+
+    #[test]
+    fn a_skipped_job_without_a_pane_prints_its_skip_line() {
+        let mut s = screen(10, 2);
+        s.apply_event(&SplitEvent::Finish {
+            name: "lint".to_string(),
+            end: JobEnd::Skipped {
+                reason: "no changes".to_string(),
+                preview: None,
+            },
+        });
+        let scrollback: Vec<String> = s.take_scrollback().iter().map(|l| l.to_string()).collect();
+        assert_eq!(scrollback, ["\u{2503}  lint (skip) no changes"]);
+    }
+}
@@ -30,6 +30,7 @@ pub mod pager;
 pub(crate) mod palette;
 pub(crate) mod term_guard;
 mod test;
+pub mod time;
 pub mod timeline;
 pub mod tui;
 
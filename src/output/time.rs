@@ -0,0 +1,149 @@
+//! Displayed times: worktree ages, hook history, audit and snapshot dates.
+//!
+//! Every human-facing timestamp goes through here so `daft.ui.timeFormat`
+//! switches them together. Structured output (`--format json` and friends)
+//! does not: it keeps RFC 3339 strings and adds raw epoch seconds, whatever
+//! the setting says.
+//!
+//! Absolute forms use the local timezone (`TZ` is honored). Timestamps are
+//! Unix epoch seconds; `chrono` callers pass `.timestamp()`.
+
+use super::format::shorthand_from_seconds;
+use crate::settings::TimeFormat;
+use chrono::{DateTime, Local, SecondsFormat, TimeZone};
+
+/// The `daft.ui.timeFormat` in effect here, for commands that don't
+/// otherwise load settings. Repo-local config wins over global; an
+/// unreadable config reads as relative.
+pub fn configured() -> TimeFormat {
+    crate::settings::DaftSettings::load_local_or_global()
+        .map(|s| s.ui_time_format)
+        .unwrap_or_default()
+}
+
+/// A table-cell time: the compact age (`3d`) in relative mode, the full
+/// timestamp otherwise.
+pub fn age(ts: i64, now: i64, format: TimeFormat) -> String {
+    match format {
+        TimeFormat::Relative => shorthand_from_seconds(now - ts),
+        _ => stamp(ts, format),
+    }
+}
+
+/// A time in running text: `3d ago` in relative mode, the full timestamp
+/// otherwise.
+pub fn ago(ts: i64, now: i64, format: TimeFormat) -> String {
+    match format {
+        TimeFormat::Relative => format!("{} ago", shorthand_from_seconds(now - ts)),
+        _ => stamp(ts, format),
+    }
+}
+
+/// A time shown with room for detail (a job's `started:` line): the age
+/// followed by the timestamp in relative mode, the timestamp alone
+/// otherwise.
+pub fn detailed(ts: i64, now: i64, format: TimeFormat) -> String {
+    match format {
+        TimeFormat::Relative => format!("{} ({})", ago(ts, now, format), stamp(ts, format)),
+        _ => stamp(ts, format),
+    }
+}
+
+/// A full timestamp. Relative mode has no absolute form of its own, so it
+/// uses the sortable `YYYY-MM-DD HH:MM:SS` daft has always shown next to
+/// ages.
+pub fn stamp(ts: i64, format: TimeFormat) -> String {
+    let Some(local) = local(ts) else {
+        return "unknown".to_string();
+    };
+    match format {
+        TimeFormat::Relative => local.format("%Y-%m-%d %H:%M:%S").to_string(),
+        TimeFormat::Iso => local.to_rfc3339_opts(SecondsFormat::Secs, false),
+        TimeFormat::Locale => local.format(locale_pattern(&current_locale())).to_string(),
+    }
+}
+
+/// A time of day for rows already grouped under a dated heading: `HH:MM:SS`
+/// in relative mode, the full timestamp otherwise.
+pub fn clock(ts: i64, format: TimeFormat) -> String {
+    match format {
+        TimeFormat::Relative => local(ts)
+            .map(|t| t.format("%H:%M:%S").to_string())
+            .unwrap_or_else(|| "unknown".to_string()),
+        _ => stamp(ts, format),
+    }
+}
+
+fn local(ts: i64) -> Option<DateTime<Local>> {
+    Local.timestamp_opt(ts, 0).single()
+}
+
+/// The locale that governs time formatting, by POSIX precedence.
+fn current_locale() -> String {
+    ["LC_ALL", "LC_TIME", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default()
+}
+
+/// `strftime` pattern for a locale name like `en_US.UTF-8` or `de_DE`. Only
+/// the field order and separators vary; month names stay out so the output
+/// never depends on locale data daft doesn't ship.
+fn locale_pattern(locale: &str) -> &'static str {
+    let name = locale.split(['.', '@']).next().unwrap_or_default();
+    let mut parts = name.split(['_', '-']);
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next().unwrap_or_default();
+    match (language, region) {
+        (_, "US" | "PH") => "%m/%d/%Y %-I:%M %p",
+        ("zh" | "ja" | "ko", _) | (_, "CN" | "JP" | "KR" | "TW") => "%Y/%m/%d %H:%M",
+        ("sv" | "lt" | "hu", _) | (_, "CA") => "%Y-%m-%d %H:%M",
+        ("de" | "ru" | "pl" | "fi" | "nb" | "nn" | "da" | "cs" | "sk" | "tr" | "uk", _) => {
+            "%d.%m.%Y %H:%M"
+        }
+        ("" | "C" | "POSIX", _) => "%Y-%m-%d %H:%M",
+        _ => "%d/%m/%Y %H:%M",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_mode_keeps_the_compact_ages() {
+        assert_eq!(age(0, 3 * 86400, TimeFormat::Relative), "3d");
+        assert_eq!(ago(0, 2 * 3600, TimeFormat::Relative), "2h ago");
+        assert!(detailed(0, 60, TimeFormat::Relative).starts_with("1m ago ("));
+    }
+
+    #[test]
+    fn absolute_modes_ignore_now() {
+        let ts = 1_760_000_000;
+        assert_eq!(age(ts, 0, TimeFormat::Iso), stamp(ts, TimeFormat::Iso));
+        assert_eq!(
+            ago(ts, 0, TimeFormat::Locale),
+            stamp(ts, TimeFormat::Locale)
+        );
+        assert_eq!(clock(ts, TimeFormat::Iso), stamp(ts, TimeFormat::Iso));
+    }
+
+    #[test]
+    fn iso_stamp_carries_an_offset() {
+        let iso = stamp(1_760_000_000, TimeFormat::Iso);
+        assert_eq!(iso.len(), "2025-10-09T08:53:20+00:00".len(), "{iso}");
+        assert!(iso.contains('T'));
+    }
+
+    #[test]
+    fn locale_patterns_follow_region_then_language() {
+        assert_eq!(locale_pattern("en_US.UTF-8"), "%m/%d/%Y %-I:%M %p");
+        assert_eq!(locale_pattern("en_GB.UTF-8"), "%d/%m/%Y %H:%M");
+        assert_eq!(locale_pattern("de_DE"), "%d.%m.%Y %H:%M");
+        assert_eq!(locale_pattern("ja_JP.UTF-8"), "%Y/%m/%d %H:%M");
+        assert_eq!(locale_pattern("sv_SE"), "%Y-%m-%d %H:%M");
+        assert_eq!(locale_pattern("C.UTF-8"), "%Y-%m-%d %H:%M");
+        assert_eq!(locale_pattern(""), "%Y-%m-%d %H:%M");
+    }
+}
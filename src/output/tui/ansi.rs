@@ -0,0 +1,168 @@
+//! Convert ANSI-colored text into ratatui lines.
+//!
+//! Hook job output and the hook renderer's own formatting are ANSI strings;
+//! drawing them inside a ratatui viewport needs them as styled spans. Only
+//! SGR sequences (`ESC [ … m`) carry over; other escape sequences and control
+//! characters are dropped so a job can't move the cursor inside the viewport.
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Parse one line of ANSI-colored text. A carriage return keeps only what
+/// follows it (the last frame of an in-place progress bar); tabs become
+/// spaces.
+pub fn ansi_line(text: &str) -> Line<'static> {
+    let text = text.rsplit('\r').next().unwrap_or_default();
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut style = Style::default();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => {
+                let sgr = match chars.peek() {
+                    Some('[') => {
+                        chars.next();
+                        let mut params = String::new();
+                        let mut terminator = None;
+                        for p in chars.by_ref() {
+                            if ('\x40'..='\x7e').contains(&p) {
+                                terminator = Some(p);
+                                break;
+                            }
+                            params.push(p);
+                        }
+                        (terminator == Some('m')).then_some(params)
+                    }
+                    Some(']') => {
+                        // OSC (hyperlinks, titles): skip to BEL or ESC \.
+                        while let Some(p) = chars.next() {
+                            if p == '\x07' || (p == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                                break;
+                            }
+                        }
+                        None
+                    }
+                    _ => {
+                        chars.next();
+                        None
+                    }
+                };
+                if let Some(params) = sgr {
+                    if !current.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut current), style));
+                    }
+                    style = apply_sgr(style, &params);
+                }
+            }
+            '\t' => current.push_str("    "),
+            c if c.is_control() => {}
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        spans.push(Span::styled(current, style));
+    }
+    Line::from(spans)
+}
+
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    let codes: Vec<u16> = params
+        .split([';', ':'])
+        .map(|p| p.parse().unwrap_or(0))
+        .collect();
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 => style = Style::default(),
+            1 => style = style.add_modifier(Modifier::BOLD),
+            2 => style = style.add_modifier(Modifier::DIM),
+            3 => style = style.add_modifier(Modifier::ITALIC),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            7 => style = style.add_modifier(Modifier::REVERSED),
+            9 => style = style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style = style.remove_modifier(Modifier::ITALIC),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            27 => style = style.remove_modifier(Modifier::REVERSED),
+            29 => style = style.remove_modifier(Modifier::CROSSED_OUT),
+            n @ 30..=37 => style = style.fg(Color::Indexed((n - 30) as u8)),
+            n @ 90..=97 => style = style.fg(Color::Indexed((n - 90 + 8) as u8)),
+            39 => style.fg = None,
+            n @ 40..=47 => style = style.bg(Color::Indexed((n - 40) as u8)),
+            n @ 100..=107 => style = style.bg(Color::Indexed((n - 100 + 8) as u8)),
+            49 => style.bg = None,
+            n @ (38 | 48) => {
+                let color = match codes.get(i + 1) {
+                    Some(5) => {
+                        let color = codes.get(i + 2).map(|&c| Color::Indexed(c as u8));
+                        i += 2;
+                        color
+                    }
+                    Some(2) => {
+                        let rgb = |k: usize| codes.get(i + k).copied().unwrap_or(0) as u8;
+                        let color = Some(Color::Rgb(rgb(2), rgb(3), rgb(4)));
+                        i += 4;
+                        color
+                    }
+                    _ => None,
+                };
+                if let Some(color) = color {
+                    style = if n == 38 {
+                        style.fg(color)
+                    } else {
+                        style.bg(color)
+                    };
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_is_one_unstyled_span() {
+        let line = ansi_line("hello world");
+        assert_eq!(line.to_string(), "hello world");
+        assert_eq!(line.spans.len(), 1);
+        assert_eq!(line.spans[0].style, Style::default());
+    }
+
+    #[test]
+    fn sgr_sequences_become_styles() {
+        let line = ansi_line("\x1b[38;5;208mname\x1b[0m \x1b[1;31merr\x1b[22m!");
+        assert_eq!(line.to_string(), "name err!");
+        assert_eq!(line.spans[0].style.fg, Some(Color::Indexed(208)));
+        assert_eq!(line.spans[1].style, Style::default());
+        assert_eq!(line.spans[2].style.fg, Some(Color::Indexed(1)));
+        assert!(line.spans[2].style.add_modifier.contains(Modifier::BOLD));
+        assert!(!line.spans[3].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(line.spans[3].style.fg, Some(Color::Indexed(1)));
+    }
+
+    #[test]
+    fn truecolor_and_background() {
+        let line = ansi_line("\x1b[38;2;1;2;3;44mx");
+        assert_eq!(line.spans[0].style.fg, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(line.spans[0].style.bg, Some(Color::Indexed(4)));
+    }
+
+    #[test]
+    fn cursor_movement_and_osc_are_dropped() {
+        let line = ansi_line("a\x1b[2Kb\x1b]8;;http://x\x1b\\c\x07d");
+        assert_eq!(line.to_string(), "abcd");
+    }
+
+    #[test]
+    fn carriage_return_keeps_the_last_frame() {
+        assert_eq!(ansi_line("10%\r50%\r100%").to_string(), "100%");
+        assert_eq!(ansi_line("a\tb").to_string(), "a    b");
+    }
+}
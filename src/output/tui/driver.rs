@@ -3,10 +3,13 @@ use super::render;
 use super::state::TuiState;
 use crate::core::worktree::sync_dag::DagEvent;
 use crate::output::deferred_warn::{self, LiveRegionGuard};
-use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     Frame, Terminal, TerminalOptions, Viewport,
+    backend::CrosstermBackend,
     layout::{Constraint, Layout, Position},
+    text::{Line, Span},
+    widgets::{Paragraph, Widget},
 };
 use std::io::Write;
 use std::sync::Arc;
@@ -56,6 +59,16 @@ pub trait LiveScreen {
     /// Advance animations. Called at the driver's tick rate with the elapsed
     /// time since the render loop started.
     fn on_tick(&mut self, render_start_elapsed: Duration);
+
+    /// Handle a key press other than Ctrl-C. Screens without keybindings
+    /// ignore keys.
+    fn on_key(&mut self, _key: KeyEvent) {}
+
+    /// Lines to print permanently above the viewport before the next draw.
+    /// Screens whose content lives entirely in the viewport have none.
+    fn take_scrollback(&mut self) -> Vec<Line<'static>> {
+        Vec::new()
+    }
 }
 
 /// Drives the inline TUI render loop, consuming collector events and updating
@@ -126,6 +139,7 @@ impl<S: LiveScreen> TuiRenderer<S> {
         // (completion, channel disconnect, Ctrl-C).
         macro_rules! final_draw_and_return {
             () => {{
+                insert_scrollback(&mut terminal, self.state.take_scrollback())?;
                 terminal.draw(|frame| self.state.render(frame, true))?;
                 drop(terminal);
                 if self.state.is_cancelled() {
@@ -151,6 +165,7 @@ impl<S: LiveScreen> TuiRenderer<S> {
             }
 
             // Render current state.
+            insert_scrollback(&mut terminal, self.state.take_scrollback())?;
             terminal.draw(|frame| self.state.render(frame, false))?;
 
             // Process all pending events.
@@ -169,20 +184,21 @@ impl<S: LiveScreen> TuiRenderer<S> {
                 }
             }
 
-            // Poll for keyboard events (Ctrl-C). Non-blocking. If a Ctrl-C
-            // is observed, flip the optional cancel signal so the producer
+            // Poll for keyboard events. Non-blocking. If a Ctrl-C is
+            // observed, flip the optional cancel signal so the producer
             // exits cooperatively, mark the screen cancelled, and emit a
-            // final draw.
+            // final draw. Other keys go to the screen.
             if event::poll(Duration::from_millis(0)).unwrap_or(false)
                 && let Ok(Event::Key(key)) = event::read()
-                && key.code == KeyCode::Char('c')
-                && key.modifiers.contains(KeyModifiers::CONTROL)
             {
-                if let Some(sig) = &self.cancel_signal {
-                    sig.store(true, Ordering::Relaxed);
+                if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    if let Some(sig) = &self.cancel_signal {
+                        sig.store(true, Ordering::Relaxed);
+                    }
+                    self.state.mark_cancelled();
+                    final_draw_and_return!();
                 }
-                self.state.mark_cancelled();
-                final_draw_and_return!();
+                self.state.on_key(key);
             }
 
             // Tick spinner animation.
@@ -196,6 +212,55 @@ impl<S: LiveScreen> TuiRenderer<S> {
     }
 }
 
+/// Print `lines` permanently above the inline viewport, hard-wrapped to the
+/// terminal width so each one is fully kept.
+fn insert_scrollback(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stderr>>,
+    lines: Vec<Line<'static>>,
+) -> anyhow::Result<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let size = terminal.size()?;
+    let rows = wrap_lines(lines, size.width);
+    for chunk in rows.chunks(usize::from(size.height.max(1))) {
+        terminal.insert_before(chunk.len() as u16, |buf| {
+            Paragraph::new(chunk.to_vec()).render(buf.area, buf);
+        })?;
+    }
+    Ok(())
+}
+
+/// Split each line into rows no wider than `width` cells, keeping styles.
+fn wrap_lines(lines: Vec<Line<'static>>, width: u16) -> Vec<Line<'static>> {
+    let width = usize::from(width.max(1));
+    let mut rows = Vec::new();
+    for line in lines {
+        if line.width() <= width {
+            rows.push(line);
+            continue;
+        }
+        let mut row: Vec<Span<'static>> = Vec::new();
+        let mut row_width = 0;
+        for grapheme in line.styled_graphemes(ratatui::style::Style::default()) {
+            let cell_width = Span::raw(grapheme.symbol).width();
+            if row_width + cell_width > width && !row.is_empty() {
+                rows.push(Line::from(std::mem::take(&mut row)));
+                row_width = 0;
+            }
+            match row.last_mut() {
+                Some(span) if span.style == grapheme.style => {
+                    span.content.to_mut().push_str(grapheme.symbol);
+                }
+                _ => row.push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
+            }
+            row_width += cell_width;
+        }
+        rows.push(Line::from(row));
+    }
+    rows
+}
+
 impl TuiState {
     /// Phase header rows: one row per phase plus a label row when phases
     /// exist; zero phases = no header at all (daft list).
@@ -522,4 +587,16 @@ mod tests {
         assert_eq!(state.viewport_height(0), 2 + 2);
         assert_eq!(state.viewport_height(3), 2 + 2 + 3);
     }
+
+    #[test]
+    fn wrap_lines_splits_long_lines_and_keeps_styles() {
+        use ratatui::style::{Color, Style};
+        let red = Style::default().fg(Color::Red);
+        let line = Line::from(vec![Span::raw("abcd"), Span::styled("efgh", red)]);
+        let rows = wrap_lines(vec![line, Line::raw("ok")], 3);
+        let text: Vec<String> = rows.iter().map(|r| r.to_string()).collect();
+        assert_eq!(text, ["abc", "def", "gh", "ok"]);
+        assert_eq!(rows[1].spans[1].style, red);
+        assert_eq!(wrap_lines(vec![Line::raw("")], 3).len(), 1);
+    }
 }
@@ -62,6 +62,9 @@ pub struct LiveTableConfig {
     /// Unlike per-cell patch state this survives collection completing —
     /// the refresh is out-of-band — but cancel clears it like any shimmer.
     pub forge_prs_loading: bool,
+    /// How the age cells read (`daft.ui.timeFormat`). Post-set after
+    /// `TuiState::new` like `forge_prs`.
+    pub time_format: crate::settings::TimeFormat,
 }
 
 pub struct LiveTable {
@@ -387,6 +390,7 @@ mod tests {
             seeded_fields: FieldSet::EMPTY,
             annotation_slots: Default::default(),
             forge_prs: None,
+            time_format: crate::settings::TimeFormat::Relative,
             forge_prs_loading: false,
         }
     }
@@ -3,6 +3,7 @@
 //! Uses ratatui with Viewport::Inline to render an operation header
 //! and worktree status table that update in-place as tasks execute.
 
+mod ansi;
 pub mod catalog_table;
 mod columns;
 mod driver;
@@ -13,6 +14,7 @@ mod render;
 pub mod shared_picker;
 mod state;
 
+pub use ansi::ansi_line;
 pub use catalog_table::{
     CatalogEvent, CatalogRepoCells, CatalogTable, CatalogWorktreeCells, tree_glyph,
 };
@@ -48,6 +48,8 @@ pub struct TableConfig {
     /// it (post-set into `LiveTableConfig`, the same pattern `daft list` uses).
     /// Sync/prune serve the cache snapshot as-is — no mid-run refresh swap.
     pub forge_prs: Option<crate::core::worktree::forge_ref::ForgePrLookup>,
+    /// How the age cells read (`daft.ui.timeFormat`), post-set the same way.
+    pub time_format: crate::settings::TimeFormat,
 }
 
 /// Result returned after the TUI completes.
@@ -152,6 +154,7 @@ impl OperationTable {
         // Post-set like `unowned_start_index`: TuiState::new stays untouched
         // for callers without forge data.
         state.live.cfg.forge_prs = self.config.forge_prs;
+        state.live.cfg.time_format = self.config.time_format;
 
         let mut renderer =
             TuiRenderer::new(state, self.receiver).with_extra_rows(self.config.extra_rows);
@@ -190,6 +193,7 @@ mod tests {
             partition_by_owner: true,
             seeded_fields: FieldSet::EMPTY,
             forge_prs: None,
+            time_format: crate::settings::TimeFormat::Relative,
         };
         assert_eq!(cfg_silent.verbosity, 0);
 
@@ -203,6 +207,7 @@ mod tests {
             partition_by_owner: true,
             seeded_fields: FieldSet::EMPTY,
             forge_prs: None,
+            time_format: crate::settings::TimeFormat::Relative,
         };
         assert!(cfg_verbose.verbosity >= 1);
     }
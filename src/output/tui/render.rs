@@ -124,6 +124,7 @@ pub fn render_table(state: &TuiState, frame: &mut Frame, area: Rect) {
         // The TUI always styles cells, so the PR status rides in color and
         // the cell text stays the bare number.
         colors: true,
+        time_format: state.live.cfg.time_format,
     };
 
     // Pre-compute all column values for sizing and reuse.
@@ -1218,6 +1219,7 @@ fn render_annotation_cell(info: &WorktreeInfo, slots: AnnotationSlots) -> Cell<'
                     AnnotationGlyph::Current => Color::Cyan,
                     AnnotationGlyph::DefaultBranch => Color::LightMagenta,
                     AnnotationGlyph::Sandbox => Color::DarkGray,
+                    AnnotationGlyph::Pinned => Color::Blue,
                     // Operations and drift share the attention colour with
                     // the status column, and with the plain renderer's yellow.
                     AnnotationGlyph::Operation(_) | AnnotationGlyph::Drift => Color::Yellow,
@@ -1511,6 +1513,7 @@ mod tests {
             stat: Stat::Lines,
             forge_prs: None,
             colors: true,
+            time_format: crate::settings::TimeFormat::Relative,
         };
         let vals = compute_column_values(&info, &ctx);
 
@@ -1575,6 +1578,7 @@ mod tests {
             stat: Stat::Lines,
             forge_prs: None,
             colors: true,
+            time_format: crate::settings::TimeFormat::Relative,
         };
         let vals = compute_column_values(&info, &ctx);
 
@@ -1631,6 +1635,7 @@ mod tests {
             stat: Stat::Summary,
             forge_prs: None,
             colors: true,
+            time_format: crate::settings::TimeFormat::Relative,
         };
         let vals = compute_column_values(&info, &ctx);
 
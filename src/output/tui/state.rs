@@ -204,6 +204,7 @@ impl TuiState {
             // pattern as `unowned_start_index` below).
             forge_prs: None,
             forge_prs_loading: false,
+            time_format: crate::settings::TimeFormat::Relative,
         };
         let mut live = LiveTable::new(worktree_infos, cfg);
         live.unowned_start_index = unowned_start_index;
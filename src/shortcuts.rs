@@ -195,6 +195,26 @@ pub fn resolve(name: &str) -> &str {
     name
 }
 
+/// Checkout shortcuts that create a branch: their shell wrappers pass `-b`,
+/// so they stand for `daft start` rather than `daft go`.
+pub const BRANCH_CREATING: &[&str] = &["gwtcb", "gwcob", "gcbw"];
+
+/// Shortcut aliases for `command`, across all styles, in table order.
+///
+/// For `git-worktree-checkout`, `creates_branch` picks the `-b` aliases
+/// ([`BRANCH_CREATING`]) or the plain checkout ones.
+pub fn aliases_for(command: &str, creates_branch: bool) -> Vec<&'static str> {
+    SHORTCUTS
+        .iter()
+        .filter(|s| s.command == command)
+        .filter(|s| {
+            command != "git-worktree-checkout"
+                || BRANCH_CREATING.contains(&s.alias) == creates_branch
+        })
+        .map(|s| s.alias)
+        .collect()
+}
+
 /// Returns all shortcuts for a given style.
 pub fn shortcuts_for_style(style: ShortcutStyle) -> Vec<&'static Shortcut> {
     SHORTCUTS.iter().filter(|s| s.style == style).collect()
@@ -234,6 +254,23 @@ mod tests {
         assert_eq!(resolve("gprune"), "git-worktree-prune");
     }
 
+    #[test]
+    fn test_aliases_for_splits_checkout_by_branch_creation() {
+        assert_eq!(
+            aliases_for("git-worktree-checkout", false),
+            ["gwtco", "gwco", "gcw"]
+        );
+        assert_eq!(
+            aliases_for("git-worktree-checkout", true),
+            ["gwtcb", "gwcob", "gcbw"]
+        );
+        assert_eq!(
+            aliases_for("git-worktree-prune", false),
+            ["gwtprune", "gprune"]
+        );
+        assert!(aliases_for("git-worktree-exec", false).is_empty());
+    }
+
     #[test]
     fn test_resolve_unknown() {
         assert_eq!(resolve("unknown"), "unknown");
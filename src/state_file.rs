@@ -0,0 +1,231 @@
+//! Crash-safe reads and writes for daft's small state files.
+//!
+//! The files under the config directory (`repos.json`, `hints.json`, the
+//! update-check and trust-prune caches) and per-repository files like
+//! `.daft/previous-worktree` are rewritten whole on every change. Writing
+//! them in place means a crash or a full disk mid-write leaves a truncated
+//! file that every later command then fails to parse. [`write_atomic`]
+//! writes a same-directory temp file and renames it into place, so a reader
+//! sees either the old contents or the new ones, never a torn file.
+//!
+//! Each file carries a `version` field. A file that is unreadable anyway
+//! (hand-edited, damaged on disk) or stamped with a version this daft doesn't
+//! know (written by a newer daft) is renamed aside to
+//! `<name>.corrupt-<unix-ts>.bak` by [`back_up_corrupt`] rather than deleted
+//! or misread, and `daft doctor` lists the backups left behind
+//! ([`corrupt_backups`]).
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::output::deferred_warn;
+
+/// Marker in the file name of a backed-up corrupt state file.
+const CORRUPT_MARKER: &str = ".corrupt-";
+
+/// Replace `path` with `contents` atomically, creating parent directories.
+///
+/// The temp file gets a random name so concurrent writers never collide, is
+/// flushed to disk before the rename so a crash can't leave the renamed file
+/// pointing at unwritten blocks, and lives in the destination directory so
+/// the rename never crosses filesystems. A file being replaced keeps its
+/// permissions; a new one gets the temp file's owner-only mode.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)
+        .with_context(|| format!("Failed to create temp file in {}", dir.display()))?;
+    tmp.write_all(contents)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    if let Ok(meta) = fs::metadata(path) {
+        tmp.as_file()
+            .set_permissions(meta.permissions())
+            .with_context(|| format!("Failed to copy permissions of {}", path.display()))?;
+    }
+    tmp.as_file()
+        .sync_all()
+        .with_context(|| format!("Failed to flush {}", path.display()))?;
+    tmp.persist(path)
+        .map_err(|e| e.error)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+/// Load a JSON state file, falling back to `T::default()` when it is absent.
+///
+/// A file that exists but doesn't parse, or whose `version` is not one of
+/// `1..=version`, is backed up with [`back_up_corrupt`] and reported once
+/// through the deferred-warning channel; the command carries on with
+/// defaults, and the next save writes a healthy file. Only a failure to
+/// *read* the file is an error.
+pub fn load_json_or_recover<T: DeserializeOwned + Default>(
+    path: &Path,
+    what: &str,
+    version: u32,
+) -> Result<T> {
+    Ok(load_json_checked(path, what, version)?.unwrap_or_default())
+}
+
+/// Like [`load_json_or_recover`], for state with no sensible default:
+/// `None` when the file is absent or was just backed up.
+///
+/// A file without a `version` field predates versioning and is left to
+/// `T`'s own deserializer to accept or reject.
+pub fn load_json_checked<T: DeserializeOwned>(
+    path: &Path,
+    what: &str,
+    version: u32,
+) -> Result<Option<T>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(e)
+                .with_context(|| format!("Failed to read {what} from {}", path.display()));
+        }
+    };
+    let problem = match serde_json::from_str::<serde_json::Value>(&contents) {
+        Ok(value) => match value.get("version").map(serde_json::Value::as_u64) {
+            Some(Some(found)) if found == 0 || found > u64::from(version) => {
+                format!("has version {found}, which this daft doesn't know")
+            }
+            Some(None) => "has a non-numeric version".to_string(),
+            _ => match serde_json::from_value(value) {
+                Ok(state) => return Ok(Some(state)),
+                Err(e) => format!("was unreadable ({e})"),
+            },
+        },
+        Err(e) => format!("was unreadable ({e})"),
+    };
+    let note = match back_up_corrupt(path) {
+        Ok(backup) => format!("backed it up to {}", backup.display()),
+        Err(backup_err) => format!("could not back it up ({backup_err:#})"),
+    };
+    deferred_warn::warn(format!(
+        "warning: daft {what} at {} {problem}; {note} and started fresh.",
+        path.display()
+    ));
+    Ok(None)
+}
+
+/// Rename an unreadable state file aside so it isn't lost when daft starts
+/// fresh. The backup sits next to the original as
+/// `<name>.corrupt-<unix-ts>.bak`.
+pub fn back_up_corrupt(path: &Path) -> Result<PathBuf> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("state");
+    let backup = path.with_file_name(format!("{name}{CORRUPT_MARKER}{ts}.bak"));
+    fs::rename(path, &backup)
+        .with_context(|| format!("Failed to back up corrupt {}", path.display()))?;
+    Ok(backup)
+}
+
+/// Backups of corrupt state files left in `dir`, sorted by name.
+pub fn corrupt_backups(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.contains(CORRUPT_MARKER) && n.ends_with(".bak"))
+        })
+        .collect();
+    backups.sort();
+    backups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use tempfile::tempdir;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    struct State {
+        version: u32,
+    }
+
+    #[test]
+    fn write_atomic_replaces_contents_and_leaves_no_temp_files() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("nested/state.json");
+        write_atomic(&path, b"{\"version\": 1}").unwrap();
+        write_atomic(&path, b"{\"version\": 2}").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"version\": 2}");
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_atomic_keeps_the_replaced_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("exclude");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        write_atomic(&path, b"new").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o644);
+    }
+
+    #[test]
+    fn unparseable_state_is_backed_up_and_replaced_by_defaults() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("hints.json");
+        fs::write(&path, "{ truncated").unwrap();
+
+        let state: State = load_json_or_recover(&path, "hints state", 1).unwrap();
+        assert_eq!(state, State::default());
+        assert!(!path.exists());
+
+        let backups = corrupt_backups(dir.path());
+        assert_eq!(backups.len(), 1);
+        assert_eq!(fs::read_to_string(&backups[0]).unwrap(), "{ truncated");
+    }
+
+    #[test]
+    fn unknown_versions_are_backed_up_and_replaced_by_defaults() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("hints.json");
+        for contents in [
+            "{\"version\": 3}",
+            "{\"version\": 0}",
+            "{\"version\": \"2\"}",
+        ] {
+            fs::write(&path, contents).unwrap();
+            let state: State = load_json_or_recover(&path, "hints state", 2).unwrap();
+            assert_eq!(state, State::default(), "{contents}");
+            assert!(!path.exists(), "{contents}");
+        }
+
+        fs::write(&path, "{\"version\": 2}").unwrap();
+        let state: State = load_json_or_recover(&path, "hints state", 2).unwrap();
+        assert_eq!(state, State { version: 2 });
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn missing_state_loads_defaults_without_a_backup() {
+        let dir = tempdir().unwrap();
+        let state: State =
+            load_json_or_recover(&dir.path().join("absent.json"), "state", 1).unwrap();
+        assert_eq!(state, State::default());
+        assert!(corrupt_backups(dir.path()).is_empty());
+    }
+}
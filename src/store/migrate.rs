@@ -7,9 +7,10 @@
 //!
 //! Daft has more than one database, each with its own **migration lineage**
 //! bundled as a [`MigrationSet`]: the per-repo coordinator store
-//! (`migrations/NNN_*.sql`) and the global repo catalog
-//! (`migrations/catalog/NNN_*.sql`). `user_version` lives per file, so the
-//! lineages evolve independently.
+//! (`migrations/NNN_*.sql`), the global repo catalog
+//! (`migrations/catalog/NNN_*.sql`), and the hooks trust audit log
+//! (`migrations/trust_audit/NNN_*.sql`). `user_version` lives per file, so
+//! the lineages evolve independently.
 //!
 //! Two safety rules per lineage:
 //!   1. The migration vector is monotonically appended to — new versions
@@ -55,11 +56,15 @@ pub fn coordinator_set() -> MigrationSet {
             M::up(include_str!("migrations/007_forge_health.sql")),
             M::up(include_str!("migrations/008_forge_pr_row_fields.sql")),
             M::up(include_str!("migrations/009_worktree_identities.sql")),
+            M::up(include_str!("migrations/010_forge_responses.sql")),
+            M::up(include_str!("migrations/011_backup_runs.sql")),
+            M::up(include_str!("migrations/012_worktree_templates.sql")),
+            M::up(include_str!("migrations/013_worktree_pins.sql")),
         ]),
         // rusqlite_migration's version counter is `migrations.len() as u32`
         // after every migration is applied. Kept as i64 for consistency with
         // the on-disk `user_version` PRAGMA type.
-        current_version: 9,
+        current_version: 13,
     }
 }
 
@@ -74,6 +79,16 @@ pub fn catalog_set() -> MigrationSet {
     }
 }
 
+/// The hooks trust audit lineage (`audit/trust.db` in the config dir).
+pub fn trust_audit_set() -> MigrationSet {
+    MigrationSet {
+        migrations: Migrations::new(vec![M::up(include_str!(
+            "migrations/trust_audit/001_trust_audit.sql"
+        ))]),
+        current_version: 1,
+    }
+}
+
 /// Highest coordinator-store schema version this binary understands.
 /// Side-effect free so the open-time refuse-newer check can call it.
 pub fn current_version() -> i64 {
@@ -130,6 +145,9 @@ mod tests {
         catalog_set()
             .validate()
             .expect("catalog migration set is well-formed");
+        trust_audit_set()
+            .validate()
+            .expect("trust audit migration set is well-formed");
     }
 
     #[test]
@@ -197,6 +215,29 @@ mod tests {
         assert_eq!(name, "repo_sizes");
     }
 
+    #[test]
+    fn trust_audit_rows_cannot_be_updated_or_deleted() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("trust.db");
+        let mut conn = open_unmigrated(&path);
+        run_set(&trust_audit_set(), &mut conn, &path).unwrap();
+        conn.execute(
+            "INSERT INTO trust_audit (recorded_at, event, repo, outcome, command)
+             VALUES ('2026-10-01T00:00:00Z', 'trust', '/r/.git', 'allow', 'daft hooks trust')",
+            [],
+        )
+        .unwrap();
+        assert!(
+            conn.execute("UPDATE trust_audit SET outcome = 'deny'", [])
+                .is_err()
+        );
+        assert!(conn.execute("DELETE FROM trust_audit", []).is_err());
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM trust_audit", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
     #[test]
     fn refuses_open_when_user_version_is_newer() {
         let tmp = TempDir::new().unwrap();
@@ -313,6 +354,71 @@ mod tests {
         assert_eq!(count, 2);
     }
 
+    #[test]
+    fn forge_response_tables_exist_after_migration() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        run(&mut conn, &path).unwrap();
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master
+                 WHERE type = 'table' AND name IN ('forge_responses', 'forge_backoff')",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn backup_runs_table_exists_after_migration() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        run(&mut conn, &path).unwrap();
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'backup_runs'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn worktree_pins_table_exists_after_migration() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        run(&mut conn, &path).unwrap();
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'worktree_pins'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn worktree_templates_table_exists_after_migration() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        run(&mut conn, &path).unwrap();
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'worktree_templates'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
     #[test]
     fn worktree_identities_table_exists_after_migration() {
         let tmp = TempDir::new().unwrap();
@@ -0,0 +1,29 @@
+-- Raw forge CLI responses, kept so forge features degrade to the last known
+-- answer instead of failing when the forge is unreachable or rate-limiting,
+-- and so `gh api` / `glab api` calls can revalidate with `If-None-Match`
+-- rather than re-download (a 304 costs GitHub no rate-limit quota).
+--
+-- `request_key` identifies one CLI request — tool, arguments, and per-call
+-- environment — and is opaque to SQL. `etag` is NULL for responses that carry
+-- none (`gh pr list` is GraphQL-backed and unconditional); those rows are only
+-- ever served as a fallback. `body` is the response body as the CLI printed
+-- it (JSON text).
+--
+-- `forge_backoff` holds at most one row per CLI tool: while `until` is in
+-- the future, daft does not call that tool for this repo at all and serves
+-- `forge_responses` instead. Set when the forge answers with a rate-limit
+-- response; `reason` is the detail shown when there is no cached answer.
+--
+-- Timestamps are TEXT ISO-8601 UTC per 001_initial.sql.
+CREATE TABLE forge_responses (
+    request_key TEXT PRIMARY KEY,
+    etag        TEXT,
+    body        TEXT NOT NULL,
+    fetched_at  TEXT NOT NULL
+);
+
+CREATE TABLE forge_backoff (
+    tool   TEXT PRIMARY KEY,
+    until  TEXT NOT NULL,
+    reason TEXT NOT NULL
+);
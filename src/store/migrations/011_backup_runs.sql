@@ -0,0 +1,24 @@
+-- One row per `daft backup` run against this repo, manual or scheduled.
+--
+-- `destination` is the remote name or bundle path as given. The counts are
+-- what the run copied: local branches, stash entries, and snapshots of
+-- uncommitted changes in dirty worktrees. `error` is NULL for a run that
+-- succeeded; a failed scheduled run is only visible here (and in
+-- `daft backup status`), since it runs detached with no terminal.
+--
+-- The scheduled backup (`daft.backup.interval`) compares the newest
+-- successful `finished_at` against the interval.
+--
+-- Timestamps are TEXT ISO-8601 UTC per 001_initial.sql.
+CREATE TABLE backup_runs (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    destination TEXT    NOT NULL,
+    started_at  TEXT    NOT NULL,
+    finished_at TEXT    NOT NULL,
+    branches    INTEGER NOT NULL,
+    stashes     INTEGER NOT NULL,
+    snapshots   INTEGER NOT NULL,
+    error       TEXT
+);
+
+CREATE INDEX idx_backup_runs_finished ON backup_runs(finished_at);
@@ -0,0 +1,22 @@
+-- One row per `daft template snapshot` of this repo.
+--
+-- The archive itself lives at `<git-common-dir>/.daft/template.tar.gz`;
+-- only its provenance is recorded here. `source_branch` and
+-- `source_commit` identify the reference worktree at snapshot time, so
+-- `daft template status` can tell how far the snapshot has drifted.
+-- `paths` is the newline-joined list of ignored directories captured.
+-- `bytes` is the compressed archive size.
+--
+-- Snapshots replace each other; `daft template clear` deletes every row.
+--
+-- Timestamps are TEXT ISO-8601 UTC per 001_initial.sql.
+CREATE TABLE worktree_templates (
+    id            INTEGER PRIMARY KEY AUTOINCREMENT,
+    source_branch TEXT    NOT NULL,
+    source_commit TEXT    NOT NULL,
+    paths         TEXT    NOT NULL,
+    bytes         INTEGER NOT NULL,
+    created_at    TEXT    NOT NULL
+);
+
+CREATE INDEX idx_worktree_templates_created ON worktree_templates(created_at);
@@ -0,0 +1,25 @@
+-- Worktrees pinned to a ref with `daft pin <branch> <ref>`.
+--
+-- A pinned worktree holds a detached checkout of `pinned_ref` (resolved to
+-- `commit_sha` at pin time) on behalf of `branch`. Update, sync and prune
+-- consult this table so they never advance or remove it; `daft list` shows
+-- the pin. The branch ref itself is left where it was, so unpinning simply
+-- checks the branch out again.
+--
+-- Keyed on the branch, since that is what every command names. The
+-- worktree's private-gitdir id (see 009_worktree_identities.sql) is kept so
+-- the worktree can still be found after `git worktree move`; the path is for
+-- display and as a fallback.
+--
+-- Conventions follow 001_initial.sql: TEXT ISO-8601 UTC timestamps,
+-- composite primary key, no blobs.
+CREATE TABLE worktree_pins (
+    repo_hash     TEXT NOT NULL,
+    branch        TEXT NOT NULL,
+    pinned_ref    TEXT NOT NULL,
+    commit_sha    TEXT NOT NULL,
+    worktree_id   TEXT,
+    worktree_path TEXT NOT NULL,
+    pinned_at     TEXT NOT NULL,
+    PRIMARY KEY (repo_hash, branch)
+);
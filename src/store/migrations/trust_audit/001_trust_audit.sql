@@ -0,0 +1,41 @@
+-- Hooks trust audit log behind `daft hooks audit`.
+--
+-- One row per trust change (a repository's level set or removed, a pattern
+-- rule added or removed) and per hook execution decision at the trust gate
+-- (allowed, prompted and answered, denied). Lives next to the trust registry
+-- in the config dir, in its own lineage, so it follows the registry it
+-- audits rather than the repo catalog.
+--
+-- Append-only: the triggers below reject UPDATE and DELETE, so rows can only
+-- be added through the store. The file itself is still the user's; the
+-- triggers stop daft (and accidental tooling) from rewriting history, not a
+-- user with write access to the file.
+--
+-- `repo` is the canonical git dir the trust registry keys on, or the glob for
+-- pattern rules. `outcome` is the new level (or `removed`) for trust and
+-- pattern events and the decision for hook events; `previous` is the level
+-- a trust change replaced. `command` is the daft invocation that caused the
+-- event. Conventions follow the catalog lineage: ISO-8601 UTC text
+-- timestamps, no blobs.
+CREATE TABLE trust_audit (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    recorded_at TEXT    NOT NULL,
+    event       TEXT    NOT NULL,
+    repo        TEXT    NOT NULL,
+    hook        TEXT,
+    outcome     TEXT    NOT NULL,
+    previous    TEXT,
+    command     TEXT    NOT NULL
+);
+
+CREATE INDEX trust_audit_repo ON trust_audit (repo, id);
+
+CREATE TRIGGER trust_audit_no_update BEFORE UPDATE ON trust_audit
+BEGIN
+    SELECT RAISE(ABORT, 'trust_audit is append-only');
+END;
+
+CREATE TRIGGER trust_audit_no_delete BEFORE DELETE ON trust_audit
+BEGIN
+    SELECT RAISE(ABORT, 'trust_audit is append-only');
+END;
@@ -0,0 +1,21 @@
+//! Row model for the `backup_runs` table.
+
+use chrono::{DateTime, Utc};
+
+/// One `daft backup` run: where it went, what it copied, and whether it
+/// succeeded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupRunRow {
+    /// Remote name or bundle path, as given.
+    pub destination: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    /// Local branches copied.
+    pub branches: i64,
+    /// Stash entries copied.
+    pub stashes: i64,
+    /// Snapshots of uncommitted worktree changes copied.
+    pub snapshots: i64,
+    /// Why the run failed; `None` on success.
+    pub error: Option<String>,
+}
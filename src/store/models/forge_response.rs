@@ -0,0 +1,29 @@
+//! Row models for the `forge_responses` and `forge_backoff` tables.
+
+use chrono::{DateTime, Utc};
+
+/// The last successful answer to one forge CLI request, served when the
+/// forge can't be asked (offline, rate-limited) and revalidated by ETag when
+/// it can.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForgeResponseRow {
+    /// Opaque identity of the request: tool, arguments, per-call env.
+    pub request_key: String,
+    /// The response's `ETag`, when the request was a conditional-capable
+    /// `api` call and the forge sent one.
+    pub etag: Option<String>,
+    /// The response body as the CLI printed it.
+    pub body: String,
+    /// When the body was last fetched or revalidated.
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// A rate-limit backoff for one CLI tool in this repo: no calls to `tool`
+/// until `until`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForgeBackoffRow {
+    pub tool: String,
+    pub until: DateTime<Utc>,
+    /// The forge's rate-limit message, shown when no cached answer exists.
+    pub reason: String,
+}
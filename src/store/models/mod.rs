@@ -5,28 +5,38 @@
 //! Models intentionally avoid any business-logic methods so the store layer
 //! stays a pure data-access layer.
 
+pub mod backup_run;
 pub mod catalog_repo;
 pub mod forge_health;
 pub mod forge_pr;
+pub mod forge_response;
 pub mod governor_event;
 pub mod hook_profile;
 pub mod invocation;
 pub mod job;
 pub mod repo_policy;
 pub mod repo_size;
+pub mod trust_audit;
 pub mod visitor_seed;
 pub mod worktree_identity;
+pub mod worktree_pin;
 pub mod worktree_size;
+pub mod worktree_template;
 
+pub use backup_run::BackupRunRow;
 pub use catalog_repo::CatalogRepoRow;
 pub use forge_health::ForgeHealthRow;
 pub use forge_pr::ForgePrRow;
+pub use forge_response::{ForgeBackoffRow, ForgeResponseRow};
 pub use governor_event::GovernorEventRow;
 pub use hook_profile::HookProfileRow;
 pub use invocation::InvocationRow;
 pub use job::JobRow;
 pub use repo_policy::RepoPolicyRow;
 pub use repo_size::RepoSizeRow;
+pub use trust_audit::TrustAuditRow;
 pub use visitor_seed::VisitorSeedRow;
 pub use worktree_identity::WorktreeIdentityRow;
+pub use worktree_pin::WorktreePinRow;
 pub use worktree_size::WorktreeSizeRow;
+pub use worktree_template::WorktreeTemplateRow;
@@ -0,0 +1,18 @@
+//! Row model for the `trust_audit` table (trust audit lineage).
+
+use chrono::{DateTime, Utc};
+
+/// One entry of the hooks trust audit log: a trust change or a hook
+/// execution decision. See `migrations/trust_audit/001_trust_audit.sql` for
+/// what each column holds per event kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustAuditRow {
+    pub recorded_at: DateTime<Utc>,
+    /// `trust`, `pattern`, or `hook`.
+    pub event: String,
+    pub repo: String,
+    pub hook: Option<String>,
+    pub outcome: String,
+    pub previous: Option<String>,
+    pub command: String,
+}
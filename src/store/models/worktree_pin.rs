@@ -0,0 +1,21 @@
+//! Row model for the `worktree_pins` table.
+
+use chrono::{DateTime, Utc};
+
+/// A worktree held at a fixed ref by `daft pin`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreePinRow {
+    pub repo_hash: String,
+    /// The branch whose worktree is pinned, e.g. `release`.
+    pub branch: String,
+    /// The ref as the user gave it (`v1.4.2`, `origin/main`, a SHA).
+    pub pinned_ref: String,
+    /// What `pinned_ref` resolved to when the pin was taken.
+    pub commit_sha: String,
+    /// The worktree's private-gitdir id, when it has one (the main worktree
+    /// of a non-bare repo does not).
+    pub worktree_id: Option<String>,
+    /// Absolute worktree path at pin time.
+    pub worktree_path: String,
+    pub pinned_at: DateTime<Utc>,
+}
@@ -0,0 +1,18 @@
+//! Row model for the `worktree_templates` table.
+
+use chrono::{DateTime, Utc};
+
+/// One `daft template snapshot`: which worktree it came from and what it
+/// captured.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeTemplateRow {
+    /// Branch checked out in the reference worktree.
+    pub source_branch: String,
+    /// `HEAD` of the reference worktree at snapshot time.
+    pub source_commit: String,
+    /// Ignored directories captured, relative to the worktree root.
+    pub paths: Vec<String>,
+    /// Compressed archive size.
+    pub bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
@@ -24,6 +24,14 @@ pub const CATALOG_SUBDIR: &str = "catalog";
 /// Filename of the global repo-catalog SQLite database.
 pub const CATALOG_DB: &str = "catalog.db";
 
+/// Subdirectory under `daft_config_dir()` that holds the hooks trust audit
+/// log, next to the trust registry it records. Dedicated for the same
+/// 0700-parent reason as [`CATALOG_SUBDIR`].
+pub const AUDIT_SUBDIR: &str = "audit";
+
+/// Filename of the hooks trust audit SQLite database.
+pub const TRUST_AUDIT_DB: &str = "trust.db";
+
 /// Resolve the per-repo coordinator DB path under the daft state dir.
 pub fn for_repo(repo_hash: &str) -> Result<PathBuf> {
     let state_dir = crate::daft_state_dir().map_err(|e| StoreError::Io {
@@ -113,6 +121,30 @@ pub fn catalog_db_probe() -> Option<PathBuf> {
     Some(data_dir.join(CATALOG_SUBDIR).join(CATALOG_DB))
 }
 
+/// Resolve the trust audit DB path under a config dir, creating `audit/` if
+/// missing. Containment is checked like [`catalog_db_under`].
+pub fn trust_audit_db_under(config_dir: &Path) -> Result<PathBuf> {
+    let parent = config_dir.join(AUDIT_SUBDIR);
+    std::fs::create_dir_all(&parent).map_err(|source| StoreError::Io {
+        path: parent.clone(),
+        source,
+    })?;
+
+    let canonical_parent = parent.canonicalize().map_err(|source| StoreError::Io {
+        path: parent.clone(),
+        source,
+    })?;
+    let canonical_base = config_dir.canonicalize().map_err(|source| StoreError::Io {
+        path: config_dir.to_path_buf(),
+        source,
+    })?;
+    if !canonical_parent.starts_with(&canonical_base) {
+        return Err(StoreError::PathOutsideStateDir(canonical_parent));
+    }
+
+    Ok(canonical_parent.join(TRUST_AUDIT_DB))
+}
+
 /// Verify a file is at most user-read-write (mode 0o600) and its parent is
 /// at most user-rwx (mode 0o700). Returns `PermissionsTooOpen` for either
 /// failure. Called once per process per opened DB.
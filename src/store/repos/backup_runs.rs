@@ -0,0 +1,118 @@
+//! Queries against the `backup_runs` table (the history behind
+//! `daft backup status` and the scheduled-backup interval).
+
+use crate::store::error::Result;
+use crate::store::models::BackupRunRow;
+use crate::store::repos::invocations::parse_rfc3339;
+use rusqlite::{Connection, OptionalExtension, params};
+
+pub struct BackupRunsRepo;
+
+impl BackupRunsRepo {
+    pub fn record(conn: &Connection, row: &BackupRunRow) -> Result<()> {
+        conn.execute(
+            "INSERT INTO backup_runs
+                 (destination, started_at, finished_at, branches, stashes, snapshots, error)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                row.destination,
+                row.started_at.to_rfc3339(),
+                row.finished_at.to_rfc3339(),
+                row.branches,
+                row.stashes,
+                row.snapshots,
+                row.error,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The newest run, successful or not.
+    pub fn latest(conn: &Connection) -> Result<Option<BackupRunRow>> {
+        let row = conn
+            .query_row(
+                "SELECT destination, started_at, finished_at, branches, stashes, snapshots, error
+                 FROM backup_runs ORDER BY finished_at DESC, id DESC LIMIT 1",
+                [],
+                row_to_run,
+            )
+            .optional()?;
+        Ok(row)
+    }
+
+    /// The newest successful run.
+    pub fn latest_success(conn: &Connection) -> Result<Option<BackupRunRow>> {
+        let row = conn
+            .query_row(
+                "SELECT destination, started_at, finished_at, branches, stashes, snapshots, error
+                 FROM backup_runs WHERE error IS NULL
+                 ORDER BY finished_at DESC, id DESC LIMIT 1",
+                [],
+                row_to_run,
+            )
+            .optional()?;
+        Ok(row)
+    }
+}
+
+fn row_to_run(row: &rusqlite::Row<'_>) -> rusqlite::Result<BackupRunRow> {
+    Ok(BackupRunRow {
+        destination: row.get("destination")?,
+        started_at: parse_rfc3339(&row.get::<_, String>("started_at")?, "started_at")?,
+        finished_at: parse_rfc3339(&row.get::<_, String>("finished_at")?, "finished_at")?,
+        branches: row.get("branches")?,
+        stashes: row.get("stashes")?,
+        snapshots: row.get("snapshots")?,
+        error: row.get("error")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{connection, migrate};
+    use chrono::{DateTime, TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, Connection) {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        migrate::run(&mut conn, &path).unwrap();
+        (tmp, conn)
+    }
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, hour, 0, 0).unwrap()
+    }
+
+    fn run(hour: u32, error: Option<&str>) -> BackupRunRow {
+        BackupRunRow {
+            destination: "backup".into(),
+            started_at: at(hour),
+            finished_at: at(hour),
+            branches: 3,
+            stashes: 1,
+            snapshots: 0,
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn latest_and_latest_success_differ_after_a_failure() {
+        let (_tmp, conn) = fresh_db();
+        assert_eq!(BackupRunsRepo::latest(&conn).unwrap(), None);
+
+        BackupRunsRepo::record(&conn, &run(1, None)).unwrap();
+        BackupRunsRepo::record(&conn, &run(2, Some("remote unreachable"))).unwrap();
+
+        assert_eq!(
+            BackupRunsRepo::latest(&conn).unwrap(),
+            Some(run(2, Some("remote unreachable")))
+        );
+        assert_eq!(
+            BackupRunsRepo::latest_success(&conn).unwrap(),
+            Some(run(1, None))
+        );
+    }
+}
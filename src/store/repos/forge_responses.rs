@@ -0,0 +1,152 @@
+//! Queries against the `forge_responses` and `forge_backoff` tables (the
+//! forge CLI response cache and its rate-limit backoff).
+
+use crate::store::error::Result;
+use crate::store::models::{ForgeBackoffRow, ForgeResponseRow};
+use crate::store::repos::invocations::parse_rfc3339;
+use chrono::{DateTime, Utc};
+use rusqlite::{Connection, OptionalExtension, params};
+
+pub struct ForgeResponsesRepo;
+
+impl ForgeResponsesRepo {
+    pub fn get(conn: &Connection, request_key: &str) -> Result<Option<ForgeResponseRow>> {
+        let row = conn
+            .query_row(
+                "SELECT request_key, etag, body, fetched_at
+                 FROM forge_responses WHERE request_key = ?1",
+                params![request_key],
+                row_to_response,
+            )
+            .optional()?;
+        Ok(row)
+    }
+
+    /// Store a fresh answer, replacing whatever was cached for the request.
+    pub fn put(conn: &Connection, row: &ForgeResponseRow) -> Result<()> {
+        conn.execute(
+            "INSERT INTO forge_responses (request_key, etag, body, fetched_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(request_key) DO UPDATE SET
+                 etag       = excluded.etag,
+                 body       = excluded.body,
+                 fetched_at = excluded.fetched_at",
+            params![
+                row.request_key,
+                row.etag,
+                row.body,
+                row.fetched_at.to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Record that a cached answer was revalidated (a 304): the body stands,
+    /// only its age resets.
+    pub fn touch(conn: &Connection, request_key: &str, at: DateTime<Utc>) -> Result<()> {
+        conn.execute(
+            "UPDATE forge_responses SET fetched_at = ?2 WHERE request_key = ?1",
+            params![request_key, at.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// The tool's backoff, if one was ever recorded. Callers compare `until`
+    /// against the clock; an expired row is simply ignored until replaced.
+    pub fn backoff(conn: &Connection, tool: &str) -> Result<Option<ForgeBackoffRow>> {
+        let row = conn
+            .query_row(
+                "SELECT tool, until, reason FROM forge_backoff WHERE tool = ?1",
+                params![tool],
+                |row| {
+                    Ok(ForgeBackoffRow {
+                        tool: row.get("tool")?,
+                        until: parse_rfc3339(&row.get::<_, String>("until")?, "until")?,
+                        reason: row.get("reason")?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(row)
+    }
+
+    pub fn set_backoff(conn: &Connection, row: &ForgeBackoffRow) -> Result<()> {
+        conn.execute(
+            "INSERT INTO forge_backoff (tool, until, reason) VALUES (?1, ?2, ?3)
+             ON CONFLICT(tool) DO UPDATE SET
+                 until  = excluded.until,
+                 reason = excluded.reason",
+            params![row.tool, row.until.to_rfc3339(), row.reason],
+        )?;
+        Ok(())
+    }
+}
+
+fn row_to_response(row: &rusqlite::Row<'_>) -> rusqlite::Result<ForgeResponseRow> {
+    Ok(ForgeResponseRow {
+        request_key: row.get("request_key")?,
+        etag: row.get("etag")?,
+        body: row.get("body")?,
+        fetched_at: parse_rfc3339(&row.get::<_, String>("fetched_at")?, "fetched_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{connection, migrate};
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, Connection) {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        migrate::run(&mut conn, &path).unwrap();
+        (tmp, conn)
+    }
+
+    fn at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, 12, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn put_replaces_and_touch_only_ages() {
+        let (_tmp, conn) = fresh_db();
+        assert_eq!(ForgeResponsesRepo::get(&conn, "gh api x").unwrap(), None);
+
+        let mut row = ForgeResponseRow {
+            request_key: "gh api x".into(),
+            etag: Some("\"a\"".into()),
+            body: "{}".into(),
+            fetched_at: at(0),
+        };
+        ForgeResponsesRepo::put(&conn, &row).unwrap();
+        row.etag = Some("\"b\"".into());
+        row.body = "{\"n\":1}".into();
+        ForgeResponsesRepo::put(&conn, &row).unwrap();
+        ForgeResponsesRepo::touch(&conn, "gh api x", at(5)).unwrap();
+
+        let got = ForgeResponsesRepo::get(&conn, "gh api x").unwrap().unwrap();
+        assert_eq!(got.etag.as_deref(), Some("\"b\""));
+        assert_eq!(got.body, "{\"n\":1}");
+        assert_eq!(got.fetched_at, at(5));
+    }
+
+    #[test]
+    fn backoff_is_per_tool_and_latest_wins() {
+        let (_tmp, conn) = fresh_db();
+        let row = |until| ForgeBackoffRow {
+            tool: "gh".into(),
+            until,
+            reason: "API rate limit exceeded".into(),
+        };
+        ForgeResponsesRepo::set_backoff(&conn, &row(at(1))).unwrap();
+        ForgeResponsesRepo::set_backoff(&conn, &row(at(9))).unwrap();
+        assert_eq!(
+            ForgeResponsesRepo::backoff(&conn, "gh").unwrap(),
+            Some(row(at(9)))
+        );
+        assert_eq!(ForgeResponsesRepo::backoff(&conn, "glab").unwrap(), None);
+    }
+}
@@ -10,31 +10,41 @@
 use crate::store::error::Result;
 use rusqlite::Connection;
 
+pub mod backup_runs;
 pub mod catalog_repos;
 pub mod forge_health;
 pub mod forge_prs;
+pub mod forge_responses;
 pub mod governor_events;
 pub mod hook_profiles;
 pub mod invocations;
 pub mod jobs;
 pub mod repo_policies;
 pub mod repo_sizes;
+pub mod trust_audit;
 pub mod visitor_seeds;
 pub mod worktree_identities;
+pub mod worktree_pins;
 pub mod worktree_sizes;
+pub mod worktree_templates;
 
+pub use backup_runs::BackupRunsRepo;
 pub use catalog_repos::CatalogReposRepo;
 pub use forge_health::ForgeHealthRepo;
 pub use forge_prs::ForgePrsRepo;
+pub use forge_responses::ForgeResponsesRepo;
 pub use governor_events::GovernorEventsRepo;
 pub use hook_profiles::HookProfilesRepo;
 pub use invocations::InvocationsRepo;
 pub use jobs::JobsRepo;
 pub use repo_policies::RepoPoliciesRepo;
 pub use repo_sizes::RepoSizesRepo;
+pub use trust_audit::TrustAuditRepo;
 pub use visitor_seeds::VisitorSeedsRepo;
 pub use worktree_identities::WorktreeIdentitiesRepo;
+pub use worktree_pins::WorktreePinsRepo;
 pub use worktree_sizes::WorktreeSizesRepo;
+pub use worktree_templates::WorktreeTemplatesRepo;
 
 /// Run a closure inside a deferred transaction on `conn`, commit on
 /// success, roll back on error. Use for multi-statement updates that must
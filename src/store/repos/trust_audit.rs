@@ -0,0 +1,132 @@
+//! Queries against the `trust_audit` table (the log behind
+//! `daft hooks audit`). Append and read only — the table's triggers reject
+//! anything else.
+
+use crate::store::error::Result;
+use crate::store::models::TrustAuditRow;
+use crate::store::repos::invocations::parse_rfc3339;
+use rusqlite::{Connection, params};
+
+pub struct TrustAuditRepo;
+
+impl TrustAuditRepo {
+    pub fn append(conn: &Connection, row: &TrustAuditRow) -> Result<()> {
+        conn.execute(
+            "INSERT INTO trust_audit
+                 (recorded_at, event, repo, hook, outcome, previous, command)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                row.recorded_at.to_rfc3339(),
+                row.event,
+                row.repo,
+                row.hook,
+                row.outcome,
+                row.previous,
+                row.command,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Entries oldest first, optionally only those for `repo`. With `limit`,
+    /// the newest `limit` entries (still oldest first).
+    pub fn list(
+        conn: &Connection,
+        repo: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<Vec<TrustAuditRow>> {
+        let limit = limit.map_or(-1, |n| n as i64);
+        let mut stmt = conn.prepare(
+            "SELECT recorded_at, event, repo, hook, outcome, previous, command
+             FROM (
+                 SELECT * FROM trust_audit
+                 WHERE ?1 IS NULL OR repo = ?1
+                 ORDER BY id DESC LIMIT ?2
+             )
+             ORDER BY id ASC",
+        )?;
+        let rows = stmt
+            .query_map(params![repo, limit], row_to_entry)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(rows)
+    }
+}
+
+fn row_to_entry(row: &rusqlite::Row<'_>) -> rusqlite::Result<TrustAuditRow> {
+    Ok(TrustAuditRow {
+        recorded_at: parse_rfc3339(&row.get::<_, String>("recorded_at")?, "recorded_at")?,
+        event: row.get("event")?,
+        repo: row.get("repo")?,
+        hook: row.get("hook")?,
+        outcome: row.get("outcome")?,
+        previous: row.get("previous")?,
+        command: row.get("command")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{connection, migrate};
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, Connection) {
+        // The trust audit lineage: bring_up + trust_audit_set, since
+        // open_for_test runs the coordinator migrations.
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("trust.db");
+        let mut conn = Connection::open(&path).unwrap();
+        connection::bring_up(
+            &mut conn,
+            &path,
+            connection::WRITER_BUSY_TIMEOUT_MS,
+            true,
+            false,
+        )
+        .unwrap();
+        migrate::run_set(&migrate::trust_audit_set(), &mut conn, &path).unwrap();
+        (tmp, conn)
+    }
+
+    fn entry(minute: u32, repo: &str, outcome: &str) -> TrustAuditRow {
+        TrustAuditRow {
+            recorded_at: Utc.with_ymd_and_hms(2026, 10, 1, 9, minute, 0).unwrap(),
+            event: "hook".into(),
+            repo: repo.into(),
+            hook: Some("post-create".into()),
+            outcome: outcome.into(),
+            previous: None,
+            command: "daft go feature".into(),
+        }
+    }
+
+    #[test]
+    fn list_filters_by_repo_and_keeps_the_newest_within_limit() {
+        let (_tmp, conn) = fresh_db();
+        TrustAuditRepo::append(&conn, &entry(1, "/a/.git", "denied")).unwrap();
+        TrustAuditRepo::append(&conn, &entry(2, "/b/.git", "allowed")).unwrap();
+        TrustAuditRepo::append(&conn, &entry(3, "/a/.git", "allowed")).unwrap();
+
+        let all = TrustAuditRepo::list(&conn, None, None).unwrap();
+        assert_eq!(all.len(), 3);
+
+        let a = TrustAuditRepo::list(&conn, Some("/a/.git"), None).unwrap();
+        assert_eq!(
+            a,
+            vec![
+                entry(1, "/a/.git", "denied"),
+                entry(3, "/a/.git", "allowed")
+            ]
+        );
+
+        let newest = TrustAuditRepo::list(&conn, None, Some(2)).unwrap();
+        assert_eq!(
+            newest,
+            vec![
+                entry(2, "/b/.git", "allowed"),
+                entry(3, "/a/.git", "allowed")
+            ]
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Queries against the `worktree_pins` table (worktrees held by `daft pin`).
+
+use crate::store::error::Result;
+use crate::store::models::WorktreePinRow;
+use crate::store::repos::invocations::parse_rfc3339;
+use rusqlite::{Connection, OptionalExtension, params};
+
+pub struct WorktreePinsRepo;
+
+impl WorktreePinsRepo {
+    /// Pin a branch's worktree, replacing any earlier pin of the same branch.
+    pub fn upsert(conn: &Connection, row: &WorktreePinRow) -> Result<()> {
+        conn.execute(
+            "INSERT INTO worktree_pins
+                 (repo_hash, branch, pinned_ref, commit_sha, worktree_id,
+                  worktree_path, pinned_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(repo_hash, branch) DO UPDATE SET
+                 pinned_ref    = excluded.pinned_ref,
+                 commit_sha    = excluded.commit_sha,
+                 worktree_id   = excluded.worktree_id,
+                 worktree_path = excluded.worktree_path,
+                 pinned_at     = excluded.pinned_at",
+            params![
+                row.repo_hash,
+                row.branch,
+                row.pinned_ref,
+                row.commit_sha,
+                row.worktree_id,
+                row.worktree_path,
+                row.pinned_at.to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn get(conn: &Connection, repo_hash: &str, branch: &str) -> Result<Option<WorktreePinRow>> {
+        let row = conn
+            .query_row(
+                "SELECT repo_hash, branch, pinned_ref, commit_sha, worktree_id,
+                        worktree_path, pinned_at
+                 FROM worktree_pins
+                 WHERE repo_hash = ?1 AND branch = ?2",
+                params![repo_hash, branch],
+                row_to_pin,
+            )
+            .optional()?;
+        Ok(row)
+    }
+
+    /// Every pin for a repo, ordered by branch.
+    pub fn list_for_repo(conn: &Connection, repo_hash: &str) -> Result<Vec<WorktreePinRow>> {
+        let mut stmt = conn.prepare(
+            "SELECT repo_hash, branch, pinned_ref, commit_sha, worktree_id,
+                    worktree_path, pinned_at
+             FROM worktree_pins
+             WHERE repo_hash = ?1
+             ORDER BY branch ASC",
+        )?;
+        let rows = stmt
+            .query_map(params![repo_hash], row_to_pin)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(rows)
+    }
+
+    /// Release a branch's pin. Returns rows deleted (0 or 1).
+    pub fn delete(conn: &Connection, repo_hash: &str, branch: &str) -> Result<usize> {
+        let n = conn.execute(
+            "DELETE FROM worktree_pins WHERE repo_hash = ?1 AND branch = ?2",
+            params![repo_hash, branch],
+        )?;
+        Ok(n)
+    }
+}
+
+fn row_to_pin(row: &rusqlite::Row<'_>) -> rusqlite::Result<WorktreePinRow> {
+    let pinned_at_str: String = row.get("pinned_at")?;
+    Ok(WorktreePinRow {
+        repo_hash: row.get("repo_hash")?,
+        branch: row.get("branch")?,
+        pinned_ref: row.get("pinned_ref")?,
+        commit_sha: row.get("commit_sha")?,
+        worktree_id: row.get("worktree_id")?,
+        worktree_path: row.get("worktree_path")?,
+        pinned_at: parse_rfc3339(&pinned_at_str, "pinned_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{connection, migrate};
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, Connection) {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        migrate::run(&mut conn, &path).unwrap();
+        (tmp, conn)
+    }
+
+    fn pin(branch: &str, pinned_ref: &str) -> WorktreePinRow {
+        WorktreePinRow {
+            repo_hash: "repo".into(),
+            branch: branch.into(),
+            pinned_ref: pinned_ref.into(),
+            commit_sha: "0123456789abcdef0123456789abcdef01234567".into(),
+            worktree_id: Some("release".into()),
+            worktree_path: "/tmp/wt/release".into(),
+            pinned_at: Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn repinning_a_branch_replaces_its_pin() {
+        let (_tmp, conn) = fresh_db();
+        WorktreePinsRepo::upsert(&conn, &pin("release", "v1.0")).unwrap();
+        WorktreePinsRepo::upsert(&conn, &pin("release", "v1.1")).unwrap();
+        assert_eq!(
+            WorktreePinsRepo::list_for_repo(&conn, "repo").unwrap(),
+            vec![pin("release", "v1.1")]
+        );
+    }
+
+    #[test]
+    fn delete_releases_only_the_named_branch() {
+        let (_tmp, conn) = fresh_db();
+        WorktreePinsRepo::upsert(&conn, &pin("release", "v1.0")).unwrap();
+        WorktreePinsRepo::upsert(&conn, &pin("stable", "v0.9")).unwrap();
+        assert_eq!(
+            WorktreePinsRepo::delete(&conn, "repo", "release").unwrap(),
+            1
+        );
+        assert_eq!(
+            WorktreePinsRepo::get(&conn, "repo", "release").unwrap(),
+            None
+        );
+        assert_eq!(
+            WorktreePinsRepo::get(&conn, "repo", "stable").unwrap(),
+            Some(pin("stable", "v0.9"))
+        );
+        assert_eq!(
+            WorktreePinsRepo::delete(&conn, "repo", "release").unwrap(),
+            0
+        );
+    }
+}
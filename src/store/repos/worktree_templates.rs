@@ -0,0 +1,102 @@
+//! Queries against the `worktree_templates` table (the provenance behind
+//! `daft template status`).
+
+use crate::store::error::Result;
+use crate::store::models::WorktreeTemplateRow;
+use crate::store::repos::invocations::parse_rfc3339;
+use rusqlite::{Connection, OptionalExtension, params};
+
+pub struct WorktreeTemplatesRepo;
+
+impl WorktreeTemplatesRepo {
+    pub fn record(conn: &Connection, row: &WorktreeTemplateRow) -> Result<()> {
+        conn.execute(
+            "INSERT INTO worktree_templates
+                 (source_branch, source_commit, paths, bytes, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                row.source_branch,
+                row.source_commit,
+                row.paths.join("\n"),
+                row.bytes,
+                row.created_at.to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The newest snapshot.
+    pub fn latest(conn: &Connection) -> Result<Option<WorktreeTemplateRow>> {
+        let row = conn
+            .query_row(
+                "SELECT source_branch, source_commit, paths, bytes, created_at
+                 FROM worktree_templates ORDER BY created_at DESC, id DESC LIMIT 1",
+                [],
+                row_to_template,
+            )
+            .optional()?;
+        Ok(row)
+    }
+
+    /// Forget every snapshot. Returns the number of rows removed.
+    pub fn clear(conn: &Connection) -> Result<usize> {
+        Ok(conn.execute("DELETE FROM worktree_templates", [])?)
+    }
+}
+
+fn row_to_template(row: &rusqlite::Row<'_>) -> rusqlite::Result<WorktreeTemplateRow> {
+    let paths: String = row.get("paths")?;
+    Ok(WorktreeTemplateRow {
+        source_branch: row.get("source_branch")?,
+        source_commit: row.get("source_commit")?,
+        paths: paths
+            .lines()
+            .filter(|p| !p.is_empty())
+            .map(str::to_string)
+            .collect(),
+        bytes: row.get("bytes")?,
+        created_at: parse_rfc3339(&row.get::<_, String>("created_at")?, "created_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::{connection, migrate};
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    fn fresh_db() -> (TempDir, Connection) {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("db.sqlite");
+        let mut conn = connection::open_for_test(&path).unwrap();
+        migrate::run(&mut conn, &path).unwrap();
+        (tmp, conn)
+    }
+
+    fn snapshot(hour: u32, commit: &str) -> WorktreeTemplateRow {
+        WorktreeTemplateRow {
+            source_branch: "main".into(),
+            source_commit: commit.into(),
+            paths: vec!["node_modules".into(), ".venv".into()],
+            bytes: 4096,
+            created_at: Utc.with_ymd_and_hms(2026, 10, 1, hour, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn latest_returns_newest_snapshot_until_cleared() {
+        let (_tmp, conn) = fresh_db();
+        assert_eq!(WorktreeTemplatesRepo::latest(&conn).unwrap(), None);
+
+        WorktreeTemplatesRepo::record(&conn, &snapshot(1, "aaa")).unwrap();
+        WorktreeTemplatesRepo::record(&conn, &snapshot(2, "bbb")).unwrap();
+        assert_eq!(
+            WorktreeTemplatesRepo::latest(&conn).unwrap(),
+            Some(snapshot(2, "bbb"))
+        );
+
+        assert_eq!(WorktreeTemplatesRepo::clear(&conn).unwrap(), 2);
+        assert_eq!(WorktreeTemplatesRepo::latest(&conn).unwrap(), None);
+    }
+}
@@ -7,12 +7,18 @@
 pub const DAFT_SUBCOMMANDS: &[&str] = &[
     "activate",
     "adopt",
+    "adopt-worktree",
+    "auth",
+    "backup",
     "carry",
     "clone",
+    "clone-all",
     "completions",
     "config",
+    "consolidate",
     "doctor",
     "eject",
+    "env",
     "file",
     "go",
     "hooks",
@@ -22,9 +28,11 @@ pub const DAFT_SUBCOMMANDS: &[&str] = &[
     "list",
     "merge",
     "multi-remote",
+    "pin",
     "prune",
     "push",
     "release-notes",
+    "remote",
     "remove",
     "rename",
     "repo",
@@ -33,8 +41,13 @@ pub const DAFT_SUBCOMMANDS: &[&str] = &[
     "shell-init",
     "skill",
     "start",
+    "stats",
     "sync",
+    "template",
     "update",
+    "whats-new",
+    "which",
+    "workspace",
     "worktree-branch",
     "worktree-branch-delete",
     "worktree-carry",
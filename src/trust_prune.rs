@@ -10,8 +10,7 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::env;
-use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -115,28 +114,21 @@ fn cache_path() -> Result<PathBuf> {
     Ok(crate::daft_config_dir()?.join("trust-prune.json"))
 }
 
-/// Load the cache from disk. Returns `None` on any error.
-fn load_cache(path: &PathBuf) -> Option<TrustPruneCache> {
-    let contents = fs::read_to_string(path).ok()?;
-    serde_json::from_str(&contents).ok()
+/// Load the cache from disk. Returns `None` on any error; an unreadable or
+/// unknown-version cache is backed up (see [`crate::state_file`]).
+fn load_cache(path: &Path) -> Option<TrustPruneCache> {
+    crate::state_file::load_json_checked(path, "trust-prune cache", CACHE_VERSION)
+        .ok()
+        .flatten()
 }
 
 /// Save the cache to disk, creating parent directories as needed.
 fn save_cache(cache: &TrustPruneCache) -> Result<()> {
     let path = cache_path()?;
-
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
-    }
-
     let contents =
         serde_json::to_string_pretty(cache).context("Failed to serialize trust prune cache")?;
-
-    fs::write(&path, contents)
-        .with_context(|| format!("Failed to write cache to {}", path.display()))?;
-
-    Ok(())
+    crate::state_file::write_atomic(&path, contents.as_bytes())
+        .context("Failed to write trust prune cache")
 }
 
 /// Returns `true` if the cache is older than 24 hours or has a future timestamp.
@@ -219,6 +211,7 @@ pub(crate) fn is_ci_environment() -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
     use tempfile::tempdir;
 
     #[test]
@@ -254,6 +247,17 @@ mod tests {
         assert!(load_cache(&path).is_none());
     }
 
+    #[test]
+    fn test_load_newer_version_is_backed_up() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("trust-prune.json");
+        let contents = format!(r#"{{"version": {}, "pruned_at": 1}}"#, CACHE_VERSION + 1);
+        fs::write(&path, contents).unwrap();
+        assert!(load_cache(&path).is_none());
+        assert!(!path.exists());
+        assert_eq!(crate::state_file::corrupt_backups(temp.path()).len(), 1);
+    }
+
     #[test]
     fn test_fresh_cache() {
         let now = SystemTime::now()
@@ -3,9 +3,14 @@
 //! Implements a fire-and-forget update notification system:
 //! 1. On every invocation, reads a cache file (~/.config/daft/update-check.json)
 //! 2. If a newer version is cached, returns a notification to display after command output
-//! 3. If the cache is stale (>24h) or missing, spawns a detached background process to check
+//! 3. If the cache is stale or missing, spawns a detached background process to check.
+//!    The cache goes stale after `daft.updateCheck.interval` (24h by default)
 //! 4. The background process fetches GitHub Releases API via `curl` and writes the cache
 //!
+//! The cache also keeps the latest release's notes: the notification shows a
+//! one-line highlight drawn from them, and `daft whats-new` renders them in
+//! full.
+//!
 //! Notification throttling: the "new version available" banner is shown at most once
 //! per 24 hours for the same version. If a different newer version appears, the banner
 //! is shown again immediately. State is tracked in a separate file
@@ -17,8 +22,7 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::env;
-use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -31,7 +35,8 @@ pub const NO_UPDATE_CHECK_ENV: &str = "DAFT_NO_UPDATE_CHECK";
 /// GitHub API URL for the latest release.
 const GITHUB_RELEASES_URL: &str = "https://api.github.com/repos/avihut/daft/releases/latest";
 
-/// How long (in seconds) before the cache is considered stale.
+/// How long (in seconds) before the cache is considered stale, unless
+/// `daft.updateCheck.interval` says otherwise.
 const CACHE_TTL_SECONDS: i64 = 24 * 60 * 60; // 24 hours
 
 /// How long (in seconds) before the notification for the same version is shown again.
@@ -52,6 +57,12 @@ pub struct UpdateCheckCache {
     pub checked_at: i64,
     /// The latest version string (without 'v' prefix).
     pub latest_version: String,
+    /// Publication date of the latest release (`YYYY-MM-DD`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    /// Release notes of the latest release, as markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
 }
 
 /// Tracks when/what version was last shown to the user, so we can throttle
@@ -72,6 +83,27 @@ pub struct NotificationState {
 #[derive(Debug, Deserialize)]
 struct GitHubRelease {
     tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    published_at: Option<String>,
+}
+
+/// The latest release as fetched from GitHub.
+#[derive(Debug, Clone)]
+struct LatestRelease {
+    version: String,
+    release_date: Option<String>,
+    notes: Option<String>,
+}
+
+/// A release newer than the running binary, for `daft whats-new`.
+#[derive(Debug, Clone)]
+pub struct PendingRelease {
+    pub version: String,
+    pub release_date: Option<String>,
+    /// Release notes as markdown; `None` if the release has none.
+    pub notes: Option<String>,
 }
 
 /// Information needed to display an update notification.
@@ -80,6 +112,8 @@ pub struct UpdateNotification {
     pub current_version: String,
     pub latest_version: String,
     pub update_command: String,
+    /// One-line summary of the release, drawn from its notes.
+    pub highlight: Option<String>,
 }
 
 /// Detected installation method, used to suggest the right update command.
@@ -108,7 +142,33 @@ pub fn maybe_check_for_update() -> Option<UpdateNotification> {
 /// Entry point for the `daft __check-update` background process.
 /// Fetches the latest version from GitHub and writes the cache file.
 pub fn run_check_update() -> Result<()> {
-    let latest = fetch_latest_version()?;
+    refresh_cache().map(|_| ())
+}
+
+/// The release newer than this binary, if any, for `daft whats-new`.
+///
+/// Uses the cached check when it is fresh and carries notes; otherwise
+/// fetches now (the user asked, so waiting on the network is fine) and
+/// refreshes the cache for the notification as well.
+pub fn pending_release() -> Result<Option<PendingRelease>> {
+    let path = cache_path()?;
+    let cache = match load_cache_from(&path) {
+        Some(c) if !is_cache_stale(&c, cache_ttl_seconds()) && c.notes.is_some() => c,
+        _ => refresh_cache().context("Could not fetch the latest release from GitHub")?,
+    };
+    if !is_newer_version(crate::VERSION, &cache.latest_version) {
+        return Ok(None);
+    }
+    Ok(Some(PendingRelease {
+        version: cache.latest_version,
+        release_date: cache.release_date,
+        notes: cache.notes,
+    }))
+}
+
+/// Fetch the latest release and write it to the cache.
+fn refresh_cache() -> Result<UpdateCheckCache> {
+    let latest = fetch_latest_release()?;
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .context("System clock error")?
@@ -117,11 +177,14 @@ pub fn run_check_update() -> Result<()> {
     let cache = UpdateCheckCache {
         version: CACHE_VERSION,
         checked_at: now,
-        latest_version: latest,
+        latest_version: latest.version,
+        release_date: latest.release_date,
+        notes: latest.notes,
     };
 
     let path = cache_path()?;
-    save_cache_to(&cache, &path)
+    save_cache_to(&cache, &path)?;
+    Ok(cache)
 }
 
 /// Print the update notification to stderr.
@@ -132,6 +195,13 @@ pub fn print_notification(notification: &UpdateNotification) {
 
     eprintln!();
     eprintln!("A new version of daft is available: {current} {arrow} {latest}");
+    if let Some(ref highlight) = notification.highlight {
+        eprintln!("  {}", styles::dim(highlight));
+    }
+    eprintln!(
+        "What's new: {}",
+        styles::cyan(&crate::daft_cmd("whats-new"))
+    );
     eprintln!("To update: {}", styles::cyan(&notification.update_command));
     eprintln!(
         "To disable: {}",
@@ -154,12 +224,17 @@ fn maybe_check_for_update_inner() -> Option<UpdateNotification> {
         return None;
     }
 
+    // `daft whats-new` shows the notes itself (and refreshes the cache).
+    if crate::cli::argv().get(1).is_some_and(|a| a == "whats-new") {
+        return None;
+    }
+
     let path = cache_path().ok()?;
     let cache = load_cache_from(&path);
 
     // Spawn a background check if cache is stale or missing
     match &cache {
-        Some(c) if !is_cache_stale(c) => {}
+        Some(c) if !is_cache_stale(c, cache_ttl_seconds()) => {}
         _ => {
             let _ = spawn_background_check();
         }
@@ -178,6 +253,7 @@ fn maybe_check_for_update_inner() -> Option<UpdateNotification> {
         let method = detect_install_method();
         Some(UpdateNotification {
             current_version: current.to_string(),
+            highlight: cache.notes.as_deref().and_then(highlight_from_notes),
             latest_version: cache.latest_version,
             update_command: update_command_for(&method),
         })
@@ -191,26 +267,20 @@ fn cache_path() -> Result<PathBuf> {
     Ok(crate::daft_config_dir()?.join("update-check.json"))
 }
 
-/// Load the cache from disk. Returns `None` on any error.
-fn load_cache_from(path: &PathBuf) -> Option<UpdateCheckCache> {
-    let contents = fs::read_to_string(path).ok()?;
-    serde_json::from_str(&contents).ok()
+/// Load the cache from disk. Returns `None` on any error; an unreadable or
+/// unknown-version cache is backed up (see [`crate::state_file`]).
+fn load_cache_from(path: &Path) -> Option<UpdateCheckCache> {
+    crate::state_file::load_json_checked(path, "update-check cache", CACHE_VERSION)
+        .ok()
+        .flatten()
 }
 
 /// Save the cache to disk, creating parent directories as needed.
-fn save_cache_to(cache: &UpdateCheckCache, path: &PathBuf) -> Result<()> {
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
-    }
-
+fn save_cache_to(cache: &UpdateCheckCache, path: &Path) -> Result<()> {
     let contents =
         serde_json::to_string_pretty(cache).context("Failed to serialize update check cache")?;
-
-    fs::write(path, contents)
-        .with_context(|| format!("Failed to write cache to {}", path.display()))?;
-
-    Ok(())
+    crate::state_file::write_atomic(path, contents.as_bytes())
+        .context("Failed to write update check cache")
 }
 
 // ---------------------------------------------------------------------------
@@ -222,28 +292,26 @@ fn notification_state_path() -> Result<PathBuf> {
     Ok(crate::daft_config_dir()?.join("update-notification.json"))
 }
 
-/// Load the notification state from disk. Returns `None` on any error.
+/// Load the notification state from disk. Returns `None` on any error; an
+/// unreadable or unknown-version file is backed up.
 fn load_notification_state() -> Option<NotificationState> {
     let path = notification_state_path().ok()?;
-    let contents = fs::read_to_string(path).ok()?;
-    serde_json::from_str(&contents).ok()
+    crate::state_file::load_json_checked(
+        &path,
+        "update notification state",
+        NOTIFICATION_STATE_VERSION,
+    )
+    .ok()
+    .flatten()
 }
 
 /// Save the notification state to disk, creating parent directories as needed.
 fn save_notification_state(state: &NotificationState) -> Result<()> {
     let path = notification_state_path()?;
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
-    }
-
     let contents =
         serde_json::to_string_pretty(state).context("Failed to serialize notification state")?;
-
-    fs::write(&path, contents)
-        .with_context(|| format!("Failed to write notification state to {}", path.display()))?;
-
-    Ok(())
+    crate::state_file::write_atomic(&path, contents.as_bytes())
+        .context("Failed to write notification state")
 }
 
 /// Pure logic: returns `true` if the notification should be suppressed.
@@ -301,8 +369,8 @@ pub fn record_notification_shown(version: &str) {
     let _ = save_notification_state(&state);
 }
 
-/// Returns `true` if the cache is older than 24 hours or has a future timestamp.
-fn is_cache_stale(cache: &UpdateCheckCache) -> bool {
+/// Returns `true` if the cache is older than `ttl` seconds or has a future timestamp.
+fn is_cache_stale(cache: &UpdateCheckCache, ttl: i64) -> bool {
     let now = match SystemTime::now().duration_since(UNIX_EPOCH) {
         Ok(d) => d.as_secs() as i64,
         Err(_) => return true,
@@ -311,7 +379,17 @@ fn is_cache_stale(cache: &UpdateCheckCache) -> bool {
     let age = now - cache.checked_at;
 
     // Future timestamp (clock skew) or older than TTL
-    !(0..=CACHE_TTL_SECONDS).contains(&age)
+    !(0..=ttl).contains(&age)
+}
+
+/// The configured check interval in seconds (`daft.updateCheck.interval`),
+/// falling back to 24 hours when unset or unparseable.
+fn cache_ttl_seconds() -> i64 {
+    global_config_value(keys::UPDATE_CHECK_INTERVAL)
+        .and_then(|value| crate::settings::parse_push_timeout(&value).flatten())
+        .map_or(CACHE_TTL_SECONDS, |interval| {
+            i64::try_from(interval.as_secs()).unwrap_or(i64::MAX)
+        })
 }
 
 /// Compare two semver version strings. Returns `true` if `latest` is newer than `current`.
@@ -390,8 +468,8 @@ fn spawn_background_check() -> Result<()> {
     Ok(())
 }
 
-/// Fetch the latest version tag from GitHub Releases API using `curl`.
-fn fetch_latest_version() -> Result<String> {
+/// Fetch the latest release from GitHub Releases API using `curl`.
+fn fetch_latest_release() -> Result<LatestRelease> {
     let output = Command::new("curl")
         .args([
             "-sL",
@@ -421,7 +499,113 @@ fn fetch_latest_version() -> Result<String> {
         .unwrap_or(&release.tag_name)
         .to_string();
 
-    Ok(version)
+    Ok(LatestRelease {
+        version,
+        // "2026-07-20T12:00:00Z" -> "2026-07-20"
+        release_date: release
+            .published_at
+            .and_then(|p| p.get(..10).map(str::to_string)),
+        notes: release
+            .body
+            .map(|b| b.trim().to_string())
+            .filter(|b| !b.is_empty()),
+    })
+}
+
+/// Longest highlight shown in the notification, in characters.
+const HIGHLIGHT_MAX_CHARS: usize = 100;
+
+/// One-line summary of a release for the notification.
+///
+/// A `Highlight: ...` line in the notes wins. Otherwise the summary is built
+/// from the first entry under the features heading and the first under the
+/// bug-fixes heading of the changelog-style notes, e.g. "adds forge PR
+/// checkout, fixes prune data loss". Returns `None` when the notes have
+/// neither.
+fn highlight_from_notes(notes: &str) -> Option<String> {
+    if let Some(explicit) = notes.lines().find_map(|l| {
+        let l = l.trim();
+        l.get(..10)
+            .filter(|p| p.eq_ignore_ascii_case("highlight:"))
+            .map(|_| l[10..].trim())
+    }) && !explicit.is_empty()
+    {
+        return Some(truncate_highlight(explicit));
+    }
+
+    let mut feature = None;
+    let mut fix = None;
+    let mut section = "";
+    for line in notes.lines().map(str::trim) {
+        if let Some(heading) = line.strip_prefix("### ") {
+            section = heading.trim();
+            continue;
+        }
+        let Some(entry) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) else {
+            continue;
+        };
+        let slot = match section {
+            "Features" | "Feature" => &mut feature,
+            "Bug Fixes" => &mut fix,
+            _ => continue,
+        };
+        if slot.is_none() {
+            *slot = Some(changelog_entry_text(entry));
+        }
+    }
+
+    let parts: Vec<String> = [
+        ("adds", feature, ["add", "adds", "added"]),
+        ("fixes", fix, ["fix", "fixes", "fixed"]),
+    ]
+    .into_iter()
+    .filter_map(|(verb, text, forms)| {
+        let text = text?;
+        // "Add X" under Features reads "adds X", not "adds add X".
+        let text = forms
+            .iter()
+            .find_map(|f| text.strip_prefix(f).and_then(|r| r.strip_prefix(' ')))
+            .unwrap_or(&text);
+        Some(format!("{verb} {text}"))
+    })
+    .collect();
+    if parts.is_empty() {
+        return None;
+    }
+    Some(truncate_highlight(&parts.join(", ")))
+}
+
+/// A changelog bullet without its `**scope**:` prefix and `(#123)` suffix,
+/// starting lowercase so it reads inside a sentence.
+fn changelog_entry_text(entry: &str) -> String {
+    let mut text = entry.trim();
+    if let Some(rest) = text.strip_prefix("**")
+        && let Some(end) = rest.find("**:")
+    {
+        text = rest[end + 3..].trim();
+    }
+    if let Some(open) = text.rfind(" (#")
+        && text.ends_with(')')
+    {
+        text = &text[..open];
+    }
+    let mut chars = text.chars();
+    match chars.next() {
+        // Keep acronyms ("PR", "CLI") intact.
+        Some(first) if !chars.next().is_some_and(|c| c.is_uppercase()) => first
+            .to_lowercase()
+            .chain(text[first.len_utf8()..].chars())
+            .collect(),
+        _ => text.to_string(),
+    }
+}
+
+fn truncate_highlight(text: &str) -> String {
+    if text.chars().count() <= HIGHLIGHT_MAX_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(HIGHLIGHT_MAX_CHARS - 1).collect();
+    format!("{}\u{2026}", cut.trim_end())
 }
 
 /// Check if update checks are disabled via env var, git config, or CI environment.
@@ -437,24 +621,32 @@ fn is_update_check_disabled() -> bool {
     }
 
     // Git config opt-out (global only — we may not be in a repo)
-    if let Ok(output) = Command::new("git")
-        .args(["config", "--global", "--get", keys::UPDATE_CHECK])
-        .output()
-        && output.status.success()
+    if let Some(value) = global_config_value(keys::UPDATE_CHECK)
+        && matches!(value.to_lowercase().as_str(), "false" | "no" | "off" | "0")
     {
-        let value = String::from_utf8_lossy(&output.stdout)
-            .trim()
-            .to_lowercase();
-        if matches!(value.as_str(), "false" | "no" | "off" | "0") {
-            return true;
-        }
+        return true;
     }
 
-    false
+    // `daft.updateCheck.interval = off` turns the check off as well
+    global_config_value(keys::UPDATE_CHECK_INTERVAL)
+        .and_then(|value| crate::settings::parse_push_timeout(&value))
+        .is_some_and(|interval| interval.is_none())
+}
+
+/// Read `key` from the global git config.
+fn global_config_value(key: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["config", "--global", "--get", key])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
 /// Returns `true` if we appear to be running in a CI environment.
-fn is_ci_environment() -> bool {
+pub(crate) fn is_ci_environment() -> bool {
     let ci_vars = [
         "CI",
         "GITHUB_ACTIONS",
@@ -476,6 +668,7 @@ fn is_ci_environment() -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
     use tempfile::tempdir;
 
     // -- Version comparison tests --
@@ -544,6 +737,8 @@ mod tests {
             version: CACHE_VERSION,
             checked_at: 1700000000,
             latest_version: "1.0.18".to_string(),
+            release_date: None,
+            notes: None,
         };
 
         save_cache_to(&cache, &path).unwrap();
@@ -568,6 +763,60 @@ mod tests {
         assert!(load_cache_from(&path).is_none());
     }
 
+    #[test]
+    fn test_cache_without_notes_still_loads() {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join("update-check.json");
+        fs::write(
+            &path,
+            r#"{"version": 1, "checked_at": 1700000000, "latest_version": "1.0.18"}"#,
+        )
+        .unwrap();
+        let loaded = load_cache_from(&path).unwrap();
+        assert_eq!(loaded.latest_version, "1.0.18");
+        assert!(loaded.notes.is_none());
+    }
+
+    // -- Highlight tests --
+
+    #[test]
+    fn test_highlight_from_changelog_sections() {
+        let notes = "### Bug Fixes\n\n\
+                     - **prune**: Fix data loss when a branch is checked out twice (#741)\n\n\
+                     ### Features\n\n\
+                     - **completions**: Add PowerShell completions (#748)\n\
+                     - **list**: Keep branch identity through rebase (#740)\n";
+        assert_eq!(
+            highlight_from_notes(notes).unwrap(),
+            "adds PowerShell completions, fixes data loss when a branch is checked out twice"
+        );
+    }
+
+    #[test]
+    fn test_highlight_keeps_acronyms_and_needs_a_section() {
+        let notes = "### Features\n\n- PR/MR checkout via gh (#723)\n";
+        assert_eq!(
+            highlight_from_notes(notes).unwrap(),
+            "adds PR/MR checkout via gh"
+        );
+        assert!(highlight_from_notes("### Testing\n\n- More tests (#1)\n").is_none());
+    }
+
+    #[test]
+    fn test_explicit_highlight_line_wins_and_is_truncated() {
+        let notes = "Highlight: adds PowerShell completions, fixes prune data loss\n\n\
+                     ### Features\n\n- Something else (#1)\n";
+        assert_eq!(
+            highlight_from_notes(notes).unwrap(),
+            "adds PowerShell completions, fixes prune data loss"
+        );
+
+        let long = format!("highlight: {}", "word ".repeat(40));
+        let highlight = highlight_from_notes(&long).unwrap();
+        assert_eq!(highlight.chars().count(), HIGHLIGHT_MAX_CHARS);
+        assert!(highlight.ends_with('\u{2026}'));
+    }
+
     // -- Cache staleness tests --
 
     #[test]
@@ -581,9 +830,11 @@ mod tests {
             version: CACHE_VERSION,
             checked_at: now - 60, // 1 minute ago
             latest_version: "1.0.0".to_string(),
+            release_date: None,
+            notes: None,
         };
 
-        assert!(!is_cache_stale(&cache));
+        assert!(!is_cache_stale(&cache, CACHE_TTL_SECONDS));
     }
 
     #[test]
@@ -597,9 +848,11 @@ mod tests {
             version: CACHE_VERSION,
             checked_at: now - CACHE_TTL_SECONDS - 1, // just past TTL
             latest_version: "1.0.0".to_string(),
+            release_date: None,
+            notes: None,
         };
 
-        assert!(is_cache_stale(&cache));
+        assert!(is_cache_stale(&cache, CACHE_TTL_SECONDS));
     }
 
     #[test]
@@ -613,9 +866,11 @@ mod tests {
             version: CACHE_VERSION,
             checked_at: now + 3600, // 1 hour in the future (clock skew)
             latest_version: "1.0.0".to_string(),
+            release_date: None,
+            notes: None,
         };
 
-        assert!(is_cache_stale(&cache));
+        assert!(is_cache_stale(&cache, CACHE_TTL_SECONDS));
     }
 
     // -- Install method tests --
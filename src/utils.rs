@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 /// Build a `git` `Command` rooted at `dir` with inherited `GIT_*` env vars
@@ -51,6 +51,21 @@ pub fn create_directory(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// The outermost directory [`create_directory`] would create for `path`:
+/// `path` itself when its parent exists, otherwise its highest missing
+/// ancestor. Removing it undoes the creation without touching any directory
+/// that was already there.
+pub fn first_missing_ancestor(path: &Path) -> PathBuf {
+    let mut top = path.to_path_buf();
+    for ancestor in path.ancestors().skip(1) {
+        if ancestor.as_os_str().is_empty() || ancestor.exists() {
+            break;
+        }
+        top = ancestor.to_path_buf();
+    }
+    top
+}
+
 pub fn remove_directory(path: &Path) -> Result<()> {
     if path.exists() {
         fs::remove_dir_all(path)
@@ -121,6 +136,35 @@ pub fn validate_branch_name(branch_name: &str) -> Result<()> {
         anyhow::bail!("Branch name too long (max 99 characters)");
     }
 
+    // Git ref rules (`git check-ref-format --branch`) not covered above, so a
+    // bad name fails here instead of on git's error after the plan is shown
+    if branch_name.starts_with('-') {
+        anyhow::bail!("Branch name cannot start with '-'");
+    }
+    if let Some(c) = branch_name
+        .chars()
+        .find(|c| matches!(c, '~' | '^' | ':' | '?' | '*' | '[' | '\\'))
+    {
+        anyhow::bail!("Branch name cannot contain '{c}'");
+    }
+    if branch_name == "@" || branch_name.contains("@{") {
+        anyhow::bail!("Branch name cannot be '@' or contain '@{{'");
+    }
+    if branch_name.contains("//") {
+        anyhow::bail!("Branch name cannot contain '//'");
+    }
+    for component in branch_name.split('/') {
+        if component.starts_with('.') {
+            anyhow::bail!("Branch name components cannot start with '.'");
+        }
+        if component.ends_with(".lock") {
+            anyhow::bail!("Branch name components cannot end with '.lock'");
+        }
+    }
+    if branch_name.ends_with('.') {
+        anyhow::bail!("Branch name cannot end with '.'");
+    }
+
     Ok(())
 }
 
@@ -231,6 +275,31 @@ mod tests {
         assert!(validate_branch_name("feature test").is_err());
     }
 
+    #[test]
+    fn test_validate_branch_name_git_ref_rules() {
+        assert!(validate_branch_name("release/v1.2").is_ok());
+        assert!(validate_branch_name("user@feature").is_ok());
+        for bad in [
+            "-feature",
+            "feat~1",
+            "feat^",
+            "feat:x",
+            "feat?",
+            "feat*",
+            "feat[1]",
+            "feat\\x",
+            "@",
+            "feat@{1}",
+            "feature//x",
+            "feature/.x",
+            "feature.lock",
+            "feature.lock/x",
+            "feature.",
+        ] {
+            assert!(validate_branch_name(bad).is_err(), "accepted {bad:?}");
+        }
+    }
+
     #[test]
     fn test_validate_repo_name() {
         assert!(validate_repo_name("my-project").is_ok());
@@ -246,6 +315,25 @@ mod tests {
         assert!(!path_exists(&temp_dir.path().join("nonexistent")));
     }
 
+    #[test]
+    fn test_first_missing_ancestor() {
+        let temp_dir = tempdir().unwrap();
+        let existing = temp_dir.path().join("host");
+        fs::create_dir(&existing).unwrap();
+
+        let leaf = existing.join("org/repo");
+        assert_eq!(first_missing_ancestor(&leaf), existing.join("org"));
+        assert_eq!(
+            first_missing_ancestor(&existing.join("repo")),
+            existing.join("repo")
+        );
+        // Relative paths stop at the current directory.
+        assert_eq!(
+            first_missing_ancestor(Path::new("daft-no-such-dir/org/repo")),
+            Path::new("daft-no-such-dir")
+        );
+    }
+
     #[test]
     fn test_create_remove_directory() {
         let temp_dir = tempdir().unwrap();
@@ -102,6 +102,10 @@ pub const DEFAULT_BRANCH_SYMBOL: &str = "\u{2726}";
 /// Symbol for sandbox (detached HEAD) worktrees.
 pub const SANDBOX_SYMBOL: &str = "\u{25cb}";
 
+/// Symbol for a worktree held at a ref by `daft pin` — a detached checkout
+/// that, unlike a sandbox, is there on purpose.
+pub const PINNED_SYMBOL: &str = "\u{25cf}";
+
 /// Symbol for a worktree whose recorded branch disagrees with what is
 /// checked out — a record that has fallen behind reality.
 pub const DRIFT_SYMBOL: &str = "\u{26a0}";
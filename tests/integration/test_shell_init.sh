@@ -535,6 +535,43 @@ test_daft_repo_wrapper_writes_cd_file() {
     return 0
 }
 
+# `daft adopt-worktree --move` relocates a worktree created with plain
+# `git worktree add`; when the shell is standing in it, the wrapper must
+# follow it to the canonical path via DAFT_CD_FILE.
+test_adopt_worktree_move_cd_through_wrapper() {
+    log "Testing: daft adopt-worktree --move through wrapper follows the moved worktree"
+
+    local remote_dir
+    remote_dir=$(create_test_remote "test-repo-adopt-worktree" "main")
+    git-worktree-clone --layout contained "$remote_dir" >/dev/null 2>&1
+    local project_root="$PWD/test-repo-adopt-worktree"
+    if [[ ! -d "$project_root/main" ]]; then
+        log_error "setup: main worktree not created"
+        return 1
+    fi
+    git -C "$project_root/main" worktree add -b stray "$project_root/manual" >/dev/null 2>&1
+
+    local out
+    out=$(PROJECT_ROOT="$project_root" bash -c '
+        eval "$(daft shell-init bash)"
+        builtin cd "$PROJECT_ROOT/manual" || exit 11
+        daft adopt-worktree --move . >/dev/null 2>&1 || exit 12
+        builtin pwd
+    ' 2>&1) || true
+
+    if [[ "$out" != "$project_root/stray" ]]; then
+        log_error "wrapper did not follow the moved worktree (now in: $out)"
+        return 1
+    fi
+    if [[ -e "$project_root/manual" ]]; then
+        log_error "worktree left behind at $project_root/manual"
+        return 1
+    fi
+
+    log_success "daft adopt-worktree --move through wrapper lands shell at: $out"
+    return 0
+}
+
 # Regression for issue #519: the `-C <path>` global flag must work end-to-end
 # through the shell wrapper. Two failure modes the wrapper could introduce:
 #  1. Binary writes DAFT_CD_FILE relative to its post-`-C` cwd correctly, but
@@ -711,6 +748,7 @@ main() {
     run_test "daft_wrapper_intercepts_subcommand" test_daft_wrapper_intercepts_subcommand
     run_test "wrapper_resolves_binary_live" test_wrapper_resolves_binary_live
     run_test "daft_repo_wrapper_writes_cd_file" test_daft_repo_wrapper_writes_cd_file
+    run_test "adopt_worktree_move_cd_through_wrapper" test_adopt_worktree_move_cd_through_wrapper
     run_test "c_flag_cd_redirect_through_wrapper" test_c_flag_cd_redirect_through_wrapper
     run_test "start_cross_repo_cd_through_wrapper" test_start_cross_repo_cd_through_wrapper
     run_test "c_flag_no_arg_through_wrapper_errors_cleanly" test_c_flag_no_arg_through_wrapper_errors_cleanly
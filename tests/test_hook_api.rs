@@ -0,0 +1,71 @@
+use anyhow::Result;
+use daft::hooks::api::{CliOutput, HookContext, HookRunner, HookType};
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Git environment variables that must be stripped from test subprocesses.
+/// When tests run inside a git hook (e.g., pre-push), git sets these
+/// variables, which would redirect test git commands to the host repo.
+const GIT_ENV_VARS: &[&str] = &[
+    "GIT_DIR",
+    "GIT_WORK_TREE",
+    "GIT_INDEX_FILE",
+    "GIT_OBJECT_DIRECTORY",
+    "GIT_ALTERNATE_OBJECT_DIRECTORIES",
+    "GIT_COMMON_DIR",
+    "GIT_CEILING_DIRECTORIES",
+];
+
+fn git(dir: &std::path::Path, args: &[&str]) -> Result<()> {
+    let mut cmd = Command::new("git");
+    for var in GIT_ENV_VARS {
+        cmd.env_remove(var);
+    }
+    let status = cmd.arg("-C").arg(dir).args(args).status()?;
+    anyhow::ensure!(status.success(), "git {} failed", args.join(" "));
+    Ok(())
+}
+
+/// An external runner builds a context for an existing worktree and runs
+/// its `daft.yml` hook through the public API only, with `skip:` honored.
+#[test]
+fn external_runner_executes_yaml_hook_with_conditions() -> Result<()> {
+    let temp = TempDir::new()?;
+    let repo = temp.path().join("app");
+    std::fs::create_dir(&repo)?;
+    git(&repo, &["init", "-q", "-b", "feat"])?;
+    std::fs::write(
+        repo.join("daft.yml"),
+        r#"hooks:
+  worktree-post-create:
+    jobs:
+      - name: marker
+        run: echo "$DAFT_BRANCH_NAME" > ran.txt
+      - name: never
+        run: touch skipped.txt
+        skip: true
+"#,
+    )?;
+
+    let ctx = HookContext::for_worktree(HookType::PostCreate, "ci", &repo)?
+        .with_state_dir(temp.path().join("state"));
+    assert_eq!(ctx.branch_name, "feat");
+
+    let result = HookRunner::new()
+        .trusted(true)
+        .run(&ctx, &mut CliOutput::default_output())?;
+
+    assert!(result.success, "hook failed: {result:?}");
+    assert_eq!(
+        std::fs::read_to_string(repo.join("ran.txt"))?.trim(),
+        "feat"
+    );
+    assert!(!repo.join("skipped.txt").exists());
+    Ok(())
+}
+
+#[test]
+fn context_outside_a_worktree_is_an_error() {
+    let temp = TempDir::new().unwrap();
+    assert!(HookContext::for_worktree(HookType::PostCreate, "ci", temp.path()).is_err());
+}
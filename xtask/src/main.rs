@@ -43,18 +43,32 @@ const COMMANDS: &[&str] = &[
     "git-daft-skill-show",
     "git-daft-skill-uninstall",
     "daft-activate",
+    "daft-adopt-worktree",
+    "daft-auth",
+    "daft-backup",
+    "daft-clone-all",
     "daft-config",
+    "daft-config-setup",
+    "daft-consolidate",
     "daft-doctor",
     "daft-file",
     "daft-hooks",
     "daft-install",
+    "daft-env",
     "daft-layout",
     "daft-multi-remote",
+    "daft-pin",
     "daft-release-notes",
+    "daft-whats-new",
+    "daft-remote",
     "daft-run",
     "daft-shared",
     "daft-shell-init",
     "daft-shortcuts",
+    "daft-stats",
+    "daft-template",
+    "daft-which",
+    "daft-workspace",
 ];
 
 /// A daft verb command that maps to an existing git-worktree-* command for man page generation
@@ -206,11 +220,14 @@ fn get_command_for_name(command_name: &str) -> Option<clap::Command> {
         "git-daft-skill-show" => Some(daft::commands::skill::show::Args::command()),
         "git-daft-skill-uninstall" => Some(daft::commands::skill::uninstall::Args::command()),
         "daft-config" => Some(daft::commands::config::remote_sync::Args::command()),
+        "daft-config-setup" => Some(daft::commands::config::setup::Args::command()),
         "daft-doctor" => Some(daft::commands::doctor::Args::command()),
         "daft-file" => Some(daft::commands::file::merge::Args::command()),
         "daft-layout" => Some(daft::commands::layout::LayoutArgs::command()),
         "daft-release-notes" => Some(daft::commands::release_notes::Args::command()),
+        "daft-whats-new" => Some(daft::commands::whats_new::Args::command()),
         "daft-shared" => Some(daft::commands::shared::Args::command()),
+        "daft-workspace" => Some(daft::commands::workspace::Args::command()),
         "daft-remove" => Some(daft::commands::worktree_branch::RemoveArgs::command()),
         "daft-rename" => Some(daft::commands::worktree_branch::RenameArgs::command()),
         "daft-go" => Some(daft::commands::checkout::GoArgs::command()),
@@ -220,7 +237,18 @@ fn get_command_for_name(command_name: &str) -> Option<clap::Command> {
         "daft-run" => Some(daft::commands::run::Args::command()),
         "daft-multi-remote" => Some(daft::commands::multi_remote::Args::command()),
         "daft-activate" => Some(daft::commands::activate::Args::command()),
+        "daft-adopt-worktree" => Some(daft::commands::adopt_worktree::Args::command()),
         "daft-shell-init" => Some(daft::commands::shell_init::Args::command()),
+        "daft-env" => Some(daft::commands::env::Args::command()),
+        "daft-auth" => Some(daft::commands::auth::Args::command()),
+        "daft-remote" => Some(daft::commands::remote::Args::command()),
+        "daft-clone-all" => Some(daft::commands::clone_all::Args::command()),
+        "daft-backup" => Some(daft::commands::backup::Args::command()),
+        "daft-which" => Some(daft::commands::which::Args::command()),
+        "daft-consolidate" => Some(daft::commands::consolidate::Args::command()),
+        "daft-stats" => Some(daft::commands::stats::Args::command()),
+        "daft-template" => Some(daft::commands::template::Args::command()),
+        "daft-pin" => Some(daft::commands::pin::Args::command()),
         "daft-shortcuts" => Some(daft::commands::shortcuts::Args::command()),
         _ => None,
     }
@@ -347,7 +375,22 @@ fn related_commands(command_name: &str) -> Vec<&'static str> {
         "git-worktree-push" => vec!["git-worktree-sync", "git-worktree-checkout"],
         // Config cluster
         "daft-doctor" => vec!["git-worktree-clone", "git-worktree-init"],
-        "daft-release-notes" => vec![],
+        "daft-release-notes" => vec!["daft-whats-new"],
+        "daft-whats-new" => vec!["daft-release-notes"],
+        "daft-env" => vec!["daft-shell-init", "daft-layout"],
+        "daft-auth" => vec!["daft-doctor", "git-worktree-checkout"],
+        "daft-remote" => vec!["daft-multi-remote", "git-worktree-clone"],
+        "daft-clone-all" => vec!["git-worktree-clone", "daft-auth"],
+        "daft-backup" => vec!["git-worktree-carry", "daft-doctor"],
+        "daft-which" => vec!["daft-go", "daft-list"],
+        "daft-consolidate" => vec!["daft-adopt-worktree", "git-worktree-flow-adopt"],
+        "daft-stats" => vec!["daft-consolidate", "git-worktree-list"],
+        "daft-template" => vec!["daft-shared", "daft-hooks"],
+        "daft-pin" => vec![
+            "git-worktree-fetch",
+            "git-worktree-prune",
+            "git-worktree-list",
+        ],
         "daft-activate" => vec!["daft-shortcuts", "daft-shell-init"],
         "daft-shortcuts" => vec!["daft-activate", "daft-shell-init"],
         "daft-shell-init" => vec!["daft-activate", "daft-shortcuts"],
@@ -794,12 +837,14 @@ fn build_top_level_command() -> clap::Command {
         .subcommand(daft::commands::list::Args::command().name("list"))
         .subcommand(daft::commands::worktree_branch::RemoveArgs::command().name("remove"))
         .subcommand(daft::commands::worktree_branch::RenameArgs::command().name("rename"))
+        .subcommand(daft::commands::adopt_worktree::Args::command().name("adopt-worktree"))
         .subcommand(daft::commands::prune::Args::command().name("prune"))
         .subcommand(daft::commands::fetch::Args::command().name("update"))
         .subcommand(daft::commands::sync::Args::command().name("sync"))
         .subcommand(daft::commands::flow_eject::Args::command().name("eject"))
         // Configuration commands
         .subcommand(daft::commands::shared::Args::command().name("shared"))
+        .subcommand(daft::commands::workspace::Args::command().name("workspace"))
         .subcommand(daft::commands::hooks::Args::command().name("hooks"))
         .subcommand(daft::commands::layout::LayoutArgs::command().name("layout"))
         .subcommand(daft::commands::multi_remote::Args::command().name("multi-remote"))
@@ -811,10 +856,21 @@ fn build_top_level_command() -> clap::Command {
                 .subcommand(daft::commands::file::merge::Args::command().name("merge")),
         )
         .subcommand(daft::commands::doctor::Args::command().name("doctor"))
+        .subcommand(daft::commands::auth::Args::command().name("auth"))
+        .subcommand(daft::commands::remote::Args::command().name("remote"))
+        .subcommand(daft::commands::clone_all::Args::command().name("clone-all"))
+        .subcommand(daft::commands::backup::Args::command().name("backup"))
+        .subcommand(daft::commands::which::Args::command().name("which"))
+        .subcommand(daft::commands::consolidate::Args::command().name("consolidate"))
+        .subcommand(daft::commands::stats::Args::command().name("stats"))
+        .subcommand(daft::commands::template::Args::command().name("template"))
+        .subcommand(daft::commands::pin::Args::command().name("pin"))
         .subcommand(daft::commands::shell_init::Args::command().name("shell-init"))
+        .subcommand(daft::commands::env::Args::command().name("env"))
         .subcommand(daft::commands::activate::Args::command().name("activate"))
         .subcommand(daft::commands::shortcuts::Args::command().name("shortcuts"))
         .subcommand(daft::commands::release_notes::Args::command().name("release-notes"))
+        .subcommand(daft::commands::whats_new::Args::command().name("whats-new"))
 }
 
 /// Generate man pages and write to a directory
@@ -1111,6 +1167,14 @@ fn structured_output_section(command_name: &str) -> Option<String> {
              daft release-notes --format json | jq '.[0].version'\n\
              ```\n"
         }
+        "daft-env" => {
+            "`daft env` supports machine-readable output via `--format`: `json`, `yaml`,\n\
+             `toon`, `markdown`, plus `--template <tera>` for custom output.\n\n\
+             ```sh\n\
+             # Branch of the current worktree, for a script\n\
+             daft env --format json | jq -r '.branch'\n\
+             ```\n"
+        }
         "daft-hooks" => {
             "`daft hooks trust list` and `daft hooks run` (listing mode) support\n\
              machine-readable output via `--format`, plus `--template <tera>` for custom\n\
@@ -1154,6 +1218,25 @@ fn structured_output_section(command_name: &str) -> Option<String> {
              daft shared status --format markdown\n\
              ```\n"
         }
+        "daft-remote" => {
+            "`daft remote show` supports machine-readable output via `--format`: `json`,\n\
+             `ndjson`, `tsv`, `csv`, `yaml`, `toon`, `markdown`, plus `--template <tera>`\n\
+             for custom output. Every format carries the parsed fields, with or without\n\
+             `--parsed`.\n\n\
+             ```sh\n\
+             # Host, port, and repository name of origin as JSON\n\
+             daft remote show origin --format json\n\
+             ```\n"
+        }
+        "daft-workspace" => {
+            "`daft workspace status` supports machine-readable output via `--format`:\n\
+             `json`, `ndjson`, `tsv`, `csv`, `yaml`, `toon`, `markdown`, plus\n\
+             `--template <tera>` for custom output.\n\n\
+             ```sh\n\
+             # Every workspace member's state as JSON\n\
+             daft workspace status --format json\n\
+             ```\n"
+        }
         _ => return None,
     };
     let mut section = String::from("## Structured Output\n\n");